@@ -10,15 +10,52 @@ menu-edit = Edit
 menu-view = View
 menu-new-event = New Event...
 menu-new-calendar = New Calendar...
+menu-add-caldav-account = Add CalDAV Account...
+menu-sync-all = Sync All Calendars
 menu-import-ical = Import iCal...
 menu-export-ical = Export iCal...
+menu-print-week = Print Week...
+menu-open-data-directory = Open Data Folder
 menu-settings = Settings...
+menu-undo = Undo
+menu-redo = Redo
+menu-select-all-events = Select All Events in View
+menu-move-event-to-today = Move Selected Event to Today
+menu-repeat-event-next-week = Repeat Selected Event Next Week
+menu-repeat-event-next-month = Repeat Selected Event Next Month
+menu-duplicate-event = Duplicate Selected Event
 menu-today = Jump to Today
 menu-day-view = Day View
 menu-week-view = Week View
 menu-month-view = Month View
 menu-year-view = Year View
+menu-agenda-view = Agenda
 menu-show-week-numbers = Show Week Numbers
+menu-show-weekends = Show Weekends
+menu-show-mini-week = Show Week Strip in Month View
+menu-events-per-day = Events per Day
+menu-events-per-day-fit = Fit to Cell
+menu-events-per-day-count = { $count } Events
+menu-grid-lines = Grid Lines
+menu-grid-lines-none = None
+menu-grid-lines-subtle = Subtle
+menu-grid-lines-strong = Strong
+menu-week-start = Week Starts On
+menu-weekend-days = Weekend
+menu-weekend-sat-sun = Saturday and Sunday
+menu-weekend-fri-sat = Friday and Saturday
+menu-locale-default = Locale Default
+menu-event-text-size = Event Text Size
+menu-event-text-size-small = Small
+menu-event-text-size-normal = Normal
+menu-event-text-size-large = Large
+menu-event-text-size-extra-large = Extra Large
+menu-event-overlap = Overlapping Events
+menu-event-overlap-columns = Side by Side
+menu-event-overlap-cascade = Cascade
+menu-new-event-dialog = Open Full Editor for New Events
+menu-show-missed-alerts = Summarize Missed Alerts on Startup
+menu-confirm-import-duplicates = Preview Imports into Non-Empty Calendars
 menu-about = About Sol Calendar
 menu-quit = Quit
 
@@ -32,6 +69,7 @@ view-day = Day
 nav-today = Today
 nav-previous = Previous
 nav-next = Next
+nav-next-event = Next event →
 
 # Sidebar
 sidebar-calendars = Calendars
@@ -44,8 +82,16 @@ week-abbr = Wk
 calendar-add = Add Calendar
 calendar-edit = Edit Calendar
 calendar-export = Export Calendar
+calendar-duplicate = Duplicate Calendar
+calendar-copy-suffix = (copy)
+calendar-sync = Sync Now
 calendar-delete = Delete Calendar
 calendar-select = Select Calendar
+calendar-change-color = Change Color
+calendar-set-default = Default for New Events
+calendar-pin = Pin to Top
+calendar-move-up = Move Up
+calendar-move-down = Move Down
 calendar-toggle = Toggle visibility
 
 # Days of week (abbreviated)
@@ -88,17 +134,41 @@ event-title = Title
 event-title-placeholder = Event title
 event-location = Location
 event-location-placeholder = Add location
+event-location-suggestions = Recent Locations
 event-all-day = All day
+week-all-day-summary = { $count ->
+    [one] 1 all-day event
+   *[other] { $count } all-day events
+}
+day-free-slots = Free {$slots}
+agenda-title = Agenda
+agenda-days = Next { $days } days
+agenda-no-events = No events in the next { $days } days
+day-no-free-slots = No free time
+day-event-ending = { $summary } ending in { $minutes } min
+event-timezone-fallback = Unknown time zone, shown in UTC
 event-starts = Starts
 event-ends = Ends
+event-duration = Duration
+event-duration-negative = Ends before it starts
+event-error-empty-title = Enter a title
+event-error-invalid-start-date = Start date isn't a valid date (YYYY-MM-DD)
+event-error-invalid-end-date = End date isn't a valid date (YYYY-MM-DD)
+event-error-end-before-start = The event ends before it starts
 event-datetime-section = Date & Time
 event-travel-time = Travel Time
 event-repeat = Repeat
+event-repeat-interval = Repeats
+event-repeat-weekdays = On
 event-schedule-section = Schedule
 event-calendar = Calendar
+event-color = Color
 event-no-calendar = No calendar
 event-invitees = Invitees
 event-invitee-placeholder = Add email address
+event-categories = Categories
+event-category-placeholder = Add category
+category-filter-clear = Show All
 event-alert = Alert
 event-attachments = Attachments
 event-no-attachments = No attachments
@@ -127,6 +197,22 @@ repeat-biweekly = Every 2 weeks
 repeat-monthly = Monthly
 repeat-yearly = Yearly
 repeat-custom = Custom
+repeat-interval-days = { $count ->
+    [one] Every day
+   *[other] Every { $count } days
+}
+repeat-interval-weeks = { $count ->
+    [one] Every week
+   *[other] Every { $count } weeks
+}
+repeat-interval-months = { $count ->
+    [one] Every month
+   *[other] Every { $count } months
+}
+repeat-interval-years = { $count ->
+    [one] Every year
+   *[other] Every { $count } years
+}
 
 # Alert Options
 alert-none = None
@@ -140,6 +226,7 @@ alert-2hours = 2 hours before
 alert-1day = 1 day before
 alert-2days = 2 days before
 alert-1week = 1 week before
+alert-day-before-at = { $time } the day before
 
 # Buttons
 button-save = Save
@@ -162,6 +249,10 @@ import-event-count = {$count ->
 import-target-calendar = Import into calendar
 import-events-preview = Events preview
 import-more-events = ...and {$count} more
+import-preview-counts = This will add {$added} and skip {$skipped} {$skipped ->
+    [one] duplicate
+    *[other] duplicates
+}
 
 # Dialog - Import Result
 dialog-import-result-title = Import Complete
@@ -178,6 +269,15 @@ dialog-edit-calendar-title = Edit Calendar
 dialog-calendar-name = Name
 dialog-calendar-name-placeholder = Calendar name
 dialog-calendar-color = Color
+dialog-calendar-mute-alerts = Mute alerts
+
+# Dialog - CalDAV Account
+dialog-caldav-title = Add CalDAV Account
+dialog-caldav-server-url = Calendar URL
+dialog-caldav-server-url-placeholder = https://example.com/dav/calendars/user/personal/
+dialog-caldav-username = Username
+dialog-caldav-password = Password
+dialog-caldav-add = Add Account
 
 # Dialog - Delete Calendar
 dialog-delete-calendar-title = Delete Calendar
@@ -188,7 +288,13 @@ dialog-delete-event-title = Delete Event
 dialog-delete-event-message = Are you sure you want to delete "{$name}"?
 dialog-delete-event-recurring-message = This is a recurring event. What would you like to delete?
 button-delete-this-occurrence = This One
+button-delete-following-occurrences = This and Following
 button-delete-all-occurrences = All
+dialog-edit-scope-title = Edit Recurring Event
+dialog-edit-scope-message = This is a recurring event. Which events would you like to change?
+button-edit-this-event = This Event
+button-edit-following-events = This and Following
+button-edit-all-events = All Events
 
 # Search
 search-placeholder = Search events...
@@ -199,6 +305,20 @@ settings-general = General
 settings-appearance = Appearance
 settings-calendars = Calendars
 settings-advanced = Advanced
+settings-title = Settings
+settings-show-week-numbers = Show week numbers
+settings-clock = Time format
+settings-clock-24-hour = 24-hour
+settings-clock-12-hour = 12-hour
+settings-week-start = Week starts on
+settings-default-view = Start in
+settings-all-day-alert = Alert for all-day events
+settings-day-start = Open other days at
+settings-shade-off-hours = Shade hours outside working hours
+settings-working-day-start = Working day starts
+settings-working-day-end = Working day ends
+settings-system-default = System default
+settings-reset = Reset to System Defaults
 
 # About dialog
 about-version = Version
@@ -207,6 +327,13 @@ about-license = License
 about-author = Author
 about-support = Support
 
+# Calendar statistics (About drawer)
+stats-title = Events per Calendar
+stats-calendar = Calendar
+stats-week = Week
+stats-month = Month
+stats-year = Year
+
 # Subscribe dialog
 subscribe-dialog-title = Subscribe to Calendar
 subscribe-dialog-url = URL
@@ -220,3 +347,33 @@ subscribe-dialog-use-existing = Use existing calendar
 subscribe-dialog-create-new = Create new calendar
 subscribe-dialog-new-calendar-placeholder = New calendar name
 subscribe-dialog-subscribe = Subscribe
+
+# Toast notifications
+toast-event-deleted = Event deleted
+toast-undo = Undo
+toast-events-imported = { $count ->
+    [one] Imported 1 event
+   *[other] Imported { $count } events
+}
+toast-events-selected = { $count ->
+    [one] 1 event selected
+   *[other] { $count } events selected
+}
+toast-events-deleted = { $count ->
+    [one] 1 event deleted
+   *[other] { $count } events deleted
+}
+toast-events-exported = { $count ->
+    [one] Exported 1 event
+   *[other] Exported { $count } events
+}
+toast-week-printed = Saved printable week to { $file }
+toast-sync-failed = Couldn't sync { $calendar }: { $reason }
+toast-event-save-failed = Couldn't save event: { $reason }
+notification-alert-body = Starts at { $time }
+notification-alert-body-all-day = All day, { $date }
+notification-open-event = Open Event
+toast-missed-alerts = { $count ->
+    [one] Missed alert: { $events }
+   *[other] { $count } missed alerts: { $events }
+}
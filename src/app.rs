@@ -6,10 +6,15 @@ use crate::fl;
 use crate::locale::LocalePreferences;
 use crate::menu_action::MenuAction;
 use crate::message::Message;
-use crate::models::{CalendarState, WeekState, DayState, YearState};
-use crate::selection::{SelectionState, EventDragState};
+use crate::models::{
+    format_missed_alerts, missed_alerts, CalendarState, CalendarStats, CategoryFilter, StatsPeriod, WeekState, DayState,
+    YearState, ToastState, UndoStack, MISSED_ALERT_GRACE_MINUTES,
+};
+use crate::selection::{SelectionState, EventDragState, EventResizeState};
+use crate::services::{AlertScheduler, CalendarHandler, EventHandler, SettingsHandler};
 use crate::settings::AppSettings;
 use crate::views::{self, CalendarView};
+use crate::validation::{validate_event_input, EventInputError};
 use chrono::{Datelike, NaiveDate};
 use cosmic::app::{Core, Task};
 use cosmic::iced::keyboard;
@@ -30,6 +35,12 @@ const APP_ID: &str = if option_env!("FLATPAK_ID").is_some() {
     "dev.xarbit.apps.Calendar.Devel"
 };
 
+/// How far ahead to look for events whose alert may have passed (covers a one-week alert)
+const MISSED_ALERT_LOOKAHEAD_DAYS: i64 = 8;
+
+/// Minimum interval between saves of the last-run timestamp while running
+const LAST_RUN_SAVE_INTERVAL_MINUTES: i64 = 5;
+
 /// Command-line flags passed to the application
 #[derive(Debug, Clone, Default)]
 pub struct AppFlags {
@@ -60,6 +71,10 @@ pub enum EventDialogField {
 pub struct EventDialogState {
     /// Event UID (None for new events, Some for editing)
     pub editing_uid: Option<String>,
+    /// Date of the occurrence being edited, when opened from one occurrence of a series
+    pub occurrence_date: Option<chrono::NaiveDate>,
+    /// Whether the "this / following / all events" prompt is shown over the dialog
+    pub scope_prompt_open: bool,
     /// Event title/summary
     pub title: String,
     /// Event location
@@ -86,12 +101,24 @@ pub struct EventDialogState {
     pub travel_time: crate::caldav::TravelTime,
     /// Repeat/recurrence settings
     pub repeat: crate::caldav::RepeatFrequency,
+    /// Repeat every N units of the frequency (1 = every day/week/month/year)
+    pub repeat_interval: u32,
+    /// Weekdays of a weekly series (empty = the start date's weekday)
+    pub repeat_weekdays: Vec<chrono::Weekday>,
     /// Selected calendar ID for the event
     pub calendar_id: String,
     /// Invitees (email addresses)
     pub invitees: Vec<String>,
     /// New invitee being typed (input buffer)
     pub invitee_input: String,
+    /// Categories/tags
+    pub categories: Vec<String>,
+    /// IANA time zone of the event being edited (None = floating local time)
+    pub timezone: Option<String>,
+    /// New category being typed (input buffer)
+    pub category_input: String,
+    /// Per-event color override (hex); None uses the calendar color
+    pub color: Option<String>,
     /// Alert/reminder settings
     pub alert: crate::caldav::AlertTime,
     /// Second alert (optional)
@@ -102,6 +129,10 @@ pub struct EventDialogState {
     pub url: String,
     /// Notes/description content (for text_editor widget)
     pub notes_content: text_editor::Content,
+    /// Event status (preserved when editing imported events)
+    pub status: crate::caldav::EventStatus,
+    /// Previously used locations offered as autocomplete suggestions
+    pub recent_locations: Vec<String>,
     /// Which field is currently being edited (None = no field in edit mode)
     pub editing_field: Option<EventDialogField>,
     /// Whether the start date calendar picker is open
@@ -118,6 +149,28 @@ pub struct EventDialogState {
     pub end_time_picker_open: bool,
 }
 
+impl EventDialogState {
+    /// Start and end as entered, in the event's own time. All-day events span
+    /// from midnight of the start date to the end of the end date.
+    pub fn local_span(&self) -> (chrono::NaiveDateTime, chrono::NaiveDateTime) {
+        let (start_time, end_time) = if self.all_day {
+            (chrono::NaiveTime::MIN, chrono::NaiveTime::from_hms_opt(23, 59, 59).unwrap())
+        } else {
+            (
+                self.start_time.unwrap_or_else(|| chrono::NaiveTime::from_hms_opt(9, 0, 0).unwrap()),
+                self.end_time.unwrap_or_else(|| chrono::NaiveTime::from_hms_opt(10, 0, 0).unwrap()),
+            )
+        };
+        (self.start_date.and_time(start_time), self.end_date.and_time(end_time))
+    }
+
+    /// Whether the input can be saved, or the first problem with it
+    pub fn validate(&self) -> Result<(), EventInputError> {
+        let (start, end) = self.local_span();
+        validate_event_input(&self.title, &self.start_date_input, &self.end_date_input, start, end)
+    }
+}
+
 /// Main application state
 pub struct CosmicCalendar {
     pub core: Core,
@@ -130,6 +183,10 @@ pub struct CosmicCalendar {
     /// Track previous condensed state to detect changes and sync sidebar
     pub last_condensed: bool,
     pub show_search: bool,
+    /// Text in the search field
+    pub search_query: String,
+    /// Events matching `search_query`, with the date each is shown on
+    pub search_results: Vec<(NaiveDate, components::DisplayEvent)>,
     pub cache: CalendarCache,
     pub week_state: WeekState,
     pub day_state: DayState,
@@ -146,6 +203,8 @@ pub struct CosmicCalendar {
     pub cached_month_events: std::collections::HashMap<chrono::NaiveDate, Vec<crate::components::DisplayEvent>>,
     /// Cached events for current week view, grouped by date
     pub cached_week_events: std::collections::HashMap<chrono::NaiveDate, Vec<crate::components::DisplayEvent>>,
+    /// Cached events for the agenda view (only filled while it is shown)
+    pub cached_agenda_events: std::collections::HashMap<chrono::NaiveDate, Vec<crate::components::DisplayEvent>>,
     /// Color of the selected calendar (cached for quick event input)
     pub selected_calendar_color: String,
     /// Centralized dialog state - only one dialog can be open at a time
@@ -154,8 +213,14 @@ pub struct CosmicCalendar {
     pub selection_state: SelectionState,
     /// Event drag state for moving events to new dates
     pub event_drag_state: EventDragState,
+    /// Event resize state for dragging the top/bottom edge of timed events
+    pub event_resize_state: EventResizeState,
+    /// Last known cursor position in the window, for placing popovers at the click
+    pub cursor_position: (f32, f32),
     /// Currently selected event UID (for viewing/editing/deleting) - uses unique_id format (calendar_id:uid)
     pub selected_event_uid: Option<String>,
+    /// Events selected together with Select All (unique_id format), for batch actions
+    pub selected_event_uids: Vec<String>,
     /// Cached unique_id (calendar_id:uid) of the event being dragged (computed from event_drag_state)
     pub dragging_event_unique_id: Option<String>,
     /// Current scroll position for week view - continuously tracked via on_scroll callback
@@ -163,6 +228,20 @@ pub struct CosmicCalendar {
     /// Saved scroll position to restore after quick event closes
     /// Captured when quick event starts, used to restore when it ends (prevents focus-induced jump)
     pub week_view_scroll_restore: Option<cosmic::iced::widget::scrollable::AbsoluteOffset>,
+    /// Transient toast notification (e.g. "Event deleted — Undo")
+    pub toast: ToastState,
+    /// Event changes (create, edit, delete, move) that Ctrl+Z / Ctrl+Shift+Z revert and reapply
+    pub undo_stack: UndoStack,
+    /// Tracks event alerts already posted as desktop notifications
+    pub alert_scheduler: AlertScheduler,
+    /// Categories used by events in enabled calendars (filter bar chips)
+    pub available_categories: Vec<String>,
+    /// Selected categories limiting which events are displayed
+    pub category_filter: CategoryFilter,
+    /// Event counts per calendar shown in the About drawer (refreshed when it opens)
+    pub calendar_stats: CalendarStats,
+    /// Soonest event date after the visible range, set only while that range shows no events
+    pub next_event_date: Option<NaiveDate>,
 
     // Legacy field - kept because text_editor::Content doesn't implement Clone
     /// Event dialog state (for Create/Edit) - None when dialog is closed
@@ -178,22 +257,18 @@ impl CosmicCalendar {
         let year = today.year();
         let month = today.month();
 
-        // Create cache and pre-cache surrounding months
-        let mut cache = CalendarCache::new(year, month);
-        cache.precache_surrounding(1, 2);
-
         // Initialize calendar manager with default calendars
-        let calendar_manager = CalendarManager::with_defaults();
-
-        // Select the first calendar by default for new events
-        let selected_calendar_id = calendar_manager
-            .sources()
-            .first()
-            .map(|c| c.info().id.clone());
+        let mut calendar_manager = CalendarManager::with_defaults();
 
         // Load application settings
         let settings = AppSettings::load().unwrap_or_default();
 
+        // Select the user's default calendar for new events, or the first enabled one
+        let selected_calendar_id = CalendarHandler::resolve_selected_calendar_id(
+            calendar_manager.sources(),
+            settings.default_calendar_id.as_deref(),
+        );
+
         // Create About dialog
         let about = about::About::default()
             .name(fl!("app-title"))
@@ -206,19 +281,24 @@ impl CosmicCalendar {
             .links([(fl!("about-repository"), "https://github.com/xarbit/sol"),
                 (fl!("about-support"), "https://github.com/xarbit/sol/issues")]);
 
-        // Detect system locale preferences
-        let locale = LocalePreferences::detect_from_system();
+        // Detect system locale preferences, with the user's overrides on top
+        let locale = locale_with_overrides(&settings);
+
+        // Create cache and pre-cache surrounding months
+        let mut cache = CalendarCache::new(year, month, locale.first_day_of_week);
+        cache.precache_surrounding(1, 2);
 
         // Initialize keyboard shortcuts from centralized module
         let key_binds = crate::keyboard::init_key_binds();
 
         // Mini calendar starts showing the current month
-        let mini_calendar_state = CalendarState::new(year, month);
+        let mini_calendar_state = CalendarState::new(year, month, locale.first_day_of_week);
 
-        // Get selected calendar color (default to first calendar's color)
+        // Get selected calendar color (default blue if there are no calendars)
         let selected_calendar_color = calendar_manager
             .sources()
-            .first()
+            .iter()
+            .find(|c| Some(&c.info().id) == selected_calendar_id.as_ref())
             .map(|c| c.info().color.clone())
             .unwrap_or_else(|| "#3B82F6".to_string());
 
@@ -226,22 +306,30 @@ impl CosmicCalendar {
         let cached_month_events = calendar_manager.get_display_events_for_month(year, month);
 
         // Create week state and cache week events
-        let week_state = WeekState::current_with_first_day(locale.first_day_of_week, &locale);
+        let week_state = WeekState::current_with_first_day(locale.first_day_of_week, settings.show_weekends, &locale);
         let cached_week_events = calendar_manager.get_display_events_for_week(&week_state.days);
 
+        // Categories for the filter bar (no filter is active at startup)
+        let available_categories = calendar_manager.all_categories();
+
+        // Alerts fired before a restart are remembered in the database
+        let alert_scheduler = AlertScheduler::with_database(chrono::Local::now().naive_local().and_utc(), calendar_manager.database());
+
         #[allow(deprecated)]
         CosmicCalendar {
             core,
-            current_view: CalendarView::Month,
+            current_view: CalendarView::from(settings.default_view),
             selected_date: today,
             calendar_manager,
             show_sidebar: true,
             last_condensed: false, // Will be synced on first render
             show_search: false,
+            search_query: String::new(),
+            search_results: Vec::new(),
             cache,
             week_state,
             day_state: DayState::current(&locale),
-            year_state: YearState::current(),
+            year_state: YearState::current(locale.first_day_of_week),
             mini_calendar_state,
             locale,
             settings,
@@ -250,19 +338,75 @@ impl CosmicCalendar {
             selected_calendar_id,
             cached_month_events,
             cached_week_events,
+            cached_agenda_events: HashMap::new(),
             selected_calendar_color,
             active_dialog: ActiveDialog::None,
             selection_state: SelectionState::new(),
             event_drag_state: EventDragState::new(),
+            event_resize_state: EventResizeState::new(),
+            cursor_position: (0.0, 0.0),
             selected_event_uid: None,
+            selected_event_uids: Vec::new(),
             dragging_event_unique_id: None,
             week_view_scroll_opt: None,
             week_view_scroll_restore: None,
+            toast: ToastState::new(),
+            undo_stack: UndoStack::new(),
+            alert_scheduler,
+            available_categories,
+            category_filter: CategoryFilter::default(),
+            calendar_stats: CalendarStats::default(),
+            next_event_date: None,
             // Legacy field - kept because text_editor::Content doesn't implement Clone
             event_dialog: None,
         }
     }
 
+    /// Rebuild locale preferences with the user's clock, week start and weekend
+    /// overrides and refresh the views that depend on them
+    pub fn apply_locale_preferences(&mut self) {
+        self.locale = locale_with_overrides(&self.settings);
+        self.sync_views_to_selected_date();
+    }
+
+    /// Show a toast summarizing alerts that passed since the last run
+    /// (if enabled), then record this run
+    fn report_missed_alerts(&mut self) {
+        let now = chrono::Local::now().naive_local().and_utc();
+
+        if let Some(last_run) = self.settings.last_run.filter(|_| self.settings.show_missed_alerts) {
+            // Events that started up to the grace period ago, through the longest preset alert lead
+            let range_start = (now - chrono::Duration::minutes(MISSED_ALERT_GRACE_MINUTES)).date_naive();
+            let range_end = (now + chrono::Duration::days(MISSED_ALERT_LOOKAHEAD_DAYS)).date_naive();
+            let events = self.calendar_manager.get_alert_occurrences_between(range_start, range_end);
+
+            let missed = missed_alerts(&events, last_run, now);
+            if !missed.is_empty() {
+                info!("CosmicCalendar: {} alert(s) missed since {}", missed.len(), last_run);
+                self.toast.show(
+                    fl!("toast-missed-alerts", count = missed.len(), events = format_missed_alerts(&missed, 3)),
+                    None,
+                    std::time::Instant::now(),
+                );
+            }
+        }
+
+        self.record_last_run(true);
+    }
+
+    /// Store the current time as the last run, at most every few minutes unless forced
+    pub fn record_last_run(&mut self, force: bool) {
+        let now = chrono::Local::now().naive_local().and_utc();
+        let due = self.settings.last_run.map_or(true, |last_run| {
+            now - last_run >= chrono::Duration::minutes(LAST_RUN_SAVE_INTERVAL_MINUTES)
+        });
+        if force || due {
+            if let Err(e) = SettingsHandler::set_last_run(&mut self.settings, now) {
+                log::error!("Failed to record last run: {}", e);
+            }
+        }
+    }
+
     /// Sync all views to show the period containing the selected_date
     pub fn sync_views_to_selected_date(&mut self) {
         let date = self.selected_date;
@@ -270,20 +414,21 @@ impl CosmicCalendar {
         let month = date.month();
 
         // Update month view cache
+        self.cache.set_first_day(self.locale.first_day_of_week);
         self.cache.set_current(year, month);
         self.cache.precache_surrounding(1, 2);
 
         // Update week view
-        self.week_state = WeekState::new(date, self.locale.first_day_of_week, &self.locale);
+        self.week_state = WeekState::new(date, self.locale.first_day_of_week, self.settings.show_weekends, &self.locale);
 
         // Update day view
         self.day_state = DayState::new(date, &self.locale);
 
         // Update year view
-        self.year_state = YearState::new(year);
+        self.year_state = YearState::new(year, self.locale.first_day_of_week);
 
         // Sync mini calendar to show the month containing selected_date
-        self.mini_calendar_state = CalendarState::new(year, month);
+        self.mini_calendar_state = CalendarState::new(year, month, self.locale.first_day_of_week);
 
         // Refresh cached events for the new month
         self.refresh_cached_events();
@@ -296,9 +441,112 @@ impl CosmicCalendar {
         self.cached_month_events = self.calendar_manager
             .get_display_events_for_month(cache_state.year, cache_state.month);
 
-        // Refresh week events
-        self.cached_week_events = self.calendar_manager
-            .get_display_events_for_week(&self.week_state.days);
+        // Refresh week events, which the day view shows too (its day may be a hidden weekend day)
+        let mut days = self.week_state.days.clone();
+        if !days.contains(&self.day_state.date) {
+            days.push(self.day_state.date);
+        }
+        self.cached_week_events = self.calendar_manager.get_display_events_for_week(&days);
+
+        // Refresh agenda events, which span more days than any other view
+        self.cached_agenda_events = if self.current_view == CalendarView::Agenda {
+            self.calendar_manager
+                .get_display_events_for_week(&views::agenda_days(self.selected_date))
+        } else {
+            HashMap::new()
+        };
+
+        // Keep the category filter in sync with the categories still in use
+        self.available_categories = self.calendar_manager.all_categories();
+        self.category_filter.retain_available(&self.available_categories);
+        self.category_filter.apply(&mut self.cached_month_events);
+        self.category_filter.apply(&mut self.cached_week_events);
+        self.category_filter.apply(&mut self.cached_agenda_events);
+
+        // Offer a jump to the next event when the visible range is empty
+        // (the year view has no per-day events cached, so it never offers it)
+        self.next_event_date = self.visible_events_by_date().and_then(|events_by_date| {
+            let range = self.visible_range();
+            views::next_event_button_target(events_by_date, range, || {
+                self.calendar_manager.next_event_date_after(range.1)
+            })
+        });
+
+        // Keep search results current after edits, imports and syncs
+        if self.show_search {
+            self.refresh_search_results();
+        }
+    }
+
+    /// Re-run the search for the current query
+    pub fn refresh_search_results(&mut self) {
+        self.search_results = self.calendar_manager
+            .search_events(&self.search_query, chrono::Local::now().date_naive());
+    }
+
+    /// Cached display events backing the current view (None for the year view)
+    pub fn visible_events_by_date(&self) -> Option<&HashMap<NaiveDate, Vec<components::DisplayEvent>>> {
+        match self.current_view {
+            CalendarView::Year => None,
+            CalendarView::Month => Some(&self.cached_month_events),
+            CalendarView::Week | CalendarView::Day => Some(&self.cached_week_events),
+            CalendarView::Agenda => Some(&self.cached_agenda_events),
+        }
+    }
+
+    /// Events drawn as selected: the clicked event and any Select All batch
+    pub fn selected_events(&self) -> components::EventSelection<'_> {
+        components::EventSelection {
+            event: self.selected_event_uid.as_deref(),
+            batch: &self.selected_event_uids,
+        }
+    }
+
+    /// Unique ids of the events shown in the current view
+    pub fn visible_event_ids(&self) -> Vec<String> {
+        self.visible_events_by_date()
+            .map(|events_by_date| views::visible_event_ids(events_by_date, self.visible_range()))
+            .unwrap_or_default()
+    }
+
+    /// First and last date visible in the current view (inclusive).
+    /// Features that query or summarize "what's on screen" should use this range.
+    pub fn visible_range(&self) -> (NaiveDate, NaiveDate) {
+        self.current_view.visible_range(
+            self.cache.current_state(),
+            &self.week_state,
+            &self.day_state,
+            self.year_state.year,
+        )
+    }
+
+    /// Recount events per calendar for the current week, month, and year
+    pub fn refresh_calendar_stats(&mut self) {
+        let today = chrono::Local::now().date_naive();
+        let count = |period: StatsPeriod| {
+            let (start, end) = period.range(today, self.locale.first_day_of_week);
+            // Event times are stored as UTC instants, so the period runs from local midnight
+            let zone = Some(crate::timezone::system_zone());
+            let start = crate::timezone::to_utc(start.and_time(chrono::NaiveTime::MIN), zone);
+            let end = crate::timezone::to_utc(end.and_time(chrono::NaiveTime::MIN), zone);
+            EventHandler::count_events_by_calendar(&self.calendar_manager, start, end).unwrap_or_else(|e| {
+                log::warn!("refresh_calendar_stats: {}", e);
+                HashMap::new()
+            })
+        };
+
+        let calendars: Vec<(String, String)> = self.calendar_manager
+            .sources()
+            .iter()
+            .map(|c| (c.info().id.clone(), c.info().name.clone()))
+            .collect();
+
+        self.calendar_stats = CalendarStats::from_counts(
+            &calendars,
+            &count(StatsPeriod::Week),
+            &count(StatsPeriod::Month),
+            &count(StatsPeriod::Year),
+        );
     }
 
     /// Update the selected calendar color cache
@@ -310,6 +558,16 @@ impl CosmicCalendar {
         }
     }
 
+    /// Make sure a calendar is selected for new events.
+    /// Keeps the current selection if it still exists, otherwise selects the first enabled calendar.
+    pub fn ensure_selected_calendar(&mut self) {
+        self.selected_calendar_id = CalendarHandler::resolve_selected_calendar_id(
+            self.calendar_manager.sources(),
+            self.selected_calendar_id.as_deref(),
+        );
+        self.update_selected_calendar_color();
+    }
+
     /// Set the selected date and sync all views
     pub fn set_selected_date(&mut self, date: NaiveDate) {
         self.selected_date = date;
@@ -330,7 +588,7 @@ impl CosmicCalendar {
         } else {
             (state.year, state.month - 1)
         };
-        self.mini_calendar_state = CalendarState::new(year, month);
+        self.mini_calendar_state = CalendarState::new(year, month, self.locale.first_day_of_week);
     }
 
     /// Navigate to the next period based on current view
@@ -341,7 +599,7 @@ impl CosmicCalendar {
         } else {
             (state.year, state.month + 1)
         };
-        self.mini_calendar_state = CalendarState::new(year, month);
+        self.mini_calendar_state = CalendarState::new(year, month, self.locale.first_day_of_week);
     }
 
     /// Render the sidebar
@@ -361,6 +619,7 @@ impl CosmicCalendar {
             selected_day,
             &self.active_dialog,
             self.selected_calendar_id.as_ref(),
+            self.settings.default_calendar_id.as_deref(),
         )
     }
 
@@ -376,18 +635,26 @@ impl CosmicCalendar {
             quick_event: quick_event_data,
             selection: &self.selection_state,
             active_dialog: &self.active_dialog,
-            selected_event_uid: self.selected_event_uid.as_deref(),
+            selected_events: self.selected_events(),
             event_drag_active: self.event_drag_state.is_active,
             dragging_event_uid: self.dragging_event_unique_id.as_deref(),
             drag_target_date: self.event_drag_state.target_date(),
+            dragging_event_color: self.event_drag_state.event_color(),
+            max_events_per_day: self.settings.max_events_per_day,
+            event_text_size: self.settings.event_text_size,
         };
 
         let week_events = views::WeekViewEvents {
             events_by_date: &self.cached_week_events,
-            selected_event_uid: self.selected_event_uid.as_deref(),
+            selected_events: self.selected_events(),
             selection: &self.selection_state,
+            resize: &self.event_resize_state,
             active_dialog: &self.active_dialog,
             calendar_color: &self.selected_calendar_color,
+            event_text_size: self.settings.event_text_size,
+            overlap_style: self.settings.event_overlap_style,
+            all_day_collapsed: self.settings.week_all_day_collapsed,
+            working_hours: self.settings.off_hours_band(),
         };
 
         views::render_main_content(
@@ -399,12 +666,29 @@ impl CosmicCalendar {
             self.current_view,
             Some(self.selected_date),
             self.settings.show_week_numbers,
+            self.settings.show_weekends,
+            self.settings.show_mini_week,
+            self.settings.grid_lines,
+            self.settings.working_hours,
+            self.core.is_condensed(),
+            &self.available_categories,
+            &self.category_filter,
             Some(month_events),
             Some(week_events),
+            &self.cached_agenda_events,
+            self.next_event_date,
+            self.show_search.then_some((self.search_query.as_str(), self.search_results.as_slice())),
         )
     }
 }
 
+/// System locale preferences with the user's clock, week start and weekend overrides
+fn locale_with_overrides(settings: &AppSettings) -> LocalePreferences {
+    LocalePreferences::detect_from_system()
+        .with_week_overrides(settings.first_day_of_week, settings.weekend_days.as_deref())
+        .with_clock_override(settings.use_24_hour)
+}
+
 impl Default for CosmicCalendar {
     fn default() -> Self {
         Self::initialize_app(Core::default())
@@ -426,9 +710,15 @@ impl Application for CosmicCalendar {
     }
 
     fn init(core: Core, flags: Self::Flags) -> (Self, cosmic::app::Task<Self::Message>) {
-        let app = Self::initialize_app(core);
+        let mut app = Self::initialize_app(core);
         info!("CosmicCalendar: Application initialized with view {:?}", app.current_view);
 
+        // Summarize alerts that passed while the app was closed
+        app.report_missed_alerts();
+
+        // Refresh remote calendars in the background; their offline copies show meanwhile
+        let sync_calendars = cosmic::app::Task::done(cosmic::Action::App(Message::SyncAllCalendars));
+
         // Handle file arguments if provided
         if !flags.files_to_open.is_empty() {
             info!("CosmicCalendar: {} file(s) to open on startup", flags.files_to_open.len());
@@ -436,7 +726,8 @@ impl Application for CosmicCalendar {
             // (Only one dialog can be open at a time)
             if let Some(file_path) = flags.files_to_open.first() {
                 info!("CosmicCalendar: Triggering import for {:?}", file_path);
-                return (app, cosmic::app::Task::done(cosmic::Action::App(Message::ImportFile(file_path.clone()))));
+                let import = cosmic::app::Task::done(cosmic::Action::App(Message::ImportFile(file_path.clone())));
+                return (app, cosmic::app::Task::batch([sync_calendars, import]));
             }
         }
 
@@ -446,15 +737,16 @@ impl Application for CosmicCalendar {
             // Process the first URL
             if let Some(url) = flags.urls_to_open.first() {
                 info!("CosmicCalendar: Processing URL: {}", url);
-                return (app, cosmic::app::Task::done(cosmic::Action::App(Message::ProcessUrl(url.clone()))));
+                let process_url = cosmic::app::Task::done(cosmic::Action::App(Message::ProcessUrl(url.clone())));
+                return (app, cosmic::app::Task::batch([sync_calendars, process_url]));
             }
         }
 
-        (app, cosmic::app::Task::none())
+        (app, sync_calendars)
     }
 
     fn header_start(&self) -> Vec<Element<'_, Self::Message>> {
-        components::render_header_start(&self.core, &self.key_binds, self.show_sidebar, self.settings.show_week_numbers)
+        components::render_header_start(&self.core, &self.key_binds, self.show_sidebar, &self.settings)
     }
 
     fn header_end(&self) -> Vec<Element<'_, Self::Message>> {
@@ -474,9 +766,14 @@ impl Application for CosmicCalendar {
             return None;
         }
 
-        Some(cosmic::app::context_drawer::about(
-            &self.about,
-            |url| Message::LaunchUrl(url.to_string()),
+        // About information followed by per-calendar event counts
+        let content = cosmic::widget::column()
+            .spacing(crate::ui_constants::SPACING_LARGE)
+            .push(about::about(&self.about, |url| Message::LaunchUrl(url.to_string())))
+            .push(components::render_calendar_stats(&self.calendar_stats));
+
+        Some(cosmic::app::context_drawer::context_drawer(
+            content,
             Message::ToggleContextDrawer,
         ))
     }
@@ -485,7 +782,7 @@ impl Application for CosmicCalendar {
         use cosmic::iced::Subscription;
 
         // Event listener for keyboard, window resize, and mouse events
-        let event_sub = cosmic::iced::event::listen_with(|event, _status, _window_id| {
+        let event_sub = cosmic::iced::event::listen_with(|event, status, _window_id| {
             match event {
                 // Handle keyboard shortcuts
                 cosmic::iced::Event::Keyboard(keyboard::Event::KeyPressed {
@@ -520,8 +817,19 @@ impl Application for CosmicCalendar {
 
                     // Look up the action in the global keyboard shortcuts
                     if let Some(action) = crate::keyboard::get_key_binds().get(&key_bind) {
+                        // A focused text input keeps its own editing shortcuts
+                        if crate::keyboard::is_text_editing_bind(*action) {
+                            return (status == cosmic::iced::event::Status::Ignored)
+                                .then_some(Message::TextEditingShortcut(*action));
+                        }
                         return Some(action.message());
                     }
+
+                    // Plain arrow and page keys move the selected date, unless a
+                    // focused widget (e.g. a text input) already handled them
+                    if modifiers.is_empty() && status == cosmic::iced::event::Status::Ignored {
+                        return crate::keyboard::date_step_for_key(&key).map(Message::MoveSelectedDate);
+                    }
                     None
                 }
                 // Handle window resize to sync sidebar with condensed state
@@ -529,20 +837,35 @@ impl Application for CosmicCalendar {
                 cosmic::iced::Event::Window(cosmic::iced::window::Event::Resized { .. }) => {
                     Some(Message::WindowResized)
                 }
+                // Pick up changes made while the window was in the background
+                cosmic::iced::Event::Window(cosmic::iced::window::Event::Focused) => {
+                    Some(Message::RefreshEvents)
+                }
                 // Track mouse position for drag preview
                 // Always emit cursor move events - the handler will check if drag is active
                 cosmic::iced::Event::Mouse(cosmic::iced::mouse::Event::CursorMoved { position }) => {
                     Some(Message::DragEventCursorMove(position.x, position.y))
                 }
+                // An event edge resize ends wherever the button is released
+                cosmic::iced::Event::Mouse(cosmic::iced::mouse::Event::ButtonReleased(cosmic::iced::mouse::Button::Left)) => {
+                    Some(Message::EventResizeEnd)
+                }
                 _ => None,
             }
         });
 
-        // Timer subscription for updating the current time indicator (every 30 seconds)
-        let timer_sub = cosmic::iced::time::every(std::time::Duration::from_secs(30))
-            .map(|_| Message::TimeTick);
+        // One tick per wall-clock minute for the current time indicator and alerts
+        let timer_sub = Subscription::run(crate::clock::minute_ticks);
+
+        // Poll for toast auto-dismiss only while a toast is visible
+        let toast_sub = if self.toast.is_visible() {
+            cosmic::iced::time::every(std::time::Duration::from_secs(1))
+                .map(|_| Message::ToastTick)
+        } else {
+            Subscription::none()
+        };
 
-        Subscription::batch([event_sub, timer_sub])
+        Subscription::batch([event_sub, timer_sub, toast_sub])
     }
 
     #[cfg(feature = "single-instance")]
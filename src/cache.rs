@@ -1,6 +1,42 @@
+use crate::caldav::CalendarEvent;
 use crate::models::CalendarState;
+use chrono::{DateTime, Utc, Weekday};
 use std::collections::HashMap;
 
+/// Number of fetched ranges kept before the event cache starts over
+const EVENT_CACHE_CAPACITY: usize = 64;
+
+/// Events fetched from calendar sources, keyed by (calendar_id, fetch range).
+/// Saves re-querying every source when views refresh without changes;
+/// cleared by `CalendarManager` whenever a source may have changed.
+#[derive(Debug, Default)]
+pub struct EventRangeCache {
+    entries: HashMap<(String, DateTime<Utc>, DateTime<Utc>), Vec<CalendarEvent>>,
+}
+
+impl EventRangeCache {
+    /// Events of a calendar fetched before for exactly this range
+    pub fn get(&self, calendar_id: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> Option<&[CalendarEvent]> {
+        self.entries
+            .get(&(calendar_id.to_string(), start, end))
+            .map(Vec::as_slice)
+    }
+
+    /// Store the events fetched for a calendar and range
+    pub fn insert(&mut self, calendar_id: &str, start: DateTime<Utc>, end: DateTime<Utc>, events: Vec<CalendarEvent>) {
+        // Navigating far around fills the cache; start over rather than track usage
+        if self.entries.len() >= EVENT_CACHE_CAPACITY {
+            self.entries.clear();
+        }
+        self.entries.insert((calendar_id.to_string(), start, end), events);
+    }
+
+    /// Forget all fetched events
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
 /// Manages all calendar caching including state, formatted text, and pre-cached months
 pub struct CalendarCache {
     /// Cache of calendar states by (year, month)
@@ -9,15 +45,18 @@ pub struct CalendarCache {
     period_texts: HashMap<(i32, u32), String>,
     /// Current active month
     current: (i32, u32),
+    /// Weekday the cached month grids start on
+    first_day: Weekday,
 }
 
 impl CalendarCache {
-    /// Create a new cache for the given year and month
-    pub fn new(year: i32, month: u32) -> Self {
+    /// Create a new cache for the given year and month, with grids starting on `first_day`
+    pub fn new(year: i32, month: u32, first_day: Weekday) -> Self {
         let mut cache = CalendarCache {
             states: HashMap::new(),
             period_texts: HashMap::new(),
             current: (year, month),
+            first_day,
         };
 
         // Cache the initial month
@@ -36,6 +75,15 @@ impl CalendarCache {
         }
     }
 
+    /// Change the weekday month grids start on, rebuilding the cached grids
+    pub fn set_first_day(&mut self, first_day: Weekday) {
+        if self.first_day != first_day {
+            self.first_day = first_day;
+            self.states.clear();
+            self.ensure_cached(self.current.0, self.current.1);
+        }
+    }
+
     /// Get the current calendar state
     pub fn current_state(&self) -> &CalendarState {
         self.states.get(&self.current)
@@ -67,7 +115,7 @@ impl CalendarCache {
 
         // Cache calendar state if not already present
         if !self.states.contains_key(&key) {
-            self.states.insert(key, CalendarState::new(year, month));
+            self.states.insert(key, CalendarState::new(year, month, self.first_day));
         }
 
         // Cache period text if not already present
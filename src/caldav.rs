@@ -1,4 +1,3 @@
-use icalendar::{Calendar, Component, Event, EventLike};
 use reqwest::blocking::Client;
 use serde::{Deserialize, Serialize};
 use std::error::Error;
@@ -16,6 +15,9 @@ pub enum RepeatFrequency {
     Custom(String), // For custom RRULE strings
 }
 
+/// Largest "every N units" interval offered in the event dialog
+pub const MAX_REPEAT_INTERVAL: u32 = 99;
+
 /// Alert timing before an event
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AlertTime {
@@ -30,15 +32,82 @@ pub enum AlertTime {
     OneDay,
     TwoDays,
     OneWeek,
+    /// At a time of day (minutes past midnight) on the day before the event
+    DayBeforeAt(u32),
     Custom(i32), // Custom minutes before
 }
 
+/// Time of day of the suggested all-day alert (9:00 the day before)
+pub const ALL_DAY_ALERT_MINUTE: u32 = 9 * 60;
+
 impl Default for AlertTime {
     fn default() -> Self {
         AlertTime::None
     }
 }
 
+impl AlertTime {
+    /// Minutes before the event start that the alert fires
+    /// (None = no alert, or an alert at a fixed time of day)
+    fn minutes_before(&self) -> Option<i64> {
+        match self {
+            AlertTime::None | AlertTime::DayBeforeAt(_) => None,
+            AlertTime::AtTime => Some(0),
+            AlertTime::FiveMinutes => Some(5),
+            AlertTime::TenMinutes => Some(10),
+            AlertTime::FifteenMinutes => Some(15),
+            AlertTime::ThirtyMinutes => Some(30),
+            AlertTime::OneHour => Some(60),
+            AlertTime::TwoHours => Some(120),
+            AlertTime::OneDay => Some(24 * 60),
+            AlertTime::TwoDays => Some(2 * 24 * 60),
+            AlertTime::OneWeek => Some(7 * 24 * 60),
+            AlertTime::Custom(minutes) => Some(*minutes as i64),
+        }
+    }
+
+    /// Minutes before the start that the alert fires, as written in an iCal
+    /// TRIGGER. An alert at a time of day the day before counts from midnight,
+    /// the start of the all-day events it is offered for.
+    pub fn trigger_minutes(&self) -> Option<i64> {
+        match self {
+            AlertTime::DayBeforeAt(minute) => Some(24 * 60 - *minute as i64),
+            other => other.minutes_before(),
+        }
+    }
+
+    /// Alert for an iCal TRIGGER `minutes` before the start, the inverse of
+    /// [`AlertTime::trigger_minutes`]
+    pub fn from_trigger_minutes(minutes: i64, all_day: bool) -> Self {
+        match minutes {
+            0 => AlertTime::AtTime,
+            5 => AlertTime::FiveMinutes,
+            10 => AlertTime::TenMinutes,
+            15 => AlertTime::FifteenMinutes,
+            30 => AlertTime::ThirtyMinutes,
+            60 => AlertTime::OneHour,
+            120 => AlertTime::TwoHours,
+            1440 => AlertTime::OneDay,
+            2880 => AlertTime::TwoDays,
+            10080 => AlertTime::OneWeek,
+            1..=1439 if all_day => AlertTime::DayBeforeAt((24 * 60 - minutes) as u32),
+            _ => AlertTime::Custom(minutes.clamp(i32::MIN as i64, i32::MAX as i64) as i32),
+        }
+    }
+
+    /// When the alert fires for an event starting at `start` (None = no alert).
+    /// All-day events start at midnight, so "9:00 the day before" is 15 hours ahead.
+    pub fn alert_time(&self, start: chrono::DateTime<chrono::Utc>) -> Option<chrono::DateTime<chrono::Utc>> {
+        match self {
+            AlertTime::DayBeforeAt(minute) => {
+                let day_before = start.date_naive().pred_opt()?;
+                Some(day_before.and_time(chrono::NaiveTime::MIN).and_utc() + chrono::Duration::minutes(*minute as i64))
+            }
+            other => other.minutes_before().map(|minutes| start - chrono::Duration::minutes(minutes)),
+        }
+    }
+}
+
 /// Travel time duration options
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub enum TravelTime {
@@ -55,6 +124,60 @@ pub enum TravelTime {
     Custom(i32), // Custom minutes
 }
 
+/// Period for copying an event forward ("repeat next week/month")
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepeatPeriod {
+    Week,
+    Month,
+}
+
+impl RepeatPeriod {
+    /// Shift a stored time forward by one period, keeping its wall-clock time
+    /// in `zone` (also across a DST change).
+    /// Month shifts clamp to the last day of shorter months (Jan 31 -> Feb 28).
+    pub fn shift(self, dt: chrono::DateTime<chrono::Utc>, zone: Option<&str>) -> chrono::DateTime<chrono::Utc> {
+        let wall_clock = crate::timezone::wall_clock(dt, zone);
+        let shifted = match self {
+            RepeatPeriod::Week => wall_clock + chrono::Duration::weeks(1),
+            RepeatPeriod::Month => wall_clock
+                .checked_add_months(chrono::Months::new(1))
+                .unwrap_or(wall_clock + chrono::Duration::days(30)),
+        };
+        crate::timezone::to_utc(shifted, zone)
+    }
+}
+
+/// Which occurrences of a recurring event an edit or delete applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecurrenceScope {
+    /// Only the chosen occurrence
+    ThisEvent,
+    /// The chosen occurrence and all later ones
+    ThisAndFollowing,
+    /// Every occurrence of the series
+    AllEvents,
+}
+
+/// Event status (RFC 5545 STATUS property)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum EventStatus {
+    #[default]
+    Confirmed,
+    Tentative,
+    Cancelled,
+}
+
+impl EventStatus {
+    /// Convert from an iCalendar STATUS value
+    pub fn from_ical(status: icalendar::EventStatus) -> Self {
+        match status {
+            icalendar::EventStatus::Confirmed => EventStatus::Confirmed,
+            icalendar::EventStatus::Tentative => EventStatus::Tentative,
+            icalendar::EventStatus::Cancelled => EventStatus::Cancelled,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CalendarEvent {
     /// Unique identifier for the event
@@ -75,6 +198,15 @@ pub struct CalendarEvent {
     pub repeat: RepeatFrequency,
     /// End date for recurring events (None means no end date)
     pub repeat_until: Option<chrono::NaiveDate>,
+    /// Number of occurrences for recurring events (iCal COUNT, None means unlimited)
+    #[serde(default)]
+    pub repeat_count: Option<u32>,
+    /// Repeat every N units of the frequency (iCal INTERVAL, 1 = every unit)
+    #[serde(default = "default_repeat_interval")]
+    pub repeat_interval: u32,
+    /// Weekdays a weekly series falls on (iCal BYDAY); empty means the start's weekday
+    #[serde(default)]
+    pub repeat_weekdays: Vec<chrono::Weekday>,
     /// Exception dates - dates where this recurring event should NOT appear
     /// Used when deleting a single occurrence of a recurring event
     pub exception_dates: Vec<chrono::NaiveDate>,
@@ -90,10 +222,77 @@ pub struct CalendarEvent {
     pub url: Option<String>,
     /// Notes/description
     pub notes: Option<String>,
+    /// Event status (confirmed, tentative, or cancelled)
+    #[serde(default)]
+    pub status: EventStatus,
+    /// IANA time zone name the event was defined in (from TZID), if any
+    #[serde(default)]
+    pub timezone: Option<String>,
+    /// Categories/tags (iCal CATEGORIES)
+    #[serde(default)]
+    pub categories: Vec<String>,
+    /// Per-event color override (hex), shown instead of the calendar color
+    #[serde(default)]
+    pub color: Option<String>,
+    /// Modified single occurrences of a recurring event (iCal RECURRENCE-ID overrides).
+    /// Each override's uid is the occurrence uid it replaces (`<uid>_YYYYMMDD`),
+    /// so a cancelled occurrence still renders instead of vanishing like an exception date.
+    #[serde(default)]
+    pub overrides: Vec<CalendarEvent>,
+}
+
+fn default_repeat_interval() -> u32 {
+    1
 }
 
-// CalDAV client for future use
-#[allow(dead_code)]
+#[cfg(test)]
+impl CalendarEvent {
+    /// A one-hour timed event with every optional field left empty, for tests
+    /// to fill in with struct update syntax
+    pub fn test_default() -> Self {
+        use chrono::TimeZone;
+
+        CalendarEvent {
+            uid: "test-event".to_string(),
+            summary: "Test Event".to_string(),
+            location: None,
+            all_day: false,
+            start: chrono::Utc.with_ymd_and_hms(2025, 1, 1, 10, 0, 0).unwrap(),
+            end: chrono::Utc.with_ymd_and_hms(2025, 1, 1, 11, 0, 0).unwrap(),
+            travel_time: TravelTime::None,
+            repeat: RepeatFrequency::Never,
+            repeat_until: None,
+            repeat_count: None,
+            repeat_interval: 1,
+            repeat_weekdays: vec![],
+            exception_dates: vec![],
+            invitees: vec![],
+            alert: AlertTime::None,
+            alert_second: None,
+            attachments: vec![],
+            url: None,
+            notes: None,
+            status: EventStatus::Confirmed,
+            timezone: None,
+            categories: vec![],
+            color: None,
+            overrides: vec![],
+        }
+    }
+}
+
+/// A calendar object resource on a CalDAV server
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemoteResource {
+    /// Resource location as returned by the server (usually a server-relative path)
+    pub href: String,
+    /// Entity tag of the stored version, sent back as If-Match when changing it
+    pub etag: Option<String>,
+    /// Events in the resource
+    pub events: Vec<CalendarEvent>,
+}
+
+/// CalDAV client for a single calendar collection (blocking, run off the UI thread)
 #[derive(Debug, Clone)]
 pub struct CalDavClient {
     server_url: String,
@@ -102,7 +301,6 @@ pub struct CalDavClient {
     client: Client,
 }
 
-#[allow(dead_code)]
 impl CalDavClient {
     pub fn new(server_url: String, username: String, password: String) -> Result<Self, Box<dyn Error>> {
         // Security: Enforce HTTPS-only connections
@@ -126,7 +324,8 @@ impl CalDavClient {
         })
     }
 
-    pub fn fetch_events(&self) -> Result<Vec<CalendarEvent>, Box<dyn Error>> {
+    /// Fetch every event resource of the calendar collection with its href and ETag
+    pub fn fetch_resources(&self) -> Result<Vec<RemoteResource>, Box<dyn Error>> {
         // CalDAV REPORT request to fetch calendar data
         let caldav_query = r#"<?xml version="1.0" encoding="utf-8" ?>
         <C:calendar-query xmlns:D="DAV:" xmlns:C="urn:ietf:params:xml:ns:caldav">
@@ -155,88 +354,181 @@ impl CalDavClient {
         }
 
         let body = response.text()?;
-        self.parse_calendar_data(&body)
-    }
-
-    fn parse_calendar_data(&self, data: &str) -> Result<Vec<CalendarEvent>, Box<dyn Error>> {
-        let events = Vec::new();
-
-        // Simple XML parsing to extract calendar data
-        // In a production app, you'd want to use a proper XML parser
-        for line in data.lines() {
-            if line.contains("BEGIN:VCALENDAR") {
-                // Extract calendar data between VCALENDAR tags
-                // This is a simplified approach
+        let mut resources = Vec::new();
+        for (href, etag, data) in parse_multistatus(&body)? {
+            // One malformed resource shouldn't hide the rest of the calendar
+            match crate::services::ExportHandler::parse_ical_string(&data) {
+                Ok(events) => resources.push(RemoteResource { href, etag, events }),
+                Err(e) => log::warn!("CalDavClient: Skipping invalid calendar data in {}: {}", href, e),
             }
         }
-
-        Ok(events)
+        Ok(resources)
     }
 
-    pub fn create_event(&self, event: &CalendarEvent) -> Result<(), Box<dyn Error>> {
-        let mut calendar = Calendar::new();
-
-        let mut ical_event = Event::new();
-        ical_event.summary(&event.summary);
+    /// Upload a new event. Fails instead of overwriting if the server already has
+    /// a resource at its URL. Returns the new resource's href and ETag.
+    pub fn create_event(&self, event: &CalendarEvent) -> Result<(String, Option<String>), Box<dyn Error>> {
+        let href = self.new_resource_path(&event.uid)?;
+        let request = self.client.put(self.resource_url(&href)?).header("If-None-Match", "*");
+        let etag = self.put_event(request, event)?;
+        Ok((href, etag))
+    }
 
-        if let Some(notes) = &event.notes {
-            ical_event.description(notes);
+    /// Upload a changed event to its resource. With an ETag the server rejects the
+    /// change if the event was modified there since it was fetched.
+    /// Returns the resource's new ETag.
+    pub fn update_event(&self, event: &CalendarEvent, href: &str, etag: Option<&str>) -> Result<Option<String>, Box<dyn Error>> {
+        let mut request = self.client.put(self.resource_url(href)?);
+        if let Some(etag) = etag {
+            request = request.header("If-Match", etag);
         }
+        self.put_event(request, event)
+    }
 
-        if let Some(loc) = &event.location {
-            ical_event.location(loc);
+    /// Delete an event resource, guarded by its ETag like `update_event`
+    pub fn delete_event(&self, href: &str, etag: Option<&str>) -> Result<(), Box<dyn Error>> {
+        let mut request = self
+            .client
+            .delete(self.resource_url(href)?)
+            .basic_auth(&self.username, Some(&self.password));
+        if let Some(etag) = etag {
+            request = request.header("If-Match", etag);
         }
 
-        if let Some(url) = &event.url {
-            ical_event.url(url);
+        let response = request.send()?;
+        // Already gone on the server is what we wanted
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(());
         }
+        check_write_status(response.status(), "delete")?;
+        Ok(())
+    }
 
-        ical_event.starts(event.start);
-        ical_event.ends(event.end);
-        ical_event.uid(&event.uid);
-
-        calendar.push(ical_event);
-
-        let ical_data = calendar.to_string();
-
-        // PUT request to create the event on the CalDAV server
-        let event_url = format!("{}/{}.ics", self.server_url, event.uid);
+    /// Send an event as a single-event iCalendar body and return the ETag from the response
+    fn put_event(&self, request: reqwest::blocking::RequestBuilder, event: &CalendarEvent) -> Result<Option<String>, Box<dyn Error>> {
+        let ical_data = crate::services::events_to_ics(std::slice::from_ref(event), chrono::Utc::now());
 
-        let response = self
-            .client
-            .put(&event_url)
+        let response = request
             .header("Content-Type", "text/calendar; charset=utf-8")
             .basic_auth(&self.username, Some(&self.password))
             .body(ical_data)
             .send()?;
 
-        if !response.status().is_success() {
-            return Err(format!("Failed to create event: {}", response.status()).into());
-        }
+        check_write_status(response.status(), "save")?;
 
-        Ok(())
+        // Servers may omit the ETag when they changed the data; the next sync fetches it
+        Ok(response
+            .headers()
+            .get(reqwest::header::ETAG)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string))
     }
 
-    pub fn update_event(&self, event: &CalendarEvent) -> Result<(), Box<dyn Error>> {
-        // Update is similar to create in CalDAV
-        self.create_event(event)
+    /// Server path for a new event resource, `<collection>/<uid>.ics`.
+    /// The UID is percent-encoded as a single path segment, since it may hold
+    /// characters like `/`, `?` or spaces.
+    fn new_resource_path(&self, uid: &str) -> Result<String, Box<dyn Error>> {
+        let mut url = url::Url::parse(&self.server_url)?;
+        url.path_segments_mut()
+            .map_err(|_| format!("CalDAV server URL can't hold event resources: {}", self.server_url))?
+            .pop_if_empty()
+            .push(&format!("{}.ics", uid));
+        Ok(url.path().to_string())
     }
 
-    pub fn delete_event(&self, uid: &str) -> Result<(), Box<dyn Error>> {
-        let event_url = format!("{}/{}.ics", self.server_url, uid);
+    /// Absolute URL for an href returned by the server
+    fn resource_url(&self, href: &str) -> Result<url::Url, Box<dyn Error>> {
+        Ok(url::Url::parse(&self.server_url)?.join(href)?)
+    }
+}
 
-        let response = self
-            .client
-            .delete(&event_url)
-            .basic_auth(&self.username, Some(&self.password))
-            .send()?;
+/// A write the server rejected because the event changed there since it was
+/// fetched (ETag mismatch), or already exists when creating it
+#[derive(Debug)]
+pub struct WriteConflict {
+    action: &'static str,
+}
 
-        if !response.status().is_success() {
-            return Err(format!("Failed to delete event: {}", response.status()).into());
-        }
+impl std::fmt::Display for WriteConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to {} event: it was changed on the server", self.action)
+    }
+}
 
-        Ok(())
+impl Error for WriteConflict {}
+
+/// Turn a failed PUT/DELETE status into an error; ETag conflicts are a [`WriteConflict`]
+fn check_write_status(status: reqwest::StatusCode, action: &'static str) -> Result<(), Box<dyn Error>> {
+    if status == reqwest::StatusCode::PRECONDITION_FAILED {
+        return Err(Box::new(WriteConflict { action }));
+    }
+    if !status.is_success() {
+        return Err(format!("Failed to {} event: {}", action, status).into());
+    }
+    Ok(())
+}
+
+/// One response of a WebDAV multistatus body: (href, ETag, calendar data)
+pub type MultistatusEntry = (String, Option<String>, String);
+
+/// Extract (href, ETag, calendar data) from each response of a WebDAV multistatus
+/// body. Responses without calendar data (e.g. the collection itself) are skipped.
+pub fn parse_multistatus(xml: &str) -> Result<Vec<MultistatusEntry>, Box<dyn Error>> {
+    use quick_xml::events::Event as XmlEvent;
+
+    #[derive(Clone, Copy, PartialEq)]
+    enum Field {
+        Href,
+        Etag,
+        Data,
     }
+
+    let mut reader = quick_xml::Reader::from_str(xml);
+    reader.config_mut().trim_text(true);
+
+    let mut resources = Vec::new();
+    let (mut href, mut etag, mut data) = (String::new(), String::new(), String::new());
+    let mut field: Option<Field> = None;
+
+    loop {
+        match reader.read_event()? {
+            XmlEvent::Start(element) => match element.local_name().as_ref() {
+                b"response" => {
+                    href.clear();
+                    etag.clear();
+                    data.clear();
+                }
+                b"href" => field = Some(Field::Href),
+                b"getetag" => field = Some(Field::Etag),
+                b"calendar-data" => field = Some(Field::Data),
+                _ => {}
+            },
+            XmlEvent::Text(text) => {
+                let text = text.unescape()?;
+                match field {
+                    Some(Field::Href) => href.push_str(&text),
+                    Some(Field::Etag) => etag.push_str(&text),
+                    Some(Field::Data) => data.push_str(&text),
+                    None => {}
+                }
+            }
+            XmlEvent::CData(cdata) if field == Some(Field::Data) => {
+                data.push_str(&String::from_utf8_lossy(&cdata.into_inner()));
+            }
+            XmlEvent::End(element) => match element.local_name().as_ref() {
+                b"response" if !href.is_empty() && !data.is_empty() => {
+                    let etag = (!etag.is_empty()).then(|| etag.clone());
+                    resources.push((href.clone(), etag, data.clone()));
+                }
+                b"href" | b"getetag" | b"calendar-data" => field = None,
+                _ => {}
+            },
+            XmlEvent::Eof => break,
+            _ => {}
+        }
+    }
+
+    Ok(resources)
 }
 
 #[cfg(test)]
@@ -255,22 +547,99 @@ mod tests {
             uid: "test-event-1".to_string(),
             summary: "Test Event".to_string(),
             location: Some("Test Location".to_string()),
-            all_day: false,
             start: chrono::Utc::now(),
             end: chrono::Utc::now() + chrono::Duration::hours(1),
-            travel_time: TravelTime::None,
-            repeat: RepeatFrequency::Never,
-            repeat_until: None,
-            exception_dates: vec![],
-            invitees: vec![],
             alert: AlertTime::FifteenMinutes,
-            alert_second: None,
-            attachments: vec![],
-            url: None,
             notes: Some("A test event".to_string()),
+            ..CalendarEvent::test_default()
         };
 
         // Note: This test would fail without a real CalDAV server
         // In production, you'd use mock servers or integration tests
     }
+
+    #[test]
+    fn test_parse_multistatus() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<d:multistatus xmlns:d="DAV:" xmlns:cal="urn:ietf:params:xml:ns:caldav">
+  <d:response>
+    <d:href>/calendars/user/work/</d:href>
+    <d:propstat><d:prop><d:getetag>"collection"</d:getetag></d:prop></d:propstat>
+  </d:response>
+  <d:response>
+    <d:href>/calendars/user/work/standup.ics</d:href>
+    <d:propstat>
+      <d:prop>
+        <d:getetag>"abc&amp;1"</d:getetag>
+        <cal:calendar-data>BEGIN:VCALENDAR
+END:VCALENDAR</cal:calendar-data>
+      </d:prop>
+    </d:propstat>
+  </d:response>
+  <d:response>
+    <d:href>/calendars/user/work/review.ics</d:href>
+    <d:propstat>
+      <d:prop><cal:calendar-data><![CDATA[BEGIN:VCALENDAR]]></cal:calendar-data></d:prop>
+    </d:propstat>
+  </d:response>
+</d:multistatus>"#;
+
+        let resources = parse_multistatus(xml).unwrap();
+        assert_eq!(
+            resources,
+            vec![
+                (
+                    "/calendars/user/work/standup.ics".to_string(),
+                    Some("\"abc&1\"".to_string()),
+                    "BEGIN:VCALENDAR\nEND:VCALENDAR".to_string(),
+                ),
+                (
+                    "/calendars/user/work/review.ics".to_string(),
+                    None,
+                    "BEGIN:VCALENDAR".to_string(),
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_new_resource_path_encodes_uid() {
+        let client = CalDavClient::new(
+            "https://example.com/dav/calendars/user/work/".to_string(),
+            "user".to_string(),
+            "pass".to_string(),
+        ).unwrap();
+
+        assert_eq!(
+            client.new_resource_path("plain-uid").unwrap(),
+            "/dav/calendars/user/work/plain-uid.ics"
+        );
+        assert_eq!(
+            client.new_resource_path("a/b c?d#e").unwrap(),
+            "/dav/calendars/user/work/a%2Fb%20c%3Fd%23e.ics"
+        );
+        assert_eq!(
+            client.resource_url("/dav/calendars/user/work/a%2Fb%20c%3Fd%23e.ics").unwrap().as_str(),
+            "https://example.com/dav/calendars/user/work/a%2Fb%20c%3Fd%23e.ics"
+        );
+    }
+
+    #[test]
+    fn test_alert_trigger_minutes_round_trip() {
+        let alerts = [
+            (AlertTime::AtTime, false),
+            (AlertTime::FifteenMinutes, false),
+            (AlertTime::OneWeek, false),
+            (AlertTime::Custom(45), false),
+            (AlertTime::DayBeforeAt(ALL_DAY_ALERT_MINUTE), true),
+        ];
+        for (alert, all_day) in alerts {
+            let minutes = alert.trigger_minutes().unwrap();
+            assert_eq!(AlertTime::from_trigger_minutes(minutes, all_day), alert);
+        }
+
+        assert_eq!(AlertTime::None.trigger_minutes(), None);
+        // Timed events have no "day before at" alert
+        assert_eq!(AlertTime::from_trigger_minutes(900, false), AlertTime::Custom(900));
+    }
 }
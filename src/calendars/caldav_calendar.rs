@@ -1,38 +1,40 @@
 //! CalDAV calendar implementation for remote calendar synchronization.
 //!
-//! NOTE: Prepared for future CalDAV integration - not yet connected to UI.
+//! Events are kept in the shared database as an offline copy, together with
+//! the server href and ETag of each event. Changes are stored locally right
+//! away and queued; the next background sync uploads them (guarded by the
+//! ETag) before fetching, so no network request runs on the UI thread.
 
-#![allow(dead_code)] // Prepared for future CalDAV sync feature
-
-use super::calendar_source::{CalendarInfo, CalendarSource, CalendarType};
+use super::calendar_source::{CalendarInfo, CalendarSource, CalendarType, RemoteAccount};
+use super::config::CalendarConfig;
 use crate::caldav::{CalDavClient, CalendarEvent};
+use crate::database::Database;
+use crate::services::CredentialStore;
 use std::error::Error;
+use std::sync::{Arc, Mutex};
 
 /// A CalDAV-based calendar (supports WebDAV, iCloud, Google, Nextcloud, etc.)
 #[derive(Debug)]
 pub struct CalDavCalendar {
     info: CalendarInfo,
     client: CalDavClient,
+    /// Shared database connection for the offline copy
+    db: Arc<Mutex<Database>>,
     cached_events: Vec<CalendarEvent>,
+    /// Local changes were queued since the last background sync started
+    unsent_changes: bool,
 }
 
 impl CalDavCalendar {
-    /// Create a new CalDAV calendar
+    /// Create a CalDAV calendar for an account, loading its offline copy from the database
     pub fn new(
         id: String,
         name: String,
-        server_url: String,
-        username: String,
+        account: RemoteAccount,
         password: String,
+        db: Arc<Mutex<Database>>,
     ) -> Result<Self, Box<dyn Error>> {
-        let info = CalendarInfo::new(id, name, CalendarType::CalDav);
-        let client = CalDavClient::new(server_url, username, password)?;
-
-        Ok(CalDavCalendar {
-            info,
-            client,
-            cached_events: Vec::new(),
-        })
+        Self::with_type(id, name, CalendarType::CalDav, account, password, db)
     }
 
     /// Create a CalDAV calendar with custom type (e.g., Google, iCloud)
@@ -40,58 +42,76 @@ impl CalDavCalendar {
         id: String,
         name: String,
         calendar_type: CalendarType,
-        server_url: String,
-        username: String,
+        account: RemoteAccount,
         password: String,
+        db: Arc<Mutex<Database>>,
     ) -> Result<Self, Box<dyn Error>> {
-        let mut info = CalendarInfo::new(id, name, calendar_type);
-        // Use the custom type's default color
-        info.color = match calendar_type {
-            CalendarType::Google => "#EA4335".to_string(),
-            CalendarType::ICloud => "#007AFF".to_string(),
-            CalendarType::Outlook => "#0078D4".to_string(),
-            _ => "#8B5CF6".to_string(),
-        };
+        let client = CalDavClient::new(account.server_url.clone(), account.username.clone(), password)?;
 
-        let client = CalDavClient::new(server_url, username, password)?;
+        let mut info = CalendarInfo::new(id, name, calendar_type);
+        info.account = Some(account);
 
-        Ok(CalDavCalendar {
+        let mut calendar = CalDavCalendar {
             info,
             client,
+            db,
             cached_events: Vec::new(),
-        })
+            unsent_changes: false,
+        };
+        calendar.load_events_from_db()?;
+        Ok(calendar)
+    }
+
+    /// Source factory for saved CalDAV calendars; reads the password from the keyring
+    pub fn from_config(
+        config: &CalendarConfig,
+        db: Arc<Mutex<Database>>,
+    ) -> Result<Box<dyn CalendarSource>, Box<dyn Error>> {
+        let account = config
+            .account
+            .clone()
+            .ok_or_else(|| format!("CalDAV calendar '{}' has no account", config.id))?;
+        let password = CredentialStore::load_password(&config.id)?;
+        Ok(Box::new(Self::new(config.id.clone(), config.name.clone(), account, password, db)?))
     }
 
     /// Create a Google Calendar instance (uses CalDAV protocol)
+    #[allow(dead_code)] // Preset for a future account type picker
     pub fn google(
         id: String,
         name: String,
         calendar_id: String,
         username: String,
         password: String,
+        db: Arc<Mutex<Database>>,
     ) -> Result<Self, Box<dyn Error>> {
         let server_url = format!(
             "https://apidata.googleusercontent.com/caldav/v2/{}/events",
             calendar_id
         );
-        Self::with_type(id, name, CalendarType::Google, server_url, username, password)
+        let account = RemoteAccount { server_url, username };
+        Self::with_type(id, name, CalendarType::Google, account, password, db)
     }
 
     /// Create an iCloud Calendar instance
+    #[allow(dead_code)] // Preset for a future account type picker
     pub fn icloud(
         id: String,
         name: String,
         username: String,
         password: String,
+        db: Arc<Mutex<Database>>,
     ) -> Result<Self, Box<dyn Error>> {
         let server_url = format!(
             "https://caldav.icloud.com/{}/calendars",
             username
         );
-        Self::with_type(id, name, CalendarType::ICloud, server_url, username, password)
+        let account = RemoteAccount { server_url, username };
+        Self::with_type(id, name, CalendarType::ICloud, account, password, db)
     }
 
     /// Create a Nextcloud Calendar instance
+    #[allow(dead_code)] // Preset for a future account type picker
     pub fn nextcloud(
         id: String,
         name: String,
@@ -99,6 +119,7 @@ impl CalDavCalendar {
         username: String,
         password: String,
         calendar_name: String,
+        db: Arc<Mutex<Database>>,
     ) -> Result<Self, Box<dyn Error>> {
         // Security: Validate HTTPS before constructing full URL
         if !server_url.starts_with("https://") {
@@ -114,12 +135,15 @@ impl CalDavCalendar {
             username,
             calendar_name
         );
-        Self::new(id, name, full_url, username, password)
+        let account = RemoteAccount { server_url: full_url, username };
+        Self::new(id, name, account, password, db)
     }
 
-    /// Get cached events without fetching from server
-    pub fn cached_events(&self) -> &[CalendarEvent] {
-        &self.cached_events
+    /// Load the offline copy of the events from the database into the cache
+    fn load_events_from_db(&mut self) -> Result<(), Box<dyn Error>> {
+        let db = self.db.lock().map_err(|_| "Database lock poisoned")?;
+        self.cached_events = db.get_events_for_calendar(&self.info.id)?;
+        Ok(())
     }
 }
 
@@ -133,39 +157,72 @@ impl CalendarSource for CalDavCalendar {
     }
 
     fn fetch_events(&self) -> Result<Vec<CalendarEvent>, Box<dyn Error>> {
-        // Return cached events to avoid network calls on every render
-        // Use sync() to refresh from server
+        // Return cached events to avoid network calls on every render.
+        // Server changes arrive through remote_client()/apply_remote_events()
         Ok(self.cached_events.clone())
     }
 
     fn add_event(&mut self, event: CalendarEvent) -> Result<(), Box<dyn Error>> {
-        self.client.create_event(&event)?;
+        let db = self.db.lock().map_err(|_| "Database lock poisoned")?;
+        db.insert_event(&self.info.id, &event)?;
+        db.queue_upload(&self.info.id, &event.uid, false)?;
+        drop(db);
+
         self.cached_events.push(event);
+        self.unsent_changes = true;
         Ok(())
     }
 
     fn update_event(&mut self, event: CalendarEvent) -> Result<(), Box<dyn Error>> {
-        self.client.update_event(&event)?;
+        if !self.cached_events.iter().any(|e| e.uid == event.uid) {
+            return Err(format!("Event {} is not in calendar '{}'", event.uid, self.info.id).into());
+        }
+
+        let db = self.db.lock().map_err(|_| "Database lock poisoned")?;
+        db.update_event(&self.info.id, &event)?;
+        db.queue_upload(&self.info.id, &event.uid, false)?;
+        drop(db);
 
-        // Update in cache
         if let Some(existing) = self.cached_events.iter_mut().find(|e| e.uid == event.uid) {
             *existing = event;
         }
-
+        self.unsent_changes = true;
         Ok(())
     }
 
     fn delete_event(&mut self, uid: &str) -> Result<(), Box<dyn Error>> {
-        self.client.delete_event(uid)?;
+        // Callers try every calendar in turn - don't queue deletions of other calendars' events
+        if !self.cached_events.iter().any(|e| e.uid == uid) {
+            return Err(format!("Event {} is not in calendar '{}'", uid, self.info.id).into());
+        }
+
+        // The server resource stays recorded until the deletion is uploaded
+        let db = self.db.lock().map_err(|_| "Database lock poisoned")?;
+        db.delete_event(&self.info.id, uid)?;
+        db.queue_upload(&self.info.id, uid, true)?;
+        drop(db);
+
         self.cached_events.retain(|e| e.uid != uid);
+        self.unsent_changes = true;
         Ok(())
     }
 
     fn sync(&mut self) -> Result<(), Box<dyn Error>> {
-        // Fetch fresh events from CalDAV server
-        let events = self.client.fetch_events()?;
+        // Refresh the cache from the offline copy. Fetching from the server is
+        // blocking and happens off the UI thread through remote_client()
+        self.load_events_from_db()
+    }
+
+    fn remote_client(&self) -> Option<CalDavClient> {
+        Some(self.client.clone())
+    }
+
+    fn apply_remote_events(&mut self, events: Vec<CalendarEvent>) {
         self.cached_events = events;
-        Ok(())
+    }
+
+    fn take_unsent_changes(&mut self) -> bool {
+        std::mem::take(&mut self.unsent_changes)
     }
 
     fn supports_read(&self) -> bool {
@@ -176,3 +233,48 @@ impl CalendarSource for CalDavCalendar {
         true
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_changes_are_queued_without_network() {
+        let db_path = std::env::temp_dir().join("sol_test_caldav_queue.db");
+        let _ = std::fs::remove_file(&db_path);
+        let db = Arc::new(Mutex::new(Database::open_at(db_path.clone()).unwrap()));
+        let account = RemoteAccount {
+            server_url: "https://caldav.invalid/calendars/user/work/".to_string(),
+            username: "user".to_string(),
+        };
+        let mut calendar = CalDavCalendar::new(
+            "remote".to_string(),
+            "Remote".to_string(),
+            account,
+            "pass".to_string(),
+            Arc::clone(&db),
+        )
+        .unwrap();
+
+        // The server doesn't exist: changes only touch the offline copy
+        calendar.add_event(CalendarEvent::test_default()).unwrap();
+        let renamed = CalendarEvent {
+            summary: "Renamed".to_string(),
+            ..CalendarEvent::test_default()
+        };
+        calendar.update_event(renamed).unwrap();
+        assert_eq!(calendar.fetch_events().unwrap()[0].summary, "Renamed");
+        assert!(calendar.take_unsent_changes());
+        assert!(!calendar.take_unsent_changes());
+
+        calendar.delete_event("test-event").unwrap();
+        assert!(calendar.fetch_events().unwrap().is_empty());
+        assert!(calendar.take_unsent_changes());
+        assert_eq!(
+            db.lock().unwrap().get_pending_uploads("remote").unwrap(),
+            vec![("test-event".to_string(), true, 3)]
+        );
+
+        let _ = std::fs::remove_file(&db_path);
+    }
+}
@@ -1,4 +1,5 @@
-use crate::caldav::CalendarEvent;
+use crate::caldav::{CalDavClient, CalendarEvent, RepeatFrequency};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::error::Error;
 use std::fmt::Debug;
@@ -28,6 +29,15 @@ impl CalendarType {
     }
 }
 
+/// Server and login of a remote calendar. The password is kept in the
+/// system keyring, never in the config file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemoteAccount {
+    /// URL of the calendar collection
+    pub server_url: String,
+    pub username: String,
+}
+
 /// Metadata about a calendar source
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CalendarInfo {
@@ -43,6 +53,29 @@ pub struct CalendarInfo {
     pub description: Option<String>,
     /// Whether the calendar is currently enabled/visible
     pub enabled: bool,
+    /// Default length of new events in minutes (None = global default)
+    pub default_duration_minutes: Option<u32>,
+    /// Whether new events in this calendar default to all-day
+    pub default_all_day: bool,
+    /// Whether the calendar is pinned to the top of the sidebar list
+    #[serde(default)]
+    pub pinned: bool,
+    /// Whether alerts of this calendar's events are muted
+    #[serde(default)]
+    pub mute_alerts: bool,
+    /// Position in the calendar list, kept equal to the index in `CalendarManager::sources()`
+    #[serde(default)]
+    pub order: u32,
+    /// Server account of a remote calendar (None for local calendars)
+    #[serde(default)]
+    pub account: Option<RemoteAccount>,
+    /// Type name the calendar was saved under, which picks its source factory
+    /// (None = derived from `calendar_type`)
+    #[serde(default)]
+    pub config_type: Option<String>,
+    /// Whether a background sync is currently running (runtime state, not persisted)
+    #[serde(skip)]
+    pub loading: bool,
 }
 
 impl CalendarInfo {
@@ -54,6 +87,14 @@ impl CalendarInfo {
             color: Self::default_color_for_type(calendar_type),
             description: None,
             enabled: true,
+            default_duration_minutes: None,
+            default_all_day: false,
+            pinned: false,
+            mute_alerts: false,
+            order: 0,
+            account: None,
+            config_type: None,
+            loading: false,
         }
     }
 
@@ -70,6 +111,18 @@ impl CalendarInfo {
 }
 
 /// Trait that all calendar sources must implement
+///
+/// Contract for implementors (built-in or registered through
+/// `CalendarManager::register_source_factory`):
+/// - `fetch_events` is called on the UI thread for every view refresh, so it must
+///   return cached events and never block on the network. Refresh the cache in
+///   `sync`, or off the UI thread through `remote_client`/`apply_remote_events`.
+///   The mutating methods run on the UI thread too; remote sources store the
+///   change locally and report it through `take_unsent_changes`.
+/// - Event UIDs must be unique within the source; the manager identifies events
+///   by `(calendar id, uid)` and derives occurrence UIDs as `<uid>_YYYYMMDD`.
+/// - Read-only sources return `false` from `supports_write` and an error from
+///   the mutating methods.
 pub trait CalendarSource: Debug + Send {
     /// Get metadata about this calendar
     fn info(&self) -> &CalendarInfo;
@@ -90,6 +143,17 @@ pub trait CalendarSource: Debug + Send {
     /// Fetch all events from this calendar source
     fn fetch_events(&self) -> Result<Vec<CalendarEvent>, Box<dyn Error>>;
 
+    /// Fetch the events overlapping `[start, end)` plus all recurring series,
+    /// whose occurrences the caller expands. Sources backed by the database
+    /// override this with an indexed query instead of filtering every event.
+    fn fetch_events_in_range(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Vec<CalendarEvent>, Box<dyn Error>> {
+        Ok(self
+            .fetch_events()?
+            .into_iter()
+            .filter(|e| e.repeat != RepeatFrequency::Never || (e.start < end && e.end >= start))
+            .collect())
+    }
+
     /// Add a new event to this calendar
     fn add_event(&mut self, event: CalendarEvent) -> Result<(), Box<dyn Error>>;
 
@@ -104,6 +168,20 @@ pub trait CalendarSource: Debug + Send {
     /// For local calendars, this might just save to disk
     fn sync(&mut self) -> Result<(), Box<dyn Error>>;
 
+    /// Client for fetching this calendar off the UI thread (remote calendars only)
+    fn remote_client(&self) -> Option<CalDavClient> {
+        None
+    }
+
+    /// Replace the cached events with events fetched through `remote_client`
+    fn apply_remote_events(&mut self, _events: Vec<CalendarEvent>) {}
+
+    /// Whether local changes were queued for upload since the last call.
+    /// Remote calendars upload them in their next background sync.
+    fn take_unsent_changes(&mut self) -> bool {
+        false
+    }
+
     /// Check if this calendar supports read operations
     #[allow(dead_code)] // Part of trait API for future use
     fn supports_read(&self) -> bool {
@@ -1,4 +1,7 @@
+use super::calendar_source::RemoteAccount;
+use log::warn;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::fs;
 use std::io;
 use std::path::PathBuf;
@@ -11,6 +14,24 @@ pub struct CalendarConfig {
     pub color: String,
     pub enabled: bool,
     pub calendar_type: String,
+    /// Default length of new events in this calendar (None = global default)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_duration_minutes: Option<u32>,
+    /// Whether new events in this calendar start out as all-day (birthdays, holidays)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub default_all_day: bool,
+    /// Whether the calendar is pinned to the top of the sidebar list
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub pinned: bool,
+    /// Whether events in this calendar never fire alerts (e.g. a noisy shared calendar)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub mute_alerts: bool,
+    /// Position in the sidebar list (configs saved before reordering existed keep file order)
+    #[serde(default)]
+    pub order: u32,
+    /// Server account of a remote calendar (the password lives in the keyring)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account: Option<RemoteAccount>,
 }
 
 /// Manager configuration that stores all calendar settings
@@ -29,11 +50,63 @@ impl CalendarManagerConfig {
         }
 
         let contents = fs::read_to_string(&path)?;
-        let config: CalendarManagerConfig = serde_json::from_str(&contents)
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let (config, renamed) = Self::parse(&contents)?;
+
+        // Persist renamed IDs so the disambiguation is stable across restarts
+        if renamed > 0 {
+            if let Err(e) = config.save() {
+                warn!("CalendarManagerConfig: Failed to save disambiguated config: {}", e);
+            }
+        }
+
         Ok(config)
     }
 
+    /// Parse configuration JSON, renaming duplicate calendar IDs and sorting
+    /// calendars by their saved order.
+    /// Returns the config and the number of calendars that were renamed.
+    fn parse(contents: &str) -> Result<(Self, usize), io::Error> {
+        let mut config: CalendarManagerConfig = serde_json::from_str(contents)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let renamed = config.disambiguate_ids();
+        config.sort_by_order();
+        Ok((config, renamed))
+    }
+
+    /// Sort calendars by their `order`, keeping file order for equal values
+    pub fn sort_by_order(&mut self) {
+        self.calendars.sort_by_key(|c| c.order);
+    }
+
+    /// Rename calendars whose ID duplicates an earlier calendar's ID.
+    /// Events are keyed on calendar ID, so duplicates would share (and leak) events.
+    /// The first calendar keeps its ID; later ones get a numeric suffix ("work-2").
+    /// Returns the number of calendars that were renamed.
+    fn disambiguate_ids(&mut self) -> usize {
+        let mut seen: HashSet<String> = HashSet::new();
+        let mut renamed = 0;
+
+        for index in 0..self.calendars.len() {
+            let id = self.calendars[index].id.clone();
+            if seen.insert(id.clone()) {
+                continue;
+            }
+
+            // Find the first free suffix, checking calendars that come later as well
+            let new_id = (2..)
+                .map(|n| format!("{}-{}", id, n))
+                .find(|candidate| !seen.contains(candidate) && !self.calendars.iter().any(|c| &c.id == candidate))
+                .unwrap_or_else(|| id.clone());
+
+            warn!("CalendarManagerConfig: Duplicate calendar ID '{}', renaming to '{}'", id, new_id);
+            self.calendars[index].id = new_id.clone();
+            seen.insert(new_id);
+            renamed += 1;
+        }
+
+        renamed
+    }
+
     /// Save configuration to disk
     pub fn save(&self) -> Result<(), io::Error> {
         let path = Self::config_path();
@@ -81,3 +154,92 @@ impl CalendarManagerConfig {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duplicate_ids_are_disambiguated_on_load() {
+        let json = r##"{
+            "calendars": [
+                {"id": "work", "name": "Work", "color": "#8B5CF6", "enabled": true, "calendar_type": "local"},
+                {"id": "work", "name": "Imported", "color": "#3B82F6", "enabled": true, "calendar_type": "local"},
+                {"id": "work-2", "name": "Work 2", "color": "#EA4335", "enabled": true, "calendar_type": "local"},
+                {"id": "work", "name": "Imported again", "color": "#0078D4", "enabled": false, "calendar_type": "local"}
+            ]
+        }"##;
+
+        let (config, renamed) = CalendarManagerConfig::parse(json).unwrap();
+        let ids: Vec<&str> = config.calendars.iter().map(|c| c.id.as_str()).collect();
+
+        // The first "work" keeps its ID; later ones skip the existing "work-2"
+        assert_eq!(renamed, 2);
+        assert_eq!(ids, vec!["work", "work-3", "work-2", "work-4"]);
+        assert_eq!(config.calendars[1].name, "Imported");
+    }
+
+    #[test]
+    fn test_default_duration_is_optional() {
+        let json = r##"{
+            "calendars": [
+                {"id": "work", "name": "Work", "color": "#8B5CF6", "enabled": true, "calendar_type": "local", "default_duration_minutes": 30},
+                {"id": "personal", "name": "Personal", "color": "#3B82F6", "enabled": true, "calendar_type": "local"}
+            ]
+        }"##;
+
+        let (config, _) = CalendarManagerConfig::parse(json).unwrap();
+        assert_eq!(config.get_calendar("work").unwrap().default_duration_minutes, Some(30));
+        assert_eq!(config.get_calendar("personal").unwrap().default_duration_minutes, None);
+    }
+
+    #[test]
+    fn test_default_all_day_is_optional() {
+        let json = r##"{
+            "calendars": [
+                {"id": "birthdays", "name": "Birthdays", "color": "#F59E0B", "enabled": true, "calendar_type": "local", "default_all_day": true},
+                {"id": "work", "name": "Work", "color": "#8B5CF6", "enabled": true, "calendar_type": "local"}
+            ]
+        }"##;
+
+        let (config, _) = CalendarManagerConfig::parse(json).unwrap();
+        assert!(config.get_calendar("birthdays").unwrap().default_all_day);
+        assert!(!config.get_calendar("work").unwrap().default_all_day);
+    }
+
+    #[test]
+    fn test_calendars_load_in_saved_order() {
+        let json = r##"{
+            "calendars": [
+                {"id": "work", "name": "Work", "color": "#8B5CF6", "enabled": true, "calendar_type": "local", "order": 2},
+                {"id": "personal", "name": "Personal", "color": "#3B82F6", "enabled": true, "calendar_type": "local", "order": 0},
+                {"id": "holidays", "name": "Holidays", "color": "#F59E0B", "enabled": true, "calendar_type": "local", "order": 1}
+            ]
+        }"##;
+
+        let (config, _) = CalendarManagerConfig::parse(json).unwrap();
+        let ids: Vec<&str> = config.calendars.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, vec!["personal", "holidays", "work"]);
+    }
+
+    #[test]
+    fn test_account_is_optional_and_has_no_password() {
+        let json = r##"{
+            "calendars": [
+                {"id": "team", "name": "Team", "color": "#8B5CF6", "enabled": true, "calendar_type": "CalDav",
+                 "account": {"server_url": "https://dav.example.com/cal/team/", "username": "alex"}},
+                {"id": "work", "name": "Work", "color": "#8B5CF6", "enabled": true, "calendar_type": "local"}
+            ]
+        }"##;
+
+        let (config, _) = CalendarManagerConfig::parse(json).unwrap();
+        let account = config.get_calendar("team").unwrap().account.clone().unwrap();
+        assert_eq!(account.server_url, "https://dav.example.com/cal/team/");
+        assert_eq!(account.username, "alex");
+        assert!(config.get_calendar("work").unwrap().account.is_none());
+
+        let saved = serde_json::to_string(&config).unwrap();
+        assert!(!saved.contains("password"));
+        assert_eq!(saved.matches("\"account\"").count(), 1);
+    }
+}
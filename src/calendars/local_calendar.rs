@@ -2,6 +2,7 @@ use super::calendar_source::{CalendarInfo, CalendarSource, CalendarType};
 use super::config::CalendarManagerConfig;
 use crate::caldav::CalendarEvent;
 use crate::database::Database;
+use chrono::{DateTime, Utc};
 use std::error::Error;
 use std::sync::{Arc, Mutex};
 
@@ -108,6 +109,11 @@ impl CalendarSource for LocalCalendar {
         Ok(self.cached_events.clone())
     }
 
+    fn fetch_events_in_range(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Vec<CalendarEvent>, Box<dyn Error>> {
+        let db = self.db.lock().map_err(|_| "Database lock poisoned")?;
+        db.get_events_in_range(&self.info.id, start, end)
+    }
+
     fn add_event(&mut self, event: CalendarEvent) -> Result<(), Box<dyn Error>> {
         if let Ok(db) = self.db.lock() {
             db.insert_event(&self.info.id, &event)?;
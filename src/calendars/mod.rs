@@ -3,25 +3,44 @@ mod caldav_calendar;
 mod config;
 mod local_calendar;
 
-pub use calendar_source::{CalendarSource, CalendarType};
+pub use calendar_source::{CalendarInfo, CalendarSource, CalendarType, RemoteAccount};
+pub use caldav_calendar::CalDavCalendar;
 pub use config::{CalendarConfig, CalendarManagerConfig};
 pub use local_calendar::LocalCalendar;
 
+use crate::cache::EventRangeCache;
 use crate::caldav::{CalendarEvent, RepeatFrequency};
 use crate::components::DisplayEvent;
 use crate::database::Database;
-use chrono::{Datelike, Timelike, NaiveDate, Duration, Months};
-use log::{debug, info};
+use crate::fl;
+use crate::timezone;
+use chrono::{Datelike, Days, NaiveDate, NaiveDateTime, NaiveTime, Duration, Months};
+use log::{debug, info, warn};
 use std::collections::HashMap;
 use std::error::Error;
 use std::sync::{Arc, Mutex};
 
+/// How far ahead `next_event_date_after` searches
+const NEXT_EVENT_SEARCH_DAYS: i64 = 366;
+
+/// Maximum number of events returned by `search_events`
+const SEARCH_RESULT_LIMIT: usize = 50;
+
+/// Creates a calendar source from its saved configuration.
+/// Receives the shared database so sources can keep an offline copy of their events.
+pub type SourceFactory =
+    fn(&CalendarConfig, Arc<Mutex<Database>>) -> Result<Box<dyn CalendarSource>, Box<dyn Error>>;
+
 /// Manager for all calendar sources
 #[derive(Debug)]
 pub struct CalendarManager {
     sources: Vec<Box<dyn CalendarSource>>,
     /// Shared database connection
     db: Arc<Mutex<Database>>,
+    /// Factories for custom calendar types, keyed by the saved `calendar_type`
+    factories: HashMap<String, SourceFactory>,
+    /// Events fetched for the month/week views, cleared when sources change
+    event_cache: EventRangeCache,
 }
 
 impl CalendarManager {
@@ -31,11 +50,19 @@ impl CalendarManager {
 
         // Open or create the database
         let db = Database::open().expect("Failed to open database");
-        let db = Arc::new(Mutex::new(db));
+        Self::with_database(Arc::new(Mutex::new(db)))
+    }
+
+    /// Create an empty CalendarManager using an existing database connection
+    pub fn with_database(db: Arc<Mutex<Database>>) -> Self {
+        let mut factories: HashMap<String, SourceFactory> = HashMap::new();
+        factories.insert(format!("{:?}", CalendarType::CalDav), CalDavCalendar::from_config);
 
         CalendarManager {
             sources: Vec::new(),
             db,
+            factories,
+            event_cache: EventRangeCache::default(),
         }
     }
 
@@ -44,49 +71,54 @@ impl CalendarManager {
     pub fn with_defaults() -> Self {
         info!("CalendarManager: Loading with defaults");
         let mut manager = Self::new();
-        let db = manager.db.clone();
+        manager.load_config();
+        manager
+    }
 
-        // Try to load calendars from config
+    /// Load the saved calendars, or create the default ones if none are saved.
+    /// Builds that add custom backends register their factories first, so those
+    /// calendars are built by them:
+    /// `CalendarManager::new()`, then `register_source_factory`, then `load_config`.
+    pub fn load_config(&mut self) {
         let config = CalendarManagerConfig::load().unwrap_or_default();
 
         if config.calendars.is_empty() {
             info!("CalendarManager: No saved calendars, creating defaults");
-            // No saved calendars, create defaults
-            manager.add_source(Box::new(LocalCalendar::with_color(
+            // No saved calendars, create defaults (add_source numbers them Personal, then Work)
+            self.add_source(Box::new(LocalCalendar::with_color(
                 "personal".to_string(),
                 "Personal".to_string(),
                 "#3B82F6".to_string(),
-                db.clone(),
+                self.db.clone(),
             )));
 
-            manager.add_source(Box::new(LocalCalendar::with_color(
+            self.add_source(Box::new(LocalCalendar::with_color(
                 "work".to_string(),
                 "Work".to_string(),
                 "#8B5CF6".to_string(),
-                db,
+                self.db.clone(),
             )));
 
             // Save the defaults
-            manager.save_config().ok();
+            self.save_config().ok();
         } else {
-            info!("CalendarManager: Loading {} calendars from config", config.calendars.len());
-            // Load calendars from config
-            for cal_config in &config.calendars {
-                debug!("CalendarManager: Loading calendar '{}' ({})", cal_config.name, cal_config.id);
-                let mut calendar = LocalCalendar::new(
-                    cal_config.id.clone(),
-                    cal_config.name.clone(),
-                    db.clone(),
-                );
-                // Apply saved settings
-                calendar.info_mut().color = cal_config.color.clone();
-                calendar.info_mut().enabled = cal_config.enabled;
-                manager.add_source(Box::new(calendar));
-            }
+            self.load_calendars(&config);
         }
 
-        info!("CalendarManager: Initialized with {} calendars", manager.sources.len());
-        manager
+        info!("CalendarManager: Initialized with {} calendars", self.sources.len());
+    }
+
+    /// Add a source for each saved calendar, pinned calendars first
+    fn load_calendars(&mut self, config: &CalendarManagerConfig) {
+        info!("CalendarManager: Loading {} calendars from config", config.calendars.len());
+        for cal_config in &config.calendars {
+            debug!("CalendarManager: Loading calendar '{}' ({})", cal_config.name, cal_config.id);
+            match self.source_from_config(cal_config) {
+                Ok(calendar) => self.add_source(calendar),
+                Err(e) => warn!("CalendarManager: Failed to load calendar '{}': {}", cal_config.id, e),
+            }
+        }
+        self.sort_pinned_first();
     }
 
     /// Add a new local calendar
@@ -112,8 +144,10 @@ impl CalendarManager {
         }
 
         // Remove from sources
+        self.event_cache.clear();
         if let Some(index) = self.sources.iter().position(|s| s.info().id == id) {
             self.sources.remove(index);
+            self.renumber_sources();
 
             // Update config file
             if let Ok(mut config) = CalendarManagerConfig::load() {
@@ -126,22 +160,164 @@ impl CalendarManager {
         false
     }
 
+    /// Duplicate a calendar as a new local calendar with its events and save configuration.
+    /// Returns the new calendar's ID.
+    pub fn duplicate_calendar(&mut self, id: &str) -> Result<String, Box<dyn Error>> {
+        let new_id = self.copy_calendar(id)?;
+        self.save_config()?;
+        Ok(new_id)
+    }
+
+    /// Copy a calendar below the original without saving configuration.
+    /// The copy keeps the color and new-event defaults and is named "<name> (copy)";
+    /// its events get fresh UIDs. Copies of remote calendars are local.
+    fn copy_calendar(&mut self, id: &str) -> Result<String, Box<dyn Error>> {
+        let Some(index) = self.sources.iter().position(|s| s.info().id == id) else {
+            return Err(format!("Calendar not found: {}", id).into());
+        };
+        let info = self.sources[index].info().clone();
+        let events = self.sources[index].fetch_events()?;
+
+        let mut new_id = format!("{}-copy", id);
+        let mut counter = 1;
+        while self.sources.iter().any(|s| s.info().id == new_id) {
+            counter += 1;
+            new_id = format!("{}-copy-{}", id, counter);
+        }
+
+        let name = format!("{} {}", info.name, fl!("calendar-copy-suffix"));
+        let mut copy = LocalCalendar::new(new_id.clone(), name.clone(), self.db.clone());
+        let copy_info = copy.info_mut();
+        copy_info.name = name;
+        copy_info.color = info.color;
+        copy_info.description = info.description;
+        copy_info.default_duration_minutes = info.default_duration_minutes;
+        copy_info.default_all_day = info.default_all_day;
+        copy_info.mute_alerts = info.mute_alerts;
+
+        let count = events.len();
+        for event in events {
+            copy.add_event(Self::event_with_fresh_uid(event))?;
+        }
+
+        self.add_source(Box::new(copy));
+        self.move_calendar(&new_id, index + 1);
+        info!("CalendarManager: Copied calendar '{}' to '{}' with {} events", id, new_id, count);
+        Ok(new_id)
+    }
+
+    /// An event under a new UID, with its occurrence overrides (`<uid>_YYYYMMDD`) following it
+    pub fn event_with_fresh_uid(mut event: CalendarEvent) -> CalendarEvent {
+        let uid = uuid::Uuid::new_v4().to_string();
+        for occurrence in &mut event.overrides {
+            if let Some(date_suffix) = occurrence.uid.strip_prefix(event.uid.as_str()) {
+                occurrence.uid = format!("{}{}", uid, date_suffix);
+            }
+        }
+        event.uid = uid;
+        event
+    }
+
     /// Get the shared database connection
-    #[allow(dead_code)] // Reserved for future database operations
     pub fn database(&self) -> Arc<Mutex<Database>> {
         self.db.clone()
     }
 
-    /// Add a calendar source to the manager
-    pub fn add_source(&mut self, source: Box<dyn CalendarSource>) {
+    /// Register a factory for calendars whose saved `calendar_type` matches,
+    /// so custom backends (e.g. an ICS-over-HTTP feed) can be added without
+    /// changing the manager. Replaces a factory registered for the same type.
+    #[allow(dead_code)] // Extension point for downstream builds; the app only uses built-in types
+    pub fn register_source_factory(&mut self, calendar_type: &str, factory: SourceFactory) {
+        info!("CalendarManager: Registering source factory for '{}'", calendar_type);
+        self.factories.insert(calendar_type.to_string(), factory);
+    }
+
+    /// Create a calendar source from its saved configuration, using the factory
+    /// registered for its type and falling back to a local calendar.
+    /// The saved color, visibility and defaults are applied to the new source.
+    pub fn source_from_config(&self, config: &CalendarConfig) -> Result<Box<dyn CalendarSource>, Box<dyn Error>> {
+        let mut source: Box<dyn CalendarSource> = match self.factories.get(&config.calendar_type) {
+            Some(factory) => factory(config, self.db.clone())?,
+            None => Box::new(LocalCalendar::new(config.id.clone(), config.name.clone(), self.db.clone())),
+        };
+
+        let info = source.info_mut();
+        info.color = config.color.clone();
+        info.enabled = config.enabled;
+        info.default_duration_minutes = config.default_duration_minutes;
+        info.default_all_day = config.default_all_day;
+        info.pinned = config.pinned;
+        info.mute_alerts = config.mute_alerts;
+        info.order = config.order;
+        info.account = config.account.clone();
+        info.config_type = Some(config.calendar_type.clone());
+        Ok(source)
+    }
+
+    /// Pin or unpin a calendar, moving pinned calendars to the top of the list.
+    /// Returns false if no calendar has this ID.
+    pub fn set_calendar_pinned(&mut self, id: &str, pinned: bool) -> bool {
+        let Some(source) = self.sources.iter_mut().find(|s| s.info().id == id) else {
+            return false;
+        };
+        source.info_mut().pinned = pinned;
+        self.sort_pinned_first();
+        true
+    }
+
+    /// Order sources with pinned calendars first, keeping the saved order within each group
+    fn sort_pinned_first(&mut self) {
+        self.sources.sort_by_key(|s| !s.info().pinned);
+        self.renumber_sources();
+    }
+
+    /// Set each source's `order` to its position in the list
+    fn renumber_sources(&mut self) {
+        for (index, source) in self.sources.iter_mut().enumerate() {
+            source.info_mut().order = index as u32;
+        }
+    }
+
+    /// Move a calendar to `new_index` in the list and save the new order.
+    /// Pinned calendars stay above unpinned ones, so a move across that
+    /// boundary stops at the edge of its group. Returns false if no calendar has this ID.
+    pub fn reorder_calendar(&mut self, id: &str, new_index: usize) -> bool {
+        if !self.move_calendar(id, new_index) {
+            return false;
+        }
+        if let Err(e) = self.save_config() {
+            warn!("CalendarManager: Failed to save calendar order: {}", e);
+        }
+        true
+    }
+
+    /// Move a calendar to `new_index` (clamped to the list) without saving
+    fn move_calendar(&mut self, id: &str, new_index: usize) -> bool {
+        let Some(index) = self.sources.iter().position(|s| s.info().id == id) else {
+            return false;
+        };
+        let source = self.sources.remove(index);
+        let new_index = new_index.min(self.sources.len());
+        debug!("CalendarManager: Moving calendar '{}' from {} to {}", id, index, new_index);
+        self.sources.insert(new_index, source);
+        self.sort_pinned_first();
+        true
+    }
+
+    /// Add a calendar source to the end of the list
+    pub fn add_source(&mut self, mut source: Box<dyn CalendarSource>) {
+        self.event_cache.clear();
+        source.info_mut().order = self.sources.len() as u32;
         self.sources.push(source);
     }
 
     /// Remove a calendar source by ID
     #[allow(dead_code)] // Reserved for future calendar removal
     pub fn remove_source(&mut self, id: &str) -> bool {
+        self.event_cache.clear();
         if let Some(index) = self.sources.iter().position(|s| s.info().id == id) {
             self.sources.remove(index);
+            self.renumber_sources();
             true
         } else {
             false
@@ -153,13 +329,15 @@ impl CalendarManager {
         &self.sources
     }
 
-    /// Get a mutable reference to all sources
+    /// Get a mutable reference to all sources.
+    /// Every event change (create, edit, delete, move, import, sync) and calendar
+    /// toggle goes through here, so the fetched events are dropped from the cache.
     pub fn sources_mut(&mut self) -> &mut [Box<dyn CalendarSource>] {
+        self.event_cache.clear();
         &mut self.sources
     }
 
     /// Get all events from all enabled calendars
-    #[allow(dead_code)] // Reserved for future event filtering
     pub fn get_all_events(&self) -> Vec<CalendarEvent> {
         let mut all_events = Vec::new();
         for source in &self.sources {
@@ -172,6 +350,19 @@ impl CalendarManager {
         all_events
     }
 
+    /// Get the distinct categories used by events in enabled calendars.
+    /// Names are deduplicated case-insensitively and sorted alphabetically.
+    pub fn all_categories(&self) -> Vec<String> {
+        let mut categories: Vec<String> = Vec::new();
+        for category in self.get_all_events().into_iter().flat_map(|e| e.categories) {
+            if !categories.iter().any(|c| c.eq_ignore_ascii_case(&category)) {
+                categories.push(category);
+            }
+        }
+        categories.sort_by_key(|c| c.to_lowercase());
+        categories
+    }
+
     /// Get events for a specific date from all enabled calendars
     #[allow(dead_code)] // Reserved for future day view filtering
     pub fn get_events_for_date(&self, date: chrono::NaiveDate) -> Vec<CalendarEvent> {
@@ -193,91 +384,328 @@ impl CalendarManager {
             .collect()
     }
 
+    /// Get event occurrences shown between two dates (inclusive) from all enabled calendars,
+    /// with recurring events expanded into individual occurrences. Multi-day events that
+    /// started earlier and run into the range are included.
+    /// Start and end are the wall-clock times shown in the views, like the local "now" they are compared to.
+    pub fn get_occurrences_between(&self, range_start: NaiveDate, range_end: NaiveDate) -> Vec<CalendarEvent> {
+        Self::occurrences_between(&self.get_all_events(), range_start, range_end)
+    }
+
+    /// Like `get_occurrences_between`, leaving out calendars with muted alerts.
+    /// Used for alert scheduling, so muted calendars never notify.
+    pub fn get_alert_occurrences_between(&self, range_start: NaiveDate, range_end: NaiveDate) -> Vec<CalendarEvent> {
+        let events: Vec<CalendarEvent> = self
+            .sources
+            .iter()
+            .filter(|source| source.is_enabled() && !source.info().mute_alerts)
+            .filter_map(|source| source.fetch_events().ok())
+            .flatten()
+            .collect();
+        Self::occurrences_between(&events, range_start, range_end)
+    }
+
+    /// Expand `events` into the occurrences shown between two dates, with display times
+    fn occurrences_between(events: &[CalendarEvent], range_start: NaiveDate, range_end: NaiveDate) -> Vec<CalendarEvent> {
+        events
+            .iter()
+            .flat_map(|event| Self::expand_recurring_event(event, range_start, range_end))
+            .map(|(_date, mut occurrence)| {
+                let (start, end) = Self::display_range(&occurrence);
+                occurrence.start = start.and_utc();
+                occurrence.end = end.and_utc();
+                occurrence
+            })
+            .collect()
+    }
+
+    /// Start date of the soonest event (or occurrence) after the given date,
+    /// looking up to a year ahead
+    pub fn next_event_date_after(&self, after: NaiveDate) -> Option<NaiveDate> {
+        self.get_occurrences_between(after + Duration::days(1), after + Duration::days(NEXT_EVENT_SEARCH_DAYS))
+            .iter()
+            .map(|event| event.start.date_naive())
+            .filter(|date| *date > after)
+            .min()
+    }
+
+    /// Search enabled calendars for events whose summary, location or notes contain `query`
+    /// (case-insensitive). Returns each match with the date to show it on, sorted by date.
+    /// Recurring events are listed at their next occurrence from `today` (or their first one).
+    pub fn search_events(&self, query: &str, today: NaiveDate) -> Vec<(NaiveDate, DisplayEvent)> {
+        let query = query.trim();
+        if query.is_empty() {
+            return vec![];
+        }
+
+        let matches = match self.db.lock() {
+            Ok(db) => db.search_events(query, SEARCH_RESULT_LIMIT).unwrap_or_else(|e| {
+                warn!("CalendarManager: Search for '{}' failed: {}", query, e);
+                vec![]
+            }),
+            Err(_) => vec![],
+        };
+
+        let mut results: Vec<(NaiveDate, DisplayEvent)> = matches
+            .into_iter()
+            .filter_map(|(calendar_id, event)| {
+                // Calendar colors live in the sources, not the database
+                let source = self.sources.iter().find(|s| s.info().id == calendar_id && s.is_enabled())?;
+                let first_date = event.start.date_naive();
+                let search_end = today + Duration::days(NEXT_EVENT_SEARCH_DAYS);
+                let (date, occurrence) = Self::expand_recurring_event(&event, today.max(first_date), search_end)
+                    .into_iter()
+                    .next()
+                    .or_else(|| Self::expand_recurring_event(&event, first_date, first_date).into_iter().next())?;
+                let (start_time, end_time) = Self::display_times(&occurrence);
+                let date = if occurrence.all_day { date } else { Self::display_range(&occurrence).0.date() };
+
+                Some((date, DisplayEvent {
+                    calendar_id,
+                    uid: occurrence.uid.clone(),
+                    summary: occurrence.summary.clone(),
+                    color: occurrence.color.clone().unwrap_or_else(|| source.info().color.clone()),
+                    all_day: occurrence.all_day,
+                    start_time,
+                    end_time,
+                    span_start: None,
+                    span_end: None,
+                    location: occurrence.location.clone(),
+                    status: occurrence.status,
+                    timezone_fallback: timezone::is_fallback(occurrence.timezone.as_deref()),
+                    categories: occurrence.categories.clone(),
+                }))
+            })
+            .collect();
+
+        results.sort_by_key(|(date, _)| *date);
+        results
+    }
+
     /// Expand a recurring event into multiple occurrences within a date range
     /// Returns a vector of (occurrence_date, event) tuples
     /// Skips exception dates (dates where the recurring event was deleted for a single occurrence)
+    /// and substitutes per-occurrence overrides for the generated occurrence
     fn expand_recurring_event(
         event: &CalendarEvent,
         range_start: NaiveDate,
         range_end: NaiveDate,
     ) -> Vec<(NaiveDate, CalendarEvent)> {
-        // Non-recurring events return a single occurrence
+        // Step through dates in the event's own zone, so a series keeps its
+        // wall-clock time across DST changes
+        let zone = Self::event_zone(event);
+        let wall_start = timezone::wall_clock(event.start, zone);
+
+        // Non-recurring events return a single occurrence, also when they
+        // start before the range and run into it
         if matches!(event.repeat, RepeatFrequency::Never) {
-            let event_date = event.start.date_naive();
-            if event_date >= range_start && event_date <= range_end {
-                return vec![(event_date, event.clone())];
+            if Self::overlaps_dates(event, range_start, range_end) {
+                return vec![(wall_start.date(), event.clone())];
             } else {
                 return vec![];
             }
         }
 
         let mut occurrences = Vec::new();
-        let event_start_date = event.start.date_naive();
+        let event_start_date = wall_start.date();
 
-        // Determine the end date for recurrence
-        let recurrence_end = event.repeat_until.unwrap_or(range_end);
+        // Determine the end date for recurrence, never past the requested range
+        let last_date = event.repeat_until.map_or(range_end, |until| until.min(range_end));
 
-        // Start from the event's start date or range_start, whichever is later
-        let mut current_date = if event_start_date < range_start {
-            // Fast-forward to first occurrence within range
-            // This is a simplification - ideal would be to calculate exact first occurrence
-            event_start_date
-        } else {
-            event_start_date
+        // Occurrences fall on every `interval`-th unit of the frequency
+        let interval = event.repeat_interval.max(1);
+        // Weekly series on specific weekdays step through those days instead
+        let weekday_series = WeekdaySeries::new(event, event_start_date, interval);
+
+        // Occurrences of a multi-day event that start this many days before
+        // the range still run into it
+        let (first_day, last_day) = Self::display_dates(event);
+        let spill_start = range_start - (last_day - first_day);
+
+        // Skip ahead to the last occurrence before the range instead of
+        // stepping from a series start that may be years back
+        let first_index = match &weekday_series {
+            Some(series) => series.first_index_near(spill_start),
+            None => Self::first_index_near(&event.repeat, event_start_date, spill_start) / interval,
         };
 
         // Limit iterations to prevent infinite loops (max 1000 occurrences per query)
         let max_iterations = 1000;
-        let mut iteration_count = 0;
 
-        while current_date <= recurrence_end && current_date <= range_end && iteration_count < max_iterations {
-            iteration_count += 1;
+        for index in first_index..first_index.saturating_add(max_iterations) {
+            let current_date = match &weekday_series {
+                Some(series) => series.nth_date(index),
+                None => index
+                    .checked_mul(interval)
+                    .and_then(|units| Self::nth_occurrence_date(&event.repeat, event_start_date, units)),
+            };
+            let Some(current_date) = current_date else {
+                break;
+            };
+            if current_date > last_date {
+                break;
+            }
+            // COUNT includes exception dates, so it limits the index rather than the output
+            if event.repeat_count.is_some_and(|count| index >= count) {
+                break;
+            }
 
             // Only add if within the visible range AND not an exception date
-            if current_date >= range_start && !event.exception_dates.contains(&current_date) {
-                // Create a clone of the event with adjusted dates
-                let duration = event.end - event.start;
-                let mut occurrence = event.clone();
-                occurrence.start = current_date.and_time(event.start.time()).and_utc();
-                occurrence.end = occurrence.start + duration;
-
+            if current_date >= spill_start && !event.exception_dates.contains(&current_date) {
                 // Generate unique UID for each occurrence by appending the date
                 // This ensures deduplication logic in views doesn't skip occurrences
-                occurrence.uid = format!("{}_{}", event.uid, current_date.format("%Y%m%d"));
+                let occurrence_uid = format!("{}_{}", event.uid, current_date.format("%Y%m%d"));
 
-                occurrences.push((current_date, occurrence));
-            }
+                if let Some(override_event) = event.overrides.iter().find(|o| o.uid == occurrence_uid) {
+                    // A modified occurrence (e.g. cancelled or renamed) replaces the generated one
+                    if Self::overlaps_dates(override_event, range_start, range_end) {
+                        let override_date = timezone::wall_clock(override_event.start, Self::event_zone(override_event)).date();
+                        occurrences.push((override_date, override_event.clone()));
+                    }
+                } else {
+                    // Create a clone of the event with adjusted dates
+                    let duration = event.end - event.start;
+                    let mut occurrence = event.clone();
+                    occurrence.start = timezone::to_utc(current_date.and_time(wall_start.time()), zone);
+                    occurrence.end = occurrence.start + duration;
+                    occurrence.uid = occurrence_uid;
+                    occurrence.overrides = vec![];
 
-            // Advance to next occurrence based on repeat frequency
-            current_date = match event.repeat {
-                RepeatFrequency::Daily => current_date + Duration::days(1),
-                RepeatFrequency::Weekly => current_date + Duration::weeks(1),
-                RepeatFrequency::Biweekly => current_date + Duration::weeks(2),
-                RepeatFrequency::Monthly => {
-                    // Add one month, handling month boundaries
-                    current_date.checked_add_months(Months::new(1))
-                        .unwrap_or(current_date + Duration::days(30))
-                },
-                RepeatFrequency::Yearly => {
-                    // Add one year
-                    current_date.checked_add_months(Months::new(12))
-                        .unwrap_or(current_date + Duration::days(365))
-                },
-                RepeatFrequency::Custom(_) => {
-                    // TODO: Parse RRULE for custom recurrence
-                    break;
-                },
-                RepeatFrequency::Never => break,
-            };
+                    occurrences.push((current_date, occurrence));
+                }
+            }
         }
 
         occurrences
     }
 
+    /// Date of the `index`-th occurrence (0 = the series start).
+    /// Monthly and yearly dates are computed from the start rather than the previous
+    /// occurrence, so a series on the 31st returns to the 31st after a short month.
+    fn nth_occurrence_date(repeat: &RepeatFrequency, start: NaiveDate, index: u32) -> Option<NaiveDate> {
+        match repeat {
+            RepeatFrequency::Daily => start.checked_add_days(Days::new(index as u64)),
+            RepeatFrequency::Weekly => start.checked_add_days(Days::new(7 * index as u64)),
+            RepeatFrequency::Biweekly => start.checked_add_days(Days::new(14 * index as u64)),
+            RepeatFrequency::Monthly => start.checked_add_months(Months::new(index)),
+            RepeatFrequency::Yearly => start.checked_add_months(Months::new(index.checked_mul(12)?)),
+            // TODO: Parse RRULE for custom recurrence - only the first occurrence is shown
+            RepeatFrequency::Custom(_) | RepeatFrequency::Never => (index == 0).then_some(start),
+        }
+    }
+
+    /// Index of the last occurrence on or before `range_start` (0 if the series starts later)
+    fn first_index_near(repeat: &RepeatFrequency, start: NaiveDate, range_start: NaiveDate) -> u32 {
+        if range_start <= start {
+            return 0;
+        }
+        let days = (range_start - start).num_days();
+        let months = (range_start.year() - start.year()) as i64 * 12
+            + range_start.month() as i64
+            - start.month() as i64;
+        let index = match repeat {
+            RepeatFrequency::Daily => days,
+            RepeatFrequency::Weekly => days / 7,
+            RepeatFrequency::Biweekly => days / 14,
+            // One step back: the day of month may be clamped in a short month
+            RepeatFrequency::Monthly => months - 1,
+            RepeatFrequency::Yearly => months / 12 - 1,
+            RepeatFrequency::Custom(_) | RepeatFrequency::Never => 0,
+        };
+        index.clamp(0, u32::MAX as i64) as u32
+    }
+
+    /// Start and end time of day for a display event.
+    /// All-day events have no times; timed events keep full precision (including seconds).
+    fn display_times(event: &CalendarEvent) -> (Option<NaiveTime>, Option<NaiveTime>) {
+        if event.all_day {
+            (None, None)
+        } else {
+            let (start, end) = Self::display_range(event);
+            (Some(start.time()), Some(end.time()))
+        }
+    }
+
+    /// Date of the first occurrence of a series, in the event's own zone
+    pub fn series_start_date(event: &CalendarEvent) -> NaiveDate {
+        timezone::wall_clock(event.start, Self::event_zone(event)).date()
+    }
+
+    /// Number of occurrences of a series before `date`, deleted ones included
+    /// (COUNT counts them too)
+    pub fn occurrences_before(event: &CalendarEvent, date: NaiveDate) -> u32 {
+        let Some(last) = date.pred_opt() else {
+            return 0;
+        };
+        let mut series = event.clone();
+        series.exception_dates.clear();
+        series.overrides.clear();
+        Self::expand_recurring_event(&series, Self::series_start_date(&series), last).len() as u32
+    }
+
+    /// Zone of an event's times; all-day events are always floating
+    pub fn event_zone(event: &CalendarEvent) -> Option<&str> {
+        if event.all_day {
+            None
+        } else {
+            event.timezone.as_deref()
+        }
+    }
+
+    /// Start and end of an event as shown in the views (zoned events in the system zone)
+    fn display_range(event: &CalendarEvent) -> (NaiveDateTime, NaiveDateTime) {
+        let zone = Self::event_zone(event);
+        (timezone::to_display(event.start, zone), timezone::to_display(event.end, zone))
+    }
+
+    /// First and last date an event is shown on, both inclusive.
+    /// Events ending exactly at midnight end on the day before: all-day events
+    /// with an iCal exclusive DTEND that wasn't normalized on import, and timed
+    /// events running until the end of a day.
+    fn display_dates(event: &CalendarEvent) -> (NaiveDate, NaiveDate) {
+        let (start, end) = Self::display_range(event);
+        let last = if end.time() == NaiveTime::MIN && end.date() > start.date() {
+            end.date() - Duration::days(1)
+        } else {
+            end.date()
+        };
+        (start.date(), last)
+    }
+
+    /// Whether an event is shown on any of the dates `range_start..=range_end`
+    fn overlaps_dates(event: &CalendarEvent, range_start: NaiveDate, range_end: NaiveDate) -> bool {
+        let (first, last) = Self::display_dates(event);
+        last >= range_start && first <= range_end
+    }
+
+    /// UTC bounds for fetching the events shown on the local dates `range_start..=range_end`,
+    /// a day wider on each side so events in any display time zone are included
+    fn fetch_bounds(range_start: NaiveDate, range_end: NaiveDate) -> (chrono::DateTime<chrono::Utc>, chrono::DateTime<chrono::Utc>) {
+        let start = (range_start - Duration::days(1)).and_time(NaiveTime::MIN).and_utc();
+        let end = (range_end + Duration::days(2)).and_time(NaiveTime::MIN).and_utc();
+        (start, end)
+    }
+
+    /// Events of `source` for the fetch range, from the cache if the range was fetched before
+    fn cached_events_in_range(
+        cache: &mut EventRangeCache,
+        source: &dyn CalendarSource,
+        start: chrono::DateTime<chrono::Utc>,
+        end: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<CalendarEvent>, Box<dyn Error>> {
+        let id = &source.info().id;
+        if let Some(events) = cache.get(id, start, end) {
+            return Ok(events.to_vec());
+        }
+        let events = source.fetch_events_in_range(start, end)?;
+        cache.insert(id, start, end, events.clone());
+        Ok(events)
+    }
+
     /// Get events for a specific month grouped by date, with calendar colors.
     /// Includes events from adjacent months that would be visible in the month view.
     /// Returns a HashMap where key is NaiveDate and value is Vec of DisplayEvents.
-    pub fn get_display_events_for_month(&self, year: i32, month: u32) -> HashMap<chrono::NaiveDate, Vec<DisplayEvent>> {
+    pub fn get_display_events_for_month(&mut self, year: i32, month: u32) -> HashMap<chrono::NaiveDate, Vec<DisplayEvent>> {
         use chrono::NaiveDate;
 
         let mut events_by_date: HashMap<NaiveDate, Vec<DisplayEvent>> = HashMap::new();
@@ -295,6 +723,8 @@ impl CalendarManager {
             NaiveDate::from_ymd_opt(year, month + 1, 1).unwrap().signed_duration_since(first_of_month).num_days()
         };
         let range_end = first_of_month + chrono::Duration::days(days_in_month + 13);
+        events_by_date.reserve((range_end - range_start).num_days() as usize + 1);
+        let (fetch_start, fetch_end) = Self::fetch_bounds(range_start, range_end);
 
         for source in &self.sources {
             if !source.is_enabled() {
@@ -303,14 +733,13 @@ impl CalendarManager {
 
             let calendar_color = source.info().color.clone();
 
-            if let Ok(events) = source.fetch_events() {
+            if let Ok(events) = Self::cached_events_in_range(&mut self.event_cache, source.as_ref(), fetch_start, fetch_end) {
                 for event in events {
                     // Expand recurring events into individual occurrences
                     let occurrences = Self::expand_recurring_event(&event, range_start, range_end);
 
                     for (_occurrence_date, occurrence_event) in occurrences {
-                        let event_start = occurrence_event.start.date_naive();
-                        let event_end = occurrence_event.end.date_naive();
+                        let (event_start, event_end) = Self::display_dates(&occurrence_event);
 
                         // For all-day events, add to each day in the range
                         // For multi-day events (end > start), show on each day
@@ -323,12 +752,16 @@ impl CalendarManager {
                                         calendar_id: source.info().id.clone(),
                                         uid: occurrence_event.uid.clone(),
                                         summary: occurrence_event.summary.clone(),
-                                        color: calendar_color.clone(),
+                                        color: occurrence_event.color.clone().unwrap_or_else(|| calendar_color.clone()),
                                         all_day: true,
                                         start_time: None,
                                         end_time: None,
                                         span_start: Some(event_start),
                                         span_end: Some(event_end),
+                                        location: occurrence_event.location.clone(),
+                                        status: occurrence_event.status,
+                                        timezone_fallback: timezone::is_fallback(occurrence_event.timezone.as_deref()),
+                                        categories: occurrence_event.categories.clone(),
                                     };
                                     events_by_date
                                         .entry(current)
@@ -341,33 +774,22 @@ impl CalendarManager {
                             // Single-day event: only add to start date
                             if event_start >= range_start && event_start <= range_end {
                                 // Extract start and end time for timed events
-                                let (start_time, end_time) = if occurrence_event.all_day {
-                                    (None, None)
-                                } else {
-                                    (
-                                        Some(chrono::NaiveTime::from_hms_opt(
-                                            occurrence_event.start.hour(),
-                                            occurrence_event.start.minute(),
-                                            0,
-                                        ).unwrap_or_default()),
-                                        Some(chrono::NaiveTime::from_hms_opt(
-                                            occurrence_event.end.hour(),
-                                            occurrence_event.end.minute(),
-                                            0,
-                                        ).unwrap_or_default()),
-                                    )
-                                };
+                                let (start_time, end_time) = Self::display_times(&occurrence_event);
 
                                 let display_event = DisplayEvent {
                                     calendar_id: source.info().id.clone(),
                                     uid: occurrence_event.uid.clone(),
                                     summary: occurrence_event.summary.clone(),
-                                    color: calendar_color.clone(),
+                                    color: occurrence_event.color.clone().unwrap_or_else(|| calendar_color.clone()),
                                     all_day: occurrence_event.all_day,
                                     start_time,
                                     end_time,
                                     span_start: None,
                                     span_end: None,
+                                    location: occurrence_event.location.clone(),
+                                    status: occurrence_event.status,
+                                    timezone_fallback: timezone::is_fallback(occurrence_event.timezone.as_deref()),
+                                    categories: occurrence_event.categories.clone(),
                                 };
                                 events_by_date
                                     .entry(event_start)
@@ -385,17 +807,16 @@ impl CalendarManager {
 
     /// Get events for a specific week grouped by date, with calendar colors.
     /// Returns a HashMap where key is NaiveDate and value is Vec of DisplayEvents.
-    pub fn get_display_events_for_week(&self, week_days: &[chrono::NaiveDate]) -> HashMap<chrono::NaiveDate, Vec<DisplayEvent>> {
+    pub fn get_display_events_for_week(&mut self, week_days: &[chrono::NaiveDate]) -> HashMap<chrono::NaiveDate, Vec<DisplayEvent>> {
         use chrono::NaiveDate;
 
         let mut events_by_date: HashMap<NaiveDate, Vec<DisplayEvent>> = HashMap::new();
 
-        if week_days.is_empty() {
+        let (Some(&range_start), Some(&range_end)) = (week_days.first(), week_days.last()) else {
             return events_by_date;
-        }
-
-        let range_start = week_days[0];
-        let range_end = week_days[week_days.len() - 1];
+        };
+        events_by_date.reserve(week_days.len());
+        let (fetch_start, fetch_end) = Self::fetch_bounds(range_start, range_end);
 
         for source in &self.sources {
             if !source.is_enabled() {
@@ -404,18 +825,18 @@ impl CalendarManager {
 
             let calendar_color = source.info().color.clone();
 
-            if let Ok(events) = source.fetch_events() {
+            if let Ok(events) = Self::cached_events_in_range(&mut self.event_cache, source.as_ref(), fetch_start, fetch_end) {
                 for event in events {
                     // Expand recurring events into individual occurrences
                     let occurrences = Self::expand_recurring_event(&event, range_start, range_end);
 
                     for (_occurrence_date, occurrence_event) in occurrences {
-                        let event_start = occurrence_event.start.date_naive();
-                        let event_end = occurrence_event.end.date_naive();
+                        let (event_start, event_end) = Self::display_dates(&occurrence_event);
 
-                        // For all-day/multi-day events, add to each day in the range
-                        if occurrence_event.all_day && event_end > event_start {
-                            // Multi-day event: iterate through each day
+                        // Multi-day events, all-day or timed, are added to each day in the range;
+                        // the time grid shows each day's part of a timed one
+                        if event_end > event_start {
+                            let (start_time, end_time) = Self::display_times(&occurrence_event);
                             let mut current = event_start;
                             while current <= event_end && current <= range_end {
                                 if current >= range_start {
@@ -423,12 +844,16 @@ impl CalendarManager {
                                         calendar_id: source.info().id.clone(),
                                         uid: occurrence_event.uid.clone(),
                                         summary: occurrence_event.summary.clone(),
-                                        color: calendar_color.clone(),
-                                        all_day: true,
-                                        start_time: None,
-                                        end_time: None,
+                                        color: occurrence_event.color.clone().unwrap_or_else(|| calendar_color.clone()),
+                                        all_day: occurrence_event.all_day,
+                                        start_time,
+                                        end_time,
                                         span_start: Some(event_start),
                                         span_end: Some(event_end),
+                                        location: occurrence_event.location.clone(),
+                                        status: occurrence_event.status,
+                                        timezone_fallback: timezone::is_fallback(occurrence_event.timezone.as_deref()),
+                                        categories: occurrence_event.categories.clone(),
                                     };
                                     events_by_date
                                         .entry(current)
@@ -441,33 +866,22 @@ impl CalendarManager {
                             // Single-day event: only add to start date
                             if event_start >= range_start && event_start <= range_end {
                                 // Extract start and end time for timed events
-                                let (start_time, end_time) = if occurrence_event.all_day {
-                                    (None, None)
-                                } else {
-                                    (
-                                        Some(chrono::NaiveTime::from_hms_opt(
-                                            occurrence_event.start.hour(),
-                                            occurrence_event.start.minute(),
-                                            0,
-                                        ).unwrap_or_default()),
-                                        Some(chrono::NaiveTime::from_hms_opt(
-                                            occurrence_event.end.hour(),
-                                            occurrence_event.end.minute(),
-                                            0,
-                                        ).unwrap_or_default()),
-                                    )
-                                };
+                                let (start_time, end_time) = Self::display_times(&occurrence_event);
 
                                 let display_event = DisplayEvent {
                                     calendar_id: source.info().id.clone(),
                                     uid: occurrence_event.uid.clone(),
                                     summary: occurrence_event.summary.clone(),
-                                    color: calendar_color.clone(),
+                                    color: occurrence_event.color.clone().unwrap_or_else(|| calendar_color.clone()),
                                     all_day: occurrence_event.all_day,
                                     start_time,
                                     end_time,
                                     span_start: None,
                                     span_end: None,
+                                    location: occurrence_event.location.clone(),
+                                    status: occurrence_event.status,
+                                    timezone_fallback: timezone::is_fallback(occurrence_event.timezone.as_deref()),
+                                    categories: occurrence_event.categories.clone(),
                                 };
                                 events_by_date
                                     .entry(event_start)
@@ -483,9 +897,47 @@ impl CalendarManager {
         events_by_date
     }
 
+    /// Default length of new events in a calendar, if the calendar overrides the global default
+    pub fn default_duration_minutes(&self, calendar_id: &str) -> Option<u32> {
+        self.sources
+            .iter()
+            .find(|s| s.info().id == calendar_id)
+            .and_then(|s| s.info().default_duration_minutes)
+    }
+
+    /// Whether new events in a calendar default to all-day
+    pub fn default_all_day(&self, calendar_id: &str) -> bool {
+        self.sources
+            .iter()
+            .find(|s| s.info().id == calendar_id)
+            .is_some_and(|s| s.info().default_all_day)
+    }
+
+    /// Whether alerts of a calendar's events are muted
+    pub fn alerts_muted(&self, calendar_id: &str) -> bool {
+        self.sources
+            .iter()
+            .find(|s| s.info().id == calendar_id)
+            .is_some_and(|s| s.info().mute_alerts)
+    }
+
+    /// Reload every enabled source from its stored events and drop the fetched
+    /// ranges, so changes made outside the app (another tool editing the
+    /// database, a sync that finished) show up. Remote calendars reload their
+    /// offline copy; a source that fails to reload keeps its previous events.
+    pub fn reload_events(&mut self) {
+        self.event_cache.clear();
+        for source in self.sources.iter_mut().filter(|source| source.is_enabled()) {
+            if let Err(e) = source.sync() {
+                warn!("CalendarManager: Failed to reload calendar '{}': {}", source.info().id, e);
+            }
+        }
+    }
+
     /// Sync all calendar sources
     #[allow(dead_code)] // Reserved for future CalDAV sync
     pub fn sync_all(&mut self) -> Result<(), Box<dyn Error>> {
+        self.event_cache.clear();
         for source in &mut self.sources {
             if source.is_enabled() {
                 source.sync()?;
@@ -499,20 +951,40 @@ impl CalendarManager {
     pub fn save_config(&self) -> Result<(), Box<dyn Error>> {
         let mut config = CalendarManagerConfig::load().unwrap_or_default();
 
-        for source in &self.sources {
-            let info = source.info();
-            config.update_calendar(CalendarConfig {
-                id: info.id.clone(),
-                name: info.name.clone(),
-                color: info.color.clone(),
-                enabled: info.enabled,
-                calendar_type: format!("{:?}", info.calendar_type),
-            });
+        for calendar in self.calendar_configs() {
+            config.update_calendar(calendar);
         }
+        // Keep the file in list order
+        config.sort_by_order();
 
         config.save()?;
         Ok(())
     }
+
+    /// Each calendar's current state as saved to the config file.
+    /// Calendars keep the type they were loaded with, so a custom
+    /// backend's factory builds them again on the next start.
+    fn calendar_configs(&self) -> Vec<CalendarConfig> {
+        self.sources
+            .iter()
+            .map(|source| {
+                let info = source.info();
+                CalendarConfig {
+                    id: info.id.clone(),
+                    name: info.name.clone(),
+                    color: info.color.clone(),
+                    enabled: info.enabled,
+                    calendar_type: info.config_type.clone().unwrap_or_else(|| format!("{:?}", info.calendar_type)),
+                    default_duration_minutes: info.default_duration_minutes,
+                    default_all_day: info.default_all_day,
+                    pinned: info.pinned,
+                    mute_alerts: info.mute_alerts,
+                    order: info.order,
+                    account: info.account.clone(),
+                }
+            })
+            .collect()
+    }
 }
 
 impl Default for CalendarManager {
@@ -520,3 +992,880 @@ impl Default for CalendarManager {
         Self::with_defaults()
     }
 }
+
+/// A weekly series on specific weekdays (iCal BYDAY), indexed by occurrence
+struct WeekdaySeries {
+    /// Monday of the week the series starts in
+    first_monday: NaiveDate,
+    /// The weekdays as days from Monday, in week order
+    days: Vec<u32>,
+    /// Days of the first week before the series start, which aren't occurrences
+    skipped: u32,
+    /// Weeks from one week of the series to the next
+    weeks_per_step: u32,
+}
+
+impl WeekdaySeries {
+    /// Series of a weekly event with weekdays set, None for any other event
+    fn new(event: &CalendarEvent, start: NaiveDate, interval: u32) -> Option<Self> {
+        let weeks_per_step = match event.repeat {
+            RepeatFrequency::Weekly => interval,
+            RepeatFrequency::Biweekly => interval.checked_mul(2)?,
+            _ => return None,
+        };
+        let mut days: Vec<u32> = event.repeat_weekdays.iter().map(|day| day.num_days_from_monday()).collect();
+        if days.is_empty() {
+            return None;
+        }
+        days.sort_unstable();
+        days.dedup();
+
+        let start_offset = start.weekday().num_days_from_monday();
+        Some(Self {
+            first_monday: start - Duration::days(start_offset as i64),
+            skipped: days.iter().filter(|&&day| day < start_offset).count() as u32,
+            days,
+            weeks_per_step,
+        })
+    }
+
+    /// Date of the `index`-th occurrence (0 = the first on or after the series start)
+    fn nth_date(&self, index: u32) -> Option<NaiveDate> {
+        let per_week = self.days.len() as u32;
+        let position = index.checked_add(self.skipped)?;
+        let weeks = (position / per_week) as u64 * self.weeks_per_step as u64;
+        let day = self.days[(position % per_week) as usize] as u64;
+        self.first_monday.checked_add_days(Days::new(weeks * 7 + day))
+    }
+
+    /// Index of the first occurrence in the series week on or before `range_start`
+    /// (0 if the series starts later)
+    fn first_index_near(&self, range_start: NaiveDate) -> u32 {
+        let days = (range_start - self.first_monday).num_days().max(0);
+        let steps = days / (7 * self.weeks_per_step as i64);
+        (steps * self.days.len() as i64 - self.skipped as i64).clamp(0, u32::MAX as i64) as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::caldav::{AlertTime, EventStatus};
+    use chrono::{TimeZone, Timelike, Utc};
+
+    fn make_event(all_day: bool) -> CalendarEvent {
+        CalendarEvent {
+            uid: "uid".to_string(),
+            summary: "Event".to_string(),
+            all_day,
+            start: Utc.with_ymd_and_hms(2025, 3, 10, 9, 15, 30).unwrap(),
+            end: Utc.with_ymd_and_hms(2025, 3, 10, 10, 45, 0).unwrap(),
+            ..CalendarEvent::test_default()
+        }
+    }
+
+    /// Custom backend serving a fixed list of events
+    #[derive(Debug)]
+    struct DummySource {
+        info: CalendarInfo,
+        events: Vec<CalendarEvent>,
+    }
+
+    impl CalendarSource for DummySource {
+        fn info(&self) -> &CalendarInfo {
+            &self.info
+        }
+
+        fn info_mut(&mut self) -> &mut CalendarInfo {
+            &mut self.info
+        }
+
+        fn fetch_events(&self) -> Result<Vec<CalendarEvent>, Box<dyn Error>> {
+            Ok(self.events.clone())
+        }
+
+        fn add_event(&mut self, _event: CalendarEvent) -> Result<(), Box<dyn Error>> {
+            Err("read-only".into())
+        }
+
+        fn update_event(&mut self, _event: CalendarEvent) -> Result<(), Box<dyn Error>> {
+            Err("read-only".into())
+        }
+
+        fn delete_event(&mut self, _uid: &str) -> Result<(), Box<dyn Error>> {
+            Err("read-only".into())
+        }
+
+        fn sync(&mut self) -> Result<(), Box<dyn Error>> {
+            Ok(())
+        }
+
+        fn supports_write(&self) -> bool {
+            false
+        }
+    }
+
+    /// Source counting how often the views fetch from it
+    #[derive(Debug)]
+    struct CountingSource {
+        info: CalendarInfo,
+        fetches: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    impl CalendarSource for CountingSource {
+        fn info(&self) -> &CalendarInfo {
+            &self.info
+        }
+
+        fn info_mut(&mut self) -> &mut CalendarInfo {
+            &mut self.info
+        }
+
+        fn fetch_events(&self) -> Result<Vec<CalendarEvent>, Box<dyn Error>> {
+            Ok(vec![make_event(false)])
+        }
+
+        fn fetch_events_in_range(
+            &self,
+            _start: chrono::DateTime<chrono::Utc>,
+            _end: chrono::DateTime<chrono::Utc>,
+        ) -> Result<Vec<CalendarEvent>, Box<dyn Error>> {
+            self.fetches.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            self.fetch_events()
+        }
+
+        fn add_event(&mut self, _event: CalendarEvent) -> Result<(), Box<dyn Error>> {
+            Ok(())
+        }
+
+        fn update_event(&mut self, _event: CalendarEvent) -> Result<(), Box<dyn Error>> {
+            Ok(())
+        }
+
+        fn delete_event(&mut self, _uid: &str) -> Result<(), Box<dyn Error>> {
+            Ok(())
+        }
+
+        fn sync(&mut self) -> Result<(), Box<dyn Error>> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_display_events_are_cached_until_sources_change() {
+        let db_path = std::env::temp_dir().join("sol_test_event_cache.db");
+        let _ = std::fs::remove_file(&db_path);
+        let db = Arc::new(Mutex::new(Database::open_at(db_path.clone()).unwrap()));
+
+        let fetches = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let mut manager = CalendarManager::with_database(db);
+        manager.add_source(Box::new(CountingSource {
+            info: CalendarInfo::new("work".to_string(), "Work".to_string(), CalendarType::Other),
+            fetches: fetches.clone(),
+        }));
+        let count = || fetches.load(std::sync::atomic::Ordering::SeqCst);
+
+        // Two renders of the same month without changes fetch once
+        let first = manager.get_display_events_for_month(2025, 3);
+        let second = manager.get_display_events_for_month(2025, 3);
+        assert_eq!(count(), 1);
+        assert_eq!(first.values().map(Vec::len).sum::<usize>(), second.values().map(Vec::len).sum::<usize>());
+
+        // Another month is a different range
+        manager.get_display_events_for_month(2025, 4);
+        assert_eq!(count(), 2);
+
+        // Changing events goes through sources_mut, which drops the cache
+        manager.sources_mut()[0].add_event(make_event(false)).unwrap();
+        manager.get_display_events_for_month(2025, 3);
+        assert_eq!(count(), 3);
+
+        let _ = std::fs::remove_file(&db_path);
+    }
+
+    #[test]
+    fn test_reload_picks_up_external_changes() {
+        let db_path = std::env::temp_dir().join("sol_test_reload_events.db");
+        let _ = std::fs::remove_file(&db_path);
+        let db = Arc::new(Mutex::new(Database::open_at(db_path.clone()).unwrap()));
+
+        let mut manager = CalendarManager::with_database(db);
+        manager.add_source(Box::new(LocalCalendar::new("work".to_string(), "Work".to_string(), manager.database())));
+        assert!(manager.get_display_events_for_month(2025, 3).is_empty());
+
+        // Another process adds an event to the same database file
+        let external = Database::open_at(db_path.clone()).unwrap();
+        external.insert_event("work", &make_event(false)).unwrap();
+        drop(external);
+
+        // The fetched month is still cached until the events are reloaded
+        assert!(manager.get_display_events_for_month(2025, 3).is_empty());
+        manager.reload_events();
+        let events = manager.get_display_events_for_month(2025, 3);
+        assert_eq!(events.values().map(Vec::len).sum::<usize>(), 1);
+        assert_eq!(manager.get_all_events().len(), 1);
+
+        let _ = std::fs::remove_file(&db_path);
+    }
+
+    fn dummy_factory(
+        config: &CalendarConfig,
+        _db: Arc<Mutex<Database>>,
+    ) -> Result<Box<dyn CalendarSource>, Box<dyn Error>> {
+        let mut event = make_event(false);
+        event.uid = format!("{}-1", config.id);
+        Ok(Box::new(DummySource {
+            info: CalendarInfo::new(config.id.clone(), config.name.clone(), CalendarType::Other),
+            events: vec![event],
+        }))
+    }
+
+    #[test]
+    fn test_registered_factory_provides_source() {
+        let db_path = std::env::temp_dir().join("sol_test_source_factory.db");
+        let _ = std::fs::remove_file(&db_path);
+        let db = Arc::new(Mutex::new(Database::open_at(db_path.clone()).unwrap()));
+
+        let mut manager = CalendarManager::with_database(db);
+        manager.register_source_factory("Dummy", dummy_factory);
+
+        let config = CalendarConfig {
+            id: "feed".to_string(),
+            name: "Feed".to_string(),
+            color: "#10B981".to_string(),
+            enabled: true,
+            calendar_type: "Dummy".to_string(),
+            default_duration_minutes: None,
+            default_all_day: false,
+            pinned: false,
+            mute_alerts: false,
+            order: 0,
+            account: None,
+        };
+        let source = manager.source_from_config(&config).unwrap();
+        // Saved settings are applied on top of what the factory built
+        assert_eq!(source.info().calendar_type, CalendarType::Other);
+        assert_eq!(source.info().color, "#10B981");
+        manager.add_source(source);
+
+        let uids: Vec<String> = manager.get_all_events().into_iter().map(|e| e.uid).collect();
+        assert_eq!(uids, vec!["feed-1".to_string()]);
+
+        let date = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+        assert_eq!(manager.get_occurrences_between(date, date).len(), 1);
+
+        // The calendar is saved under its registered type, not the source's CalendarType
+        let saved = manager.calendar_configs();
+        assert_eq!(saved[0].calendar_type, "Dummy");
+
+        // Loading the saved calendars builds them with the registered factory again
+        let mut reloaded = CalendarManager::with_database(manager.database());
+        reloaded.register_source_factory("Dummy", dummy_factory);
+        reloaded.load_calendars(&CalendarManagerConfig { calendars: saved });
+        assert_eq!(reloaded.get_all_events()[0].uid, "feed-1");
+
+        let _ = std::fs::remove_file(&db_path);
+    }
+
+    #[test]
+    fn test_display_times_matches_event_times() {
+        let event = make_event(false);
+        let (start, end) = CalendarManager::display_times(&event);
+        let start = start.unwrap();
+        let end = end.unwrap();
+
+        // Same hour/minute as before, and seconds are preserved
+        assert_eq!((start.hour(), start.minute(), start.second()), (9, 15, 30));
+        assert_eq!((end.hour(), end.minute(), end.second()), (10, 45, 0));
+        assert_eq!(start, event.start.time());
+        assert_eq!(end, event.end.time());
+    }
+
+    #[test]
+    fn test_display_times_all_day() {
+        let event = make_event(true);
+        assert_eq!(CalendarManager::display_times(&event), (None, None));
+    }
+
+    #[test]
+    fn test_imported_all_day_events_span_their_inclusive_days() {
+        let ics = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//Test//EN\r\n\
+BEGIN:VEVENT\r\n\
+UID:holiday\r\n\
+SUMMARY:Holiday\r\n\
+DTSTART;VALUE=DATE:20251201\r\n\
+DTEND;VALUE=DATE:20251202\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:trip\r\n\
+SUMMARY:Trip\r\n\
+DTSTART;VALUE=DATE:20251201\r\n\
+DTEND;VALUE=DATE:20251204\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+        let mut events = crate::services::ExportHandler::parse_ical_string(ics).unwrap();
+
+        // Stored before import normalized the exclusive end: still a single day
+        let mut stored = make_event(true);
+        stored.uid = "stored".to_string();
+        stored.start = Utc.with_ymd_and_hms(2025, 12, 5, 0, 0, 0).unwrap();
+        stored.end = Utc.with_ymd_and_hms(2025, 12, 6, 0, 0, 0).unwrap();
+        events.push(stored);
+
+        let db_path = std::env::temp_dir().join("sol_test_all_day_spans.db");
+        let _ = std::fs::remove_file(&db_path);
+        let db = Arc::new(Mutex::new(Database::open_at(db_path.clone()).unwrap()));
+        let mut manager = CalendarManager::with_database(db);
+        manager.add_source(Box::new(DummySource {
+            info: CalendarInfo::new("holidays".to_string(), "Holidays".to_string(), CalendarType::Other),
+            events,
+        }));
+
+        let by_date = manager.get_display_events_for_month(2025, 12);
+        let days_of = |uid: &str| -> Vec<u32> {
+            let mut days: Vec<u32> = by_date
+                .iter()
+                .filter(|(_, events)| events.iter().any(|e| e.uid == uid))
+                .map(|(date, _)| date.day())
+                .collect();
+            days.sort();
+            days
+        };
+        assert_eq!(days_of("holiday"), [1]);
+        assert_eq!(days_of("stored"), [5]);
+        assert_eq!(days_of("trip"), [1, 2, 3]);
+
+        let trip = by_date[&NaiveDate::from_ymd_opt(2025, 12, 2).unwrap()]
+            .iter()
+            .find(|e| e.uid == "trip")
+            .unwrap();
+        assert_eq!(trip.span_end, NaiveDate::from_ymd_opt(2025, 12, 3));
+
+        let _ = std::fs::remove_file(&db_path);
+    }
+
+    #[test]
+    fn test_event_color_overrides_calendar_color() {
+        let mut tagged = make_event(false);
+        tagged.uid = "tagged".to_string();
+        tagged.color = Some("#EF4444".to_string());
+        let mut plain = make_event(false);
+        plain.uid = "plain".to_string();
+
+        let db_path = std::env::temp_dir().join("sol_test_event_color.db");
+        let _ = std::fs::remove_file(&db_path);
+        let db = Arc::new(Mutex::new(Database::open_at(db_path.clone()).unwrap()));
+        let mut manager = CalendarManager::with_database(db);
+        let mut info = CalendarInfo::new("work".to_string(), "Work".to_string(), CalendarType::Other);
+        info.color = "#3B82F6".to_string();
+        manager.add_source(Box::new(DummySource { info, events: vec![tagged, plain] }));
+
+        let date = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+        let color_of = |events: &HashMap<NaiveDate, Vec<DisplayEvent>>, uid: &str| {
+            events[&date].iter().find(|e| e.uid == uid).map(|e| e.color.clone())
+        };
+        let month = manager.get_display_events_for_month(2025, 3);
+        assert_eq!(color_of(&month, "tagged").as_deref(), Some("#EF4444"));
+        assert_eq!(color_of(&month, "plain").as_deref(), Some("#3B82F6"));
+        let week = manager.get_display_events_for_week(&[date]);
+        assert_eq!(color_of(&week, "tagged").as_deref(), Some("#EF4444"));
+
+        let _ = std::fs::remove_file(&db_path);
+    }
+
+    #[test]
+    fn test_multi_day_timed_event_shown_on_each_day() {
+        // Monday 10:00 to Wednesday 14:00, and an evening event ending at midnight
+        let mut offsite = make_event(false);
+        offsite.uid = "offsite".to_string();
+        offsite.start = Utc.with_ymd_and_hms(2025, 3, 10, 10, 0, 0).unwrap();
+        offsite.end = Utc.with_ymd_and_hms(2025, 3, 12, 14, 0, 0).unwrap();
+        let mut late = make_event(false);
+        late.uid = "late".to_string();
+        late.start = Utc.with_ymd_and_hms(2025, 3, 10, 22, 0, 0).unwrap();
+        late.end = Utc.with_ymd_and_hms(2025, 3, 11, 0, 0, 0).unwrap();
+
+        let db_path = std::env::temp_dir().join("sol_test_multi_day_timed.db");
+        let _ = std::fs::remove_file(&db_path);
+        let db = Arc::new(Mutex::new(Database::open_at(db_path.clone()).unwrap()));
+        let mut manager = CalendarManager::with_database(db);
+        let info = CalendarInfo::new("work".to_string(), "Work".to_string(), CalendarType::Other);
+        manager.add_source(Box::new(DummySource { info, events: vec![offsite, late] }));
+
+        let monday = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+        let days: Vec<NaiveDate> = (0..7).map(|offset| monday + Duration::days(offset)).collect();
+        let week = manager.get_display_events_for_week(&days);
+        let uids_on = |offset: i64| -> Vec<&str> {
+            let mut uids: Vec<&str> = week.get(&(monday + Duration::days(offset)))
+                .map(|events| events.iter().map(|e| e.uid.as_str()).collect())
+                .unwrap_or_default();
+            uids.sort();
+            uids
+        };
+        assert_eq!(uids_on(0), vec!["late", "offsite"]);
+        assert_eq!(uids_on(1), vec!["offsite"]);
+        assert_eq!(uids_on(2), vec!["offsite"]);
+        assert!(uids_on(3).is_empty());
+
+        // Each day's copy is timed and carries the whole span
+        let tuesday_copy = &week[&(monday + Duration::days(1))][0];
+        assert!(!tuesday_copy.all_day);
+        assert!(tuesday_copy.is_multi_day_timed());
+        assert_eq!(tuesday_copy.span_start, Some(monday));
+        assert_eq!(tuesday_copy.span_end, Some(monday + Duration::days(2)));
+
+        let _ = std::fs::remove_file(&db_path);
+    }
+
+    #[test]
+    fn test_multi_day_timed_event_runs_into_next_week() {
+        // Saturday 10:00 to Tuesday 14:00, seen in the week starting Monday
+        let mut weekend = make_event(false);
+        weekend.uid = "weekend".to_string();
+        weekend.start = Utc.with_ymd_and_hms(2025, 3, 8, 10, 0, 0).unwrap();
+        weekend.end = Utc.with_ymd_and_hms(2025, 3, 11, 14, 0, 0).unwrap();
+
+        let db_path = std::env::temp_dir().join("sol_test_timed_into_next_week.db");
+        let _ = std::fs::remove_file(&db_path);
+        let db = Arc::new(Mutex::new(Database::open_at(db_path.clone()).unwrap()));
+        let mut manager = CalendarManager::with_database(db);
+        let info = CalendarInfo::new("work".to_string(), "Work".to_string(), CalendarType::Other);
+        manager.add_source(Box::new(DummySource { info, events: vec![weekend] }));
+
+        let monday = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+        let days: Vec<NaiveDate> = (0..7).map(|offset| monday + Duration::days(offset)).collect();
+        let week = manager.get_display_events_for_week(&days);
+        let shown_on: Vec<NaiveDate> = days.iter().copied().filter(|day| week.contains_key(day)).collect();
+        assert_eq!(shown_on, [monday, monday + Duration::days(1)]);
+
+        let monday_copy = &week[&monday][0];
+        assert!(monday_copy.is_multi_day_timed());
+        assert_eq!(monday_copy.span_start, NaiveDate::from_ymd_opt(2025, 3, 8));
+
+        let _ = std::fs::remove_file(&db_path);
+    }
+
+    #[test]
+    fn test_events_from_before_the_range_spill_into_it() {
+        // A trip from the middle of February into March, and a weekly
+        // Friday to Monday rota whose last February weekend runs into March
+        let mut trip = make_event(true);
+        trip.uid = "trip".to_string();
+        trip.start = Utc.with_ymd_and_hms(2025, 2, 17, 0, 0, 0).unwrap();
+        trip.end = Utc.with_ymd_and_hms(2025, 3, 2, 0, 0, 0).unwrap();
+        let mut rota = make_event(true);
+        rota.uid = "rota".to_string();
+        rota.start = Utc.with_ymd_and_hms(2025, 2, 7, 0, 0, 0).unwrap();
+        rota.end = Utc.with_ymd_and_hms(2025, 2, 10, 0, 0, 0).unwrap();
+        rota.repeat = RepeatFrequency::Weekly;
+
+        let db_path = std::env::temp_dir().join("sol_test_spill_into_range.db");
+        let _ = std::fs::remove_file(&db_path);
+        let db = Arc::new(Mutex::new(Database::open_at(db_path.clone()).unwrap()));
+        let mut manager = CalendarManager::with_database(db);
+        let info = CalendarInfo::new("personal".to_string(), "Personal".to_string(), CalendarType::Other);
+        manager.add_source(Box::new(DummySource { info, events: vec![trip, rota] }));
+
+        let march = |day: u32| NaiveDate::from_ymd_opt(2025, 3, day).unwrap();
+        let month = manager.get_display_events_for_month(2025, 3);
+        assert!(month[&march(1)].iter().any(|e| e.uid == "trip"));
+        assert!(month[&march(2)].iter().any(|e| e.uid == "rota_20250228"));
+
+        let occurrences = manager.get_occurrences_between(march(1), march(2));
+        let mut uids: Vec<&str> = occurrences.iter().map(|e| e.uid.as_str()).collect();
+        uids.sort();
+        assert_eq!(uids, ["rota_20250228", "trip"]);
+
+        let _ = std::fs::remove_file(&db_path);
+    }
+
+    #[test]
+    fn test_override_replaces_single_occurrence() {
+        let mut event = make_event(false);
+        event.repeat = RepeatFrequency::Daily;
+        event.overrides = vec![CalendarEvent {
+            uid: "uid_20250312".to_string(),
+            summary: "Moved standup".to_string(),
+            status: EventStatus::Cancelled,
+            ..make_event(false)
+        }];
+        event.overrides[0].start = Utc.with_ymd_and_hms(2025, 3, 12, 9, 15, 30).unwrap();
+        event.overrides[0].end = Utc.with_ymd_and_hms(2025, 3, 12, 10, 45, 0).unwrap();
+
+        let range_start = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+        let range_end = NaiveDate::from_ymd_opt(2025, 3, 13).unwrap();
+        let occurrences = CalendarManager::expand_recurring_event(&event, range_start, range_end);
+
+        // The series keeps all four days; only the overridden one changes
+        assert_eq!(occurrences.len(), 4);
+        let (date, overridden) = &occurrences[2];
+        assert_eq!(*date, NaiveDate::from_ymd_opt(2025, 3, 12).unwrap());
+        assert_eq!(overridden.uid, "uid_20250312");
+        assert_eq!(overridden.summary, "Moved standup");
+        assert_eq!(overridden.status, EventStatus::Cancelled);
+
+        for (_, occurrence) in occurrences.iter().filter(|(d, _)| d != date) {
+            assert_eq!(occurrence.summary, "Event");
+            assert_eq!(occurrence.status, EventStatus::Confirmed);
+            assert!(occurrence.overrides.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_exception_date_takes_precedence_over_override() {
+        let mut event = make_event(false);
+        event.repeat = RepeatFrequency::Daily;
+        event.exception_dates = vec![NaiveDate::from_ymd_opt(2025, 3, 11).unwrap()];
+        event.overrides = vec![CalendarEvent {
+            uid: "uid_20250311".to_string(),
+            status: EventStatus::Cancelled,
+            ..make_event(false)
+        }];
+
+        let range_start = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+        let range_end = NaiveDate::from_ymd_opt(2025, 3, 12).unwrap();
+        let occurrences = CalendarManager::expand_recurring_event(&event, range_start, range_end);
+
+        let dates: Vec<NaiveDate> = occurrences.iter().map(|(d, _)| *d).collect();
+        assert_eq!(dates, vec![
+            NaiveDate::from_ymd_opt(2025, 3, 10).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 3, 12).unwrap(),
+        ]);
+    }
+
+    #[test]
+    fn test_old_series_still_reaches_visible_range() {
+        // Daily since 2019: more occurrences before the range than the iteration cap
+        let mut event = make_event(false);
+        event.start = Utc.with_ymd_and_hms(2019, 1, 1, 9, 0, 0).unwrap();
+        event.end = Utc.with_ymd_and_hms(2019, 1, 1, 9, 30, 0).unwrap();
+        event.repeat = RepeatFrequency::Daily;
+
+        let range_start = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+        let range_end = NaiveDate::from_ymd_opt(2025, 3, 16).unwrap();
+        let occurrences = CalendarManager::expand_recurring_event(&event, range_start, range_end);
+
+        assert_eq!(occurrences.len(), 7);
+        let (date, first) = &occurrences[0];
+        assert_eq!(*date, range_start);
+        assert_eq!(first.uid, "uid_20250310");
+        // Same time of day and duration as the base event
+        assert_eq!(first.start, Utc.with_ymd_and_hms(2025, 3, 10, 9, 0, 0).unwrap());
+        assert_eq!(first.end, Utc.with_ymd_and_hms(2025, 3, 10, 9, 30, 0).unwrap());
+    }
+
+    #[test]
+    fn test_zoned_series_keeps_local_time_across_dst() {
+        // Weekly at 09:00 Berlin time, starting the week before summer time begins
+        let mut event = make_event(false);
+        event.start = Utc.with_ymd_and_hms(2025, 3, 24, 8, 0, 0).unwrap();
+        event.end = Utc.with_ymd_and_hms(2025, 3, 24, 9, 0, 0).unwrap();
+        event.timezone = Some("Europe/Berlin".to_string());
+        event.repeat = RepeatFrequency::Weekly;
+
+        let range_start = NaiveDate::from_ymd_opt(2025, 3, 24).unwrap();
+        let range_end = NaiveDate::from_ymd_opt(2025, 3, 31).unwrap();
+        let occurrences = CalendarManager::expand_recurring_event(&event, range_start, range_end);
+
+        assert_eq!(occurrences.len(), 2);
+        // 09:00 CEST is 07:00 UTC; the duration stays one hour
+        let (date, summer) = &occurrences[1];
+        assert_eq!(*date, range_end);
+        assert_eq!(summer.start, Utc.with_ymd_and_hms(2025, 3, 31, 7, 0, 0).unwrap());
+        assert_eq!(summer.end, Utc.with_ymd_and_hms(2025, 3, 31, 8, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_expansion_stops_at_range_end_and_keeps_month_day() {
+        let mut weekly = make_event(false);
+        weekly.repeat = RepeatFrequency::Weekly;
+        let range_start = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+        let range_end = NaiveDate::from_ymd_opt(2025, 3, 31).unwrap();
+        let dates: Vec<NaiveDate> = CalendarManager::expand_recurring_event(&weekly, range_start, range_end)
+            .into_iter()
+            .map(|(d, _)| d)
+            .collect();
+        assert_eq!(dates, [10, 17, 24, 31].map(|d| NaiveDate::from_ymd_opt(2025, 3, d).unwrap()));
+
+        // A monthly series on the 31st is clamped in February but not afterwards
+        let mut monthly = make_event(false);
+        monthly.start = Utc.with_ymd_and_hms(2025, 1, 31, 9, 0, 0).unwrap();
+        monthly.end = Utc.with_ymd_and_hms(2025, 1, 31, 10, 0, 0).unwrap();
+        monthly.repeat = RepeatFrequency::Monthly;
+        let range_start = NaiveDate::from_ymd_opt(2025, 2, 1).unwrap();
+        let range_end = NaiveDate::from_ymd_opt(2025, 4, 30).unwrap();
+        let dates: Vec<NaiveDate> = CalendarManager::expand_recurring_event(&monthly, range_start, range_end)
+            .into_iter()
+            .map(|(d, _)| d)
+            .collect();
+        assert_eq!(dates, vec![
+            NaiveDate::from_ymd_opt(2025, 2, 28).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 3, 31).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 4, 30).unwrap(),
+        ]);
+    }
+
+    #[test]
+    fn test_weekly_count_stops_after_three_occurrences() {
+        let mut event = make_event(false);
+        event.repeat = RepeatFrequency::Weekly;
+        event.repeat_count = Some(3);
+
+        let range_start = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+        let range_end = NaiveDate::from_ymd_opt(2025, 6, 30).unwrap();
+        let dates: Vec<NaiveDate> = CalendarManager::expand_recurring_event(&event, range_start, range_end)
+            .into_iter()
+            .map(|(d, _)| d)
+            .collect();
+        assert_eq!(dates, [10, 17, 24].map(|d| NaiveDate::from_ymd_opt(2025, 3, d).unwrap()));
+
+        // A range starting after the third occurrence shows nothing
+        let later = NaiveDate::from_ymd_opt(2025, 3, 25).unwrap();
+        assert!(CalendarManager::expand_recurring_event(&event, later, range_end).is_empty());
+
+        // An exception date still counts toward COUNT
+        event.exception_dates = vec![NaiveDate::from_ymd_opt(2025, 3, 17).unwrap()];
+        assert_eq!(CalendarManager::expand_recurring_event(&event, range_start, range_end).len(), 2);
+    }
+
+    #[test]
+    fn test_count_and_until_both_limit_the_series() {
+        let mut event = make_event(false);
+        event.repeat = RepeatFrequency::Daily;
+        event.repeat_count = Some(10);
+        event.repeat_until = NaiveDate::from_ymd_opt(2025, 3, 12);
+
+        let range_start = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+        let range_end = NaiveDate::from_ymd_opt(2025, 3, 31).unwrap();
+        assert_eq!(CalendarManager::expand_recurring_event(&event, range_start, range_end).len(), 3);
+    }
+
+    #[test]
+    fn test_every_two_weeks_over_a_quarter() {
+        let mut event = make_event(false);
+        event.repeat = RepeatFrequency::Weekly;
+        event.repeat_interval = 2;
+
+        let date = |month, day| NaiveDate::from_ymd_opt(2025, month, day).unwrap();
+        let dates = |range_start, range_end| -> Vec<NaiveDate> {
+            CalendarManager::expand_recurring_event(&event, range_start, range_end)
+                .into_iter()
+                .map(|(d, _)| d)
+                .collect()
+        };
+        assert_eq!(dates(date(3, 1), date(5, 31)), [
+            date(3, 10), date(3, 24), date(4, 7), date(4, 21), date(5, 5), date(5, 19),
+        ]);
+
+        // Skipping ahead to a later range keeps the series on its own weeks
+        assert_eq!(dates(date(4, 1), date(4, 30)), [date(4, 7), date(4, 21)]);
+    }
+
+    #[test]
+    fn test_weekly_series_on_mon_wed_fri() {
+        let mut event = make_event(false);
+        event.repeat = RepeatFrequency::Weekly;
+        event.repeat_weekdays = vec![chrono::Weekday::Fri, chrono::Weekday::Mon, chrono::Weekday::Wed];
+
+        let date = |day| NaiveDate::from_ymd_opt(2025, 3, day).unwrap();
+        let dates = |event: &CalendarEvent, range_start, range_end| -> Vec<NaiveDate> {
+            CalendarManager::expand_recurring_event(event, range_start, range_end)
+                .into_iter()
+                .map(|(d, _)| d)
+                .collect()
+        };
+        // A later week, reached by skipping ahead
+        assert_eq!(dates(&event, date(17), date(23)), [date(17), date(19), date(21)]);
+
+        // COUNT counts days, not weeks
+        event.repeat_count = Some(4);
+        assert_eq!(dates(&event, date(1), date(31)), [date(10), date(12), date(14), date(17)]);
+
+        // A series starting on Wednesday skips that week's Monday
+        event.repeat_count = None;
+        event.start = Utc.with_ymd_and_hms(2025, 3, 12, 9, 0, 0).unwrap();
+        event.end = Utc.with_ymd_and_hms(2025, 3, 12, 10, 0, 0).unwrap();
+        assert_eq!(dates(&event, date(10), date(17)), [date(12), date(14), date(17)]);
+    }
+
+    #[test]
+    fn test_copy_calendar_copies_events_under_new_id() {
+        let db_path = std::env::temp_dir().join("sol_test_copy_calendar.db");
+        let _ = std::fs::remove_file(&db_path);
+        let db = Arc::new(Mutex::new(Database::open_at(db_path.clone()).unwrap()));
+
+        let mut manager = CalendarManager::with_database(db.clone());
+        manager.add_source(Box::new(LocalCalendar::new("work".to_string(), "Work".to_string(), db.clone())));
+        manager.add_source(Box::new(LocalCalendar::new("home".to_string(), "Home".to_string(), db.clone())));
+        for uid in ["standup", "review", "retro"] {
+            let mut event = make_event(false);
+            event.uid = uid.to_string();
+            manager.sources_mut()[0].add_event(event).unwrap();
+        }
+
+        let new_id = manager.copy_calendar("work").unwrap();
+        assert_eq!(new_id, "work-copy");
+        // Listed right below the original
+        let copy = &manager.sources()[1];
+        assert_eq!(copy.info().id, "work-copy");
+        assert_eq!(copy.info().color, manager.sources()[0].info().color);
+
+        let uids = |index: usize| -> Vec<String> {
+            manager.sources()[index].fetch_events().unwrap().into_iter().map(|e| e.uid).collect()
+        };
+        assert_eq!(uids(1).len(), 3);
+        assert_eq!(uids(0).len(), 3);
+        assert!(uids(1).iter().all(|uid| !uids(0).contains(uid)));
+
+        // A second copy gets its own ID
+        assert_eq!(manager.copy_calendar("work").unwrap(), "work-copy-2");
+        assert!(manager.copy_calendar("missing").is_err());
+
+        let _ = std::fs::remove_file(&db_path);
+    }
+
+    #[test]
+    fn test_fresh_uid_keeps_every_other_field() {
+        let mut event = make_event(false);
+        event.invitees = vec!["ana@example.com".to_string()];
+        event.attachments = vec!["/home/ana/agenda.pdf".to_string()];
+        event.alert = AlertTime::FifteenMinutes;
+        event.alert_second = Some(AlertTime::OneDay);
+        event.repeat = RepeatFrequency::Weekly;
+        event.repeat_count = Some(6);
+        let mut moved = make_event(false);
+        moved.uid = "uid_20250310".to_string();
+        event.overrides = vec![moved];
+
+        let copy = CalendarManager::event_with_fresh_uid(event.clone());
+        assert_ne!(copy.uid, event.uid);
+        assert_eq!(copy.overrides[0].uid, format!("{}_20250310", copy.uid));
+
+        // Apart from the UIDs the copy is identical
+        let mut restored = copy;
+        restored.uid = event.uid.clone();
+        restored.overrides[0].uid = "uid_20250310".to_string();
+        assert_eq!(restored, event);
+    }
+
+    #[test]
+    fn test_pinned_calendars_move_to_top() {
+        let db_path = std::env::temp_dir().join("sol_test_pinned_calendars.db");
+        let _ = std::fs::remove_file(&db_path);
+        let db = Arc::new(Mutex::new(Database::open_at(db_path.clone()).unwrap()));
+
+        let mut manager = CalendarManager::with_database(db.clone());
+        for id in ["personal", "work", "holidays"] {
+            manager.add_source(Box::new(LocalCalendar::new(id.to_string(), id.to_string(), db.clone())));
+        }
+        let ids = |manager: &CalendarManager| -> Vec<String> {
+            manager.sources().iter().map(|s| s.info().id.clone()).collect()
+        };
+
+        assert!(manager.set_calendar_pinned("holidays", true));
+        assert_eq!(ids(&manager), ["holidays", "personal", "work"]);
+        assert!(manager.set_calendar_pinned("work", true));
+        assert_eq!(ids(&manager), ["holidays", "work", "personal"]);
+
+        // Unpinning keeps the remaining order stable
+        assert!(manager.set_calendar_pinned("holidays", false));
+        assert_eq!(ids(&manager), ["work", "holidays", "personal"]);
+        assert!(!manager.set_calendar_pinned("missing", true));
+
+        let _ = std::fs::remove_file(&db_path);
+    }
+
+    #[test]
+    fn test_move_calendar_renumbers_order() {
+        let db_path = std::env::temp_dir().join("sol_test_move_calendar.db");
+        let _ = std::fs::remove_file(&db_path);
+        let db = Arc::new(Mutex::new(Database::open_at(db_path.clone()).unwrap()));
+
+        let mut manager = CalendarManager::with_database(db.clone());
+        for id in ["personal", "work", "holidays"] {
+            manager.add_source(Box::new(LocalCalendar::new(id.to_string(), id.to_string(), db.clone())));
+        }
+        let ids = |manager: &CalendarManager| -> Vec<(String, u32)> {
+            manager.sources().iter().map(|s| (s.info().id.clone(), s.info().order)).collect()
+        };
+        let expected = |pairs: &[(&str, u32)]| -> Vec<(String, u32)> {
+            pairs.iter().map(|(id, order)| (id.to_string(), *order)).collect()
+        };
+
+        assert_eq!(ids(&manager), expected(&[("personal", 0), ("work", 1), ("holidays", 2)]));
+        assert!(manager.move_calendar("holidays", 0));
+        assert_eq!(ids(&manager), expected(&[("holidays", 0), ("personal", 1), ("work", 2)]));
+        // Indexes past the end move to the bottom
+        assert!(manager.move_calendar("holidays", 10));
+        assert_eq!(ids(&manager), expected(&[("personal", 0), ("work", 1), ("holidays", 2)]));
+
+        // A pinned calendar can't be moved below unpinned ones
+        assert!(manager.set_calendar_pinned("work", true));
+        assert!(manager.move_calendar("work", 2));
+        assert_eq!(ids(&manager), expected(&[("work", 0), ("personal", 1), ("holidays", 2)]));
+        assert!(!manager.move_calendar("missing", 0));
+
+        let _ = std::fs::remove_file(&db_path);
+    }
+
+    #[test]
+    fn test_muted_calendars_are_left_out_of_alerts() {
+        let db_path = std::env::temp_dir().join("sol_test_muted_alerts.db");
+        let _ = std::fs::remove_file(&db_path);
+        let db = Arc::new(Mutex::new(Database::open_at(db_path.clone()).unwrap()));
+
+        let mut manager = CalendarManager::with_database(db.clone());
+        for id in ["work", "shared"] {
+            let mut calendar = LocalCalendar::new(id.to_string(), id.to_string(), db.clone());
+            let mut event = make_event(false);
+            event.uid = format!("{}-standup", id);
+            event.alert = AlertTime::FifteenMinutes;
+            calendar.add_event(event).unwrap();
+            manager.add_source(Box::new(calendar));
+        }
+        manager.sources_mut()[1].info_mut().mute_alerts = true;
+
+        // A day either side, whatever the local offset of the display times
+        let (start, end) = (NaiveDate::from_ymd_opt(2025, 3, 9).unwrap(), NaiveDate::from_ymd_opt(2025, 3, 11).unwrap());
+        let uids = |events: Vec<CalendarEvent>| -> Vec<String> {
+            let mut uids: Vec<String> = events.into_iter().map(|e| e.uid).collect();
+            uids.sort();
+            uids
+        };
+        assert_eq!(uids(manager.get_alert_occurrences_between(start, end)), ["work-standup"]);
+        // Muted events are still shown
+        assert_eq!(uids(manager.get_occurrences_between(start, end)), ["shared-standup", "work-standup"]);
+        assert!(manager.alerts_muted("shared"));
+        assert!(!manager.alerts_muted("work"));
+
+        let _ = std::fs::remove_file(&db_path);
+    }
+
+    #[test]
+    fn test_caldav_calendars_need_an_account() {
+        let db_path = std::env::temp_dir().join("sol_test_caldav_without_account.db");
+        let _ = std::fs::remove_file(&db_path);
+        let db = Arc::new(Mutex::new(Database::open_at(db_path.clone()).unwrap()));
+        let manager = CalendarManager::with_database(db);
+
+        // CalDAV calendars are built by the registered factory, which needs the account
+        let config = CalendarConfig {
+            id: "team".to_string(),
+            name: "Team".to_string(),
+            color: "#8B5CF6".to_string(),
+            enabled: true,
+            calendar_type: format!("{:?}", CalendarType::CalDav),
+            default_duration_minutes: None,
+            default_all_day: false,
+            pinned: false,
+            mute_alerts: false,
+            order: 0,
+            account: None,
+        };
+        assert!(manager.source_from_config(&config).is_err());
+
+        let _ = std::fs::remove_file(&db_path);
+    }
+}
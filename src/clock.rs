@@ -0,0 +1,66 @@
+//! Minute-aligned clock ticks
+//!
+//! Drives `Message::TimeTick` (current time indicator, dimming of past events,
+//! alert checks) with one tick right after each wall-clock minute starts.
+//! Sleeps are capped at `MAX_SLEEP` because timers don't advance while the
+//! system is suspended: after a resume the minute is checked again within a
+//! few seconds instead of when the sleep started before suspending runs out.
+
+use chrono::{Local, NaiveDateTime, Timelike};
+use futures_util::stream::{self, Stream};
+use std::time::Duration;
+
+use crate::message::Message;
+
+/// Longest time between two checks of the wall clock
+const MAX_SLEEP: Duration = Duration::from_secs(5);
+
+/// Stream of `Message::TimeTick`, one per wall-clock minute
+pub fn minute_ticks() -> impl Stream<Item = Message> {
+    stream::unfold(minute_of(Local::now().naive_local()), |last| async move {
+        loop {
+            tokio::time::sleep(next_wake(Local::now().naive_local())).await;
+            let minute = minute_of(Local::now().naive_local());
+            if minute != last {
+                return Some((Message::TimeTick, minute));
+            }
+        }
+    })
+}
+
+/// `now` truncated to the minute
+fn minute_of(now: NaiveDateTime) -> NaiveDateTime {
+    now.with_second(0).and_then(|time| time.with_nanosecond(0)).unwrap_or(now)
+}
+
+/// Time from `now` until the next minute starts, at most `MAX_SLEEP`
+fn next_wake(now: NaiveDateTime) -> Duration {
+    // Leap seconds report more than 1s of nanoseconds; treat them as the last instant of the minute
+    let into_minute = Duration::new(now.second() as u64, now.nanosecond().min(999_999_999));
+    Duration::from_secs(60).saturating_sub(into_minute).min(MAX_SLEEP)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn at(second: u32, milli: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 3, 14).unwrap().and_hms_milli_opt(9, 41, second, milli).unwrap()
+    }
+
+    #[test]
+    fn test_wakes_at_the_minute_boundary() {
+        assert_eq!(next_wake(at(57, 500)), Duration::from_millis(2_500));
+        assert_eq!(next_wake(at(59, 999)), Duration::from_millis(1));
+        // Far from the boundary: checked again after the cap
+        assert_eq!(next_wake(at(0, 0)), MAX_SLEEP);
+        assert_eq!(next_wake(at(30, 0)), MAX_SLEEP);
+    }
+
+    #[test]
+    fn test_minute_of_drops_seconds() {
+        assert_eq!(minute_of(at(42, 250)), at(0, 0));
+        assert_ne!(minute_of(at(59, 999)), minute_of(at(59, 999) + chrono::Duration::milliseconds(1)));
+    }
+}
@@ -3,10 +3,26 @@
 use cosmic::iced::Color;
 
 pub const COLOR_DEFAULT_GRAY: Color = Color::from_rgb(107.0/255.0, 114.0/255.0, 128.0/255.0);
-pub const COLOR_BORDER_LIGHT: Color = Color::from_rgba(0.0, 0.0, 0.0, 0.2);
-pub const COLOR_BORDER_SELECTED: Color = Color::from_rgb(0.0, 0.0, 0.0);
+/// Opacity of an unselected color swatch border (the color itself comes from the theme)
+pub const COLOR_SWATCH_BORDER_ALPHA: f32 = 0.2;
 pub const COLOR_DAY_CELL_BORDER: Color = Color::from_rgba(0.5, 0.5, 0.5, 0.2);
+pub const COLOR_DAY_CELL_BORDER_STRONG: Color = Color::from_rgba(0.5, 0.5, 0.5, 0.5); // "Strong" grid line style
 pub const COLOR_WEEKEND_BACKGROUND: Color = Color::from_rgba(0.5, 0.5, 0.5, 0.05); // Subtle gray tint
+/// Dimmed hours outside the working day in week/day views, on top of the weekend tint
+pub const COLOR_OFF_HOURS_BACKGROUND: Color = Color::from_rgba(0.5, 0.5, 0.5, 0.1);
+
+/// Per-event color presets (hex) offered in the event dialog; the calendar color is the default
+pub const EVENT_COLOR_PRESETS: &[&str] = &[
+    "#EF4444", // Red
+    "#F97316", // Orange
+    "#EAB308", // Yellow
+    "#22C55E", // Green
+    "#14B8A6", // Teal
+    "#3B82F6", // Blue
+    "#8B5CF6", // Purple
+    "#EC4899", // Pink
+    "#6B7280", // Gray (COLOR_DEFAULT_GRAY)
+];
 
 /// Blue color for "today" indicator circle - consistent across all themes
 pub const COLOR_TODAY_BLUE: Color = Color::from_rgb(0.0, 122.0/255.0, 255.0/255.0); // #007AFF
@@ -1,14 +1,15 @@
 use cosmic::iced::Length;
-use cosmic::widget::{button, column, container, dialog, row, text_input};
+use cosmic::widget::{button, column, container, dialog, row, text_input, toggler};
 use cosmic::{widget, Element};
 
+use crate::caldav::RecurrenceScope;
 use crate::components::color_picker::{parse_hex_color, QUICK_PICKER_COLORS};
 use crate::dialogs::ActiveDialog;
 use crate::fl;
 use crate::message::Message;
-use crate::styles::color_button_style;
+use crate::styles::{color_button_style, color_swatch_border};
 use crate::ui_constants::{
-    BORDER_WIDTH_HIGHLIGHT, BORDER_WIDTH_SELECTED, COLOR_BORDER_LIGHT, COLOR_BORDER_SELECTED,
+    BORDER_WIDTH_HIGHLIGHT, BORDER_WIDTH_SELECTED,
     COLOR_BUTTON_SIZE_SMALL, COLOR_DEFAULT_GRAY, SPACING_COLOR_GRID,
 };
 
@@ -16,9 +17,9 @@ use crate::ui_constants::{
 /// Takes the active dialog state which should be CalendarCreate or CalendarEdit variant
 pub fn render_calendar_dialog(active_dialog: &ActiveDialog) -> Element<'_, Message> {
     // Extract data from active_dialog
-    let (is_edit_mode, name, current_color) = match active_dialog {
-        ActiveDialog::CalendarCreate { name, color } => (false, name.as_str(), color.as_str()),
-        ActiveDialog::CalendarEdit { name, color, .. } => (true, name.as_str(), color.as_str()),
+    let (is_edit_mode, name, current_color, mute_alerts) = match active_dialog {
+        ActiveDialog::CalendarCreate { name, color, mute_alerts } => (false, name.as_str(), color.as_str(), *mute_alerts),
+        ActiveDialog::CalendarEdit { name, color, mute_alerts, .. } => (true, name.as_str(), color.as_str(), *mute_alerts),
         _ => return widget::text("").into(), // Should not happen
     };
 
@@ -49,18 +50,13 @@ pub fn render_calendar_dialog(active_dialog: &ActiveDialog) -> Element<'_, Messa
             } else {
                 BORDER_WIDTH_HIGHLIGHT
             };
-            let border_color = if is_selected {
-                COLOR_BORDER_SELECTED
-            } else {
-                COLOR_BORDER_LIGHT
-            };
 
             let color_button = button::custom(
                 container(widget::text(""))
                     .width(COLOR_BUTTON_SIZE_SMALL)
                     .height(COLOR_BUTTON_SIZE_SMALL)
-                    .style(move |_theme: &cosmic::Theme| {
-                        color_button_style(color, COLOR_BUTTON_SIZE_SMALL, border_width, border_color)
+                    .style(move |theme: &cosmic::Theme| {
+                        color_button_style(color, COLOR_BUTTON_SIZE_SMALL, border_width, color_swatch_border(theme, is_selected))
                     }),
             )
             .on_press(Message::CalendarDialogColorChanged(hex_owned))
@@ -78,6 +74,13 @@ pub fn render_calendar_dialog(active_dialog: &ActiveDialog) -> Element<'_, Messa
         .push(widget::text(fl!("dialog-calendar-color")))
         .push(color_grid);
 
+    // Muted calendars never fire alerts for their events
+    let mute_alerts_control = row()
+        .spacing(8)
+        .align_y(cosmic::iced::Alignment::Center)
+        .push(widget::text(fl!("dialog-calendar-mute-alerts")).width(Length::Fill))
+        .push(toggler(mute_alerts).on_toggle(Message::CalendarDialogMuteAlertsToggled));
+
     // Dialog title changes based on mode
     let title = if is_edit_mode {
         fl!("dialog-edit-calendar-title")
@@ -97,6 +100,7 @@ pub fn render_calendar_dialog(active_dialog: &ActiveDialog) -> Element<'_, Messa
         .title(title)
         .control(name_control)
         .control(color_control)
+        .control(mute_alerts_control)
         .secondary_action(
             button::text(fl!("button-cancel")).on_press(Message::CancelCalendarDialog),
         )
@@ -142,11 +146,11 @@ pub fn render_delete_event_dialog(active_dialog: &ActiveDialog) -> Element<'_, M
     };
 
     if is_recurring {
-        // For recurring events, show three buttons: Cancel, Delete This One, Delete All
+        // For recurring events, show four buttons: Cancel, Delete This One, This and Following, Delete All
         // The body explains this is a recurring event
         let body_message = fl!("dialog-delete-event-recurring-message");
 
-        // Create a custom button row with three buttons - wrap to allow buttons to adapt
+        // Create a custom button row with four buttons - wrap to allow buttons to adapt
         let button_row = row()
             .spacing(8)
             .push(
@@ -159,6 +163,11 @@ pub fn render_delete_event_dialog(active_dialog: &ActiveDialog) -> Element<'_, M
                     .on_press(Message::DeleteSingleOccurrence)
                     .width(Length::Shrink),
             )
+            .push(
+                button::standard(fl!("button-delete-following-occurrences"))
+                    .on_press(Message::DeleteFollowingOccurrences)
+                    .width(Length::Shrink),
+            )
             .push(
                 button::destructive(fl!("button-delete-all-occurrences"))
                     .on_press(Message::ConfirmDeleteEvent)
@@ -197,3 +206,42 @@ pub fn render_delete_event_dialog(active_dialog: &ActiveDialog) -> Element<'_, M
             .into()
     }
 }
+
+/// Render the prompt asking which occurrences of a recurring event an edit applies to.
+/// Shown over the event dialog, which stays open behind it.
+pub fn render_edit_scope_dialog<'a>() -> Element<'a, Message> {
+    let button_row = row()
+        .spacing(8)
+        .push(
+            button::text(fl!("button-cancel"))
+                .on_press(Message::CancelEditScope)
+                .width(Length::Shrink),
+        )
+        .push(
+            button::standard(fl!("button-edit-this-event"))
+                .on_press(Message::ConfirmEditScope(RecurrenceScope::ThisEvent))
+                .width(Length::Shrink),
+        )
+        .push(
+            button::standard(fl!("button-edit-following-events"))
+                .on_press(Message::ConfirmEditScope(RecurrenceScope::ThisAndFollowing))
+                .width(Length::Shrink),
+        )
+        .push(
+            button::suggested(fl!("button-edit-all-events"))
+                .on_press(Message::ConfirmEditScope(RecurrenceScope::AllEvents))
+                .width(Length::Shrink),
+        );
+
+    let content = column()
+        .spacing(16)
+        .push(widget::text(fl!("dialog-edit-scope-message")))
+        .push(button_row);
+
+    dialog()
+        .title(fl!("dialog-edit-scope-title"))
+        .icon(widget::icon::from_name("view-refresh-symbolic").size(64))
+        .control(content)
+        .width(Length::Fixed(480.0))
+        .into()
+}
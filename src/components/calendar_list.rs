@@ -7,15 +7,23 @@ use crate::calendars::CalendarSource;
 use crate::components::{render_color_indicator, render_quick_color_picker};
 use crate::dialogs::ActiveDialog;
 use crate::fl;
+use crate::keyboard::calendar_toggle_hint;
 use crate::message::Message;
-use crate::ui_constants::{SPACING_MEDIUM, SPACING_SMALL, PADDING_MEDIUM, FONT_SIZE_BODY, PADDING_COLOR_PICKER_NESTED, COLOR_INDICATOR_SIZE};
+use crate::ui_constants::{ICON_SYNCING, SPACING_MEDIUM, SPACING_SMALL, PADDING_MEDIUM, FONT_SIZE_BODY, FONT_SIZE_SMALL, PADDING_COLOR_PICKER_NESTED, COLOR_INDICATOR_SIZE};
 
 /// Context menu actions for calendar items - uses index to avoid Clone/Copy issues with String
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum CalendarContextAction {
     Select(usize),
     Edit(usize),
+    ChangeColor(usize),
     Export(usize),
+    Duplicate(usize),
+    Sync(usize),
+    SetDefault(usize),
+    TogglePin(usize),
+    MoveUp(usize),
+    MoveDown(usize),
     Delete(usize),
 }
 
@@ -26,20 +34,36 @@ impl menu::Action for CalendarContextAction {
         match self {
             CalendarContextAction::Select(index) => Message::SelectCalendarByIndex(*index),
             CalendarContextAction::Edit(index) => Message::EditCalendarByIndex(*index),
+            CalendarContextAction::ChangeColor(index) => Message::ChangeCalendarColorByIndex(*index),
             CalendarContextAction::Export(index) => Message::ExportCalendarByIndex(*index),
+            CalendarContextAction::Duplicate(index) => Message::DuplicateCalendarByIndex(*index),
+            CalendarContextAction::Sync(index) => Message::SyncCalendarByIndex(*index),
+            CalendarContextAction::SetDefault(index) => Message::SetDefaultCalendarByIndex(*index),
+            CalendarContextAction::TogglePin(index) => Message::TogglePinCalendarByIndex(*index),
+            CalendarContextAction::MoveUp(index) => Message::MoveCalendarByIndex(*index, index.saturating_sub(1)),
+            CalendarContextAction::MoveDown(index) => Message::MoveCalendarByIndex(*index, index + 1),
             CalendarContextAction::Delete(index) => Message::DeleteCalendarByIndex(*index),
         }
     }
 }
 
 /// Build context menu items for a calendar by index
-fn calendar_context_menu(index: usize) -> Option<Vec<menu::Tree<Message>>> {
+/// `is_default` and `is_pinned` check the matching entries
+fn calendar_context_menu(index: usize, is_default: bool, is_pinned: bool) -> Option<Vec<menu::Tree<Message>>> {
     Some(menu::items(
         &HashMap::new(),
         vec![
             menu::Item::Button(fl!("calendar-select"), None, CalendarContextAction::Select(index)),
             menu::Item::Button(fl!("calendar-edit"), None, CalendarContextAction::Edit(index)),
+            menu::Item::Button(fl!("calendar-change-color"), None, CalendarContextAction::ChangeColor(index)),
             menu::Item::Button(fl!("calendar-export"), None, CalendarContextAction::Export(index)),
+            menu::Item::Button(fl!("calendar-duplicate"), None, CalendarContextAction::Duplicate(index)),
+            menu::Item::Button(fl!("calendar-sync"), None, CalendarContextAction::Sync(index)),
+            menu::Item::Divider,
+            menu::Item::CheckBox(fl!("calendar-set-default"), None, is_default, CalendarContextAction::SetDefault(index)),
+            menu::Item::CheckBox(fl!("calendar-pin"), None, is_pinned, CalendarContextAction::TogglePin(index)),
+            menu::Item::Button(fl!("calendar-move-up"), None, CalendarContextAction::MoveUp(index)),
+            menu::Item::Button(fl!("calendar-move-down"), None, CalendarContextAction::MoveDown(index)),
             menu::Item::Divider,
             menu::Item::Button(fl!("calendar-delete"), None, CalendarContextAction::Delete(index)),
         ],
@@ -51,6 +75,7 @@ pub fn render_calendar_list<'a>(
     calendars: &'a [Box<dyn CalendarSource>],
     active_dialog: &ActiveDialog,
     selected_calendar_id: Option<&String>,
+    default_calendar_id: Option<&str>,
 ) -> Element<'a, Message> {
     let mut calendar_list = column()
         .spacing(SPACING_MEDIUM)
@@ -62,6 +87,7 @@ pub fn render_calendar_list<'a>(
         let is_enabled = calendar.is_enabled();
         let is_picker_open = active_dialog.color_picker_calendar_id() == Some(&info.id);
         let is_selected = selected_calendar_id.map(|id| id == &info.id).unwrap_or(false);
+        let is_default = default_calendar_id == Some(info.id.as_str());
 
         // Use the color picker component for the indicator
         let color_indicator = render_color_indicator(
@@ -88,17 +114,27 @@ pub fn render_calendar_list<'a>(
             cosmic::theme::Button::Text
         });
 
-        let calendar_row = row()
+        let mut calendar_row = row()
             .spacing(SPACING_SMALL)
             .align_y(cosmic::iced::Alignment::Center)
             .push(checkbox)
             .push(color_indicator)
             .push(name_button);
 
+        // Subtle loading indicator while the calendar syncs
+        if info.loading {
+            calendar_row = calendar_row.push(widget::icon::from_name(ICON_SYNCING).size(16));
+        }
+
+        // Visibility shortcut hint for the first calendars
+        if let Some(hint) = calendar_toggle_hint(index) {
+            calendar_row = calendar_row.push(widget::text::caption(hint).size(FONT_SIZE_SMALL));
+        }
+
         // Wrap in context menu for right-click actions
         let calendar_row_with_context = widget::context_menu(
             calendar_row,
-            calendar_context_menu(index),
+            calendar_context_menu(index, is_default, info.pinned),
         )
         .on_surface_action(Message::Surface);
 
@@ -117,3 +153,26 @@ pub fn render_calendar_list<'a>(
 
     calendar_list.into()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_context_actions_map_to_calendar_messages() {
+        let message = |action: CalendarContextAction| menu::Action::message(&action);
+
+        assert!(matches!(message(CalendarContextAction::Select(1)), Message::SelectCalendarByIndex(1)));
+        assert!(matches!(message(CalendarContextAction::Edit(2)), Message::EditCalendarByIndex(2)));
+        assert!(matches!(message(CalendarContextAction::ChangeColor(3)), Message::ChangeCalendarColorByIndex(3)));
+        assert!(matches!(message(CalendarContextAction::Export(4)), Message::ExportCalendarByIndex(4)));
+        assert!(matches!(message(CalendarContextAction::Duplicate(4)), Message::DuplicateCalendarByIndex(4)));
+        assert!(matches!(message(CalendarContextAction::Sync(5)), Message::SyncCalendarByIndex(5)));
+        assert!(matches!(message(CalendarContextAction::SetDefault(6)), Message::SetDefaultCalendarByIndex(6)));
+        assert!(matches!(message(CalendarContextAction::TogglePin(7)), Message::TogglePinCalendarByIndex(7)));
+        assert!(matches!(message(CalendarContextAction::Delete(8)), Message::DeleteCalendarByIndex(8)));
+        assert!(matches!(message(CalendarContextAction::MoveUp(3)), Message::MoveCalendarByIndex(3, 2)));
+        assert!(matches!(message(CalendarContextAction::MoveUp(0)), Message::MoveCalendarByIndex(0, 0)));
+        assert!(matches!(message(CalendarContextAction::MoveDown(3)), Message::MoveCalendarByIndex(3, 4)));
+    }
+}
@@ -0,0 +1,55 @@
+//! Calendar statistics section
+//!
+//! Table of event counts per calendar for this week, month, and year,
+//! shown below the About information in the context drawer.
+
+use cosmic::iced::Length;
+use cosmic::widget::{column, container, row, text};
+use cosmic::Element;
+
+use crate::fl;
+use crate::message::Message;
+use crate::models::CalendarStats;
+use crate::ui_constants::{FONT_SIZE_BODY, FONT_SIZE_SMALL, SPACING_MEDIUM, SPACING_SMALL};
+
+/// Width of each count column
+const COUNT_COLUMN_WIDTH: f32 = 56.0;
+
+/// Build one table row: calendar name followed by the three counts
+fn stats_row(label: String, counts: [String; 3], size: u16) -> Element<'static, Message> {
+    let mut stats_row = row()
+        .spacing(SPACING_SMALL)
+        .push(container(text(label).size(size)).width(Length::Fill));
+    for count in counts {
+        stats_row = stats_row.push(
+            container(text(count).size(size))
+                .width(Length::Fixed(COUNT_COLUMN_WIDTH))
+                .align_x(cosmic::iced::Alignment::End),
+        );
+    }
+    stats_row.into()
+}
+
+/// Render event counts per calendar for the current week, month, and year
+pub fn render_calendar_stats(stats: &CalendarStats) -> Element<'static, Message> {
+    let mut table = column().spacing(SPACING_SMALL).push(stats_row(
+        fl!("stats-calendar"),
+        [fl!("stats-week"), fl!("stats-month"), fl!("stats-year")],
+        FONT_SIZE_SMALL,
+    ));
+
+    for calendar in &stats.calendars {
+        table = table.push(stats_row(
+            calendar.name.clone(),
+            [calendar.week.to_string(), calendar.month.to_string(), calendar.year.to_string()],
+            FONT_SIZE_BODY,
+        ));
+    }
+
+    column()
+        .spacing(SPACING_MEDIUM)
+        .push(text::title4(fl!("stats-title")))
+        .push(table)
+        .width(Length::Fill)
+        .into()
+}
@@ -0,0 +1,56 @@
+//! Category filter bar
+//!
+//! Row of toggleable chips, one per event category, shown below the toolbar.
+//! Selecting chips limits the calendar views to events with those categories.
+
+use cosmic::iced::Alignment;
+use cosmic::widget::{button, row, text};
+use cosmic::Element;
+
+use crate::fl;
+use crate::message::Message;
+use crate::models::CategoryFilter;
+use crate::ui_constants::{PADDING_SMALL, SPACING_SMALL};
+
+/// Render the category filter bar.
+/// Returns None when no events have categories, so the bar takes no space.
+///
+/// # Arguments
+/// * `categories` - All categories in use, in display order
+/// * `filter` - Current filter selection
+pub fn render_category_filter_bar(
+    categories: &[String],
+    filter: &CategoryFilter,
+) -> Option<Element<'static, Message>> {
+    if categories.is_empty() {
+        return None;
+    }
+
+    let mut chips = row()
+        .spacing(SPACING_SMALL)
+        .padding([0, PADDING_SMALL, PADDING_SMALL, PADDING_SMALL])
+        .align_y(Alignment::Center);
+
+    for category in categories {
+        let is_selected = filter.is_selected(category);
+        chips = chips.push(
+            button::custom(text(category.clone()).size(12))
+                .on_press(Message::ToggleCategoryFilter(category.clone()))
+                .padding([2, 8])
+                .class(if is_selected {
+                    cosmic::theme::Button::Suggested
+                } else {
+                    cosmic::theme::Button::Standard
+                }),
+        );
+    }
+
+    if filter.is_active() {
+        chips = chips.push(
+            button::text(fl!("category-filter-clear"))
+                .on_press(Message::ClearCategoryFilter),
+        );
+    }
+
+    Some(chips.into())
+}
@@ -1,13 +1,15 @@
 use chrono::NaiveDate;
-use cosmic::iced::{alignment, Length, Size};
+use cosmic::iced::{alignment, Color, Length, Size};
 use cosmic::widget::{column, container, mouse_area, responsive};
 use cosmic::{widget, Element};
 
 use crate::components::{
-    render_compact_events, render_unified_events_with_selection, render_quick_event_input, DisplayEvent,
+    render_compact_events, render_unified_events_with_selection, render_quick_event_input, DisplayEvent, EventSelection,
     calculate_display_mode, EventDisplayMode,
 };
+use crate::locale::LocalePreferences;
 use crate::message::Message;
+use crate::settings::{EventTextSize, GridLineStyle};
 use crate::styles::{
     today_circle_style, selected_day_style, day_cell_style, adjacent_month_day_style,
     adjacent_month_selected_style, selection_highlight_style, adjacent_month_selection_style,
@@ -23,7 +25,7 @@ const PADDING_DAY_CELL_VERTICAL: [u16; 4] = [PADDING_DAY_CELL[0], 0, PADDING_DAY
 
 /// Apply the appropriate style to a day cell container based on state
 /// Today no longer gets special cell styling - the circle is on the day number
-/// Selected gets a border, drag selection gets highlight, drop target gets accent highlight,
+/// Selected gets a border, drag selection gets highlight, drop target gets the dragged event's color,
 /// regular cells get weekend background
 /// Uses vertical-only padding so all-day events can span edge-to-edge
 fn apply_day_cell_style<'a>(
@@ -31,7 +33,9 @@ fn apply_day_cell_style<'a>(
     is_selected: bool,
     is_in_selection: bool,
     is_drag_target: bool,
+    drag_color: Option<Color>,
     is_weekend: bool,
+    grid_lines: GridLineStyle,
 ) -> container::Container<'a, Message, cosmic::Theme> {
     let base = container(content)
         .padding(PADDING_DAY_CELL_VERTICAL) // Vertical padding only, horizontal handled per-element
@@ -40,16 +44,27 @@ fn apply_day_cell_style<'a>(
 
     if is_drag_target {
         // Drop target takes priority - show where the event will land
-        base.style(move |theme: &cosmic::Theme| drag_target_style(theme, is_weekend))
+        base.style(move |theme: &cosmic::Theme| drag_target_style(theme, is_weekend, drag_color))
     } else if is_selected {
         base.style(move |theme: &cosmic::Theme| selected_day_style(theme, is_weekend))
     } else if is_in_selection {
         base.style(move |theme: &cosmic::Theme| selection_highlight_style(theme, is_weekend))
     } else {
-        base.style(move |_theme: &cosmic::Theme| day_cell_style(is_weekend))
+        base.style(move |_theme: &cosmic::Theme| day_cell_style(is_weekend, grid_lines))
     }
 }
 
+/// "+N more" label for hidden events; a press opens the popover with all events of `date`
+/// instead of starting a drag selection on the cell
+fn overflow_indicator(label: String, size: f32, date: NaiveDate) -> Element<'static, Message> {
+    mouse_area(
+        container(widget::text(label).size(size))
+            .padding([0, PADDING_DAY_CELL[1], 0, PADDING_DAY_CELL[3]])
+    )
+    .on_press(Message::OpenDayEvents(date))
+    .into()
+}
+
 /// Configuration for rendering a day cell with events
 pub struct DayCellConfig {
     pub year: i32,
@@ -76,14 +91,24 @@ pub struct DayCellConfig {
     pub is_in_selection: bool,
     /// Whether a drag selection is currently active
     pub selection_active: bool,
-    /// Currently selected event UID (for visual feedback)
-    pub selected_event_uid: Option<String>,
+    /// Ids of the selected events (for visual feedback)
+    pub selected_event_ids: Vec<String>,
     /// Whether an event drag operation is currently active
     pub event_drag_active: bool,
     /// The UID of the event currently being dragged (for dimming its original position)
     pub dragging_event_uid: Option<String>,
     /// Whether this cell is the current drop target
     pub is_drag_target: bool,
+    /// Color of the event being dragged, used to tint the drop target
+    pub drag_color: Option<Color>,
+    /// User cap on events shown before "+N more" (None = as many as fit)
+    pub max_events_cap: Option<usize>,
+    /// Grid line style for the cell border
+    pub grid_lines: GridLineStyle,
+    /// Font size of event labels
+    pub event_text_size: EventTextSize,
+    /// Locale used to format event times
+    pub locale: LocalePreferences,
 }
 
 /// Render a day cell with events and optional quick event input
@@ -93,7 +118,7 @@ pub fn render_day_cell_with_events(config: DayCellConfig) -> Element<'static, Me
 
     // Use responsive to get actual cell dimensions and adapt display
     let cell_content = responsive(move |size: Size| {
-        let display_mode = calculate_display_mode(size);
+        let display_mode = calculate_display_mode(size).with_max_events_cap(config.max_events_cap);
 
         // Day number - with circle background if today (only for current month)
         let day_number: Element<'static, Message> = if config.is_today && !config.is_adjacent_month {
@@ -161,15 +186,13 @@ pub fn render_day_cell_with_events(config: DayCellConfig) -> Element<'static, Me
                     }
 
                     // Show overflow count as small number if there are hidden events
-                    // (only if cell is tall enough)
+                    // (only if cell is tall enough); clicking it lists all events
                     if show_overflow && compact_events.overflow_count > 0 {
-                        content = content.push(
-                            container(
-                                widget::text(format!("+{}", compact_events.overflow_count))
-                                    .size(8)
-                            )
-                            .padding([0, PADDING_DAY_CELL[1], 0, PADDING_DAY_CELL[3]])
-                        );
+                        content = content.push(overflow_indicator(
+                            format!("+{}", compact_events.overflow_count),
+                            8.0,
+                            current_date,
+                        ));
                     }
                 } else {
                     // Full mode: Tetris-style rendering with timed events filling empty slots
@@ -179,9 +202,11 @@ pub fn render_day_cell_with_events(config: DayCellConfig) -> Element<'static, Me
                         current_date,
                         config.week_max_slot,
                         &config.day_occupied_slots,
-                        config.selected_event_uid.as_deref(),
+                        EventSelection { event: None, batch: &config.selected_event_ids },
                         config.event_drag_active,
                         config.dragging_event_uid.as_deref(),
+                        config.event_text_size,
+                        &config.locale,
                     );
 
                     // Single container for all events (placeholders + timed)
@@ -193,21 +218,21 @@ pub fn render_day_cell_with_events(config: DayCellConfig) -> Element<'static, Me
                         content = content.push(events_container);
                     }
 
-                    // Show "+N more" if there are hidden events (only if cell is tall enough)
+                    // Show "+N more" if there are hidden events (only if cell is tall enough);
+                    // clicking it lists all events
                     if show_overflow && unified.overflow_count > 0 {
-                        content = content.push(
-                            container(
-                                widget::text(format!("+{} more", unified.overflow_count))
-                                    .size(10)
-                            )
-                            .padding([0, PADDING_DAY_CELL[1], 0, PADDING_DAY_CELL[3]])
-                        );
+                        content = content.push(overflow_indicator(
+                            format!("+{} more", unified.overflow_count),
+                            10.0,
+                            current_date,
+                        ));
                     }
                 }
             }
         }
 
         // Build styled container based on state
+        let grid_lines = config.grid_lines;
         let styled: Element<'static, Message> = if config.is_adjacent_month {
             // Adjacent month: grayed out style, but show selection/highlight if applicable
             if config.is_selected {
@@ -229,17 +254,19 @@ pub fn render_day_cell_with_events(config: DayCellConfig) -> Element<'static, Me
                     .padding(PADDING_DAY_CELL_VERTICAL)
                     .width(Length::Fill)
                     .height(Length::Fill)
-                    .style(|_theme: &cosmic::Theme| adjacent_month_day_style())
+                    .style(move |_theme: &cosmic::Theme| adjacent_month_day_style(grid_lines))
                     .into()
             }
         } else {
-            // Current month: normal styling (selected gets border, selection gets highlight, drop target gets accent)
+            // Current month: normal styling (selected gets border, selection gets highlight, drop target gets event color)
             apply_day_cell_style(
                 content,
                 config.is_selected,
                 config.is_in_selection,
                 config.is_drag_target,
+                config.drag_color,
                 config.is_weekend,
+                config.grid_lines,
             ).into()
         };
 
@@ -252,8 +279,8 @@ pub fn render_day_cell_with_events(config: DayCellConfig) -> Element<'static, Me
         let mut area = mouse_area(cell_content)
             // Start drag selection on mouse press (only if not dragging an event)
             .on_press(Message::SelectionStart(date))
-            // Double-click opens quick event for single-day creation
-            .on_double_click(Message::StartQuickEvent(date));
+            // Double-click creates an event (quick input or dialog, per settings)
+            .on_double_click(Message::CreateEventAt(date, None));
 
         // Handle release: either end selection or end event drag
         if config.event_drag_active {
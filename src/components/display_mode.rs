@@ -63,6 +63,26 @@ impl EventDisplayMode {
             EventDisplayMode::Compact { show_overflow, .. } => *show_overflow,
         }
     }
+
+    /// Apply the user's per-day event cap on top of the layout-derived limit.
+    /// The cap only lowers the limit; a cell never shows more than fits.
+    pub fn with_max_events_cap(self, cap: Option<usize>) -> Self {
+        let Some(cap) = cap else { return self };
+        let cap = cap.max(1);
+        match self {
+            EventDisplayMode::Full { max_visible, show_overflow } => {
+                EventDisplayMode::Full { max_visible: max_visible.min(cap), show_overflow }
+            }
+            EventDisplayMode::Compact { max_visible, show_overflow } => {
+                EventDisplayMode::Compact { max_visible: max_visible.min(cap), show_overflow }
+            }
+        }
+    }
+}
+
+/// Number of events hidden behind the "+N more" indicator
+pub fn overflow_count(total_events: usize, shown: usize) -> usize {
+    total_events.saturating_sub(shown)
 }
 
 /// Calculate the event display mode based on cell dimensions.
@@ -121,3 +141,36 @@ pub fn should_use_compact(cell_width: f32, cell_height: f32) -> bool {
     cell_height < MIN_CELL_HEIGHT_FOR_FULL_EVENTS
         || cell_width < MIN_CELL_WIDTH_FOR_FULL_EVENTS
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_events_cap_lowers_limit() {
+        let mode = EventDisplayMode::Full { max_visible: 6, show_overflow: true };
+        assert_eq!(mode.with_max_events_cap(Some(3)).max_visible(), 3);
+        assert!(mode.with_max_events_cap(Some(3)).show_overflow());
+
+        let compact = EventDisplayMode::Compact { max_visible: 4, show_overflow: false };
+        assert_eq!(compact.with_max_events_cap(Some(2)), EventDisplayMode::Compact { max_visible: 2, show_overflow: false });
+    }
+
+    #[test]
+    fn test_max_events_cap_never_raises_limit() {
+        let mode = EventDisplayMode::Full { max_visible: 2, show_overflow: true };
+        assert_eq!(mode.with_max_events_cap(Some(5)).max_visible(), 2);
+        assert_eq!(mode.with_max_events_cap(None).max_visible(), 2);
+        // A zero cap still shows one event
+        assert_eq!(mode.with_max_events_cap(Some(0)).max_visible(), 1);
+    }
+
+    #[test]
+    fn test_overflow_count_with_cap() {
+        let mode = EventDisplayMode::Full { max_visible: 8, show_overflow: true }
+            .with_max_events_cap(Some(3));
+        assert_eq!(overflow_count(5, mode.max_visible()), 2);
+        assert_eq!(overflow_count(3, mode.max_visible()), 0);
+        assert_eq!(overflow_count(1, mode.max_visible()), 0);
+    }
+}
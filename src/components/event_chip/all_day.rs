@@ -8,6 +8,7 @@ use cosmic::widget::container;
 use cosmic::{widget, Element};
 
 use crate::message::Message;
+use crate::settings::EventTextSize;
 use crate::ui_constants::{BORDER_RADIUS, BORDER_WIDTH_HIGHLIGHT};
 
 use super::types::{ChipOpacity, ChipSelectionState, SpanPosition, span_border_radius, span_padding};
@@ -19,17 +20,19 @@ use super::types::{ChipOpacity, ChipSelectionState, SpanPosition, span_border_ra
 /// * `color` - Event calendar color
 /// * `span_position` - Position within a multi-day span (affects border radius)
 /// * `selection` - Optional selection state for interactive chips; None for simple display
+/// * `text_size` - Font size of the label
 pub fn render_all_day_chip(
     summary: String,
     color: cosmic::iced::Color,
     span_position: SpanPosition,
     selection: Option<ChipSelectionState>,
+    text_size: EventTextSize,
 ) -> Element<'static, Message> {
     let border_radius = span_border_radius(span_position, BORDER_RADIUS[0]);
     let padding = span_padding(span_position);
 
     let content: Element<'static, Message> = widget::text(summary)
-        .size(11)
+        .size(text_size.chip_size())
         .wrapping(Wrapping::None)
         .into();
 
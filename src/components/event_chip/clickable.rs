@@ -3,14 +3,18 @@
 //! Wraps event chips with mouse interaction for selection and dragging.
 
 use chrono::{Local, NaiveDate};
-use cosmic::widget::mouse_area;
-use cosmic::Element;
+use cosmic::widget::{container, mouse_area, tooltip};
+use cosmic::{widget, Element};
 
 use crate::components::color_picker::parse_hex_color;
+use crate::fl;
+use crate::locale::LocalePreferences;
 use crate::message::Message;
+use crate::settings::EventTextSize;
 use crate::ui_constants::COLOR_DEFAULT_GRAY;
 
 use super::all_day::render_all_day_chip;
+use super::status::apply_status_style;
 use super::timed::render_timed_event_chip;
 use super::types::{ChipSelectionState, DisplayEvent};
 
@@ -21,8 +25,9 @@ use super::types::{ChipSelectionState, DisplayEvent};
 /// # Arguments
 /// * `event` - The display event with span metadata
 /// * `current_date` - The date of the cell being rendered (for span position calculation)
+/// * `locale` - Formats event times in the user's clock style
 #[allow(dead_code)]
-pub fn render_event_chip(event: DisplayEvent, current_date: NaiveDate) -> Element<'static, Message> {
+pub fn render_event_chip(event: DisplayEvent, current_date: NaiveDate, locale: &LocalePreferences) -> Element<'static, Message> {
     let color = parse_hex_color(&event.color).unwrap_or(COLOR_DEFAULT_GRAY);
 
     // Check if this event is in the past
@@ -31,9 +36,9 @@ pub fn render_event_chip(event: DisplayEvent, current_date: NaiveDate) -> Elemen
     if event.all_day {
         // Calculate span position for multi-day events
         let span_position = event.span_position_for_date(current_date);
-        render_all_day_chip(event.summary, color, span_position, None)
+        render_all_day_chip(event.summary, color, span_position, None, EventTextSize::default())
     } else {
-        render_timed_event_chip(event.summary, event.start_time, color, None, is_past)
+        render_timed_event_chip(event.summary, event.start_time, color, None, is_past, EventTextSize::default(), locale)
     }
 }
 
@@ -63,6 +68,39 @@ fn is_event_past(event: &DisplayEvent, current_date: NaiveDate) -> bool {
     }
 }
 
+/// Build the tooltip text for an event chip: summary, time range, and location.
+/// Chips clip long summaries, so the tooltip recovers the full details.
+///
+/// # Arguments
+/// * `event` - The display event
+/// * `all_day_label` - Localized label used in place of a time range for all-day events
+/// * `timezone_fallback_label` - Localized note shown when the event's time zone is unknown
+/// * `locale` - Formats the time range in the user's clock style
+pub fn tooltip_text(event: &DisplayEvent, all_day_label: &str, timezone_fallback_label: &str, locale: &LocalePreferences) -> String {
+    let mut lines = vec![event.summary.clone()];
+
+    let time_line = if event.all_day {
+        Some(all_day_label.to_string())
+    } else {
+        match (event.start_time, event.end_time) {
+            (Some(start), Some(end)) => Some(format!("{} – {}", locale.format_time(&start), locale.format_time(&end))),
+            (Some(start), None) => Some(locale.format_time(&start)),
+            _ => None,
+        }
+    };
+    lines.extend(time_line);
+
+    if let Some(location) = event.location.as_ref().filter(|l| !l.trim().is_empty()) {
+        lines.push(location.clone());
+    }
+
+    if event.timezone_fallback {
+        lines.push(timezone_fallback_label.to_string());
+    }
+
+    lines.join("\n")
+}
+
 /// Render a clickable event chip with selection state and drag support
 /// Wraps the event chip with mouse interaction for selection and dragging
 ///
@@ -72,12 +110,16 @@ fn is_event_past(event: &DisplayEvent, current_date: NaiveDate) -> bool {
 /// * `is_selected` - Whether this event is currently selected
 /// * `is_drag_active` - Whether any event drag is currently active
 /// * `is_being_dragged` - Whether this specific event is currently being dragged (for dimming)
+/// * `text_size` - Font size of the chip label
+/// * `locale` - Formats event times in the user's clock style
 pub fn render_clickable_event_chip(
     event: DisplayEvent,
     current_date: NaiveDate,
     is_selected: bool,
     is_drag_active: bool,
     is_being_dragged: bool,
+    text_size: EventTextSize,
+    locale: &LocalePreferences,
 ) -> Element<'static, Message> {
     let calendar_id = event.calendar_id.clone();
     let uid = event.uid.clone();
@@ -85,6 +127,8 @@ pub fn render_clickable_event_chip(
     // Clone summary and color_hex for the drag preview message (before they're moved into chip)
     let drag_summary = event.summary.clone();
     let drag_color = event.color.clone();
+    let tooltip_content = tooltip_text(&event, &fl!("event-all-day"), &fl!("event-timezone-fallback"), locale);
+    let status = event.status;
 
     // Check if this event is in the past
     let is_past = is_event_past(&event, current_date);
@@ -93,10 +137,11 @@ pub fn render_clickable_event_chip(
 
     let chip = if event.all_day {
         let span_position = event.span_position_for_date(current_date);
-        render_all_day_chip(event.summary, color, span_position, selection)
+        render_all_day_chip(event.summary, color, span_position, selection, text_size)
     } else {
-        render_timed_event_chip(event.summary, event.start_time, color, selection, is_past)
+        render_timed_event_chip(event.summary, event.start_time, color, selection, is_past, text_size, locale)
     };
+    let chip = apply_status_style(chip, status, color);
 
     // Wrap with mouse area for click/drag handling
     // - on_press: Start drag (will be resolved as select or move on release)
@@ -111,7 +156,93 @@ pub fn render_clickable_event_chip(
     // Only track mouse enter during active drag for performance
     if is_drag_active {
         area = area.on_enter(Message::DragEventUpdate(current_date));
+        // No tooltip while dragging - it would cover the drop targets
+        return area.into();
     }
 
-    area.into()
+    // Show full summary, time, and location on hover (chip text is clipped)
+    tooltip(
+        area,
+        container(widget::text(tooltip_content).size(12)).padding([4, 8]),
+        tooltip::Position::Top,
+    )
+    .class(cosmic::theme::Container::Tooltip)
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveTime, Weekday};
+
+    fn locale_24h() -> LocalePreferences {
+        LocalePreferences {
+            use_24_hour: true,
+            first_day_of_week: Weekday::Mon,
+            weekend_days: vec![Weekday::Sat, Weekday::Sun],
+            date_format: crate::locale::DateFormat::DMY,
+            locale_string: "de_DE.UTF-8".to_string(),
+        }
+    }
+
+    fn make_event(all_day: bool, location: Option<&str>) -> DisplayEvent {
+        DisplayEvent {
+            calendar_id: "cal".to_string(),
+            uid: "uid".to_string(),
+            summary: "A rather long meeting title that gets clipped".to_string(),
+            all_day,
+            start_time: NaiveTime::from_hms_opt(9, 0, 0),
+            end_time: NaiveTime::from_hms_opt(10, 30, 0),
+            location: location.map(String::from),
+            ..DisplayEvent::test_default()
+        }
+    }
+
+    #[test]
+    fn test_tooltip_text_timed_with_location() {
+        let event = make_event(false, Some("Room 4"));
+        assert_eq!(
+            tooltip_text(&event, "All day", "Unknown time zone", &locale_24h()),
+            "A rather long meeting title that gets clipped\n09:00 – 10:30\nRoom 4"
+        );
+    }
+
+    #[test]
+    fn test_tooltip_text_uses_clock_style() {
+        let event = make_event(false, None);
+        let locale_12h = LocalePreferences { use_24_hour: false, ..locale_24h() };
+        assert_eq!(
+            tooltip_text(&event, "All day", "Unknown time zone", &locale_12h),
+            "A rather long meeting title that gets clipped\n9:00 AM – 10:30 AM"
+        );
+    }
+
+    #[test]
+    fn test_tooltip_text_all_day_without_location() {
+        let event = make_event(true, None);
+        assert_eq!(
+            tooltip_text(&event, "All day", "Unknown time zone", &locale_24h()),
+            "A rather long meeting title that gets clipped\nAll day"
+        );
+    }
+
+    #[test]
+    fn test_tooltip_text_skips_blank_location() {
+        let mut event = make_event(false, Some("  "));
+        event.end_time = None;
+        assert_eq!(
+            tooltip_text(&event, "All day", "Unknown time zone", &locale_24h()),
+            "A rather long meeting title that gets clipped\n09:00"
+        );
+    }
+
+    #[test]
+    fn test_tooltip_text_notes_timezone_fallback() {
+        let mut event = make_event(false, None);
+        event.timezone_fallback = true;
+        assert_eq!(
+            tooltip_text(&event, "All day", "Unknown time zone", &locale_24h()),
+            "A rather long meeting title that gets clipped\n09:00 – 10:30\nUnknown time zone"
+        );
+    }
 }
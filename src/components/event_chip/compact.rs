@@ -8,6 +8,7 @@ use cosmic::widget::{column, container, row};
 use cosmic::{widget, Element};
 
 use crate::components::color_picker::parse_hex_color;
+use crate::components::overflow_count;
 use crate::message::Message;
 use crate::ui_constants::{
     SPACING_TINY, COLOR_DEFAULT_GRAY, COMPACT_EVENT_HEIGHT, DATE_EVENT_SPACING,
@@ -130,11 +131,7 @@ pub fn render_compact_events(
         has_content = true;
     }
 
-    let overflow_count = if total_events > shown {
-        total_events - shown
-    } else {
-        0
-    };
+    let overflow_count = overflow_count(total_events, shown);
 
     CompactEventsResult {
         element: if has_content { Some(col.into()) } else { None },
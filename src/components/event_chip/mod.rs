@@ -1,10 +1,11 @@
 //! Event chip rendering module
 //!
 //! This module contains all event chip rendering logic, organized into submodules:
-//! - `types`: Core types (SpanPosition, ChipOpacity, ChipSelectionState, DisplayEvent)
+//! - `types`: Core types (SpanPosition, ChipOpacity, ChipSelectionState, EventSelection, DisplayEvent)
 //! - `all_day`: All-day event chip rendering
 //! - `timed`: Timed event chip rendering
 //! - `clickable`: Clickable event chip wrapper
+//! - `status`: Status decoration (cancelled/tentative)
 //! - `quick_event`: Quick event input fields
 //! - `unified`: Unified events column rendering
 //! - `compact`: Compact events rendering
@@ -13,15 +14,18 @@ mod all_day;
 mod clickable;
 mod compact;
 mod quick_event;
+mod status;
 mod timed;
 mod types;
 mod unified;
 
 // Re-export public types (only what's actually used externally)
-pub use types::{ChipOpacity, DisplayEvent, span_border_radius_from_flags};
+pub use types::{ChipOpacity, DisplayEvent, EventSelection, span_border_radius_from_flags};
 
 // Re-export rendering functions (only what's actually used externally)
 pub use compact::render_compact_events;
+pub use status::apply_status_style;
+pub use clickable::tooltip_text;
 pub use quick_event::{
     quick_event_input_id, render_quick_event_input, render_spanning_quick_event_input,
 };
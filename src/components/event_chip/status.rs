@@ -0,0 +1,121 @@
+//! Event status decoration
+//!
+//! Overlays status-specific styling on event chips:
+//! cancelled events are struck through, tentative events are hatched.
+
+use cosmic::iced::widget::stack;
+use cosmic::iced::{alignment, Length};
+use cosmic::widget::{container, row};
+use cosmic::Element;
+
+use crate::caldav::EventStatus;
+use crate::message::Message;
+
+/// Number of stripes drawn across a tentative event chip
+const HATCH_STRIPE_COUNT: usize = 24;
+
+/// Opacity of the hatch stripes over the chip background
+const HATCH_STRIPE_OPACITY: f32 = 0.25;
+
+/// Thickness of the strike-through line for cancelled events
+const STRIKETHROUGH_HEIGHT: f32 = 1.0;
+
+/// Visual decoration applied to a chip based on its event status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct StatusStyle {
+    /// Draw a line through the chip (cancelled events)
+    pub strikethrough: bool,
+    /// Draw a striped pattern over the chip (tentative events)
+    pub hatched: bool,
+}
+
+impl StatusStyle {
+    /// Decide how a chip should be decorated for the given status
+    pub fn for_status(status: EventStatus) -> Self {
+        match status {
+            EventStatus::Confirmed => Self::default(),
+            EventStatus::Tentative => Self { hatched: true, ..Self::default() },
+            EventStatus::Cancelled => Self { strikethrough: true, ..Self::default() },
+        }
+    }
+
+    /// Whether the chip needs no decoration
+    pub fn is_plain(&self) -> bool {
+        !self.strikethrough && !self.hatched
+    }
+}
+
+/// Decorate an event chip according to its status.
+/// Confirmed events are returned unchanged; the decoration is drawn as an
+/// overlay so it works for every chip shape (all-day bars, timed chips, blocks).
+///
+/// # Arguments
+/// * `chip` - The rendered chip
+/// * `status` - Event status
+/// * `color` - Chip text/calendar color used for the decoration
+pub fn apply_status_style(
+    chip: Element<'static, Message>,
+    status: EventStatus,
+    color: cosmic::iced::Color,
+) -> Element<'static, Message> {
+    let style = StatusStyle::for_status(status);
+    if style.is_plain() {
+        return chip;
+    }
+
+    let mut layers: Vec<Element<'static, Message>> = vec![chip];
+
+    if style.hatched {
+        let stripes = (0..HATCH_STRIPE_COUNT).fold(row().width(Length::Fill).height(Length::Fill), |stripes, i| {
+            let alpha = if i % 2 == 0 { HATCH_STRIPE_OPACITY } else { 0.0 };
+            stripes.push(
+                container(cosmic::widget::Space::new(Length::Fill, Length::Fill))
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .style(move |_theme: &cosmic::Theme| container::Style {
+                        background: Some(cosmic::iced::Background::Color(color.scale_alpha(alpha))),
+                        ..Default::default()
+                    }),
+            )
+        });
+        layers.push(stripes.into());
+    }
+
+    if style.strikethrough {
+        let line = container(cosmic::widget::Space::new(Length::Fill, Length::Fixed(STRIKETHROUGH_HEIGHT)))
+            .width(Length::Fill)
+            .height(Length::Fixed(STRIKETHROUGH_HEIGHT))
+            .style(move |_theme: &cosmic::Theme| container::Style {
+                background: Some(cosmic::iced::Background::Color(color)),
+                ..Default::default()
+            });
+        layers.push(
+            container(line)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .align_y(alignment::Vertical::Center)
+                .into(),
+        );
+    }
+
+    // Size the stack to the chip - the overlays fill whatever space it takes
+    stack(layers).height(Length::Shrink).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_style_per_status() {
+        assert!(StatusStyle::for_status(EventStatus::Confirmed).is_plain());
+        assert_eq!(
+            StatusStyle::for_status(EventStatus::Tentative),
+            StatusStyle { strikethrough: false, hatched: true }
+        );
+        assert_eq!(
+            StatusStyle::for_status(EventStatus::Cancelled),
+            StatusStyle { strikethrough: true, hatched: false }
+        );
+    }
+}
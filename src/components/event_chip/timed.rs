@@ -8,7 +8,9 @@ use cosmic::iced::widget::text::Wrapping;
 use cosmic::widget::{container, row};
 use cosmic::{widget, Element};
 
+use crate::locale::LocalePreferences;
 use crate::message::Message;
+use crate::settings::EventTextSize;
 use crate::ui_constants::{SPACING_XXS, BORDER_RADIUS, BORDER_WIDTH_HIGHLIGHT};
 
 use super::types::{ChipOpacity, ChipSelectionState};
@@ -24,12 +26,16 @@ const TIMED_EVENT_DOT_SIZE: f32 = 8.0;
 /// * `color` - Event calendar color
 /// * `selection` - Optional selection state for interactive chips; None for simple display
 /// * `is_past` - Whether this event is in the past (for dimming)
+/// * `text_size` - Font size of the label
+/// * `locale` - Formats the start time in the user's clock style
 pub fn render_timed_event_chip(
     summary: String,
     start_time: Option<NaiveTime>,
     color: cosmic::iced::Color,
     selection: Option<ChipSelectionState>,
     is_past: bool,
+    text_size: EventTextSize,
+    locale: &LocalePreferences,
 ) -> Element<'static, Message> {
     // Calculate opacity based on selection state and past status
     let is_being_dragged = selection.map_or(false, |s| s.is_being_dragged);
@@ -57,13 +63,13 @@ pub fn render_timed_event_chip(
 
     // Format time if available
     let display_text = if let Some(time) = start_time {
-        format!("{} {}", time.format("%H:%M"), summary)
+        format!("{} {}", locale.format_time(&time), summary)
     } else {
         summary
     };
 
     let text = widget::text(display_text)
-        .size(11)
+        .size(text_size.chip_size())
         .wrapping(Wrapping::None); // Prevent text from wrapping to next line
 
     // Text opacity for past events
@@ -4,6 +4,9 @@
 
 use chrono::{NaiveDate, NaiveTime};
 
+use crate::caldav::EventStatus;
+use crate::services::BUSY_EVENT_UID_PREFIX;
+
 /// Position within a multi-day event span
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SpanPosition {
@@ -72,6 +75,9 @@ pub struct ChipOpacity {
 /// Multiplier for dimming past events (applied to both background and text)
 const PAST_EVENT_DIM_FACTOR: f32 = 0.5;
 
+/// Multiplier for muting busy blocks imported from free/busy feeds
+const BUSY_BLOCK_DIM_FACTOR: f32 = 0.5;
+
 impl ChipOpacity {
     /// Calculate opacity values based on selection and drag state.
     /// - Dragging: very dim background (0.15), dim text (0.4) to show event is "in flight"
@@ -120,6 +126,13 @@ impl ChipOpacity {
         let bg = if is_past { base_bg * PAST_EVENT_DIM_FACTOR } else { base_bg };
         (bg, border)
     }
+
+    /// Get background opacity for busy blocks from free/busy feeds.
+    /// Busy blocks carry no details, so they stay muted to read as "occupied" rather than as events.
+    pub fn busy_block_opacity(is_selected: bool, is_past: bool) -> (f32, f32) {
+        let (bg, border) = Self::timed_event_opacity(is_selected, is_past);
+        (bg * BUSY_BLOCK_DIM_FACTOR, border)
+    }
 }
 
 /// Selection and drag state for event chips.
@@ -140,6 +153,33 @@ impl ChipSelectionState {
     }
 }
 
+/// Events drawn as selected: the clicked event and any Select All batch.
+/// Ids have format "calendar_id:uid", as returned by `DisplayEvent::unique_id`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EventSelection<'a> {
+    /// Event selected by clicking it
+    pub event: Option<&'a str>,
+    /// Events selected together (Ctrl+A)
+    pub batch: &'a [String],
+}
+
+impl EventSelection<'_> {
+    /// Whether the chip with this unique id is selected
+    pub fn contains(&self, unique_id: &str) -> bool {
+        self.event == Some(unique_id) || self.batch.iter().any(|id| id == unique_id)
+    }
+
+    /// Whether no event is selected
+    pub fn is_empty(&self) -> bool {
+        self.event.is_none() && self.batch.is_empty()
+    }
+
+    /// Owned copy of the selected ids, for views rendered after the borrow ends
+    pub fn to_ids(self) -> Vec<String> {
+        self.event.map(str::to_string).into_iter().chain(self.batch.iter().cloned()).collect()
+    }
+}
+
 /// Event with associated calendar color for display
 #[derive(Debug, Clone)]
 pub struct DisplayEvent {
@@ -152,8 +192,16 @@ pub struct DisplayEvent {
     pub end_time: Option<NaiveTime>,   // End time for timed events (for week/day view positioning)
     /// Start date of the event span (for multi-day events)
     pub span_start: Option<NaiveDate>,
-    /// End date of the event span (for multi-day events)
+    /// Last date of the event span, inclusive (for multi-day events)
     pub span_end: Option<NaiveDate>,
+    /// Event location (shown in the hover tooltip)
+    pub location: Option<String>,
+    /// Event status (cancelled/tentative events are decorated)
+    pub status: EventStatus,
+    /// The event's time zone couldn't be resolved and its times are shown in UTC
+    pub timezone_fallback: bool,
+    /// Event categories/tags (used by the category filter)
+    pub categories: Vec<String>,
 }
 
 impl DisplayEvent {
@@ -163,6 +211,11 @@ impl DisplayEvent {
         format!("{}:{}", self.calendar_id, self.uid)
     }
 
+    /// Check if this is a synthetic busy block imported from a free/busy feed
+    pub fn is_busy_block(&self) -> bool {
+        self.uid.starts_with(BUSY_EVENT_UID_PREFIX)
+    }
+
     /// Check if this is a multi-day all-day event
     pub fn is_multi_day(&self) -> bool {
         self.all_day
@@ -171,6 +224,14 @@ impl DisplayEvent {
             && self.span_start != self.span_end
     }
 
+    /// Check if this is a timed event running over several days
+    pub fn is_multi_day_timed(&self) -> bool {
+        !self.all_day
+            && self.span_start.is_some()
+            && self.span_end.is_some()
+            && self.span_start != self.span_end
+    }
+
     /// Get the span position for a given date within this event
     pub fn span_position_for_date(&self, date: NaiveDate) -> SpanPosition {
         match (self.span_start, self.span_end) {
@@ -189,3 +250,26 @@ impl DisplayEvent {
         }
     }
 }
+
+#[cfg(test)]
+impl DisplayEvent {
+    /// A one-hour timed event on a single day, for tests to fill in with
+    /// struct update syntax
+    pub fn test_default() -> Self {
+        DisplayEvent {
+            calendar_id: "test-calendar".to_string(),
+            uid: "test-event".to_string(),
+            summary: "Test Event".to_string(),
+            color: "#3B82F6".to_string(),
+            all_day: false,
+            start_time: NaiveTime::from_hms_opt(10, 0, 0),
+            end_time: NaiveTime::from_hms_opt(11, 0, 0),
+            span_start: None,
+            span_end: None,
+            location: None,
+            status: EventStatus::Confirmed,
+            timezone_fallback: false,
+            categories: vec![],
+        }
+    }
+}
@@ -8,11 +8,14 @@ use cosmic::iced::Length;
 use cosmic::widget::{column, container};
 use cosmic::{widget, Element};
 
+use crate::components::overflow_count;
+use crate::locale::LocalePreferences;
 use crate::message::Message;
+use crate::settings::EventTextSize;
 use crate::ui_constants::{DATE_EVENT_HEIGHT, DATE_EVENT_SPACING};
 
 use super::clickable::render_clickable_event_chip;
-use super::types::DisplayEvent;
+use super::types::{DisplayEvent, EventSelection};
 
 /// Result containing a unified events column with placeholders and timed events
 pub struct UnifiedEventsResult {
@@ -22,6 +25,54 @@ pub struct UnifiedEventsResult {
     pub overflow_count: usize,
 }
 
+/// What a row of the unified events column holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlotContent {
+    /// Invisible spacer (a date event bar is drawn here by the overlay, or the slot is unused)
+    Placeholder,
+    /// Timed event at this index of the start-time-sorted timed events
+    Timed(usize),
+}
+
+/// Plan the rows of a day cell's unified events column.
+/// Slots 0..total_slots mirror the overlay's date event rows: occupied slots get a
+/// placeholder so the overlay bar shows through, free slots are filled with timed events.
+/// Timed events that don't fit in free slots follow below.
+///
+/// # Arguments
+/// * `total_slots` - Number of overlay slot rows in this week
+/// * `day_occupied_slots` - Slots occupied by date events on this day
+/// * `timed_count` - Number of timed events on this day
+/// * `max_visible` - Maximum number of rows to show
+pub fn plan_unified_slots(
+    total_slots: usize,
+    day_occupied_slots: &std::collections::HashSet<usize>,
+    timed_count: usize,
+    max_visible: usize,
+) -> Vec<SlotContent> {
+    let mut plan = Vec::new();
+    let mut next_timed = 0;
+
+    for slot in 0..total_slots {
+        if plan.len() >= max_visible {
+            return plan;
+        }
+        if !day_occupied_slots.contains(&slot) && next_timed < timed_count {
+            plan.push(SlotContent::Timed(next_timed));
+            next_timed += 1;
+        } else {
+            plan.push(SlotContent::Placeholder);
+        }
+    }
+
+    while next_timed < timed_count && plan.len() < max_visible {
+        plan.push(SlotContent::Timed(next_timed));
+        next_timed += 1;
+    }
+
+    plan
+}
+
 /// Render an empty placeholder to maintain slot alignment
 /// This creates an invisible spacer with the same height as an overlay event row
 fn render_empty_slot_placeholder() -> Element<'static, Message> {
@@ -42,16 +93,18 @@ fn render_empty_slot_placeholder() -> Element<'static, Message> {
 /// * `max_visible` - Maximum number of events to show
 /// * `current_date` - The date of the cell
 /// * `week_max_slot` - Maximum slot index for the week (determines placeholder count)
+/// * `locale` - Formats event times in the user's clock style
 #[allow(dead_code)]
 pub fn render_unified_events(
     events: Vec<DisplayEvent>,
     max_visible: usize,
     current_date: NaiveDate,
     week_max_slot: Option<usize>,
+    locale: &LocalePreferences,
 ) -> UnifiedEventsResult {
     // Use empty set for day_occupied_slots - this legacy function doesn't do Tetris-style rendering
     let empty_slots = std::collections::HashSet::new();
-    render_unified_events_with_selection(events, max_visible, current_date, week_max_slot, &empty_slots, None, false, None)
+    render_unified_events_with_selection(events, max_visible, current_date, week_max_slot, &empty_slots, EventSelection::default(), false, None, EventTextSize::default(), locale)
 }
 
 /// Render events as a unified column with selection support.
@@ -64,17 +117,21 @@ pub fn render_unified_events(
 /// * `current_date` - The date of the cell
 /// * `week_max_slot` - Maximum slot index for the week (determines total slot count)
 /// * `day_occupied_slots` - Slots occupied by date events on THIS specific day
-/// * `selected_event_uid` - UID of the currently selected event (if any)
+/// * `selected_events` - Events currently selected
 /// * `dragging_event_uid` - UID of the event currently being dragged (if any)
+/// * `text_size` - Font size of event labels
+/// * `locale` - Formats event times in the user's clock style
 pub fn render_unified_events_with_selection(
     events: Vec<DisplayEvent>,
     max_visible: usize,
     current_date: NaiveDate,
     week_max_slot: Option<usize>,
     day_occupied_slots: &std::collections::HashSet<usize>,
-    selected_event_uid: Option<&str>,
+    selected_events: EventSelection<'_>,
     is_drag_active: bool,
     dragging_event_uid: Option<&str>,
+    text_size: EventTextSize,
+    locale: &LocalePreferences,
 ) -> UnifiedEventsResult {
     // Separate all-day and timed events
     let (all_day_events, mut timed_events): (Vec<_>, Vec<_>) =
@@ -93,54 +150,38 @@ pub fn render_unified_events_with_selection(
 
     // Build a single column with consistent DATE_EVENT_SPACING to match overlay
     let mut col = column().spacing(DATE_EVENT_SPACING as u16);
-    let mut shown = 0;
-
-    // Track which timed events we've used
-    let mut timed_event_iter = timed_events.into_iter().peekable();
+    let plan = plan_unified_slots(total_slots, day_occupied_slots, timed_events.len(), max_visible);
+    let shown = plan.len();
 
     // Tetris-style rendering: for each slot position, either:
     // - Show a placeholder if the slot is occupied by a date event (overlay renders it)
     // - Show a timed event if slot is empty (fill the gap)
-    for slot in 0..total_slots {
-        if shown >= max_visible {
-            break;
-        }
-
-        if day_occupied_slots.contains(&slot) {
-            // Slot is occupied by a date event - render placeholder
-            col = col.push(render_empty_slot_placeholder());
-        } else {
-            // Slot is empty - fill with a timed event if available
-            if let Some(event) = timed_event_iter.next() {
+    // Then any remaining timed events that didn't fit in empty slots
+    let mut timed_event_iter = timed_events.into_iter();
+    for (row_idx, content) in plan.into_iter().enumerate() {
+        match content {
+            SlotContent::Placeholder => {
+                col = col.push(render_empty_slot_placeholder());
+            }
+            SlotContent::Timed(_) => {
+                let Some(event) = timed_event_iter.next() else { continue };
                 let event_unique_id = event.unique_id();
-                let is_selected = selected_event_uid.map_or(false, |uid| uid == event_unique_id);
+                let is_selected = selected_events.contains(&event_unique_id);
                 let is_being_dragged = dragging_event_uid.map_or(false, |uid| uid == event_unique_id);
-                col = col.push(render_clickable_event_chip(event, current_date, is_selected, is_drag_active, is_being_dragged));
-            } else {
-                // No more timed events - render placeholder to maintain slot alignment
-                col = col.push(render_empty_slot_placeholder());
+                let chip = render_clickable_event_chip(event, current_date, is_selected, is_drag_active, is_being_dragged, text_size, locale);
+
+                if row_idx < total_slots {
+                    // Inside the overlay's slot rows the chip must match the bar height,
+                    // otherwise every following slot drifts and lands under a date bar
+                    col = col.push(container(chip).width(Length::Fill).height(Length::Fixed(DATE_EVENT_HEIGHT)));
+                } else {
+                    col = col.push(chip);
+                }
             }
         }
-        shown += 1;
     }
 
-    // Render any remaining timed events that didn't fit in empty slots
-    for event in timed_event_iter {
-        if shown >= max_visible {
-            break;
-        }
-        let event_unique_id = event.unique_id();
-        let is_selected = selected_event_uid.map_or(false, |uid| uid == event_unique_id);
-        let is_being_dragged = dragging_event_uid.map_or(false, |uid| uid == event_unique_id);
-        col = col.push(render_clickable_event_chip(event, current_date, is_selected, is_drag_active, is_being_dragged));
-        shown += 1;
-    }
-
-    let overflow_count = if total_events > max_visible {
-        total_events - max_visible
-    } else {
-        0
-    };
+    let overflow_count = overflow_count(total_events, max_visible);
 
     let events = if total_slots > 0 || shown > 0 {
         Some(col.into())
@@ -153,3 +194,31 @@ pub fn render_unified_events_with_selection(
         overflow_count,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_plan_fills_free_slots_with_timed_events() {
+        // Slot 1 is taken by a date bar; slots 0 and 2 are free
+        let occupied: HashSet<usize> = [1].into_iter().collect();
+        assert_eq!(
+            plan_unified_slots(3, &occupied, 3, 10),
+            vec![SlotContent::Timed(0), SlotContent::Placeholder, SlotContent::Timed(1), SlotContent::Timed(2)]
+        );
+
+        // A day with no date bar (e.g. Monday before a bar starting Wednesday) uses slot 0
+        assert_eq!(
+            plan_unified_slots(1, &HashSet::new(), 2, 10),
+            vec![SlotContent::Timed(0), SlotContent::Timed(1)]
+        );
+
+        // The visible cap applies to placeholders and timed events alike
+        assert_eq!(
+            plan_unified_slots(3, &occupied, 3, 2),
+            vec![SlotContent::Timed(0), SlotContent::Placeholder]
+        );
+    }
+}
@@ -1,18 +1,26 @@
 //! Event dialog component for creating and editing events
 //! Uses COSMIC settings-style grouped sections with editable_input
 
-use chrono::Weekday;
-use cosmic::iced::widget::stack;
-use cosmic::iced::Length;
-use cosmic::widget::{button, calendar, column, container, mouse_area, popover, row, scrollable, settings, text, text_editor, toggler};
+use chrono::{Datelike, Days, NaiveDateTime, NaiveTime, Weekday};
+use cosmic::iced::{Color, Length};
+use cosmic::widget::{button, calendar, column, container, popover, row, scrollable, settings, text, text_editor, toggler};
 use cosmic::widget::editable_input;
 use cosmic::{widget, Element};
 
 use crate::app::{EventDialogField, EventDialogState};
-use crate::caldav::{AlertTime, RepeatFrequency, TravelTime};
+use crate::caldav::{AlertTime, RepeatFrequency, TravelTime, ALL_DAY_ALERT_MINUTE, MAX_REPEAT_INTERVAL};
 use crate::calendars::CalendarSource;
+use crate::components::color_picker::parse_hex_color;
 use crate::fl;
+use crate::locale::LocalePreferences;
+use crate::localized_names::get_weekday_short;
 use crate::message::Message;
+use crate::styles::{color_button_style, color_swatch_border};
+use crate::ui_constants::{
+    BORDER_WIDTH_HIGHLIGHT, BORDER_WIDTH_SELECTED, COLOR_BUTTON_SIZE_SMALL, COLOR_DEFAULT_GRAY, EVENT_COLOR_PRESETS,
+    SPACING_COLOR_GRID,
+};
+use crate::validation::EventInputError;
 
 /// Helper to format TravelTime for display
 fn travel_time_label(tt: &TravelTime) -> String {
@@ -30,6 +38,40 @@ fn travel_time_label(tt: &TravelTime) -> String {
     }
 }
 
+/// Message shown for input the dialog can't save
+pub fn input_error_label(error: EventInputError) -> String {
+    match error {
+        EventInputError::EmptyTitle => fl!("event-error-empty-title"),
+        EventInputError::InvalidStartDate => fl!("event-error-invalid-start-date"),
+        EventInputError::InvalidEndDate => fl!("event-error-invalid-end-date"),
+        EventInputError::EndBeforeStart => fl!("event-error-end-before-start"),
+    }
+}
+
+/// Text style of a field with invalid input: the theme's destructive color
+fn invalid_text_style(theme: &cosmic::Theme, invalid: bool) -> container::Style {
+    container::Style {
+        text_color: invalid.then(|| theme.cosmic().destructive_color().into()),
+        ..Default::default()
+    }
+}
+
+/// Round swatch in the event color row; pressing it sets `color` (None = calendar color)
+fn color_swatch<'a>(swatch: Color, selected: bool, color: Option<String>) -> Element<'a, Message> {
+    let border_width = if selected { BORDER_WIDTH_SELECTED } else { BORDER_WIDTH_HIGHLIGHT };
+    button::custom(
+        container(widget::text(""))
+            .width(COLOR_BUTTON_SIZE_SMALL)
+            .height(COLOR_BUTTON_SIZE_SMALL)
+            .style(move |theme: &cosmic::Theme| {
+                color_button_style(swatch, COLOR_BUTTON_SIZE_SMALL, border_width, color_swatch_border(theme, selected))
+            }),
+    )
+    .on_press(Message::EventDialogColorChanged(color))
+    .padding(0)
+    .into()
+}
+
 /// Helper to format RepeatFrequency for display
 fn repeat_label(repeat: &RepeatFrequency) -> String {
     match repeat {
@@ -43,8 +85,19 @@ fn repeat_label(repeat: &RepeatFrequency) -> String {
     }
 }
 
+/// "Every N days/weeks/..." label for frequencies that take an interval
+fn repeat_interval_label(repeat: &RepeatFrequency, interval: u32) -> Option<String> {
+    match repeat {
+        RepeatFrequency::Daily => Some(fl!("repeat-interval-days", count = interval)),
+        RepeatFrequency::Weekly => Some(fl!("repeat-interval-weeks", count = interval)),
+        RepeatFrequency::Monthly => Some(fl!("repeat-interval-months", count = interval)),
+        RepeatFrequency::Yearly => Some(fl!("repeat-interval-years", count = interval)),
+        _ => None,
+    }
+}
+
 /// Helper to format AlertTime for display
-fn alert_label(alert: &AlertTime) -> String {
+pub fn alert_label(alert: &AlertTime, locale: &LocalePreferences) -> String {
     match alert {
         AlertTime::None => fl!("alert-none"),
         AlertTime::AtTime => fl!("alert-at-time"),
@@ -57,16 +110,77 @@ fn alert_label(alert: &AlertTime) -> String {
         AlertTime::OneDay => fl!("alert-1day"),
         AlertTime::TwoDays => fl!("alert-2days"),
         AlertTime::OneWeek => fl!("alert-1week"),
+        AlertTime::DayBeforeAt(minute) => {
+            let time = NaiveTime::from_num_seconds_from_midnight_opt(minute * 60, 0).unwrap_or(NaiveTime::MIN);
+            fl!("alert-day-before-at", time = locale.format_time(&time))
+        }
         AlertTime::Custom(mins) => format!("{} min before", mins),
     }
 }
 
+/// Format the time between two instants, e.g. "45m", "1h 30m" or "2d 3h".
+/// Zero-length spans give "0m"; an end before the start gives a leading minus.
+fn format_duration(start: NaiveDateTime, end: NaiveDateTime) -> String {
+    let total_minutes = (end - start).num_minutes();
+    let sign = if total_minutes < 0 { "-" } else { "" };
+    let minutes = total_minutes.abs();
+
+    let parts: Vec<String> = [(minutes / (24 * 60), "d"), (minutes / 60 % 24, "h"), (minutes % 60, "m")]
+        .into_iter()
+        .filter(|(value, _)| *value > 0)
+        .map(|(value, unit)| format!("{}{}", value, unit))
+        .collect();
+
+    if parts.is_empty() {
+        "0m".to_string()
+    } else {
+        format!("{}{}", sign, parts.join(" "))
+    }
+}
+
+/// Start and end of the event being edited. All-day events run from midnight
+/// of the start date to midnight after the end date.
+fn dialog_time_span(state: &EventDialogState) -> Option<(NaiveDateTime, NaiveDateTime)> {
+    if state.all_day {
+        let end = state.end_date.checked_add_days(Days::new(1))?;
+        Some((state.start_date.and_time(NaiveTime::MIN), end.and_time(NaiveTime::MIN)))
+    } else {
+        Some((state.start_date.and_time(state.start_time?), state.end_date.and_time(state.end_time?)))
+    }
+}
+
+/// Maximum number of location suggestions shown below the location field
+const MAX_LOCATION_SUGGESTIONS: usize = 5;
+
+/// Filter previously used locations by what the user has typed.
+/// Matches case-insensitively on the start of the location; an empty input
+/// or an exact match yields no suggestions.
+fn location_suggestions<'a>(recent: &'a [String], input: &str, limit: usize) -> Vec<&'a String> {
+    let needle = input.trim().to_lowercase();
+    if needle.is_empty() {
+        return Vec::new();
+    }
+
+    recent
+        .iter()
+        .filter(|location| {
+            let candidate = location.to_lowercase();
+            candidate.starts_with(&needle) && candidate != needle
+        })
+        .take(limit)
+        .collect()
+}
+
 /// Render the event dialog (Create or Edit mode)
 pub fn render_event_dialog<'a>(
     state: &'a EventDialogState,
     calendars: &'a [Box<dyn CalendarSource>],
+    locale: &LocalePreferences,
 ) -> Element<'a, Message> {
     let is_edit_mode = state.editing_uid.is_some();
+    let validation = state.validate();
+    let start_date_invalid = validation == Err(EventInputError::InvalidStartDate);
+    let end_date_invalid = matches!(validation, Err(EventInputError::InvalidEndDate | EventInputError::EndBeforeStart));
 
     // === Dialog Title ===
     let dialog_title = if is_edit_mode {
@@ -100,7 +214,7 @@ pub fn render_event_dialog<'a>(
     .on_input(Message::EventDialogLocationChanged)
     .width(Length::Fill);
 
-    let basic_section = settings::section()
+    let mut basic_section = settings::section()
         .add(
             settings::item::builder(fl!("event-title"))
                 .control(title_input),
@@ -110,12 +224,30 @@ pub fn render_event_dialog<'a>(
                 .control(location_input),
         );
 
+    // Suggest previously used locations while the location is being typed
+    if is_editing(EventDialogField::Location) {
+        let suggestions = location_suggestions(&state.recent_locations, &state.location, MAX_LOCATION_SUGGESTIONS);
+        if !suggestions.is_empty() {
+            let suggestion_buttons = suggestions.into_iter().fold(column().spacing(4), |col, location| {
+                col.push(
+                    button::text(location.as_str())
+                        .on_press(Message::EventDialogLocationChanged(location.clone())),
+                )
+            });
+            basic_section = basic_section.add(
+                settings::item::builder(fl!("event-location-suggestions"))
+                    .control(suggestion_buttons),
+            );
+        }
+    }
+
     // === Date & Time Section ===
     let all_day_toggler = toggler(state.all_day)
         .on_toggle(Message::EventDialogAllDayToggled);
 
     // Start date display as text
-    let start_date_text = text(&state.start_date_input).width(Length::Fixed(100.0));
+    let start_date_text = container(text(&state.start_date_input).width(Length::Fixed(100.0)))
+        .style(move |theme: &cosmic::Theme| invalid_text_style(theme, start_date_invalid));
 
     // Calendar picker button for start date with popover
     let start_date_picker_btn = button::custom(
@@ -181,7 +313,8 @@ pub fn render_event_dialog<'a>(
     };
 
     // End date display as text
-    let end_date_text = text(&state.end_date_input).width(Length::Fixed(100.0));
+    let end_date_text = container(text(&state.end_date_input).width(Length::Fixed(100.0)))
+        .style(move |theme: &cosmic::Theme| invalid_text_style(theme, end_date_invalid));
 
     // Calendar picker button for end date with popover
     let end_date_picker_btn = button::custom(
@@ -279,6 +412,19 @@ pub fn render_event_dialog<'a>(
                 .control(ends_row),
         );
 
+    // Computed from the fields above, so it follows every date and time change
+    let datetime_section = match dialog_time_span(state) {
+        Some((start, end)) => {
+            let duration = if end < start {
+                fl!("event-duration-negative")
+            } else {
+                format_duration(start, end)
+            };
+            datetime_section.add(settings::item::builder(fl!("event-duration")).control(text(duration)))
+        }
+        None => datetime_section,
+    };
+
     // === Travel Time Section ===
     let travel_time_options = [
         TravelTime::None,
@@ -329,7 +475,7 @@ pub fn render_event_dialog<'a>(
         );
     }
 
-    let schedule_section = settings::section()
+    let mut schedule_section = settings::section()
         .title(fl!("event-schedule-section"))
         .add(
             settings::item::builder(fl!("event-travel-time"))
@@ -340,6 +486,57 @@ pub fn render_event_dialog<'a>(
                 .control(repeat_buttons),
         );
 
+    // Interval stepper, only for frequencies that can repeat every N units
+    if let Some(interval_label) = repeat_interval_label(&state.repeat, state.repeat_interval) {
+        let interval = state.repeat_interval;
+        let interval_stepper = row()
+            .spacing(4)
+            .align_y(cosmic::iced::Alignment::Center)
+            .push(
+                button::custom(text("−").size(12))
+                    .on_press_maybe((interval > 1).then(|| Message::EventDialogRepeatIntervalChanged(interval - 1)))
+                    .padding([4, 8])
+                    .class(cosmic::theme::Button::Standard),
+            )
+            .push(text(interval_label).size(12))
+            .push(
+                button::custom(text("+").size(12))
+                    .on_press_maybe(
+                        (interval < MAX_REPEAT_INTERVAL).then(|| Message::EventDialogRepeatIntervalChanged(interval + 1)),
+                    )
+                    .padding([4, 8])
+                    .class(cosmic::theme::Button::Standard),
+            );
+        schedule_section = schedule_section.add(
+            settings::item::builder(fl!("event-repeat-interval"))
+                .control(interval_stepper),
+        );
+    }
+
+    // Weekday toggles for weekly series; no weekdays means the start date's weekday
+    if matches!(state.repeat, RepeatFrequency::Weekly | RepeatFrequency::Biweekly) {
+        let start_weekday = state.start_date.weekday();
+        let mut weekday_buttons = row().spacing(4);
+        for day in [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri, Weekday::Sat, Weekday::Sun] {
+            let is_selected = state.repeat_weekdays.contains(&day)
+                || (state.repeat_weekdays.is_empty() && day == start_weekday);
+            weekday_buttons = weekday_buttons.push(
+                button::custom(text(get_weekday_short(day)).size(12))
+                    .on_press(Message::EventDialogRepeatWeekdayToggled(day))
+                    .padding([4, 8])
+                    .class(if is_selected {
+                        cosmic::theme::Button::Suggested
+                    } else {
+                        cosmic::theme::Button::Standard
+                    }),
+            );
+        }
+        schedule_section = schedule_section.add(
+            settings::item::builder(fl!("event-repeat-weekdays"))
+                .control(weekday_buttons),
+        );
+    }
+
     // === Calendar Section ===
     let mut calendar_section = settings::section()
         .title(fl!("event-calendar"));
@@ -347,8 +544,7 @@ pub fn render_event_dialog<'a>(
     for calendar in calendars.iter() {
         let info = calendar.info();
         let is_selected = info.id == state.calendar_id;
-        let cal_color = crate::components::color_picker::parse_hex_color(&info.color)
-            .unwrap_or(crate::ui_constants::COLOR_DEFAULT_GRAY);
+        let cal_color = parse_hex_color(&info.color).unwrap_or(COLOR_DEFAULT_GRAY);
         let calendar_id = info.id.clone();
 
         let calendar_btn = button::custom(
@@ -381,6 +577,28 @@ pub fn render_event_dialog<'a>(
         calendar_section = calendar_section.add(calendar_btn);
     }
 
+    // Event color: the calendar's color first, then the presets. A color set
+    // elsewhere (e.g. imported) that isn't a preset is shown as well.
+    let calendar_color = calendars
+        .iter()
+        .find(|calendar| calendar.info().id == state.calendar_id)
+        .and_then(|calendar| parse_hex_color(&calendar.info().color).ok())
+        .unwrap_or(COLOR_DEFAULT_GRAY);
+    let is_preset = |hex: &str| EVENT_COLOR_PRESETS.iter().any(|preset| preset.eq_ignore_ascii_case(hex));
+    let custom_color = state.color.as_deref().filter(|hex| !is_preset(hex));
+    let mut color_row = row()
+        .spacing(SPACING_COLOR_GRID)
+        .push(color_swatch(calendar_color, state.color.is_none(), None));
+    for hex in custom_color.into_iter().chain(EVENT_COLOR_PRESETS.iter().copied()) {
+        let selected = state.color.as_deref().is_some_and(|color| color.eq_ignore_ascii_case(hex));
+        let swatch = parse_hex_color(hex).unwrap_or(COLOR_DEFAULT_GRAY);
+        color_row = color_row.push(color_swatch(swatch, selected, Some(hex.to_string())));
+    }
+    calendar_section = calendar_section.add(
+        settings::item::builder(fl!("event-color"))
+            .control(color_row),
+    );
+
     // === Alert Section ===
     let alert_options = [
         AlertTime::None,
@@ -389,13 +607,15 @@ pub fn render_event_dialog<'a>(
         AlertTime::OneHour,
         AlertTime::OneDay,
     ];
+    // All-day events start at midnight, so also offer a time on the day before
+    let all_day_options = state.all_day.then_some(AlertTime::DayBeforeAt(ALL_DAY_ALERT_MINUTE));
 
     let mut alert_buttons = row().spacing(4);
-    for opt in alert_options.iter() {
+    for opt in alert_options.iter().chain(all_day_options.iter()) {
         let is_selected = &state.alert == opt;
         let opt_clone = opt.clone();
         alert_buttons = alert_buttons.push(
-            button::custom(text(alert_label(opt)).size(11))
+            button::custom(text(alert_label(opt, locale)).size(11))
                 .on_press(Message::EventDialogAlertChanged(opt_clone))
                 .padding([4, 6])
                 .class(if is_selected {
@@ -445,6 +665,41 @@ pub fn render_event_dialog<'a>(
         .title(fl!("event-invitees"))
         .add(settings::item::builder(fl!("event-invitees")).control(invitees_content));
 
+    // === Categories Section ===
+    let mut category_chips = row().spacing(4);
+    for (index, category) in state.categories.iter().enumerate() {
+        category_chips = category_chips.push(
+            button::custom(
+                row()
+                    .spacing(4)
+                    .push(text(category).size(12))
+                    .push(text("×").size(12)),
+            )
+            .on_press(Message::EventDialogRemoveCategory(index))
+            .padding([2, 6])
+            .class(cosmic::theme::Button::Standard),
+        );
+    }
+
+    let category_input = editable_input(
+        fl!("event-category-placeholder"),
+        &state.category_input,
+        true, // Always editable for input
+        |_| Message::EventDialogAddCategory, // Toggle acts as submit
+    )
+    .on_input(Message::EventDialogCategoryInputChanged)
+    .on_submit(|_| Message::EventDialogAddCategory)
+    .width(Length::Fill);
+
+    let categories_content = column()
+        .spacing(4)
+        .push(category_chips)
+        .push(category_input);
+
+    let categories_section = settings::section()
+        .title(fl!("event-categories"))
+        .add(settings::item::builder(fl!("event-categories")).control(categories_content));
+
     // === Additional Info Section ===
     let url_input = editable_input(
         fl!("event-url-placeholder"),
@@ -475,14 +730,22 @@ pub fn render_event_dialog<'a>(
     // === Dialog Buttons ===
     let cancel_btn = button::text(fl!("button-cancel")).on_press(Message::CancelEventDialog);
 
+    // Saving is only offered for valid input; the first problem is shown next to the buttons
+    let confirm_message = validation.is_ok().then_some(Message::ConfirmEventDialog);
     let confirm_btn = if is_edit_mode {
-        button::suggested(fl!("button-save")).on_press(Message::ConfirmEventDialog)
+        button::suggested(fl!("button-save")).on_press_maybe(confirm_message)
     } else {
-        button::suggested(fl!("button-create")).on_press(Message::ConfirmEventDialog)
+        button::suggested(fl!("button-create")).on_press_maybe(confirm_message)
     };
+    let validation_message = validation.err().map(|error| {
+        container(text(input_error_label(error)).size(12))
+            .style(|theme: &cosmic::Theme| invalid_text_style(theme, true))
+    });
 
     let buttons = row()
         .spacing(8)
+        .align_y(cosmic::iced::Alignment::Center)
+        .push_maybe(validation_message)
         .push(widget::horizontal_space())
         .push(cancel_btn)
         .push(confirm_btn);
@@ -495,6 +758,7 @@ pub fn render_event_dialog<'a>(
         calendar_section.into(),
         alert_section.into(),
         invitees_section.into(),
+        categories_section.into(),
         additional_section.into(),
     ])
     .padding(0);
@@ -518,27 +782,8 @@ pub fn render_event_dialog<'a>(
         .max_height(700.0)
         .style(dialog_container_style);
 
-    // Clickable backdrop that closes the dialog
-    let backdrop = mouse_area(
-        container(widget::text(""))
-            .width(Length::Fill)
-            .height(Length::Fill)
-            .style(|_theme: &cosmic::Theme| container::Style {
-                background: Some(cosmic::iced::Color::from_rgba(0.0, 0.0, 0.0, 0.5).into()),
-                ..Default::default()
-            }),
-    )
-    .on_press(Message::CloseDialog);
-
-    // Center the dialog card
-    let centered_dialog = container(dialog_card)
-        .width(Length::Fill)
-        .height(Length::Fill)
-        .center_x(Length::Fill)
-        .center_y(Length::Fill);
-
-    // Stack: backdrop on bottom, dialog on top
-    stack![backdrop, centered_dialog].into()
+    // Center over the shared clickable backdrop that closes the dialog
+    crate::views::wrap_with_dialog_backdrop(dialog_card.into())
 }
 
 /// Style function for popup containers (calendar picker, etc.)
@@ -582,3 +827,46 @@ fn dialog_container_style(theme: &cosmic::Theme) -> container::Style {
         ..Default::default()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_location_suggestions_prefix_match() {
+        let recent: Vec<String> = ["Room 4", "Rooftop Bar", "Office", "room 12"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+
+        // Case-insensitive prefix match, in recency order
+        assert_eq!(location_suggestions(&recent, "roo", 5), vec!["Room 4", "Rooftop Bar", "room 12"]);
+        // Only prefixes match, not substrings
+        assert!(location_suggestions(&recent, "bar", 5).is_empty());
+        // Limit is respected
+        assert_eq!(location_suggestions(&recent, "room", 1), vec!["Room 4"]);
+        // Exact match and empty input give no suggestions
+        assert!(location_suggestions(&recent, "office", 5).is_empty());
+        assert!(location_suggestions(&recent, "  ", 5).is_empty());
+    }
+
+    fn at(day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        chrono::NaiveDate::from_ymd_opt(2025, 3, day).unwrap().and_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn test_format_duration_minutes_and_hours() {
+        assert_eq!(format_duration(at(10, 9, 0), at(10, 9, 0)), "0m");
+        assert_eq!(format_duration(at(10, 9, 0), at(10, 9, 45)), "45m");
+        assert_eq!(format_duration(at(10, 9, 0), at(10, 10, 0)), "1h");
+        assert_eq!(format_duration(at(10, 9, 0), at(10, 10, 30)), "1h 30m");
+    }
+
+    #[test]
+    fn test_format_duration_multiple_days() {
+        assert_eq!(format_duration(at(10, 0, 0), at(12, 0, 0)), "2d");
+        assert_eq!(format_duration(at(10, 22, 0), at(13, 1, 15)), "2d 3h 15m");
+        // Mistakes show up as a negative duration
+        assert_eq!(format_duration(at(10, 10, 30), at(10, 9, 0)), "-1h 30m");
+    }
+}
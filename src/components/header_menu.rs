@@ -1,13 +1,17 @@
+use chrono::Weekday;
 use cosmic::widget::{button, menu};
 use cosmic::{widget, Element};
 use cosmic::app::Core;
 use std::collections::HashMap;
 use std::sync::LazyLock;
 
+use crate::caldav::RepeatPeriod;
 use crate::fl;
+use crate::localized_names::get_weekday_full;
 use crate::menu_action::MenuAction;
 use crate::message::Message;
-use crate::ui_constants::{ICON_ADD, ICON_SEARCH, ICON_TODAY, MENU_ITEM_HEIGHT, MENU_ITEM_WIDTH, MENU_SPACING};
+use crate::settings::{AppSettings, EventOverlapStyle, EventTextSize, GridLineStyle, NewEventMode};
+use crate::ui_constants::{ICON_ADD, ICON_SEARCH, MENU_ITEM_HEIGHT, MENU_ITEM_WIDTH, MENU_SPACING};
 
 /// Static menu ID for responsive menu bar - must persist across renders for collapse state tracking
 static MENU_ID: LazyLock<widget::Id> = LazyLock::new(|| widget::Id::new("sol-calendar-menu"));
@@ -17,8 +21,15 @@ pub fn render_header_start<'a>(
     core: &'a Core,
     key_binds: &'a HashMap<menu::KeyBind, MenuAction>,
     sidebar_visible: bool,
-    show_week_numbers: bool,
+    settings: &AppSettings,
 ) -> Vec<Element<'a, Message>> {
+    let max_events = settings.max_events_per_day;
+    let grid_lines = settings.grid_lines;
+    let text_size = settings.event_text_size;
+    let overlap_style = settings.event_overlap_style;
+    let first_day = settings.first_day_of_week;
+    let weekend_days = settings.weekend_days.as_deref();
+
     vec![
         // Use the official COSMIC nav_bar_toggle for consistent styling
         widget::nav_bar_toggle()
@@ -38,11 +49,28 @@ pub fn render_header_start<'a>(
                     (fl!("menu-file"), vec![
                         menu::Item::Button(fl!("menu-new-event"), None, MenuAction::NewEvent),
                         menu::Item::Button(fl!("menu-new-calendar"), None, MenuAction::NewCalendar),
+                        menu::Item::Button(fl!("menu-add-caldav-account"), None, MenuAction::AddCalDavAccount),
+                        menu::Item::Button(fl!("menu-sync-all"), None, MenuAction::SyncAllCalendars),
                         menu::Item::Divider,
                         menu::Item::Button(fl!("menu-import-ical"), None, MenuAction::ImportICal),
                         menu::Item::Button(fl!("menu-export-ical"), None, MenuAction::ExportICal),
+                        menu::Item::Button(fl!("menu-print-week"), None, MenuAction::PrintWeek),
+                        menu::Item::Divider,
+                        menu::Item::Button(fl!("menu-open-data-directory"), None, MenuAction::OpenDataDirectory),
                     ]),
                     (fl!("menu-edit"), vec![
+                        menu::Item::Button(fl!("menu-undo"), None, MenuAction::Undo),
+                        menu::Item::Button(fl!("menu-redo"), None, MenuAction::Redo),
+                        menu::Item::Divider,
+                        menu::Item::CheckBox(fl!("menu-new-event-dialog"), None, settings.new_event_mode == NewEventMode::Dialog, MenuAction::ToggleNewEventMode),
+                        menu::Item::CheckBox(fl!("menu-show-missed-alerts"), None, settings.show_missed_alerts, MenuAction::ToggleMissedAlerts),
+                        menu::Item::CheckBox(fl!("menu-confirm-import-duplicates"), None, settings.confirm_import_duplicates, MenuAction::ToggleConfirmImportDuplicates),
+                        menu::Item::Button(fl!("menu-select-all-events"), None, MenuAction::SelectAllEventsInView),
+                        menu::Item::Button(fl!("menu-move-event-to-today"), None, MenuAction::MoveSelectedEventToToday),
+                        menu::Item::Button(fl!("menu-repeat-event-next-week"), None, MenuAction::RepeatSelectedEventForward(RepeatPeriod::Week)),
+                        menu::Item::Button(fl!("menu-repeat-event-next-month"), None, MenuAction::RepeatSelectedEventForward(RepeatPeriod::Month)),
+                        menu::Item::Button(fl!("menu-duplicate-event"), None, MenuAction::DuplicateSelectedEvent),
+                        menu::Item::Divider,
                         menu::Item::Button(fl!("menu-settings"), None, MenuAction::Settings),
                     ]),
                     (fl!("menu-view"), vec![
@@ -52,8 +80,43 @@ pub fn render_header_start<'a>(
                         menu::Item::Button(fl!("menu-week-view"), None, MenuAction::ViewWeek),
                         menu::Item::Button(fl!("menu-month-view"), None, MenuAction::ViewMonth),
                         menu::Item::Button(fl!("menu-year-view"), None, MenuAction::ViewYear),
+                        menu::Item::Button(fl!("menu-agenda-view"), None, MenuAction::ViewAgenda),
                         menu::Item::Divider,
-                        menu::Item::CheckBox(fl!("menu-show-week-numbers"), None, show_week_numbers, MenuAction::ToggleWeekNumbers),
+                        menu::Item::CheckBox(fl!("menu-show-week-numbers"), None, settings.show_week_numbers, MenuAction::ToggleWeekNumbers),
+                        menu::Item::CheckBox(fl!("menu-show-weekends"), None, settings.show_weekends, MenuAction::ToggleWeekends),
+                        menu::Item::CheckBox(fl!("menu-show-mini-week"), None, settings.show_mini_week, MenuAction::ToggleMiniWeek),
+                        menu::Item::Folder(fl!("menu-events-per-day"), vec![
+                            menu::Item::CheckBox(fl!("menu-events-per-day-fit"), None, max_events.is_none(), MenuAction::SetMaxEventsPerDay(None)),
+                            menu::Item::CheckBox(fl!("menu-events-per-day-count", count = 2), None, max_events == Some(2), MenuAction::SetMaxEventsPerDay(Some(2))),
+                            menu::Item::CheckBox(fl!("menu-events-per-day-count", count = 3), None, max_events == Some(3), MenuAction::SetMaxEventsPerDay(Some(3))),
+                            menu::Item::CheckBox(fl!("menu-events-per-day-count", count = 5), None, max_events == Some(5), MenuAction::SetMaxEventsPerDay(Some(5))),
+                        ]),
+                        menu::Item::Folder(fl!("menu-grid-lines"), vec![
+                            menu::Item::CheckBox(fl!("menu-grid-lines-none"), None, grid_lines == GridLineStyle::None, MenuAction::SetGridLineStyle(GridLineStyle::None)),
+                            menu::Item::CheckBox(fl!("menu-grid-lines-subtle"), None, grid_lines == GridLineStyle::Subtle, MenuAction::SetGridLineStyle(GridLineStyle::Subtle)),
+                            menu::Item::CheckBox(fl!("menu-grid-lines-strong"), None, grid_lines == GridLineStyle::Strong, MenuAction::SetGridLineStyle(GridLineStyle::Strong)),
+                        ]),
+                        menu::Item::Folder(fl!("menu-week-start"), vec![
+                            menu::Item::CheckBox(fl!("menu-locale-default"), None, first_day.is_none(), MenuAction::SetFirstDayOfWeek(None)),
+                            menu::Item::CheckBox(get_weekday_full(Weekday::Mon), None, first_day == Some(Weekday::Mon), MenuAction::SetFirstDayOfWeek(Some(Weekday::Mon))),
+                            menu::Item::CheckBox(get_weekday_full(Weekday::Sat), None, first_day == Some(Weekday::Sat), MenuAction::SetFirstDayOfWeek(Some(Weekday::Sat))),
+                            menu::Item::CheckBox(get_weekday_full(Weekday::Sun), None, first_day == Some(Weekday::Sun), MenuAction::SetFirstDayOfWeek(Some(Weekday::Sun))),
+                        ]),
+                        menu::Item::Folder(fl!("menu-weekend-days"), vec![
+                            menu::Item::CheckBox(fl!("menu-locale-default"), None, weekend_days.is_none(), MenuAction::SetWeekendDays(None)),
+                            menu::Item::CheckBox(fl!("menu-weekend-sat-sun"), None, weekend_days == Some(&[Weekday::Sat, Weekday::Sun][..]), MenuAction::SetWeekendDays(Some((Weekday::Sat, Weekday::Sun)))),
+                            menu::Item::CheckBox(fl!("menu-weekend-fri-sat"), None, weekend_days == Some(&[Weekday::Fri, Weekday::Sat][..]), MenuAction::SetWeekendDays(Some((Weekday::Fri, Weekday::Sat)))),
+                        ]),
+                        menu::Item::Folder(fl!("menu-event-text-size"), vec![
+                            menu::Item::CheckBox(fl!("menu-event-text-size-small"), None, text_size == EventTextSize::Small, MenuAction::SetEventTextSize(EventTextSize::Small)),
+                            menu::Item::CheckBox(fl!("menu-event-text-size-normal"), None, text_size == EventTextSize::Normal, MenuAction::SetEventTextSize(EventTextSize::Normal)),
+                            menu::Item::CheckBox(fl!("menu-event-text-size-large"), None, text_size == EventTextSize::Large, MenuAction::SetEventTextSize(EventTextSize::Large)),
+                            menu::Item::CheckBox(fl!("menu-event-text-size-extra-large"), None, text_size == EventTextSize::ExtraLarge, MenuAction::SetEventTextSize(EventTextSize::ExtraLarge)),
+                        ]),
+                        menu::Item::Folder(fl!("menu-event-overlap"), vec![
+                            menu::Item::CheckBox(fl!("menu-event-overlap-columns"), None, overlap_style == EventOverlapStyle::Columns, MenuAction::SetEventOverlapStyle(EventOverlapStyle::Columns)),
+                            menu::Item::CheckBox(fl!("menu-event-overlap-cascade"), None, overlap_style == EventOverlapStyle::Cascade, MenuAction::SetEventOverlapStyle(EventOverlapStyle::Cascade)),
+                        ]),
                         menu::Item::Divider,
                         menu::Item::Button(fl!("menu-about"), None, MenuAction::About),
                     ]),
@@ -62,15 +125,13 @@ pub fn render_header_start<'a>(
     ]
 }
 
-/// Render the right side of the header (add, today, and search buttons)
+/// Render the right side of the header (add and search buttons).
+/// Today lives in the calendar toolbar.
 pub fn render_header_end() -> Vec<Element<'static, Message>> {
     vec![
         button::icon(widget::icon::from_name(ICON_ADD))
             .on_press(Message::NewEvent)
             .into(),
-        button::icon(widget::icon::from_name(ICON_TODAY))
-            .on_press(Message::Today)
-            .into(),
         button::icon(widget::icon::from_name(ICON_SEARCH))
             .on_press(Message::ToggleSearch)
             .into(),
@@ -0,0 +1,117 @@
+//! Search term highlighting
+//!
+//! Finds where search terms occur in event text and renders the matches in
+//! bold, so search results show why an event matched.
+
+use cosmic::widget::row;
+use cosmic::{widget, Element};
+
+use crate::message::Message;
+
+/// Find the byte ranges in `text` matched by the whitespace-separated terms
+/// of `query`, case-insensitively. Overlapping and adjacent ranges are merged
+/// and the result is sorted, so it can be used directly to split the text.
+///
+/// # Returns
+/// `(start, end)` byte offsets into `text`, always on char boundaries
+pub fn highlight_ranges(text: &str, query: &str) -> Vec<(usize, usize)> {
+    // Lowercase the text while remembering which original char each
+    // lowercased byte came from (lowercasing can change byte lengths)
+    let mut lowered = String::with_capacity(text.len());
+    let mut origin: Vec<(usize, usize)> = Vec::with_capacity(text.len());
+    for (start, c) in text.char_indices() {
+        let end = start + c.len_utf8();
+        for lower in c.to_lowercase() {
+            origin.extend(std::iter::repeat_n((start, end), lower.len_utf8()));
+            lowered.push(lower);
+        }
+    }
+
+    let mut ranges: Vec<(usize, usize)> = query
+        .split_whitespace()
+        .map(str::to_lowercase)
+        .flat_map(|term| {
+            lowered
+                .match_indices(&term)
+                .map(|(pos, _)| (origin[pos].0, origin[pos + term.len() - 1].1))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    ranges.sort_unstable();
+    let mut merged: Vec<(usize, usize)> = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+/// Render text with the parts matching `query` in bold
+///
+/// # Arguments
+/// * `text` - The text to display (summary or location)
+/// * `query` - The search query
+/// * `size` - Font size for all segments
+pub fn render_highlighted_text(text: &str, query: &str, size: u16) -> Element<'static, Message> {
+    let mut segments = row();
+    let mut cursor = 0;
+
+    for (start, end) in highlight_ranges(text, query) {
+        if start > cursor {
+            segments = segments.push(widget::text(text[cursor..start].to_string()).size(size));
+        }
+        segments = segments.push(
+            widget::text(text[start..end].to_string())
+                .size(size)
+                .font(cosmic::font::bold()),
+        );
+        cursor = end;
+    }
+    if cursor < text.len() {
+        segments = segments.push(widget::text(text[cursor..].to_string()).size(size));
+    }
+
+    segments.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_highlight_ranges_case_insensitive() {
+        assert_eq!(highlight_ranges("Team Meeting", "meet"), vec![(5, 9)]);
+        assert_eq!(highlight_ranges("team meeting", "TEAM"), vec![(0, 4)]);
+    }
+
+    #[test]
+    fn test_highlight_ranges_multiple_terms() {
+        // Each term is matched independently, results are sorted
+        assert_eq!(highlight_ranges("Lunch with Alex at Cafe", "cafe alex"), vec![(11, 15), (19, 23)]);
+        // Every occurrence is highlighted
+        assert_eq!(highlight_ranges("Sync sync", "sync"), vec![(0, 4), (5, 9)]);
+    }
+
+    #[test]
+    fn test_highlight_ranges_merges_overlaps() {
+        assert_eq!(highlight_ranges("Standup", "stand andup"), vec![(0, 7)]);
+    }
+
+    #[test]
+    fn test_highlight_ranges_no_match() {
+        assert!(highlight_ranges("Dentist", "gym").is_empty());
+        assert!(highlight_ranges("Dentist", "   ").is_empty());
+    }
+
+    #[test]
+    fn test_highlight_ranges_non_ascii() {
+        let text = "Café Über";
+        let ranges = highlight_ranges(text, "über");
+        assert_eq!(ranges.len(), 1);
+        let (start, end) = ranges[0];
+        assert_eq!(&text[start..end], "Über");
+    }
+}
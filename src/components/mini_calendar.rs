@@ -1,5 +1,6 @@
+use cosmic::iced::mouse::ScrollDelta;
 use cosmic::iced::Length;
-use cosmic::widget::{button, column, container, row};
+use cosmic::widget::{button, column, container, mouse_area, row};
 use cosmic::{widget, Element};
 
 use crate::localized_names;
@@ -11,6 +12,18 @@ use crate::ui_constants::{
     MINI_CALENDAR_GRID_HEIGHT, ICON_PREVIOUS, ICON_NEXT
 };
 
+/// Month step for a mouse wheel scroll over the mini calendar.
+/// Scrolling up (or left) shows the previous month, down (or right) the next.
+fn mini_calendar_scroll_message(delta: ScrollDelta) -> Message {
+    let (ScrollDelta::Lines { x, y } | ScrollDelta::Pixels { x, y }) = delta;
+    let amount = if y.abs() >= x.abs() { y } else { x };
+    if amount > 0.0 {
+        Message::MiniCalendarPrevMonth
+    } else {
+        Message::MiniCalendarNextMonth
+    }
+}
+
 pub fn render_mini_calendar(
     calendar_state: &CalendarState,
     selected_day: Option<u32>,
@@ -37,8 +50,7 @@ pub fn render_mini_calendar(
 
     // Weekday headers (abbreviated)
     let mut header_row = row().spacing(SPACING_XXS);
-    let weekday_names = localized_names::get_weekday_names_short();
-    for weekday in weekday_names {
+    for weekday in calendar_state.weekdays().into_iter().map(localized_names::get_weekday_short) {
         header_row = header_row.push(
             container(widget::text(weekday).size(FONT_SIZE_SMALL))
                 .width(Length::Fixed(MINI_CALENDAR_DAY_BUTTON_SIZE))
@@ -92,5 +104,29 @@ pub fn render_mini_calendar(
     // Wrap grid in fixed-height container to prevent layout shifts between months
     let grid_container = container(grid).height(Length::Fixed(MINI_CALENDAR_GRID_HEIGHT));
 
-    column().spacing(SPACING_MINI_CALENDAR).push(header).push(grid_container).into()
+    let content = column().spacing(SPACING_MINI_CALENDAR).push(header).push(grid_container);
+
+    // The wheel steps through months like the arrow buttons
+    mouse_area(content)
+        .on_scroll(mini_calendar_scroll_message)
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scroll_direction_steps_month() {
+        let message = mini_calendar_scroll_message;
+
+        assert!(matches!(message(ScrollDelta::Lines { x: 0.0, y: 1.0 }), Message::MiniCalendarPrevMonth));
+        assert!(matches!(message(ScrollDelta::Lines { x: 0.0, y: -1.0 }), Message::MiniCalendarNextMonth));
+        // Touchpads report pixels
+        assert!(matches!(message(ScrollDelta::Pixels { x: 0.0, y: 12.0 }), Message::MiniCalendarPrevMonth));
+        assert!(matches!(message(ScrollDelta::Pixels { x: 0.0, y: -12.0 }), Message::MiniCalendarNextMonth));
+        // Mostly horizontal scrolls use the horizontal direction
+        assert!(matches!(message(ScrollDelta::Pixels { x: 8.0, y: -1.0 }), Message::MiniCalendarPrevMonth));
+        assert!(matches!(message(ScrollDelta::Pixels { x: -8.0, y: 1.0 }), Message::MiniCalendarNextMonth));
+    }
 }
@@ -1,6 +1,8 @@
 mod calendar_dialog;
 mod calendar_dialog_callbacks;
 mod calendar_list;
+mod calendar_stats;
+mod category_filter_bar;
 pub mod color_picker;
 mod day_cell;
 mod day_header;
@@ -9,27 +11,33 @@ mod event_chip;
 mod event_dialog;
 mod event_dialog_callbacks;
 mod header_menu;
+mod highlighted_text;
 mod mini_calendar;
+mod search_panel;
 pub mod spacer;
 mod time_grid;
 pub mod time_picker;
 mod toolbar;
 
-pub use calendar_dialog::{render_calendar_dialog, render_delete_calendar_dialog, render_delete_event_dialog};
+pub use calendar_dialog::{render_calendar_dialog, render_delete_calendar_dialog, render_delete_event_dialog, render_edit_scope_dialog};
 pub use calendar_list::render_calendar_list;
-pub use event_dialog::render_event_dialog;
+pub use calendar_stats::render_calendar_stats;
+pub use category_filter_bar::render_category_filter_bar;
+pub use event_dialog::{alert_label, input_error_label, render_event_dialog};
 pub use color_picker::{render_color_indicator, render_quick_color_picker, parse_hex_color, parse_color_safe};
 pub use day_cell::{render_day_cell_with_events, DayCellConfig};
 pub use day_header::{render_day_header, DayHeaderConfig};
-pub use event_chip::{render_quick_event_input, render_spanning_quick_event_input, render_compact_events, render_unified_events_with_selection, quick_event_input_id, DisplayEvent, span_border_radius_from_flags, ChipOpacity};
+pub use event_chip::{render_quick_event_input, render_spanning_quick_event_input, render_compact_events, render_unified_events_with_selection, quick_event_input_id, DisplayEvent, EventSelection, span_border_radius_from_flags, ChipOpacity, apply_status_style, tooltip_text};
 pub use header_menu::{render_header_end, render_header_start};
+pub use highlighted_text::render_highlighted_text;
 pub use mini_calendar::render_mini_calendar;
-pub use time_grid::{render_time_grid, render_time_column_placeholder, DayColumn};
+pub use search_panel::{render_search_panel, search_input_id};
+pub use time_grid::{render_time_grid, render_time_column_placeholder, TimeGridState};
 // time_picker is used internally by event_dialog
 #[allow(unused_imports)]
 pub use time_picker::render_time_picker;
 pub use toolbar::render_toolbar;
-pub use display_mode::{EventDisplayMode, calculate_display_mode, should_use_compact};
+pub use display_mode::{EventDisplayMode, calculate_display_mode, overflow_count, should_use_compact};
 
 // These callback structs are available for future use when we complete the refactoring
 // to make dialogs generic over message type (like time_picker.rs)
@@ -0,0 +1,116 @@
+//! Event search panel
+//!
+//! Search field shown below the toolbar while search is open, with the
+//! matching events listed by date. Matched text in the summary and location
+//! is highlighted; clicking a result jumps to its date and selects it.
+
+use chrono::NaiveDate;
+use cosmic::iced::{Alignment, Length};
+use cosmic::widget::{button, column, container, row, scrollable, text, text_input};
+use cosmic::{widget, Element};
+
+use crate::components::{parse_color_safe, render_highlighted_text, DisplayEvent};
+use crate::fl;
+use crate::locale::LocalePreferences;
+use crate::message::Message;
+use crate::ui_constants::{
+    FONT_SIZE_BODY, FONT_SIZE_SMALL, PADDING_SMALL, PADDING_TINY, SPACING_SMALL, SPACING_XXS,
+};
+
+/// Maximum height of the results list before it scrolls
+const SEARCH_RESULTS_MAX_HEIGHT: f32 = 280.0;
+
+/// Diameter of the calendar color dot in front of each result
+const RESULT_COLOR_DOT_SIZE: f32 = 10.0;
+
+/// ID for the search text input - used for auto-focus
+pub fn search_input_id() -> text_input::Id {
+    text_input::Id::new("event_search_input")
+}
+
+/// Render the search field and its results
+///
+/// # Arguments
+/// * `query` - Current search text
+/// * `results` - Matching events with the date each is shown on
+/// * `locale` - Formats the result times in the user's clock style
+pub fn render_search_panel<'a>(
+    query: &'a str,
+    results: &'a [(NaiveDate, DisplayEvent)],
+    locale: &LocalePreferences,
+) -> Element<'a, Message> {
+    let input = widget::search_input(fl!("search-placeholder"), query)
+        .id(search_input_id())
+        .on_input(Message::SearchQueryChanged)
+        .on_clear(Message::SearchQueryChanged(String::new()))
+        .width(Length::Fill);
+
+    let mut panel = column()
+        .spacing(SPACING_SMALL)
+        .padding([0, PADDING_SMALL, PADDING_SMALL, PADDING_SMALL])
+        .push(input);
+
+    if query.trim().is_empty() {
+        return panel.into();
+    }
+
+    if results.is_empty() {
+        return panel
+            .push(text(fl!("search-no-results")).size(FONT_SIZE_SMALL))
+            .into();
+    }
+
+    let list = results
+        .iter()
+        .fold(column().spacing(SPACING_XXS), |list, (date, event)| {
+            list.push(render_search_result(query, *date, event, locale))
+        });
+    panel = panel.push(
+        container(scrollable(list))
+            .max_height(SEARCH_RESULTS_MAX_HEIGHT)
+            .width(Length::Fill),
+    );
+
+    panel.into()
+}
+
+/// One result row: color dot, highlighted summary, then date and location
+fn render_search_result<'a>(query: &str, date: NaiveDate, event: &DisplayEvent, locale: &LocalePreferences) -> Element<'a, Message> {
+    let color = parse_color_safe(&event.color);
+    let dot = container(text(""))
+        .width(Length::Fixed(RESULT_COLOR_DOT_SIZE))
+        .height(Length::Fixed(RESULT_COLOR_DOT_SIZE))
+        .style(move |_theme: &cosmic::Theme| container::Style {
+            background: Some(color.into()),
+            border: cosmic::iced::Border {
+                radius: (RESULT_COLOR_DOT_SIZE / 2.0).into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+
+    let when = match event.start_time {
+        Some(start) => format!("{} {}", date.format("%a %-d %b %Y"), locale.format_time(&start)),
+        None => date.format("%a %-d %b %Y").to_string(),
+    };
+    let mut details = column()
+        .spacing(SPACING_XXS)
+        .push(render_highlighted_text(&event.summary, query, FONT_SIZE_BODY))
+        .push(text(when).size(FONT_SIZE_SMALL));
+    if let Some(location) = event.location.as_deref().filter(|l| !l.is_empty()) {
+        details = details.push(render_highlighted_text(location, query, FONT_SIZE_SMALL));
+    }
+
+    let content = row()
+        .spacing(SPACING_SMALL)
+        .align_y(Alignment::Center)
+        .push(dot)
+        .push(details);
+
+    button::custom(content)
+        .on_press(Message::SelectSearchResult(date, event.unique_id()))
+        .padding(PADDING_TINY)
+        .width(Length::Fill)
+        .class(cosmic::theme::Button::MenuItem)
+        .into()
+}
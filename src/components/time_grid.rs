@@ -1,93 +1,202 @@
-use cosmic::iced::{alignment, Border, Length};
-use cosmic::widget::{column, container, row};
+//! Timed grid shared by the week and day views
+//!
+//! One column per day with hour cells (click and drag to create), timed events
+//! laid out side by side where they overlap (timed events running over several
+//! days shown on each of them), the current time line and the
+//! inline quick event input. The week view passes its visible days, the day
+//! view a single day.
+
+use chrono::{Datelike, NaiveDate, NaiveTime};
+use cosmic::iced::widget::stack;
+use cosmic::iced::Length;
+use cosmic::widget::{container, row};
 use cosmic::{widget, Element};
+use std::collections::HashMap;
 
+use crate::components::{DisplayEvent, EventSelection};
+use crate::dialogs::ActiveDialog;
 use crate::locale::LocalePreferences;
 use crate::message::Message;
-use crate::styles::weekend_background;
-use crate::ui_constants::{
-    PADDING_SMALL, FONT_SIZE_SMALL, COLOR_DAY_CELL_BORDER,
-    HOUR_ROW_HEIGHT, TIME_LABEL_WIDTH, BORDER_WIDTH_THIN
+use crate::selection::SelectionState;
+use crate::settings::{EventOverlapStyle, EventTextSize, GridLineStyle, WorkingHours};
+use crate::ui_constants::TIME_LABEL_WIDTH;
+use crate::views::week::{
+    calculate_event_columns, clip_to_day, current_time_in_days, render_events_overlay_layer, render_hour_grid_background,
+    render_overflow_indicator_layer, render_quick_event_input_layer, render_time_indicator_layer,
+    render_time_labels_column, split_overflow_columns, MAX_VISIBLE_EVENT_COLUMNS,
 };
 
-/// Information about a single day column in the time grid
-#[derive(Clone)]
-pub struct DayColumn {
-    pub is_weekend: bool,
+/// Interaction state drawn over the timed grid
+#[derive(Clone, Copy, Default)]
+pub struct TimeGridState<'a> {
+    /// Currently selected events (for visual feedback)
+    pub selected_events: EventSelection<'a>,
+    /// Selection state for time slot highlighting
+    pub selection: Option<&'a SelectionState>,
+    /// Active dialog state (for quick event input)
+    pub active_dialog: Option<&'a ActiveDialog>,
+    /// Selected calendar color (for quick event styling)
+    pub calendar_color: Option<&'a str>,
+    /// Font size of event labels
+    pub event_text_size: EventTextSize,
+    /// Layout of overlapping timed events
+    pub overlap_style: EventOverlapStyle,
+    /// Working day to dim the other hours around (None = no shading)
+    pub working_hours: Option<WorkingHours>,
 }
 
-impl DayColumn {
-    #[allow(dead_code)] // Reserved for future day column configuration
-    pub fn new(is_weekend: bool) -> Self {
-        Self { is_weekend }
-    }
+/// Render the time labels column and one column per day in `days`, with the
+/// timed events of each day spanning their full duration
+pub fn render_time_grid<'a>(
+    locale: &'a LocalePreferences,
+    days: &[NaiveDate],
+    timed_events: &HashMap<NaiveDate, Vec<DisplayEvent>>,
+    state: TimeGridState<'a>,
+    grid_lines: GridLineStyle,
+) -> Element<'a, Message> {
+    // Today's column and the current time for the "now" indicator, only when today is shown
+    let today_column = current_time_in_days(days, chrono::Local::now().naive_local());
+    let today_column_index = today_column.map(|(index, _)| index);
+    let current_time = today_column.map(|(_, time)| time);
+
+    // Check if there's an active timed quick event to display
+    let quick_event_data = state.active_dialog.and_then(|dialog| {
+        if let ActiveDialog::QuickEvent { start_date, start_time: Some(start_time), end_time: Some(end_time), text, .. } = dialog {
+            Some((*start_date, *start_time, *end_time, text.as_str()))
+        } else {
+            None
+        }
+    });
+
+    // Build the grid as a row: time labels column + day columns
+    let mut main_row = row().spacing(0);
+
+    // Time labels column
+    let time_labels = render_time_labels_column(locale, current_time, grid_lines);
+    main_row = main_row.push(time_labels);
+
+    // Day columns with events
+    for (day_idx, date) in days.iter().enumerate() {
+        let is_weekend = locale.is_weekend(date.weekday());
+        let is_today_column = today_column_index == Some(day_idx);
+        // Events running over several days show only their part of this day
+        let day_events: Vec<DisplayEvent> = timed_events
+            .get(date)
+            .map(|events| events.iter().map(|event| clip_to_day(event, *date)).collect())
+            .unwrap_or_default();
+
+        // Check if this day has the quick event input
+        let day_quick_event = quick_event_data.and_then(|(qe_date, start, end, text)| {
+            if qe_date == *date {
+                Some((start, end, text, state.calendar_color.unwrap_or("#3B82F6")))
+            } else {
+                None
+            }
+        });
+
+        let day_column = render_day_column_with_events(
+            *date,
+            &day_events,
+            is_weekend,
+            is_today_column,
+            current_time,
+            state.selected_events,
+            state.selection,
+            day_quick_event,
+            grid_lines,
+            state.event_text_size,
+            state.overlap_style,
+            state.working_hours,
+            locale,
+        );
 
-    pub fn regular() -> Self {
-        Self { is_weekend: false }
+        main_row = main_row.push(day_column);
     }
+
+    main_row.into()
 }
 
-/// Render a time grid with hourly slots
-///
-/// # Arguments
-/// * `locale` - Locale preferences for time formatting
-/// * `day_columns` - List of day columns to render (1 for day view, 7 for week view)
-pub fn render_time_grid(
+/// Render a single day column with events spanning their full duration using stack overlay
+fn render_day_column_with_events(
+    date: NaiveDate,
+    events: &[DisplayEvent],
+    is_weekend: bool,
+    is_today: bool,
+    current_time: Option<NaiveTime>, // Set when today is one of the shown days
+    selected_events: EventSelection<'_>,
+    selection: Option<&SelectionState>,
+    quick_event: Option<(NaiveTime, NaiveTime, &str, &str)>, // (start_time, end_time, text, color)
+    grid_lines: GridLineStyle,
+    event_text_size: EventTextSize,
+    overlap_style: EventOverlapStyle,
+    working_hours: Option<WorkingHours>,
     locale: &LocalePreferences,
-    day_columns: &[DayColumn],
 ) -> Element<'static, Message> {
-    let mut grid = column().spacing(0);
-
-    // Render 24 hours
-    for hour in 0..24 {
-        let mut hour_row = row().spacing(0);
-
-        // Time label - use locale-aware formatting
-        let time_label = locale.format_hour(hour);
-
-        hour_row = hour_row.push(
-            container(
-                widget::text(time_label)
-                    .size(FONT_SIZE_SMALL)
-            )
-            .width(Length::Fixed(TIME_LABEL_WIDTH))
-            .height(Length::Fixed(HOUR_ROW_HEIGHT))
-            .padding(PADDING_SMALL)
-            .align_y(alignment::Vertical::Top)
-            .style(|_theme: &cosmic::Theme| container::Style {
-                border: Border {
-                    width: BORDER_WIDTH_THIN,
-                    color: COLOR_DAY_CELL_BORDER,
-                    ..Default::default()
-                },
-                ..Default::default()
-            })
-        );
+    // Build the base hour grid (background layer) - without time indicator
+    let hour_grid = render_hour_grid_background(date, is_weekend, selection, grid_lines, working_hours);
 
-        // Day columns
-        for day_col in day_columns {
-            let is_weekend = day_col.is_weekend;
-
-            hour_row = hour_row.push(
-                container(widget::text(""))
-                    .width(Length::Fill)
-                    .height(Length::Fixed(HOUR_ROW_HEIGHT))
-                    .style(move |_theme: &cosmic::Theme| container::Style {
-                        background: weekend_background(is_weekend),
-                        border: Border {
-                            width: BORDER_WIDTH_THIN,
-                            color: COLOR_DAY_CELL_BORDER,
-                            ..Default::default()
-                        },
-                        ..Default::default()
-                    })
-            );
-        }
+    // Build the time indicator layer (rendered on top of events)
+    let time_indicator_layer = current_time.map(|now| render_time_indicator_layer(now, is_today));
 
-        grid = grid.push(hour_row);
+    // Build quick event input layer if active
+    let quick_event_layer = quick_event.map(|(start_time, end_time, text, color)| {
+        render_quick_event_input_layer(start_time, end_time, text.to_string(), color.to_string())
+    });
+
+    // If no events and no quick event, just return the grid with time indicator on top
+    if events.is_empty() && quick_event_layer.is_none() {
+        return if let Some(time_layer) = time_indicator_layer {
+            container(stack![hour_grid, time_layer])
+                .width(Length::Fill)
+                .into()
+        } else {
+            container(hour_grid)
+                .width(Length::Fill)
+                .into()
+        };
     }
 
-    grid.into()
+    // Calculate column assignments for overlapping events; columns past the cap are hidden
+    let (positioned_events, hidden_events) =
+        split_overflow_columns(calculate_event_columns(events), MAX_VISIBLE_EVENT_COLUMNS);
+    let max_columns = positioned_events.iter().map(|p| p.total_columns).max().unwrap_or(1).max(1);
+
+    // Build the events overlay layer, with the "+N" indicator on top of it
+    let events_layer = render_events_overlay_layer(date, &positioned_events, max_columns, selected_events, event_text_size, overlap_style, locale);
+    let events_layer: Element<'static, Message> = if hidden_events.is_empty() {
+        events_layer
+    } else {
+        stack![events_layer, render_overflow_indicator_layer(date, &hidden_events, event_text_size)].into()
+    };
+
+    // Stack order: grid (bottom) -> events -> time indicator -> quick event (top)
+    // Time indicator must be above events so it's always visible
+    let stacked: Element<'static, Message> = match (time_indicator_layer, quick_event_layer) {
+        (Some(time_layer), Some(qe_layer)) => stack![
+            hour_grid,
+            events_layer,
+            time_layer,
+            qe_layer
+        ].into(),
+        (Some(time_layer), None) => stack![
+            hour_grid,
+            events_layer,
+            time_layer
+        ].into(),
+        (None, Some(qe_layer)) => stack![
+            hour_grid,
+            events_layer,
+            qe_layer
+        ].into(),
+        (None, None) => stack![
+            hour_grid,
+            events_layer
+        ].into(),
+    };
+
+    container(stacked)
+        .width(Length::Fill)
+        .into()
 }
 
 /// Render the time column placeholder for all-day section headers
@@ -97,4 +206,3 @@ pub fn render_time_column_placeholder(height: f32) -> Element<'static, Message>
         .height(Length::Fixed(height))
         .into()
 }
-
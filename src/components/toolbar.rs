@@ -1,35 +1,142 @@
-use cosmic::widget::{button, row};
+use cosmic::iced::Length;
+use cosmic::widget::{button, menu, row};
 use cosmic::{widget, Element};
+use std::collections::HashMap;
 
+use crate::fl;
 use crate::message::Message;
-use crate::ui_constants::{ICON_NEXT, ICON_PREVIOUS, SPACING_MEDIUM, PADDING_TINY, PADDING_SMALL};
+use crate::ui_constants::{ICON_MORE, ICON_NEXT, ICON_PREVIOUS, SPACING_MEDIUM, PADDING_TINY, PADDING_SMALL};
+
+/// Actions offered by the calendar toolbar
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ToolbarAction {
+    Previous,
+    Next,
+    Today,
+}
+
+impl ToolbarAction {
+    /// Period navigation always stays inline; everything else may overflow
+    fn is_essential(self) -> bool {
+        matches!(self, ToolbarAction::Previous | ToolbarAction::Next)
+    }
+}
+
+impl menu::Action for ToolbarAction {
+    type Message = Message;
+
+    fn message(&self) -> Self::Message {
+        match self {
+            ToolbarAction::Previous => Message::PreviousPeriod,
+            ToolbarAction::Next => Message::NextPeriod,
+            ToolbarAction::Today => Message::Today,
+        }
+    }
+}
+
+/// All toolbar actions in display order
+const TOOLBAR_ACTIONS: [ToolbarAction; 3] = [ToolbarAction::Previous, ToolbarAction::Next, ToolbarAction::Today];
+
+/// Split toolbar actions into (inline buttons, overflow menu entries).
+/// The compact toolbar keeps only period navigation inline.
+pub fn toolbar_layout(compact: bool) -> (Vec<ToolbarAction>, Vec<ToolbarAction>) {
+    TOOLBAR_ACTIONS
+        .into_iter()
+        .partition(|action| !compact || action.is_essential())
+}
+
+/// Render a single inline toolbar button
+fn render_toolbar_button(action: ToolbarAction) -> Element<'static, Message> {
+    use menu::Action;
+
+    match action {
+        ToolbarAction::Previous => button::icon(widget::icon::from_name(ICON_PREVIOUS))
+            .on_press(action.message())
+            .padding(PADDING_TINY)
+            .into(),
+        ToolbarAction::Next => button::icon(widget::icon::from_name(ICON_NEXT))
+            .on_press(action.message())
+            .padding(PADDING_TINY)
+            .into(),
+        ToolbarAction::Today => button::standard(fl!("nav-today"))
+            .on_press(action.message())
+            .into(),
+    }
+}
+
+/// Label of a toolbar action in the overflow menu
+fn overflow_label(action: ToolbarAction) -> String {
+    match action {
+        ToolbarAction::Previous => fl!("nav-previous"),
+        ToolbarAction::Next => fl!("nav-next"),
+        ToolbarAction::Today => fl!("nav-today"),
+    }
+}
+
+/// Render the overflow menu button holding the actions that don't fit
+fn render_overflow_menu(actions: Vec<ToolbarAction>) -> Element<'static, Message> {
+    let items = actions
+        .into_iter()
+        .map(|action| menu::Item::Button(overflow_label(action), None, action))
+        .collect();
+
+    menu::bar(vec![menu::Tree::with_children(
+        button::icon(widget::icon::from_name(ICON_MORE)).padding(PADDING_TINY),
+        menu::items(&HashMap::new(), items),
+    )])
+    .into()
+}
 
 /// Render the calendar toolbar with navigation controls
-/// primary_text is displayed bold, secondary_text is displayed in normal weight
-pub fn render_toolbar(primary_text: &str, secondary_text: &str) -> Element<'static, Message> {
+/// primary_text is displayed bold, secondary_text is displayed in normal weight.
+/// In compact mode (condensed windows) less-used actions move into an overflow menu.
+pub fn render_toolbar(primary_text: &str, secondary_text: &str, compact: bool) -> Element<'static, Message> {
     let primary = primary_text.to_string();
     let secondary = secondary_text.to_string();
+    let (inline, overflow) = toolbar_layout(compact);
 
-    row()
-        .padding(PADDING_SMALL)
+    let title = row()
         .spacing(SPACING_MEDIUM)
         .align_y(cosmic::iced::Alignment::Center)
-        .push(
-            button::icon(widget::icon::from_name(ICON_PREVIOUS))
-                .on_press(Message::PreviousPeriod)
-                .padding(PADDING_TINY)
-        )
-        .push(
-            button::icon(widget::icon::from_name(ICON_NEXT))
-                .on_press(Message::NextPeriod)
-                .padding(PADDING_TINY)
-        )
-        .push(
-            row()
-                .spacing(SPACING_MEDIUM)
-                .align_y(cosmic::iced::Alignment::Center)
-                .push(widget::text::title4(primary))
-                .push(widget::text::body(secondary))
-        )
-        .into()
+        .push(widget::text::title4(primary))
+        .push(widget::text::body(secondary));
+
+    let mut toolbar = row()
+        .padding(PADDING_SMALL)
+        .spacing(SPACING_MEDIUM)
+        .align_y(cosmic::iced::Alignment::Center);
+
+    // Navigation buttons sit before the title, the rest after it
+    let (navigation, others): (Vec<_>, Vec<_>) = inline.into_iter().partition(|a| a.is_essential());
+    for action in navigation {
+        toolbar = toolbar.push(render_toolbar_button(action));
+    }
+    toolbar = toolbar.push(title.width(Length::Fill));
+    for action in others {
+        toolbar = toolbar.push(render_toolbar_button(action));
+    }
+    if !overflow.is_empty() {
+        toolbar = toolbar.push(render_overflow_menu(overflow));
+    }
+
+    toolbar.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_full_toolbar_has_no_overflow() {
+        let (inline, overflow) = toolbar_layout(false);
+        assert_eq!(inline, TOOLBAR_ACTIONS.to_vec());
+        assert!(overflow.is_empty());
+    }
+
+    #[test]
+    fn test_compact_toolbar_moves_today_into_overflow() {
+        let (inline, overflow) = toolbar_layout(true);
+        assert_eq!(inline, vec![ToolbarAction::Previous, ToolbarAction::Next]);
+        assert_eq!(overflow, vec![ToolbarAction::Today]);
+    }
 }
@@ -1,13 +1,32 @@
 use chrono::{DateTime, Utc};
 use log::{debug, info};
 use rusqlite::{Connection, params, Result as SqlResult};
+use std::collections::{HashMap, HashSet};
 use std::error::Error;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use crate::caldav::CalendarEvent;
+use crate::caldav::{CalendarEvent, RemoteResource};
+use crate::timezone;
 
 /// Current database schema version for migrations
-const SCHEMA_VERSION: i32 = 5;
+const SCHEMA_VERSION: i32 = 17;
+
+/// Event columns in the order `Database::event_from_row` reads them
+const EVENT_COLUMNS: &str = "uid, summary, location, all_day, start_time, end_time, \
+    travel_time, repeat, repeat_until, exception_dates, invitees, alert, alert_second, \
+    attachments, url, notes, status, timezone, categories, overrides, color, repeat_count, repeat_interval, repeat_weekdays";
+
+/// Number of columns in `EVENT_COLUMNS`
+const EVENT_COLUMN_COUNT: usize = 24;
+
+/// Where an event of a remote calendar lives on the server: (href, ETag)
+pub type ResourceLocation = (String, Option<String>);
+
+/// A queued upload of a remote calendar event: (uid, deleted, revision)
+pub type PendingUpload = (String, bool, i64);
+
+/// An alert that already fired: (uid, minutes before start, alert time)
+pub type FiredAlert = (String, i64, DateTime<Utc>);
 
 /// Database connection wrapper with encryption support
 pub struct Database {
@@ -84,6 +103,20 @@ impl Database {
         path
     }
 
+    /// Get the directory holding the database (used for "Open data folder")
+    pub fn get_data_directory() -> PathBuf {
+        Self::data_directory_for(&Self::get_database_path())
+    }
+
+    /// Resolve the directory containing a database file
+    fn data_directory_for(db_path: &Path) -> PathBuf {
+        db_path
+            .parent()
+            .filter(|p| !p.as_os_str().is_empty())
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| PathBuf::from("."))
+    }
+
     /// Initialize the database schema
     fn init_schema(&mut self) -> Result<(), Box<dyn Error>> {
         // Check current schema version
@@ -157,6 +190,14 @@ impl Database {
                 attachments TEXT NOT NULL DEFAULT '[]',
                 url TEXT,
                 notes TEXT,
+                status TEXT NOT NULL DEFAULT 'Confirmed',
+                timezone TEXT,
+                categories TEXT NOT NULL DEFAULT '[]',
+                overrides TEXT NOT NULL DEFAULT '[]',
+                color TEXT,
+                repeat_count INTEGER,
+                repeat_interval INTEGER NOT NULL DEFAULT 1,
+                repeat_weekdays TEXT NOT NULL DEFAULT '[]',
                 created_at TEXT NOT NULL DEFAULT (datetime('now')),
                 updated_at TEXT NOT NULL DEFAULT (datetime('now')),
                 UNIQUE(calendar_id, uid)
@@ -166,6 +207,34 @@ impl Database {
             CREATE INDEX IF NOT EXISTS idx_events_start_time ON events(start_time);
             CREATE INDEX IF NOT EXISTS idx_events_calendar_id ON events(calendar_id);
             CREATE INDEX IF NOT EXISTS idx_events_calendar_date ON events(calendar_id, start_time);
+
+            -- Server location and ETag of each event in a remote (CalDAV) calendar
+            CREATE TABLE IF NOT EXISTS remote_resources (
+                calendar_id TEXT NOT NULL,
+                uid TEXT NOT NULL,
+                href TEXT NOT NULL,
+                etag TEXT,
+                PRIMARY KEY(calendar_id, uid)
+            );
+
+            -- Local changes of remote (CalDAV) calendar events waiting for upload.
+            -- The revision grows with every change, so an upload that raced a
+            -- newer change leaves it queued
+            CREATE TABLE IF NOT EXISTS pending_uploads (
+                calendar_id TEXT NOT NULL,
+                uid TEXT NOT NULL,
+                deleted INTEGER NOT NULL DEFAULT 0,
+                revision INTEGER NOT NULL DEFAULT 1,
+                PRIMARY KEY(calendar_id, uid)
+            );
+
+            -- Alerts that already notified, so a restart doesn't notify again
+            CREATE TABLE IF NOT EXISTS fired_alerts (
+                uid TEXT NOT NULL,
+                minutes_before INTEGER NOT NULL,
+                alert_time TEXT NOT NULL,
+                PRIMARY KEY(uid, minutes_before)
+            );
             "#,
         )?;
 
@@ -289,10 +358,166 @@ impl Database {
             )?;
         }
 
+        if from_version < 6 {
+            // Migrate from v5 to v6: Add event status (confirmed/tentative/cancelled)
+            self.conn.execute_batch(
+                r#"
+                ALTER TABLE events ADD COLUMN status TEXT NOT NULL DEFAULT 'Confirmed';
+                "#,
+            )?;
+        }
+
+        if from_version < 7 {
+            // Migrate from v6 to v7: Add source time zone (IANA name from TZID)
+            self.conn.execute_batch(
+                r#"
+                ALTER TABLE events ADD COLUMN timezone TEXT;
+                "#,
+            )?;
+        }
+
+        if from_version < 8 {
+            // Migrate from v7 to v8: Add event categories/tags (JSON array)
+            self.conn.execute_batch(
+                r#"
+                ALTER TABLE events ADD COLUMN categories TEXT NOT NULL DEFAULT '[]';
+                "#,
+            )?;
+        }
+
+        if from_version < 9 {
+            // Migrate from v8 to v9: Add per-occurrence overrides (JSON array of events)
+            self.conn.execute_batch(
+                r#"
+                ALTER TABLE events ADD COLUMN overrides TEXT NOT NULL DEFAULT '[]';
+                "#,
+            )?;
+        }
+
+        if from_version < 10 {
+            // Migrate from v9 to v10: Add per-event color override (hex)
+            self.conn.execute_batch(
+                r#"
+                ALTER TABLE events ADD COLUMN color TEXT;
+                "#,
+            )?;
+        }
+
+        if from_version < 11 {
+            // Migrate from v10 to v11: Add occurrence count for recurring events.
+            // The repeat column keeps its JSON shape, so existing rows stay valid
+            // and read back as unlimited (NULL count).
+            self.conn.execute_batch(
+                r#"
+                ALTER TABLE events ADD COLUMN repeat_count INTEGER;
+                "#,
+            )?;
+        }
+
+        if from_version < 12 {
+            // Migrate from v11 to v12: Track server resources of remote calendar events
+            self.conn.execute_batch(
+                r#"
+                CREATE TABLE IF NOT EXISTS remote_resources (
+                    calendar_id TEXT NOT NULL,
+                    uid TEXT NOT NULL,
+                    href TEXT NOT NULL,
+                    etag TEXT,
+                    PRIMARY KEY(calendar_id, uid)
+                );
+                "#,
+            )?;
+        }
+
+        if from_version < 13 {
+            // Migrate from v12 to v13: Timed events without a zone were stored as
+            // wall-clock times; move them to the system zone as real UTC instants
+            self.assign_system_zone_to_floating_events()?;
+        }
+
+        if from_version < 14 {
+            // Migrate from v13 to v14: Add the repeat interval (every N units);
+            // existing series repeat every unit
+            self.conn.execute_batch(
+                r#"
+                ALTER TABLE events ADD COLUMN repeat_interval INTEGER NOT NULL DEFAULT 1;
+                "#,
+            )?;
+        }
+
+        if from_version < 15 {
+            // Migrate from v14 to v15: Add the weekdays of weekly series (JSON array)
+            self.conn.execute_batch(
+                r#"
+                ALTER TABLE events ADD COLUMN repeat_weekdays TEXT NOT NULL DEFAULT '[]';
+                "#,
+            )?;
+        }
+
+        if from_version < 16 {
+            // Migrate from v15 to v16: Remember fired alerts across restarts
+            self.conn.execute_batch(
+                r#"
+                CREATE TABLE IF NOT EXISTS fired_alerts (
+                    uid TEXT NOT NULL,
+                    minutes_before INTEGER NOT NULL,
+                    alert_time TEXT NOT NULL,
+                    PRIMARY KEY(uid, minutes_before)
+                );
+                "#,
+            )?;
+        }
+
+        if from_version < 17 {
+            // Migrate from v16 to v17: Queue local changes of remote calendars for upload
+            self.conn.execute_batch(
+                r#"
+                CREATE TABLE IF NOT EXISTS pending_uploads (
+                    calendar_id TEXT NOT NULL,
+                    uid TEXT NOT NULL,
+                    deleted INTEGER NOT NULL DEFAULT 0,
+                    revision INTEGER NOT NULL DEFAULT 1,
+                    PRIMARY KEY(calendar_id, uid)
+                );
+                "#,
+            )?;
+        }
+
         self.set_schema_version(SCHEMA_VERSION)?;
         Ok(())
     }
 
+    /// Give timed events without a zone the system zone, converting their
+    /// stored wall-clock times to the matching UTC instants (DST-aware).
+    /// Only local calendars are changed: events cached from a CalDAV server
+    /// (those with a remote resource) keep the server's times.
+    fn assign_system_zone_to_floating_events(&self) -> Result<(), Box<dyn Error>> {
+        let zone = timezone::system_zone();
+        let floating: Vec<(String, String, String, String)> = {
+            let mut stmt = self.conn.prepare(
+                r#"SELECT calendar_id, uid, start_time, end_time FROM events
+                   WHERE timezone IS NULL AND all_day = 0
+                     AND calendar_id NOT IN (SELECT calendar_id FROM remote_resources)"#,
+            )?;
+            let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)))?;
+            rows.collect::<SqlResult<_>>()?
+        };
+
+        let tx = self.conn.unchecked_transaction()?;
+        for (calendar_id, uid, start, end) in &floating {
+            let start = timezone::to_utc(DateTime::parse_from_rfc3339(start)?.naive_utc(), Some(zone));
+            let end = timezone::to_utc(DateTime::parse_from_rfc3339(end)?.naive_utc(), Some(zone));
+            tx.execute(
+                "UPDATE events SET start_time = ?1, end_time = ?2, timezone = ?3 WHERE calendar_id = ?4 AND uid = ?5",
+                params![start.to_rfc3339(), end.to_rfc3339(), zone, calendar_id, uid],
+            )?;
+        }
+        tx.commit()?;
+
+        info!("Database: Moved {} floating event(s) to time zone {}", floating.len(), zone);
+        Ok(())
+    }
+
     // ==================== Event Operations ====================
     // Note: Calendar metadata (name, color, enabled) is stored in config file
 
@@ -310,12 +535,16 @@ impl Database {
             .map(|d| d.format("%Y-%m-%d").to_string())
             .collect();
         let exception_dates_json = serde_json::to_string(&exception_dates)?;
+        let status = serde_json::to_string(&event.status)?;
+        let categories = serde_json::to_string(&event.categories)?;
+        let overrides = serde_json::to_string(&event.overrides)?;
+        let repeat_weekdays = serde_json::to_string(&event.repeat_weekdays)?;
 
         self.conn.execute(
             r#"
             INSERT INTO events (uid, calendar_id, summary, location, all_day, start_time, end_time,
-                               travel_time, repeat, repeat_until, exception_dates, invitees, alert, alert_second, attachments, url, notes)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17)
+                               travel_time, repeat, repeat_until, exception_dates, invitees, alert, alert_second, attachments, url, notes, status, timezone, categories, overrides, color, repeat_count, repeat_interval, repeat_weekdays)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25)
             "#,
             params![
                 event.uid,
@@ -335,6 +564,14 @@ impl Database {
                 attachments,
                 event.url,
                 event.notes,
+                status,
+                event.timezone,
+                categories,
+                overrides,
+                event.color,
+                event.repeat_count,
+                event.repeat_interval,
+                repeat_weekdays,
             ],
         )?;
         Ok(())
@@ -355,6 +592,10 @@ impl Database {
             .map(|d| d.format("%Y-%m-%d").to_string())
             .collect();
         let exception_dates_json = serde_json::to_string(&exception_dates)?;
+        let status = serde_json::to_string(&event.status)?;
+        let categories = serde_json::to_string(&event.categories)?;
+        let overrides = serde_json::to_string(&event.overrides)?;
+        let repeat_weekdays = serde_json::to_string(&event.repeat_weekdays)?;
 
         self.conn.execute(
             r#"
@@ -374,6 +615,14 @@ impl Database {
                 attachments = ?15,
                 url = ?16,
                 notes = ?17,
+                status = ?18,
+                timezone = ?19,
+                categories = ?20,
+                overrides = ?21,
+                color = ?22,
+                repeat_count = ?23,
+                repeat_interval = ?24,
+                repeat_weekdays = ?25,
                 updated_at = datetime('now')
             WHERE calendar_id = ?1 AND uid = ?2
             "#,
@@ -395,6 +644,14 @@ impl Database {
                 attachments,
                 event.url,
                 event.notes,
+                status,
+                event.timezone,
+                categories,
+                overrides,
+                event.color,
+                event.repeat_count,
+                event.repeat_interval,
+                repeat_weekdays,
             ],
         )?;
         Ok(())
@@ -409,67 +666,307 @@ impl Database {
         Ok(rows > 0)
     }
 
+    /// Get one event of a calendar by UID
+    pub fn get_event(&self, calendar_id: &str, uid: &str) -> Result<Option<CalendarEvent>, Box<dyn Error>> {
+        let mut stmt = self.conn.prepare(&format!("SELECT {} FROM events WHERE calendar_id = ?1 AND uid = ?2", EVENT_COLUMNS))?;
+        let mut rows = stmt.query_map(params![calendar_id, uid], Self::event_from_row)?;
+        Ok(rows.next().transpose()?)
+    }
+
     /// Get all events for a calendar
     pub fn get_events_for_calendar(&self, calendar_id: &str) -> Result<Vec<CalendarEvent>, Box<dyn Error>> {
-        let mut stmt = self.conn.prepare(
-            r#"SELECT uid, summary, location, all_day, start_time, end_time,
-                      travel_time, repeat, repeat_until, exception_dates, invitees, alert, alert_second,
-                      attachments, url, notes
-               FROM events WHERE calendar_id = ?1"#
-        )?;
+        let mut stmt = self.conn.prepare(&format!("SELECT {} FROM events WHERE calendar_id = ?1", EVENT_COLUMNS))?;
 
-        let events = stmt.query_map(params![calendar_id], |row| {
-            let start_str: String = row.get(4)?;
-            let end_str: String = row.get(5)?;
-            let travel_time_str: String = row.get(6)?;
-            let repeat_str: String = row.get(7)?;
-            let repeat_until_str: Option<String> = row.get(8)?;
-            let exception_dates_str: String = row.get::<_, Option<String>>(9)?.unwrap_or_else(|| "[]".to_string());
-            let invitees_str: String = row.get(10)?;
-            let alert_str: String = row.get(11)?;
-            let alert_second_str: Option<String> = row.get(12)?;
-            let attachments_str: String = row.get(13)?;
-
-            // Parse exception_dates from JSON array of date strings
-            let exception_dates_strings: Vec<String> = serde_json::from_str(&exception_dates_str).unwrap_or_default();
-            let exception_dates: Vec<chrono::NaiveDate> = exception_dates_strings.iter()
-                .filter_map(|s| chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").ok())
-                .collect();
-
-            Ok(CalendarEvent {
-                uid: row.get(0)?,
-                summary: row.get(1)?,
-                location: row.get(2)?,
-                all_day: row.get(3)?,
-                start: DateTime::parse_from_rfc3339(&start_str)
-                    .map(|dt| dt.with_timezone(&Utc))
-                    .unwrap_or_else(|_| Utc::now()),
-                end: DateTime::parse_from_rfc3339(&end_str)
-                    .map(|dt| dt.with_timezone(&Utc))
-                    .unwrap_or_else(|_| Utc::now()),
-                travel_time: serde_json::from_str(&travel_time_str).unwrap_or_default(),
-                repeat: serde_json::from_str(&repeat_str).unwrap_or_default(),
-                repeat_until: repeat_until_str.and_then(|s| chrono::NaiveDate::parse_from_str(&s, "%Y-%m-%d").ok()),
-                exception_dates,
-                invitees: serde_json::from_str(&invitees_str).unwrap_or_default(),
-                alert: serde_json::from_str(&alert_str).unwrap_or_default(),
-                alert_second: alert_second_str.and_then(|s| serde_json::from_str(&s).ok()),
-                attachments: serde_json::from_str(&attachments_str).unwrap_or_default(),
-                url: row.get(14)?,
-                notes: row.get(15)?,
-            })
+        let events = stmt.query_map(params![calendar_id], Self::event_from_row)?
+            .collect::<SqlResult<Vec<_>>>()?;
+
+        Ok(events)
+    }
+
+    /// Get the events of a calendar that overlap `[start, end)`, including
+    /// multi-day events that started before `start`.
+    /// Recurring series are always included, since their occurrences are
+    /// expanded by the caller. The two are queried separately so the start
+    /// time bound of single events can use `idx_events_calendar_date`.
+    pub fn get_events_in_range(
+        &self,
+        calendar_id: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<CalendarEvent>, Box<dyn Error>> {
+        // Rows from before the repeat column held JSON store the bare variant name
+        let mut stmt = self.conn.prepare(&format!(
+            r#"SELECT {0} FROM events
+               WHERE calendar_id = ?1 AND start_time < ?3 AND end_time >= ?2
+                 AND repeat IN ('"Never"', 'Never')
+               UNION ALL
+               SELECT {0} FROM events
+               WHERE calendar_id = ?1 AND repeat NOT IN ('"Never"', 'Never')"#,
+            EVENT_COLUMNS
+        ))?;
+
+        let events = stmt.query_map(params![calendar_id, start.to_rfc3339(), end.to_rfc3339()], Self::event_from_row)?
+            .collect::<SqlResult<Vec<_>>>()?;
+
+        Ok(events)
+    }
+
+    /// Find events whose summary, location or notes contain `query`
+    /// (case-insensitive for ASCII), ordered by start time.
+    /// Returns (calendar_id, event) pairs, at most `limit` of them.
+    pub fn search_events(&self, query: &str, limit: usize) -> Result<Vec<(String, CalendarEvent)>, Box<dyn Error>> {
+        // Match the query literally - LIKE wildcards in it are escaped
+        let escaped = query.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_");
+        let pattern = format!("%{}%", escaped);
+
+        let mut stmt = self.conn.prepare(&format!(
+            r#"SELECT {}, calendar_id FROM events
+               WHERE summary LIKE ?1 ESCAPE '\' OR location LIKE ?1 ESCAPE '\' OR notes LIKE ?1 ESCAPE '\'
+               ORDER BY start_time
+               LIMIT ?2"#,
+            EVENT_COLUMNS
+        ))?;
+
+        let results = stmt.query_map(params![pattern, limit as i64], |row| {
+            Ok((row.get(EVENT_COLUMN_COUNT)?, Self::event_from_row(row)?))
         })?
         .collect::<SqlResult<Vec<_>>>()?;
 
-        Ok(events)
+        Ok(results)
     }
 
-    /// Delete all events for a calendar
+    /// Build an event from a row selected with `EVENT_COLUMNS`
+    fn event_from_row(row: &rusqlite::Row<'_>) -> SqlResult<CalendarEvent> {
+        let start_str: String = row.get(4)?;
+        let end_str: String = row.get(5)?;
+        let travel_time_str: String = row.get(6)?;
+        let repeat_str: String = row.get(7)?;
+        let repeat_until_str: Option<String> = row.get(8)?;
+        let exception_dates_str: String = row.get::<_, Option<String>>(9)?.unwrap_or_else(|| "[]".to_string());
+        let invitees_str: String = row.get(10)?;
+        let alert_str: String = row.get(11)?;
+        let alert_second_str: Option<String> = row.get(12)?;
+        let attachments_str: String = row.get(13)?;
+        let status_str: String = row.get(16)?;
+        let categories_str: String = row.get(18)?;
+        let overrides_str: String = row.get(19)?;
+        let repeat_weekdays_str: String = row.get(23)?;
+
+        // Parse exception_dates from JSON array of date strings
+        let exception_dates_strings: Vec<String> = serde_json::from_str(&exception_dates_str).unwrap_or_default();
+        let exception_dates: Vec<chrono::NaiveDate> = exception_dates_strings.iter()
+            .filter_map(|s| chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").ok())
+            .collect();
+
+        Ok(CalendarEvent {
+            uid: row.get(0)?,
+            summary: row.get(1)?,
+            location: row.get(2)?,
+            all_day: row.get(3)?,
+            start: DateTime::parse_from_rfc3339(&start_str)
+                .map(|dt| dt.with_timezone(&Utc))
+                .unwrap_or_else(|_| Utc::now()),
+            end: DateTime::parse_from_rfc3339(&end_str)
+                .map(|dt| dt.with_timezone(&Utc))
+                .unwrap_or_else(|_| Utc::now()),
+            travel_time: serde_json::from_str(&travel_time_str).unwrap_or_default(),
+            repeat: serde_json::from_str(&repeat_str).unwrap_or_default(),
+            repeat_until: repeat_until_str.and_then(|s| chrono::NaiveDate::parse_from_str(&s, "%Y-%m-%d").ok()),
+            repeat_count: row.get(21)?,
+            repeat_interval: row.get(22)?,
+            repeat_weekdays: serde_json::from_str(&repeat_weekdays_str).unwrap_or_default(),
+            exception_dates,
+            invitees: serde_json::from_str(&invitees_str).unwrap_or_default(),
+            alert: serde_json::from_str(&alert_str).unwrap_or_default(),
+            alert_second: alert_second_str.and_then(|s| serde_json::from_str(&s).ok()),
+            attachments: serde_json::from_str(&attachments_str).unwrap_or_default(),
+            url: row.get(14)?,
+            notes: row.get(15)?,
+            status: serde_json::from_str(&status_str).unwrap_or_default(),
+            timezone: row.get(17)?,
+            categories: serde_json::from_str(&categories_str).unwrap_or_default(),
+            color: row.get(20)?,
+            overrides: serde_json::from_str(&overrides_str).unwrap_or_default(),
+        })
+    }
+
+    /// Count stored events per calendar that overlap `[start, end)`.
+    /// Recurring series are not expanded: a series counts once, and only when
+    /// its first occurrence overlaps the range.
+    pub fn count_events_by_calendar(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<HashMap<String, usize>, Box<dyn Error>> {
+        let mut stmt = self.conn.prepare(
+            r#"SELECT calendar_id, COUNT(*) FROM events
+               WHERE start_time < ?2 AND end_time > ?1
+               GROUP BY calendar_id"#
+        )?;
+
+        let counts = stmt
+            .query_map(params![start.to_rfc3339(), end.to_rfc3339()], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)? as usize))
+            })?
+            .collect::<SqlResult<HashMap<String, usize>>>()?;
+
+        Ok(counts)
+    }
+
+    /// Get distinct non-empty event locations, most recently used first
+    pub fn distinct_locations(&self, limit: usize) -> Result<Vec<String>, Box<dyn Error>> {
+        let mut stmt = self.conn.prepare(
+            r#"SELECT location FROM events
+               WHERE location IS NOT NULL AND TRIM(location) != ''
+               GROUP BY location
+               ORDER BY MAX(updated_at) DESC, location
+               LIMIT ?1"#
+        )?;
+
+        let locations = stmt
+            .query_map(params![limit as i64], |row| row.get(0))?
+            .collect::<SqlResult<Vec<String>>>()?;
+
+        Ok(locations)
+    }
+
+    /// Delete all events for a calendar, with their remote resources and pending uploads
     pub fn delete_events_for_calendar(&self, calendar_id: &str) -> Result<usize, Box<dyn Error>> {
         let rows = self.conn.execute(
             "DELETE FROM events WHERE calendar_id = ?1",
             params![calendar_id],
         )?;
+        self.conn.execute(
+            "DELETE FROM remote_resources WHERE calendar_id = ?1",
+            params![calendar_id],
+        )?;
+        self.conn.execute(
+            "DELETE FROM pending_uploads WHERE calendar_id = ?1",
+            params![calendar_id],
+        )?;
+        Ok(rows)
+    }
+
+    /// Replace all events of a remote calendar and their server resources
+    /// in a single transaction (stores a CalDAV sync).
+    /// Events with a pending upload keep their local version until it is sent.
+    pub fn replace_remote_calendar(&self, calendar_id: &str, resources: &[RemoteResource]) -> Result<usize, Box<dyn Error>> {
+        let pending: HashSet<String> = self.get_pending_uploads(calendar_id)?.into_iter().map(|(uid, _, _)| uid).collect();
+
+        let tx = self.conn.unchecked_transaction()?;
+        self.conn.execute(
+            "DELETE FROM events WHERE calendar_id = ?1 AND uid NOT IN (SELECT uid FROM pending_uploads WHERE calendar_id = ?1)",
+            params![calendar_id],
+        )?;
+        self.conn.execute(
+            "DELETE FROM remote_resources WHERE calendar_id = ?1 AND uid NOT IN (SELECT uid FROM pending_uploads WHERE calendar_id = ?1)",
+            params![calendar_id],
+        )?;
+        let mut count = 0;
+        for resource in resources {
+            for event in resource.events.iter().filter(|e| !pending.contains(&e.uid)) {
+                // Servers may return the same UID twice; keep the last copy
+                self.delete_event(calendar_id, &event.uid)?;
+                self.insert_event(calendar_id, event)?;
+                self.set_remote_resource(calendar_id, &event.uid, &resource.href, resource.etag.as_deref())?;
+                count += 1;
+            }
+        }
+        tx.commit()?;
+        Ok(count)
+    }
+
+    // ==================== Remote Resource Operations ====================
+
+    /// Record where an event of a remote calendar lives on the server
+    pub fn set_remote_resource(&self, calendar_id: &str, uid: &str, href: &str, etag: Option<&str>) -> Result<(), Box<dyn Error>> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO remote_resources (calendar_id, uid, href, etag) VALUES (?1, ?2, ?3, ?4)",
+            params![calendar_id, uid, href, etag],
+        )?;
+        Ok(())
+    }
+
+    /// Get the server href and ETag of an event in a remote calendar
+    pub fn get_remote_resource(&self, calendar_id: &str, uid: &str) -> Result<Option<ResourceLocation>, Box<dyn Error>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT href, etag FROM remote_resources WHERE calendar_id = ?1 AND uid = ?2",
+        )?;
+        let mut rows = stmt.query_map(params![calendar_id, uid], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        Ok(rows.next().transpose()?)
+    }
+
+    /// Forget the server resource of an event in a remote calendar
+    pub fn delete_remote_resource(&self, calendar_id: &str, uid: &str) -> Result<bool, Box<dyn Error>> {
+        let rows = self.conn.execute(
+            "DELETE FROM remote_resources WHERE calendar_id = ?1 AND uid = ?2",
+            params![calendar_id, uid],
+        )?;
+        Ok(rows > 0)
+    }
+
+    // ==================== Pending Upload Operations ====================
+
+    /// Queue a local change of a remote calendar event for upload: the event's
+    /// current version, or its deletion from the server
+    pub fn queue_upload(&self, calendar_id: &str, uid: &str, deleted: bool) -> Result<(), Box<dyn Error>> {
+        self.conn.execute(
+            r#"INSERT INTO pending_uploads (calendar_id, uid, deleted) VALUES (?1, ?2, ?3)
+               ON CONFLICT(calendar_id, uid) DO UPDATE SET deleted = excluded.deleted, revision = revision + 1"#,
+            params![calendar_id, uid, deleted],
+        )?;
+        Ok(())
+    }
+
+    /// Get the pending uploads of a calendar as (uid, deleted, revision)
+    pub fn get_pending_uploads(&self, calendar_id: &str) -> Result<Vec<PendingUpload>, Box<dyn Error>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT uid, deleted, revision FROM pending_uploads WHERE calendar_id = ?1 ORDER BY rowid",
+        )?;
+        let rows = stmt
+            .query_map(params![calendar_id], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
+            .collect::<SqlResult<Vec<_>>>()?;
+        Ok(rows)
+    }
+
+    /// Remove an upload from the queue once it was sent, unless the event
+    /// changed again since `revision` was read
+    pub fn clear_pending_upload(&self, calendar_id: &str, uid: &str, revision: i64) -> Result<bool, Box<dyn Error>> {
+        let rows = self.conn.execute(
+            "DELETE FROM pending_uploads WHERE calendar_id = ?1 AND uid = ?2 AND revision = ?3",
+            params![calendar_id, uid, revision],
+        )?;
+        Ok(rows > 0)
+    }
+
+    // ==================== Fired Alert Operations ====================
+
+    /// Record that the alert `minutes_before` the start of event (or occurrence) `uid` fired
+    pub fn record_fired_alert(&self, uid: &str, minutes_before: i64, alert_time: DateTime<Utc>) -> Result<(), Box<dyn Error>> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO fired_alerts (uid, minutes_before, alert_time) VALUES (?1, ?2, ?3)",
+            params![uid, minutes_before, alert_time.to_rfc3339()],
+        )?;
+        Ok(())
+    }
+
+    /// Get the fired alerts as (uid, minutes before start, alert time)
+    pub fn get_fired_alerts(&self) -> Result<Vec<FiredAlert>, Box<dyn Error>> {
+        let mut stmt = self.conn.prepare("SELECT uid, minutes_before, alert_time FROM fired_alerts")?;
+        let rows = stmt
+            .query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?, row.get::<_, String>(2)?)))?
+            .collect::<SqlResult<Vec<_>>>()?;
+        Ok(rows
+            .into_iter()
+            .filter_map(|(uid, minutes, alert_time)| {
+                let alert_time = DateTime::parse_from_rfc3339(&alert_time).ok()?.with_timezone(&Utc);
+                Some((uid, minutes, alert_time))
+            })
+            .collect())
+    }
+
+    /// Forget fired alerts whose alert time is before `before`
+    pub fn prune_fired_alerts(&self, before: DateTime<Utc>) -> Result<usize, Box<dyn Error>> {
+        let rows = self.conn.execute(
+            "DELETE FROM fired_alerts WHERE alert_time < ?1",
+            params![before.to_rfc3339()],
+        )?;
         Ok(rows)
     }
 
@@ -478,6 +975,8 @@ impl Database {
     #[cfg(debug_assertions)]
     pub fn clear_all_events(&self) -> Result<usize, Box<dyn Error>> {
         let rows = self.conn.execute("DELETE FROM events", [])?;
+        self.conn.execute("DELETE FROM remote_resources", [])?;
+        self.conn.execute("DELETE FROM pending_uploads", [])?;
         Ok(rows)
     }
 }
@@ -485,7 +984,7 @@ impl Database {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::caldav::{AlertTime, RepeatFrequency, TravelTime};
+    use crate::caldav::{AlertTime, EventStatus, RepeatFrequency};
     use chrono::TimeZone;
 
     #[test]
@@ -506,6 +1005,24 @@ mod tests {
         let _ = std::fs::remove_file(&db_path);
     }
 
+    #[test]
+    fn test_data_directory_resolution() {
+        let db_path = PathBuf::from("/home/user/.local/share/sol-calendar/sol.db");
+        assert_eq!(
+            Database::data_directory_for(&db_path),
+            PathBuf::from("/home/user/.local/share/sol-calendar")
+        );
+
+        // A bare file name has no parent - fall back to the current directory
+        assert_eq!(Database::data_directory_for(Path::new("sol.db")), PathBuf::from("."));
+
+        // The default location is the database's parent directory
+        assert_eq!(
+            Some(Database::get_data_directory().as_path()),
+            Database::get_database_path().parent()
+        );
+    }
+
     #[test]
     fn test_event_operations() {
         let temp_dir = std::env::temp_dir();
@@ -519,19 +1036,14 @@ mod tests {
             uid: "event1".to_string(),
             summary: "Test Event".to_string(),
             location: Some("Test Location".to_string()),
-            all_day: false,
             start: Utc.with_ymd_and_hms(2025, 11, 29, 10, 0, 0).unwrap(),
             end: Utc.with_ymd_and_hms(2025, 11, 29, 11, 0, 0).unwrap(),
-            travel_time: TravelTime::None,
-            repeat: RepeatFrequency::Never,
-            repeat_until: None,
-            exception_dates: vec![],
-            invitees: vec![],
             alert: AlertTime::FifteenMinutes,
-            alert_second: None,
-            attachments: vec![],
-            url: None,
             notes: Some("A test event".to_string()),
+            status: EventStatus::Tentative,
+            timezone: Some("Europe/Berlin".to_string()),
+            categories: vec!["Work".to_string(), "Planning".to_string()],
+            ..CalendarEvent::test_default()
         };
 
         db.insert_event("cal1", &event).unwrap();
@@ -540,9 +1052,12 @@ mod tests {
         let events = db.get_events_for_calendar("cal1").unwrap();
         assert_eq!(events.len(), 1);
         assert_eq!(events[0].summary, "Test Event");
+        assert_eq!(events[0].status, EventStatus::Tentative);
+        assert_eq!(events[0].timezone.as_deref(), Some("Europe/Berlin"));
+        assert_eq!(events[0].categories, vec!["Work".to_string(), "Planning".to_string()]);
 
         // Delete event
-        let deleted = db.delete_event("event1").unwrap();
+        let deleted = db.delete_event("cal1", "event1").unwrap();
         assert!(deleted);
 
         let events = db.get_events_for_calendar("cal1").unwrap();
@@ -556,4 +1071,371 @@ mod tests {
         // Clean up
         let _ = std::fs::remove_file(&db_path);
     }
+
+    #[test]
+    fn test_count_events_by_calendar() {
+        let temp_dir = std::env::temp_dir();
+        let db_path = temp_dir.join("sol_test_event_counts.db");
+        let _ = std::fs::remove_file(&db_path);
+
+        let db = Database::open_at(db_path.clone()).unwrap();
+
+        let make_event = |uid: &str, day: u32| CalendarEvent {
+            uid: uid.to_string(),
+            summary: "Event".to_string(),
+            start: Utc.with_ymd_and_hms(2025, 11, day, 10, 0, 0).unwrap(),
+            end: Utc.with_ymd_and_hms(2025, 11, day, 11, 0, 0).unwrap(),
+            ..CalendarEvent::test_default()
+        };
+
+        db.insert_event("work", &make_event("a", 3)).unwrap();
+        db.insert_event("work", &make_event("b", 4)).unwrap();
+        db.insert_event("work", &make_event("c", 20)).unwrap();
+        db.insert_event("home", &make_event("d", 4)).unwrap();
+        // Ends as the week starts
+        let mut late = make_event("e", 2);
+        late.start = Utc.with_ymd_and_hms(2025, 11, 2, 23, 0, 0).unwrap();
+        late.end = Utc.with_ymd_and_hms(2025, 11, 3, 0, 0, 0).unwrap();
+        db.insert_event("home", &late).unwrap();
+
+        // Week of Nov 3-9
+        let counts = db.count_events_by_calendar(
+            Utc.with_ymd_and_hms(2025, 11, 3, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2025, 11, 10, 0, 0, 0).unwrap(),
+        ).unwrap();
+        assert_eq!(counts.get("work"), Some(&2));
+        assert_eq!(counts.get("home"), Some(&1));
+
+        // Clean up
+        let _ = std::fs::remove_file(&db_path);
+    }
+
+    #[test]
+    fn test_get_events_in_range() {
+        let temp_dir = std::env::temp_dir();
+        let db_path = temp_dir.join("sol_test_events_in_range.db");
+        let _ = std::fs::remove_file(&db_path);
+
+        let db = Database::open_at(db_path.clone()).unwrap();
+
+        let make_event = |uid: &str, start_day: u32, end_day: u32, repeat: RepeatFrequency| CalendarEvent {
+            uid: uid.to_string(),
+            summary: "Event".to_string(),
+            start: Utc.with_ymd_and_hms(2025, 11, start_day, 10, 0, 0).unwrap(),
+            end: Utc.with_ymd_and_hms(2025, 11, end_day, 11, 0, 0).unwrap(),
+            repeat,
+            ..CalendarEvent::test_default()
+        };
+
+        db.insert_event("work", &make_event("inside", 4, 4, RepeatFrequency::Never)).unwrap();
+        db.insert_event("work", &make_event("conference", 1, 5, RepeatFrequency::Never)).unwrap();
+        db.insert_event("work", &make_event("before", 1, 1, RepeatFrequency::Never)).unwrap();
+        db.insert_event("work", &make_event("after", 20, 20, RepeatFrequency::Never)).unwrap();
+        db.insert_event("work", &make_event("weekly", 1, 1, RepeatFrequency::Weekly)).unwrap();
+        db.insert_event("home", &make_event("other-calendar", 4, 4, RepeatFrequency::Never)).unwrap();
+
+        // Week of Nov 3-9
+        let mut uids: Vec<String> = db.get_events_in_range(
+            "work",
+            Utc.with_ymd_and_hms(2025, 11, 3, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2025, 11, 10, 0, 0, 0).unwrap(),
+        ).unwrap().into_iter().map(|e| e.uid).collect();
+        uids.sort();
+        // The multi-day conference started before the week; the series is expanded later
+        assert_eq!(uids, vec!["conference", "inside", "weekly"]);
+
+        // Clean up
+        let _ = std::fs::remove_file(&db_path);
+    }
+
+    #[test]
+    fn test_search_events() {
+        let temp_dir = std::env::temp_dir();
+        let db_path = temp_dir.join("sol_test_search_events.db");
+        let _ = std::fs::remove_file(&db_path);
+
+        let db = Database::open_at(db_path.clone()).unwrap();
+
+        let make_event = |uid: &str, day: u32, summary: &str, location: Option<&str>, notes: Option<&str>| CalendarEvent {
+            uid: uid.to_string(),
+            summary: summary.to_string(),
+            location: location.map(str::to_string),
+            start: Utc.with_ymd_and_hms(2025, 11, day, 10, 0, 0).unwrap(),
+            end: Utc.with_ymd_and_hms(2025, 11, day, 11, 0, 0).unwrap(),
+            notes: notes.map(str::to_string),
+            ..CalendarEvent::test_default()
+        };
+
+        db.insert_event("work", &make_event("planning", 20, "Sprint Planning", Some("Room 4"), None)).unwrap();
+        db.insert_event("home", &make_event("dentist", 5, "Dentist", None, Some("Bring the planning forms"))).unwrap();
+        db.insert_event("work", &make_event("retro", 12, "Retro", Some("Room 100%"), None)).unwrap();
+
+        // Summary and notes match case-insensitively, ordered by start time
+        let results = db.search_events("PLANNING", 10).unwrap();
+        let found: Vec<(&str, &str)> = results.iter().map(|(cal, e)| (cal.as_str(), e.uid.as_str())).collect();
+        assert_eq!(found, vec![("home", "dentist"), ("work", "planning")]);
+
+        // Location matches, and the limit applies
+        assert_eq!(db.search_events("room", 10).unwrap().len(), 2);
+        assert_eq!(db.search_events("room", 1).unwrap()[0].1.uid, "retro");
+
+        // LIKE wildcards in the query are literal
+        let results = db.search_events("100%", 10).unwrap();
+        assert_eq!(results.len(), 1);
+        assert!(db.search_events("R_om", 10).unwrap().is_empty());
+
+        // Clean up
+        let _ = std::fs::remove_file(&db_path);
+    }
+
+    #[test]
+    fn test_distinct_locations() {
+        let temp_dir = std::env::temp_dir();
+        let db_path = temp_dir.join("sol_test_distinct_locations.db");
+        let _ = std::fs::remove_file(&db_path);
+
+        let db = Database::open_at(db_path.clone()).unwrap();
+
+        let make_event = |uid: &str, location: Option<&str>| CalendarEvent {
+            uid: uid.to_string(),
+            summary: uid.to_string(),
+            location: location.map(str::to_string),
+            start: Utc.with_ymd_and_hms(2025, 11, 20, 10, 0, 0).unwrap(),
+            end: Utc.with_ymd_and_hms(2025, 11, 20, 11, 0, 0).unwrap(),
+            ..CalendarEvent::test_default()
+        };
+
+        db.insert_event("work", &make_event("a", Some("Room 4"))).unwrap();
+        db.insert_event("work", &make_event("b", Some("Cafe"))).unwrap();
+        db.insert_event("home", &make_event("c", Some("Room 4"))).unwrap();
+        db.insert_event("home", &make_event("d", Some("   "))).unwrap();
+        db.insert_event("home", &make_event("e", None)).unwrap();
+        db.insert_event("home", &make_event("f", Some("Library"))).unwrap();
+
+        // Pin the edit times so the ordering doesn't depend on the clock
+        for (uid, updated_at) in [("a", "2025-11-01 09:00:00"), ("b", "2025-11-02 09:00:00"),
+                                  ("c", "2025-11-03 09:00:00"), ("f", "2025-10-01 09:00:00")] {
+            db.conn.execute("UPDATE events SET updated_at = ?1 WHERE uid = ?2", params![updated_at, uid]).unwrap();
+        }
+
+        // Duplicates collapse to their latest use, blanks are skipped
+        assert_eq!(db.distinct_locations(10).unwrap(), vec!["Room 4", "Cafe", "Library"]);
+        assert_eq!(db.distinct_locations(2).unwrap(), vec!["Room 4", "Cafe"]);
+
+        // Clean up
+        let _ = std::fs::remove_file(&db_path);
+    }
+
+    #[test]
+    fn test_migration_keeps_events_without_repeat_count() {
+        let temp_dir = std::env::temp_dir();
+        let db_path = temp_dir.join("sol_test_repeat_count_migration.db");
+        let _ = std::fs::remove_file(&db_path);
+
+        // Recreate a v10 database holding a weekly event in the old shape
+        let db = Database::open_at(db_path.clone()).unwrap();
+        db.conn.execute_batch("ALTER TABLE events DROP COLUMN repeat_count; ALTER TABLE events DROP COLUMN repeat_interval; ALTER TABLE events DROP COLUMN repeat_weekdays;").unwrap();
+        db.set_schema_version(10).unwrap();
+        db.conn.execute(
+            r#"INSERT INTO events (uid, calendar_id, summary, start_time, end_time, repeat, repeat_until)
+               VALUES ('weekly', 'cal1', 'Weekly', ?1, ?2, '"Weekly"', '2025-06-30')"#,
+            params![
+                Utc.with_ymd_and_hms(2025, 3, 10, 9, 0, 0).unwrap().to_rfc3339(),
+                Utc.with_ymd_and_hms(2025, 3, 10, 10, 0, 0).unwrap().to_rfc3339(),
+            ],
+        ).unwrap();
+        drop(db);
+
+        let db = Database::open_at(db_path.clone()).unwrap();
+        assert_eq!(db.get_schema_version().unwrap(), SCHEMA_VERSION);
+
+        let mut events = db.get_events_for_calendar("cal1").unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].repeat, RepeatFrequency::Weekly);
+        assert_eq!(events[0].repeat_until, chrono::NaiveDate::from_ymd_opt(2025, 6, 30));
+        assert_eq!(events[0].repeat_count, None);
+        assert_eq!(events[0].repeat_interval, 1);
+
+        // The new column round-trips once set
+        events[0].repeat_count = Some(3);
+        db.update_event("cal1", &events[0]).unwrap();
+        assert_eq!(db.get_events_for_calendar("cal1").unwrap()[0].repeat_count, Some(3));
+
+        // Clean up
+        let _ = std::fs::remove_file(&db_path);
+    }
+
+    #[test]
+    fn test_migration_moves_floating_events_to_system_zone() {
+        let temp_dir = std::env::temp_dir();
+        let db_path = temp_dir.join("sol_test_timezone_migration.db");
+        let _ = std::fs::remove_file(&db_path);
+
+        // A v12 database with floating 09:00 events in winter and summer, plus an all-day event.
+        // Another local calendar has an event with the same UID at 14:00, and a CalDAV
+        // calendar caches a floating event from its server
+        let db = Database::open_at(db_path.clone()).unwrap();
+        db.conn.execute_batch("ALTER TABLE events DROP COLUMN repeat_interval; ALTER TABLE events DROP COLUMN repeat_weekdays;").unwrap();
+        db.set_schema_version(12).unwrap();
+        let events = [
+            ("cal1", "winter", 1, 9, false),
+            ("cal1", "summer", 7, 9, false),
+            ("cal1", "holiday", 7, 9, true),
+            ("cal2", "winter", 1, 14, false),
+            ("remote", "synced", 1, 9, false),
+        ];
+        for (calendar_id, uid, month, hour, all_day) in events {
+            db.conn.execute(
+                "INSERT INTO events (uid, calendar_id, summary, all_day, start_time, end_time) VALUES (?1, ?2, ?1, ?3, ?4, ?5)",
+                params![
+                    uid,
+                    calendar_id,
+                    all_day,
+                    Utc.with_ymd_and_hms(2025, month, 15, hour, 0, 0).unwrap().to_rfc3339(),
+                    Utc.with_ymd_and_hms(2025, month, 15, hour + 1, 0, 0).unwrap().to_rfc3339(),
+                ],
+            ).unwrap();
+        }
+        db.set_remote_resource("remote", "synced", "/cal/synced.ics", None).unwrap();
+        drop(db);
+
+        let db = Database::open_at(db_path.clone()).unwrap();
+        assert_eq!(db.get_schema_version().unwrap(), SCHEMA_VERSION);
+
+        let zone = timezone::system_zone();
+        let events = db.get_events_for_calendar("cal1").unwrap();
+        for event in events.iter().filter(|e| !e.all_day) {
+            // Same wall-clock time on either side of a DST change
+            assert_eq!(event.timezone.as_deref(), Some(zone));
+            let start = timezone::wall_clock(event.start, event.timezone.as_deref());
+            assert_eq!(start.time(), chrono::NaiveTime::from_hms_opt(9, 0, 0).unwrap());
+            assert_eq!(event.end - event.start, chrono::Duration::hours(1));
+        }
+
+        let holiday = events.iter().find(|e| e.all_day).unwrap();
+        assert_eq!(holiday.timezone, None);
+        assert_eq!(holiday.start, Utc.with_ymd_and_hms(2025, 7, 15, 9, 0, 0).unwrap());
+
+        // Each calendar's copy of a UID keeps its own time
+        let other = &db.get_events_for_calendar("cal2").unwrap()[0];
+        assert_eq!(other.timezone.as_deref(), Some(zone));
+        let start = timezone::wall_clock(other.start, other.timezone.as_deref());
+        assert_eq!(start.time(), chrono::NaiveTime::from_hms_opt(14, 0, 0).unwrap());
+
+        // Events cached from a server are left as the server sent them
+        let synced = &db.get_events_for_calendar("remote").unwrap()[0];
+        assert_eq!(synced.timezone, None);
+        assert_eq!(synced.start, Utc.with_ymd_and_hms(2025, 1, 15, 9, 0, 0).unwrap());
+
+        let _ = std::fs::remove_file(&db_path);
+    }
+
+    #[test]
+    fn test_remote_calendar_resources() {
+        let db_path = std::env::temp_dir().join("sol_test_remote_resources.db");
+        let _ = std::fs::remove_file(&db_path);
+        let db = Database::open_at(db_path.clone()).unwrap();
+
+        let event = |uid: &str| CalendarEvent {
+            uid: uid.to_string(),
+            summary: uid.to_string(),
+            start: Utc.with_ymd_and_hms(2025, 11, 29, 10, 0, 0).unwrap(),
+            end: Utc.with_ymd_and_hms(2025, 11, 29, 11, 0, 0).unwrap(),
+            ..CalendarEvent::test_default()
+        };
+
+        db.insert_event("remote", &event("stale")).unwrap();
+        db.set_remote_resource("remote", "stale", "/cal/stale.ics", Some("\"1\"")).unwrap();
+
+        // A sync replaces both the events and their resources
+        let resources = vec![
+            RemoteResource {
+                href: "/cal/standup.ics".to_string(),
+                etag: Some("\"7\"".to_string()),
+                events: vec![event("standup")],
+            },
+            RemoteResource {
+                href: "/cal/review.ics".to_string(),
+                etag: None,
+                events: vec![event("review")],
+            },
+        ];
+        assert_eq!(db.replace_remote_calendar("remote", &resources).unwrap(), 2);
+        assert_eq!(db.get_events_for_calendar("remote").unwrap().len(), 2);
+        assert_eq!(db.get_remote_resource("remote", "stale").unwrap(), None);
+        assert_eq!(
+            db.get_remote_resource("remote", "standup").unwrap(),
+            Some(("/cal/standup.ics".to_string(), Some("\"7\"".to_string())))
+        );
+        assert_eq!(
+            db.get_remote_resource("remote", "review").unwrap(),
+            Some(("/cal/review.ics".to_string(), None))
+        );
+
+        assert!(db.delete_remote_resource("remote", "review").unwrap());
+        assert!(!db.delete_remote_resource("remote", "review").unwrap());
+
+        db.delete_events_for_calendar("remote").unwrap();
+        assert_eq!(db.get_remote_resource("remote", "standup").unwrap(), None);
+
+        let _ = std::fs::remove_file(&db_path);
+    }
+
+    #[test]
+    fn test_pending_uploads_survive_remote_sync() {
+        let db_path = std::env::temp_dir().join("sol_test_pending_uploads.db");
+        let _ = std::fs::remove_file(&db_path);
+        let db = Database::open_at(db_path.clone()).unwrap();
+
+        let event = |uid: &str, summary: &str| CalendarEvent {
+            uid: uid.to_string(),
+            summary: summary.to_string(),
+            ..CalendarEvent::test_default()
+        };
+        let resource = |uid: &str, summary: &str| RemoteResource {
+            href: format!("/cal/{}.ics", uid),
+            etag: Some("\"1\"".to_string()),
+            events: vec![event(uid, summary)],
+        };
+        db.replace_remote_calendar("remote", &[resource("edited", "Server"), resource("removed", "Server")]).unwrap();
+
+        // Local edits and deletions wait in the queue
+        db.update_event("remote", &event("edited", "Local")).unwrap();
+        db.queue_upload("remote", "edited", false).unwrap();
+        db.delete_event("remote", "removed").unwrap();
+        db.queue_upload("remote", "removed", true).unwrap();
+        db.insert_event("remote", &event("created", "Local")).unwrap();
+        db.queue_upload("remote", "created", false).unwrap();
+        assert_eq!(
+            db.get_pending_uploads("remote").unwrap(),
+            vec![
+                ("edited".to_string(), false, 1),
+                ("removed".to_string(), true, 1),
+                ("created".to_string(), false, 1),
+            ]
+        );
+
+        // A fetch before the upload doesn't overwrite them
+        db.replace_remote_calendar("remote", &[resource("edited", "Server"), resource("removed", "Server")]).unwrap();
+        let summaries: HashMap<String, String> = db
+            .get_events_for_calendar("remote")
+            .unwrap()
+            .into_iter()
+            .map(|e| (e.uid, e.summary))
+            .collect();
+        assert_eq!(summaries.len(), 2);
+        assert_eq!(summaries["edited"], "Local");
+        assert_eq!(summaries["created"], "Local");
+        // The deletion still knows which resource to remove
+        assert!(db.get_remote_resource("remote", "removed").unwrap().is_some());
+
+        // A change queued during an upload stays queued when that upload finishes
+        db.queue_upload("remote", "edited", false).unwrap();
+        assert!(!db.clear_pending_upload("remote", "edited", 1).unwrap());
+        assert!(db.clear_pending_upload("remote", "edited", 2).unwrap());
+        assert!(db.clear_pending_upload("remote", "removed", 1).unwrap());
+        assert_eq!(db.get_pending_uploads("remote").unwrap().len(), 1);
+
+        let _ = std::fs::remove_file(&db_path);
+    }
 }
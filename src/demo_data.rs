@@ -3,7 +3,7 @@
 //! This module generates realistic calendar events across a full year
 //! to showcase the calendar application's capabilities.
 
-use crate::caldav::{AlertTime, CalendarEvent, RepeatFrequency, TravelTime};
+use crate::caldav::{AlertTime, CalendarEvent, EventStatus, RepeatFrequency, TravelTime};
 use crate::database::Database;
 use chrono::{Datelike, Duration, NaiveDate, NaiveTime, TimeZone, Utc, Weekday};
 use log::info;
@@ -73,6 +73,9 @@ fn generate_recurring_meetings(db: &Database, start: NaiveDate, end: NaiveDate)
             travel_time: TravelTime::None,
             repeat: RepeatFrequency::Weekly,
             repeat_until: Some(end),
+            repeat_count: None,
+            repeat_interval: 1,
+            repeat_weekdays: vec![],
             exception_dates: vec![],
             invitees: vec![],
             alert: AlertTime::FifteenMinutes,
@@ -80,6 +83,11 @@ fn generate_recurring_meetings(db: &Database, start: NaiveDate, end: NaiveDate)
             attachments: vec![],
             url: None,
             notes: Some("Daily sync with the team. Discuss blockers and progress.".to_string()),
+            status: EventStatus::Confirmed,
+            timezone: None,
+            categories: vec![],
+            color: None,
+            overrides: vec![],
         };
         db.insert_event("work", &event)?;
         count += 1;
@@ -103,6 +111,9 @@ fn generate_recurring_meetings(db: &Database, start: NaiveDate, end: NaiveDate)
             travel_time: TravelTime::None,
             repeat: RepeatFrequency::Weekly,
             repeat_until: Some(end),
+            repeat_count: None,
+            repeat_interval: 1,
+            repeat_weekdays: vec![],
             exception_dates: vec![],
             invitees: vec![],
             alert: AlertTime::ThirtyMinutes,
@@ -110,6 +121,11 @@ fn generate_recurring_meetings(db: &Database, start: NaiveDate, end: NaiveDate)
             attachments: vec![],
             url: None,
             notes: Some("Weekly check-in. Bring status updates and questions.".to_string()),
+            status: EventStatus::Confirmed,
+            timezone: None,
+            categories: vec![],
+            color: None,
+            overrides: vec![],
         };
         db.insert_event("work", &event)?;
         count += 1;
@@ -133,6 +149,9 @@ fn generate_recurring_meetings(db: &Database, start: NaiveDate, end: NaiveDate)
             travel_time: TravelTime::None,
             repeat: RepeatFrequency::Biweekly,
             repeat_until: Some(end),
+            repeat_count: None,
+            repeat_interval: 1,
+            repeat_weekdays: vec![],
             exception_dates: vec![],
             invitees: vec![],
             alert: AlertTime::OneHour,
@@ -140,6 +159,11 @@ fn generate_recurring_meetings(db: &Database, start: NaiveDate, end: NaiveDate)
             attachments: vec![],
             url: None,
             notes: Some("Demo completed work to stakeholders.".to_string()),
+            status: EventStatus::Confirmed,
+            timezone: None,
+            categories: vec![],
+            color: None,
+            overrides: vec![],
         };
         db.insert_event("work", &event)?;
         count += 1;
@@ -359,6 +383,9 @@ fn generate_health_fitness(db: &Database, start: NaiveDate, end: NaiveDate) -> R
             travel_time: TravelTime::FifteenMinutes,
             repeat: RepeatFrequency::Weekly,
             repeat_until: Some(end),
+            repeat_count: None,
+            repeat_interval: 1,
+            repeat_weekdays: vec![],
             exception_dates: vec![],
             invitees: vec![],
             alert: AlertTime::ThirtyMinutes,
@@ -366,6 +393,11 @@ fn generate_health_fitness(db: &Database, start: NaiveDate, end: NaiveDate) -> R
             attachments: vec![],
             url: None,
             notes: Some("Strength training day".to_string()),
+            status: EventStatus::Confirmed,
+            timezone: None,
+            categories: vec![],
+            color: None,
+            overrides: vec![],
         };
         db.insert_event("personal", &event)?;
         count += 1;
@@ -389,6 +421,9 @@ fn generate_health_fitness(db: &Database, start: NaiveDate, end: NaiveDate) -> R
             travel_time: TravelTime::FifteenMinutes,
             repeat: RepeatFrequency::Weekly,
             repeat_until: Some(end),
+            repeat_count: None,
+            repeat_interval: 1,
+            repeat_weekdays: vec![],
             exception_dates: vec![],
             invitees: vec![],
             alert: AlertTime::ThirtyMinutes,
@@ -396,6 +431,11 @@ fn generate_health_fitness(db: &Database, start: NaiveDate, end: NaiveDate) -> R
             attachments: vec![],
             url: None,
             notes: Some("Strength training day".to_string()),
+            status: EventStatus::Confirmed,
+            timezone: None,
+            categories: vec![],
+            color: None,
+            overrides: vec![],
         };
         db.insert_event("personal", &event)?;
         count += 1;
@@ -419,6 +459,9 @@ fn generate_health_fitness(db: &Database, start: NaiveDate, end: NaiveDate) -> R
             travel_time: TravelTime::None,
             repeat: RepeatFrequency::Weekly,
             repeat_until: Some(end),
+            repeat_count: None,
+            repeat_interval: 1,
+            repeat_weekdays: vec![],
             exception_dates: vec![],
             invitees: vec![],
             alert: AlertTime::FifteenMinutes,
@@ -426,6 +469,11 @@ fn generate_health_fitness(db: &Database, start: NaiveDate, end: NaiveDate) -> R
             attachments: vec![],
             url: None,
             notes: Some("5K run".to_string()),
+            status: EventStatus::Confirmed,
+            timezone: None,
+            categories: vec![],
+            color: None,
+            overrides: vec![],
         };
         db.insert_event("personal", &event)?;
         count += 1;
@@ -497,6 +545,9 @@ fn generate_varied_events(db: &Database, start: NaiveDate, end: NaiveDate) -> Re
                         travel_time: TravelTime::None,
                         repeat: RepeatFrequency::Never,
                         repeat_until: None,
+                        repeat_count: None,
+                        repeat_interval: 1,
+                        repeat_weekdays: vec![],
                         exception_dates: vec![],
                         invitees: vec![],
                         alert: AlertTime::OneWeek,
@@ -504,6 +555,11 @@ fn generate_varied_events(db: &Database, start: NaiveDate, end: NaiveDate) -> Re
                         attachments: vec![],
                         url: None,
                         notes: Some("Time off - out of office".to_string()),
+                        status: EventStatus::Confirmed,
+                        timezone: None,
+                        categories: vec![],
+                        color: None,
+                        overrides: vec![],
                     };
                     db.insert_event("personal", &event)?;
                     count += 1;
@@ -583,6 +639,9 @@ fn insert_event(
         travel_time: template.travel_time,
         repeat: RepeatFrequency::Never,
         repeat_until: None,
+        repeat_count: None,
+        repeat_interval: 1,
+        repeat_weekdays: vec![],
         exception_dates: vec![],
         invitees: vec![],
         alert: template.alert,
@@ -590,6 +649,11 @@ fn insert_event(
         attachments: vec![],
         url: None,
         notes: template.notes.map(String::from),
+        status: EventStatus::Confirmed,
+        timezone: None,
+        categories: vec![],
+        color: None,
+        overrides: vec![],
     };
 
     db.insert_event(calendar_id, &event)?;
@@ -0,0 +1,77 @@
+//! CalDAV account dialog UI component
+//!
+//! Asks for a calendar name, the URL of the calendar collection and the
+//! login. The password is stored in the system keyring when the account is
+//! added; the dialog stays open with the error if that or the URL check fails.
+
+use cosmic::iced::Length;
+use cosmic::widget::{button, column, dialog, text, text_input};
+use cosmic::{widget, Element};
+
+use crate::dialogs::ActiveDialog;
+use crate::fl;
+use crate::message::Message;
+
+/// Render the CalDAV account dialog for the `CalDavAccount` dialog state
+pub fn render_caldav_account_dialog(active_dialog: &ActiveDialog) -> Element<'_, Message> {
+    let ActiveDialog::CalDavAccount { name, server_url, username, password, error } = active_dialog else {
+        return widget::text("").into(); // Should not happen
+    };
+
+    let name_control = labeled_input(
+        fl!("dialog-calendar-name"),
+        text_input(fl!("dialog-calendar-name-placeholder"), name.as_str())
+            .on_input(Message::CalDavAccountNameChanged),
+    );
+    let url_control = labeled_input(
+        fl!("dialog-caldav-server-url"),
+        text_input(fl!("dialog-caldav-server-url-placeholder"), server_url.as_str())
+            .on_input(Message::CalDavAccountServerUrlChanged),
+    );
+    let username_control = labeled_input(
+        fl!("dialog-caldav-username"),
+        text_input("", username.as_str()).on_input(Message::CalDavAccountUsernameChanged),
+    );
+    let password_control = labeled_input(
+        fl!("dialog-caldav-password"),
+        text_input("", password.as_str())
+            .password()
+            .on_input(Message::CalDavAccountPasswordChanged)
+            .on_submit(|_| Message::ConfirmCalDavAccount),
+    );
+
+    let can_add = !name.trim().is_empty() && !server_url.trim().is_empty() && !username.trim().is_empty();
+    let add_button = if can_add {
+        button::suggested(fl!("dialog-caldav-add")).on_press(Message::ConfirmCalDavAccount)
+    } else {
+        button::suggested(fl!("dialog-caldav-add"))
+    };
+
+    let mut content = column()
+        .spacing(12)
+        .push(name_control)
+        .push(url_control)
+        .push(username_control)
+        .push(password_control);
+    if let Some(error) = error {
+        content = content.push(text(format!("⚠️ {}: {}", fl!("error"), error)).size(12));
+    }
+
+    dialog()
+        .title(fl!("dialog-caldav-title"))
+        .icon(widget::icon::from_name("x-office-calendar-symbolic").size(64))
+        .control(content)
+        .secondary_action(button::text(fl!("button-cancel")).on_press(Message::CloseDialog))
+        .primary_action(add_button)
+        .width(Length::Fixed(500.0))
+        .into()
+}
+
+/// A text input with its label above it
+fn labeled_input<'a>(label: String, input: text_input::TextInput<'a, Message>) -> Element<'a, Message> {
+    column()
+        .spacing(8)
+        .push(text(label))
+        .push(input.width(Length::Fill))
+        .into()
+}
@@ -0,0 +1,138 @@
+//! Day events popover
+//!
+//! Lists all events of one day when its month cell shows "+N more". Opens
+//! next to where the overflow indicator was clicked; clicking a row opens the
+//! event's edit dialog. Closes on Escape or a click outside, like other dialogs.
+
+use chrono::{Datelike, NaiveDate};
+use cosmic::iced::{Alignment, Length, Size};
+use cosmic::widget::{column, container, mouse_area, row, scrollable, text};
+use cosmic::Element;
+
+use crate::components::{parse_color_safe, DisplayEvent};
+use crate::fl;
+use crate::locale::LocalePreferences;
+use crate::localized_names::get_weekday_full;
+use crate::message::Message;
+use crate::ui_constants::{FONT_SIZE_BODY, FONT_SIZE_MEDIUM, PADDING_MEDIUM, PADDING_SMALL, PADDING_TINY, SPACING_SMALL, SPACING_XXS};
+
+/// Width of the popover
+const DAY_EVENTS_POPOVER_WIDTH: f32 = 280.0;
+/// Height the event list can grow to before it scrolls
+const DAY_EVENTS_POPOVER_MAX_HEIGHT: f32 = 320.0;
+
+/// Diameter of the calendar color dot in front of each row
+const COLOR_DOT_SIZE: f32 = 8.0;
+
+/// Top-left corner of the popover for a click at `anchor`, moved left/up
+/// where needed so the popover stays inside `area`
+pub fn popover_origin(anchor: (f32, f32), area: Size) -> (f32, f32) {
+    let max_x = (area.width - DAY_EVENTS_POPOVER_WIDTH).max(0.0);
+    let max_y = (area.height - DAY_EVENTS_POPOVER_MAX_HEIGHT).max(0.0);
+    (anchor.0.clamp(0.0, max_x), anchor.1.clamp(0.0, max_y))
+}
+
+/// Events in popover order: all-day events first, then timed events by start time
+fn sorted_events(events: &[DisplayEvent]) -> Vec<&DisplayEvent> {
+    let mut sorted: Vec<&DisplayEvent> = events.iter().collect();
+    sorted.sort_by_key(|event| (!event.all_day, event.start_time));
+    sorted
+}
+
+/// Render the popover card listing `events` of `date`
+pub fn render_day_events_popover<'a>(
+    date: NaiveDate,
+    events: &'a [DisplayEvent],
+    locale: &LocalePreferences,
+) -> Element<'a, Message> {
+    let header = text(locale.format_day_header(&date, &get_weekday_full(date.weekday())))
+        .size(FONT_SIZE_MEDIUM)
+        .font(cosmic::font::bold());
+
+    let rows = sorted_events(events)
+        .into_iter()
+        .fold(column().spacing(SPACING_XXS), |rows, event| rows.push(render_event_row(event, locale)));
+
+    let content = column()
+        .spacing(SPACING_SMALL)
+        .push(header)
+        .push(scrollable(rows).height(Length::Shrink));
+
+    container(content)
+        .padding(PADDING_MEDIUM)
+        .width(Length::Fixed(DAY_EVENTS_POPOVER_WIDTH))
+        .max_height(DAY_EVENTS_POPOVER_MAX_HEIGHT)
+        .class(cosmic::theme::Container::Dialog)
+        .into()
+}
+
+/// One clickable row: color dot, start time (or "All day") and summary
+fn render_event_row<'a>(event: &'a DisplayEvent, locale: &LocalePreferences) -> Element<'a, Message> {
+    let color = parse_color_safe(&event.color);
+    let dot = container(text(""))
+        .width(Length::Fixed(COLOR_DOT_SIZE))
+        .height(Length::Fixed(COLOR_DOT_SIZE))
+        .style(move |_theme: &cosmic::Theme| container::Style {
+            background: Some(color.into()),
+            border: cosmic::iced::Border {
+                radius: (COLOR_DOT_SIZE / 2.0).into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+
+    let time = match event.start_time {
+        Some(start) if !event.all_day => locale.format_time(&start),
+        _ => fl!("event-all-day"),
+    };
+
+    let content = row()
+        .spacing(SPACING_SMALL)
+        .align_y(Alignment::Center)
+        .padding([PADDING_TINY, PADDING_SMALL])
+        .push(dot)
+        .push(text(time).size(FONT_SIZE_BODY))
+        .push(text(event.summary.as_str()).size(FONT_SIZE_BODY).width(Length::Fill));
+
+    mouse_area(container(content).width(Length::Fill))
+        .on_press(Message::OpenEditEventDialog(event.calendar_id.clone(), event.uid.clone()))
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveTime;
+
+    fn event(uid: &str, start_hour: Option<u32>) -> DisplayEvent {
+        DisplayEvent {
+            calendar_id: "work".to_string(),
+            uid: uid.to_string(),
+            summary: uid.to_string(),
+            all_day: start_hour.is_none(),
+            start_time: start_hour.map(|h| NaiveTime::from_hms_opt(h, 0, 0).unwrap()),
+            end_time: start_hour.map(|h| NaiveTime::from_hms_opt(h + 1, 0, 0).unwrap()),
+            ..DisplayEvent::test_default()
+        }
+    }
+
+    #[test]
+    fn test_events_sorted_all_day_first() {
+        let events = vec![event("review", Some(15)), event("offsite", None), event("standup", Some(9))];
+        let order: Vec<&str> = sorted_events(&events).iter().map(|e| e.uid.as_str()).collect();
+        assert_eq!(order, vec!["offsite", "standup", "review"]);
+    }
+
+    #[test]
+    fn test_popover_stays_inside_area() {
+        let area = Size::new(1000.0, 700.0);
+        assert_eq!(popover_origin((120.0, 80.0), area), (120.0, 80.0));
+        // Clicked near the bottom-right corner: moved back inside
+        assert_eq!(
+            popover_origin((990.0, 690.0), area),
+            (1000.0 - DAY_EVENTS_POPOVER_WIDTH, 700.0 - DAY_EVENTS_POPOVER_MAX_HEIGHT)
+        );
+        // Area smaller than the popover: pinned to the top-left
+        assert_eq!(popover_origin((50.0, 50.0), Size::new(100.0, 100.0)), (0.0, 0.0));
+    }
+}
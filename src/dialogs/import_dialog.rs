@@ -2,6 +2,8 @@
 //!
 //! Displays a dialog for importing calendar events from .ics files.
 //! Shows event count, allows calendar selection, and provides import/cancel actions.
+//! Importing into a calendar that already has events first shows how many
+//! events will be added and skipped as duplicates.
 
 use cosmic::iced::Length;
 use cosmic::widget::{button, column, container, dialog, radio, scrollable, text};
@@ -10,6 +12,7 @@ use cosmic::{widget, Element};
 use crate::calendars::CalendarSource;
 use crate::dialogs::ActiveDialog;
 use crate::fl;
+use crate::locale::LocalePreferences;
 use crate::message::Message;
 
 /// Render the import events dialog using COSMIC dialog widget
@@ -17,14 +20,16 @@ use crate::message::Message;
 pub fn render_import_dialog<'a>(
     active_dialog: &'a ActiveDialog,
     calendars: &'a [Box<dyn CalendarSource>],
+    locale: &LocalePreferences,
 ) -> Element<'a, Message> {
     // Extract data from active_dialog
-    let (events, source_file_name, selected_calendar_id) = match active_dialog {
+    let (events, source_file_name, selected_calendar_id, preview) = match active_dialog {
         ActiveDialog::Import {
             events,
             source_file_name,
             selected_calendar_id,
-        } => (events, source_file_name.as_str(), selected_calendar_id.as_ref()),
+            preview,
+        } => (events, source_file_name.as_str(), selected_calendar_id.as_ref(), preview),
         _ => return widget::text("").into(), // Should not happen
     };
 
@@ -37,7 +42,7 @@ pub fn render_import_dialog<'a>(
         .push(text(source_file_name).size(12));
 
     // Event count info
-    let event_info = column().spacing(8).push(
+    let mut event_info = column().spacing(8).push(
         text(fl!(
             "import-event-count",
             count = (event_count as i64)
@@ -45,6 +50,18 @@ pub fn render_import_dialog<'a>(
         .size(14),
     );
 
+    // Dry-run counts when importing into a calendar that already has events
+    if let Some(preview) = preview {
+        event_info = event_info.push(
+            text(fl!(
+                "import-preview-counts",
+                added = (preview.added as i64),
+                skipped = (preview.skipped as i64)
+            ))
+            .size(14),
+        );
+    }
+
     // Calendar selection with radio buttons
     let mut calendar_control =
         column().spacing(8).push(text(fl!("import-target-calendar")).size(14));
@@ -85,8 +102,8 @@ pub fn render_import_dialog<'a>(
                 format!(
                     "• {} ({} - {})",
                     event.summary,
-                    event.start.format("%H:%M"),
-                    event.end.format("%H:%M")
+                    locale.format_time(&event.start.with_timezone(&chrono::Local)),
+                    locale.format_time(&event.end.with_timezone(&chrono::Local))
                 )
             };
             event_list = event_list.push(text(event_text).size(12));
@@ -31,7 +31,8 @@
 //! The centralized `Message::CloseDialog` handler closes all legacy dialog fields.
 
 use crate::caldav::CalendarEvent;
-use chrono::{NaiveDate, NaiveTime};
+use crate::services::ImportSummary;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 use log::{debug, info};
 
 /// Identifies which dialog or transient UI element is currently active.
@@ -71,12 +72,14 @@ pub enum ActiveDialog {
     CalendarCreate {
         name: String,
         color: String,
+        mute_alerts: bool,
     },
     /// Edit existing calendar dialog
     CalendarEdit {
         calendar_id: String,
         name: String,
         color: String,
+        mute_alerts: bool,
     },
     /// Delete calendar confirmation dialog
     CalendarDelete {
@@ -107,6 +110,8 @@ pub enum ActiveDialog {
         source_file_name: String,
         /// Selected target calendar ID
         selected_calendar_id: Option<String>,
+        /// Dry-run counts for the selected calendar, shown before the import is committed
+        preview: Option<ImportSummary>,
     },
     /// Import progress dialog for large imports
     ImportProgress {
@@ -154,6 +159,28 @@ pub enum ActiveDialog {
         create_new_calendar: bool,
         new_calendar_name: String,
     },
+
+    /// Settings dialog (edits `app.settings` directly, so it holds no state)
+    Settings,
+
+    /// Popover listing all events of a month cell that shows "+N more"
+    DayEvents {
+        date: NaiveDate,
+        /// Window position of the click that opened it; the popover opens there
+        anchor: (f32, f32),
+    },
+
+    /// Add a CalDAV account as a new calendar
+    CalDavAccount {
+        name: String,
+        /// URL of the calendar collection
+        server_url: String,
+        username: String,
+        /// Only held until confirmed, then moved to the system keyring
+        password: String,
+        /// Why the last attempt to add the account failed
+        error: Option<String>,
+    },
 }
 
 impl Default for ActiveDialog {
@@ -214,6 +241,7 @@ impl ActiveDialog {
                 events,
                 source_file_name,
                 selected_calendar_id,
+                ..
             } => Some((
                 events,
                 source_file_name,
@@ -319,6 +347,8 @@ pub enum DialogAction {
     StartQuickEventRange { start: NaiveDate, end: NaiveDate },
     /// Start a quick timed event with specific times (time slot selection in week/day view)
     StartQuickTimedEvent { date: NaiveDate, start_time: NaiveTime, end_time: NaiveTime },
+    /// Start a quick timed event that may span days (time selection dragged across days)
+    StartQuickTimedEventRange { start: NaiveDateTime, end: NaiveDateTime },
     /// Update quick event text while typing
     QuickEventTextChanged(String),
     /// Commit the quick event (create the event)
@@ -332,6 +362,7 @@ pub enum DialogAction {
         calendar_id: String,
         name: String,
         color: String,
+        mute_alerts: bool,
     },
     /// Open delete calendar confirmation
     OpenCalendarDelete {
@@ -387,6 +418,17 @@ impl DialogManager {
         }
     }
 
+    /// Close the dialog after a click outside it (dialog backdrop or main content)
+    /// Quick events follow the focus-loss rule and are only dismissed when empty;
+    /// other dialogs close unconditionally
+    pub fn close_from_backdrop(current: &mut ActiveDialog) {
+        if current.is_quick_event() {
+            Self::dismiss_empty_quick_event(current);
+        } else {
+            Self::close(current);
+        }
+    }
+
     /// Close dialog if Escape was pressed
     /// Returns true if a dialog was closed
     #[allow(dead_code)] // Reserved for keyboard handling
@@ -453,6 +495,19 @@ impl DialogManager {
                 );
                 None
             }
+            DialogAction::StartQuickTimedEventRange { start, end } => {
+                Self::open(
+                    current,
+                    ActiveDialog::QuickEvent {
+                        start_date: start.date(),
+                        end_date: end.date(),
+                        start_time: Some(start.time()),
+                        end_time: Some(end.time()),
+                        text: String::new(),
+                    },
+                );
+                None
+            }
             DialogAction::QuickEventTextChanged(text) => {
                 if let ActiveDialog::QuickEvent { text: t, .. } = current {
                     *t = text;
@@ -485,6 +540,7 @@ impl DialogManager {
                     ActiveDialog::CalendarCreate {
                         name: String::new(),
                         color: default_color,
+                        mute_alerts: false,
                     },
                 );
                 None
@@ -493,6 +549,7 @@ impl DialogManager {
                 calendar_id,
                 name,
                 color,
+                mute_alerts,
             } => {
                 Self::open(
                     current,
@@ -500,6 +557,7 @@ impl DialogManager {
                         calendar_id,
                         name,
                         color,
+                        mute_alerts,
                     },
                 );
                 None
@@ -551,6 +609,7 @@ impl DialogManager {
                         events,
                         source_file_name,
                         selected_calendar_id: None,
+                        preview: None,
                     },
                 );
                 None
@@ -558,10 +617,13 @@ impl DialogManager {
             DialogAction::SelectImportCalendar(calendar_id) => {
                 if let ActiveDialog::Import {
                     selected_calendar_id,
+                    preview,
                     ..
                 } = current
                 {
                     *selected_calendar_id = Some(calendar_id);
+                    // Counts were for the previous calendar
+                    *preview = None;
                 }
                 None
             }
@@ -621,6 +683,7 @@ mod tests {
             ActiveDialog::CalendarCreate {
                 name: String::new(),
                 color: "#FF0000".to_string(),
+                mute_alerts: false,
             },
         );
 
@@ -650,6 +713,35 @@ mod tests {
         assert!(!dialog.is_open());
     }
 
+    #[test]
+    fn test_backdrop_click_closes_modal_dialog() {
+        let mut dialog = ActiveDialog::CalendarDelete {
+            calendar_id: "test".to_string(),
+            calendar_name: "Test".to_string(),
+        };
+
+        DialogManager::close_from_backdrop(&mut dialog);
+
+        assert!(!dialog.is_open());
+    }
+
+    #[test]
+    fn test_backdrop_click_follows_quick_event_focus_rules() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+
+        // Empty quick event is dismissed
+        let mut dialog = ActiveDialog::None;
+        DialogManager::handle_action(&mut dialog, DialogAction::StartQuickEvent(date));
+        DialogManager::close_from_backdrop(&mut dialog);
+        assert!(!dialog.is_open());
+
+        // Quick event with text is kept so the typed title isn't lost
+        DialogManager::handle_action(&mut dialog, DialogAction::StartQuickEvent(date));
+        DialogManager::handle_action(&mut dialog, DialogAction::QuickEventTextChanged("Meeting".to_string()));
+        DialogManager::close_from_backdrop(&mut dialog);
+        assert_eq!(dialog.quick_event_data(), Some((date, "Meeting")));
+    }
+
     #[test]
     fn test_quick_event_start() {
         let mut dialog = ActiveDialog::None;
@@ -26,10 +26,13 @@
 
 mod manager;
 mod event_dialog;
+mod caldav_account_dialog;
 mod calendar_dialog;
+mod day_events_dialog;
 mod import_dialog;
 mod import_progress_dialog;
 mod import_result_dialog;
+mod settings_dialog;
 mod subscribe_dialog;
 
 pub use manager::{
@@ -38,9 +41,12 @@ pub use manager::{
     DialogManager,
     QuickEventResult,
 };
+pub use caldav_account_dialog::render_caldav_account_dialog;
+pub use day_events_dialog::{popover_origin, render_day_events_popover};
 pub use import_dialog::render_import_dialog;
 pub use import_progress_dialog::render_import_progress_dialog;
 pub use import_result_dialog::render_import_result_dialog;
+pub use settings_dialog::render_settings_dialog;
 pub use subscribe_dialog::view_subscribe_dialog;
 
 // Keep old exports for backwards compatibility during migration
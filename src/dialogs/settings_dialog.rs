@@ -0,0 +1,152 @@
+//! Settings dialog UI component
+//!
+//! Gathers the display options that are otherwise spread over the View menu:
+//! - Week numbers
+//! - 24-hour or 12-hour clock (or the locale's choice)
+//! - First day of the week (or the locale's choice)
+//! - View shown on startup
+//! - What double-clicking an empty cell or time slot opens
+//! - The alert new all-day events get
+//! - Working hours, with the hours outside them shaded in the week and day views
+//! - The hour the week and day views open at when today isn't shown
+//!
+//! Changes apply and are saved immediately; "Reset to System Defaults"
+//! clears the overrides again.
+
+use chrono::Weekday;
+use cosmic::iced::Length;
+use cosmic::widget::{button, column, dialog, row, settings, text, toggler};
+use cosmic::{widget, Element};
+
+use crate::caldav::{AlertTime, ALL_DAY_ALERT_MINUTE};
+use crate::components::alert_label;
+use crate::fl;
+use crate::locale::LocalePreferences;
+use crate::localized_names::get_weekday_full;
+use crate::message::Message;
+use crate::settings::{AppSettings, DefaultView, NewEventMode, WorkingHours};
+
+/// Hours offered for the top of the time grid when today isn't shown
+const DAY_START_HOURS: [u32; 6] = [0, 6, 7, 8, 9, 10];
+
+/// Hours offered for the start of the working day
+const WORKING_DAY_STARTS: [u32; 5] = [6, 7, 8, 9, 10];
+
+/// Hours offered for the end of the working day (all after every start)
+const WORKING_DAY_ENDS: [u32; 5] = [16, 17, 18, 19, 20];
+
+/// Render the settings dialog for the current settings, with hours in the clock format of `locale`
+pub fn render_settings_dialog<'a>(app_settings: &'a AppSettings, locale: &LocalePreferences) -> Element<'a, Message> {
+    let week_numbers_toggler = toggler(app_settings.show_week_numbers)
+        .on_toggle(|_| Message::ToggleWeekNumbers);
+
+    // Same setting as the View menu's "Open Full Editor for New Events"
+    let new_event_dialog_toggler = toggler(app_settings.new_event_mode == NewEventMode::Dialog)
+        .on_toggle(|_| Message::ToggleNewEventMode);
+
+    let clock_buttons = [
+        (fl!("settings-system-default"), None),
+        (fl!("settings-clock-24-hour"), Some(true)),
+        (fl!("settings-clock-12-hour"), Some(false)),
+    ]
+    .into_iter()
+    .fold(row().spacing(4), |buttons, (label, value)| {
+        buttons.push(choice_button(label, app_settings.use_24_hour == value, Message::SetUse24Hour(value)))
+    });
+
+    // Same choices as the View menu's "Week Starts On"
+    let week_start_buttons = [None, Some(Weekday::Mon), Some(Weekday::Sat), Some(Weekday::Sun)]
+        .into_iter()
+        .fold(row().spacing(4), |buttons, first_day| {
+            let label = first_day.map_or_else(|| fl!("settings-system-default"), get_weekday_full);
+            buttons.push(choice_button(
+                label,
+                app_settings.first_day_of_week == first_day,
+                Message::SetFirstDayOfWeek(first_day),
+            ))
+        });
+
+    let default_view_buttons = [
+        (fl!("menu-year-view"), DefaultView::Year),
+        (fl!("menu-month-view"), DefaultView::Month),
+        (fl!("menu-week-view"), DefaultView::Week),
+        (fl!("menu-day-view"), DefaultView::Day),
+    ]
+    .into_iter()
+    .fold(row().spacing(4), |buttons, (label, view)| {
+        buttons.push(choice_button(label, app_settings.default_view == view, Message::SetDefaultView(view)))
+    });
+
+    let all_day_alert_buttons = [
+        AlertTime::None,
+        AlertTime::AtTime,
+        AlertTime::DayBeforeAt(ALL_DAY_ALERT_MINUTE),
+        AlertTime::DayBeforeAt(18 * 60),
+    ]
+    .into_iter()
+    .fold(row().spacing(4), |buttons, alert| {
+        let selected = app_settings.all_day_alert == alert;
+        buttons.push(choice_button(alert_label(&alert, locale), selected, Message::SetAllDayAlert(alert)))
+    });
+
+    let off_hours_toggler = toggler(app_settings.shade_off_hours)
+        .on_toggle(|_| Message::ToggleOffHoursShading);
+
+    let working_hours = app_settings.working_hours;
+    let working_start_buttons = WORKING_DAY_STARTS.into_iter().fold(row().spacing(4), |buttons, start| {
+        let hours = WorkingHours { start, ..working_hours };
+        buttons.push(choice_button(locale.format_hour(start), working_hours.start == start, Message::SetWorkingHours(hours)))
+    });
+    let working_end_buttons = WORKING_DAY_ENDS.into_iter().fold(row().spacing(4), |buttons, end| {
+        let hours = WorkingHours { end, ..working_hours };
+        buttons.push(choice_button(locale.format_hour(end), working_hours.end == end, Message::SetWorkingHours(hours)))
+    });
+
+    let day_start_buttons = DAY_START_HOURS.into_iter().fold(row().spacing(4), |buttons, hour| {
+        let selected = app_settings.day_start_hour == hour;
+        buttons.push(choice_button(locale.format_hour(hour), selected, Message::SetDayStartHour(hour)))
+    });
+
+    let general_section = settings::section()
+        .title(fl!("settings-general"))
+        .add(settings::item::builder(fl!("settings-default-view")).control(default_view_buttons))
+        .add(settings::item::builder(fl!("settings-show-week-numbers")).control(week_numbers_toggler))
+        .add(settings::item::builder(fl!("menu-new-event-dialog")).control(new_event_dialog_toggler))
+        .add(settings::item::builder(fl!("settings-all-day-alert")).control(all_day_alert_buttons));
+
+    let locale_section = settings::section()
+        .title(fl!("settings-appearance"))
+        .add(settings::item::builder(fl!("settings-clock")).control(clock_buttons))
+        .add(settings::item::builder(fl!("settings-week-start")).control(week_start_buttons))
+        .add(settings::item::builder(fl!("settings-day-start")).control(day_start_buttons))
+        .add(settings::item::builder(fl!("settings-shade-off-hours")).control(off_hours_toggler))
+        .add(settings::item::builder(fl!("settings-working-day-start")).control(working_start_buttons))
+        .add(settings::item::builder(fl!("settings-working-day-end")).control(working_end_buttons));
+
+    let content = column()
+        .spacing(16)
+        .push(general_section)
+        .push(locale_section);
+
+    dialog()
+        .title(fl!("settings-title"))
+        .icon(widget::icon::from_name("preferences-system-symbolic").size(64))
+        .control(content)
+        .tertiary_action(button::text(fl!("settings-reset")).on_press(Message::ResetSettingsToSystemDefaults))
+        .primary_action(button::suggested(fl!("button-close")).on_press(Message::CloseDialog))
+        .width(Length::Fixed(560.0))
+        .into()
+}
+
+/// One option of a choice row, highlighted when selected
+fn choice_button<'a>(label: String, selected: bool, message: Message) -> Element<'a, Message> {
+    button::custom(text(label).size(11))
+        .on_press(message)
+        .padding([4, 8])
+        .class(if selected {
+            cosmic::theme::Button::Suggested
+        } else {
+            cosmic::theme::Button::Standard
+        })
+        .into()
+}
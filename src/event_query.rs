@@ -0,0 +1,147 @@
+//! Headless event listing
+//!
+//! `--list-today` and `--list-range START END` print the events of a date range
+//! and exit without opening a window, for scripts and status bars. Events come
+//! from the same enabled calendars the app shows, with recurring events
+//! expanded; `--json` prints them as a JSON array instead of text lines.
+
+use chrono::{Local, NaiveDate};
+use serde_json::json;
+
+use crate::caldav::CalendarEvent;
+use crate::calendars::CalendarManager;
+use crate::locale::LocalePreferences;
+use crate::settings::AppSettings;
+use crate::validation::parse_date;
+
+/// Parse the `--list-range` dates (YYYY-MM-DD), end inclusive
+pub fn parse_range(start: &str, end: &str) -> Result<(NaiveDate, NaiveDate), String> {
+    let start_date = parse_date(start).ok_or_else(|| format!("invalid start date '{}', expected YYYY-MM-DD", start))?;
+    let end_date = parse_date(end).ok_or_else(|| format!("invalid end date '{}', expected YYYY-MM-DD", end))?;
+    if end_date < start_date {
+        return Err(format!("end date {} is before start date {}", end_date, start_date));
+    }
+    Ok((start_date, end_date))
+}
+
+/// Today's date as a range for `--list-today`
+pub fn today_range() -> (NaiveDate, NaiveDate) {
+    let today = Local::now().date_naive();
+    (today, today)
+}
+
+/// Print the events shown between `start` and `end` (inclusive) to stdout
+pub fn print_events(start: NaiveDate, end: NaiveDate, as_json: bool) {
+    let manager = CalendarManager::with_defaults();
+    let mut events = manager.get_occurrences_between(start, end);
+    events.sort_by_key(|event| (event.start, !event.all_day));
+    // Times follow the same clock style as the app
+    let locale = LocalePreferences::detect_from_system()
+        .with_clock_override(AppSettings::load().unwrap_or_default().use_24_hour);
+    let output = format_events(&events, as_json, &locale);
+    if !output.is_empty() {
+        println!("{}", output);
+    }
+}
+
+/// Listing of `events` (with display times) as text lines or a JSON array.
+/// Text lines format times in the locale's clock style; JSON is always ISO 8601.
+pub fn format_events(events: &[CalendarEvent], as_json: bool, locale: &LocalePreferences) -> String {
+    if as_json {
+        let entries: Vec<serde_json::Value> = events
+            .iter()
+            .map(|event| {
+                json!({
+                    "uid": event.uid,
+                    "summary": event.summary,
+                    "all_day": event.all_day,
+                    "start": event.start.naive_utc().format("%Y-%m-%dT%H:%M").to_string(),
+                    "end": event.end.naive_utc().format("%Y-%m-%dT%H:%M").to_string(),
+                    "location": event.location,
+                })
+            })
+            .collect();
+        return serde_json::Value::Array(entries).to_string();
+    }
+
+    events.iter().map(|event| format_line(event, locale)).collect::<Vec<_>>().join("\n")
+}
+
+/// One text line: date, time span (or "all day"), summary and location
+fn format_line(event: &CalendarEvent, locale: &LocalePreferences) -> String {
+    let (start, end) = (event.start.naive_utc(), event.end.naive_utc());
+    let time = if event.all_day {
+        "all day    ".to_string()
+    } else {
+        format!("{}-{}", locale.format_time(&start), locale.format_time(&end))
+    };
+    let mut line = format!("{} {}  {}", start.format("%Y-%m-%d"), time, event.summary);
+    if let Some(location) = event.location.as_deref().filter(|location| !location.is_empty()) {
+        line.push_str(&format!(" @ {}", location));
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc, Weekday};
+
+    fn locale(use_24_hour: bool) -> LocalePreferences {
+        LocalePreferences {
+            use_24_hour,
+            first_day_of_week: Weekday::Mon,
+            weekend_days: vec![Weekday::Sat, Weekday::Sun],
+            date_format: crate::locale::DateFormat::YMD,
+            locale_string: "en_US.UTF-8".to_string(),
+        }
+    }
+
+    fn event(summary: &str, all_day: bool, location: Option<&str>) -> CalendarEvent {
+        CalendarEvent {
+            uid: summary.to_lowercase(),
+            summary: summary.to_string(),
+            location: location.map(str::to_string),
+            all_day,
+            start: Utc.with_ymd_and_hms(2025, 3, 14, 9, 0, 0).unwrap(),
+            end: Utc.with_ymd_and_hms(2025, 3, 14, 9, 30, 0).unwrap(),
+            ..CalendarEvent::test_default()
+        }
+    }
+
+    #[test]
+    fn test_parse_range() {
+        let march = |day| NaiveDate::from_ymd_opt(2025, 3, day).unwrap();
+        assert_eq!(parse_range("2025-03-10", "2025-03-16"), Ok((march(10), march(16))));
+        assert_eq!(parse_range("2025-03-10", "2025-03-10"), Ok((march(10), march(10))));
+        assert!(parse_range("2025-03-16", "2025-03-10").is_err());
+        assert!(parse_range("tomorrow", "2025-03-10").is_err());
+        assert!(parse_range("2025-03-10", "2025-02-30").is_err());
+    }
+
+    #[test]
+    fn test_format_text_lines() {
+        let events = vec![event("Offsite", true, None), event("Standup", false, Some("Room 4"))];
+        assert_eq!(
+            format_events(&events, false, &locale(true)),
+            "2025-03-14 all day      Offsite\n2025-03-14 09:00-09:30  Standup @ Room 4"
+        );
+        assert_eq!(
+            format_events(&events[1..], false, &locale(false)),
+            "2025-03-14 9:00 AM-9:30 AM  Standup @ Room 4"
+        );
+        assert_eq!(format_events(&[], false, &locale(true)), "");
+    }
+
+    #[test]
+    fn test_format_json() {
+        let events = vec![event("Standup", false, Some("Room 4"))];
+        let parsed: serde_json::Value = serde_json::from_str(&format_events(&events, true, &locale(false))).unwrap();
+        assert_eq!(parsed[0]["summary"], "Standup");
+        assert_eq!(parsed[0]["start"], "2025-03-14T09:00");
+        assert_eq!(parsed[0]["end"], "2025-03-14T09:30");
+        assert_eq!(parsed[0]["all_day"], false);
+        assert_eq!(parsed[0]["location"], "Room 4");
+        assert_eq!(format_events(&[], true, &locale(false)), "[]");
+    }
+}
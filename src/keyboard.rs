@@ -1,3 +1,4 @@
+use chrono::{Duration, Months, NaiveDate};
 use cosmic::iced::keyboard::key::Named;
 use cosmic::iced::keyboard::Key;
 use cosmic::widget::menu;
@@ -5,10 +6,74 @@ use std::collections::HashMap;
 use std::sync::OnceLock;
 
 use crate::menu_action::MenuAction;
+use crate::views::CalendarView;
 
 /// Global keyboard shortcuts registry
 static KEY_BINDS: OnceLock<HashMap<menu::KeyBind, MenuAction>> = OnceLock::new();
 
+/// Number of calendars whose visibility can be toggled by shortcut (Ctrl+1 through Ctrl+9)
+pub const CALENDAR_TOGGLE_SHORTCUTS: usize = 9;
+
+/// Map a calendar toggle shortcut key ("1"-"9") to the calendar source index it toggles
+pub fn calendar_index_for_key(key: &str) -> Option<usize> {
+    let digit: usize = key.parse().ok()?;
+    (1..=CALENDAR_TOGGLE_SHORTCUTS).contains(&digit).then(|| digit - 1)
+}
+
+/// Shortcut hint shown next to a calendar in the sidebar (None past the first nine)
+pub fn calendar_toggle_hint(index: usize) -> Option<String> {
+    (index < CALENDAR_TOGGLE_SHORTCUTS).then(|| format!("Ctrl+{}", index + 1))
+}
+
+/// Unmodified navigation keys that move the selected date
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateStep {
+    Left,
+    Right,
+    Up,
+    Down,
+    PageUp,
+    PageDown,
+}
+
+/// Map an arrow or page key to the date step it performs
+pub fn date_step_for_key(key: &Key) -> Option<DateStep> {
+    match key {
+        Key::Named(Named::ArrowLeft) => Some(DateStep::Left),
+        Key::Named(Named::ArrowRight) => Some(DateStep::Right),
+        Key::Named(Named::ArrowUp) => Some(DateStep::Up),
+        Key::Named(Named::ArrowDown) => Some(DateStep::Down),
+        Key::Named(Named::PageUp) => Some(DateStep::PageUp),
+        Key::Named(Named::PageDown) => Some(DateStep::PageDown),
+        _ => None,
+    }
+}
+
+/// Date reached from `date` by a step in the given view.
+/// Left/Right move by a day and PageUp/PageDown by a month (clamped to its last day).
+/// Up/Down move by a week in the month and year grids, and by a day in the
+/// week, day and agenda views where days run along one axis.
+pub fn step_date(date: NaiveDate, step: DateStep, view: CalendarView) -> NaiveDate {
+    let vertical_days = match view {
+        CalendarView::Month | CalendarView::Year => 7,
+        CalendarView::Week | CalendarView::Day | CalendarView::Agenda => 1,
+    };
+    match step {
+        DateStep::Left => date - Duration::days(1),
+        DateStep::Right => date + Duration::days(1),
+        DateStep::Up => date - Duration::days(vertical_days),
+        DateStep::Down => date + Duration::days(vertical_days),
+        DateStep::PageUp => date.checked_sub_months(Months::new(1)).unwrap_or(date),
+        DateStep::PageDown => date.checked_add_months(Months::new(1)).unwrap_or(date),
+    }
+}
+
+/// Shortcuts that mean something else in a text input (undo there edits the
+/// text, select all selects it), left to the input while it has the keyboard
+pub fn is_text_editing_bind(action: MenuAction) -> bool {
+    matches!(action, MenuAction::Undo | MenuAction::Redo | MenuAction::SelectAllEventsInView)
+}
+
 /// Initialize the global keyboard shortcuts
 pub fn init_key_binds() -> HashMap<menu::KeyBind, MenuAction> {
     let mut key_binds = HashMap::new();
@@ -67,6 +132,15 @@ pub fn init_key_binds() -> HashMap<menu::KeyBind, MenuAction> {
         MenuAction::ViewYear,
     );
 
+    // Agenda View: Ctrl+Shift+L
+    key_binds.insert(
+        menu::KeyBind {
+            modifiers: vec![menu::key_bind::Modifier::Ctrl, menu::key_bind::Modifier::Shift],
+            key: Key::Character("l".into()),
+        },
+        MenuAction::ViewAgenda,
+    );
+
     // Navigate Previous Period: Ctrl+Shift+Left (prev month/week/day depending on view)
     key_binds.insert(
         menu::KeyBind {
@@ -112,7 +186,43 @@ pub fn init_key_binds() -> HashMap<menu::KeyBind, MenuAction> {
         MenuAction::DeleteSelectedEvent,
     );
 
-    // Cycle View Next: Ctrl+Shift+] (Year → Month → Week → Day)
+    // Undo: Ctrl+Z
+    key_binds.insert(
+        menu::KeyBind {
+            modifiers: vec![menu::key_bind::Modifier::Ctrl],
+            key: Key::Character("z".into()),
+        },
+        MenuAction::Undo,
+    );
+
+    // Redo: Ctrl+Shift+Z
+    key_binds.insert(
+        menu::KeyBind {
+            modifiers: vec![menu::key_bind::Modifier::Ctrl, menu::key_bind::Modifier::Shift],
+            key: Key::Character("z".into()),
+        },
+        MenuAction::Redo,
+    );
+
+    // Select All Events in View: Ctrl+A
+    key_binds.insert(
+        menu::KeyBind {
+            modifiers: vec![menu::key_bind::Modifier::Ctrl],
+            key: Key::Character("a".into()),
+        },
+        MenuAction::SelectAllEventsInView,
+    );
+
+    // Duplicate Selected Event: Ctrl+D
+    key_binds.insert(
+        menu::KeyBind {
+            modifiers: vec![menu::key_bind::Modifier::Ctrl],
+            key: Key::Character("d".into()),
+        },
+        MenuAction::DuplicateSelectedEvent,
+    );
+
+    // Cycle View Next: Ctrl+Shift+] (Year → Month → Week → Day → Agenda)
     key_binds.insert(
         menu::KeyBind {
             modifiers: vec![menu::key_bind::Modifier::Ctrl, menu::key_bind::Modifier::Shift],
@@ -121,7 +231,7 @@ pub fn init_key_binds() -> HashMap<menu::KeyBind, MenuAction> {
         MenuAction::CycleViewNext,
     );
 
-    // Cycle View Previous: Ctrl+Shift+[ (Day → Week → Month → Year)
+    // Cycle View Previous: Ctrl+Shift+[ (Agenda → Day → Week → Month → Year)
     key_binds.insert(
         menu::KeyBind {
             modifiers: vec![menu::key_bind::Modifier::Ctrl, menu::key_bind::Modifier::Shift],
@@ -130,6 +240,20 @@ pub fn init_key_binds() -> HashMap<menu::KeyBind, MenuAction> {
         MenuAction::CycleViewPrevious,
     );
 
+    // Toggle Calendar Visibility: Ctrl+1 … Ctrl+9 (first nine calendars in the sidebar)
+    for digit in 1..=CALENDAR_TOGGLE_SHORTCUTS {
+        let key = digit.to_string();
+        if let Some(index) = calendar_index_for_key(&key) {
+            key_binds.insert(
+                menu::KeyBind {
+                    modifiers: vec![menu::key_bind::Modifier::Ctrl],
+                    key: Key::Character(key.into()),
+                },
+                MenuAction::ToggleCalendarByIndex(index),
+            );
+        }
+    }
+
     // Store globally for subscription access
     let _ = KEY_BINDS.set(key_binds.clone());
 
@@ -140,3 +264,66 @@ pub fn init_key_binds() -> HashMap<menu::KeyBind, MenuAction> {
 pub fn get_key_binds() -> &'static HashMap<menu::KeyBind, MenuAction> {
     KEY_BINDS.get().expect("KEY_BINDS not initialized")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calendar_index_for_key() {
+        assert_eq!(calendar_index_for_key("1"), Some(0));
+        assert_eq!(calendar_index_for_key("5"), Some(4));
+        assert_eq!(calendar_index_for_key("9"), Some(8));
+        assert_eq!(calendar_index_for_key("0"), None);
+        assert_eq!(calendar_index_for_key("10"), None);
+        assert_eq!(calendar_index_for_key("a"), None);
+    }
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_date_step_for_key() {
+        assert_eq!(date_step_for_key(&Key::Named(Named::ArrowLeft)), Some(DateStep::Left));
+        assert_eq!(date_step_for_key(&Key::Named(Named::PageDown)), Some(DateStep::PageDown));
+        assert_eq!(date_step_for_key(&Key::Character("j".into())), None);
+        assert_eq!(date_step_for_key(&Key::Named(Named::Enter)), None);
+    }
+
+    #[test]
+    fn test_step_date_follows_view_layout() {
+        let wednesday = date(3, 12);
+        assert_eq!(step_date(wednesday, DateStep::Left, CalendarView::Month), date(3, 11));
+        assert_eq!(step_date(wednesday, DateStep::Right, CalendarView::Week), date(3, 13));
+
+        // A row up in the month grid is a week earlier
+        assert_eq!(step_date(wednesday, DateStep::Up, CalendarView::Month), date(3, 5));
+        assert_eq!(step_date(wednesday, DateStep::Down, CalendarView::Year), date(3, 19));
+        // In the week and day views days are columns, so Up/Down move a day
+        assert_eq!(step_date(wednesday, DateStep::Up, CalendarView::Week), date(3, 11));
+        assert_eq!(step_date(wednesday, DateStep::Down, CalendarView::Day), date(3, 13));
+    }
+
+    #[test]
+    fn test_step_date_by_month_clamps_day() {
+        assert_eq!(step_date(date(1, 31), DateStep::PageDown, CalendarView::Month), date(2, 28));
+        assert_eq!(step_date(date(3, 31), DateStep::PageUp, CalendarView::Week), date(2, 28));
+        assert_eq!(step_date(date(3, 12), DateStep::PageDown, CalendarView::Day), date(4, 12));
+    }
+
+    #[test]
+    fn test_text_editing_binds() {
+        assert!(is_text_editing_bind(MenuAction::Undo));
+        assert!(is_text_editing_bind(MenuAction::Redo));
+        assert!(is_text_editing_bind(MenuAction::SelectAllEventsInView));
+        assert!(!is_text_editing_bind(MenuAction::DuplicateSelectedEvent));
+    }
+
+    #[test]
+    fn test_calendar_toggle_hint() {
+        assert_eq!(calendar_toggle_hint(0).as_deref(), Some("Ctrl+1"));
+        assert_eq!(calendar_toggle_hint(8).as_deref(), Some("Ctrl+9"));
+        assert_eq!(calendar_toggle_hint(9), None);
+    }
+}
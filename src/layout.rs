@@ -1,9 +1,13 @@
 use crate::app::CosmicCalendar;
-use crate::components::{render_calendar_dialog, render_delete_calendar_dialog, render_delete_event_dialog, render_event_dialog};
-use crate::dialogs::{render_import_dialog, render_import_progress_dialog, render_import_result_dialog, view_subscribe_dialog};
+use crate::components::{render_calendar_dialog, render_delete_calendar_dialog, render_delete_event_dialog, render_edit_scope_dialog, render_event_dialog};
+use crate::dialogs::{popover_origin, render_caldav_account_dialog, render_day_events_popover, render_import_dialog, render_import_progress_dialog, render_import_result_dialog, render_settings_dialog, view_subscribe_dialog};
 use crate::message::Message;
 use crate::styles;
-use crate::ui_constants::{BORDER_RADIUS, SIDEBAR_WIDTH};
+use crate::fl;
+use crate::views::wrap_with_dialog_backdrop;
+use crate::ui_constants::{
+    BORDER_RADIUS, ICON_CLOSE, PADDING_MEDIUM, PADDING_SMALL, PADDING_TINY, SIDEBAR_WIDTH, SPACING_MEDIUM,
+};
 use cosmic::iced::widget::stack;
 use cosmic::iced::{alignment, Length};
 use cosmic::widget::{container, divider, mouse_area, row};
@@ -44,7 +48,53 @@ pub fn render_layout(app: &CosmicCalendar) -> Element<'_, Message> {
     };
 
     // Show dialog overlays based on active_dialog state
-    render_dialog_overlay(app, with_sidebar)
+    let with_dialogs = render_dialog_overlay(app, with_sidebar);
+
+    // Toast notifications float above everything
+    render_toast_overlay(app, with_dialogs)
+}
+
+/// Render the toast notification (if any) at the bottom center of the window
+fn render_toast_overlay<'a>(
+    app: &'a CosmicCalendar,
+    base: Element<'a, Message>,
+) -> Element<'a, Message> {
+    use cosmic::widget::{button, icon, text};
+
+    let Some(toast) = app.toast.current() else {
+        return base;
+    };
+
+    let mut toast_row = row()
+        .spacing(SPACING_MEDIUM)
+        .align_y(cosmic::iced::Alignment::Center)
+        .push(text::body(toast.message.as_str()));
+
+    if toast.undo.is_some() {
+        toast_row = toast_row.push(
+            button::text(fl!("toast-undo"))
+                .on_press(Message::Undo)
+        );
+    }
+
+    toast_row = toast_row.push(
+        button::icon(icon::from_name(ICON_CLOSE))
+            .on_press(Message::DismissToast)
+            .padding(PADDING_TINY)
+    );
+
+    let toast_card = container(toast_row)
+        .padding([PADDING_SMALL, PADDING_MEDIUM])
+        .class(cosmic::theme::Container::Dialog);
+
+    let positioned_toast = container(toast_card)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(PADDING_MEDIUM)
+        .align_x(alignment::Horizontal::Center)
+        .align_y(alignment::Vertical::Bottom);
+
+    stack![base, positioned_toast].into()
 }
 
 /// Render dialog overlay based on active_dialog state
@@ -57,45 +107,52 @@ fn render_dialog_overlay<'a>(
     let with_drag_preview = render_drag_preview_overlay(app, base);
 
     // Event dialog takes priority (uses legacy field due to text_editor::Content)
-    // Note: Event dialog has its own backdrop built-in
+    // Note: Event dialog wraps itself with the shared backdrop
     #[allow(deprecated)]
     if let Some(ref dialog_state) = app.event_dialog {
-        let dialog = render_event_dialog(dialog_state, app.calendar_manager.sources());
+        let dialog = render_event_dialog(dialog_state, app.calendar_manager.sources(), &app.locale);
+        // Saving one occurrence of a series first asks which occurrences change
+        if dialog_state.scope_prompt_open {
+            let prompt = wrap_with_dialog_backdrop(render_edit_scope_dialog());
+            return stack![with_drag_preview, dialog, prompt].into();
+        }
         return stack![with_drag_preview, dialog].into();
     }
 
-    // Check active_dialog for calendar dialogs
-    // COSMIC dialog widget doesn't include backdrop, so we wrap with one
+    // Check active_dialog for modal dialogs
+    // COSMIC dialog widget doesn't include backdrop, so we wrap with one.
+    // Inline editors (quick event, color picker) stay undimmed; clicking the
+    // main content closes them through the same CloseDialog routing.
     use crate::dialogs::ActiveDialog;
     match &app.active_dialog {
         ActiveDialog::CalendarCreate { .. } | ActiveDialog::CalendarEdit { .. } => {
             let dialog = render_calendar_dialog(&app.active_dialog);
-            let dialog_with_backdrop = wrap_dialog_with_backdrop(dialog);
+            let dialog_with_backdrop = wrap_with_dialog_backdrop(dialog);
             return stack![with_drag_preview, dialog_with_backdrop].into();
         }
         ActiveDialog::CalendarDelete { .. } => {
             let dialog = render_delete_calendar_dialog(&app.active_dialog);
-            let dialog_with_backdrop = wrap_dialog_with_backdrop(dialog);
+            let dialog_with_backdrop = wrap_with_dialog_backdrop(dialog);
             return stack![with_drag_preview, dialog_with_backdrop].into();
         }
         ActiveDialog::EventDelete { .. } => {
             let dialog = render_delete_event_dialog(&app.active_dialog);
-            let dialog_with_backdrop = wrap_dialog_with_backdrop(dialog);
+            let dialog_with_backdrop = wrap_with_dialog_backdrop(dialog);
             return stack![with_drag_preview, dialog_with_backdrop].into();
         }
         ActiveDialog::Import { .. } => {
-            let dialog = render_import_dialog(&app.active_dialog, app.calendar_manager.sources());
-            let dialog_with_backdrop = wrap_dialog_with_backdrop(dialog);
+            let dialog = render_import_dialog(&app.active_dialog, app.calendar_manager.sources(), &app.locale);
+            let dialog_with_backdrop = wrap_with_dialog_backdrop(dialog);
             return stack![with_drag_preview, dialog_with_backdrop].into();
         }
         ActiveDialog::ImportProgress { .. } => {
             let dialog = render_import_progress_dialog(&app.active_dialog);
-            let dialog_with_backdrop = wrap_dialog_with_backdrop(dialog);
+            let dialog_with_backdrop = wrap_with_dialog_backdrop(dialog);
             return stack![with_drag_preview, dialog_with_backdrop].into();
         }
         ActiveDialog::ImportResult { .. } => {
             let dialog = render_import_result_dialog(&app.active_dialog);
-            let dialog_with_backdrop = wrap_dialog_with_backdrop(dialog);
+            let dialog_with_backdrop = wrap_with_dialog_backdrop(dialog);
             return stack![with_drag_preview, dialog_with_backdrop].into();
         }
         ActiveDialog::SubscribeCalendar {
@@ -115,40 +172,59 @@ fn render_dialog_overlay<'a>(
                 *create_new_calendar,
                 new_calendar_name,
             );
-            let dialog_with_backdrop = wrap_dialog_with_backdrop(dialog);
+            let dialog_with_backdrop = wrap_with_dialog_backdrop(dialog);
+            return stack![with_drag_preview, dialog_with_backdrop].into();
+        }
+        ActiveDialog::Settings => {
+            let dialog = render_settings_dialog(&app.settings, &app.locale);
+            let dialog_with_backdrop = wrap_with_dialog_backdrop(dialog);
+            return stack![with_drag_preview, dialog_with_backdrop].into();
+        }
+        ActiveDialog::CalDavAccount { .. } => {
+            let dialog = render_caldav_account_dialog(&app.active_dialog);
+            let dialog_with_backdrop = wrap_with_dialog_backdrop(dialog);
             return stack![with_drag_preview, dialog_with_backdrop].into();
         }
+        ActiveDialog::DayEvents { date, anchor } => {
+            let date = *date;
+            // Opened from the month grid or the week view's "+N", so use that view's events
+            let events = app
+                .visible_events_by_date()
+                .and_then(|events_by_date| events_by_date.get(&date))
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            let popover = wrap_popover_at(move || render_day_events_popover(date, events, &app.locale), *anchor);
+            return stack![with_drag_preview, popover].into();
+        }
         _ => {}
     }
 
     with_drag_preview
 }
 
-/// Wrap a COSMIC dialog widget with a backdrop and center it
-fn wrap_dialog_with_backdrop(dialog: Element<'_, Message>) -> Element<'_, Message> {
-    use cosmic::iced::alignment;
+/// Place a popover with its top-left corner at `anchor` (kept inside the window),
+/// over an undimmed backdrop that closes it when clicked.
+/// The popover is built per layout pass, since its position depends on the window size
+fn wrap_popover_at<'a>(
+    popover: impl Fn() -> Element<'a, Message> + 'a,
+    anchor: (f32, f32),
+) -> Element<'a, Message> {
+    let backdrop = mouse_area(container(cosmic::widget::text("")).width(Length::Fill).height(Length::Fill))
+        .on_press(Message::CloseDialog);
 
-    // Clickable backdrop that closes the dialog
-    let backdrop = mouse_area(
-        container(cosmic::widget::text(""))
+    // Padding positions the popover, as for the drag preview
+    let positioned = cosmic::widget::responsive(move |size| {
+        let (x, y) = popover_origin(anchor, size);
+        container(popover())
+            .padding([y as u16, 0, 0, x as u16])
             .width(Length::Fill)
             .height(Length::Fill)
-            .style(|_theme: &cosmic::Theme| container::Style {
-                background: Some(cosmic::iced::Color::from_rgba(0.0, 0.0, 0.0, 0.5).into()),
-                ..Default::default()
-            }),
-    )
-    .on_press(Message::CloseDialog);
-
-    // Center the dialog
-    let centered_dialog = container(dialog)
-        .width(Length::Fill)
-        .height(Length::Fill)
-        .align_x(alignment::Horizontal::Center)
-        .align_y(alignment::Vertical::Center);
+            .align_x(alignment::Horizontal::Left)
+            .align_y(alignment::Vertical::Top)
+            .into()
+    });
 
-    // Stack: backdrop on bottom, dialog on top
-    stack![backdrop, centered_dialog].into()
+    stack![backdrop, positioned].into()
 }
 
 /// Render a floating drag preview overlay when an event is being dragged
@@ -159,22 +235,8 @@ fn render_drag_preview_overlay<'a>(
     use cosmic::widget::text;
     use cosmic::iced::Background;
 
-    let drag_state = &app.event_drag_state;
-
-    // Only show preview if actively dragging and we have cursor position
-    if !drag_state.is_active {
-        return base;
-    }
-
-    let Some((cursor_x, cursor_y)) = drag_state.cursor_position() else {
-        return base;
-    };
-
-    let Some(summary) = drag_state.event_summary() else {
-        return base;
-    };
-
-    let Some(color_hex) = drag_state.event_color() else {
+    // Only show preview while actually dragging, updated by DragEventCursorMove
+    let Some((summary, color_hex, (cursor_x, cursor_y))) = app.event_drag_state.floating_preview() else {
         return base;
     };
 
@@ -123,6 +123,9 @@ pub const BORDER_WIDTH_THIN: f32 = 0.5;
 /// Normal border width for standard day cell borders
 pub const BORDER_WIDTH_NORMAL: f32 = 1.0;
 
+/// Multiplier applied to grid border widths for the "strong" grid line style
+pub const GRID_LINE_STRONG_SCALE: f32 = 2.0;
+
 /// Highlight border width for today indicator and selected items
 pub const BORDER_WIDTH_HIGHLIGHT: f32 = 2.0;
 
@@ -188,12 +191,24 @@ pub const ICON_NEXT: &str = "go-next-symbolic";
 /// Search icon
 pub const ICON_SEARCH: &str = "system-search-symbolic";
 
-/// Today/calendar icon
-pub const ICON_TODAY: &str = "x-office-calendar-symbolic";
-
 /// Add/plus icon
 pub const ICON_ADD: &str = "list-add-symbolic";
 
+/// Close/dismiss icon
+pub const ICON_CLOSE: &str = "window-close-symbolic";
+
+/// Overflow menu icon
+pub const ICON_MORE: &str = "view-more-symbolic";
+
+/// Expand icon (collapsed section)
+pub const ICON_EXPAND: &str = "pan-end-symbolic";
+
+/// Collapse icon (expanded section)
+pub const ICON_COLLAPSE: &str = "pan-down-symbolic";
+
+/// Loading indicator icon (calendar sync in progress)
+pub const ICON_SYNCING: &str = "process-working-symbolic";
+
 // =============================================================================
 // Font Sizes
 // =============================================================================
@@ -15,6 +15,8 @@ pub enum DateFormat {
 pub struct LocalePreferences {
     pub use_24_hour: bool,
     pub first_day_of_week: chrono::Weekday,
+    /// Days shaded (and hideable) as weekend - independent of the first day of the week
+    pub weekend_days: Vec<chrono::Weekday>,
     pub date_format: DateFormat,
     pub locale_string: String,
 }
@@ -29,16 +31,39 @@ impl LocalePreferences {
 
         let use_24_hour = detect_24_hour_format(&locale_string);
         let first_day_of_week = detect_first_day_of_week(&locale_string);
+        let weekend_days = detect_weekend_days(&locale_string);
         let date_format = detect_date_format(&locale_string);
 
         LocalePreferences {
             use_24_hour,
             first_day_of_week,
+            weekend_days,
             date_format,
             locale_string,
         }
     }
 
+    /// Replace the locale's first day of week and/or weekend days with user settings.
+    /// Each override applies on its own, so e.g. a Monday start can be combined
+    /// with a Friday/Saturday weekend.
+    pub fn with_week_overrides(mut self, first_day_of_week: Option<chrono::Weekday>, weekend_days: Option<&[chrono::Weekday]>) -> Self {
+        if let Some(first_day) = first_day_of_week {
+            self.first_day_of_week = first_day;
+        }
+        if let Some(days) = weekend_days {
+            self.weekend_days = days.to_vec();
+        }
+        self
+    }
+
+    /// Replace the locale's clock format with the user's choice (None keeps the locale's)
+    pub fn with_clock_override(mut self, use_24_hour: Option<bool>) -> Self {
+        if let Some(use_24_hour) = use_24_hour {
+            self.use_24_hour = use_24_hour;
+        }
+        self
+    }
+
     /// Format hour for display (12h or 24h format)
     pub fn format_hour(&self, hour: u32) -> String {
         if self.use_24_hour {
@@ -57,6 +82,21 @@ impl LocalePreferences {
         }
     }
 
+    /// Format a time of day for display (e.g. "14:30" or "2:30 PM")
+    pub fn format_time(&self, time: &impl chrono::Timelike) -> String {
+        let (hour, minute) = (time.hour(), time.minute());
+        if self.use_24_hour {
+            format!("{:02}:{:02}", hour, minute)
+        } else {
+            let suffix = if hour < 12 { "AM" } else { "PM" };
+            let hour_12 = match hour % 12 {
+                0 => 12,
+                h => h,
+            };
+            format!("{}:{:02} {}", hour_12, minute, suffix)
+        }
+    }
+
     /// Get the number of days to subtract from Monday to get first day of week
     /// Monday = 0, Tuesday = 1, ..., Sunday = 6
     #[allow(dead_code)] // Reserved for future locale-aware week calculation
@@ -76,12 +116,7 @@ impl LocalePreferences {
     /// Check if a given weekday is a weekend day
     /// Weekend is typically Saturday and Sunday, but this can vary by locale
     pub fn is_weekend(&self, weekday: chrono::Weekday) -> bool {
-        use chrono::Weekday;
-
-        // For most locales, weekend is Saturday and Sunday
-        // In some Middle Eastern countries, weekend is Friday and Saturday
-        // For now, use standard Saturday/Sunday
-        matches!(weekday, Weekday::Sat | Weekday::Sun)
+        self.weekend_days.contains(&weekday)
     }
 
     /// Format a date range for week view (e.g., "Nov 24 - 30, 2024" or "24 - 30 Nov, 2024")
@@ -269,6 +304,29 @@ fn detect_first_day_of_week(locale: &str) -> chrono::Weekday {
     Weekday::Mon
 }
 
+/// Detect weekend days from locale
+fn detect_weekend_days(locale: &str) -> Vec<chrono::Weekday> {
+    use chrono::Weekday;
+
+    let locale_lower = locale.to_lowercase();
+
+    // Locales with a Friday/Saturday weekend
+    let friday_saturday_locales = [
+        "ar_sa", "ar_eg", "ar_iq", "ar_kw", "ar_qa", "ar_bh",
+        "ar_om", "ar_jo", "ar_sy", "ar_ly", "ar_sd", "ar_dz",
+        "he_il"
+    ];
+
+    for fri_sat_locale in &friday_saturday_locales {
+        if locale_lower.starts_with(fri_sat_locale) {
+            return vec![Weekday::Fri, Weekday::Sat];
+        }
+    }
+
+    // Default to Saturday and Sunday
+    vec![Weekday::Sat, Weekday::Sun]
+}
+
 /// Detect date format from locale
 fn detect_date_format(locale: &str) -> DateFormat {
     let locale_lower = locale.to_lowercase();
@@ -326,11 +384,57 @@ mod tests {
         assert_eq!(detect_first_day_of_week("ar_SA.UTF-8"), Weekday::Sun);
     }
 
+    #[test]
+    fn test_weekend_detection() {
+        assert_eq!(detect_weekend_days("de_DE.UTF-8"), vec![Weekday::Sat, Weekday::Sun]);
+        assert_eq!(detect_weekend_days("en_US.UTF-8"), vec![Weekday::Sat, Weekday::Sun]);
+        assert_eq!(detect_weekend_days("he_IL.UTF-8"), vec![Weekday::Fri, Weekday::Sat]);
+        assert_eq!(detect_weekend_days("ar_SA.UTF-8"), vec![Weekday::Fri, Weekday::Sat]);
+    }
+
+    #[test]
+    fn test_week_overrides_are_independent() {
+        let locale = LocalePreferences {
+            use_24_hour: false,
+            first_day_of_week: Weekday::Sun,
+            weekend_days: vec![Weekday::Sat, Weekday::Sun],
+            date_format: DateFormat::MDY,
+            locale_string: "en_US.UTF-8".to_string(),
+        };
+
+        // Only the weekend is overridden - the locale's first day stays
+        let weekend_only = locale.clone().with_week_overrides(None, Some(&[Weekday::Fri, Weekday::Sat]));
+        assert_eq!(weekend_only.first_day_of_week, Weekday::Sun);
+        assert!(weekend_only.is_weekend(Weekday::Fri));
+        assert!(!weekend_only.is_weekend(Weekday::Sun));
+
+        // Only the first day is overridden - the locale's weekend stays
+        let monday_start = locale.with_week_overrides(Some(Weekday::Mon), None);
+        assert_eq!(monday_start.first_day_of_week, Weekday::Mon);
+        assert_eq!(monday_start.weekend_days, vec![Weekday::Sat, Weekday::Sun]);
+    }
+
+    #[test]
+    fn test_clock_override() {
+        let locale = LocalePreferences {
+            use_24_hour: false,
+            first_day_of_week: Weekday::Sun,
+            weekend_days: vec![Weekday::Sat, Weekday::Sun],
+            date_format: DateFormat::MDY,
+            locale_string: "en_US.UTF-8".to_string(),
+        };
+
+        assert!(!locale.clone().with_clock_override(None).use_24_hour);
+        let forced_24h = locale.with_clock_override(Some(true));
+        assert_eq!(forced_24h.format_hour(13), "13:00");
+    }
+
     #[test]
     fn test_hour_formatting() {
         let locale_24h = LocalePreferences {
             use_24_hour: true,
             first_day_of_week: Weekday::Mon,
+            weekend_days: vec![Weekday::Sat, Weekday::Sun],
             date_format: DateFormat::DMY,
             locale_string: "de_DE.UTF-8".to_string(),
         };
@@ -342,6 +446,7 @@ mod tests {
         let locale_12h = LocalePreferences {
             use_24_hour: false,
             first_day_of_week: Weekday::Sun,
+            weekend_days: vec![Weekday::Sat, Weekday::Sun],
             date_format: DateFormat::MDY,
             locale_string: "en_US.UTF-8".to_string(),
         };
@@ -352,6 +457,26 @@ mod tests {
         assert_eq!(locale_12h.format_hour(13), "1 PM");
     }
 
+    #[test]
+    fn test_time_formatting() {
+        let mut locale = LocalePreferences {
+            use_24_hour: true,
+            first_day_of_week: Weekday::Mon,
+            weekend_days: vec![Weekday::Sat, Weekday::Sun],
+            date_format: DateFormat::DMY,
+            locale_string: "de_DE.UTF-8".to_string(),
+        };
+        let time = |h, m| chrono::NaiveTime::from_hms_opt(h, m, 0).unwrap();
+
+        assert_eq!(locale.format_time(&time(0, 5)), "00:05");
+        assert_eq!(locale.format_time(&time(14, 30)), "14:30");
+
+        locale.use_24_hour = false;
+        assert_eq!(locale.format_time(&time(0, 5)), "12:05 AM");
+        assert_eq!(locale.format_time(&time(12, 0)), "12:00 PM");
+        assert_eq!(locale.format_time(&time(14, 30)), "2:30 PM");
+    }
+
     #[test]
     fn test_date_format_detection() {
         assert_eq!(detect_date_format("en_US.UTF-8"), DateFormat::MDY);
@@ -32,8 +32,17 @@
 //! You can also filter by module:
 //! - `RUST_LOG=sol_calendar::services=debug` - Debug logs for services only
 //! - `RUST_LOG=sol_calendar=debug,cosmic=warn` - Mixed levels
+//!
+//! # Action Log
+//!
+//! To diagnose UI issues from user reports, set `XCALENDAR_ACTION_LOG=1` to log
+//! the name of every processed `Message` (no payloads) at trace level:
+//! - `XCALENDAR_ACTION_LOG=1 RUST_LOG=info,xcalendar::actions=trace`
 
-use log::{info, LevelFilter};
+use crate::message::Message;
+use log::{info, trace, LevelFilter};
+use std::fmt::Debug;
+use std::sync::OnceLock;
 
 /// Default log level when RUST_LOG is not set
 const DEFAULT_LOG_LEVEL: &str = "info";
@@ -41,6 +50,12 @@ const DEFAULT_LOG_LEVEL: &str = "info";
 /// Application name for log prefix
 const APP_NAME: &str = "xcalendar";
 
+/// Environment variable that enables the action log
+const ACTION_LOG_ENV: &str = "XCALENDAR_ACTION_LOG";
+
+/// Log target of action log entries (filter with `RUST_LOG=xcalendar::actions=trace`)
+const ACTION_LOG_TARGET: &str = "xcalendar::actions";
+
 /// Initialize the logging system.
 ///
 /// This should be called once at application startup, before any logging occurs.
@@ -118,9 +133,55 @@ pub fn is_trace_enabled() -> bool {
     log::max_level() >= LevelFilter::Trace
 }
 
+/// Whether the action log was enabled through `XCALENDAR_ACTION_LOG` (read once)
+fn is_action_log_enabled() -> bool {
+    static ENABLED: OnceLock<bool> = OnceLock::new();
+    *ENABLED.get_or_init(|| {
+        std::env::var(ACTION_LOG_ENV).is_ok_and(|value| !value.is_empty() && value != "0")
+    })
+}
+
+/// Name of an enum variant from its Debug output, without any payload
+/// (e.g. `SelectDay(2025, 3, 10)` -> `SelectDay`)
+fn variant_name(value: &impl Debug) -> String {
+    let debug = format!("{:?}", value);
+    let end = debug
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(debug.len());
+    debug[..end].to_string()
+}
+
+/// Record a processed message by name only, so logs carry no event data.
+/// No-op unless the action log is enabled and trace logging is on.
+pub fn log_message(message: &Message) {
+    if is_action_log_enabled() && log::log_enabled!(target: ACTION_LOG_TARGET, log::Level::Trace) {
+        trace!(target: ACTION_LOG_TARGET, "{}", variant_name(message));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::dialogs::DialogAction;
+    use crate::views::CalendarView;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_message_names_exclude_payloads() {
+        let date = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+        let cases = [
+            (Message::Today, "Today"),
+            (Message::SelectDay(2025, 3, 10), "SelectDay"),
+            (Message::ChangeView(CalendarView::Week), "ChangeView"),
+            (Message::Dialog(DialogAction::StartQuickEvent(date)), "Dialog"),
+            (Message::QuickEventTextChanged("Dentist appointment".to_string()), "QuickEventTextChanged"),
+            (Message::DeleteEvent("private-uid".to_string()), "DeleteEvent"),
+        ];
+
+        for (message, expected) in cases {
+            assert_eq!(variant_name(&message), expected);
+        }
+    }
 
     #[test]
     fn test_default_log_level() {
@@ -2,12 +2,14 @@ mod app;
 mod cache;
 mod caldav;
 mod calendars;
+mod clock;
 mod color_constants;
 mod components;
 mod database;
 #[cfg(debug_assertions)]
 mod demo_data;
 mod dialogs;
+mod event_query;
 mod keyboard;
 mod layout;
 mod layout_constants;
@@ -24,6 +26,7 @@ mod services;
 mod settings;
 mod storage;
 mod styles;
+mod timezone;
 mod ui_constants;
 mod update;
 mod url_handler;
@@ -49,6 +52,18 @@ struct Cli {
     #[arg(value_name = "FILE_OR_URL")]
     inputs: Vec<String>,
 
+    /// Print today's events and exit without opening a window
+    #[arg(long = "list-today", conflicts_with = "list_range")]
+    list_today: bool,
+
+    /// Print the events between two dates (YYYY-MM-DD, inclusive) and exit without opening a window
+    #[arg(long = "list-range", num_args = 2, value_names = ["START", "END"])]
+    list_range: Option<Vec<String>>,
+
+    /// Print listed events as JSON
+    #[arg(long = "json")]
+    json: bool,
+
     /// Reset database (development only, debug builds only)
     #[cfg(debug_assertions)]
     #[arg(long = "dev-reset-db")]
@@ -117,6 +132,24 @@ pub fn main() -> cosmic::iced::Result {
         }
     }
 
+    // Headless listing: print the events and exit before any GUI setup
+    let list_range = match cli.list_range.as_deref() {
+        Some([start, end]) => match event_query::parse_range(start, end) {
+            Ok(range) => Some(range),
+            Err(e) => {
+                eprintln!("--list-range: {}", e);
+                std::process::exit(2);
+            }
+        },
+        _ if cli.list_today => Some(event_query::today_range()),
+        _ => None,
+    };
+    if let Some((start, end)) = list_range {
+        info!("main: Listing events from {} to {}", start, end);
+        event_query::print_events(start, end, cli.json);
+        return Ok(());
+    }
+
     // Separate files and URLs
     let mut files_to_open = Vec::new();
     let mut urls_to_open = Vec::new();
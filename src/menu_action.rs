@@ -1,6 +1,9 @@
+use chrono::Weekday;
 use cosmic::widget::menu;
 
+use crate::caldav::RepeatPeriod;
 use crate::message::Message;
+use crate::settings::{EventOverlapStyle, EventTextSize, GridLineStyle};
 use crate::views::CalendarView;
 
 /// Menu actions for the application menu bar
@@ -8,15 +11,32 @@ use crate::views::CalendarView;
 pub enum MenuAction {
     NewEvent,
     NewCalendar,
+    AddCalDavAccount,
+    SyncAllCalendars,
     ImportICal,
     ExportICal,
+    PrintWeek,
+    OpenDataDirectory,
     Settings,
     Today,
     ViewYear,
     ViewMonth,
     ViewWeek,
     ViewDay,
+    ViewAgenda,
     ToggleWeekNumbers,
+    ToggleWeekends,
+    ToggleMiniWeek,
+    SetMaxEventsPerDay(Option<usize>),
+    SetGridLineStyle(GridLineStyle),
+    SetEventTextSize(EventTextSize),
+    SetEventOverlapStyle(EventOverlapStyle),
+    SetFirstDayOfWeek(Option<Weekday>),
+    /// Weekend as a pair of days (None = locale default)
+    SetWeekendDays(Option<(Weekday, Weekday)>),
+    ToggleNewEventMode,
+    ToggleMissedAlerts,
+    ToggleConfirmImportDuplicates,
     About,
     // Navigation shortcuts (Ctrl+Shift+Arrow keys)
     NavigatePrevious,
@@ -24,7 +44,14 @@ pub enum MenuAction {
     ScrollTimelineUp,
     ScrollTimelineDown,
     // Event actions
+    Undo,
+    Redo,
     DeleteSelectedEvent,
+    SelectAllEventsInView,
+    MoveSelectedEventToToday,
+    RepeatSelectedEventForward(RepeatPeriod),
+    DuplicateSelectedEvent,
+    ToggleCalendarByIndex(usize),
     // View cycling (V + Arrow keys)
     CycleViewNext,
     CycleViewPrevious,
@@ -37,21 +64,44 @@ impl menu::action::MenuAction for MenuAction {
         match self {
             MenuAction::NewEvent => Message::NewEvent,
             MenuAction::NewCalendar => Message::OpenNewCalendarDialog,
+            MenuAction::AddCalDavAccount => Message::OpenCalDavAccountDialog,
+            MenuAction::SyncAllCalendars => Message::SyncAllCalendars,
             MenuAction::ImportICal => Message::ImportICal,
             MenuAction::ExportICal => Message::ExportICal,
+            MenuAction::PrintWeek => Message::PrintWeek,
+            MenuAction::OpenDataDirectory => Message::OpenDataDirectory,
             MenuAction::Settings => Message::Settings,
             MenuAction::Today => Message::Today,
             MenuAction::ViewYear => Message::ChangeView(CalendarView::Year),
             MenuAction::ViewMonth => Message::ChangeView(CalendarView::Month),
             MenuAction::ViewWeek => Message::ChangeView(CalendarView::Week),
             MenuAction::ViewDay => Message::ChangeView(CalendarView::Day),
+            MenuAction::ViewAgenda => Message::ChangeView(CalendarView::Agenda),
             MenuAction::ToggleWeekNumbers => Message::ToggleWeekNumbers,
+            MenuAction::ToggleWeekends => Message::ToggleWeekends,
+            MenuAction::ToggleMiniWeek => Message::ToggleMiniWeek,
+            MenuAction::SetMaxEventsPerDay(max) => Message::SetMaxEventsPerDay(*max),
+            MenuAction::ToggleNewEventMode => Message::ToggleNewEventMode,
+            MenuAction::ToggleMissedAlerts => Message::ToggleMissedAlerts,
+            MenuAction::ToggleConfirmImportDuplicates => Message::ToggleConfirmImportDuplicates,
+            MenuAction::SetGridLineStyle(grid_lines) => Message::SetGridLineStyle(*grid_lines),
+            MenuAction::SetEventTextSize(text_size) => Message::SetEventTextSize(*text_size),
+            MenuAction::SetEventOverlapStyle(style) => Message::SetEventOverlapStyle(*style),
+            MenuAction::SetFirstDayOfWeek(first_day) => Message::SetFirstDayOfWeek(*first_day),
+            MenuAction::SetWeekendDays(days) => Message::SetWeekendDays(days.map(|(first, second)| vec![first, second])),
             MenuAction::About => Message::About,
             MenuAction::NavigatePrevious => Message::PreviousPeriod,
             MenuAction::NavigateNext => Message::NextPeriod,
             MenuAction::ScrollTimelineUp => Message::ScrollTimelineUp,
             MenuAction::ScrollTimelineDown => Message::ScrollTimelineDown,
+            MenuAction::Undo => Message::Undo,
+            MenuAction::Redo => Message::Redo,
             MenuAction::DeleteSelectedEvent => Message::RequestDeleteSelectedEvent,
+            MenuAction::SelectAllEventsInView => Message::SelectAllEventsInView,
+            MenuAction::MoveSelectedEventToToday => Message::MoveSelectedEventToToday,
+            MenuAction::RepeatSelectedEventForward(period) => Message::RepeatSelectedEventForward(*period),
+            MenuAction::DuplicateSelectedEvent => Message::DuplicateSelectedEvent,
+            MenuAction::ToggleCalendarByIndex(index) => Message::ToggleCalendarByIndex(*index),
             MenuAction::CycleViewNext => Message::CycleViewNext,
             MenuAction::CycleViewPrevious => Message::CycleViewPrevious,
         }
@@ -1,7 +1,9 @@
-use chrono::{NaiveDate, NaiveTime};
+use chrono::{NaiveDate, NaiveTime, Weekday};
 use crate::app::EventDialogField;
-use crate::caldav::{AlertTime, CalendarEvent, RepeatFrequency, TravelTime};
+use crate::caldav::{AlertTime, CalendarEvent, RecurrenceScope, RepeatFrequency, RepeatPeriod, TravelTime};
 use crate::dialogs::DialogAction;
+use crate::selection::ResizeEdge;
+use crate::settings::{DefaultView, EventOverlapStyle, EventTextSize, GridLineStyle, WorkingHours};
 use crate::views::CalendarView;
 use cosmic::iced::widget::scrollable::Viewport;
 use cosmic::widget::text_editor;
@@ -25,9 +27,15 @@ pub enum Message {
     PreviousPeriod,
     NextPeriod,
     Today,
+    /// Jump to the soonest event after the (empty) visible range
+    GoToNextEvent,
     SelectDay(i32, u32, u32), // (year, month, day)
     /// Select a day without navigating away from current month view (for adjacent month days)
     SelectDayNoNavigate(NaiveDate),
+    /// Move the selected date with the arrow and page keys
+    MoveSelectedDate(crate::keyboard::DateStep),
+    /// Open the week view for the week containing this date (week number click)
+    SelectWeek(NaiveDate),
 
     // UI state
     /// Timer tick for updating current time indicator (every minute)
@@ -35,8 +43,59 @@ pub enum Message {
     ToggleSidebar,
     /// Triggered on window resize to sync sidebar with condensed state
     WindowResized,
+    /// Reload events from storage and refresh the views (the window regained focus)
+    RefreshEvents,
     ToggleSearch,
+    /// Search text changed - refreshes the search results
+    SearchQueryChanged(String),
+    /// Search result clicked - go to its date and select the event (date, unique_id)
+    SelectSearchResult(NaiveDate, String),
+    /// Text-editing shortcut (Ctrl+Z, Ctrl+A) pressed with no text input taking it;
+    /// ignored while the search field has the keyboard
+    TextEditingShortcut(crate::menu_action::MenuAction),
     ToggleWeekNumbers,
+    /// Show or hide weekend days in week and month views
+    ToggleWeekends,
+    /// Show or hide the current-week strip above the month grid
+    ToggleMiniWeek,
+    /// Cap events shown per month cell before "+N more" (None = fit to cell)
+    SetMaxEventsPerDay(Option<usize>),
+    /// Toggle whether new events from cells open quick input or the full dialog
+    ToggleNewEventMode,
+    /// Toggle the startup summary of alerts missed while the app was closed
+    ToggleMissedAlerts,
+    /// Toggle the add/skip preview before importing into a non-empty calendar
+    ToggleConfirmImportDuplicates,
+    /// Change grid line visibility/weight in month/week/day views
+    SetGridLineStyle(GridLineStyle),
+    /// Change the font size of event labels
+    SetEventTextSize(EventTextSize),
+    /// Change how overlapping events are laid out in the week view
+    SetEventOverlapStyle(EventOverlapStyle),
+    /// Change the first day of the week (None = locale default)
+    SetFirstDayOfWeek(Option<Weekday>),
+    /// Change the weekend days (None = locale default)
+    SetWeekendDays(Option<Vec<Weekday>>),
+    /// Use a 24-hour (true) or 12-hour (false) clock (None = locale default)
+    SetUse24Hour(Option<bool>),
+    /// Change the view shown on startup
+    SetDefaultView(DefaultView),
+    /// Change the alert given to new all-day events
+    SetAllDayAlert(AlertTime),
+    /// Toggle shading the hours outside the working day in the week and day views
+    ToggleOffHoursShading,
+    /// Change the working day used by the off-hours shading
+    SetWorkingHours(WorkingHours),
+    /// Change the hour the week and day views scroll to when today isn't shown
+    SetDayStartHour(u32),
+    /// Clear the settings dialog's overrides and use the system defaults
+    ResetSettingsToSystemDefaults,
+    /// Collapse or expand the week view's all-day section
+    ToggleAllDayCollapsed,
+    /// Toggle a category in the filter bar (show only events with selected categories)
+    ToggleCategoryFilter(String),
+    /// Clear the category filter and show all events
+    ClearCategoryFilter,
     /// Week view scroll position changed - tracks scroll via on_scroll callback (COSMIC pattern)
     WeekViewScroll(Viewport),
     /// Restore week view scroll position after quick event dialog closes
@@ -64,10 +123,30 @@ pub enum Message {
     EditCalendarByIndex(usize),
     /// Export calendar to iCalendar file by index (from context menu)
     ExportCalendarByIndex(usize),
+    /// Sync calendar with its backend by index (from context menu)
+    SyncCalendarByIndex(usize),
+    /// Background sync finished for a calendar (calendar_id, fetched events or error)
+    CalendarSynced(String, Result<Vec<CalendarEvent>, String>),
+    /// Sync all enabled calendars with their backends (startup and File menu)
+    SyncAllCalendars,
+    /// Open the dialog for adding a CalDAV account
+    OpenCalDavAccountDialog,
+    /// Update the calendar name in the CalDAV account dialog
+    CalDavAccountNameChanged(String),
+    /// Update the server URL in the CalDAV account dialog
+    CalDavAccountServerUrlChanged(String),
+    /// Update the username in the CalDAV account dialog
+    CalDavAccountUsernameChanged(String),
+    /// Update the password in the CalDAV account dialog
+    CalDavAccountPasswordChanged(String),
+    /// Add the calendar entered in the CalDAV account dialog
+    ConfirmCalDavAccount,
     /// Update calendar name while typing in dialog
     CalendarDialogNameChanged(String),
     /// Update calendar color selection in dialog
     CalendarDialogColorChanged(String),
+    /// Toggle muted alerts in the calendar dialog
+    CalendarDialogMuteAlertsToggled(bool),
     /// Confirm the calendar dialog (Create or Edit)
     ConfirmCalendarDialog,
     /// Cancel the calendar dialog
@@ -78,8 +157,20 @@ pub enum Message {
     RequestDeleteCalendar(String),
     /// Select calendar by index (from context menu)
     SelectCalendarByIndex(usize),
+    /// Toggle visibility of the calendar at this sidebar index (keyboard shortcut)
+    ToggleCalendarByIndex(usize),
     /// Delete calendar by index (from context menu)
     DeleteCalendarByIndex(usize),
+    /// Open the color picker for the calendar at this index (from context menu)
+    ChangeCalendarColorByIndex(usize),
+    /// Make the calendar at this index the default for new events (from context menu)
+    SetDefaultCalendarByIndex(usize),
+    /// Pin or unpin the calendar at this index at the top of the list (from context menu)
+    TogglePinCalendarByIndex(usize),
+    /// Move the calendar at the first index to the second index in the list (from context menu)
+    MoveCalendarByIndex(usize, usize),
+    /// Duplicate the calendar at the index with all its events (from context menu)
+    DuplicateCalendarByIndex(usize),
     /// Confirm calendar deletion
     ConfirmDeleteCalendar,
     /// Cancel calendar deletion
@@ -108,6 +199,9 @@ pub enum Message {
     StartQuickEvent(NaiveDate),
     /// Start creating a quick timed event with start and end times
     StartQuickTimedEvent(NaiveDate, NaiveTime, NaiveTime),
+    /// Create an event from a day cell or time slot double-click (date, optional start time)
+    /// Opens quick input or the full dialog depending on the new event mode setting
+    CreateEventAt(NaiveDate, Option<NaiveTime>),
     /// Update the quick event text while editing
     QuickEventTextChanged(String),
     /// Commit the quick event (on Enter press)
@@ -118,32 +212,68 @@ pub enum Message {
     DeleteEvent(String),
     /// Request to delete the currently selected event (opens confirmation dialog)
     RequestDeleteSelectedEvent,
+    /// Reschedule an event (by UID) to today, keeping its time of day
+    MoveEventToToday(String),
+    /// Reschedule the selected event to today
+    MoveSelectedEventToToday,
+    /// Copy a single event (by UID) one week/month forward as a new event
+    RepeatEventForward(String, RepeatPeriod),
+    /// Copy the selected event one week/month forward
+    RepeatSelectedEventForward(RepeatPeriod),
+    /// Copy an event (by UID) as a new event and open the copy for editing
+    DuplicateEvent(String),
+    /// Duplicate the selected event
+    DuplicateSelectedEvent,
     /// Confirm event deletion from the confirmation dialog (deletes all occurrences for recurring)
     ConfirmDeleteEvent,
     /// Delete only the selected occurrence of a recurring event
     DeleteSingleOccurrence,
+    /// Delete the selected occurrence of a recurring event and all later ones
+    DeleteFollowingOccurrences,
     /// Cancel event deletion
     CancelDeleteEvent,
     /// Select an event (single click) for viewing/editing
     SelectEvent(String),
+    /// Select every event shown in the current view for batch actions
+    SelectAllEventsInView,
+    /// Undo the last event change (Ctrl+Z or the toast's "Undo" button)
+    Undo,
+    /// Reapply the last undone event change (Ctrl+Shift+Z)
+    Redo,
+
+    // Toast notifications
+    /// Dismiss the visible toast
+    DismissToast,
+    /// Periodic tick while a toast is visible (auto-dismiss after timeout)
+    ToastTick,
 
     // Event drag-and-drop
     /// Start dragging an event to move it (calendar_id, uid, original_date, summary, color)
     DragEventStart(String, String, NaiveDate, String, String),
     /// Update the drag target date as user drags over cells
     DragEventUpdate(NaiveDate),
-    /// Update cursor position during drag (x, y)
+    /// Update cursor position during drag or resize (x, y)
     DragEventCursorMove(f32, f32),
     /// End the drag operation (drop the event)
     DragEventEnd,
     /// Cancel the drag operation
     DragEventCancel,
 
+    // Event resizing in the week/day time grid
+    /// Start dragging an edge of a timed event (calendar_id, uid, edge, shown start, shown end)
+    EventResizeStart(String, String, ResizeEdge, NaiveTime, NaiveTime),
+    /// End the resize (mouse released anywhere) and save the new time
+    EventResizeEnd,
+
     // Event management - Event dialog
     /// Open the event dialog for creating a new event
     OpenNewEventDialog,
+    /// Open the event dialog for a new event on a date (optional start time)
+    OpenNewEventDialogAt(NaiveDate, Option<NaiveTime>),
     /// Open the event dialog for editing an existing event (calendar_id, uid)
     OpenEditEventDialog(String, String),
+    /// Open the popover with all events of a day ("+N more" in the month view)
+    OpenDayEvents(NaiveDate),
     /// Toggle edit mode for a specific field
     EventDialogToggleEdit(EventDialogField, bool),
     /// Update event title in dialog
@@ -188,6 +318,10 @@ pub enum Message {
     EventDialogTravelTimeChanged(TravelTime),
     /// Update repeat frequency in dialog
     EventDialogRepeatChanged(RepeatFrequency),
+    /// Update the repeat interval (every N days/weeks/months/years) in dialog
+    EventDialogRepeatIntervalChanged(u32),
+    /// Toggle a weekday of a weekly series in dialog
+    EventDialogRepeatWeekdayToggled(chrono::Weekday),
     /// Update selected calendar in dialog
     EventDialogCalendarChanged(String),
     /// Update invitee input text
@@ -196,6 +330,14 @@ pub enum Message {
     EventDialogAddInvitee,
     /// Remove an invitee from the list
     EventDialogRemoveInvitee(usize),
+    /// Update category input text
+    EventDialogCategoryInputChanged(String),
+    /// Add a category/tag to the event
+    EventDialogAddCategory,
+    /// Remove a category/tag from the event
+    EventDialogRemoveCategory(usize),
+    /// Set the event color override in dialog (None = calendar color)
+    EventDialogColorChanged(Option<String>),
     /// Update alert setting in dialog
     EventDialogAlertChanged(AlertTime),
     /// Update second alert setting in dialog
@@ -210,6 +352,10 @@ pub enum Message {
     EventDialogNotesAction(text_editor::Action),
     /// Confirm the event dialog (Create or Save)
     ConfirmEventDialog,
+    /// Save an edited occurrence for the chosen occurrences of its series
+    ConfirmEditScope(RecurrenceScope),
+    /// Close the edit scope prompt, back to the event dialog
+    CancelEditScope,
     /// Cancel the event dialog
     CancelEventDialog,
 
@@ -221,6 +367,11 @@ pub enum Message {
     NewEvent,
     ImportICal,
     ExportICal,
+    /// Save the current week as a printable page
+    PrintWeek,
+    /// Open the file manager at the local data directory (for manual backup)
+    OpenDataDirectory,
+    /// Open the settings dialog
     Settings,
     About,
     LaunchUrl(String),
@@ -246,6 +397,12 @@ pub enum Message {
     RevertImport,
     /// Export calendar to file (calendar_id, file_path)
     ExportCalendarToFile(String, PathBuf),
+    /// Export all enabled calendars to file
+    ExportAllToFile(PathBuf),
+    /// Export the selected events to file ((calendar_id, uid) of each series, file_path)
+    ExportEventsToFile(Vec<(String, String)>, PathBuf),
+    /// Write the printable week page to file
+    PrintWeekToFile(PathBuf),
 
     /// Process URL from command line (webcal://, ics://, calendar://)
     ProcessUrl(String),
@@ -1,4 +1,4 @@
-use chrono::Datelike;
+use chrono::{Datelike, Months, NaiveDate, Weekday};
 use crate::localized_names;
 
 /// Represents a day in the calendar grid with full date info
@@ -21,98 +21,58 @@ pub struct CalendarState {
     pub weeks_full: Vec<Vec<CalendarDay>>,
     pub today: (i32, u32, u32), // (year, month, day)
     pub month_year_text: String, // Pre-formatted "Month Year" text
+    /// Weekday each grid row starts on (the user's week start)
+    pub first_day: Weekday,
 }
 
-impl CalendarState {
-    pub fn new(year: i32, month: u32) -> Self {
-        let first_day = chrono::NaiveDate::from_ymd_opt(year, month, 1).unwrap();
-        let first_weekday = first_day.weekday().num_days_from_monday();
-
-        let days_in_month = if month == 12 {
-            chrono::NaiveDate::from_ymd_opt(year + 1, 1, 1)
-                .unwrap()
-                .signed_duration_since(first_day)
-                .num_days()
-        } else {
-            chrono::NaiveDate::from_ymd_opt(year, month + 1, 1)
-                .unwrap()
-                .signed_duration_since(first_day)
-                .num_days()
-        };
-
-        // Calculate previous month info
-        let (prev_year, prev_month) = if month == 1 {
-            (year - 1, 12)
-        } else {
-            (year, month - 1)
-        };
-        let prev_month_days = if month == 1 {
-            chrono::NaiveDate::from_ymd_opt(year - 1, 12, 31).unwrap().day()
-        } else {
-            chrono::NaiveDate::from_ymd_opt(year, month, 1)
-                .unwrap()
-                .pred_opt()
-                .unwrap()
-                .day()
-        };
-
-        // Calculate next month info
-        let (next_year, next_month) = if month == 12 {
-            (year + 1, 1)
-        } else {
-            (year, month + 1)
-        };
-
-        let mut weeks = vec![];
-        let mut weeks_full = vec![];
-        let mut current_week = vec![];
-        let mut current_week_full = vec![];
-
-        // Fill in previous month days
-        for i in 0..first_weekday {
-            current_week.push(None);
-            let prev_day = prev_month_days - (first_weekday - 1 - i);
-            current_week_full.push(CalendarDay {
-                year: prev_year,
-                month: prev_month,
-                day: prev_day,
-                is_current_month: false,
-            });
-        }
+/// Build the weeks of a month grid, each a full row of 7 days starting at `first_day`.
+/// Leading and trailing cells are filled with days from the adjacent months,
+/// so a month spans 4 to 6 rows depending on its length and starting weekday.
+pub fn build_month_weeks(year: i32, month: u32, first_day: Weekday) -> Vec<Vec<CalendarDay>> {
+    let first_of_month = NaiveDate::from_ymd_opt(year, month, 1).unwrap();
+    let first_of_next = first_of_month
+        .checked_add_months(Months::new(1))
+        .unwrap();
+
+    // Days between the start of the grid row and the 1st of the month
+    let leading_days = first_of_month.weekday().days_since(first_day);
+    let grid_start = first_of_month - chrono::Duration::days(leading_days as i64);
+
+    let mut weeks = Vec::new();
+    let mut week_start = grid_start;
+    while week_start < first_of_next {
+        let week = (0..7)
+            .map(|offset| {
+                let date = week_start + chrono::Duration::days(offset);
+                CalendarDay {
+                    year: date.year(),
+                    month: date.month(),
+                    day: date.day(),
+                    is_current_month: date.year() == year && date.month() == month,
+                }
+            })
+            .collect();
+        weeks.push(week);
+        week_start += chrono::Duration::weeks(1);
+    }
 
-        // Fill in current month days
-        for day in 1..=days_in_month {
-            current_week.push(Some(day as u32));
-            current_week_full.push(CalendarDay {
-                year,
-                month,
-                day: day as u32,
-                is_current_month: true,
-            });
-            if current_week.len() == 7 {
-                weeks.push(current_week.clone());
-                weeks_full.push(current_week_full.clone());
-                current_week.clear();
-                current_week_full.clear();
-            }
-        }
+    weeks
+}
 
-        // Fill in next month days
-        if !current_week.is_empty() {
-            let mut next_day = 1u32;
-            while current_week.len() < 7 {
-                current_week.push(None);
-                current_week_full.push(CalendarDay {
-                    year: next_year,
-                    month: next_month,
-                    day: next_day,
-                    is_current_month: false,
-                });
-                next_day += 1;
-            }
-            weeks.push(current_week);
-            weeks_full.push(current_week_full);
-        }
+impl CalendarState {
+    /// Month grid with rows starting on `first_day`
+    pub fn new(year: i32, month: u32, first_day: Weekday) -> Self {
+        let weeks_full = build_month_weeks(year, month, first_day);
+
+        // Day numbers of the displayed month only (None for adjacent-month cells)
+        let weeks = weeks_full
+            .iter()
+            .map(|week| {
+                week.iter()
+                    .map(|d| d.is_current_month.then_some(d.day))
+                    .collect()
+            })
+            .collect();
 
         let today = chrono::Local::now();
         let month_name = localized_names::get_month_name(month);
@@ -125,9 +85,15 @@ impl CalendarState {
             weeks_full,
             today: (today.year(), today.month(), today.day()),
             month_year_text,
+            first_day,
         }
     }
 
+    /// Weekdays of the grid columns, in order
+    pub fn weekdays(&self) -> Vec<Weekday> {
+        std::iter::successors(Some(self.first_day), |day| Some(day.succ())).take(7).collect()
+    }
+
     pub fn is_today(&self, day: u32) -> bool {
         self.today == (self.year, self.month, day)
     }
@@ -164,3 +130,84 @@ impl CalendarState {
         date.weekday()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day_numbers(week: &[CalendarDay]) -> Vec<u32> {
+        week.iter().map(|d| d.day).collect()
+    }
+
+    #[test]
+    fn test_four_row_month() {
+        // February 2015 starts on a Sunday and has 28 days
+        let weeks = build_month_weeks(2015, 2, Weekday::Sun);
+        assert_eq!(weeks.len(), 4);
+        assert!(weeks.iter().flatten().all(|d| d.is_current_month));
+
+        // With Monday start the Sunday 1st needs a leading row
+        let weeks = build_month_weeks(2015, 2, Weekday::Mon);
+        assert_eq!(weeks.len(), 5);
+        assert_eq!(day_numbers(&weeks[0]), vec![26, 27, 28, 29, 30, 31, 1]);
+    }
+
+    #[test]
+    fn test_five_row_month() {
+        // April 2025 starts on a Tuesday and has 30 days
+        let weeks = build_month_weeks(2025, 4, Weekday::Mon);
+        assert_eq!(weeks.len(), 5);
+        assert_eq!(day_numbers(&weeks[0]), vec![31, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(day_numbers(&weeks[4]), vec![28, 29, 30, 1, 2, 3, 4]);
+
+        let weeks = build_month_weeks(2025, 4, Weekday::Sun);
+        assert_eq!(weeks.len(), 5);
+        assert_eq!(day_numbers(&weeks[0]), vec![30, 31, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_six_row_month() {
+        // March 2025 starts on a Saturday and has 31 days
+        for first_day in [Weekday::Mon, Weekday::Sun] {
+            let weeks = build_month_weeks(2025, 3, first_day);
+            assert_eq!(weeks.len(), 6);
+            assert!(weeks.iter().all(|week| week.len() == 7));
+        }
+    }
+
+    #[test]
+    fn test_adjacent_month_days() {
+        // January grid reaches back into December of the previous year
+        let weeks = build_month_weeks(2025, 1, Weekday::Mon);
+        let first = weeks[0][0];
+        assert_eq!((first.year, first.month, first.day, first.is_current_month), (2024, 12, 30, false));
+
+        // ...and December forward into January of the next year
+        let weeks = build_month_weeks(2025, 12, Weekday::Mon);
+        let last = *weeks.last().unwrap().last().unwrap();
+        assert_eq!((last.year, last.month, last.day, last.is_current_month), (2026, 1, 4, false));
+
+        // Rows always start on the requested weekday
+        for week in &weeks {
+            let date = NaiveDate::from_ymd_opt(week[0].year, week[0].month, week[0].day).unwrap();
+            assert_eq!(date.weekday(), Weekday::Mon);
+        }
+    }
+
+    #[test]
+    fn test_calendar_state_weeks_match_grid() {
+        let state = CalendarState::new(2025, 4, Weekday::Mon);
+        assert_eq!(state.weeks.len(), state.weeks_full.len());
+        assert_eq!(state.weeks[0], vec![None, Some(1), Some(2), Some(3), Some(4), Some(5), Some(6)]);
+        assert_eq!(state.weeks[4], vec![Some(28), Some(29), Some(30), None, None, None, None]);
+    }
+
+    #[test]
+    fn test_calendar_state_follows_week_start() {
+        // Sunday-start grids begin with Sunday March 30 and list Sunday first
+        let state = CalendarState::new(2025, 4, Weekday::Sun);
+        assert_eq!(state.weeks[0], vec![None, None, Some(1), Some(2), Some(3), Some(4), Some(5)]);
+        assert_eq!(state.weekdays()[0], Weekday::Sun);
+        assert_eq!(state.weekdays()[6], Weekday::Sat);
+    }
+}
@@ -0,0 +1,138 @@
+//! Calendar statistics
+//!
+//! Per-calendar event counts for the current week, month, and year, shown in
+//! the About drawer.
+
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use std::collections::HashMap;
+
+/// A period that events are counted over
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatsPeriod {
+    Week,
+    Month,
+    Year,
+}
+
+impl StatsPeriod {
+    /// Date range `[start, end)` of the period containing `today`
+    ///
+    /// # Arguments
+    /// * `today` - The reference date
+    /// * `first_day_of_week` - Locale's first day of the week (for `Week`)
+    pub fn range(self, today: NaiveDate, first_day_of_week: Weekday) -> (NaiveDate, NaiveDate) {
+        match self {
+            StatsPeriod::Week => {
+                let offset = (7 + today.weekday().num_days_from_monday()
+                    - first_day_of_week.num_days_from_monday()) % 7;
+                let start = today - Duration::days(offset as i64);
+                (start, start + Duration::days(7))
+            }
+            StatsPeriod::Month => {
+                let start = today.with_day(1).unwrap_or(today);
+                let end = if start.month() == 12 {
+                    NaiveDate::from_ymd_opt(start.year() + 1, 1, 1)
+                } else {
+                    NaiveDate::from_ymd_opt(start.year(), start.month() + 1, 1)
+                };
+                (start, end.unwrap_or(start))
+            }
+            StatsPeriod::Year => {
+                let start = NaiveDate::from_ymd_opt(today.year(), 1, 1).unwrap_or(today);
+                let end = NaiveDate::from_ymd_opt(today.year() + 1, 1, 1).unwrap_or(today);
+                (start, end)
+            }
+        }
+    }
+}
+
+/// Event counts for one calendar
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalendarPeriodCounts {
+    pub calendar_id: String,
+    pub name: String,
+    pub week: usize,
+    pub month: usize,
+    pub year: usize,
+}
+
+/// Event counts per calendar for the current week, month, and year
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CalendarStats {
+    pub calendars: Vec<CalendarPeriodCounts>,
+}
+
+impl CalendarStats {
+    /// Combine per-period counts (keyed by calendar ID) into stats rows.
+    /// Calendars without events in a period count as zero.
+    ///
+    /// # Arguments
+    /// * `calendars` - `(id, name)` of each calendar, in display order
+    /// * `week`, `month`, `year` - Event counts per calendar ID for each period
+    pub fn from_counts(
+        calendars: &[(String, String)],
+        week: &HashMap<String, usize>,
+        month: &HashMap<String, usize>,
+        year: &HashMap<String, usize>,
+    ) -> Self {
+        let count = |counts: &HashMap<String, usize>, id: &str| counts.get(id).copied().unwrap_or(0);
+        Self {
+            calendars: calendars
+                .iter()
+                .map(|(id, name)| CalendarPeriodCounts {
+                    calendar_id: id.clone(),
+                    name: name.clone(),
+                    week: count(week, id),
+                    month: count(month, id),
+                    year: count(year, id),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_week_range_respects_first_day() {
+        // Wednesday 2025-12-31
+        let today = date(2025, 12, 31);
+        assert_eq!(StatsPeriod::Week.range(today, Weekday::Mon), (date(2025, 12, 29), date(2026, 1, 5)));
+        assert_eq!(StatsPeriod::Week.range(today, Weekday::Sun), (date(2025, 12, 28), date(2026, 1, 4)));
+        // First day of the week is its own start
+        assert_eq!(StatsPeriod::Week.range(date(2025, 12, 29), Weekday::Mon).0, date(2025, 12, 29));
+    }
+
+    #[test]
+    fn test_month_and_year_ranges() {
+        let today = date(2025, 12, 15);
+        assert_eq!(StatsPeriod::Month.range(today, Weekday::Mon), (date(2025, 12, 1), date(2026, 1, 1)));
+        assert_eq!(StatsPeriod::Month.range(date(2024, 2, 29), Weekday::Mon), (date(2024, 2, 1), date(2024, 3, 1)));
+        assert_eq!(StatsPeriod::Year.range(today, Weekday::Mon), (date(2025, 1, 1), date(2026, 1, 1)));
+    }
+
+    #[test]
+    fn test_from_counts() {
+        let calendars = vec![
+            ("work".to_string(), "Work".to_string()),
+            ("home".to_string(), "Home".to_string()),
+        ];
+        let week = HashMap::from([("work".to_string(), 2)]);
+        let month = HashMap::from([("work".to_string(), 5), ("home".to_string(), 1)]);
+        let year = HashMap::from([("work".to_string(), 40), ("home".to_string(), 12)]);
+
+        let stats = CalendarStats::from_counts(&calendars, &week, &month, &year);
+
+        assert_eq!(stats.calendars.len(), 2);
+        assert_eq!(stats.calendars[0].name, "Work");
+        assert_eq!((stats.calendars[0].week, stats.calendars[0].month, stats.calendars[0].year), (2, 5, 40));
+        // Missing counts are zero
+        assert_eq!((stats.calendars[1].week, stats.calendars[1].month, stats.calendars[1].year), (0, 1, 12));
+    }
+}
@@ -0,0 +1,134 @@
+//! Category filter state
+//!
+//! Tracks which event categories (tags) are selected in the filter bar and
+//! narrows cached display events down to the matching ones.
+
+use chrono::NaiveDate;
+use std::collections::{BTreeSet, HashMap};
+
+use crate::components::DisplayEvent;
+
+/// Selected categories for filtering displayed events.
+/// An empty selection shows all events.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CategoryFilter {
+    /// Selected category names (stored lowercase for case-insensitive matching)
+    selected: BTreeSet<String>,
+}
+
+impl CategoryFilter {
+    /// Select the category if it isn't selected, otherwise deselect it
+    pub fn toggle(&mut self, category: &str) {
+        let key = category.to_lowercase();
+        if !self.selected.remove(&key) {
+            self.selected.insert(key);
+        }
+    }
+
+    /// Clear the selection so all events are shown
+    pub fn clear(&mut self) {
+        self.selected.clear();
+    }
+
+    /// Whether any category is selected
+    pub fn is_active(&self) -> bool {
+        !self.selected.is_empty()
+    }
+
+    /// Drop selected categories that are no longer used by any event,
+    /// so a stale selection can't hide every event
+    pub fn retain_available(&mut self, available: &[String]) {
+        self.selected.retain(|key| available.iter().any(|c| c.to_lowercase() == *key));
+    }
+
+    /// Whether the given category is selected
+    pub fn is_selected(&self, category: &str) -> bool {
+        self.selected.contains(&category.to_lowercase())
+    }
+
+    /// Whether an event with these categories passes the filter.
+    /// Events match when they have at least one selected category.
+    pub fn matches(&self, categories: &[String]) -> bool {
+        !self.is_active() || categories.iter().any(|c| self.is_selected(c))
+    }
+
+    /// Drop events that don't match the filter, removing days left empty
+    pub fn apply(&self, events_by_date: &mut HashMap<NaiveDate, Vec<DisplayEvent>>) {
+        if !self.is_active() {
+            return;
+        }
+        events_by_date.retain(|_, events| {
+            events.retain(|e| self.matches(&e.categories));
+            !events.is_empty()
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(uid: &str, categories: &[&str]) -> DisplayEvent {
+        DisplayEvent {
+            calendar_id: "cal".to_string(),
+            uid: uid.to_string(),
+            summary: uid.to_string(),
+            all_day: true,
+            start_time: None,
+            end_time: None,
+            categories: categories.iter().map(|c| c.to_string()).collect(),
+            ..DisplayEvent::test_default()
+        }
+    }
+
+    fn uids(events_by_date: &HashMap<NaiveDate, Vec<DisplayEvent>>, date: NaiveDate) -> Vec<String> {
+        events_by_date
+            .get(&date)
+            .map(|events| events.iter().map(|e| e.uid.clone()).collect())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn test_toggle_is_case_insensitive() {
+        let mut filter = CategoryFilter::default();
+        assert!(!filter.is_active());
+
+        filter.toggle("Work");
+        assert!(filter.is_selected("work"));
+        filter.toggle("WORK");
+        assert!(!filter.is_active());
+    }
+
+    #[test]
+    fn test_apply_keeps_matching_events() {
+        let day1 = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+        let day2 = NaiveDate::from_ymd_opt(2025, 3, 11).unwrap();
+        let mut events_by_date = HashMap::new();
+        events_by_date.insert(day1, vec![
+            event("standup", &["Work"]),
+            event("gym", &["Health"]),
+            event("untagged", &[]),
+        ]);
+        events_by_date.insert(day2, vec![event("dentist", &["Health"])]);
+
+        // No selection leaves everything visible
+        let mut unfiltered = events_by_date.clone();
+        CategoryFilter::default().apply(&mut unfiltered);
+        assert_eq!(uids(&unfiltered, day1), vec!["standup", "gym", "untagged"]);
+        assert_eq!(uids(&unfiltered, day2), vec!["dentist"]);
+
+        let mut filter = CategoryFilter::default();
+        filter.toggle("work");
+        let mut filtered = events_by_date.clone();
+        filter.apply(&mut filtered);
+        assert_eq!(uids(&filtered, day1), vec!["standup"]);
+        assert!(!filtered.contains_key(&day2));
+
+        // Multiple selections match any of them
+        filter.toggle("Health");
+        let mut filtered = events_by_date.clone();
+        filter.apply(&mut filtered);
+        assert_eq!(uids(&filtered, day1), vec!["standup", "gym"]);
+        assert_eq!(uids(&filtered, day2), vec!["dentist"]);
+    }
+}
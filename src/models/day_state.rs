@@ -1,6 +1,9 @@
-use chrono::{Datelike, NaiveDate};
+use chrono::{Datelike, Duration, NaiveDate, NaiveTime};
+use crate::caldav::EventStatus;
+use crate::components::DisplayEvent;
 use crate::locale::LocalePreferences;
 use crate::localized_names;
+use crate::settings::WorkingHours;
 
 /// Cached day state for day view
 #[derive(Debug, Clone, PartialEq)]
@@ -54,3 +57,185 @@ impl DayState {
         self.date == self.today
     }
 }
+
+/// Last second of a day, the end of intervals that run until midnight
+const END_OF_DAY: NaiveTime = NaiveTime::from_hms_opt(23, 59, 59).unwrap();
+
+/// Time window of the working day searched for free slots in the day summary.
+/// A working day ending at 24 runs to the end of the day.
+pub fn working_window(hours: WorkingHours) -> (NaiveTime, NaiveTime) {
+    let start = NaiveTime::from_hms_opt(hours.start, 0, 0).unwrap_or(NaiveTime::MIN);
+    let end = NaiveTime::from_hms_opt(hours.end, 0, 0).unwrap_or(END_OF_DAY);
+    (start, end)
+}
+
+/// Busy intervals of a day's timed events.
+/// All-day and cancelled events don't block time; events running past midnight
+/// are busy until the end of the day.
+pub fn busy_intervals(events: &[DisplayEvent]) -> Vec<(NaiveTime, NaiveTime)> {
+    events
+        .iter()
+        .filter(|e| !e.all_day && e.status != EventStatus::Cancelled)
+        .filter_map(|e| {
+            let start = e.start_time?;
+            let end = e.end_time.filter(|end| *end > start).unwrap_or(END_OF_DAY);
+            Some((start, end))
+        })
+        .collect()
+}
+
+/// Open gaps within `window` not covered by any busy interval.
+/// Overlapping and touching busy intervals are merged, so adjacent free time
+/// always comes back as a single slot.
+pub fn free_slots(busy: &[(NaiveTime, NaiveTime)], window: (NaiveTime, NaiveTime)) -> Vec<(NaiveTime, NaiveTime)> {
+    let (window_start, window_end) = window;
+
+    // Clamp to the window and drop intervals outside it
+    let mut intervals: Vec<(NaiveTime, NaiveTime)> = busy
+        .iter()
+        .map(|&(start, end)| (start.max(window_start), end.min(window_end)))
+        .filter(|(start, end)| start < end)
+        .collect();
+    intervals.sort();
+
+    let mut slots = Vec::new();
+    let mut free_from = window_start;
+    for (start, end) in intervals {
+        if start > free_from {
+            slots.push((free_from, start));
+        }
+        free_from = free_from.max(end);
+    }
+    if free_from < window_end {
+        slots.push((free_from, window_end));
+    }
+
+    slots
+}
+
+/// Time left until a running event ends, for "ending in N min" in the day summary.
+/// None for all-day and cancelled events, and for events that haven't started or are over.
+/// `now` is the wall-clock time on the day the event is shown.
+pub fn time_until_end(event: &DisplayEvent, now: NaiveTime) -> Option<Duration> {
+    if event.all_day || event.status == EventStatus::Cancelled {
+        return None;
+    }
+    let start = event.start_time?;
+    let end = event.end_time?;
+    (start <= now && now < end).then(|| end - now)
+}
+
+/// Format free slots as "10:00–12:00, 15:00–17:00", in the locale's clock style
+pub fn format_free_slots(slots: &[(NaiveTime, NaiveTime)], locale: &LocalePreferences) -> String {
+    slots
+        .iter()
+        .map(|(start, end)| format!("{}–{}", locale.format_time(start), locale.format_time(end)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn t(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    fn nine_to_five() -> (NaiveTime, NaiveTime) {
+        working_window(WorkingHours { start: 9, end: 17 })
+    }
+
+    fn timed_event(start: NaiveTime, end: NaiveTime) -> DisplayEvent {
+        DisplayEvent {
+            calendar_id: "work".to_string(),
+            uid: "standup".to_string(),
+            summary: "Standup".to_string(),
+            start_time: Some(start),
+            end_time: Some(end),
+            ..DisplayEvent::test_default()
+        }
+    }
+
+    #[test]
+    fn test_time_until_end_of_running_event() {
+        let event = timed_event(t(9, 0), t(10, 0));
+        assert_eq!(time_until_end(&event, t(9, 0)), Some(Duration::hours(1)));
+        assert_eq!(time_until_end(&event, t(9, 48)), Some(Duration::minutes(12)));
+    }
+
+    #[test]
+    fn test_time_until_end_of_upcoming_and_past_events() {
+        let event = timed_event(t(9, 0), t(10, 0));
+        assert_eq!(time_until_end(&event, t(8, 59)), None);
+        assert_eq!(time_until_end(&event, t(10, 0)), None);
+        assert_eq!(time_until_end(&event, t(15, 0)), None);
+    }
+
+    #[test]
+    fn test_time_until_end_ignores_all_day_and_cancelled_events() {
+        let mut event = timed_event(t(9, 0), t(10, 0));
+        event.status = EventStatus::Cancelled;
+        assert_eq!(time_until_end(&event, t(9, 30)), None);
+
+        let mut event = timed_event(t(9, 0), t(10, 0));
+        event.all_day = true;
+        assert_eq!(time_until_end(&event, t(9, 30)), None);
+    }
+
+    #[test]
+    fn test_free_slots_empty_day() {
+        assert_eq!(free_slots(&[], nine_to_five()), vec![(t(9, 0), t(17, 0))]);
+    }
+
+    #[test]
+    fn test_free_slots_between_meetings() {
+        let busy = [(t(9, 0), t(10, 0)), (t(12, 0), t(15, 0))];
+        assert_eq!(
+            free_slots(&busy, nine_to_five()),
+            vec![(t(10, 0), t(12, 0)), (t(15, 0), t(17, 0))]
+        );
+        let locale = LocalePreferences {
+            use_24_hour: true,
+            first_day_of_week: chrono::Weekday::Mon,
+            weekend_days: vec![chrono::Weekday::Sat, chrono::Weekday::Sun],
+            date_format: crate::locale::DateFormat::DMY,
+            locale_string: "de_DE.UTF-8".to_string(),
+        };
+        assert_eq!(format_free_slots(&free_slots(&busy, nine_to_five()), &locale), "10:00–12:00, 15:00–17:00");
+    }
+
+    #[test]
+    fn test_free_slots_merges_overlapping_and_touching_busy_time() {
+        // Unsorted, overlapping and back-to-back meetings form one busy block
+        let busy = [(t(11, 0), t(12, 0)), (t(10, 0), t(11, 30)), (t(12, 0), t(13, 0))];
+        assert_eq!(
+            free_slots(&busy, nine_to_five()),
+            vec![(t(9, 0), t(10, 0)), (t(13, 0), t(17, 0))]
+        );
+    }
+
+    #[test]
+    fn test_free_slots_clamps_to_window() {
+        // Early and late events only eat into the edges of the working day
+        let busy = [(t(7, 0), t(9, 30)), (t(16, 0), t(20, 0)), (t(18, 0), t(19, 0))];
+        assert_eq!(free_slots(&busy, nine_to_five()), vec![(t(9, 30), t(16, 0))]);
+
+        // A day booked solid has no free slots
+        let busy = [(t(8, 0), t(18, 0))];
+        assert!(free_slots(&busy, nine_to_five()).is_empty());
+    }
+
+    #[test]
+    fn test_free_slots_follow_working_hours_setting() {
+        let busy = [(t(10, 0), t(11, 0))];
+        assert_eq!(
+            free_slots(&busy, working_window(WorkingHours { start: 7, end: 12 })),
+            vec![(t(7, 0), t(10, 0)), (t(11, 0), t(12, 0))]
+        );
+
+        // A working day until 24 runs to the end of the day
+        let (_, end) = working_window(WorkingHours { start: 20, end: 24 });
+        assert_eq!(end, END_OF_DAY);
+    }
+}
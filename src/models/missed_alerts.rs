@@ -0,0 +1,144 @@
+//! Missed alerts
+//!
+//! Alerts whose time passed while the app was closed, summarized on startup.
+
+use crate::caldav::{CalendarEvent, EventStatus};
+use chrono::{DateTime, Duration, Utc};
+
+/// How long after its start an event still counts as recent enough to report
+pub const MISSED_ALERT_GRACE_MINUTES: i64 = 60;
+
+/// An event whose alert fired while the app was closed
+#[derive(Debug, Clone, PartialEq)]
+pub struct MissedAlert {
+    pub uid: String,
+    pub summary: String,
+    pub start: DateTime<Utc>,
+}
+
+/// Select events with an alert time in `(last_run, now]` whose start is
+/// still in the future or at most `MISSED_ALERT_GRACE_MINUTES` ago.
+/// Each event is reported once even if both of its alerts were missed.
+/// Sorted by start time.
+pub fn missed_alerts(
+    events: &[CalendarEvent],
+    last_run: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> Vec<MissedAlert> {
+    let earliest_start = now - Duration::minutes(MISSED_ALERT_GRACE_MINUTES);
+
+    let mut missed: Vec<MissedAlert> = events
+        .iter()
+        .filter(|e| e.status != EventStatus::Cancelled && e.start >= earliest_start)
+        .filter(|e| {
+            std::iter::once(&e.alert)
+                .chain(e.alert_second.as_ref())
+                .filter_map(|alert| alert.alert_time(e.start))
+                .any(|alert_time| alert_time > last_run && alert_time <= now)
+        })
+        .map(|e| MissedAlert {
+            uid: e.uid.clone(),
+            summary: e.summary.clone(),
+            start: e.start,
+        })
+        .collect();
+
+    missed.sort_by_key(|m| m.start);
+    missed
+}
+
+/// Comma-separated summaries of the first `limit` missed alerts,
+/// with an ellipsis when more were missed
+pub fn format_missed_alerts(missed: &[MissedAlert], limit: usize) -> String {
+    let mut text = missed
+        .iter()
+        .take(limit)
+        .map(|m| m.summary.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+    if missed.len() > limit {
+        text.push_str(", …");
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::caldav::AlertTime;
+    use chrono::TimeZone;
+
+    fn event(uid: &str, start: DateTime<Utc>, alert: AlertTime) -> CalendarEvent {
+        CalendarEvent {
+            uid: uid.to_string(),
+            summary: uid.to_string(),
+            start,
+            end: start + Duration::hours(1),
+            alert,
+            ..CalendarEvent::test_default()
+        }
+    }
+
+    fn at(hour: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, 10, hour, min, 0).unwrap()
+    }
+
+    #[test]
+    fn test_selects_alerts_that_passed_while_closed() {
+        // Closed from 08:00 until 10:00
+        let last_run = at(8, 0);
+        let now = at(10, 0);
+        let events = vec![
+            // Alert at 09:45, event still ahead
+            event("upcoming", at(10, 15), AlertTime::ThirtyMinutes),
+            // Alert at 09:00, event started 30 minutes ago
+            event("recent", at(9, 30), AlertTime::ThirtyMinutes),
+            // Alert at 07:30 fired before the app was closed
+            event("before-close", at(8, 30), AlertTime::OneHour),
+            // Alert at 10:30 has not fired yet
+            event("not-yet", at(11, 0), AlertTime::ThirtyMinutes),
+            // Started too long ago to still be worth reporting
+            event("stale", at(8, 15), AlertTime::AtTime),
+            // No alert at all
+            event("silent", at(10, 30), AlertTime::None),
+        ];
+
+        let missed = missed_alerts(&events, last_run, now);
+        let uids: Vec<&str> = missed.iter().map(|m| m.uid.as_str()).collect();
+        assert_eq!(uids, vec!["recent", "upcoming"]);
+    }
+
+    #[test]
+    fn test_second_alert_and_cancelled_events() {
+        let last_run = at(8, 0);
+        let now = at(10, 0);
+
+        // First alert (a day before) fired long ago, second one while closed
+        let mut second = event("second", at(12, 0), AlertTime::OneDay);
+        second.alert_second = Some(AlertTime::TwoHours);
+
+        let mut cancelled = event("cancelled", at(10, 15), AlertTime::ThirtyMinutes);
+        cancelled.status = EventStatus::Cancelled;
+
+        // Both alerts missed, reported once
+        let mut both = event("both", at(10, 30), AlertTime::OneHour);
+        both.alert_second = Some(AlertTime::ThirtyMinutes);
+
+        let missed = missed_alerts(&[second, cancelled, both], last_run, now);
+        let uids: Vec<&str> = missed.iter().map(|m| m.uid.as_str()).collect();
+        assert_eq!(uids, vec!["both", "second"]);
+    }
+
+    #[test]
+    fn test_format_missed_alerts_truncates() {
+        let events: Vec<CalendarEvent> = ["a", "b", "c", "d"]
+            .iter()
+            .enumerate()
+            .map(|(i, uid)| event(uid, at(10, 10 + i as u32), AlertTime::FifteenMinutes))
+            .collect();
+        let missed = missed_alerts(&events, at(9, 0), at(10, 0));
+
+        assert_eq!(format_missed_alerts(&missed, 3), "a, b, c, …");
+        assert_eq!(format_missed_alerts(&missed[..2], 3), "a, b");
+    }
+}
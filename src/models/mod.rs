@@ -1,9 +1,19 @@
 mod calendar_state;
+mod calendar_stats;
+mod category_filter;
 mod week_state;
 mod day_state;
+mod missed_alerts;
 mod year_state;
+mod toast_state;
+mod undo_stack;
 
 pub use calendar_state::{CalendarState, CalendarDay};
+pub use calendar_stats::{CalendarPeriodCounts, CalendarStats, StatsPeriod};
+pub use category_filter::CategoryFilter;
 pub use week_state::WeekState;
-pub use day_state::DayState;
+pub use day_state::{busy_intervals, format_free_slots, free_slots, time_until_end, working_window, DayState};
+pub use missed_alerts::{format_missed_alerts, missed_alerts, MissedAlert, MISSED_ALERT_GRACE_MINUTES};
 pub use year_state::YearState;
+pub use toast_state::{Toast, ToastState, UndoAction};
+pub use undo_stack::{EventChange, UndoStack};
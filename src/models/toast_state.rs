@@ -0,0 +1,126 @@
+use std::time::{Duration, Instant};
+
+use crate::caldav::CalendarEvent;
+
+/// How long a toast stays visible before auto-dismissing
+pub const TOAST_DURATION: Duration = Duration::from_secs(5);
+
+/// Action that can be reverted from a toast's "Undo" button
+#[derive(Debug, Clone)]
+pub enum UndoAction {
+    /// Re-add a deleted event to its calendar (calendar_id, event)
+    RestoreDeletedEvent(String, Box<CalendarEvent>),
+    /// Restore several deleted events or occurrences, each to its calendar (calendar_id, event before the delete)
+    RestoreDeletedEvents(Vec<(String, CalendarEvent)>),
+}
+
+/// A transient notification shown at the bottom of the window
+#[derive(Debug, Clone)]
+pub struct Toast {
+    /// Localized message text
+    pub message: String,
+    /// Action performed when "Undo" is clicked (None = no undo button)
+    pub undo: Option<UndoAction>,
+    /// When the toast was shown (for auto-dismiss)
+    pub shown_at: Instant,
+}
+
+/// Toast notification state - at most one toast is visible at a time
+#[derive(Debug, Clone, Default)]
+pub struct ToastState {
+    current: Option<Toast>,
+}
+
+impl ToastState {
+    /// Create an empty toast state
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show a toast, replacing any toast that is currently visible
+    pub fn show(&mut self, message: String, undo: Option<UndoAction>, now: Instant) {
+        self.current = Some(Toast { message, undo, shown_at: now });
+    }
+
+    /// Get the currently visible toast
+    pub fn current(&self) -> Option<&Toast> {
+        self.current.as_ref()
+    }
+
+    /// Whether a toast is currently visible
+    pub fn is_visible(&self) -> bool {
+        self.current.is_some()
+    }
+
+    /// Dismiss the current toast
+    pub fn dismiss(&mut self) {
+        self.current = None;
+    }
+
+    /// Dismiss the toast if it has been visible for at least `TOAST_DURATION`.
+    /// Returns true if the toast was dismissed.
+    pub fn expire(&mut self, now: Instant) -> bool {
+        let expired = self
+            .current
+            .as_ref()
+            .is_some_and(|toast| now.duration_since(toast.shown_at) >= TOAST_DURATION);
+        if expired {
+            self.current = None;
+        }
+        expired
+    }
+
+    /// Dismiss the toast and take its undo action (if any)
+    pub fn take_undo(&mut self) -> Option<UndoAction> {
+        self.current.take().and_then(|toast| toast.undo)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deleted_event() -> UndoAction {
+        let now = chrono::Utc::now();
+        UndoAction::RestoreDeletedEvent(
+            "local".to_string(),
+            Box::new(CalendarEvent {
+                uid: "deleted-1".to_string(),
+                summary: "Deleted".to_string(),
+                start: now,
+                end: now + chrono::Duration::hours(1),
+                ..CalendarEvent::test_default()
+            }),
+        )
+    }
+
+    #[test]
+    fn test_toast_lifecycle() {
+        let mut state = ToastState::new();
+        assert!(!state.is_visible());
+
+        // Shown on delete
+        let shown_at = Instant::now();
+        state.show("Event deleted".to_string(), Some(deleted_event()), shown_at);
+        assert!(state.is_visible());
+        assert!(state.current().unwrap().undo.is_some());
+
+        // Still visible before the timeout
+        assert!(!state.expire(shown_at + Duration::from_secs(1)));
+        assert!(state.is_visible());
+
+        // Dismissed once the timeout has elapsed
+        assert!(state.expire(shown_at + TOAST_DURATION));
+        assert!(!state.is_visible());
+        assert!(state.take_undo().is_none());
+    }
+
+    #[test]
+    fn test_take_undo_dismisses_toast() {
+        let mut state = ToastState::new();
+        state.show("Event deleted".to_string(), Some(deleted_event()), Instant::now());
+
+        assert!(matches!(state.take_undo(), Some(UndoAction::RestoreDeletedEvent(ref cal, _)) if cal == "local"));
+        assert!(!state.is_visible());
+    }
+}
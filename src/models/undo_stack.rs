@@ -0,0 +1,197 @@
+use crate::caldav::CalendarEvent;
+
+/// Maximum number of actions kept for undo; the oldest are dropped first
+pub const UNDO_STACK_DEPTH: usize = 50;
+
+/// An event as it is (or was) stored in a calendar
+#[derive(Debug, Clone)]
+pub struct EventSnapshot {
+    pub calendar_id: String,
+    pub event: CalendarEvent,
+}
+
+/// One change to one event.
+/// `before` is None for a created event, `after` is None for a deleted event.
+#[derive(Debug, Clone)]
+pub struct EventChange {
+    pub before: Option<EventSnapshot>,
+    pub after: Option<EventSnapshot>,
+}
+
+impl EventChange {
+    /// An event added to a calendar
+    pub fn created(calendar_id: &str, event: CalendarEvent) -> Self {
+        Self {
+            before: None,
+            after: Some(EventSnapshot { calendar_id: calendar_id.to_string(), event }),
+        }
+    }
+
+    /// An event changed in place (edited or moved)
+    pub fn edited(calendar_id: &str, before: CalendarEvent, after: CalendarEvent) -> Self {
+        Self {
+            before: Some(EventSnapshot { calendar_id: calendar_id.to_string(), event: before }),
+            after: Some(EventSnapshot { calendar_id: calendar_id.to_string(), event: after }),
+        }
+    }
+
+    /// An event removed from a calendar, kept whole (invitees, attachments, alerts) for restoring
+    pub fn deleted(calendar_id: &str, event: CalendarEvent) -> Self {
+        Self {
+            before: Some(EventSnapshot { calendar_id: calendar_id.to_string(), event }),
+            after: None,
+        }
+    }
+
+    /// The change that reverts this one
+    pub fn inverted(&self) -> Self {
+        Self {
+            before: self.after.clone(),
+            after: self.before.clone(),
+        }
+    }
+}
+
+/// Undo/redo history of event changes.
+/// Each entry holds the changes of one user action (a batch delete is one entry).
+#[derive(Debug, Clone, Default)]
+pub struct UndoStack {
+    undo: Vec<Vec<EventChange>>,
+    redo: Vec<Vec<EventChange>>,
+}
+
+impl UndoStack {
+    /// Create an empty history
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the changes of a new action. Clears the redo history.
+    pub fn push(&mut self, changes: Vec<EventChange>) {
+        if changes.is_empty() {
+            return;
+        }
+        self.redo.clear();
+        self.undo.push(changes);
+        if self.undo.len() > UNDO_STACK_DEPTH {
+            self.undo.remove(0);
+        }
+    }
+
+    /// Take the newest action for undoing and return the changes that revert it,
+    /// newest change first. The action moves to the redo history.
+    pub fn undo(&mut self) -> Option<Vec<EventChange>> {
+        let changes = self.undo.pop()?;
+        let reverts = changes.iter().rev().map(EventChange::inverted).collect();
+        self.redo.push(changes);
+        Some(reverts)
+    }
+
+    /// Take the newest undone action and return its changes for applying again.
+    /// The action moves back to the undo history.
+    pub fn redo(&mut self) -> Option<Vec<EventChange>> {
+        let changes = self.redo.pop()?;
+        self.undo.push(changes.clone());
+        Some(changes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::caldav::AlertTime;
+
+    fn event(uid: &str, summary: &str) -> CalendarEvent {
+        let start = chrono::Utc::now();
+        CalendarEvent {
+            uid: uid.to_string(),
+            summary: summary.to_string(),
+            start,
+            end: start + chrono::Duration::hours(1),
+            ..CalendarEvent::test_default()
+        }
+    }
+
+    fn summaries(change: &EventChange) -> (Option<&str>, Option<&str>) {
+        (
+            change.before.as_ref().map(|s| s.event.summary.as_str()),
+            change.after.as_ref().map(|s| s.event.summary.as_str()),
+        )
+    }
+
+    #[test]
+    fn test_undo_reverts_and_redo_reapplies() {
+        let mut stack = UndoStack::new();
+        stack.push(vec![EventChange::created("local", event("a", "Created"))]);
+        stack.push(vec![EventChange::edited("local", event("a", "Before"), event("a", "After"))]);
+
+        let reverts = stack.undo().unwrap();
+        assert_eq!(summaries(&reverts[0]), (Some("After"), Some("Before")));
+
+        let reapply = stack.redo().unwrap();
+        assert_eq!(summaries(&reapply[0]), (Some("Before"), Some("After")));
+        assert!(stack.redo().is_none());
+
+        // Undoing both: the edit first, then the creation becomes a delete
+        stack.undo();
+        let reverts = stack.undo().unwrap();
+        assert_eq!(summaries(&reverts[0]), (Some("Created"), None));
+        assert!(stack.undo().is_none());
+    }
+
+    #[test]
+    fn test_undo_delete_restores_whole_event() {
+        let mut deleted = event("a", "Review");
+        deleted.alert = AlertTime::FifteenMinutes;
+        deleted.invitees = vec!["ana@example.com".to_string()];
+        deleted.attachments = vec!["/home/ana/agenda.pdf".to_string()];
+
+        let mut stack = UndoStack::new();
+        stack.push(vec![EventChange::deleted("work", deleted)]);
+
+        let reverts = stack.undo().unwrap();
+        let restored = reverts[0].after.as_ref().unwrap();
+        assert_eq!(restored.calendar_id, "work");
+        assert_eq!(restored.event.alert, AlertTime::FifteenMinutes);
+        assert_eq!(restored.event.invitees, vec!["ana@example.com".to_string()]);
+        assert_eq!(restored.event.attachments, vec!["/home/ana/agenda.pdf".to_string()]);
+        assert!(reverts[0].before.is_none());
+    }
+
+    #[test]
+    fn test_batch_is_reverted_newest_first() {
+        let mut stack = UndoStack::new();
+        stack.push(vec![
+            EventChange::deleted("local", event("a", "First")),
+            EventChange::deleted("local", event("b", "Second")),
+        ]);
+
+        let reverts = stack.undo().unwrap();
+        assert_eq!(summaries(&reverts[0]), (None, Some("Second")));
+        assert_eq!(summaries(&reverts[1]), (None, Some("First")));
+    }
+
+    #[test]
+    fn test_new_action_clears_redo_and_depth_is_capped() {
+        let mut stack = UndoStack::new();
+        stack.push(vec![]);
+        assert!(stack.undo().is_none());
+
+        for index in 0..UNDO_STACK_DEPTH + 5 {
+            stack.push(vec![EventChange::created("local", event(&index.to_string(), &index.to_string()))]);
+        }
+        stack.undo();
+        stack.push(vec![EventChange::created("local", event("new", "New"))]);
+        assert!(stack.redo().is_none());
+
+        let mut count = 0;
+        let mut oldest = None;
+        while let Some(reverts) = stack.undo() {
+            oldest = reverts[0].before.as_ref().map(|s| s.event.summary.clone());
+            count += 1;
+        }
+        assert_eq!(count, UNDO_STACK_DEPTH);
+        // The first five actions were dropped
+        assert_eq!(oldest.as_deref(), Some("5"));
+    }
+}
@@ -6,15 +6,18 @@ use crate::locale::LocalePreferences;
 pub struct WeekState {
     pub year: i32,
     pub week_number: u32,
-    pub days: Vec<NaiveDate>, // 7 days in the week starting from first_day_of_week
+    pub days: Vec<NaiveDate>, // Visible days in the week starting from first_day_of_week
     pub week_range_text: String, // Pre-formatted week range with locale-aware format
     pub today: NaiveDate,
     pub first_day_of_week: Weekday,
+    /// Whether weekend days are included in `days`
+    pub show_weekends: bool,
 }
 
 impl WeekState {
-    /// Create a new WeekState for the week containing the given date
-    pub fn new(date: NaiveDate, first_day_of_week: Weekday, locale: &LocalePreferences) -> Self {
+    /// Create a new WeekState for the week containing the given date.
+    /// When `show_weekends` is false only weekdays are included.
+    pub fn new(date: NaiveDate, first_day_of_week: Weekday, show_weekends: bool, locale: &LocalePreferences) -> Self {
         let today = chrono::Local::now().date_naive();
 
         // Find the first day of the week containing the date
@@ -22,19 +25,35 @@ impl WeekState {
         let days_since_first = days_between_weekdays(first_day_of_week, weekday);
         let first_day = date - chrono::Duration::days(days_since_first as i64);
 
-        // Build the 7 days of the week
-        let mut days = Vec::with_capacity(7);
-        for i in 0..7 {
-            days.push(first_day + chrono::Duration::days(i));
-        }
+        let days = visible_week_days(first_day, show_weekends, locale);
+        Self::build(date, days, today, first_day_of_week, show_weekends, locale)
+    }
 
+    /// Create a WeekState showing exactly `days`, which may hold any number of days.
+    /// Year and week number follow the first day (today when `days` is empty).
+    #[cfg(test)]
+    pub fn from_days(days: Vec<NaiveDate>, first_day_of_week: Weekday, show_weekends: bool, locale: &LocalePreferences) -> Self {
+        let today = chrono::Local::now().date_naive();
+        let date = days.first().copied().unwrap_or(today);
+        Self::build(date, days, today, first_day_of_week, show_weekends, locale)
+    }
+
+    fn build(
+        date: NaiveDate,
+        days: Vec<NaiveDate>,
+        today: NaiveDate,
+        first_day_of_week: Weekday,
+        show_weekends: bool,
+        locale: &LocalePreferences,
+    ) -> Self {
         let year = date.year();
         let week_number = date.iso_week().week();
 
-        // Format week range text using locale-aware formatting
-        let first_day = &days[0];
-        let last_day = &days[6];
-        let week_range_text = locale.format_week_range(first_day, last_day, week_number);
+        // Format week range text using locale-aware formatting (empty when no day is visible)
+        let week_range_text = match (days.first(), days.last()) {
+            (Some(first_day), Some(last_day)) => locale.format_week_range(first_day, last_day, week_number),
+            _ => String::new(),
+        };
 
         WeekState {
             year,
@@ -43,30 +62,36 @@ impl WeekState {
             week_range_text,
             today,
             first_day_of_week,
+            show_weekends,
         }
     }
 
+    /// First visible day, or today when no day is visible
+    fn anchor_day(&self) -> NaiveDate {
+        self.days.first().copied().unwrap_or(self.today)
+    }
+
     /// Create WeekState for current week with Monday as first day
     #[allow(dead_code)] // Reserved for direct week state creation
     pub fn current(locale: &LocalePreferences) -> Self {
-        Self::new(chrono::Local::now().date_naive(), Weekday::Mon, locale)
+        Self::new(chrono::Local::now().date_naive(), Weekday::Mon, true, locale)
     }
 
     /// Create WeekState for current week with custom first day
-    pub fn current_with_first_day(first_day_of_week: Weekday, locale: &LocalePreferences) -> Self {
-        Self::new(chrono::Local::now().date_naive(), first_day_of_week, locale)
+    pub fn current_with_first_day(first_day_of_week: Weekday, show_weekends: bool, locale: &LocalePreferences) -> Self {
+        Self::new(chrono::Local::now().date_naive(), first_day_of_week, show_weekends, locale)
     }
 
     /// Navigate to previous week
     #[allow(dead_code)] // Navigation used by view transitions
     pub fn previous(&self, locale: &LocalePreferences) -> Self {
-        Self::new(self.days[0] - chrono::Duration::days(7), self.first_day_of_week, locale)
+        Self::new(self.anchor_day() - chrono::Duration::days(7), self.first_day_of_week, self.show_weekends, locale)
     }
 
     /// Navigate to next week
     #[allow(dead_code)] // Navigation used by view transitions
     pub fn next(&self, locale: &LocalePreferences) -> Self {
-        Self::new(self.days[0] + chrono::Duration::days(7), self.first_day_of_week, locale)
+        Self::new(self.anchor_day() + chrono::Duration::days(7), self.first_day_of_week, self.show_weekends, locale)
     }
 
     /// Check if a given date is today
@@ -81,6 +106,16 @@ impl WeekState {
     }
 }
 
+/// Build the visible days of the week starting at `first_day`.
+/// All 7 days are returned when `show_weekends` is true; otherwise the
+/// locale's weekend days are skipped.
+fn visible_week_days(first_day: NaiveDate, show_weekends: bool, locale: &LocalePreferences) -> Vec<NaiveDate> {
+    (0..7)
+        .map(|i| first_day + chrono::Duration::days(i))
+        .filter(|day| show_weekends || !locale.is_weekend(day.weekday()))
+        .collect()
+}
+
 /// Calculate the number of days between two weekdays
 /// Returns how many days forward from 'start' to reach 'end'
 fn days_between_weekdays(start: Weekday, end: Weekday) -> u32 {
@@ -93,3 +128,86 @@ fn days_between_weekdays(start: Weekday, end: Weekday) -> u32 {
         7 - (start_num - end_num)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::locale::DateFormat;
+
+    fn locale() -> LocalePreferences {
+        LocalePreferences {
+            use_24_hour: true,
+            first_day_of_week: Weekday::Mon,
+            weekend_days: vec![Weekday::Sat, Weekday::Sun],
+            date_format: DateFormat::YMD,
+            locale_string: "en_US.UTF-8".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_visible_week_days_with_weekends() {
+        let monday = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+        let days = visible_week_days(monday, true, &locale());
+        assert_eq!(days.len(), 7);
+        assert_eq!(days[6], NaiveDate::from_ymd_opt(2025, 3, 16).unwrap());
+    }
+
+    #[test]
+    fn test_visible_week_days_weekdays_only() {
+        // Week starting on Sunday: the leading Sunday and trailing Saturday are dropped
+        let sunday = NaiveDate::from_ymd_opt(2025, 3, 9).unwrap();
+        let days = visible_week_days(sunday, false, &locale());
+        let expected: Vec<NaiveDate> = (10..=14)
+            .map(|d| NaiveDate::from_ymd_opt(2025, 3, d).unwrap())
+            .collect();
+        assert_eq!(days, expected);
+
+        let state = WeekState::new(NaiveDate::from_ymd_opt(2025, 3, 12).unwrap(), Weekday::Sun, false, &locale());
+        assert_eq!(state.days, expected);
+    }
+
+    #[test]
+    fn test_monday_start_with_friday_saturday_weekend() {
+        let locale = LocalePreferences {
+            weekend_days: vec![Weekday::Fri, Weekday::Sat],
+            ..locale()
+        };
+
+        // Wed 12 March 2025 - the week runs Mon 10 through Sun 16
+        let date = NaiveDate::from_ymd_opt(2025, 3, 12).unwrap();
+        let state = WeekState::new(date, Weekday::Mon, true, &locale);
+        assert_eq!(state.days.first(), NaiveDate::from_ymd_opt(2025, 3, 10).as_ref());
+        assert_eq!(state.days.last(), NaiveDate::from_ymd_opt(2025, 3, 16).as_ref());
+
+        let weekend: Vec<u32> = state.days.iter()
+            .filter(|d| locale.is_weekend(d.weekday()))
+            .map(|d| d.day())
+            .collect();
+        assert_eq!(weekend, vec![14, 15]);
+
+        // Hiding weekends drops Friday and Saturday but keeps Sunday
+        let state = WeekState::new(date, Weekday::Mon, false, &locale);
+        let days: Vec<u32> = state.days.iter().map(|d| d.day()).collect();
+        assert_eq!(days, vec![10, 11, 12, 13, 16]);
+    }
+
+    #[test]
+    fn test_from_days_accepts_any_length() {
+        let locale = locale();
+        let monday = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+        for count in [1, 3, 5, 7] {
+            let days: Vec<NaiveDate> = (0..count).map(|i| monday + chrono::Duration::days(i)).collect();
+            let state = WeekState::from_days(days.clone(), Weekday::Mon, true, &locale);
+            assert_eq!(state.days, days);
+            assert_eq!(state.week_number, 11);
+            assert!(!state.week_range_text.is_empty());
+            assert_eq!(state.next(&locale).days.first(), NaiveDate::from_ymd_opt(2025, 3, 17).as_ref());
+        }
+
+        // No visible days: no range text, navigation starts from today
+        let state = WeekState::from_days(vec![], Weekday::Mon, true, &locale);
+        assert!(state.week_range_text.is_empty());
+        assert!(!state.contains_today());
+        assert_eq!(state.previous(&locale).days.len(), 7);
+    }
+}
@@ -1,5 +1,5 @@
 use crate::models::CalendarState;
-use chrono::Datelike;
+use chrono::{Datelike, Weekday};
 
 /// Cached year state for year view
 #[derive(Debug, Clone, PartialEq)]
@@ -11,14 +11,14 @@ pub struct YearState {
 }
 
 impl YearState {
-    /// Create a new YearState for the given year
-    pub fn new(year: i32) -> Self {
+    /// Create a new YearState for the given year, with month grids starting on `first_day`
+    pub fn new(year: i32, first_day: Weekday) -> Self {
         let today = chrono::Local::now();
         let today_tuple = (today.year(), today.month(), today.day());
 
         // Generate CalendarState for all 12 months
         let months = (1..=12)
-            .map(|month| CalendarState::new(year, month))
+            .map(|month| CalendarState::new(year, month, first_day))
             .collect();
 
         YearState {
@@ -30,20 +30,25 @@ impl YearState {
     }
 
     /// Create YearState for current year
-    pub fn current() -> Self {
-        Self::new(chrono::Local::now().year())
+    pub fn current(first_day: Weekday) -> Self {
+        Self::new(chrono::Local::now().year(), first_day)
+    }
+
+    /// Weekday the month grids start on
+    fn first_day(&self) -> Weekday {
+        self.months.first().map_or(Weekday::Mon, |month| month.first_day)
     }
 
     /// Navigate to previous year
     #[allow(dead_code)] // Navigation used by view transitions
     pub fn previous(&self) -> Self {
-        Self::new(self.year - 1)
+        Self::new(self.year - 1, self.first_day())
     }
 
     /// Navigate to next year
     #[allow(dead_code)] // Navigation used by view transitions
     pub fn next(&self) -> Self {
-        Self::new(self.year + 1)
+        Self::new(self.year + 1, self.first_day())
     }
 
     /// Check if this year is the current year
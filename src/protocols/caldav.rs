@@ -8,6 +8,7 @@
 
 use crate::caldav::{CalDavClient, CalendarEvent};
 use super::{Protocol, ProtocolResult};
+use std::collections::HashMap;
 
 /// CalDAV protocol for remote calendar servers.
 #[allow(dead_code)] // Foundation for future CalDAV support
@@ -17,6 +18,8 @@ pub struct CalDavProtocol {
     client: CalDavClient,
     /// Cached events (to avoid repeated network calls)
     cached_events: Vec<CalendarEvent>,
+    /// Server href and ETag of each cached event, keyed by UID
+    resources: HashMap<String, (String, Option<String>)>,
 }
 
 impl CalDavProtocol {
@@ -27,6 +30,7 @@ impl CalDavProtocol {
         Ok(CalDavProtocol {
             client,
             cached_events: Vec::new(),
+            resources: HashMap::new(),
         })
     }
 
@@ -79,16 +83,24 @@ impl Protocol for CalDavProtocol {
 
     fn add_event(&mut self, _calendar_id: &str, event: &CalendarEvent) -> ProtocolResult<()> {
         // Send to remote server
-        self.client.create_event(event)?;
+        let resource = self.client.create_event(event)?;
         // Update local cache
+        self.resources.insert(event.uid.clone(), resource);
         self.cached_events.push(event.clone());
         Ok(())
     }
 
     fn update_event(&mut self, _calendar_id: &str, event: &CalendarEvent) -> ProtocolResult<()> {
-        // Update on remote server
-        self.client.update_event(event)?;
+        // Update on remote server, guarded by the ETag from the last sync
+        let resource = match self.resources.get(&event.uid) {
+            Some((href, etag)) => {
+                let etag = self.client.update_event(event, href, etag.as_deref())?;
+                (href.clone(), etag)
+            }
+            None => self.client.create_event(event)?,
+        };
         // Update local cache
+        self.resources.insert(event.uid.clone(), resource);
         if let Some(pos) = self.cached_events.iter().position(|e| e.uid == event.uid) {
             self.cached_events[pos] = event.clone();
         }
@@ -97,7 +109,9 @@ impl Protocol for CalDavProtocol {
 
     fn delete_event(&mut self, _calendar_id: &str, uid: &str) -> ProtocolResult<bool> {
         // Delete from remote server
-        self.client.delete_event(uid)?;
+        if let Some((href, etag)) = self.resources.remove(uid) {
+            self.client.delete_event(&href, etag.as_deref())?;
+        }
         // Remove from local cache
         if let Some(pos) = self.cached_events.iter().position(|e| e.uid == uid) {
             self.cached_events.remove(pos);
@@ -109,7 +123,12 @@ impl Protocol for CalDavProtocol {
 
     fn sync(&mut self, _calendar_id: &str) -> ProtocolResult<()> {
         // Fetch fresh data from server
-        self.cached_events = self.client.fetch_events()?;
+        let resources = self.client.fetch_resources()?;
+        self.resources = resources
+            .iter()
+            .flat_map(|r| r.events.iter().map(|e| (e.uid.clone(), (r.href.clone(), r.etag.clone()))))
+            .collect();
+        self.cached_events = resources.into_iter().flat_map(|r| r.events).collect();
         Ok(())
     }
 
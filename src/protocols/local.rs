@@ -63,7 +63,6 @@ impl Protocol for LocalProtocol {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::caldav::{AlertTime, RepeatFrequency, TravelTime};
     use chrono::{TimeZone, Utc};
 
     #[test]
@@ -80,20 +79,9 @@ mod tests {
         let event = CalendarEvent {
             uid: "protocol-test-1".to_string(),
             summary: "Protocol Test".to_string(),
-            location: None,
-            all_day: false,
             start: Utc.with_ymd_and_hms(2025, 11, 30, 10, 0, 0).unwrap(),
             end: Utc.with_ymd_and_hms(2025, 11, 30, 11, 0, 0).unwrap(),
-            travel_time: TravelTime::None,
-            repeat: RepeatFrequency::Never,
-            repeat_until: None,
-            exception_dates: vec![],
-            invitees: vec![],
-            alert: AlertTime::None,
-            alert_second: None,
-            attachments: vec![],
-            url: None,
-            notes: None,
+            ..CalendarEvent::test_default()
         };
 
         // Add event
@@ -20,6 +20,15 @@
 //! 1. Create a new file in `src/protocols/` (e.g., `google.rs`)
 //! 2. Implement the `Protocol` trait
 //! 3. Re-export from this module
+//!
+//! # Adding a Custom Calendar Source
+//!
+//! Backends that don't fit the protocol layer (e.g. a read-only ICS-over-HTTP
+//! feed) implement `CalendarSource` instead and register a factory with
+//! `CalendarManager::register_source_factory` before loading the saved
+//! calendars with `CalendarManager::load_config`. Calendars whose saved
+//! `calendar_type` matches the registered name are then built by that factory.
+//! See `CalendarSource` for the trait contract.
 
 mod local;
 mod caldav;
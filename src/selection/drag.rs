@@ -3,6 +3,9 @@
 use chrono::{NaiveDate, NaiveTime};
 use log::debug;
 
+/// Cursor travel (in pixels) below which a press+release on an event is a click, not a move
+pub const DRAG_THRESHOLD_PX: f32 = 4.0;
+
 /// Display information for the drag preview.
 /// Separated from EventDragState to maintain clean architecture.
 #[derive(Debug, Clone, Default)]
@@ -13,6 +16,8 @@ pub struct DragPreviewInfo {
     pub color: Option<String>,
     /// Current cursor position for rendering drag preview (x, y)
     pub cursor_position: Option<(f32, f32)>,
+    /// Cursor position where the event was pressed (x, y)
+    pub start_position: Option<(f32, f32)>,
 }
 
 impl DragPreviewInfo {
@@ -28,16 +33,30 @@ impl DragPreviewInfo {
         self.color = Some(color);
     }
 
+    /// Record where the event was pressed; the drag threshold is measured from here
+    pub fn set_origin(&mut self, x: f32, y: f32) {
+        self.start_position = Some((x, y));
+    }
+
     /// Update cursor position
     pub fn update_cursor(&mut self, x: f32, y: f32) {
         self.cursor_position = Some((x, y));
     }
 
+    /// Whether the cursor travelled further than `threshold` pixels from where it was pressed
+    pub fn moved_beyond(&self, threshold: f32) -> bool {
+        match (self.start_position, self.cursor_position) {
+            (Some((start_x, start_y)), Some((x, y))) => (x - start_x).hypot(y - start_y) > threshold,
+            _ => false,
+        }
+    }
+
     /// Reset the preview info
     pub fn reset(&mut self) {
         self.summary = None;
         self.color = None;
         self.cursor_position = None;
+        self.start_position = None;
     }
 }
 
@@ -115,6 +134,13 @@ impl EventDragState {
         self.preview.set_event_info(summary, color);
     }
 
+    /// Record the cursor position the drag was pressed at
+    pub fn set_origin(&mut self, x: f32, y: f32) {
+        if self.is_active {
+            self.preview.set_origin(x, y);
+        }
+    }
+
     /// Update cursor position during drag
     pub fn update_cursor(&mut self, x: f32, y: f32) {
         if self.is_active {
@@ -245,4 +271,19 @@ impl EventDragState {
     pub fn cursor_position(&self) -> Option<(f32, f32)> {
         self.preview.cursor_position
     }
+
+    /// Whether the cursor moved far enough for the press to count as a drag
+    pub fn is_past_drag_threshold(&self) -> bool {
+        self.preview.moved_beyond(DRAG_THRESHOLD_PX)
+    }
+
+    /// Summary, color and cursor position for the floating preview chip.
+    /// Shown only once the cursor has moved past the drag threshold, so
+    /// clicking an event doesn't flash a chip.
+    pub fn floating_preview(&self) -> Option<(&str, &str, (f32, f32))> {
+        if !self.is_active || !self.is_past_drag_threshold() {
+            return None;
+        }
+        Some((self.event_summary()?, self.event_color()?, self.cursor_position()?))
+    }
 }
@@ -3,6 +3,7 @@
 //! This module provides state tracking for:
 //! - Drag selection across day/time cells for creating new events
 //! - Event drag-and-drop for moving existing events
+//! - Event edge dragging for changing an event's start or end time
 //!
 //! The architecture supports both date-only (month view) and date+time (week/day views) operations.
 //!
@@ -12,6 +13,7 @@
 //! - [`range`] - Normalized selection range (start <= end)
 //! - [`state`] - Selection state for tracking drag selection
 //! - [`drag`] - Event drag state for moving events
+//! - [`resize`] - Event resize state for dragging an event's top or bottom edge
 //!
 //! # Usage Flow for Selection
 //!
@@ -46,6 +48,7 @@
 mod drag;
 mod point;
 mod range;
+mod resize;
 mod state;
 
 // Re-export public types
@@ -55,12 +58,15 @@ pub use drag::{DragPreviewInfo, DragTarget, EventDragState};
 pub use point::SelectionPoint;
 #[allow(unused_imports)] // Part of selection API, used by tests
 pub use range::SelectionRange;
-pub use state::SelectionState;
+pub use resize::{EventResizeState, ResizeEdge};
+pub use state::{SelectionState, TIME_SELECTION_SNAP_MINUTES};
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use chrono::{NaiveDate, NaiveTime};
+    use super::drag::DRAG_THRESHOLD_PX;
+    use super::resize::resized_times;
 
     #[test]
     fn test_selection_point_date_only() {
@@ -108,6 +114,57 @@ mod tests {
         assert_eq!(range.start_time(), Some(time));
     }
 
+    #[test]
+    fn test_contains_time_highlights_quarter_hour_slots() {
+        let mut state = SelectionState::new();
+        let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        let time = |hour, minute| NaiveTime::from_hms_opt(hour, minute, 0).unwrap();
+
+        // Dragging from 9:15 to 10:30 covers the slots 9:15 through 10:30
+        state.start_with_time(date, time(9, 15));
+        state.update_with_time(date, time(10, 30));
+
+        assert!(!state.contains_time(date, time(9, 0)));
+        assert!(state.contains_time(date, time(9, 15)));
+        assert!(state.contains_time(date, time(9, 45)));
+        assert!(state.contains_time(date, time(10, 30)));
+        assert!(!state.contains_time(date, time(10, 45)));
+        assert!(!state.contains_time(date.succ_opt().unwrap(), time(9, 30)));
+    }
+
+    #[test]
+    fn test_time_bounds_cross_day() {
+        let mut state = SelectionState::new();
+        let monday = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        let tuesday = NaiveDate::from_ymd_opt(2024, 1, 16).unwrap();
+        let two_pm = NaiveTime::from_hms_opt(14, 0, 0).unwrap();
+        let four_pm = NaiveTime::from_hms_opt(16, 0, 0).unwrap();
+
+        // Dragging backwards (Tuesday 16:00 -> Monday 14:00) still spans forward
+        state.start_with_time(tuesday, four_pm);
+        state.update_with_time(monday, two_pm);
+        let range = state.end().unwrap();
+
+        assert_eq!(range.time_bounds(), Some((monday.and_time(two_pm), tuesday.and_time(four_pm))));
+
+        // An earlier time on a later day stays a valid cross-day range
+        let range = SelectionRange::new(
+            SelectionPoint::with_time(monday, four_pm),
+            SelectionPoint::with_time(tuesday, two_pm),
+        );
+        assert_eq!(range.time_bounds(), Some((monday.and_time(four_pm), tuesday.and_time(two_pm))));
+    }
+
+    #[test]
+    fn test_time_bounds_single_cell() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        let time = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+        let point = SelectionPoint::with_time(date, time);
+
+        assert_eq!(SelectionRange::new(point, point).time_bounds(), None);
+        assert_eq!(SelectionRange::from_dates(date, date).time_bounds(), None);
+    }
+
     #[test]
     fn test_selection_state_update() {
         let mut state = SelectionState::new();
@@ -223,7 +280,7 @@ mod tests {
         let mut state = EventDragState::new();
         let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
 
-        state.start("event-123".to_string(), date, "Test Event".to_string(), "#0000ff".to_string());
+        state.start("work".to_string(), "event-123".to_string(), date, "Test Event".to_string(), "#0000ff".to_string());
 
         assert!(state.is_active);
         assert_eq!(state.event_uid, Some("event-123".to_string()));
@@ -239,7 +296,7 @@ mod tests {
         let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
         let time = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
 
-        state.start_with_time("event-123".to_string(), date, time, "Test Event".to_string(), "#0000ff".to_string());
+        state.start_with_time("work".to_string(), "event-123".to_string(), date, time, "Test Event".to_string(), "#0000ff".to_string());
 
         assert!(state.is_active);
         assert_eq!(state.original_time, Some(time));
@@ -252,7 +309,7 @@ mod tests {
         let original = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
         let target = NaiveDate::from_ymd_opt(2024, 1, 18).unwrap();
 
-        state.start("event-123".to_string(), original, "Test Event".to_string(), "#0000ff".to_string());
+        state.start("work".to_string(), "event-123".to_string(), original, "Test Event".to_string(), "#0000ff".to_string());
         state.update(target);
 
         assert!(state.is_active);
@@ -266,13 +323,14 @@ mod tests {
         let original = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
         let target = NaiveDate::from_ymd_opt(2024, 1, 18).unwrap();
 
-        state.start("event-123".to_string(), original, "Test Event".to_string(), "#0000ff".to_string());
+        state.start("work".to_string(), "event-123".to_string(), original, "Test Event".to_string(), "#0000ff".to_string());
         state.update(target);
         let result = state.end();
 
         assert!(!state.is_active);
         assert!(result.is_some());
-        let (uid, orig, tgt) = result.unwrap();
+        let (calendar_id, uid, orig, tgt) = result.unwrap();
+        assert_eq!(calendar_id, "work");
         assert_eq!(uid, "event-123");
         assert_eq!(orig, original);
         assert_eq!(tgt, target);
@@ -283,7 +341,7 @@ mod tests {
         let mut state = EventDragState::new();
         let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
 
-        state.start("event-123".to_string(), date, "Test Event".to_string(), "#0000ff".to_string());
+        state.start("work".to_string(), "event-123".to_string(), date, "Test Event".to_string(), "#0000ff".to_string());
         // Don't update - target stays same as original
         let result = state.end();
 
@@ -298,7 +356,7 @@ mod tests {
         let original_time = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
         let target_time = NaiveTime::from_hms_opt(14, 0, 0).unwrap();
 
-        state.start_with_time("event-123".to_string(), date, original_time, "Test Event".to_string(), "#0000ff".to_string());
+        state.start_with_time("work".to_string(), "event-123".to_string(), date, original_time, "Test Event".to_string(), "#0000ff".to_string());
         state.update_with_time(date, target_time);
         let result = state.end_with_time();
 
@@ -318,7 +376,7 @@ mod tests {
         let original = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
         let target = NaiveDate::from_ymd_opt(2024, 1, 18).unwrap();
 
-        state.start("event-123".to_string(), original, "Test Event".to_string(), "#0000ff".to_string());
+        state.start("work".to_string(), "event-123".to_string(), original, "Test Event".to_string(), "#0000ff".to_string());
         state.update(target);
 
         assert_eq!(state.get_offset(), Some(3)); // 3 days forward
@@ -329,7 +387,7 @@ mod tests {
         let mut state = EventDragState::new();
         let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
 
-        state.start("event-123".to_string(), date, "Test Event".to_string(), "#0000ff".to_string());
+        state.start("work".to_string(), "event-123".to_string(), date, "Test Event".to_string(), "#0000ff".to_string());
         assert!(state.is_active);
 
         state.cancel();
@@ -354,5 +412,121 @@ mod tests {
         assert!(preview.summary.is_none());
         assert!(preview.color.is_none());
         assert!(preview.cursor_position.is_none());
+        assert!(preview.start_position.is_none());
+    }
+
+    #[test]
+    fn test_drag_threshold_separates_click_from_move() {
+        let mut preview = DragPreviewInfo::new();
+        // No cursor movement at all is a click
+        assert!(!preview.moved_beyond(DRAG_THRESHOLD_PX));
+
+        // A 3px jitter while clicking stays below the threshold
+        preview.set_origin(100.0, 200.0);
+        preview.update_cursor(102.0, 202.0);
+        assert!(!preview.moved_beyond(DRAG_THRESHOLD_PX));
+
+        // Moving further away turns it into a drag
+        preview.update_cursor(100.0, 205.0);
+        assert!(preview.moved_beyond(DRAG_THRESHOLD_PX));
+    }
+
+    #[test]
+    fn test_slow_drag_measured_from_press_position() {
+        let mut preview = DragPreviewInfo::new();
+        preview.set_origin(100.0, 200.0);
+
+        // Moving 1px per event still adds up to a drag
+        for step in 1..=5 {
+            preview.update_cursor(100.0 + step as f32, 200.0);
+        }
+        assert!(preview.moved_beyond(DRAG_THRESHOLD_PX));
+    }
+
+    #[test]
+    fn test_floating_preview_follows_cursor_during_drag() {
+        let mut state = EventDragState::new();
+        let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        assert!(state.floating_preview().is_none());
+
+        state.start("work".to_string(), "event-123".to_string(), date, "Standup".to_string(), "#0000ff".to_string());
+        state.set_origin(100.0, 200.0);
+        // Pressed but not moved yet: no cursor position, no chip
+        assert!(state.floating_preview().is_none());
+
+        state.update_cursor(101.0, 201.0);
+        assert!(state.floating_preview().is_none());
+
+        state.update_cursor(140.0, 230.0);
+        assert_eq!(state.floating_preview(), Some(("Standup", "#0000ff", (140.0, 230.0))));
+
+        state.cancel();
+        assert!(state.floating_preview().is_none());
+    }
+
+    fn hm(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn test_resized_times_snap_to_quarter_hours() {
+        let meeting = (hm(9, 0), hm(10, 0));
+
+        // Dragging the bottom edge down lands on the nearest quarter hour
+        assert_eq!(resized_times(meeting, ResizeEdge::End, 37), (hm(9, 0), hm(10, 30)));
+        assert_eq!(resized_times(meeting, ResizeEdge::End, 40), (hm(9, 0), hm(10, 45)));
+        // Dragging the top edge up half an hour
+        assert_eq!(resized_times(meeting, ResizeEdge::Start, -30), (hm(8, 30), hm(10, 0)));
+
+        // An edge off the grid stays put for small movements, then snaps to the grid
+        let odd = (hm(9, 10), hm(9, 50));
+        assert_eq!(resized_times(odd, ResizeEdge::End, 3), odd);
+        assert_eq!(resized_times(odd, ResizeEdge::End, 12), (hm(9, 10), hm(10, 0)));
+    }
+
+    #[test]
+    fn test_resized_times_keep_minimum_duration_and_day() {
+        let meeting = (hm(9, 0), hm(10, 0));
+
+        // Edges can't cross: at least 15 minutes remain
+        assert_eq!(resized_times(meeting, ResizeEdge::End, -120), (hm(9, 0), hm(9, 15)));
+        assert_eq!(resized_times(meeting, ResizeEdge::Start, 120), (hm(9, 45), hm(10, 0)));
+
+        // Clamped to the day
+        assert_eq!(resized_times(meeting, ResizeEdge::Start, -600), (hm(0, 0), hm(10, 0)));
+        assert_eq!(resized_times(meeting, ResizeEdge::End, 900), (hm(9, 0), hm(23, 59)));
+    }
+
+    #[test]
+    fn test_event_resize_state_lifecycle() {
+        let mut state = EventResizeState::new();
+        state.start("work".to_string(), "standup".to_string(), ResizeEdge::End, hm(9, 0), hm(10, 0));
+        assert!(state.is_active);
+
+        // First cursor position is the reference; 60px per hour, 30px down is 30 minutes
+        state.update_cursor(200.0, 60.0);
+        state.update_cursor(230.0, 60.0);
+        assert_eq!(state.preview_for("work:standup"), Some((hm(9, 0), hm(10, 30))));
+        assert_eq!(state.preview_for("work:other"), None);
+
+        let result = state.end();
+        assert_eq!(
+            result,
+            Some(("work".to_string(), "standup".to_string(), (hm(9, 0), hm(10, 0)), (hm(9, 0), hm(10, 30))))
+        );
+        assert!(!state.is_active);
+        assert_eq!(state.preview_for("work:standup"), None);
+    }
+
+    #[test]
+    fn test_event_resize_without_change_returns_none() {
+        let mut state = EventResizeState::new();
+        state.start("work".to_string(), "standup".to_string(), ResizeEdge::Start, hm(9, 0), hm(10, 0));
+        state.update_cursor(200.0, 60.0);
+        state.update_cursor(203.0, 60.0);
+        assert!(state.end().is_none());
+
+        // Ending without an active resize does nothing
+        assert!(state.end().is_none());
     }
 }
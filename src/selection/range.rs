@@ -1,6 +1,7 @@
 //! Selection range representing a normalized date/time range.
 
 use chrono::NaiveDate;
+use chrono::NaiveDateTime;
 use chrono::NaiveTime;
 
 use super::point::SelectionPoint;
@@ -57,6 +58,16 @@ impl SelectionRange {
         self.end.time
     }
 
+    /// Get the full start and end date-times of a time range.
+    /// The range may cross days (e.g. Monday 14:00 to Tuesday 16:00).
+    /// Returns None if either point has no time, or both points are the same
+    /// (a click without drag).
+    pub fn time_bounds(&self) -> Option<(NaiveDateTime, NaiveDateTime)> {
+        let start = self.start.date.and_time(self.start.time?);
+        let end = self.end.date.and_time(self.end.time?);
+        (start != end).then_some((start.min(end), start.max(end)))
+    }
+
     /// Check if this is a date-only range (no times specified)
     #[allow(dead_code)] // Part of selection API
     pub fn is_date_only(&self) -> bool {
@@ -0,0 +1,152 @@
+//! Event resize state for changing an event's start or end time by dragging its edge.
+
+use chrono::{NaiveTime, Timelike};
+use log::debug;
+
+/// Resized edges snap to this grid (minutes past midnight)
+pub const RESIZE_SNAP_MINUTES: i64 = 15;
+
+/// Shortest duration an event can be resized to
+const MIN_RESIZED_DURATION_MINUTES: i64 = 15;
+
+/// Last minute of the day an end edge can be dragged to (23:59)
+const LAST_MINUTE_OF_DAY: i64 = 24 * 60 - 1;
+
+/// Which edge of a timed event block is being dragged
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResizeEdge {
+    /// Top edge - changes the start time
+    Start,
+    /// Bottom edge - changes the end time
+    End,
+}
+
+/// Result of a finished resize: (calendar_id, event_uid, original (start, end), new (start, end))
+pub type ResizeResult = (String, String, (NaiveTime, NaiveTime), (NaiveTime, NaiveTime));
+
+/// State for tracking an event edge drag in the week/day time grid.
+///
+/// Separate from EventDragState, which moves whole events between dates.
+/// The new time follows the vertical cursor travel since the drag started.
+#[derive(Debug, Clone, Default)]
+pub struct EventResizeState {
+    /// The calendar ID of the event being resized
+    pub calendar_id: Option<String>,
+    /// The UID of the event being resized (occurrence UID for recurring events)
+    pub event_uid: Option<String>,
+    /// The edge being dragged
+    pub edge: Option<ResizeEdge>,
+    /// Start and end time when the drag started
+    original: Option<(NaiveTime, NaiveTime)>,
+    /// Start and end time for the current cursor position
+    current: Option<(NaiveTime, NaiveTime)>,
+    /// Cursor y position of the first move after the press
+    start_y: Option<f32>,
+    /// Whether a resize is currently active
+    pub is_active: bool,
+}
+
+impl EventResizeState {
+    /// Create a new empty resize state
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start dragging one edge of an event shown from `start` to `end`
+    pub fn start(&mut self, calendar_id: String, event_uid: String, edge: ResizeEdge, start: NaiveTime, end: NaiveTime) {
+        debug!("EventResizeState: Starting {:?} resize for calendar={} event={} ({}-{})", edge, calendar_id, event_uid, start, end);
+        self.calendar_id = Some(calendar_id);
+        self.event_uid = Some(event_uid);
+        self.edge = Some(edge);
+        self.original = Some((start, end));
+        self.current = Some((start, end));
+        self.start_y = None;
+        self.is_active = true;
+    }
+
+    /// Update the dragged edge for the cursor at `y`, with `hour_height` pixels per hour
+    pub fn update_cursor(&mut self, y: f32, hour_height: f32) {
+        let (Some(edge), Some(original)) = (self.edge, self.original) else {
+            return;
+        };
+        if !self.is_active {
+            return;
+        }
+        let start_y = *self.start_y.get_or_insert(y);
+        let offset_minutes = ((y - start_y) / hour_height * 60.0).round() as i64;
+        self.current = Some(resized_times(original, edge, offset_minutes));
+    }
+
+    /// Start and end time to preview for the event with `unique_id` (calendar_id:uid)
+    pub fn preview_for(&self, unique_id: &str) -> Option<(NaiveTime, NaiveTime)> {
+        if !self.is_active {
+            return None;
+        }
+        let (calendar_id, uid) = unique_id.split_once(':')?;
+        let matches = self.calendar_id.as_deref() == Some(calendar_id) && self.event_uid.as_deref() == Some(uid);
+        if matches { self.current } else { None }
+    }
+
+    /// End the resize and return the change if the times differ from the original
+    pub fn end(&mut self) -> Option<ResizeResult> {
+        if !self.is_active {
+            return None;
+        }
+
+        let result = match (&self.calendar_id, &self.event_uid, self.original, self.current) {
+            (Some(cal_id), Some(uid), Some(original), Some(current)) if original != current => {
+                debug!("EventResizeState: Ending resize - event={} from {:?} to {:?}", uid, original, current);
+                Some((cal_id.clone(), uid.clone(), original, current))
+            }
+            _ => {
+                debug!("EventResizeState: Ending resize - no change");
+                None
+            }
+        };
+
+        self.reset();
+        result
+    }
+
+    /// Reset the resize state
+    pub fn reset(&mut self) {
+        self.calendar_id = None;
+        self.event_uid = None;
+        self.edge = None;
+        self.original = None;
+        self.current = None;
+        self.start_y = None;
+        self.is_active = false;
+    }
+}
+
+/// Times after moving `edge` by `offset_minutes`, snapped to `RESIZE_SNAP_MINUTES`.
+/// Offsets under half a step keep the original time, so an edge off the grid
+/// doesn't jump on a click. The event keeps at least
+/// `MIN_RESIZED_DURATION_MINUTES` and stays within its day.
+pub fn resized_times(
+    (start, end): (NaiveTime, NaiveTime),
+    edge: ResizeEdge,
+    offset_minutes: i64,
+) -> (NaiveTime, NaiveTime) {
+    if offset_minutes.abs() * 2 < RESIZE_SNAP_MINUTES {
+        return (start, end);
+    }
+
+    let minutes = |time: NaiveTime| (time.hour() * 60 + time.minute()) as i64;
+    let snap = |value: i64| (value as f64 / RESIZE_SNAP_MINUTES as f64).round() as i64 * RESIZE_SNAP_MINUTES;
+    let time = |value: i64| NaiveTime::from_hms_opt((value / 60) as u32, (value % 60) as u32, 0).unwrap_or(NaiveTime::MIN);
+
+    match edge {
+        ResizeEdge::Start => {
+            let latest = minutes(end) - MIN_RESIZED_DURATION_MINUTES;
+            let new_start = snap(minutes(start) + offset_minutes).clamp(0, latest.max(0));
+            (time(new_start), end)
+        }
+        ResizeEdge::End => {
+            let earliest = minutes(start) + MIN_RESIZED_DURATION_MINUTES;
+            let new_end = snap(minutes(end) + offset_minutes).clamp(earliest.min(LAST_MINUTE_OF_DAY), LAST_MINUTE_OF_DAY);
+            (start, time(new_end))
+        }
+    }
+}
@@ -6,6 +6,10 @@ use log::debug;
 use super::point::SelectionPoint;
 use super::range::SelectionRange;
 
+/// Granularity of time selection in the week/day grid: each hour cell is split into
+/// slots of this many minutes, so a drag snaps to :00/:15/:30/:45. Must divide 60.
+pub const TIME_SELECTION_SNAP_MINUTES: u32 = 15;
+
 /// State for tracking drag selection across day/time cells.
 ///
 /// This is a transient UI state, not a dialog, so it lives directly
@@ -116,16 +120,19 @@ impl SelectionState {
             .unwrap_or(false)
     }
 
-    /// Check if a date+hour cell is within the current time-based selection
-    /// Used for highlighting hour cells in week/day views
-    pub fn contains_time(&self, date: NaiveDate, hour: u32) -> bool {
+    /// Check if the time slot starting at `slot_start` on `date` is within the current
+    /// time-based selection. Slots are `TIME_SELECTION_SNAP_MINUTES` long.
+    /// Used for highlighting the time grid in week/day views
+    pub fn contains_time(&self, date: NaiveDate, slot_start: NaiveTime) -> bool {
         let Some(range) = self.get_range() else {
             return false;
         };
 
-        // Create time points for the start and end of the hour
-        let cell_start = NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
-        let cell_end = NaiveTime::from_hms_opt(hour, 59, 59).unwrap();
+        // Start and last second of the slot
+        let cell_start = slot_start;
+        let cell_end = slot_start
+            .overflowing_add_signed(chrono::Duration::seconds(TIME_SELECTION_SNAP_MINUTES as i64 * 60 - 1))
+            .0;
 
         // Get selection times (default to full day if not set)
         let sel_start_time = range.start.time.unwrap_or_else(|| NaiveTime::from_hms_opt(0, 0, 0).unwrap());
@@ -0,0 +1,284 @@
+//! Alert scheduler
+//!
+//! Decides which event alerts became due on each timer tick and posts them
+//! as desktop notifications over the freedesktop notification D-Bus interface.
+//! Fired alerts are stored in the database so restarting the app doesn't
+//! notify about them again.
+
+use crate::caldav::{CalendarEvent, EventStatus};
+use crate::database::Database;
+use chrono::{DateTime, Duration, Utc};
+use log::{debug, error};
+use notify_rust::Notification;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// How far ahead to look for events whose alert may be due (covers a one-week alert)
+pub const ALERT_LOOKAHEAD_DAYS: i64 = 8;
+
+/// How long a fired alert is remembered after its alert time
+const FIRED_RETENTION_DAYS: i64 = 1;
+
+/// Notification action sent when the user clicks the notification body
+const DEFAULT_ACTION: &str = "default";
+
+/// An alert that is due for notification
+#[derive(Debug, Clone, PartialEq)]
+pub struct DueAlert {
+    /// Event (or occurrence) UID
+    pub uid: String,
+    pub summary: String,
+    pub start: DateTime<Utc>,
+    pub all_day: bool,
+}
+
+/// Tracks which alerts already fired so each (uid, alert) pair notifies once
+#[derive(Debug)]
+pub struct AlertScheduler {
+    /// Alerts at or before this time were already handled
+    last_check: DateTime<Utc>,
+    /// Fired alerts keyed by (uid, minutes before start), with their alert time
+    fired: HashMap<(String, i64), DateTime<Utc>>,
+    /// Database persisting the fired alerts, if any
+    db: Option<Arc<Mutex<Database>>>,
+}
+
+impl AlertScheduler {
+    /// Create a scheduler that only fires alerts after `now`.
+    /// Alerts that passed while the app was closed are reported by the missed alerts summary.
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self {
+            last_check: now,
+            fired: HashMap::new(),
+            db: None,
+        }
+    }
+
+    /// Create a scheduler that remembers fired alerts in `db`, starting with
+    /// the ones fired before the app was restarted. Forgets expired ones.
+    pub fn with_database(now: DateTime<Utc>, db: Arc<Mutex<Database>>) -> Self {
+        let mut scheduler = Self::new(now);
+        match db.lock() {
+            Ok(db) => {
+                let cutoff = now - Duration::days(FIRED_RETENTION_DAYS);
+                if let Err(e) = db.prune_fired_alerts(cutoff) {
+                    error!("AlertScheduler: Failed to prune fired alerts: {}", e);
+                }
+                match db.get_fired_alerts() {
+                    Ok(fired) => {
+                        debug!("AlertScheduler: Loaded {} fired alert(s)", fired.len());
+                        scheduler.fired = fired
+                            .into_iter()
+                            .map(|(uid, minutes, alert_time)| ((uid, minutes), alert_time))
+                            .collect();
+                    }
+                    Err(e) => error!("AlertScheduler: Failed to load fired alerts: {}", e),
+                }
+            }
+            Err(e) => error!("AlertScheduler: Failed to lock database: {}", e),
+        }
+        scheduler.db = Some(db);
+        scheduler
+    }
+
+    /// Alerts (`alert` and `alert_second`) whose time is in `(last check, now]`
+    /// and that haven't fired yet. Records them as fired. Sorted by start time.
+    pub fn due_alerts(&mut self, events: &[CalendarEvent], now: DateTime<Utc>) -> Vec<DueAlert> {
+        let since = self.last_check;
+        self.last_check = now;
+        let cutoff = now - Duration::days(FIRED_RETENTION_DAYS);
+        let before = self.fired.len();
+        self.fired.retain(|_, alert_time| *alert_time > cutoff);
+        if self.fired.len() < before {
+            self.with_db(|db| db.prune_fired_alerts(cutoff).map(|_| ()));
+        }
+
+        let mut due = Vec::new();
+        for event in events.iter().filter(|e| e.status != EventStatus::Cancelled) {
+            let alert_times: Vec<DateTime<Utc>> = std::iter::once(&event.alert)
+                .chain(event.alert_second.as_ref())
+                .filter_map(|alert| alert.alert_time(event.start))
+                .collect();
+
+            let mut is_due = false;
+            for alert_time in alert_times {
+                if alert_time <= since || alert_time > now {
+                    continue;
+                }
+                let minutes = (event.start - alert_time).num_minutes();
+                // Insert even if the other alert of this event already matched,
+                // so neither fires again on a later tick
+                if self.fired.insert((event.uid.clone(), minutes), alert_time).is_none() {
+                    self.with_db(|db| db.record_fired_alert(&event.uid, minutes, alert_time));
+                    is_due = true;
+                }
+            }
+
+            if is_due {
+                debug!("AlertScheduler: Alert due for uid={}", event.uid);
+                due.push(DueAlert {
+                    uid: event.uid.clone(),
+                    summary: event.summary.clone(),
+                    start: event.start,
+                    all_day: event.all_day,
+                });
+            }
+        }
+
+        due.sort_by_key(|alert| alert.start);
+        due
+    }
+
+    /// Run a write against the fired alerts database, logging failures
+    fn with_db(&self, write: impl FnOnce(&Database) -> Result<(), Box<dyn std::error::Error>>) {
+        let Some(db) = &self.db else { return };
+        let result = match db.lock() {
+            Ok(db) => write(&db),
+            Err(e) => {
+                error!("AlertScheduler: Failed to lock database: {}", e);
+                return;
+            }
+        };
+        if let Err(e) = result {
+            error!("AlertScheduler: Failed to store fired alerts: {}", e);
+        }
+    }
+}
+
+/// Post a desktop notification and block until it is clicked or closed.
+/// Returns true if the user clicked it.
+pub fn show_notification(app_name: &str, summary: &str, body: &str, open_label: &str) -> bool {
+    let handle = match Notification::new()
+        .appname(app_name)
+        .summary(summary)
+        .body(body)
+        .icon("x-office-calendar")
+        .action(DEFAULT_ACTION, open_label)
+        .show()
+    {
+        Ok(handle) => handle,
+        Err(e) => {
+            error!("AlertScheduler: Failed to show notification: {}", e);
+            return false;
+        }
+    };
+
+    let mut clicked = false;
+    handle.wait_for_action(|action| clicked = action == DEFAULT_ACTION);
+    clicked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::caldav::{AlertTime, ALL_DAY_ALERT_MINUTE};
+    use chrono::TimeZone;
+
+    fn event(uid: &str, start: DateTime<Utc>, alert: AlertTime) -> CalendarEvent {
+        CalendarEvent {
+            uid: uid.to_string(),
+            summary: uid.to_string(),
+            start,
+            end: start + Duration::hours(1),
+            alert,
+            ..CalendarEvent::test_default()
+        }
+    }
+
+    fn at(hour: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, 10, hour, min, 0).unwrap()
+    }
+
+    fn uids(alerts: &[DueAlert]) -> Vec<&str> {
+        alerts.iter().map(|a| a.uid.as_str()).collect()
+    }
+
+    #[test]
+    fn test_fires_each_alert_once_when_due() {
+        let mut scheduler = AlertScheduler::new(at(9, 0));
+        let events = vec![
+            // Alert at 09:45
+            event("meeting", at(10, 0), AlertTime::FifteenMinutes),
+            // Alert at 09:00, which was the start of tracking
+            event("earlier", at(9, 30), AlertTime::ThirtyMinutes),
+            event("silent", at(9, 50), AlertTime::None),
+        ];
+
+        assert!(scheduler.due_alerts(&events, at(9, 30)).is_empty());
+        assert_eq!(uids(&scheduler.due_alerts(&events, at(9, 45))), vec!["meeting"]);
+        assert!(scheduler.due_alerts(&events, at(9, 46)).is_empty());
+    }
+
+    #[test]
+    fn test_second_alert_and_duplicates() {
+        let mut scheduler = AlertScheduler::new(at(8, 0));
+        let mut event = event("review", at(12, 0), AlertTime::TwoHours);
+        event.alert_second = Some(AlertTime::FifteenMinutes);
+
+        assert_eq!(uids(&scheduler.due_alerts(&[event.clone()], at(10, 0))), vec!["review"]);
+        assert!(scheduler.due_alerts(&[event.clone()], at(11, 0)).is_empty());
+        assert_eq!(uids(&scheduler.due_alerts(&[event.clone()], at(11, 45))), vec!["review"]);
+
+        // Moving the clock back doesn't fire the same alerts again
+        scheduler.last_check = at(8, 0);
+        assert!(scheduler.due_alerts(&[event], at(12, 0)).is_empty());
+    }
+
+    #[test]
+    fn test_all_day_alert_on_the_day_before() {
+        // All-day event on March 11: the alert is due at 09:00 on March 10
+        let mut holiday = event("holiday", Utc.with_ymd_and_hms(2025, 3, 11, 0, 0, 0).unwrap(), AlertTime::DayBeforeAt(ALL_DAY_ALERT_MINUTE));
+        holiday.all_day = true;
+        assert_eq!(holiday.alert.alert_time(holiday.start), Some(at(9, 0)));
+
+        let mut scheduler = AlertScheduler::new(at(8, 0));
+        assert!(scheduler.due_alerts(&[holiday.clone()], at(8, 59)).is_empty());
+        assert_eq!(uids(&scheduler.due_alerts(&[holiday.clone()], at(9, 0))), vec!["holiday"]);
+        assert!(scheduler.due_alerts(&[holiday], at(12, 0)).is_empty());
+    }
+
+    #[test]
+    fn test_day_before_alert_ignores_start_time() {
+        // Timed events alert at the same time of day, whatever their start
+        let evening = AlertTime::DayBeforeAt(18 * 60 + 30);
+        assert_eq!(evening.alert_time(at(7, 15) + Duration::days(1)), Some(at(18, 30)));
+        assert_eq!(evening.alert_time(at(23, 0) + Duration::days(1)), Some(at(18, 30)));
+        assert_eq!(AlertTime::OneDay.alert_time(at(23, 0) + Duration::days(1)), Some(at(23, 0)));
+        assert_eq!(AlertTime::None.alert_time(at(9, 0)), None);
+    }
+
+    #[test]
+    fn test_fired_alerts_survive_restart() {
+        let db_path = std::env::temp_dir().join("sol_test_fired_alerts.db");
+        let _ = std::fs::remove_file(&db_path);
+        let db = Arc::new(Mutex::new(Database::open_at(db_path.clone()).unwrap()));
+        let events = vec![event("meeting", at(10, 0), AlertTime::FifteenMinutes)];
+
+        let mut scheduler = AlertScheduler::with_database(at(9, 0), db.clone());
+        assert_eq!(uids(&scheduler.due_alerts(&events, at(9, 45))), vec!["meeting"]);
+        drop(scheduler);
+
+        // Restarted app whose tracking starts before the alert time
+        let mut restarted = AlertScheduler::with_database(at(9, 40), db.clone());
+        assert!(restarted.due_alerts(&events, at(9, 50)).is_empty());
+
+        // A scheduler without the stored markers would fire it again
+        assert_eq!(uids(&AlertScheduler::new(at(9, 40)).due_alerts(&events, at(9, 50))), vec!["meeting"]);
+
+        // Markers older than the retention period are pruned on startup
+        drop(restarted);
+        let _ = AlertScheduler::with_database(at(9, 45) + Duration::days(2), db.clone());
+        assert!(db.lock().unwrap().get_fired_alerts().unwrap().is_empty());
+
+        let _ = std::fs::remove_file(&db_path);
+    }
+
+    #[test]
+    fn test_skips_cancelled_events() {
+        let mut scheduler = AlertScheduler::new(at(9, 0));
+        let mut cancelled = event("cancelled", at(10, 0), AlertTime::AtTime);
+        cancelled.status = EventStatus::Cancelled;
+
+        assert!(scheduler.due_alerts(&[cancelled], at(10, 0)).is_empty());
+    }
+}
@@ -3,7 +3,8 @@
 //! This handler manages calendar CRUD operations (not events, but the calendars themselves).
 //! It handles creating, editing, deleting calendars, toggling visibility, and color changes.
 
-use crate::calendars::CalendarManager;
+use super::CredentialStore;
+use crate::calendars::{CalDavCalendar, CalendarManager, CalendarSource, RemoteAccount};
 use crate::components::color_picker::CALENDAR_COLORS;
 use log::{debug, error, info, warn};
 use std::error::Error;
@@ -49,6 +50,7 @@ pub struct UpdateCalendarData {
     pub name: Option<String>,
     pub color: Option<String>,
     pub enabled: Option<bool>,
+    pub mute_alerts: Option<bool>,
 }
 
 /// Calendar Handler - centralized calendar management.
@@ -124,6 +126,47 @@ impl CalendarHandler {
         Ok(id)
     }
 
+    /// Add a CalDAV calendar for a server account.
+    /// The password goes to the system keyring; only the URL and username are saved in the config.
+    /// The calendar starts out empty until its first sync.
+    pub fn create_caldav(
+        manager: &mut CalendarManager,
+        name: &str,
+        account: RemoteAccount,
+        password: &str,
+    ) -> CalendarResult<String> {
+        info!("CalendarHandler: Adding CalDAV calendar '{}'", name);
+
+        if name.trim().is_empty() {
+            return Err(CalendarError::ValidationError("Calendar name is required".to_string()));
+        }
+        if account.username.trim().is_empty() {
+            return Err(CalendarError::ValidationError("Username is required".to_string()));
+        }
+
+        let id = Self::generate_id(name, manager);
+        // Checks the URL (HTTPS only) before anything is stored
+        let calendar = CalDavCalendar::new(
+            id.clone(),
+            name.trim().to_string(),
+            account,
+            password.to_string(),
+            manager.database(),
+        )
+        .map_err(|e| CalendarError::ValidationError(e.to_string()))?;
+
+        CredentialStore::store_password(&id, password).map_err(CalendarError::ConfigError)?;
+
+        manager.add_source(Box::new(calendar));
+        manager.save_config().map_err(|e| {
+            error!("CalendarHandler: Failed to save config: {}", e);
+            CalendarError::ConfigError(e.to_string())
+        })?;
+
+        info!("CalendarHandler: Successfully added CalDAV calendar '{}' (id={})", name, id);
+        Ok(id)
+    }
+
     /// Update an existing calendar
     pub fn update(
         manager: &mut CalendarManager,
@@ -163,6 +206,11 @@ impl CalendarHandler {
             calendar.set_enabled(enabled);
         }
 
+        if let Some(mute_alerts) = data.mute_alerts {
+            debug!("CalendarHandler: Updating mute_alerts to {}", mute_alerts);
+            calendar.info_mut().mute_alerts = mute_alerts;
+        }
+
         // Save configuration
         manager
             .save_config()
@@ -204,6 +252,34 @@ impl CalendarHandler {
         Ok(new_state)
     }
 
+    /// Toggle whether a calendar is pinned to the top of the list and save configuration.
+    /// Returns the new pinned state.
+    pub fn toggle_pinned(manager: &mut CalendarManager, calendar_id: &str) -> CalendarResult<bool> {
+        debug!("CalendarHandler: Toggling pinned state for '{}'", calendar_id);
+
+        let pinned = manager
+            .sources()
+            .iter()
+            .find(|c| c.info().id == calendar_id)
+            .map(|c| !c.info().pinned)
+            .ok_or_else(|| {
+                error!("CalendarHandler: Calendar '{}' not found for pin", calendar_id);
+                CalendarError::NotFound(calendar_id.to_string())
+            })?;
+        manager.set_calendar_pinned(calendar_id, pinned);
+
+        info!("CalendarHandler: Calendar '{}' pinned={}", calendar_id, pinned);
+
+        manager
+            .save_config()
+            .map_err(|e| {
+                error!("CalendarHandler: Failed to save config after pin: {}", e);
+                CalendarError::ConfigError(e.to_string())
+            })?;
+
+        Ok(pinned)
+    }
+
     /// Change a calendar's color
     pub fn change_color(
         manager: &mut CalendarManager,
@@ -218,6 +294,7 @@ impl CalendarHandler {
                 name: None,
                 color: Some(color),
                 enabled: None,
+                mute_alerts: None,
             },
         )
     }
@@ -226,11 +303,22 @@ impl CalendarHandler {
     pub fn delete(manager: &mut CalendarManager, calendar_id: &str) -> CalendarResult<()> {
         info!("CalendarHandler: Deleting calendar '{}'", calendar_id);
 
+        let has_account = manager
+            .sources()
+            .iter()
+            .any(|c| c.info().id == calendar_id && c.info().account.is_some());
+
         if !manager.delete_calendar(calendar_id) {
             error!("CalendarHandler: Calendar '{}' not found for deletion", calendar_id);
             return Err(CalendarError::NotFound(calendar_id.to_string()));
         }
 
+        if has_account {
+            if let Err(e) = CredentialStore::delete_password(calendar_id) {
+                warn!("CalendarHandler: {}", e);
+            }
+        }
+
         info!("CalendarHandler: Successfully deleted calendar '{}'", calendar_id);
         Ok(())
     }
@@ -255,10 +343,25 @@ impl CalendarHandler {
         Ok((info.name.clone(), info.color.clone(), info.enabled))
     }
 
-    /// Get the first available calendar ID (for selecting a default)
-    pub fn get_first_calendar_id(manager: &CalendarManager) -> Option<String> {
-        let id = manager.sources().first().map(|c| c.info().id.clone());
-        debug!("CalendarHandler: First calendar ID: {:?}", id);
+    /// Resolve which calendar should be selected for new events.
+    /// Keeps `current` if that calendar still exists, otherwise picks the first
+    /// enabled calendar, falling back to the first calendar if all are disabled.
+    pub fn resolve_selected_calendar_id(
+        sources: &[Box<dyn CalendarSource>],
+        current: Option<&str>,
+    ) -> Option<String> {
+        if let Some(current) = current {
+            if sources.iter().any(|c| c.info().id == current) {
+                return Some(current.to_string());
+            }
+        }
+
+        let id = sources
+            .iter()
+            .find(|c| c.is_enabled())
+            .or_else(|| sources.first())
+            .map(|c| c.info().id.clone());
+        debug!("CalendarHandler: Default calendar ID: {:?}", id);
         id
     }
 }
@@ -266,6 +369,9 @@ impl CalendarHandler {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::calendars::LocalCalendar;
+    use crate::database::Database;
+    use std::sync::{Arc, Mutex};
 
     #[test]
     fn test_validate_empty_name() {
@@ -293,4 +399,44 @@ mod tests {
         assert!(!color.is_empty());
         assert!(color.starts_with('#'));
     }
+
+    #[test]
+    fn test_deleting_selected_calendar_reselects_enabled() {
+        let db_path = std::env::temp_dir().join("sol_test_calendar_selection.db");
+        let _ = std::fs::remove_file(&db_path);
+        let db = Arc::new(Mutex::new(Database::open_at(db_path.clone()).unwrap()));
+
+        let mut sources: Vec<Box<dyn CalendarSource>> = ["hidden", "personal", "work"]
+            .iter()
+            .map(|id| {
+                Box::new(LocalCalendar::with_color(id.to_string(), id.to_string(), "#3B82F6".to_string(), db.clone()))
+                    as Box<dyn CalendarSource>
+            })
+            .collect();
+        // Set visibility explicitly - saved calendar config may otherwise apply
+        sources[0].set_enabled(false);
+        sources[1].set_enabled(true);
+        sources[2].set_enabled(true);
+
+        // Fresh profile: nothing selected yet, so the first enabled calendar wins
+        assert_eq!(
+            CalendarHandler::resolve_selected_calendar_id(&sources, None).as_deref(),
+            Some("personal")
+        );
+
+        // An existing selection is kept
+        assert_eq!(
+            CalendarHandler::resolve_selected_calendar_id(&sources, Some("work")).as_deref(),
+            Some("work")
+        );
+
+        // Deleting the selected calendar re-selects another enabled one
+        sources.retain(|c| c.info().id != "personal");
+        assert_eq!(
+            CalendarHandler::resolve_selected_calendar_id(&sources, Some("personal")).as_deref(),
+            Some("work")
+        );
+
+        let _ = std::fs::remove_file(&db_path);
+    }
 }
@@ -0,0 +1,47 @@
+//! Credential store for remote calendar accounts.
+//!
+//! Passwords are kept in the desktop secret service (GNOME Keyring, KWallet)
+//! under the calendar's ID, so the calendar config never holds them in plain text.
+
+use log::{debug, error};
+
+/// Service name the passwords are stored under
+const KEYRING_SERVICE: &str = "sol-calendar";
+
+/// Keyring access for calendar account passwords
+pub struct CredentialStore;
+
+impl CredentialStore {
+    /// Store (or replace) the password of a calendar's account
+    pub fn store_password(calendar_id: &str, password: &str) -> Result<(), String> {
+        debug!("CredentialStore: Storing password for calendar '{}'", calendar_id);
+        Self::entry(calendar_id)?
+            .set_password(password)
+            .map_err(|e| {
+                error!("CredentialStore: Failed to store password for '{}': {}", calendar_id, e);
+                format!("Could not save the password in the keyring: {}", e)
+            })
+    }
+
+    /// Load the password of a calendar's account
+    pub fn load_password(calendar_id: &str) -> Result<String, String> {
+        Self::entry(calendar_id)?.get_password().map_err(|e| match e {
+            keyring::Error::NoEntry => format!("No password saved for calendar '{}'", calendar_id),
+            e => format!("Could not read the password from the keyring: {}", e),
+        })
+    }
+
+    /// Remove the password of a calendar's account. Succeeds if none was stored.
+    pub fn delete_password(calendar_id: &str) -> Result<(), String> {
+        debug!("CredentialStore: Deleting password for calendar '{}'", calendar_id);
+        match Self::entry(calendar_id)?.delete_credential() {
+            Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+            Err(e) => Err(format!("Could not remove the password from the keyring: {}", e)),
+        }
+    }
+
+    fn entry(calendar_id: &str) -> Result<keyring::Entry, String> {
+        keyring::Entry::new(KEYRING_SERVICE, calendar_id)
+            .map_err(|e| format!("Keyring unavailable: {}", e))
+    }
+}
@@ -28,10 +28,13 @@
 //! CalendarManager → CalendarSource → Protocol
 //! ```
 
-use crate::caldav::CalendarEvent;
+use crate::caldav::{CalendarEvent, RepeatFrequency};
 use crate::calendars::CalendarManager;
 use log::{debug, error, info, trace, warn};
+use chrono::{DateTime, NaiveDate, Utc};
+use std::collections::HashMap;
 use std::error::Error;
+use uuid::Uuid;
 
 /// Result type for event handler operations
 pub type EventResult<T> = Result<T, EventError>;
@@ -261,6 +264,44 @@ impl EventHandler {
         Ok(deleted)
     }
 
+    /// Delete an event by UID from one calendar.
+    ///
+    /// Unlike `delete_event`, an event with the same UID in another calendar
+    /// is left alone.
+    pub fn delete_event_in_calendar(
+        calendar_manager: &mut CalendarManager,
+        calendar_id: &str,
+        uid: &str,
+    ) -> EventResult<()> {
+        info!("EventHandler: Deleting event uid={} from calendar '{}'", uid, calendar_id);
+
+        let calendar = calendar_manager
+            .sources_mut()
+            .iter_mut()
+            .find(|c| c.info().id == calendar_id)
+            .ok_or_else(|| {
+                error!("EventHandler: Calendar '{}' not found for delete", calendar_id);
+                EventError::CalendarNotFound(calendar_id.to_string())
+            })?;
+
+        calendar
+            .delete_event(uid)
+            .map_err(|e| {
+                error!("EventHandler: Failed to delete event: {}", e);
+                EventError::StorageError(e.to_string())
+            })?;
+
+        calendar
+            .sync()
+            .map_err(|e| {
+                error!("EventHandler: Sync failed after delete: {}", e);
+                EventError::SyncError(e.to_string())
+            })?;
+
+        info!("EventHandler: Successfully deleted event uid={} from calendar '{}'", uid, calendar_id);
+        Ok(())
+    }
+
     /// Add an exception date to a recurring event.
     ///
     /// This is used to delete a single occurrence of a recurring event.
@@ -291,6 +332,121 @@ impl EventHandler {
         Ok(())
     }
 
+    /// Remove the occurrences on `dates` from a series (EXDATE), together
+    /// with any modified copies of them. The rest of the series stays.
+    pub fn skip_occurrences(mut series: CalendarEvent, dates: &[NaiveDate]) -> CalendarEvent {
+        for date in dates {
+            if !series.exception_dates.contains(date) {
+                series.exception_dates.push(*date);
+            }
+        }
+        let uid = series.uid.clone();
+        series
+            .overrides
+            .retain(|o| !Self::override_date(&uid, &o.uid).is_some_and(|occurrence| dates.contains(&occurrence)));
+        series
+    }
+
+    /// Take one occurrence out of a series for a "this event" edit.
+    ///
+    /// The series skips `date` from now on (EXDATE) and `edited` becomes a
+    /// standalone event under a new UID. Returns both, series first.
+    pub fn detach_occurrence(
+        mut series: CalendarEvent,
+        date: NaiveDate,
+        mut edited: CalendarEvent,
+    ) -> (CalendarEvent, CalendarEvent) {
+        if !series.exception_dates.contains(&date) {
+            series.exception_dates.push(date);
+        }
+        // A modified copy of that occurrence is replaced by the standalone event
+        let occurrence_uid = format!("{}_{}", series.uid, date.format("%Y%m%d"));
+        series.overrides.retain(|o| o.uid != occurrence_uid);
+
+        edited.uid = Uuid::new_v4().to_string();
+        edited.repeat = RepeatFrequency::Never;
+        edited.repeat_until = None;
+        edited.repeat_count = None;
+        edited.repeat_interval = 1;
+        edited.repeat_weekdays = vec![];
+        edited.exception_dates = vec![];
+        edited.overrides = vec![];
+
+        info!("EventHandler: Detached occurrence {} of event uid={} as uid={}", date, series.uid, edited.uid);
+        (series, edited)
+    }
+
+    /// Split a series at `date` for a "this and following" edit.
+    ///
+    /// The series ends the day before `date` (UNTIL) and `edited` continues it
+    /// from `date` under a new UID, taking over the exception dates and
+    /// modified occurrences from `date` on. A COUNT limit is shared between
+    /// the two, the original keeping the occurrences before `date` (so an
+    /// export, where COUNT takes precedence over UNTIL, ends it there too).
+    /// Returns both, original series first.
+    pub fn split_series(
+        mut series: CalendarEvent,
+        date: NaiveDate,
+        mut edited: CalendarEvent,
+    ) -> (CalendarEvent, CalendarEvent) {
+        edited.uid = Uuid::new_v4().to_string();
+        if let Some(count) = edited.repeat_count {
+            edited.repeat_count = Some(count.saturating_sub(CalendarManager::occurrences_before(&series, date)));
+        }
+
+        let (following, earlier): (Vec<NaiveDate>, Vec<NaiveDate>) =
+            series.exception_dates.iter().partition(|exception| **exception >= date);
+        series.exception_dates = earlier;
+        edited.exception_dates = following;
+
+        let (following, earlier): (Vec<CalendarEvent>, Vec<CalendarEvent>) = std::mem::take(&mut series.overrides)
+            .into_iter()
+            .partition(|o| Self::override_date(&series.uid, &o.uid).is_some_and(|occurrence| occurrence >= date));
+        series.overrides = earlier;
+        edited.overrides = following
+            .into_iter()
+            .map(|mut o| {
+                o.uid = o.uid.replacen(&series.uid, &edited.uid, 1);
+                o
+            })
+            .collect();
+
+        series.repeat_count = series.repeat_count.map(|_| CalendarManager::occurrences_before(&series, date));
+        let until = date.pred_opt();
+        series.repeat_until = match (series.repeat_until, until) {
+            (Some(existing), Some(until)) => Some(existing.min(until)),
+            (existing, until) => until.or(existing),
+        };
+
+        info!("EventHandler: Split event uid={} at {} into uid={}", series.uid, date, edited.uid);
+        (series, edited)
+    }
+
+    /// End a series before `date` for a "this and following" delete.
+    ///
+    /// Returns None when `date` is the first occurrence, as then the whole
+    /// series goes.
+    pub fn end_series_before(mut series: CalendarEvent, date: NaiveDate) -> Option<CalendarEvent> {
+        if date <= CalendarManager::series_start_date(&series) {
+            return None;
+        }
+        let until = date.pred_opt()?;
+        series.repeat_count = series.repeat_count.map(|_| CalendarManager::occurrences_before(&series, date));
+        series.repeat_until = Some(series.repeat_until.map_or(until, |existing| existing.min(until)));
+        series.exception_dates.retain(|exception| *exception < date);
+        let uid = series.uid.clone();
+        series
+            .overrides
+            .retain(|o| Self::override_date(&uid, &o.uid).is_none_or(|occurrence| occurrence < date));
+        Some(series)
+    }
+
+    /// Date of a modified occurrence of the series `series_uid` (UID `<series_uid>_YYYYMMDD`)
+    fn override_date(series_uid: &str, override_uid: &str) -> Option<NaiveDate> {
+        let suffix = override_uid.strip_prefix(series_uid)?.strip_prefix('_')?;
+        NaiveDate::parse_from_str(suffix, "%Y%m%d").ok()
+    }
+
     /// Find an event by UID across all calendars.
     ///
     /// Returns the event and the calendar ID it was found in.
@@ -356,6 +512,44 @@ impl EventHandler {
         Ok(event.clone())
     }
 
+    /// Count events per calendar overlapping `[start, end)` (for calendar statistics)
+    pub fn count_events_by_calendar(
+        calendar_manager: &CalendarManager,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> EventResult<HashMap<String, usize>> {
+        let db = calendar_manager.database();
+        let db = db.lock().map_err(|e| {
+            error!("EventHandler: Failed to lock database: {}", e);
+            EventError::StorageError(e.to_string())
+        })?;
+
+        let counts = db.count_events_by_calendar(start, end).map_err(|e| {
+            error!("EventHandler: Failed to count events: {}", e);
+            EventError::StorageError(e.to_string())
+        })?;
+
+        debug!("EventHandler: Counted events for {} calendars", counts.len());
+        Ok(counts)
+    }
+
+    /// Get previously used event locations (most recent first) for autocomplete
+    pub fn recent_locations(calendar_manager: &CalendarManager, limit: usize) -> EventResult<Vec<String>> {
+        let db = calendar_manager.database();
+        let db = db.lock().map_err(|e| {
+            error!("EventHandler: Failed to lock database: {}", e);
+            EventError::StorageError(e.to_string())
+        })?;
+
+        let locations = db.distinct_locations(limit).map_err(|e| {
+            error!("EventHandler: Failed to load recent locations: {}", e);
+            EventError::StorageError(e.to_string())
+        })?;
+
+        debug!("EventHandler: Loaded {} recent locations", locations.len());
+        Ok(locations)
+    }
+
     /// Sync all calendars.
     #[allow(dead_code)] // Reserved for CalDAV/remote calendar sync
     pub fn sync_all(calendar_manager: &mut CalendarManager) -> EventResult<()> {
@@ -383,27 +577,15 @@ impl EventHandler {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::caldav::{AlertTime, RepeatFrequency, TravelTime};
     use chrono::{TimeZone, Utc};
 
     fn create_test_event(uid: &str, summary: &str) -> CalendarEvent {
         CalendarEvent {
             uid: uid.to_string(),
             summary: summary.to_string(),
-            location: None,
-            all_day: false,
             start: Utc.with_ymd_and_hms(2025, 11, 30, 10, 0, 0).unwrap(),
             end: Utc.with_ymd_and_hms(2025, 11, 30, 11, 0, 0).unwrap(),
-            travel_time: TravelTime::None,
-            repeat: RepeatFrequency::Never,
-            repeat_until: None,
-            exception_dates: vec![],
-            invitees: vec![],
-            alert: AlertTime::None,
-            alert_second: None,
-            attachments: vec![],
-            url: None,
-            notes: None,
+            ..CalendarEvent::test_default()
         }
     }
 
@@ -435,4 +617,115 @@ mod tests {
         let result = EventHandler::validate_event(&event);
         assert!(matches!(result, Err(EventError::ValidationError(_))));
     }
+
+    fn daily_series() -> CalendarEvent {
+        let mut event = create_test_event("series", "Standup");
+        event.repeat = RepeatFrequency::Daily;
+        event
+    }
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 12, day).unwrap()
+    }
+
+    #[test]
+    fn test_detach_occurrence_for_this_event() {
+        let mut series = daily_series();
+        let mut moved = create_test_event("series_20251203", "Moved standup");
+        moved.start = Utc.with_ymd_and_hms(2025, 12, 3, 14, 0, 0).unwrap();
+        series.overrides = vec![moved];
+        let mut edited = daily_series();
+        edited.summary = "Standup with guests".to_string();
+        edited.start = Utc.with_ymd_and_hms(2025, 12, 3, 10, 0, 0).unwrap();
+        edited.end = Utc.with_ymd_and_hms(2025, 12, 3, 11, 0, 0).unwrap();
+
+        let (series, standalone) = EventHandler::detach_occurrence(series, date(3), edited);
+
+        // The series skips the day and drops its stored copy of that occurrence
+        assert_eq!(series.uid, "series");
+        assert_eq!(series.exception_dates, vec![date(3)]);
+        assert!(series.overrides.is_empty());
+        assert_eq!(series.summary, "Standup");
+
+        // The edit lives on as a one-off event on that day
+        assert_ne!(standalone.uid, "series");
+        assert_eq!(standalone.repeat, RepeatFrequency::Never);
+        assert_eq!(standalone.summary, "Standup with guests");
+        assert_eq!(standalone.start.date_naive(), date(3));
+    }
+
+    #[test]
+    fn test_skip_occurrences_for_batch_delete() {
+        let mut series = daily_series();
+        series.exception_dates = vec![date(2)];
+        series.overrides = vec![
+            create_test_event("series_20251203", "Moved standup"),
+            create_test_event("series_20251204", "Long standup"),
+        ];
+
+        let series = EventHandler::skip_occurrences(series, &[date(2), date(3)]);
+
+        // Only the selected days go, each excluded once, and the moved one with them
+        assert_eq!(series.uid, "series");
+        assert_eq!(series.repeat, RepeatFrequency::Daily);
+        assert_eq!(series.exception_dates, vec![date(2), date(3)]);
+        assert_eq!(series.overrides.len(), 1);
+        assert_eq!(series.overrides[0].uid, "series_20251204");
+    }
+
+    #[test]
+    fn test_split_series_for_this_and_following() {
+        let mut series = daily_series();
+        series.repeat_count = Some(10);
+        series.exception_dates = vec![date(1), date(5)];
+        let mut moved = create_test_event("series_20251206", "Moved standup");
+        moved.start = Utc.with_ymd_and_hms(2025, 12, 6, 14, 0, 0).unwrap();
+        series.overrides = vec![create_test_event("series_20251202", "Early"), moved];
+        let mut edited = series.clone();
+        edited.summary = "Standup (new room)".to_string();
+        edited.start = Utc.with_ymd_and_hms(2025, 12, 4, 10, 0, 0).unwrap();
+        edited.end = Utc.with_ymd_and_hms(2025, 12, 4, 11, 0, 0).unwrap();
+
+        let (original, following) = EventHandler::split_series(series, date(4), edited);
+
+        // Nov 30 to Dec 3 stay with the original series
+        assert_eq!(original.uid, "series");
+        assert_eq!(original.repeat_until, Some(date(3)));
+        assert_eq!(original.repeat_count, Some(4));
+        assert_eq!(original.exception_dates, vec![date(1)]);
+        assert_eq!(original.overrides.len(), 1);
+        assert_eq!(original.overrides[0].uid, "series_20251202");
+
+        // The rest continues under a new UID with the remaining six of ten occurrences
+        assert_ne!(following.uid, "series");
+        assert_eq!(following.summary, "Standup (new room)");
+        assert_eq!(following.repeat, RepeatFrequency::Daily);
+        assert_eq!(following.repeat_count, Some(6));
+        assert_eq!(following.exception_dates, vec![date(5)]);
+        assert_eq!(following.overrides.len(), 1);
+        assert_eq!(following.overrides[0].uid, format!("{}_20251206", following.uid));
+    }
+
+    #[test]
+    fn test_end_series_before_for_delete_following() {
+        let mut series = daily_series();
+        series.repeat_until = Some(date(20));
+        series.exception_dates = vec![date(2), date(12)];
+
+        let ended = EventHandler::end_series_before(series.clone(), date(10)).unwrap();
+        assert_eq!(ended.repeat_until, Some(date(9)));
+        assert_eq!(ended.exception_dates, vec![date(2)]);
+
+        // An earlier UNTIL is kept
+        assert_eq!(EventHandler::end_series_before(ended, date(15)).unwrap().repeat_until, Some(date(9)));
+
+        // A COUNT limit is cut down to the occurrences that are left
+        let mut counted = daily_series();
+        counted.repeat_count = Some(10);
+        assert_eq!(EventHandler::end_series_before(counted, date(3)).unwrap().repeat_count, Some(3));
+
+        // From the first occurrence on, nothing of the series is left
+        let first = NaiveDate::from_ymd_opt(2025, 11, 30).unwrap();
+        assert!(EventHandler::end_series_before(series, first).is_none());
+    }
 }
@@ -3,11 +3,14 @@
 //! This handler manages importing and exporting calendar data in various formats,
 //! primarily iCalendar (.ics) format.
 
-use crate::caldav::{AlertTime, CalendarEvent, RepeatFrequency, TravelTime};
-use crate::calendars::CalendarManager;
-use chrono::{DateTime, Utc};
+use crate::caldav::{AlertTime, CalendarEvent, EventStatus, RepeatFrequency, TravelTime};
+use crate::calendars::{CalendarManager, CalendarSource};
+use super::ical_export::{event_zone, events_to_ics};
+use crate::timezone::CustomZone;
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, Utc, Weekday};
 use icalendar::{Calendar, Component, DatePerhapsTime, Event, EventLike};
 use log::{debug, error, info, warn};
+use std::collections::HashSet;
 use std::error::Error;
 use std::fs;
 use std::path::Path;
@@ -46,164 +49,507 @@ impl std::fmt::Display for ExportError {
 
 impl Error for ExportError {}
 
-/// Export Handler - import/export operations.
-#[allow(dead_code)] // Foundation for future import/export feature
-pub struct ExportHandler;
+/// How many events an import adds and how many it skips: duplicates, and
+/// events that can't be read (e.g. a VEVENT without DTSTART)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ImportSummary {
+    pub added: usize,
+    pub skipped: usize,
+}
 
-impl ExportHandler {
-    /// Export a single event to iCalendar format
-    #[allow(dead_code)] // Part of export API
-    pub fn event_to_ical(event: &CalendarEvent) -> Calendar {
-        debug!("ExportHandler: Converting event '{}' (uid={}) to iCal", event.summary, event.uid);
+/// UID prefix for synthetic "Busy" events created from VFREEBUSY periods
+pub const BUSY_EVENT_UID_PREFIX: &str = "freebusy-";
 
-        let mut calendar = Calendar::new();
+/// A busy interval parsed from a VFREEBUSY component
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BusyPeriod {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
 
-        let mut ical_event = Event::new();
-        ical_event.summary(&event.summary);
-        ical_event.uid(&event.uid);
-        ical_event.starts(event.start);
-        ical_event.ends(event.end);
+/// Join categories into a CATEGORIES value (comma-separated, commas in names escaped)
+pub(super) fn format_categories(categories: &[String]) -> String {
+    categories
+        .iter()
+        .map(|c| c.replace(',', "\\,"))
+        .collect::<Vec<_>>()
+        .join(",")
+}
 
-        if let Some(ref location) = event.location {
-            ical_event.location(location);
+/// Split a CATEGORIES value into trimmed, non-empty category names
+fn parse_categories(value: &str) -> Vec<String> {
+    let mut categories = Vec::new();
+    let mut current = String::new();
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some(escaped) = chars.next() {
+                    current.push(escaped);
+                }
+            }
+            ',' => categories.push(std::mem::take(&mut current)),
+            _ => current.push(c),
         }
+    }
+    categories.push(current);
 
-        if let Some(ref notes) = event.notes {
-            ical_event.description(notes);
-        }
+    categories
+        .into_iter()
+        .map(|c| c.trim().to_string())
+        .filter(|c| !c.is_empty())
+        .collect()
+}
 
-        if let Some(ref url) = event.url {
-            ical_event.url(url);
+/// CSS color names accepted in the COLOR property, with their hex values
+const CSS_COLOR_NAMES: &[(&str, &str)] = &[
+    ("black", "#000000"),
+    ("silver", "#c0c0c0"),
+    ("gray", "#808080"),
+    ("grey", "#808080"),
+    ("white", "#ffffff"),
+    ("maroon", "#800000"),
+    ("red", "#ff0000"),
+    ("purple", "#800080"),
+    ("fuchsia", "#ff00ff"),
+    ("magenta", "#ff00ff"),
+    ("green", "#008000"),
+    ("lime", "#00ff00"),
+    ("olive", "#808000"),
+    ("yellow", "#ffff00"),
+    ("navy", "#000080"),
+    ("blue", "#0000ff"),
+    ("teal", "#008080"),
+    ("aqua", "#00ffff"),
+    ("cyan", "#00ffff"),
+    ("orange", "#ffa500"),
+    ("pink", "#ffc0cb"),
+    ("brown", "#a52a2a"),
+    ("gold", "#ffd700"),
+    ("indigo", "#4b0082"),
+    ("violet", "#ee82ee"),
+];
+
+/// Parse a COLOR value (CSS color name or `#rrggbb`/`#rgb`) into lowercase `#rrggbb`
+fn parse_ical_color(value: &str) -> Option<String> {
+    let value = value.trim().to_ascii_lowercase();
+    if let Some(hex) = value.strip_prefix('#') {
+        if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
         }
+        return match hex.len() {
+            6 => Some(value),
+            3 => Some(hex.chars().fold(String::from("#"), |mut s, c| {
+                s.push(c);
+                s.push(c);
+                s
+            })),
+            _ => None,
+        };
+    }
+    CSS_COLOR_NAMES
+        .iter()
+        .find(|(name, _)| *name == value)
+        .map(|(_, hex)| hex.to_string())
+}
+
+/// Format a hex color for the COLOR property, preferring its CSS name when it has one
+pub(super) fn format_ical_color(hex: &str) -> String {
+    CSS_COLOR_NAMES
+        .iter()
+        .find(|(_, named)| named.eq_ignore_ascii_case(hex))
+        .map(|(name, _)| name.to_string())
+        .unwrap_or_else(|| hex.to_string())
+}
 
-        calendar.push(ical_event);
-        debug!("ExportHandler: Event conversion complete");
-        calendar
+/// End of an all-day event on its last day (23:59:59), like events created in the dialog.
+/// iCal DTEND dates are exclusive (the day after the last day), so an end at midnight
+/// moves back a day; the event always covers at least its start day.
+fn inclusive_all_day_end(start: DateTime<Utc>, end: DateTime<Utc>) -> DateTime<Utc> {
+    let last_day = if end.time() == NaiveTime::MIN && end.date_naive() > start.date_naive() {
+        end.date_naive() - chrono::Duration::days(1)
+    } else {
+        end.date_naive().max(start.date_naive())
+    };
+    last_day.and_time(NaiveTime::from_hms_opt(23, 59, 59).unwrap()).and_utc()
+}
+
+/// Two-letter iCal code of a weekday (BYDAY)
+pub(super) fn format_weekday(day: Weekday) -> &'static str {
+    match day {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
     }
+}
 
-    /// Export all events from a calendar to iCalendar format
-    #[allow(dead_code)] // Part of export API
-    pub fn calendar_to_ical(
-        manager: &CalendarManager,
-        calendar_id: &str,
-    ) -> ExportResult<Calendar> {
-        info!("ExportHandler: Exporting calendar '{}' to iCal format", calendar_id);
+/// Weekday of a two-letter iCal code; None for codes with an ordinal like "1MO"
+fn parse_weekday(code: &str) -> Option<Weekday> {
+    match code.to_ascii_uppercase().as_str() {
+        "MO" => Some(Weekday::Mon),
+        "TU" => Some(Weekday::Tue),
+        "WE" => Some(Weekday::Wed),
+        "TH" => Some(Weekday::Thu),
+        "FR" => Some(Weekday::Fri),
+        "SA" => Some(Weekday::Sat),
+        "SU" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
 
-        let calendar = manager
-            .sources()
-            .iter()
-            .find(|c| c.info().id == calendar_id)
-            .ok_or_else(|| {
-                error!("ExportHandler: Calendar '{}' not found", calendar_id);
-                ExportError::CalendarNotFound(calendar_id.to_string())
-            })?;
+/// Recurrence read from an RRULE value
+#[derive(Debug)]
+struct ParsedRule {
+    repeat: RepeatFrequency,
+    interval: u32,
+    weekdays: Vec<Weekday>,
+    until: Option<NaiveDate>,
+    count: Option<u32>,
+}
 
-        let events = calendar
-            .fetch_events()
-            .map_err(|e| {
-                error!("ExportHandler: Failed to fetch events: {}", e);
-                ExportError::IoError(e.to_string())
-            })?;
+impl Default for ParsedRule {
+    fn default() -> Self {
+        Self {
+            repeat: RepeatFrequency::Never,
+            interval: 1,
+            weekdays: vec![],
+            until: None,
+            count: None,
+        }
+    }
+}
 
-        debug!("ExportHandler: Found {} events to export", events.len());
+/// Map an RRULE value to a repeat frequency, its INTERVAL, weekly BYDAY and its optional
+/// UNTIL date and COUNT. Rules beyond a plain FREQ/INTERVAL/BYDAY/UNTIL/COUNT are kept
+/// verbatim as `Custom`.
+fn parse_rrule(value: &str) -> ParsedRule {
+    let mut freq = None;
+    let mut interval = 1;
+    let mut weekdays: Vec<Weekday> = vec![];
+    let mut until = None;
+    let mut count = None;
+    let mut simple = true;
+
+    for part in value.split(';').filter(|p| !p.is_empty()) {
+        let Some((key, val)) = part.split_once('=') else {
+            warn!("ExportHandler: Ignoring malformed RRULE part '{}'", part);
+            continue;
+        };
+        match key.to_ascii_uppercase().as_str() {
+            "FREQ" => freq = Some(val.to_ascii_uppercase()),
+            "INTERVAL" => match val.parse::<u32>() {
+                Ok(n) if n > 0 => interval = n,
+                _ => simple = false,
+            },
+            "BYDAY" => match val.split(',').map(parse_weekday).collect::<Option<Vec<_>>>() {
+                Some(days) => weekdays = days,
+                None => simple = false,
+            },
+            // UNTIL is either a DATE or a DATE-TIME; the date part is enough
+            "UNTIL" => until = val.get(..8).and_then(|d| NaiveDate::parse_from_str(d, "%Y%m%d").ok()),
+            "COUNT" => match val.parse::<u32>() {
+                Ok(n) if n > 0 => count = Some(n),
+                _ => simple = false,
+            },
+            "WKST" => {}
+            _ => simple = false,
+        }
+    }
 
-        let mut ical = Calendar::new();
+    // Weekdays are only supported on weekly rules
+    if !weekdays.is_empty() && freq.as_deref() != Some("WEEKLY") {
+        simple = false;
+    }
+    weekdays.sort_by_key(|day| day.num_days_from_monday());
+    weekdays.dedup();
+
+    let (repeat, interval) = match (freq.as_deref(), interval, simple) {
+        (Some("WEEKLY"), 2, true) => (RepeatFrequency::Biweekly, 1),
+        (Some("DAILY"), n, true) => (RepeatFrequency::Daily, n),
+        (Some("WEEKLY"), n, true) => (RepeatFrequency::Weekly, n),
+        (Some("MONTHLY"), n, true) => (RepeatFrequency::Monthly, n),
+        (Some("YEARLY"), n, true) => (RepeatFrequency::Yearly, n),
+        (Some(_), _, _) => {
+            return ParsedRule {
+                repeat: RepeatFrequency::Custom(value.to_string()),
+                until,
+                count,
+                ..Default::default()
+            };
+        }
+        (None, _, _) => {
+            warn!("ExportHandler: Ignoring RRULE without FREQ: '{}'", value);
+            return ParsedRule::default();
+        }
+    };
+    ParsedRule { repeat, interval, weekdays, until, count }
+}
 
-        for event in events {
-            let mut ical_event = Event::new();
-            ical_event.summary(&event.summary);
-            ical_event.uid(&event.uid);
-            ical_event.starts(event.start);
-            ical_event.ends(event.end);
-
-            if let Some(ref location) = event.location {
-                ical_event.location(location);
+/// Parse an unsigned DURATION value such as "PT0S", "PT1H30M", "P1D" or "P1W"
+fn parse_duration(value: &str) -> Option<chrono::Duration> {
+    let rest = value.trim_start_matches('+').strip_prefix('P')?;
+    let mut total = chrono::Duration::zero();
+    let mut number = String::new();
+    let mut in_time = false;
+
+    for c in rest.chars() {
+        match c {
+            'T' => in_time = true,
+            '0'..='9' => number.push(c),
+            unit => {
+                let n: i64 = number.parse().ok()?;
+                number.clear();
+                total += match (unit, in_time) {
+                    ('W', false) => chrono::Duration::weeks(n),
+                    ('D', false) => chrono::Duration::days(n),
+                    ('H', true) => chrono::Duration::hours(n),
+                    ('M', true) => chrono::Duration::minutes(n),
+                    ('S', true) => chrono::Duration::seconds(n),
+                    _ => return None,
+                };
             }
+        }
+    }
 
-            if let Some(ref notes) = event.notes {
-                ical_event.description(notes);
-            }
+    number.is_empty().then_some(total)
+}
+
+/// Minutes before the start of a relative TRIGGER value ("-PT15M", "PT0S", "-P1D");
+/// negative for alerts after the start
+fn parse_trigger(value: &str) -> Option<i64> {
+    let value = value.trim();
+    let (sign, duration) = match value.strip_prefix('-') {
+        Some(rest) => (1, rest),
+        None => (-1, value.trim_start_matches('+')),
+    };
+    Some(sign * parse_duration(duration)?.num_minutes())
+}
+
+/// An occurrence named by RECURRENCE-ID or EXDATE: a wall-clock date, or a
+/// UTC instant whose date depends on the series' zone
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum OccurrenceTime {
+    Date(NaiveDate),
+    Utc(DateTime<Utc>),
+}
+
+impl OccurrenceTime {
+    /// Parse a DATE, a local DATE-TIME or a UTC DATE-TIME ("...Z")
+    fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        match value.strip_suffix('Z') {
+            Some(utc) => NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").ok().map(|dt| Self::Utc(dt.and_utc())),
+            None => value.get(..8).and_then(|d| NaiveDate::parse_from_str(d, "%Y%m%d").ok()).map(Self::Date),
+        }
+    }
 
-            if let Some(ref url) = event.url {
-                ical_event.url(url);
+    /// Date of the occurrence in the series' zone
+    fn date_in(self, zone: Option<&str>) -> NaiveDate {
+        match self {
+            Self::Date(date) => date,
+            Self::Utc(instant) => crate::timezone::wall_clock(instant, zone).date(),
+        }
+    }
+}
+
+/// VEVENT properties read from the raw text: the iCal library's accessors
+/// return one value per property name, which would drop repeated EXDATE and
+/// ATTENDEE lines, and don't reach into VALARM components
+#[derive(Debug, Default)]
+struct RawVevent {
+    recurrence_id: Option<OccurrenceTime>,
+    exception_dates: Vec<OccurrenceTime>,
+    /// Email addresses of the ATTENDEE lines
+    invitees: Vec<String>,
+    /// Minutes before the start of each VALARM with a relative trigger
+    alerts: Vec<i64>,
+}
+
+/// Scan the VEVENTs of an iCalendar string in document order.
+/// Properties of nested components are not the event's own; only the
+/// TRIGGER of a VALARM is read.
+fn scan_vevents(ical_str: &str) -> Vec<RawVevent> {
+    // Unfold continuation lines (RFC 5545 §3.1)
+    let unfolded = ical_str.replace("\r\n", "\n").replace("\n ", "").replace("\n\t", "");
+
+    let mut events = Vec::new();
+    let mut current: Option<RawVevent> = None;
+    let mut nested = 0;
+    let mut in_alarm = false;
+
+    for line in unfolded.lines() {
+        let line = line.trim_end();
+        let Some((name_and_params, value)) = line.split_once(':') else { continue };
+        let mut parts = name_and_params.split(';');
+        let name = parts.next().unwrap_or_default().to_ascii_uppercase();
+        let Some(event) = current.as_mut() else {
+            if name == "BEGIN" && value.eq_ignore_ascii_case("VEVENT") {
+                current = Some(RawVevent::default());
             }
+            continue;
+        };
 
-            ical.push(ical_event);
+        match name.as_str() {
+            "BEGIN" => {
+                nested += 1;
+                in_alarm = nested == 1 && value.eq_ignore_ascii_case("VALARM");
+            }
+            "END" if nested > 0 => {
+                nested -= 1;
+                in_alarm = false;
+            }
+            "END" => events.extend(current.take()),
+            // Triggers at a fixed time or relative to the end have no matching alert
+            "TRIGGER" if in_alarm => {
+                let relative_to_start = parts.all(|param| {
+                    !param.eq_ignore_ascii_case("VALUE=DATE-TIME") && !param.eq_ignore_ascii_case("RELATED=END")
+                });
+                if relative_to_start {
+                    event.alerts.extend(parse_trigger(value));
+                }
+            }
+            _ if nested > 0 => {}
+            "RECURRENCE-ID" => event.recurrence_id = OccurrenceTime::parse(value),
+            "EXDATE" => event.exception_dates.extend(value.split(',').filter_map(OccurrenceTime::parse)),
+            "ATTENDEE" => {
+                let address = value.trim();
+                let email = match address.get(..7) {
+                    Some(scheme) if scheme.eq_ignore_ascii_case("mailto:") => &address[7..],
+                    _ => address,
+                };
+                event.invitees.push(email.to_string());
+            }
+            _ => {}
         }
+    }
+
+    events
+}
+
+/// Largest end-before-start gap that is treated as a typo and clamped instead of rejected
+const MAX_CLAMPED_INVERSION_HOURS: i64 = 24;
+
+/// Check an imported event's times and fix small problems in place.
+///
+/// Events with years outside 1..=9999 or an end more than a day before the start
+/// are unusable and return false. A slightly inverted end is clamped to the
+/// default duration (1 hour, or 1 day for all-day events).
+pub fn sanitize_imported_event(event: &mut CalendarEvent) -> bool {
+    let year_ok = |dt: &DateTime<Utc>| (1..=9999).contains(&dt.year());
+    if !year_ok(&event.start) || !year_ok(&event.end) {
+        warn!("ExportHandler: Skipping event uid={} with out-of-range date ({} - {})",
+              event.uid, event.start, event.end);
+        return false;
+    }
 
-        info!("ExportHandler: Successfully exported calendar '{}'", calendar_id);
-        Ok(ical)
+    if event.end < event.start {
+        if event.start - event.end > chrono::Duration::hours(MAX_CLAMPED_INVERSION_HOURS) {
+            warn!("ExportHandler: Skipping event uid={} ending {} before it starts",
+                  event.uid, event.start - event.end);
+            return false;
+        }
+        let duration = if event.all_day { chrono::Duration::days(1) } else { chrono::Duration::hours(1) };
+        warn!("ExportHandler: Clamping end of event uid={} (ended before start)", event.uid);
+        event.end = event.start + duration;
     }
 
+    true
+}
+
+/// Export Handler - import/export operations.
+#[allow(dead_code)] // Foundation for future import/export feature
+pub struct ExportHandler;
+
+impl ExportHandler {
     /// Export a calendar to an iCalendar file
-    #[allow(dead_code)] // Part of export API
     pub fn export_to_file<P: AsRef<Path>>(
         manager: &CalendarManager,
         calendar_id: &str,
         path: P,
-    ) -> ExportResult<()> {
+    ) -> ExportResult<usize> {
         info!("ExportHandler: Exporting calendar '{}' to file {:?}", calendar_id, path.as_ref());
 
-        let ical = Self::calendar_to_ical(manager, calendar_id)?;
-        let ical_string = ical.to_string();
+        let calendar = manager
+            .sources()
+            .iter()
+            .find(|c| c.info().id == calendar_id)
+            .ok_or_else(|| {
+                error!("ExportHandler: Calendar '{}' not found", calendar_id);
+                ExportError::CalendarNotFound(calendar_id.to_string())
+            })?;
 
-        fs::write(&path, ical_string).map_err(|e| {
-            error!("ExportHandler: Failed to write file: {}", e);
+        let events = calendar.fetch_events().map_err(|e| {
+            error!("ExportHandler: Failed to fetch events: {}", e);
             ExportError::IoError(e.to_string())
         })?;
 
-        info!("ExportHandler: Successfully exported to {:?}", path.as_ref());
-        Ok(())
+        Self::write_events(&events, &path)?;
+        info!("ExportHandler: Exported {} events to {:?}", events.len(), path.as_ref());
+        Ok(events.len())
     }
 
-    /// Export all calendars to a single iCalendar file
-    #[allow(dead_code)] // Part of export API
+    /// Export all enabled calendars to a single iCalendar file
     pub fn export_all_to_file<P: AsRef<Path>>(
         manager: &CalendarManager,
         path: P,
-    ) -> ExportResult<()> {
+    ) -> ExportResult<usize> {
         info!("ExportHandler: Exporting all calendars to file {:?}", path.as_ref());
 
-        let mut combined = Calendar::new();
-        let mut total_events = 0;
-
+        let mut events = Vec::new();
         for calendar in manager.sources() {
             if !calendar.is_enabled() {
                 debug!("ExportHandler: Skipping disabled calendar '{}'", calendar.info().name);
                 continue;
             }
 
-            if let Ok(events) = calendar.fetch_events() {
-                debug!("ExportHandler: Adding {} events from '{}'", events.len(), calendar.info().name);
-                for event in events {
-                    let mut ical_event = Event::new();
-                    ical_event.summary(&event.summary);
-                    ical_event.uid(&event.uid);
-                    ical_event.starts(event.start);
-                    ical_event.ends(event.end);
-
-                    if let Some(ref location) = event.location {
-                        ical_event.location(location);
-                    }
+            if let Ok(calendar_events) = calendar.fetch_events() {
+                debug!("ExportHandler: Adding {} events from '{}'", calendar_events.len(), calendar.info().name);
+                events.extend(calendar_events);
+            }
+        }
 
-                    if let Some(ref notes) = event.notes {
-                        ical_event.description(notes);
-                    }
+        Self::write_events(&events, &path)?;
+        info!("ExportHandler: Exported {} events to {:?}", events.len(), path.as_ref());
+        Ok(events.len())
+    }
 
-                    combined.push(ical_event);
-                    total_events += 1;
-                }
+    /// Export the given events to a single iCalendar file
+    /// Events are identified by (calendar_id, uid); ones no longer found are skipped
+    pub fn export_events_to_file<P: AsRef<Path>>(
+        manager: &CalendarManager,
+        ids: &[(String, String)],
+        path: P,
+    ) -> ExportResult<usize> {
+        info!("ExportHandler: Exporting {} events to file {:?}", ids.len(), path.as_ref());
+
+        let mut events = Vec::new();
+        for calendar in manager.sources() {
+            if !ids.iter().any(|(calendar_id, _)| *calendar_id == calendar.info().id) {
+                continue;
+            }
+            if let Ok(calendar_events) = calendar.fetch_events() {
+                events.extend(calendar_events.into_iter().filter(|event| {
+                    ids.iter().any(|(calendar_id, uid)| *calendar_id == calendar.info().id && *uid == event.uid)
+                }));
             }
         }
 
-        let ical_string = combined.to_string();
+        Self::write_events(&events, &path)?;
+        info!("ExportHandler: Exported {} events to {:?}", events.len(), path.as_ref());
+        Ok(events.len())
+    }
+
+    /// Serialize events to RFC 5545 text and write them to `path`
+    fn write_events<P: AsRef<Path>>(events: &[CalendarEvent], path: P) -> ExportResult<()> {
+        let ical_string = events_to_ics(events, Utc::now());
         fs::write(&path, ical_string).map_err(|e| {
             error!("ExportHandler: Failed to write file: {}", e);
             ExportError::IoError(e.to_string())
-        })?;
-
-        info!("ExportHandler: Exported {} events to {:?}", total_events, path.as_ref());
-        Ok(())
+        })
     }
 
     /// Read an iCalendar file (placeholder for future import functionality)
@@ -227,6 +573,11 @@ impl ExportHandler {
     /// Parse an iCalendar string and return a list of events
     #[allow(dead_code)] // Part of import API
     pub fn parse_ical_string(ical_str: &str) -> ExportResult<Vec<CalendarEvent>> {
+        Self::parse_ical_events(ical_str).map(|(events, _)| events)
+    }
+
+    /// Parse an iCalendar string into its events and the number of VEVENTs skipped as unreadable
+    fn parse_ical_events(ical_str: &str) -> ExportResult<(Vec<CalendarEvent>, usize)> {
         debug!("ExportHandler: Parsing iCal string ({} bytes)", ical_str.len());
 
         let calendar = ical_str.parse::<Calendar>().map_err(|e| {
@@ -234,21 +585,77 @@ impl ExportHandler {
             ExportError::ParseError(e.to_string())
         })?;
 
+        let (events, skipped) = Self::calendar_events(calendar, ical_str);
+        info!("ExportHandler: Successfully parsed {} events (skipped {} invalid)", events.len(), skipped);
+        Ok((events, skipped))
+    }
+
+    /// Events of a parsed calendar plus busy blocks from its VFREEBUSY periods.
+    /// Events that can't be converted or fail sanitizing are left out and counted.
+    fn calendar_events(calendar: Calendar, ical_str: &str) -> (Vec<CalendarEvent>, usize) {
+        // Custom TZIDs defined by embedded VTIMEZONE blocks
+        let zones = crate::timezone::parse_vtimezones(ical_str);
+
+        // Properties read from the raw text, one entry per VEVENT in document order
+        let mut raw_events = scan_vevents(ical_str).into_iter();
+
         let mut events = Vec::new();
+        let mut modified = Vec::new();
+        let mut skipped = 0;
         for component in calendar.components {
             if let icalendar::CalendarComponent::Event(ical_event) = component {
-                match Self::ical_event_to_calendar_event(&ical_event) {
-                    Ok(event) => events.push(event),
+                let raw = raw_events.next().unwrap_or_default();
+                match Self::ical_event_to_calendar_event(&ical_event, &zones) {
+                    Ok(mut event) => {
+                        let zone = event_zone(&event);
+                        let exception_dates = raw.exception_dates.iter().map(|date| date.date_in(zone)).collect();
+                        event.exception_dates = exception_dates;
+                        event.invitees = raw.invitees;
+                        let all_day = event.all_day;
+                        let mut alerts = raw.alerts.into_iter().map(|minutes| AlertTime::from_trigger_minutes(minutes, all_day));
+                        event.alert = alerts.next().unwrap_or_default();
+                        event.alert_second = alerts.next();
+                        if !sanitize_imported_event(&mut event) {
+                            skipped += 1;
+                        } else if let Some(recurrence_id) = raw.recurrence_id {
+                            modified.push((recurrence_id, event));
+                        } else {
+                            events.push(event);
+                        }
+                    }
                     Err(e) => {
                         warn!("ExportHandler: Skipping invalid event: {}", e);
-                        continue;
+                        skipped += 1;
                     }
                 }
             }
         }
 
-        info!("ExportHandler: Successfully parsed {} events", events.len());
-        Ok(events)
+        Self::attach_overrides(&mut events, modified);
+
+        // Free/busy feeds carry VFREEBUSY instead of VEVENT - turn periods into busy blocks
+        events.extend(Self::parse_freebusy_periods(ical_str).into_iter().map(Self::busy_period_to_event));
+        (events, skipped)
+    }
+
+    /// Attach modified occurrences (VEVENTs with a RECURRENCE-ID) to their series
+    /// as overrides named `<uid>_YYYYMMDD`. Occurrences whose series isn't in
+    /// the file are kept as standalone events.
+    fn attach_overrides(events: &mut Vec<CalendarEvent>, modified: Vec<(OccurrenceTime, CalendarEvent)>) {
+        for (recurrence_id, mut occurrence) in modified {
+            let series = events
+                .iter_mut()
+                .find(|e| e.uid == occurrence.uid && !matches!(e.repeat, RepeatFrequency::Never));
+            let Some(series) = series else {
+                warn!("ExportHandler: No series for modified occurrence uid={}, keeping it as an event", occurrence.uid);
+                events.push(occurrence);
+                continue;
+            };
+            let date = recurrence_id.date_in(event_zone(series));
+            occurrence.uid = format!("{}_{}", series.uid, date.format("%Y%m%d"));
+            series.overrides.retain(|o| o.uid != occurrence.uid);
+            series.overrides.push(occurrence);
+        }
     }
 
     /// Parse iCalendar string and extract calendar name and events
@@ -271,26 +678,16 @@ impl ExportHandler {
 
         debug!("ExportHandler: Extracted calendar name: {}", calendar_name);
 
-        let mut events = Vec::new();
-        for component in calendar.components {
-            if let icalendar::CalendarComponent::Event(ical_event) = component {
-                match Self::ical_event_to_calendar_event(&ical_event) {
-                    Ok(event) => events.push(event),
-                    Err(e) => {
-                        warn!("ExportHandler: Skipping invalid event: {}", e);
-                        continue;
-                    }
-                }
-            }
-        }
+        let (events, _) = Self::calendar_events(calendar, ical_str);
 
         info!("ExportHandler: Successfully parsed calendar '{}' with {} events", calendar_name, events.len());
         Ok((calendar_name, events))
     }
 
     /// Convert an icalendar::Event to a CalendarEvent
+    /// TZIDs are resolved through `zones` (embedded VTIMEZONE definitions) before the IANA database
     #[allow(dead_code)] // Part of import API
-    fn ical_event_to_calendar_event(ical_event: &Event) -> ExportResult<CalendarEvent> {
+    fn ical_event_to_calendar_event(ical_event: &Event, zones: &[CustomZone]) -> ExportResult<CalendarEvent> {
         // Extract UID (required)
         let uid = ical_event
             .get_uid()
@@ -309,12 +706,15 @@ impl ExportHandler {
             })?
             .to_string();
 
-        // Extract start time (required)
+        // Extract start time (required). Without it there is no date to put the event on,
+        // so the event is skipped rather than guessed
         let start_prop = ical_event.get_start().ok_or_else(|| {
-            error!("ExportHandler: Event uid={} missing start time", uid);
-            ExportError::ParseError(format!("Event uid={} missing start time", uid))
+            ExportError::ParseError(format!("Event uid={} missing DTSTART", uid))
         })?;
 
+        // IANA zone of DTSTART, kept so display can flag unresolvable zones
+        let mut timezone = None;
+
         let (start, all_day) = match start_prop {
             DatePerhapsTime::DateTime(cal_dt) => {
                 // Convert CalendarDateTime to chrono DateTime<Utc>
@@ -322,9 +722,22 @@ impl ExportHandler {
                     icalendar::CalendarDateTime::Floating(dt) => {
                         (DateTime::from_naive_utc_and_offset(dt, Utc), false)
                     }
-                    icalendar::CalendarDateTime::Utc(dt) => (dt, false),
-                    icalendar::CalendarDateTime::WithTimezone { date_time, .. } => {
-                        (DateTime::from_naive_utc_and_offset(date_time, Utc), false)
+                    icalendar::CalendarDateTime::Utc(dt) => {
+                        // UTC instants shift with the viewer's zone, unlike floating times
+                        timezone = Some(crate::timezone::UTC_ZONE.to_string());
+                        (dt, false)
+                    }
+                    icalendar::CalendarDateTime::WithTimezone { date_time, tzid } => {
+                        let (dt, _) = crate::timezone::local_to_utc_with_zones(date_time, &tzid, zones);
+                        // Only IANA names are kept: custom zones exist just in this file,
+                        // so their times, already resolved above, are kept as UTC instants
+                        let is_custom = zones.iter().any(|zone| zone.tzid == tzid);
+                        if is_custom && crate::timezone::lookup(&tzid).is_none() {
+                            timezone = Some(crate::timezone::UTC_ZONE.to_string());
+                        } else {
+                            timezone = Some(tzid);
+                        }
+                        (dt, false)
                     }
                 }
             }
@@ -347,8 +760,8 @@ impl ExportHandler {
                             DateTime::from_naive_utc_and_offset(dt, Utc)
                         }
                         icalendar::CalendarDateTime::Utc(dt) => dt,
-                        icalendar::CalendarDateTime::WithTimezone { date_time, .. } => {
-                            DateTime::from_naive_utc_and_offset(date_time, Utc)
+                        icalendar::CalendarDateTime::WithTimezone { date_time, tzid } => {
+                            crate::timezone::local_to_utc_with_zones(date_time, &tzid, zones).0
                         }
                     }
                 }
@@ -362,11 +775,25 @@ impl ExportHandler {
         } else {
             start + chrono::Duration::hours(1)
         };
+        let end = if all_day { inclusive_all_day_end(start, end) } else { end };
 
         // Extract optional fields
         let location = ical_event.get_location().map(|s| s.to_string());
         let notes = ical_event.get_description().map(|s| s.to_string());
         let url = ical_event.get_url().map(|s| s.to_string());
+        let status = ical_event.get_status().map(EventStatus::from_ical).unwrap_or_default();
+        let categories = ical_event
+            .property_value("CATEGORIES")
+            .map(parse_categories)
+            .unwrap_or_default();
+        let color = ical_event.property_value("COLOR").and_then(|value| {
+            let color = parse_ical_color(value);
+            if color.is_none() {
+                warn!("ExportHandler: Ignoring unknown COLOR '{}' on event uid={}", value, uid);
+            }
+            color
+        });
+        let rule = ical_event.property_value("RRULE").map(parse_rrule).unwrap_or_default();
 
         debug!("ExportHandler: Parsed event uid={}", uid);
 
@@ -378,8 +805,11 @@ impl ExportHandler {
             start,
             end,
             travel_time: TravelTime::None,
-            repeat: RepeatFrequency::Never,
-            repeat_until: None,
+            repeat: rule.repeat,
+            repeat_until: rule.until,
+            repeat_count: rule.count,
+            repeat_interval: rule.interval,
+            repeat_weekdays: rule.weekdays,
             exception_dates: vec![],
             invitees: vec![],
             alert: AlertTime::None,
@@ -387,21 +817,137 @@ impl ExportHandler {
             attachments: vec![],
             url,
             notes,
+            status,
+            timezone,
+            categories,
+            color,
+            overrides: vec![],
         })
     }
 
-    /// Import events from a file into a specific calendar
-    /// Returns the number of events imported (skips duplicates based on UID)
+    /// Extract busy periods from all VFREEBUSY components in an iCalendar string.
+    /// Periods with FBTYPE=FREE are ignored; other FBTYPE values count as busy.
+    pub fn parse_freebusy_periods(ical_str: &str) -> Vec<BusyPeriod> {
+        // Unfold continuation lines (RFC 5545 §3.1)
+        let unfolded = ical_str.replace("\r\n", "\n").replace("\n ", "").replace("\n\t", "");
+
+        let mut periods = Vec::new();
+        let mut in_freebusy = false;
+
+        for line in unfolded.lines() {
+            let line = line.trim_end();
+            if line.eq_ignore_ascii_case("BEGIN:VFREEBUSY") {
+                in_freebusy = true;
+                continue;
+            }
+            if line.eq_ignore_ascii_case("END:VFREEBUSY") {
+                in_freebusy = false;
+                continue;
+            }
+            if !in_freebusy {
+                continue;
+            }
+
+            let Some((name_and_params, value)) = line.split_once(':') else { continue };
+            let mut parts = name_and_params.split(';');
+            if !parts.next().is_some_and(|name| name.eq_ignore_ascii_case("FREEBUSY")) {
+                continue;
+            }
+            let is_free = parts.any(|param| param.eq_ignore_ascii_case("FBTYPE=FREE"));
+            if is_free {
+                continue;
+            }
+
+            for period in value.split(',') {
+                match Self::parse_period(period.trim()) {
+                    Some(busy) => periods.push(busy),
+                    None => warn!("ExportHandler: Skipping invalid FREEBUSY period"),
+                }
+            }
+        }
+
+        debug!("ExportHandler: Parsed {} busy periods", periods.len());
+        periods
+    }
+
+    /// Parse a PERIOD value: "start/end" or "start/duration"
+    fn parse_period(period: &str) -> Option<BusyPeriod> {
+        let (start_str, end_str) = period.split_once('/')?;
+        let start = Self::parse_ical_datetime(start_str)?;
+        let end = if end_str.starts_with('P') || end_str.starts_with('+') {
+            start + Self::parse_ical_duration(end_str)?
+        } else {
+            Self::parse_ical_datetime(end_str)?
+        };
+
+        (end > start).then_some(BusyPeriod { start, end })
+    }
+
+    /// Parse a DATE-TIME value in UTC ("...Z") or floating form (treated as UTC)
+    fn parse_ical_datetime(value: &str) -> Option<DateTime<Utc>> {
+        let value = value.trim_end_matches('Z');
+        NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S")
+            .ok()
+            .map(|dt| DateTime::from_naive_utc_and_offset(dt, Utc))
+    }
+
+    /// Parse a positive DURATION value such as "PT2H", "PT1H30M", "P1D" or "P1W"
+    fn parse_ical_duration(value: &str) -> Option<chrono::Duration> {
+        parse_duration(value).filter(|duration| *duration > chrono::Duration::zero())
+    }
+
+    /// Convert a busy period into a synthetic "Busy" event.
+    /// The UID is derived from the period so re-importing the same feed skips duplicates.
+    fn busy_period_to_event(period: BusyPeriod) -> CalendarEvent {
+        CalendarEvent {
+            uid: format!("{}{}-{}", BUSY_EVENT_UID_PREFIX, period.start.timestamp(), period.end.timestamp()),
+            summary: "Busy".to_string(),
+            location: None,
+            all_day: false,
+            start: period.start,
+            end: period.end,
+            travel_time: TravelTime::None,
+            repeat: RepeatFrequency::Never,
+            repeat_until: None,
+            repeat_count: None,
+            repeat_interval: 1,
+            repeat_weekdays: vec![],
+            exception_dates: vec![],
+            invitees: vec![],
+            alert: AlertTime::None,
+            alert_second: None,
+            attachments: vec![],
+            url: None,
+            notes: None,
+            status: EventStatus::Confirmed,
+            timezone: None,
+            categories: vec![],
+            color: None,
+            overrides: vec![],
+        }
+    }
+
+    /// Import events from a file into a specific calendar, skipping duplicates by UID.
+    /// With `dry_run` the file is parsed and deduplicated but nothing is inserted,
+    /// so the returned counts can be shown before committing.
     #[allow(dead_code)] // Part of import API
-    pub fn import_from_file<P: AsRef<Path>>(
+    pub fn import_ics_file<P: AsRef<Path>>(
         manager: &mut CalendarManager,
         calendar_id: &str,
         path: P,
-    ) -> ExportResult<usize> {
-        info!("ExportHandler: Importing events from {:?} into calendar '{}'", path.as_ref(), calendar_id);
-
-        // Parse the file
-        let events = Self::parse_ical_file(&path)?;
+        dry_run: bool,
+    ) -> ExportResult<ImportSummary> {
+        info!(
+            "ExportHandler: Importing events from {:?} into calendar '{}' (dry_run={})",
+            path.as_ref(),
+            calendar_id,
+            dry_run
+        );
+
+        // Parse the file; unreadable events count as skipped
+        info!("ExportHandler: Parsing iCal file {:?}", path.as_ref());
+        let ical_string = Self::read_ical_file(&path)?;
+        let (events, invalid) = Self::parse_ical_events(&ical_string)?;
 
         // Get the target calendar
         let calendar = manager
@@ -413,33 +959,50 @@ impl ExportHandler {
                 ExportError::CalendarNotFound(calendar_id.to_string())
             })?;
 
+        let mut summary = Self::import_events(calendar.as_mut(), &events, dry_run)?;
+        summary.skipped += invalid;
+        Ok(summary)
+    }
+
+    /// Add parsed events to a calendar, skipping UIDs it already contains
+    /// (or that appear earlier in `events`). With `dry_run` only the counts are computed.
+    pub fn import_events(
+        calendar: &mut dyn CalendarSource,
+        events: &[CalendarEvent],
+        dry_run: bool,
+    ) -> ExportResult<ImportSummary> {
         // Get existing event UIDs to detect duplicates
         let existing_events = calendar.fetch_events().map_err(|e| {
             error!("ExportHandler: Failed to fetch existing events: {}", e);
             ExportError::IoError(e.to_string())
         })?;
-        let existing_uids: std::collections::HashSet<_> =
-            existing_events.iter().map(|e| e.uid.as_str()).collect();
+        let mut known_uids: HashSet<String> =
+            existing_events.into_iter().map(|e| e.uid).collect();
 
-        // Import events, skipping duplicates
-        let mut imported_count = 0;
-        let total_events = events.len();
+        let mut summary = ImportSummary::default();
         for event in events {
-            if existing_uids.contains(event.uid.as_str()) {
+            if !known_uids.insert(event.uid.clone()) {
                 debug!("ExportHandler: Skipping duplicate event uid={}", event.uid);
+                summary.skipped += 1;
                 continue;
             }
 
-            calendar.add_event(event).map_err(|e| {
-                error!("ExportHandler: Failed to add event: {}", e);
-                ExportError::IoError(e.to_string())
-            })?;
-            imported_count += 1;
+            if !dry_run {
+                calendar.add_event(event.clone()).map_err(|e| {
+                    error!("ExportHandler: Failed to add event: {}", e);
+                    ExportError::IoError(e.to_string())
+                })?;
+            }
+            summary.added += 1;
         }
 
-        info!("ExportHandler: Successfully imported {} events (skipped {} duplicates)",
-              imported_count, total_events - imported_count);
-        Ok(imported_count)
+        info!(
+            "ExportHandler: {} {} events (skipped {} duplicates)",
+            if dry_run { "Would import" } else { "Imported" },
+            summary.added,
+            summary.skipped
+        );
+        Ok(summary)
     }
 
     /// Validate an iCalendar file for RFC 5545 compliance
@@ -514,12 +1077,12 @@ impl ExportHandler {
             warn!("ExportHandler: Event uid={} missing DTSTAMP (RFC 5545 §3.8.7.2) - continuing anyway", uid);
         }
 
-        // DTSTART is required for most events (RFC 5545 §3.8.2.4)
-        let start = event.get_start().ok_or_else(|| {
-            ExportError::ValidationError(format!(
-                "Event uid={} missing required DTSTART property (RFC 5545 §3.8.2.4)", uid
-            ))
-        })?;
+        // DTSTART is required for most events (RFC 5545 §3.8.2.4).
+        // One broken event shouldn't block the file - parsing skips it
+        let Some(start) = event.get_start() else {
+            warn!("ExportHandler: Event uid={} missing DTSTART (RFC 5545 §3.8.2.4) - it will be skipped", uid);
+            return Ok(());
+        };
 
         // If DTEND exists, validate it's after DTSTART
         if let Some(end) = event.get_end() {
@@ -581,35 +1144,28 @@ impl ExportHandler {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::caldav::{AlertTime, RepeatFrequency, TravelTime};
     use chrono::{TimeZone, Utc};
 
+    /// Export a single event with the RFC 5545 serializer
+    fn to_ics(event: &CalendarEvent) -> String {
+        events_to_ics(std::slice::from_ref(event), Utc::now())
+    }
+
     fn create_test_event() -> CalendarEvent {
         CalendarEvent {
             uid: "test-export-1".to_string(),
             summary: "Test Export Event".to_string(),
             location: Some("Test Location".to_string()),
-            all_day: false,
             start: Utc.with_ymd_and_hms(2025, 12, 1, 10, 0, 0).unwrap(),
             end: Utc.with_ymd_and_hms(2025, 12, 1, 11, 0, 0).unwrap(),
-            travel_time: TravelTime::None,
-            repeat: RepeatFrequency::Never,
-            repeat_until: None,
-            exception_dates: vec![],
-            invitees: vec![],
-            alert: AlertTime::None,
-            alert_second: None,
-            attachments: vec![],
-            url: None,
             notes: Some("Test notes".to_string()),
+            ..CalendarEvent::test_default()
         }
     }
 
     #[test]
-    fn test_event_to_ical() {
-        let event = create_test_event();
-        let ical = ExportHandler::event_to_ical(&event);
-        let ical_string = ical.to_string();
+    fn test_export_single_event() {
+        let ical_string = to_ics(&create_test_event());
 
         assert!(ical_string.contains("BEGIN:VCALENDAR"));
         assert!(ical_string.contains("BEGIN:VEVENT"));
@@ -617,4 +1173,440 @@ mod tests {
         assert!(ical_string.contains("END:VEVENT"));
         assert!(ical_string.contains("END:VCALENDAR"));
     }
+
+    #[test]
+    fn test_status_round_trip() {
+        for status in [EventStatus::Confirmed, EventStatus::Tentative, EventStatus::Cancelled] {
+            let event = CalendarEvent { status, ..create_test_event() };
+            let ical_string = to_ics(&event);
+
+            let events = ExportHandler::parse_ical_string(&ical_string).unwrap();
+            assert_eq!(events.len(), 1);
+            assert_eq!(events[0].status, status);
+        }
+
+        // Events without STATUS default to confirmed
+        let ical = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+BEGIN:VEVENT\r\n\
+UID:no-status\r\n\
+SUMMARY:No Status\r\n\
+DTSTART:20251201T100000Z\r\n\
+DTEND:20251201T110000Z\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+        let events = ExportHandler::parse_ical_string(ical).unwrap();
+        assert_eq!(events[0].status, EventStatus::Confirmed);
+    }
+
+    #[test]
+    fn test_categories_round_trip() {
+        let categories = vec!["Work".to_string(), "Travel, Europe".to_string()];
+        let event = CalendarEvent { categories: categories.clone(), ..create_test_event() };
+        let ical_string = to_ics(&event);
+
+        let events = ExportHandler::parse_ical_string(&ical_string).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].categories, categories);
+
+        // Events without CATEGORIES have no tags
+        let events = ExportHandler::parse_ical_string(
+            &to_ics(&create_test_event())
+        ).unwrap();
+        assert!(events[0].categories.is_empty());
+    }
+
+    #[test]
+    fn test_parse_categories() {
+        assert_eq!(parse_categories("Work, Travel ,,Home"), vec!["Work", "Travel", "Home"]);
+        assert_eq!(parse_categories("Q1\\, Q2,Review"), vec!["Q1, Q2", "Review"]);
+        assert!(parse_categories("").is_empty());
+    }
+
+    #[test]
+    fn test_import_resolves_tzid() {
+        let ical = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+BEGIN:VEVENT\r\n\
+UID:tz-known\r\n\
+SUMMARY:Known Zone\r\n\
+DTSTART;TZID=Europe/Berlin:20251201T100000\r\n\
+DTEND;TZID=Europe/Berlin:20251201T110000\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:tz-unknown\r\n\
+SUMMARY:Unknown Zone\r\n\
+DTSTART;TZID=Obsolete/Zone:20251201T100000\r\n\
+DTEND;TZID=Obsolete/Zone:20251201T110000\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+        let events = ExportHandler::parse_ical_string(ical).unwrap();
+        assert_eq!(events.len(), 2);
+
+        // Berlin is UTC+1 in December
+        assert_eq!(events[0].start, Utc.with_ymd_and_hms(2025, 12, 1, 9, 0, 0).unwrap());
+        assert_eq!(events[0].timezone.as_deref(), Some("Europe/Berlin"));
+
+        // Unknown zones are read as UTC and keep their name for the display indicator
+        assert_eq!(events[1].start, Utc.with_ymd_and_hms(2025, 12, 1, 10, 0, 0).unwrap());
+        assert_eq!(events[1].end, Utc.with_ymd_and_hms(2025, 12, 1, 11, 0, 0).unwrap());
+        assert!(crate::timezone::is_fallback(events[1].timezone.as_deref()));
+    }
+
+    #[test]
+    fn test_import_resolves_custom_vtimezone() {
+        let ical = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+BEGIN:VTIMEZONE\r\n\
+TZID:Office Time\r\n\
+BEGIN:STANDARD\r\n\
+DTSTART:19701025T030000\r\n\
+TZOFFSETFROM:+0200\r\n\
+TZOFFSETTO:+0100\r\n\
+RRULE:FREQ=YEARLY;BYMONTH=10;BYDAY=-1SU\r\n\
+END:STANDARD\r\n\
+BEGIN:DAYLIGHT\r\n\
+DTSTART:19700329T020000\r\n\
+TZOFFSETFROM:+0100\r\n\
+TZOFFSETTO:+0200\r\n\
+RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=-1SU\r\n\
+END:DAYLIGHT\r\n\
+END:VTIMEZONE\r\n\
+BEGIN:VEVENT\r\n\
+UID:tz-custom\r\n\
+SUMMARY:Custom Zone\r\n\
+DTSTART;TZID=Office Time:20250701T100000\r\n\
+DTEND;TZID=Office Time:20250701T110000\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+        let events = ExportHandler::parse_ical_string(ical).unwrap();
+        assert_eq!(events.len(), 1);
+
+        // Daylight offset +02:00 from the embedded definition, not a UTC fallback
+        assert_eq!(events[0].start, Utc.with_ymd_and_hms(2025, 7, 1, 8, 0, 0).unwrap());
+        assert_eq!(events[0].end, Utc.with_ymd_and_hms(2025, 7, 1, 9, 0, 0).unwrap());
+        // The zone only exists in the file, so the instant is kept in UTC rather than as floating time
+        assert_eq!(events[0].timezone.as_deref(), Some(crate::timezone::UTC_ZONE));
+    }
+
+    #[test]
+    fn test_import_attaches_modified_occurrences_to_their_series() {
+        // The modified occurrence comes first, as some servers write them
+        let ical = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+BEGIN:VEVENT\r\n\
+UID:standup\r\n\
+SUMMARY:Standup (moved)\r\n\
+RECURRENCE-ID;TZID=Europe/Berlin:20251203T090000\r\n\
+DTSTART;TZID=Europe/Berlin:20251203T110000\r\n\
+DTEND;TZID=Europe/Berlin:20251203T113000\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:standup\r\n\
+SUMMARY:Standup\r\n\
+DTSTART;TZID=Europe/Berlin:20251201T090000\r\n\
+DTEND;TZID=Europe/Berlin:20251201T093000\r\n\
+RRULE:FREQ=DAILY\r\n\
+EXDATE:20251204T080000Z,20251205T080000Z\r\n\
+EXDATE;TZID=Europe/Berlin:20251208T090000\r\n\
+BEGIN:VALARM\r\n\
+ACTION:DISPLAY\r\n\
+TRIGGER:-PT5M\r\n\
+END:VALARM\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:orphan\r\n\
+SUMMARY:Orphan\r\n\
+RECURRENCE-ID:20251210T090000Z\r\n\
+DTSTART:20251210T100000Z\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+        let events = ExportHandler::parse_ical_string(ical).unwrap();
+        assert_eq!(events.len(), 2);
+
+        let series = &events[0];
+        assert_eq!(series.summary, "Standup");
+        assert_eq!(
+            series.exception_dates,
+            vec![
+                NaiveDate::from_ymd_opt(2025, 12, 4).unwrap(),
+                NaiveDate::from_ymd_opt(2025, 12, 5).unwrap(),
+                NaiveDate::from_ymd_opt(2025, 12, 8).unwrap(),
+            ]
+        );
+        assert_eq!(series.overrides.len(), 1);
+        assert_eq!(series.overrides[0].uid, "standup_20251203");
+        assert_eq!(series.overrides[0].summary, "Standup (moved)");
+        assert_eq!(series.overrides[0].start, Utc.with_ymd_and_hms(2025, 12, 3, 10, 0, 0).unwrap());
+
+        // A modified occurrence without its series stays a plain event
+        assert_eq!(events[1].uid, "orphan");
+        assert!(events[1].overrides.is_empty());
+    }
+
+    #[test]
+    fn test_parse_freebusy_periods() {
+        let ical = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//Test//EN\r\n\
+BEGIN:VFREEBUSY\r\n\
+UID:fb-1\r\n\
+FREEBUSY;FBTYPE=BUSY:20251201T090000Z/20251201T100000Z,20251201T130000Z/PT1H30M\r\n\
+FREEBUSY;FBTYPE=FREE:20251201T140000Z/20251201T150000Z\r\n\
+FREEBUSY:20251202T080000Z/P\r\n \
+T2H\r\n\
+END:VFREEBUSY\r\n\
+END:VCALENDAR\r\n";
+
+        let periods = ExportHandler::parse_freebusy_periods(ical);
+        assert_eq!(
+            periods,
+            vec![
+                BusyPeriod {
+                    start: Utc.with_ymd_and_hms(2025, 12, 1, 9, 0, 0).unwrap(),
+                    end: Utc.with_ymd_and_hms(2025, 12, 1, 10, 0, 0).unwrap(),
+                },
+                BusyPeriod {
+                    start: Utc.with_ymd_and_hms(2025, 12, 1, 13, 0, 0).unwrap(),
+                    end: Utc.with_ymd_and_hms(2025, 12, 1, 14, 30, 0).unwrap(),
+                },
+                BusyPeriod {
+                    start: Utc.with_ymd_and_hms(2025, 12, 2, 8, 0, 0).unwrap(),
+                    end: Utc.with_ymd_and_hms(2025, 12, 2, 10, 0, 0).unwrap(),
+                },
+            ]
+        );
+
+        // Busy periods become synthetic "Busy" events alongside regular VEVENTs
+        let events = ExportHandler::parse_ical_string(ical).unwrap();
+        assert_eq!(events.len(), 3);
+        assert!(events.iter().all(|e| e.summary == "Busy" && e.uid.starts_with(BUSY_EVENT_UID_PREFIX)));
+    }
+
+    #[test]
+    fn test_sanitize_rejects_out_of_range_years() {
+        let mut event = create_test_event();
+        assert!(sanitize_imported_event(&mut event));
+
+        event.start = Utc.with_ymd_and_hms(0, 12, 1, 10, 0, 0).unwrap();
+        assert!(!sanitize_imported_event(&mut event));
+
+        let mut event = create_test_event();
+        event.end = Utc.with_ymd_and_hms(10000, 1, 1, 0, 0, 0).unwrap();
+        assert!(!sanitize_imported_event(&mut event));
+    }
+
+    #[test]
+    fn test_sanitize_inverted_times() {
+        // Slightly inverted: end is clamped to the default duration
+        let mut event = create_test_event();
+        event.end = Utc.with_ymd_and_hms(2025, 12, 1, 9, 30, 0).unwrap();
+        assert!(sanitize_imported_event(&mut event));
+        assert_eq!(event.end, Utc.with_ymd_and_hms(2025, 12, 1, 11, 0, 0).unwrap());
+
+        // All-day events get a one-day span
+        let mut event = create_test_event();
+        event.all_day = true;
+        event.start = Utc.with_ymd_and_hms(2025, 12, 1, 0, 0, 0).unwrap();
+        event.end = Utc.with_ymd_and_hms(2025, 11, 30, 12, 0, 0).unwrap();
+        assert!(sanitize_imported_event(&mut event));
+        assert_eq!(event.end, Utc.with_ymd_and_hms(2025, 12, 2, 0, 0, 0).unwrap());
+
+        // Wildly inverted: rejected
+        let mut event = create_test_event();
+        event.end = Utc.with_ymd_and_hms(2024, 12, 1, 11, 0, 0).unwrap();
+        assert!(!sanitize_imported_event(&mut event));
+    }
+
+    #[test]
+    fn test_parse_skips_event_ending_long_before_start() {
+        let ical = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//Test//EN\r\n\
+BEGIN:VEVENT\r\n\
+UID:ok\r\n\
+SUMMARY:Fine\r\n\
+DTSTART:20251201T100000Z\r\n\
+DTEND:20251201T110000Z\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:broken\r\n\
+SUMMARY:Broken\r\n\
+DTSTART:20251201T100000Z\r\n\
+DTEND:20200101T110000Z\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        let events = ExportHandler::parse_ical_string(ical).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].uid, "ok");
+    }
+
+    #[test]
+    fn test_import_dry_run_counts_without_inserting() {
+        use crate::calendars::LocalCalendar;
+        use crate::database::Database;
+        use std::sync::{Arc, Mutex};
+
+        let db_path = std::env::temp_dir().join("sol_test_import_dry_run.db");
+        let _ = std::fs::remove_file(&db_path);
+        let db = Arc::new(Mutex::new(Database::open_at(db_path.clone()).unwrap()));
+        let mut calendar = LocalCalendar::new("import-target".to_string(), "Target".to_string(), db.clone());
+        calendar.add_event(create_test_event()).unwrap();
+
+        // One UID already in the calendar, one repeated within the file, two new
+        let mut events = vec![create_test_event()];
+        for uid in ["new-1", "new-2", "new-1"] {
+            let mut event = create_test_event();
+            event.uid = uid.to_string();
+            events.push(event);
+        }
+
+        let preview = ExportHandler::import_events(&mut calendar, &events, true).unwrap();
+        assert_eq!(preview, ImportSummary { added: 2, skipped: 2 });
+        assert_eq!(calendar.fetch_events().unwrap().len(), 1);
+        assert_eq!(db.lock().unwrap().get_events_for_calendar("import-target").unwrap().len(), 1);
+
+        // The real import matches the preview
+        let summary = ExportHandler::import_events(&mut calendar, &events, false).unwrap();
+        assert_eq!(summary, preview);
+        assert_eq!(db.lock().unwrap().get_events_for_calendar("import-target").unwrap().len(), 3);
+
+        let _ = std::fs::remove_file(&db_path);
+    }
+
+    #[test]
+    fn test_import_skips_and_counts_event_without_dtstart() {
+        use crate::calendars::LocalCalendar;
+        use crate::database::Database;
+        use std::sync::{Arc, Mutex};
+
+        let ical = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//Test//EN\r\n\
+BEGIN:VEVENT\r\n\
+UID:ok\r\n\
+SUMMARY:Fine\r\n\
+DTSTART:20251201T100000Z\r\n\
+DTEND:20251201T110000Z\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:no-start\r\n\
+SUMMARY:No start\r\n\
+DTEND:20251201T110000Z\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        // The file as a whole still validates
+        assert!(ExportHandler::validate_ical_string(ical).is_ok());
+
+        let ics_path = std::env::temp_dir().join("sol_test_import_no_dtstart.ics");
+        std::fs::write(&ics_path, ical).unwrap();
+        let db_path = std::env::temp_dir().join("sol_test_import_no_dtstart.db");
+        let _ = std::fs::remove_file(&db_path);
+        let db = Arc::new(Mutex::new(Database::open_at(db_path.clone()).unwrap()));
+        let mut manager = CalendarManager::with_database(db.clone());
+        manager.add_source(Box::new(LocalCalendar::new("import-target".to_string(), "Target".to_string(), db.clone())));
+
+        let summary = ExportHandler::import_ics_file(&mut manager, "import-target", &ics_path, false).unwrap();
+        assert_eq!(summary, ImportSummary { added: 1, skipped: 1 });
+        let stored = db.lock().unwrap().get_events_for_calendar("import-target").unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].uid, "ok");
+
+        let _ = std::fs::remove_file(&ics_path);
+        let _ = std::fs::remove_file(&db_path);
+    }
+
+    #[test]
+    fn test_import_maps_rrule_and_all_day() {
+        let ical = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//Test//EN\r\n\
+BEGIN:VEVENT\r\n\
+UID:standup\r\n\
+SUMMARY:Standup\r\n\
+DTSTART:20251201T090000Z\r\n\
+DTEND:20251201T091500Z\r\n\
+RRULE:FREQ=WEEKLY;INTERVAL=2;UNTIL=20260301T000000Z\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:payday\r\n\
+SUMMARY:Payday\r\n\
+DTSTART;VALUE=DATE:20251215\r\n\
+DTEND;VALUE=DATE:20251216\r\n\
+RRULE:FREQ=MONTHLY;BYMONTHDAY=15,-1\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:course\r\n\
+SUMMARY:Course\r\n\
+DTSTART:20251203T180000Z\r\n\
+DTEND:20251203T200000Z\r\n\
+RRULE:FREQ=WEEKLY;COUNT=3\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        let events = ExportHandler::parse_ical_string(ical).unwrap();
+        assert_eq!(events.len(), 3);
+
+        assert_eq!(events[0].repeat, RepeatFrequency::Biweekly);
+        assert_eq!(events[0].repeat_until, NaiveDate::from_ymd_opt(2026, 3, 1));
+        assert!(!events[0].all_day);
+
+        assert!(events[1].all_day);
+        // The exclusive DTEND becomes the end of the last (and only) day
+        assert_eq!(events[1].end, Utc.with_ymd_and_hms(2025, 12, 15, 23, 59, 59).unwrap());
+        assert_eq!(events[1].repeat, RepeatFrequency::Custom("FREQ=MONTHLY;BYMONTHDAY=15,-1".to_string()));
+        assert_eq!(events[1].repeat_until, None);
+
+        assert_eq!(events[2].repeat, RepeatFrequency::Weekly);
+        assert_eq!(events[2].repeat_count, Some(3));
+        assert_eq!(events[2].repeat_until, None);
+    }
+
+    #[test]
+    fn test_parse_named_and_hex_colors() {
+        let ical = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//Test//EN\r\n\
+BEGIN:VEVENT\r\n\
+UID:named\r\n\
+SUMMARY:Named\r\n\
+DTSTART:20251201T100000Z\r\n\
+COLOR:Teal\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:hex\r\n\
+SUMMARY:Hex\r\n\
+DTSTART:20251201T110000Z\r\n\
+COLOR:#3B82F6\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:unknown\r\n\
+SUMMARY:Unknown\r\n\
+DTSTART:20251201T120000Z\r\n\
+COLOR:papayawhipish\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        let events = ExportHandler::parse_ical_string(ical).unwrap();
+        assert_eq!(events[0].color.as_deref(), Some("#008080"));
+        assert_eq!(events[1].color.as_deref(), Some("#3b82f6"));
+        assert_eq!(events[2].color, None);
+
+        assert_eq!(parse_ical_color("#abc").as_deref(), Some("#aabbcc"));
+        assert_eq!(parse_ical_color("#12345g"), None);
+    }
+
+    #[test]
+    fn test_export_emits_color() {
+        let mut event = create_test_event();
+        event.color = Some("#008080".to_string());
+        let ical = to_ics(&event);
+        assert!(ical.contains("COLOR:teal"));
+
+        event.color = Some("#3b82f6".to_string());
+        let ical = to_ics(&event);
+        assert!(ical.contains("COLOR:#3b82f6"));
+    }
 }
@@ -0,0 +1,494 @@
+//! iCalendar (RFC 5545) serializer for exporting events.
+//!
+//! Writes VCALENDAR/VEVENT text directly so all-day dates, recurrence rules
+//! and line folding follow the spec regardless of the parsing library.
+//!
+//! Times are written the way they were defined: floating times without a
+//! zone, UTC times with the `Z` suffix and zoned times with their IANA name
+//! as TZID. Modified occurrences follow their series as VEVENTs with a
+//! RECURRENCE-ID, and invitees and alerts become ATTENDEE and VALARM.
+
+use super::export_handler::{format_categories, format_ical_color, format_weekday};
+use crate::caldav::{CalendarEvent, EventStatus, RepeatFrequency};
+use crate::timezone::{self, UTC_ZONE};
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use log::warn;
+
+/// Maximum line length in octets before folding (RFC 5545 §3.1)
+const MAX_LINE_OCTETS: usize = 75;
+
+/// Product identifier written to exported files
+const PRODID: &str = "-//xarbit//Sol Calendar//EN";
+
+/// Serialize events into a complete VCALENDAR document.
+/// `stamp` is written as every event's DTSTAMP.
+pub fn events_to_ics(events: &[CalendarEvent], stamp: DateTime<Utc>) -> String {
+    let mut out = String::new();
+    push_line(&mut out, "BEGIN:VCALENDAR");
+    push_line(&mut out, "VERSION:2.0");
+    push_line(&mut out, &format!("PRODID:{}", PRODID));
+    push_line(&mut out, "CALSCALE:GREGORIAN");
+    for event in events {
+        push_event(&mut out, event, stamp);
+    }
+    push_line(&mut out, "END:VCALENDAR");
+    out
+}
+
+/// Append the VEVENT of an event, followed by one VEVENT per modified occurrence
+fn push_event(out: &mut String, event: &CalendarEvent, stamp: DateTime<Utc>) {
+    push_vevent(out, event, &event.uid, None, stamp);
+    for modified in &event.overrides {
+        match override_date(&event.uid, &modified.uid) {
+            Some(date) => {
+                let recurrence_id = occurrence_property("RECURRENCE-ID", event, date);
+                push_vevent(out, modified, &event.uid, Some(&recurrence_id), stamp);
+            }
+            None => warn!("ical_export: Skipping override uid={} not named after its series", modified.uid),
+        }
+    }
+}
+
+/// Append one VEVENT block with the given UID and, for a modified
+/// occurrence, its RECURRENCE-ID line
+fn push_vevent(out: &mut String, event: &CalendarEvent, uid: &str, recurrence_id: Option<&str>, stamp: DateTime<Utc>) {
+    push_line(out, "BEGIN:VEVENT");
+    push_line(out, &format!("UID:{}", escape_text(uid)));
+    push_line(out, &format!("DTSTAMP:{}", format_utc(stamp)));
+    if let Some(recurrence_id) = recurrence_id {
+        push_line(out, recurrence_id);
+    }
+
+    if event.all_day {
+        let (start, end) = all_day_dates(event);
+        push_line(out, &format!("DTSTART;VALUE=DATE:{}", format_date(start)));
+        push_line(out, &format!("DTEND;VALUE=DATE:{}", format_date(end)));
+    } else {
+        let zone = event_zone(event);
+        push_line(out, &date_time_property("DTSTART", timezone::wall_clock(event.start, zone), zone));
+        push_line(out, &date_time_property("DTEND", timezone::wall_clock(event.end, zone), zone));
+    }
+
+    push_line(out, &format!("SUMMARY:{}", escape_text(&event.summary)));
+    if let Some(ref location) = event.location {
+        push_line(out, &format!("LOCATION:{}", escape_text(location)));
+    }
+    if let Some(ref notes) = event.notes {
+        push_line(out, &format!("DESCRIPTION:{}", escape_text(notes)));
+    }
+    if let Some(ref url) = event.url {
+        push_line(out, &format!("URL:{}", url));
+    }
+    // Modified occurrences are single instances; the series carries the recurrence
+    if recurrence_id.is_none() {
+        if let Some(rule) = rrule(event) {
+            push_line(out, &format!("RRULE:{}", rule));
+        }
+        let mut exception_dates = event.exception_dates.clone();
+        exception_dates.sort();
+        exception_dates.dedup();
+        for date in exception_dates {
+            push_line(out, &occurrence_property("EXDATE", event, date));
+        }
+    }
+    push_line(out, &format!("STATUS:{}", status_value(event.status)));
+    if !event.categories.is_empty() {
+        push_line(out, &format!("CATEGORIES:{}", format_categories(&event.categories)));
+    }
+    if let Some(ref color) = event.color {
+        push_line(out, &format!("COLOR:{}", format_ical_color(color)));
+    }
+    for invitee in &event.invitees {
+        push_line(out, &format!("ATTENDEE:mailto:{}", invitee));
+    }
+    for alert in std::iter::once(&event.alert).chain(event.alert_second.as_ref()) {
+        if let Some(minutes) = alert.trigger_minutes() {
+            push_line(out, "BEGIN:VALARM");
+            push_line(out, "ACTION:DISPLAY");
+            push_line(out, &format!("DESCRIPTION:{}", escape_text(&event.summary)));
+            push_line(out, &format!("TRIGGER:{}", format_trigger(minutes)));
+            push_line(out, "END:VALARM");
+        }
+    }
+    push_line(out, "END:VEVENT");
+}
+
+/// TRIGGER duration for an alert `minutes` before the start (negative = after)
+fn format_trigger(minutes: i64) -> String {
+    match minutes {
+        0 => "PT0S".to_string(),
+        m if m > 0 => format!("-PT{}M", m),
+        m => format!("PT{}M", -m),
+    }
+}
+
+/// Zone an event's times are defined in; None for floating and all-day events
+pub(super) fn event_zone(event: &CalendarEvent) -> Option<&str> {
+    if event.all_day {
+        None
+    } else {
+        event.timezone.as_deref()
+    }
+}
+
+/// A DATE-TIME property for a wall-clock time in `zone`: bare when floating,
+/// with the `Z` suffix in UTC, and with a TZID parameter in other zones
+fn date_time_property(name: &str, date_time: NaiveDateTime, zone: Option<&str>) -> String {
+    let value = date_time.format("%Y%m%dT%H%M%S");
+    match zone {
+        None => format!("{}:{}", name, value),
+        Some(UTC_ZONE) => format!("{}:{}Z", name, value),
+        Some(tzid) => format!("{};TZID={}:{}", name, tzid, value),
+    }
+}
+
+/// A property naming the occurrence of `series` on `date` (EXDATE, RECURRENCE-ID).
+/// Its value type matches DTSTART: a DATE for all-day series, otherwise the
+/// series' start time on that day in its zone.
+fn occurrence_property(name: &str, series: &CalendarEvent, date: NaiveDate) -> String {
+    if series.all_day {
+        return format!("{};VALUE=DATE:{}", name, format_date(date));
+    }
+    let zone = event_zone(series);
+    let time = timezone::wall_clock(series.start, zone).time();
+    date_time_property(name, date.and_time(time), zone)
+}
+
+/// Date of a modified occurrence of the series `series_uid` (UID `<series_uid>_YYYYMMDD`)
+fn override_date(series_uid: &str, override_uid: &str) -> Option<NaiveDate> {
+    let suffix = override_uid.strip_prefix(series_uid)?.strip_prefix('_')?;
+    NaiveDate::parse_from_str(suffix, "%Y%m%d").ok()
+}
+
+/// First day and exclusive end day of an all-day event.
+/// Ends stored as the last day's late evening (from the event dialog) become the next day.
+fn all_day_dates(event: &CalendarEvent) -> (NaiveDate, NaiveDate) {
+    let start = event.start.date_naive();
+    let mut end = event.end.date_naive();
+    if event.end.time() != NaiveTime::MIN {
+        end += Duration::days(1);
+    }
+    (start, end.max(start + Duration::days(1)))
+}
+
+/// RRULE value for the event's repeat frequency, interval and weekdays, with COUNT or UNTIL when it ends
+fn rrule(event: &CalendarEvent) -> Option<String> {
+    let mut freq = match &event.repeat {
+        RepeatFrequency::Never => return None,
+        RepeatFrequency::Daily => "FREQ=DAILY",
+        RepeatFrequency::Weekly => "FREQ=WEEKLY",
+        RepeatFrequency::Biweekly => "FREQ=WEEKLY;INTERVAL=2",
+        RepeatFrequency::Monthly => "FREQ=MONTHLY",
+        RepeatFrequency::Yearly => "FREQ=YEARLY",
+        // Custom rules are exported verbatim, including any UNTIL/COUNT they carry
+        RepeatFrequency::Custom(rule) => return Some(rule.clone()),
+    }
+    .to_string();
+    if event.repeat_interval > 1 {
+        freq = match event.repeat {
+            // Biweekly already carries an interval of two weeks
+            RepeatFrequency::Biweekly => format!("FREQ=WEEKLY;INTERVAL={}", 2 * event.repeat_interval),
+            _ => format!("{};INTERVAL={}", freq, event.repeat_interval),
+        };
+    }
+    let weekly = matches!(event.repeat, RepeatFrequency::Weekly | RepeatFrequency::Biweekly);
+    if weekly && !event.repeat_weekdays.is_empty() {
+        let mut weekdays = event.repeat_weekdays.clone();
+        weekdays.sort_by_key(|day| day.num_days_from_monday());
+        weekdays.dedup();
+        let codes: Vec<&str> = weekdays.into_iter().map(format_weekday).collect();
+        freq = format!("{};BYDAY={}", freq, codes.join(","));
+    }
+
+    // RFC 5545 allows only one of COUNT and UNTIL; COUNT wins if both are set.
+    // UNTIL must be a DATE for all-day events, floating for floating events
+    // and in UTC otherwise
+    Some(match (event.repeat_count, event.repeat_until) {
+        (Some(count), _) => format!("{};COUNT={}", freq, count),
+        (None, Some(until)) if event.all_day => format!("{};UNTIL={}", freq, format_date(until)),
+        (None, Some(until)) if event_zone(event).is_none() => format!("{};UNTIL={}T235959", freq, format_date(until)),
+        (None, Some(until)) => format!("{};UNTIL={}T235959Z", freq, format_date(until)),
+        (None, None) => freq,
+    })
+}
+
+fn status_value(status: EventStatus) -> &'static str {
+    match status {
+        EventStatus::Confirmed => "CONFIRMED",
+        EventStatus::Tentative => "TENTATIVE",
+        EventStatus::Cancelled => "CANCELLED",
+    }
+}
+
+fn format_utc(dt: DateTime<Utc>) -> String {
+    dt.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+fn format_date(date: NaiveDate) -> String {
+    date.format("%Y%m%d").to_string()
+}
+
+/// Escape a TEXT value (RFC 5545 §3.3.11)
+fn escape_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Append a content line, folded to at most 75 octets per physical line
+/// without splitting UTF-8 characters, terminated by CRLF
+fn push_line(out: &mut String, line: &str) {
+    let mut octets = 0;
+    for c in line.chars() {
+        // Continuation lines start with a space, which counts toward the limit
+        if octets + c.len_utf8() > MAX_LINE_OCTETS {
+            out.push_str("\r\n ");
+            octets = 1;
+        }
+        out.push(c);
+        octets += c.len_utf8();
+    }
+    out.push_str("\r\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::caldav::{AlertTime, ALL_DAY_ALERT_MINUTE};
+    use crate::services::ExportHandler;
+    use chrono::{TimeZone, Weekday};
+
+    fn event(uid: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> CalendarEvent {
+        CalendarEvent {
+            uid: uid.to_string(),
+            summary: format!("Event {}", uid),
+            start,
+            end,
+            ..CalendarEvent::test_default()
+        }
+    }
+
+    fn stamp() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 11, 1, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_round_trip_reproduces_events() {
+        let mut timed = event(
+            "timed",
+            Utc.with_ymd_and_hms(2025, 12, 1, 9, 30, 0).unwrap(),
+            Utc.with_ymd_and_hms(2025, 12, 1, 10, 0, 0).unwrap(),
+        );
+        timed.location = Some("Room 4".to_string());
+        timed.url = Some("https://example.com/standup".to_string());
+        timed.notes = Some("Café planning notes that are long enough to need folding across more than one line".to_string());
+        timed.repeat = RepeatFrequency::Biweekly;
+        timed.repeat_until = NaiveDate::from_ymd_opt(2026, 3, 1);
+        timed.invitees = vec!["ana@example.com".to_string(), "bo@example.com".to_string()];
+        timed.alert = AlertTime::FifteenMinutes;
+        timed.alert_second = Some(AlertTime::Custom(45));
+
+        let mut all_day = event(
+            "all-day",
+            Utc.with_ymd_and_hms(2025, 12, 24, 0, 0, 0).unwrap(),
+            // All-day ends are stored on the last day, as import normalizes them
+            Utc.with_ymd_and_hms(2025, 12, 25, 23, 59, 59).unwrap(),
+        );
+        all_day.all_day = true;
+        all_day.repeat = RepeatFrequency::Yearly;
+        all_day.repeat_count = Some(5);
+        all_day.alert = AlertTime::DayBeforeAt(ALL_DAY_ALERT_MINUTE);
+
+        let events = vec![timed, all_day];
+        let ics = events_to_ics(&events, stamp());
+        assert!(ics.contains("ATTENDEE:mailto:ana@example.com\r\n"));
+        assert!(ics.contains("TRIGGER:-PT15M\r\n"));
+        assert!(ics.contains("TRIGGER:-PT45M\r\n"));
+        // 9:00 the day before an all-day event is 15 hours before its midnight start
+        assert!(ics.contains("TRIGGER:-PT900M\r\n"));
+
+        let imported = ExportHandler::parse_ical_string(&ics).unwrap();
+        assert_eq!(imported, events);
+    }
+
+    #[test]
+    fn test_repeat_interval_round_trips_through_rrule() {
+        let mut every_three_days = event(
+            "every-three-days",
+            Utc.with_ymd_and_hms(2025, 12, 1, 9, 30, 0).unwrap(),
+            Utc.with_ymd_and_hms(2025, 12, 1, 10, 0, 0).unwrap(),
+        );
+        every_three_days.repeat = RepeatFrequency::Daily;
+        every_three_days.repeat_interval = 3;
+
+        let mut every_other_month = event(
+            "every-other-month",
+            Utc.with_ymd_and_hms(2025, 12, 15, 14, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2025, 12, 15, 15, 0, 0).unwrap(),
+        );
+        every_other_month.repeat = RepeatFrequency::Monthly;
+        every_other_month.repeat_interval = 2;
+        every_other_month.repeat_count = Some(6);
+
+        let events = vec![every_three_days, every_other_month];
+        let ics = events_to_ics(&events, stamp());
+        assert!(ics.contains("RRULE:FREQ=DAILY;INTERVAL=3\r\n"));
+        assert!(ics.contains("RRULE:FREQ=MONTHLY;INTERVAL=2;COUNT=6\r\n"));
+
+        let imported = ExportHandler::parse_ical_string(&ics).unwrap();
+        assert_eq!(imported, events);
+    }
+
+    #[test]
+    fn test_weekdays_round_trip_through_byday() {
+        let mut classes = event(
+            "classes",
+            Utc.with_ymd_and_hms(2025, 12, 1, 8, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2025, 12, 1, 9, 30, 0).unwrap(),
+        );
+        classes.repeat = RepeatFrequency::Weekly;
+        classes.repeat_weekdays = vec![Weekday::Mon, Weekday::Wed, Weekday::Fri];
+        classes.repeat_count = Some(12);
+
+        let ics = events_to_ics(std::slice::from_ref(&classes), stamp());
+        assert!(ics.contains("RRULE:FREQ=WEEKLY;BYDAY=MO,WE,FR;COUNT=12\r\n"));
+
+        let imported = ExportHandler::parse_ical_string(&ics).unwrap();
+        assert_eq!(imported, vec![classes]);
+
+        // Ordinal weekdays ("first Monday") aren't weekly weekdays and stay custom
+        let custom = ics.replace("BYDAY=MO,WE,FR", "BYDAY=1MO");
+        let imported = ExportHandler::parse_ical_string(&custom).unwrap();
+        assert_eq!(imported[0].repeat, RepeatFrequency::Custom("FREQ=WEEKLY;BYDAY=1MO;COUNT=12".to_string()));
+        assert!(imported[0].repeat_weekdays.is_empty());
+    }
+
+    #[test]
+    fn test_times_keep_their_zone_and_all_day_dates() {
+        let mut all_day = event(
+            "all-day",
+            Utc.with_ymd_and_hms(2025, 12, 24, 0, 0, 0).unwrap(),
+            // Dialog-created all-day events end late on their last day
+            Utc.with_ymd_and_hms(2025, 12, 25, 23, 59, 59).unwrap(),
+        );
+        all_day.all_day = true;
+        let mut utc = event(
+            "utc",
+            Utc.with_ymd_and_hms(2025, 12, 1, 9, 30, 0).unwrap(),
+            Utc.with_ymd_and_hms(2025, 12, 1, 10, 0, 0).unwrap(),
+        );
+        utc.timezone = Some(UTC_ZONE.to_string());
+        let floating = event(
+            "floating",
+            Utc.with_ymd_and_hms(2025, 12, 2, 9, 30, 0).unwrap(),
+            Utc.with_ymd_and_hms(2025, 12, 2, 10, 0, 0).unwrap(),
+        );
+        let mut zoned = event(
+            "zoned",
+            Utc.with_ymd_and_hms(2025, 12, 3, 9, 30, 0).unwrap(),
+            Utc.with_ymd_and_hms(2025, 12, 3, 10, 0, 0).unwrap(),
+        );
+        zoned.timezone = Some("Europe/Berlin".to_string());
+        zoned.repeat = RepeatFrequency::Weekly;
+        zoned.repeat_until = NaiveDate::from_ymd_opt(2026, 1, 31);
+
+        let events = vec![all_day, utc, floating, zoned];
+        let ics = events_to_ics(&events, stamp());
+        assert!(ics.contains("DTSTART;VALUE=DATE:20251224\r\n"));
+        assert!(ics.contains("DTEND;VALUE=DATE:20251226\r\n"));
+        assert!(ics.contains("DTSTART:20251201T093000Z\r\n"));
+        assert!(ics.contains("DTEND:20251201T100000Z\r\n"));
+        assert!(ics.contains("DTSTART:20251202T093000\r\n"));
+        assert!(ics.contains("DTEND:20251202T100000\r\n"));
+        // Berlin is an hour ahead of UTC in winter
+        assert!(ics.contains("DTSTART;TZID=Europe/Berlin:20251203T103000\r\n"));
+        assert!(ics.contains("DTEND;TZID=Europe/Berlin:20251203T110000\r\n"));
+        assert!(ics.contains("RRULE:FREQ=WEEKLY;UNTIL=20260131T235959Z\r\n"));
+        assert!(ics.contains("DTSTAMP:20251101T120000Z\r\n"));
+
+        let imported = ExportHandler::parse_ical_string(&ics).unwrap();
+        assert_eq!(imported, events);
+    }
+
+    #[test]
+    fn test_exception_dates_and_overrides() {
+        let mut series = event(
+            "series",
+            Utc.with_ymd_and_hms(2025, 12, 1, 9, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2025, 12, 1, 10, 0, 0).unwrap(),
+        );
+        series.timezone = Some("Europe/Berlin".to_string());
+        series.repeat = RepeatFrequency::Daily;
+        series.exception_dates = vec![NaiveDate::from_ymd_opt(2025, 12, 3).unwrap()];
+        let mut moved = event(
+            "series_20251204",
+            Utc.with_ymd_and_hms(2025, 12, 4, 13, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2025, 12, 4, 14, 0, 0).unwrap(),
+        );
+        moved.timezone = Some("Europe/Berlin".to_string());
+        // Occurrences are copies of the series, recurrence included
+        moved.repeat = RepeatFrequency::Daily;
+        moved.status = EventStatus::Cancelled;
+        series.overrides = vec![moved];
+
+        let ics = events_to_ics(std::slice::from_ref(&series), stamp());
+        assert!(ics.contains("EXDATE;TZID=Europe/Berlin:20251203T100000\r\n"));
+        assert!(ics.contains("RECURRENCE-ID;TZID=Europe/Berlin:20251204T100000\r\n"));
+        assert!(ics.contains("DTSTART;TZID=Europe/Berlin:20251204T140000\r\n"));
+        assert_eq!(ics.matches("UID:series\r\n").count(), 2);
+        assert_eq!(ics.matches("RRULE:").count(), 1);
+        assert!(!ics.contains("UID:series_20251204"));
+
+        let imported = ExportHandler::parse_ical_string(&ics).unwrap();
+        assert_eq!(imported.len(), 1);
+        assert_eq!(imported[0].exception_dates, series.exception_dates);
+        let overrides = &imported[0].overrides;
+        assert_eq!(overrides.len(), 1);
+        assert_eq!(overrides[0].uid, "series_20251204");
+        assert_eq!(overrides[0].status, EventStatus::Cancelled);
+        assert_eq!(overrides[0].start, series.overrides[0].start);
+
+        let mut all_day = event(
+            "holiday",
+            Utc.with_ymd_and_hms(2025, 12, 24, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2025, 12, 24, 23, 59, 59).unwrap(),
+        );
+        all_day.all_day = true;
+        all_day.repeat = RepeatFrequency::Yearly;
+        all_day.exception_dates = vec![NaiveDate::from_ymd_opt(2026, 12, 24).unwrap()];
+        let ics = events_to_ics(&[all_day], stamp());
+        assert!(ics.contains("EXDATE;VALUE=DATE:20261224\r\n"));
+    }
+
+    #[test]
+    fn test_long_lines_are_folded_on_char_boundaries() {
+        let mut line = String::new();
+        push_line(&mut line, &format!("SUMMARY:{}", "é".repeat(80)));
+
+        let physical: Vec<&str> = line.trim_end_matches("\r\n").split("\r\n").collect();
+        assert!(physical.len() > 1);
+        assert!(physical.iter().all(|l| l.len() <= MAX_LINE_OCTETS));
+        assert!(physical[1..].iter().all(|l| l.starts_with(' ')));
+
+        let unfolded: String = physical
+            .iter()
+            .enumerate()
+            .map(|(i, l)| if i == 0 { *l } else { &l[1..] })
+            .collect();
+        assert_eq!(unfolded, format!("SUMMARY:{}", "é".repeat(80)));
+    }
+
+    #[test]
+    fn test_text_values_are_escaped() {
+        assert_eq!(escape_text("a, b; c\\d\nnext"), "a\\, b\\; c\\\\d\\nnext");
+    }
+}
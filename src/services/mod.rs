@@ -8,17 +8,26 @@
 //! - `CalendarHandler` - Calendar management (create, edit, delete calendars)
 //! - `SettingsHandler` - Application settings (load, save, validate settings)
 //! - `SyncHandler` - Synchronization (sync calendars with backends)
+//! - `CredentialStore` - Account passwords in the system keyring
 //! - `ExportHandler` - Import/Export (iCalendar import/export)
+//! - `ical_export` - RFC 5545 serializer used by `ExportHandler`
+//! - `alert_scheduler` - Desktop notifications for event alerts
 
+mod alert_scheduler;
 mod calendar_handler;
+mod credential_store;
 mod event_handler;
 mod export_handler;
+mod ical_export;
 mod settings_handler;
 mod sync_handler;
 
+pub use alert_scheduler::{show_notification, AlertScheduler, ALERT_LOOKAHEAD_DAYS};
 pub use calendar_handler::{CalendarHandler, NewCalendarData, UpdateCalendarData};
+pub use credential_store::CredentialStore;
 pub use event_handler::EventHandler;
-pub use export_handler::ExportHandler;
+pub use export_handler::{ExportHandler, ImportSummary, BUSY_EVENT_UID_PREFIX};
+pub use ical_export::events_to_ics;
 pub use settings_handler::SettingsHandler;
 
 // Internal types - exported for potential future use but not currently needed externally
@@ -3,7 +3,9 @@
 //! This handler provides a single point of contact for all settings operations,
 //! including loading, saving, validation, and applying settings changes.
 
-use crate::settings::AppSettings;
+use crate::caldav::AlertTime;
+use crate::settings::{AppSettings, DefaultView, EventOverlapStyle, EventTextSize, GridLineStyle, NewEventMode, WorkingHours};
+use chrono::{DateTime, Utc, Weekday};
 use log::{debug, error, info, warn};
 use std::error::Error;
 
@@ -19,7 +21,6 @@ pub enum SettingsError {
     /// Failed to save settings
     SaveError(String),
     /// Invalid setting value
-    #[allow(dead_code)] // Reserved for future validation
     ValidationError(String),
 }
 
@@ -84,6 +85,177 @@ impl SettingsHandler {
         Self::save(settings)
     }
 
+    /// Set the per-day event cap for month cells and save (None = fit to cell)
+    pub fn set_max_events_per_day(settings: &mut AppSettings, max_events: Option<usize>) -> SettingsResult<()> {
+        info!("SettingsHandler: Setting max events per day to {:?}", max_events);
+        settings.max_events_per_day = max_events;
+        Self::save(settings)
+    }
+
+    /// Toggle between quick input and the full dialog for new events and save
+    pub fn toggle_new_event_mode(settings: &mut AppSettings) -> SettingsResult<()> {
+        let new_mode = match settings.new_event_mode {
+            NewEventMode::QuickInput => NewEventMode::Dialog,
+            NewEventMode::Dialog => NewEventMode::QuickInput,
+        };
+        info!("SettingsHandler: Setting new event mode to {:?}", new_mode);
+        settings.new_event_mode = new_mode;
+        Self::save(settings)
+    }
+
+    /// Toggle weekend days in week and month views and save
+    pub fn toggle_weekends(settings: &mut AppSettings) -> SettingsResult<()> {
+        let new_value = !settings.show_weekends;
+        info!("SettingsHandler: Toggling weekends: {} -> {}", settings.show_weekends, new_value);
+        settings.show_weekends = new_value;
+        Self::save(settings)
+    }
+
+    /// Toggle the current-week strip above the month grid and save
+    pub fn toggle_mini_week(settings: &mut AppSettings) -> SettingsResult<()> {
+        let new_value = !settings.show_mini_week;
+        info!("SettingsHandler: Toggling mini week strip: {} -> {}", settings.show_mini_week, new_value);
+        settings.show_mini_week = new_value;
+        Self::save(settings)
+    }
+
+    /// Toggle collapsing the week view's all-day section and save
+    pub fn toggle_all_day_collapsed(settings: &mut AppSettings) -> SettingsResult<()> {
+        let new_value = !settings.week_all_day_collapsed;
+        info!("SettingsHandler: Toggling all-day section collapsed: {} -> {}", settings.week_all_day_collapsed, new_value);
+        settings.week_all_day_collapsed = new_value;
+        Self::save(settings)
+    }
+
+    /// Set the grid line style for month/week/day cells and save
+    pub fn set_grid_lines(settings: &mut AppSettings, grid_lines: GridLineStyle) -> SettingsResult<()> {
+        info!("SettingsHandler: Setting grid lines to {:?}", grid_lines);
+        settings.grid_lines = grid_lines;
+        Self::save(settings)
+    }
+
+    /// Set the font size of event labels and save
+    pub fn set_event_text_size(settings: &mut AppSettings, text_size: EventTextSize) -> SettingsResult<()> {
+        info!("SettingsHandler: Setting event text size to {:?}", text_size);
+        settings.event_text_size = text_size;
+        Self::save(settings)
+    }
+
+    /// Set how overlapping events are laid out in the time grid and save
+    pub fn set_event_overlap_style(settings: &mut AppSettings, style: EventOverlapStyle) -> SettingsResult<()> {
+        info!("SettingsHandler: Setting event overlap style to {:?}", style);
+        settings.event_overlap_style = style;
+        Self::save(settings)
+    }
+
+    /// Toggle shading the hours outside the working day and save
+    pub fn toggle_off_hours_shading(settings: &mut AppSettings) -> SettingsResult<()> {
+        let new_value = !settings.shade_off_hours;
+        info!("SettingsHandler: Toggling off-hours shading: {} -> {}", settings.shade_off_hours, new_value);
+        settings.shade_off_hours = new_value;
+        Self::save(settings)
+    }
+
+    /// Set the working day shaded around in the week and day views and save
+    pub fn set_working_hours(settings: &mut AppSettings, hours: WorkingHours) -> SettingsResult<()> {
+        if !hours.is_valid() {
+            return Err(SettingsError::ValidationError(format!(
+                "working hours {}-{} must end after they start, by 24:00", hours.start, hours.end
+            )));
+        }
+        info!("SettingsHandler: Setting working hours to {}-{}", hours.start, hours.end);
+        settings.working_hours = hours;
+        Self::save(settings)
+    }
+
+    /// Set the hour the time grids scroll to when today isn't shown and save
+    pub fn set_day_start_hour(settings: &mut AppSettings, hour: u32) -> SettingsResult<()> {
+        if hour >= 24 {
+            return Err(SettingsError::ValidationError(format!("day start hour {} is past 23", hour)));
+        }
+        info!("SettingsHandler: Setting day start hour to {}", hour);
+        settings.day_start_hour = hour;
+        Self::save(settings)
+    }
+
+    /// Set the first day of the week (None = locale default) and save
+    pub fn set_first_day_of_week(settings: &mut AppSettings, first_day: Option<Weekday>) -> SettingsResult<()> {
+        info!("SettingsHandler: Setting first day of week to {:?}", first_day);
+        settings.first_day_of_week = first_day;
+        Self::save(settings)
+    }
+
+    /// Set the weekend days (None = locale default) and save
+    pub fn set_weekend_days(settings: &mut AppSettings, weekend_days: Option<Vec<Weekday>>) -> SettingsResult<()> {
+        info!("SettingsHandler: Setting weekend days to {:?}", weekend_days);
+        settings.weekend_days = weekend_days;
+        Self::save(settings)
+    }
+
+    /// Set the clock format (None = locale default) and save
+    pub fn set_use_24_hour(settings: &mut AppSettings, use_24_hour: Option<bool>) -> SettingsResult<()> {
+        info!("SettingsHandler: Setting 24-hour clock to {:?}", use_24_hour);
+        settings.use_24_hour = use_24_hour;
+        Self::save(settings)
+    }
+
+    /// Set the view shown on startup and save
+    pub fn set_default_view(settings: &mut AppSettings, default_view: DefaultView) -> SettingsResult<()> {
+        info!("SettingsHandler: Setting default view to {:?}", default_view);
+        settings.default_view = default_view;
+        Self::save(settings)
+    }
+
+    /// Set the calendar selected for new events on startup and save
+    pub fn set_default_calendar(settings: &mut AppSettings, calendar_id: Option<String>) -> SettingsResult<()> {
+        info!("SettingsHandler: Setting default calendar to {:?}", calendar_id);
+        settings.default_calendar_id = calendar_id;
+        Self::save(settings)
+    }
+
+    /// Restore the settings dialog's options to their defaults and save:
+    /// week numbers shown, clock and week start from the locale, month view on startup.
+    /// Other settings are kept.
+    pub fn reset_to_system_defaults(settings: &mut AppSettings) -> SettingsResult<()> {
+        info!("SettingsHandler: Resetting display settings to system defaults");
+        let defaults = AppSettings::default();
+        settings.show_week_numbers = defaults.show_week_numbers;
+        settings.use_24_hour = defaults.use_24_hour;
+        settings.first_day_of_week = defaults.first_day_of_week;
+        settings.default_view = defaults.default_view;
+        Self::save(settings)
+    }
+
+    /// Set the alert given to new all-day events and save
+    pub fn set_all_day_alert(settings: &mut AppSettings, alert: AlertTime) -> SettingsResult<()> {
+        info!("SettingsHandler: Setting all-day event alert to {:?}", alert);
+        settings.all_day_alert = alert;
+        Self::save(settings)
+    }
+
+    /// Toggle the missed-alerts summary on startup and save
+    pub fn toggle_missed_alerts(settings: &mut AppSettings) -> SettingsResult<()> {
+        let new_value = !settings.show_missed_alerts;
+        info!("SettingsHandler: Toggling missed alerts summary: {} -> {}", settings.show_missed_alerts, new_value);
+        settings.show_missed_alerts = new_value;
+        Self::save(settings)
+    }
+
+    /// Toggle the add/skip preview before importing into a non-empty calendar and save
+    pub fn toggle_confirm_import_duplicates(settings: &mut AppSettings) -> SettingsResult<()> {
+        let new_value = !settings.confirm_import_duplicates;
+        info!("SettingsHandler: Toggling import confirmation: {} -> {}", settings.confirm_import_duplicates, new_value);
+        settings.confirm_import_duplicates = new_value;
+        Self::save(settings)
+    }
+
+    /// Record when the app was last running and save
+    pub fn set_last_run(settings: &mut AppSettings, last_run: DateTime<Utc>) -> SettingsResult<()> {
+        debug!("SettingsHandler: Recording last run at {}", last_run);
+        settings.last_run = Some(last_run);
+        Self::save(settings)
+    }
+
     /// Reset settings to defaults and save
     #[allow(dead_code)] // Part of settings API
     pub fn reset_to_defaults() -> SettingsResult<AppSettings> {
@@ -116,4 +288,15 @@ mod tests {
 
         assert_ne!(settings.show_week_numbers, original);
     }
+
+    #[test]
+    fn test_invalid_working_hours_are_rejected() {
+        let mut settings = AppSettings::default();
+        let result = SettingsHandler::set_working_hours(&mut settings, WorkingHours { start: 18, end: 9 });
+        assert!(matches!(result, Err(SettingsError::ValidationError(_))));
+        assert!(SettingsHandler::set_working_hours(&mut settings, WorkingHours { start: 9, end: 25 }).is_err());
+        assert_eq!(settings.working_hours, WorkingHours::default());
+        assert!(SettingsHandler::set_day_start_hour(&mut settings, 24).is_err());
+        assert_eq!(settings.day_start_hour, 8);
+    }
 }
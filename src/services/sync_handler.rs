@@ -3,15 +3,19 @@
 //! This handler manages synchronization of calendars with their backends,
 //! including local database refreshes and remote CalDAV syncs.
 //!
-//! NOTE: This module is prepared for future CalDAV integration.
-//! Currently unused but provides the sync infrastructure needed when
-//! remote calendar support is implemented.
+//! Remote calendars are synced on a background thread through
+//! `begin_remote_sync` / `upload_pending_changes` / `fetch_and_store_remote` /
+//! `finish_remote_sync`.
+//! The blocking whole-manager helpers (`sync_all` and friends) are not used by the UI.
 
-#![allow(dead_code)] // Prepared for future CalDAV sync feature
+#![allow(dead_code)] // Blocking sync helpers kept for non-UI callers
 
-use crate::calendars::CalendarManager;
+use crate::caldav::{CalDavClient, CalendarEvent, RemoteResource, WriteConflict};
+use crate::calendars::{CalendarManager, CalendarSource};
+use crate::database::Database;
 use log::{debug, error, info, warn};
 use std::error::Error;
+use std::sync::{Mutex, MutexGuard};
 
 /// Result type for sync operations
 pub type SyncResult<T> = Result<T, SyncError>;
@@ -74,10 +78,126 @@ impl SyncReport {
     }
 }
 
+/// Lock the shared database from a sync thread
+fn lock(db: &Mutex<Database>) -> Result<MutexGuard<'_, Database>, String> {
+    db.lock().map_err(|_| "Database lock poisoned".to_string())
+}
+
 /// Sync Handler - centralized synchronization management.
 pub struct SyncHandler;
 
 impl SyncHandler {
+    /// Mark a remote calendar as loading and return a client to fetch it off the UI thread.
+    /// Returns None for calendars without a remote backend.
+    pub fn begin_remote_sync(calendar: &mut dyn CalendarSource) -> Option<CalDavClient> {
+        let client = calendar.remote_client()?;
+        debug!("SyncHandler: Starting background sync of '{}'", calendar.info().name);
+        calendar.info_mut().loading = true;
+        // The sync uploads everything queued so far
+        calendar.take_unsent_changes();
+        Some(client)
+    }
+
+    /// Upload the queued local changes of a remote calendar, oldest first, before
+    /// it is fetched. The database is only locked between requests. Changes the
+    /// server rejects as conflicting are dropped so the fetch brings in the
+    /// server's version; other failures stop and keep the queue for the next sync.
+    /// Meant for a background thread. Returns the number of changes sent.
+    pub fn upload_pending_changes(
+        db: &Mutex<Database>,
+        calendar_id: &str,
+        client: &CalDavClient,
+    ) -> Result<usize, String> {
+        let pending = lock(db)?
+            .get_pending_uploads(calendar_id)
+            .map_err(|e| format!("Failed to load pending changes: {}", e))?;
+
+        for (uid, deleted, revision) in &pending {
+            let (event, resource) = {
+                let db = lock(db)?;
+                let event = if *deleted { None } else { db.get_event(calendar_id, uid).map_err(|e| e.to_string())? };
+                (event, db.get_remote_resource(calendar_id, uid).map_err(|e| e.to_string())?)
+            };
+
+            // The event's server resource after the upload (None = gone from the server)
+            let uploaded = match (event, resource) {
+                (Some(event), Some((href, etag))) => client
+                    .update_event(&event, &href, etag.as_deref())
+                    .map(|etag| Some((href, etag))),
+                (Some(event), None) => client.create_event(&event).map(Some),
+                (None, Some((href, etag))) => client.delete_event(&href, etag.as_deref()).map(|()| None),
+                // Deleted before it was ever uploaded
+                (None, None) => Ok(None),
+            };
+
+            let db = lock(db)?;
+            let stored = match uploaded {
+                Ok(Some((href, etag))) => db.set_remote_resource(calendar_id, uid, &href, etag.as_deref()),
+                Ok(None) => db.delete_remote_resource(calendar_id, uid).map(|_| ()),
+                Err(e) if e.downcast_ref::<WriteConflict>().is_some() => {
+                    warn!("SyncHandler: {} (uid={}), keeping the server's version", e, uid);
+                    Ok(())
+                }
+                Err(e) => return Err(format!("Failed to upload changes: {}", e)),
+            };
+            stored
+                .and_then(|()| db.clear_pending_upload(calendar_id, uid, *revision))
+                .map_err(|e| format!("Failed to store uploaded change: {}", e))?;
+        }
+
+        debug!("SyncHandler: Uploaded {} changes for '{}'", pending.len(), calendar_id);
+        Ok(pending.len())
+    }
+
+    /// Fetch a remote calendar with the href and ETag of every event and store them
+    /// in the database, so later edits can be sent back guarded by their ETag.
+    /// `fetch` does the network I/O (usually `CalDavClient::fetch_resources`) before
+    /// the database lock is taken, so the UI thread is only blocked for the final
+    /// write. Meant for a background thread.
+    pub fn fetch_and_store_remote<F>(
+        db: &Mutex<Database>,
+        calendar_id: &str,
+        fetch: F,
+    ) -> Result<Vec<CalendarEvent>, String>
+    where
+        F: FnOnce() -> Result<Vec<RemoteResource>, Box<dyn Error>>,
+    {
+        let resources = fetch().map_err(|e| e.to_string())?;
+
+        let db = lock(db)?;
+        db.replace_remote_calendar(calendar_id, &resources)
+            .map_err(|e| format!("Failed to store synced events: {}", e))?;
+        // Read back so duplicate UIDs across resources collapse the same way as in the database
+        let events = db
+            .get_events_for_calendar(calendar_id)
+            .map_err(|e| format!("Failed to load synced events: {}", e))?;
+        debug!("SyncHandler: Stored {} synced events for '{}'", events.len(), calendar_id);
+        Ok(events)
+    }
+
+    /// Apply the result of a background fetch and clear the loading flag.
+    /// On failure the previously cached events are kept.
+    pub fn finish_remote_sync(
+        calendar: &mut dyn CalendarSource,
+        result: Result<Vec<CalendarEvent>, String>,
+    ) -> SyncResult<()> {
+        calendar.info_mut().loading = false;
+        match result {
+            Ok(events) => {
+                info!("SyncHandler: Fetched {} events for '{}'", events.len(), calendar.info().name);
+                calendar.apply_remote_events(events);
+                Ok(())
+            }
+            Err(reason) => {
+                error!("SyncHandler: Background sync failed for '{}': {}", calendar.info().id, reason);
+                Err(SyncError::SyncFailed {
+                    calendar_id: calendar.info().id.clone(),
+                    reason,
+                })
+            }
+        }
+    }
+
     /// Sync a single calendar
     pub fn sync_calendar(manager: &mut CalendarManager, calendar_id: &str) -> SyncResult<()> {
         info!("SyncHandler: Syncing calendar '{}'", calendar_id);
@@ -195,6 +315,80 @@ impl SyncHandler {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::calendars::{CalendarInfo, CalendarType};
+    use chrono::{TimeZone, Utc};
+    use std::sync::{mpsc, Arc};
+
+    /// CalDAV calendar source that can't reach its server
+    #[derive(Debug)]
+    struct OfflineSource {
+        info: CalendarInfo,
+        events: Vec<CalendarEvent>,
+    }
+
+    impl OfflineSource {
+        fn new() -> Self {
+            OfflineSource {
+                info: CalendarInfo::new("remote".to_string(), "Remote".to_string(), CalendarType::CalDav),
+                events: Vec::new(),
+            }
+        }
+    }
+
+    impl CalendarSource for OfflineSource {
+        fn info(&self) -> &CalendarInfo {
+            &self.info
+        }
+
+        fn info_mut(&mut self) -> &mut CalendarInfo {
+            &mut self.info
+        }
+
+        fn fetch_events(&self) -> Result<Vec<CalendarEvent>, Box<dyn Error>> {
+            Ok(self.events.clone())
+        }
+
+        fn add_event(&mut self, event: CalendarEvent) -> Result<(), Box<dyn Error>> {
+            self.events.push(event);
+            Ok(())
+        }
+
+        fn update_event(&mut self, _event: CalendarEvent) -> Result<(), Box<dyn Error>> {
+            Ok(())
+        }
+
+        fn delete_event(&mut self, _uid: &str) -> Result<(), Box<dyn Error>> {
+            Ok(())
+        }
+
+        fn sync(&mut self) -> Result<(), Box<dyn Error>> {
+            Err("offline".into())
+        }
+
+        fn apply_remote_events(&mut self, events: Vec<CalendarEvent>) {
+            self.events = events;
+        }
+    }
+
+    #[test]
+    fn test_finish_remote_sync_clears_loading() {
+        let mut source = OfflineSource::new();
+
+        source.info_mut().loading = true;
+        assert!(SyncHandler::finish_remote_sync(&mut source, Err("timeout".to_string())).is_err());
+        assert!(!source.info().loading);
+
+        source.info_mut().loading = true;
+        assert!(SyncHandler::finish_remote_sync(&mut source, Ok(vec![])).is_ok());
+        assert!(!source.info().loading);
+    }
+
+    #[test]
+    fn test_local_calendars_have_no_background_sync() {
+        let mut source = OfflineSource::new();
+        assert!(SyncHandler::begin_remote_sync(&mut source).is_none());
+        assert!(!source.info().loading);
+    }
 
     #[test]
     fn test_sync_report_all_succeeded() {
@@ -217,4 +411,74 @@ mod tests {
         };
         assert!(!report.all_succeeded());
     }
+
+    fn remote_event(uid: &str) -> CalendarEvent {
+        let start = Utc.with_ymd_and_hms(2025, 3, 10, 9, 0, 0).unwrap();
+        CalendarEvent {
+            uid: uid.to_string(),
+            summary: "Remote".to_string(),
+            start,
+            end: start + chrono::Duration::hours(1),
+            ..CalendarEvent::test_default()
+        }
+    }
+
+    #[test]
+    fn test_fetch_does_not_hold_database_lock() {
+        let db_path = std::env::temp_dir().join("sol_test_sync_lock.db");
+        let _ = std::fs::remove_file(&db_path);
+        let db = Arc::new(Mutex::new(Database::open_at(db_path.clone()).unwrap()));
+        db.lock().unwrap().insert_event("remote", &remote_event("stale")).unwrap();
+
+        let (started_sender, started) = mpsc::channel();
+        let (release, release_receiver) = mpsc::channel::<()>();
+        let sync_db = Arc::clone(&db);
+        let sync = std::thread::spawn(move || {
+            SyncHandler::fetch_and_store_remote(&sync_db, "remote", || {
+                started_sender.send(()).unwrap();
+                // Slow network fetch: only completes once the UI-side read is done
+                release_receiver.recv().unwrap();
+                Ok(vec![RemoteResource {
+                    href: "/cal/fresh.ics".to_string(),
+                    etag: Some("\"1\"".to_string()),
+                    events: vec![remote_event("fresh")],
+                }])
+            })
+        });
+
+        // While the fetch is in flight the UI can read without waiting
+        started.recv().unwrap();
+        {
+            let guard = db.try_lock().expect("database locked during fetch");
+            let events = guard.get_events_for_calendar("remote").unwrap();
+            assert_eq!(events[0].uid, "stale");
+        }
+        release.send(()).unwrap();
+
+        // The fetched events replace the stored ones, with their server resources
+        assert_eq!(sync.join().unwrap().unwrap().len(), 1);
+        let events = db.lock().unwrap().get_events_for_calendar("remote").unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].uid, "fresh");
+        assert_eq!(
+            db.lock().unwrap().get_remote_resource("remote", "fresh").unwrap(),
+            Some(("/cal/fresh.ics".to_string(), Some("\"1\"".to_string())))
+        );
+
+        let _ = std::fs::remove_file(&db_path);
+    }
+
+    #[test]
+    fn test_failed_fetch_keeps_stored_events() {
+        let db_path = std::env::temp_dir().join("sol_test_sync_failed_fetch.db");
+        let _ = std::fs::remove_file(&db_path);
+        let db = Mutex::new(Database::open_at(db_path.clone()).unwrap());
+        db.lock().unwrap().insert_event("remote", &remote_event("kept")).unwrap();
+
+        let result = SyncHandler::fetch_and_store_remote(&db, "remote", || Err("offline".into()));
+        assert_eq!(result.unwrap_err(), "offline");
+        assert_eq!(db.lock().unwrap().get_events_for_calendar("remote").unwrap().len(), 1);
+
+        let _ = std::fs::remove_file(&db_path);
+    }
 }
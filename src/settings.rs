@@ -1,23 +1,227 @@
+use crate::caldav::AlertTime;
+use chrono::{DateTime, Utc, Weekday};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::io;
 use std::path::PathBuf;
 
+/// What creating an event from a day cell or time slot opens
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum NewEventMode {
+    /// Inline quick event input in the cell
+    #[default]
+    QuickInput,
+    /// Full event dialog
+    Dialog,
+}
+
+/// Visibility and weight of the cell grid lines in month/week/day views
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum GridLineStyle {
+    /// No grid lines
+    None,
+    /// Thin, faint lines
+    #[default]
+    Subtle,
+    /// Thicker, more visible lines
+    Strong,
+}
+
+/// How overlapping timed events are laid out in the week view
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum EventOverlapStyle {
+    /// Equal-width columns side by side
+    #[default]
+    Columns,
+    /// Later events overlap earlier ones, shifted right by an offset
+    Cascade,
+}
+
+/// Font size of event labels on chips, week blocks and all-day chips
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum EventTextSize {
+    Small,
+    #[default]
+    Normal,
+    Large,
+    ExtraLarge,
+}
+
+impl EventTextSize {
+    /// Text size for event chips in month cells
+    pub fn chip_size(self) -> u16 {
+        match self {
+            EventTextSize::Small => 10,
+            EventTextSize::Normal => 11,
+            EventTextSize::Large => 13,
+            EventTextSize::ExtraLarge => 15,
+        }
+    }
+
+    /// Text size for week view event blocks and all-day chips (one step below chips)
+    pub fn block_size(self) -> u16 {
+        self.chip_size() - 1
+    }
+}
+
+/// Working day in the week and day views, in whole hours
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkingHours {
+    /// First working hour (0-23)
+    pub start: u32,
+    /// Hour the working day ends (exclusive, up to 24)
+    pub end: u32,
+}
+
+impl Default for WorkingHours {
+    fn default() -> Self {
+        Self { start: 8, end: 18 }
+    }
+}
+
+impl WorkingHours {
+    /// Whether the range is a non-empty part of the 24-hour grid
+    pub fn is_valid(self) -> bool {
+        self.start < self.end && self.end <= 24
+    }
+
+    /// Whether the hour starting at `hour` is outside the working day
+    pub fn is_off_hour(self, hour: u32) -> bool {
+        hour < self.start || hour >= self.end
+    }
+}
+
+/// Calendar view shown when the app starts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum DefaultView {
+    Year,
+    #[default]
+    Month,
+    Week,
+    Day,
+}
+
 /// Application-level settings that persist across sessions
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppSettings {
     pub show_week_numbers: bool,
+    /// Maximum events shown per month cell before "+N more" (None = as many as fit)
+    #[serde(default)]
+    pub max_events_per_day: Option<usize>,
+    /// Whether new events from day cells/time slots use quick input or the full dialog
+    #[serde(default)]
+    pub new_event_mode: NewEventMode,
+    /// Whether weekend days are shown in week and month views
+    #[serde(default = "default_show_weekends")]
+    pub show_weekends: bool,
+    /// Grid line style for month/week/day cells
+    #[serde(default)]
+    pub grid_lines: GridLineStyle,
+    /// Whether the current-week strip is shown above the month grid
+    #[serde(default)]
+    pub show_mini_week: bool,
+    /// Font size of event labels
+    #[serde(default)]
+    pub event_text_size: EventTextSize,
+    /// Layout of overlapping events in the week view time grid
+    #[serde(default)]
+    pub event_overlap_style: EventOverlapStyle,
+    /// Whether the week view's all-day section is collapsed to a summary row
+    #[serde(default)]
+    pub week_all_day_collapsed: bool,
+    /// First day of the week (None = locale default)
+    #[serde(default)]
+    pub first_day_of_week: Option<Weekday>,
+    /// Weekend days (None = locale default), set independently of the first day
+    #[serde(default)]
+    pub weekend_days: Option<Vec<Weekday>>,
+    /// 24-hour (true) or 12-hour (false) clock (None = locale default)
+    #[serde(default)]
+    pub use_24_hour: Option<bool>,
+    /// View shown when the app starts
+    #[serde(default)]
+    pub default_view: DefaultView,
+    /// Calendar selected for new events on startup (None = first enabled calendar)
+    #[serde(default)]
+    pub default_calendar_id: Option<String>,
+    /// Whether alerts missed while the app was closed are summarized on startup
+    #[serde(default = "default_show_missed_alerts")]
+    pub show_missed_alerts: bool,
+    /// When the app was last running (wall time as UTC), used to find missed alerts
+    #[serde(default)]
+    pub last_run: Option<DateTime<Utc>>,
+    /// Whether importing into a calendar that already has events first shows
+    /// how many events will be added and skipped as duplicates
+    #[serde(default = "default_confirm_import_duplicates")]
+    pub confirm_import_duplicates: bool,
+    /// Alert given to new all-day events (timed events start without one)
+    #[serde(default)]
+    pub all_day_alert: AlertTime,
+    /// Whether hours outside the working day are shaded in the week and day views
+    #[serde(default)]
+    pub shade_off_hours: bool,
+    /// Working day used by the off-hours shading
+    #[serde(default)]
+    pub working_hours: WorkingHours,
+    /// Hour the week and day views scroll to when today isn't shown
+    #[serde(default = "default_day_start_hour")]
+    pub day_start_hour: u32,
+}
+
+/// Weekends are shown unless the user hides them
+fn default_show_weekends() -> bool {
+    true
+}
+
+/// Missed alerts are summarized unless the user turns it off
+fn default_show_missed_alerts() -> bool {
+    true
+}
+
+/// Imports into non-empty calendars are previewed unless the user turns it off
+fn default_confirm_import_duplicates() -> bool {
+    true
+}
+
+/// Time grids open at 8:00 unless the user picks another hour
+fn default_day_start_hour() -> u32 {
+    8
 }
 
 impl Default for AppSettings {
     fn default() -> Self {
         Self {
             show_week_numbers: true, // Show week numbers by default
+            max_events_per_day: None, // Fit as many events as the cell height allows
+            new_event_mode: NewEventMode::QuickInput,
+            show_weekends: true,
+            grid_lines: GridLineStyle::Subtle,
+            show_mini_week: false,
+            event_text_size: EventTextSize::Normal,
+            event_overlap_style: EventOverlapStyle::Columns,
+            week_all_day_collapsed: false,
+            first_day_of_week: None,
+            weekend_days: None,
+            use_24_hour: None,
+            default_view: DefaultView::Month,
+            default_calendar_id: None,
+            show_missed_alerts: true,
+            last_run: None,
+            confirm_import_duplicates: true,
+            all_day_alert: AlertTime::None,
+            shade_off_hours: false,
+            working_hours: WorkingHours::default(),
+            day_start_hour: 8,
         }
     }
 }
 
 impl AppSettings {
+    /// Working day to shade around in the time grid, if shading is on
+    pub fn off_hours_band(&self) -> Option<WorkingHours> {
+        Some(self.working_hours).filter(|hours| self.shade_off_hours && hours.is_valid())
+    }
+
     /// Load settings from disk
     pub fn load() -> Result<Self, io::Error> {
         let path = Self::settings_path();
@@ -55,3 +259,69 @@ impl AppSettings {
         path
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_text_size_normal_keeps_default_sizes() {
+        assert_eq!(EventTextSize::default(), EventTextSize::Normal);
+        assert_eq!(EventTextSize::Normal.chip_size(), 11);
+        assert_eq!(EventTextSize::Normal.block_size(), 10);
+    }
+
+    #[test]
+    fn test_event_text_size_grows_with_each_step() {
+        let sizes = [
+            EventTextSize::Small,
+            EventTextSize::Normal,
+            EventTextSize::Large,
+            EventTextSize::ExtraLarge,
+        ];
+        for pair in sizes.windows(2) {
+            assert!(pair[0].chip_size() < pair[1].chip_size());
+            assert!(pair[0].block_size() < pair[1].block_size());
+        }
+    }
+
+    #[test]
+    fn test_settings_without_event_text_size_load_as_normal() {
+        let settings: AppSettings = serde_json::from_str(r#"{"show_week_numbers": true}"#).unwrap();
+        assert_eq!(settings.event_text_size, EventTextSize::Normal);
+    }
+
+    #[test]
+    fn test_settings_without_missed_alerts_fields_enable_summary() {
+        let settings: AppSettings = serde_json::from_str(r#"{"show_week_numbers": true}"#).unwrap();
+        assert!(settings.show_missed_alerts);
+        assert_eq!(settings.last_run, None);
+        assert!(settings.confirm_import_duplicates);
+    }
+
+    #[test]
+    fn test_settings_without_clock_and_view_fields_use_defaults() {
+        let settings: AppSettings = serde_json::from_str(r#"{"show_week_numbers": true}"#).unwrap();
+        assert_eq!(settings.use_24_hour, None);
+        assert_eq!(settings.default_view, DefaultView::Month);
+        assert_eq!(settings.day_start_hour, 8);
+    }
+
+    #[test]
+    fn test_working_hours_band() {
+        let mut settings: AppSettings = serde_json::from_str(r#"{"show_week_numbers": true}"#).unwrap();
+        assert_eq!(settings.working_hours, WorkingHours { start: 8, end: 18 });
+        assert_eq!(settings.off_hours_band(), None);
+
+        settings.shade_off_hours = true;
+        let band = settings.off_hours_band().unwrap();
+        assert!(band.is_off_hour(7));
+        assert!(!band.is_off_hour(8));
+        assert!(!band.is_off_hour(17));
+        assert!(band.is_off_hour(18));
+
+        // An empty or inverted range shades nothing
+        settings.working_hours = WorkingHours { start: 18, end: 8 };
+        assert_eq!(settings.off_hours_band(), None);
+    }
+}
@@ -1,9 +1,11 @@
 use cosmic::iced::{Background, Border, Color, Shadow, Vector};
 use cosmic::widget::container;
+use crate::settings::GridLineStyle;
 use crate::ui_constants::{
     SHADOW_OPACITY, SHADOW_OFFSET_X, SHADOW_OFFSET_Y, SHADOW_BLUR_RADIUS,
-    BORDER_RADIUS, BORDER_WIDTH_HIGHLIGHT, BORDER_WIDTH_NORMAL,
-    COLOR_DAY_CELL_BORDER, COLOR_WEEKEND_BACKGROUND, COLOR_TODAY_BLUE
+    BORDER_RADIUS, BORDER_WIDTH_HIGHLIGHT, BORDER_WIDTH_NORMAL, GRID_LINE_STRONG_SCALE,
+    COLOR_DAY_CELL_BORDER, COLOR_DAY_CELL_BORDER_STRONG, COLOR_WEEKEND_BACKGROUND, COLOR_OFF_HOURS_BACKGROUND, COLOR_TODAY_BLUE,
+    COLOR_SWATCH_BORDER_ALPHA
 };
 
 /// Returns the weekend background if is_weekend is true, None otherwise.
@@ -17,6 +19,34 @@ pub fn weekend_background(is_weekend: bool) -> Option<Background> {
     }
 }
 
+/// Background of a week/day hour cell: the weekend tint, dimmed further
+/// outside working hours so off-hours on weekends still stand apart
+pub fn hour_cell_background(is_weekend: bool, is_off_hours: bool) -> Option<Background> {
+    if !is_off_hours {
+        return weekend_background(is_weekend);
+    }
+    let weekend_alpha = if is_weekend { COLOR_WEEKEND_BACKGROUND.a } else { 0.0 };
+    Some(Background::Color(Color {
+        a: COLOR_OFF_HOURS_BACKGROUND.a + weekend_alpha,
+        ..COLOR_OFF_HOURS_BACKGROUND
+    }))
+}
+
+/// Border for a grid line in the user's grid line style.
+/// `base_width` is the width the call site uses for the subtle style.
+pub fn grid_border(grid_lines: GridLineStyle, base_width: f32) -> Border {
+    let (width, color) = match grid_lines {
+        GridLineStyle::None => (0.0, Color::TRANSPARENT),
+        GridLineStyle::Subtle => (base_width, COLOR_DAY_CELL_BORDER),
+        GridLineStyle::Strong => (base_width * GRID_LINE_STRONG_SCALE, COLOR_DAY_CELL_BORDER_STRONG),
+    };
+    Border {
+        width,
+        color,
+        ..Default::default()
+    }
+}
+
 /// Style for the overlay sidebar in mobile/condensed mode
 pub fn overlay_sidebar_style(theme: &cosmic::Theme) -> container::Style {
     container::Style {
@@ -80,26 +110,24 @@ pub fn today_circle_style(_theme: &cosmic::Theme, size: f32) -> container::Style
 }
 
 /// Style for regular day cell with optional weekend background
-pub fn day_cell_style(is_weekend: bool) -> container::Style {
+pub fn day_cell_style(is_weekend: bool, grid_lines: GridLineStyle) -> container::Style {
     container::Style {
         background: weekend_background(is_weekend),
         border: Border {
-            color: COLOR_DAY_CELL_BORDER,
-            width: BORDER_WIDTH_NORMAL,
             radius: BORDER_RADIUS.into(),
+            ..grid_border(grid_lines, BORDER_WIDTH_NORMAL)
         },
         ..Default::default()
     }
 }
 
 /// Style for adjacent month day cells (previous/next month) - grayed out
-pub fn adjacent_month_day_style() -> container::Style {
+pub fn adjacent_month_day_style(grid_lines: GridLineStyle) -> container::Style {
     container::Style {
         background: None,
         border: Border {
-            color: COLOR_DAY_CELL_BORDER,
-            width: BORDER_WIDTH_NORMAL,
             radius: BORDER_RADIUS.into(),
+            ..grid_border(grid_lines, BORDER_WIDTH_NORMAL)
         },
         // Gray text for adjacent month days
         text_color: Some(Color::from_rgba(0.5, 0.5, 0.5, 0.5)),
@@ -135,6 +163,18 @@ pub fn color_button_style(color: Color, size: f32, border_width: f32, border_col
     }
 }
 
+/// Border color of a color swatch, taken from the theme's text color so it stays
+/// visible on light and dark backgrounds. Style closures read it at draw time,
+/// so a system appearance change is picked up on the next redraw.
+pub fn color_swatch_border(theme: &cosmic::Theme, selected: bool) -> Color {
+    let on_bg: Color = theme.cosmic().on_bg_color().into();
+    if selected {
+        on_bg
+    } else {
+        Color { a: COLOR_SWATCH_BORDER_ALPHA, ..on_bg }
+    }
+}
+
 /// Style for day cells in a drag selection range
 /// Uses a semi-transparent accent color background
 pub fn selection_highlight_style(theme: &cosmic::Theme, _is_weekend: bool) -> container::Style {
@@ -175,37 +215,29 @@ pub fn adjacent_month_selection_style(theme: &cosmic::Theme) -> container::Style
 /// Style for a grid cell with border and optional weekend background
 /// Used in time grid rendering for hour slots
 #[allow(dead_code)] // Reserved for future time grid customization
-pub fn grid_cell_style(is_weekend: bool) -> container::Style {
+pub fn grid_cell_style(is_weekend: bool, grid_lines: GridLineStyle) -> container::Style {
     container::Style {
         background: weekend_background(is_weekend),
-        border: Border {
-            width: BORDER_WIDTH_NORMAL,
-            color: COLOR_DAY_CELL_BORDER,
-            ..Default::default()
-        },
+        border: grid_border(grid_lines, BORDER_WIDTH_NORMAL),
         ..Default::default()
     }
 }
 
 /// Style for a bordered cell without weekend background
 /// Used for time labels and header cells
-pub fn bordered_cell_style() -> container::Style {
+pub fn bordered_cell_style(grid_lines: GridLineStyle) -> container::Style {
     container::Style {
-        border: Border {
-            width: BORDER_WIDTH_NORMAL,
-            color: COLOR_DAY_CELL_BORDER,
-            ..Default::default()
-        },
+        border: grid_border(grid_lines, BORDER_WIDTH_NORMAL),
         ..Default::default()
     }
 }
 
 /// Style for a day cell that is the current drop target during event drag
-/// Uses a dashed-like appearance with accent color to indicate where the event will land
-pub fn drag_target_style(theme: &cosmic::Theme, is_weekend: bool) -> container::Style {
-    let accent = theme.cosmic().accent_color();
+/// Tinted with the dragged event's color (accent color if unknown) to show where the event will land
+pub fn drag_target_style(theme: &cosmic::Theme, is_weekend: bool, event_color: Option<Color>) -> container::Style {
+    let tint = event_color.unwrap_or_else(|| theme.cosmic().accent_color().into());
     // Highlight background to show this is the drop target
-    let target_bg = Color::from_rgba(accent.red, accent.green, accent.blue, 0.25);
+    let target_bg = Color { a: 0.25, ..tint };
 
     container::Style {
         background: Some(Background::Color(if is_weekend {
@@ -220,10 +252,63 @@ pub fn drag_target_style(theme: &cosmic::Theme, is_weekend: bool) -> container::
             target_bg
         })),
         border: Border {
-            color: Color::from_rgba(accent.red, accent.green, accent.blue, 0.8),
+            color: Color { a: 0.8, ..tint },
             width: BORDER_WIDTH_HIGHLIGHT,
             radius: BORDER_RADIUS.into(),
         },
         ..Default::default()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_border_per_style() {
+        let none = grid_border(GridLineStyle::None, BORDER_WIDTH_NORMAL);
+        assert_eq!(none.width, 0.0);
+        assert_eq!(none.color, Color::TRANSPARENT);
+
+        let subtle = grid_border(GridLineStyle::Subtle, BORDER_WIDTH_NORMAL);
+        assert_eq!(subtle.width, BORDER_WIDTH_NORMAL);
+        assert_eq!(subtle.color, COLOR_DAY_CELL_BORDER);
+
+        let strong = grid_border(GridLineStyle::Strong, BORDER_WIDTH_NORMAL);
+        assert!(strong.width > subtle.width);
+        assert_eq!(strong.color, COLOR_DAY_CELL_BORDER_STRONG);
+    }
+
+    #[test]
+    fn test_swatch_border_follows_theme() {
+        let light = color_swatch_border(&cosmic::Theme::light(), true);
+        let dark = color_swatch_border(&cosmic::Theme::dark(), true);
+        assert_ne!(light, dark);
+        // Dark text on a light theme, light text on a dark theme
+        assert!(light.r < dark.r);
+
+        let unselected = color_swatch_border(&cosmic::Theme::dark(), false);
+        assert_eq!(unselected.a, COLOR_SWATCH_BORDER_ALPHA);
+        assert_eq!((unselected.r, unselected.g, unselected.b), (dark.r, dark.g, dark.b));
+    }
+
+    #[test]
+    fn test_drag_target_tinted_with_event_color() {
+        let theme = cosmic::Theme::dark();
+        let red = Color::from_rgb(1.0, 0.0, 0.0);
+        let style = drag_target_style(&theme, false, Some(red));
+        assert_eq!(style.background, Some(Background::Color(Color { a: 0.25, ..red })));
+        assert_eq!(style.border.color, Color { a: 0.8, ..red });
+
+        // Without a known event color the accent color is used
+        let accent: Color = theme.cosmic().accent_color().into();
+        let style = drag_target_style(&theme, false, None);
+        assert_eq!(style.background, Some(Background::Color(Color { a: 0.25, ..accent })));
+    }
+
+    #[test]
+    fn test_day_cell_style_uses_grid_style() {
+        let style = day_cell_style(false, GridLineStyle::Strong);
+        assert_eq!(style.border.width, BORDER_WIDTH_NORMAL * GRID_LINE_STRONG_SCALE);
+    }
+}
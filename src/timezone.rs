@@ -0,0 +1,534 @@
+//! Time zone resolution
+//!
+//! Events imported from other calendars carry IANA zone names (TZID) that
+//! may be renamed or obsolete in the bundled `chrono-tz` database. All zone
+//! lookups go through this module so unknown names fall back to UTC instead
+//! of failing, for both import and display.
+//!
+//! iCal files may also embed VTIMEZONE definitions for custom TZIDs. Their
+//! STANDARD/DAYLIGHT observances are parsed into [`CustomZone`]s, which take
+//! precedence over the IANA lookup during import.
+//!
+//! Events created in the app are stored in the system zone. Stored times are
+//! UTC instants; views show zoned events in the system zone, while floating
+//! events (no zone) keep their wall-clock time everywhere.
+
+use chrono::{
+    DateTime, Datelike, Duration, LocalResult, NaiveDate, NaiveDateTime, TimeZone, Timelike, Utc, Weekday,
+};
+use chrono_tz::Tz;
+use log::{debug, warn};
+use std::sync::OnceLock;
+
+/// Zone name of events defined in UTC (iCal times with a `Z` suffix)
+pub const UTC_ZONE: &str = "UTC";
+
+/// Upper bound when searching past a DST gap (gaps are at most a few hours)
+const MAX_GAP_MINUTES: i64 = 24 * 60;
+
+/// Result of resolving a stored IANA zone name
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResolvedZone {
+    /// The zone to use for conversions
+    pub tz: Tz,
+    /// Whether the name was unknown and UTC was used instead
+    pub is_fallback: bool,
+}
+
+/// Look up an IANA zone name without logging.
+/// Returns None when the name is not in the time zone database.
+pub fn lookup(name: &str) -> Option<Tz> {
+    name.trim().parse::<Tz>().ok()
+}
+
+/// Resolve an IANA zone name, falling back to UTC with a warning when the
+/// name can't be found (renamed or obsolete zones).
+pub fn resolve(name: &str) -> ResolvedZone {
+    match lookup(name) {
+        Some(tz) => ResolvedZone { tz, is_fallback: false },
+        None => {
+            warn!("timezone: Unknown time zone '{}', falling back to UTC", name);
+            ResolvedZone { tz: Tz::UTC, is_fallback: true }
+        }
+    }
+}
+
+/// Whether a stored zone name can't be resolved and times are shown in UTC.
+/// Events without a zone are never considered a fallback.
+pub fn is_fallback(name: Option<&str>) -> bool {
+    name.is_some_and(|name| lookup(name).is_none())
+}
+
+/// Convert a wall-clock time in the named zone to UTC.
+/// Ambiguous times (DST fall-back) use the earlier instant; times skipped by
+/// a DST gap (spring-forward) move to the first valid instant after the gap.
+///
+/// # Returns
+/// The UTC instant and whether the zone fell back to UTC
+pub fn local_to_utc(date_time: NaiveDateTime, name: &str) -> (DateTime<Utc>, bool) {
+    let zone = resolve(name);
+    let utc = match zone.tz.from_local_datetime(&date_time) {
+        LocalResult::Single(dt) => dt.with_timezone(&Utc),
+        LocalResult::Ambiguous(earliest, _) => earliest.with_timezone(&Utc),
+        LocalResult::None => match first_valid_after_gap(zone.tz, date_time) {
+            Some(dt) => {
+                warn!("timezone: {} does not exist in {} (DST gap), using {}", date_time, name, dt);
+                dt
+            }
+            None => {
+                warn!("timezone: {} does not exist in {}, treating it as UTC", date_time, name);
+                DateTime::from_naive_utc_and_offset(date_time, Utc)
+            }
+        },
+    };
+    (utc, zone.is_fallback)
+}
+
+/// Find the first valid instant at or after a wall-clock time that falls in a
+/// DST gap. Transitions happen on minute boundaries, so step minute by minute.
+fn first_valid_after_gap(tz: Tz, date_time: NaiveDateTime) -> Option<DateTime<Utc>> {
+    let start = date_time.with_second(0)?.with_nanosecond(0)?;
+    (1..=MAX_GAP_MINUTES)
+        .map(|minutes| start + Duration::minutes(minutes))
+        .find_map(|candidate| tz.from_local_datetime(&candidate).earliest())
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// Convert a time entered by the user to UTC.
+/// Times without a zone are floating wall-clock times stored as-is; zoned
+/// times are resolved through `local_to_utc` (including DST gaps).
+pub fn to_utc(date_time: NaiveDateTime, zone: Option<&str>) -> DateTime<Utc> {
+    match zone {
+        Some(name) => local_to_utc(date_time, name).0,
+        None => Utc.from_utc_datetime(&date_time),
+    }
+}
+
+/// Convert a stored UTC instant to wall-clock time in the named zone
+/// (UTC when the zone is unknown).
+pub fn utc_to_local(date_time: DateTime<Utc>, name: &str) -> NaiveDateTime {
+    date_time.with_timezone(&resolve(name).tz).naive_local()
+}
+
+/// Wall-clock time of a stored instant in the event's own zone.
+/// Floating times are stored as wall-clock time already.
+pub fn wall_clock(date_time: DateTime<Utc>, zone: Option<&str>) -> NaiveDateTime {
+    match zone {
+        Some(name) => utc_to_local(date_time, name),
+        None => date_time.naive_utc(),
+    }
+}
+
+/// Wall-clock time of a stored instant as shown in the views (system zone).
+/// Floating times are shown as-is and unknown zones in UTC.
+pub fn to_display(date_time: DateTime<Utc>, zone: Option<&str>) -> NaiveDateTime {
+    to_display_in(date_time, zone, system_zone())
+}
+
+fn to_display_in(date_time: DateTime<Utc>, zone: Option<&str>, display_zone: &str) -> NaiveDateTime {
+    match zone {
+        Some(name) if lookup(name).is_some() => utc_to_local(date_time, display_zone),
+        _ => date_time.naive_utc(),
+    }
+}
+
+/// IANA name of the system time zone, detected once.
+/// Checks `TZ`, then the `/etc/localtime` link, then `/etc/timezone`; UTC if none resolves.
+pub fn system_zone() -> &'static str {
+    static SYSTEM_ZONE: OnceLock<String> = OnceLock::new();
+    SYSTEM_ZONE.get_or_init(|| {
+        let from_env = std::env::var("TZ").ok().map(|tz| tz.trim_start_matches(':').to_string());
+        let from_link = std::fs::read_link("/etc/localtime").ok().and_then(|target| {
+            let target = target.to_string_lossy().into_owned();
+            target.split_once("zoneinfo/").map(|(_, name)| name.to_string())
+        });
+        let from_file = std::fs::read_to_string("/etc/timezone").ok().map(|name| name.trim().to_string());
+
+        let zone = [from_env, from_link, from_file]
+            .into_iter()
+            .flatten()
+            .find(|name| lookup(name).is_some())
+            .unwrap_or_else(|| UTC_ZONE.to_string());
+        debug!("timezone: System time zone is {}", zone);
+        zone
+    })
+}
+
+/// Yearly transition rule of an observance, e.g. `FREQ=YEARLY;BYMONTH=3;BYDAY=-1SU`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct YearlyRule {
+    month: u32,
+    /// Occurrence of the weekday in the month (1 = first, -1 = last)
+    week: i32,
+    weekday: Weekday,
+    /// Last local time the rule applies (from UNTIL)
+    until: Option<NaiveDateTime>,
+}
+
+impl YearlyRule {
+    /// Parse the subset of RRULE used by VTIMEZONE observances
+    fn parse(value: &str) -> Option<Self> {
+        let mut freq_yearly = false;
+        let mut month = None;
+        let mut by_day = None;
+        let mut until = None;
+
+        for part in value.split(';') {
+            let (key, val) = part.split_once('=')?;
+            match key.to_ascii_uppercase().as_str() {
+                "FREQ" => freq_yearly = val.eq_ignore_ascii_case("YEARLY"),
+                "BYMONTH" => month = val.parse::<u32>().ok().filter(|m| (1..=12).contains(m)),
+                "BYDAY" => by_day = parse_by_day(val),
+                "UNTIL" => until = parse_local_datetime(val),
+                "INTERVAL" if val != "1" => return None,
+                "WKST" | "INTERVAL" => {}
+                _ => return None,
+            }
+        }
+
+        let (week, weekday) = by_day?;
+        freq_yearly.then_some(YearlyRule { month: month?, week, weekday, until })
+    }
+
+    /// Date of the transition in a given year
+    fn date_in(&self, year: i32) -> Option<NaiveDate> {
+        if self.week > 0 {
+            NaiveDate::from_weekday_of_month_opt(year, self.month, self.weekday, self.week as u8)
+        } else {
+            let next_month = if self.month == 12 {
+                NaiveDate::from_ymd_opt(year + 1, 1, 1)?
+            } else {
+                NaiveDate::from_ymd_opt(year, self.month + 1, 1)?
+            };
+            let last_day = next_month.pred_opt()?;
+            let back = last_day.weekday().days_since(self.weekday) as i64;
+            let date = last_day - Duration::days(back) - Duration::weeks((-self.week - 1) as i64);
+            (date.month() == self.month).then_some(date)
+        }
+    }
+}
+
+/// Parse a BYDAY value with an occurrence, e.g. "-1SU" or "2SU"
+fn parse_by_day(value: &str) -> Option<(i32, Weekday)> {
+    let split = value.len().checked_sub(2).filter(|i| value.is_char_boundary(*i))?;
+    let (week, day) = value.split_at(split);
+    let week: i32 = week.trim_start_matches('+').parse().ok().filter(|w: &i32| *w != 0 && w.abs() <= 5)?;
+    let weekday = match day.to_ascii_uppercase().as_str() {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        _ => return None,
+    };
+    Some((week, weekday))
+}
+
+/// Parse a local or UTC DATE-TIME ("20250330T020000" or "...Z") as wall-clock time
+fn parse_local_datetime(value: &str) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(value.trim().trim_end_matches('Z'), "%Y%m%dT%H%M%S").ok()
+}
+
+/// Parse a UTC-OFFSET value ("+0100", "-0500", "+053000") into seconds east of UTC
+fn parse_utc_offset(value: &str) -> Option<i32> {
+    let value = value.trim();
+    let (sign, digits) = if let Some(rest) = value.strip_prefix('+') {
+        (1, rest)
+    } else {
+        (-1, value.strip_prefix('-')?)
+    };
+    if !(digits.len() == 4 || digits.len() == 6) || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let hours: i32 = digits[0..2].parse().ok()?;
+    let minutes: i32 = digits[2..4].parse().ok()?;
+    let seconds: i32 = digits.get(4..6).map_or(Some(0), |s| s.parse().ok())?;
+    Some(sign * (hours * 3600 + minutes * 60 + seconds))
+}
+
+/// A STANDARD or DAYLIGHT observance of a VTIMEZONE
+#[derive(Debug, Clone, PartialEq)]
+struct Observance {
+    /// First onset, in the wall-clock time before the transition
+    start: NaiveDateTime,
+    /// Offset in effect once the observance starts (seconds east of UTC)
+    offset_to: i32,
+    /// Yearly repetition of the onset (None = only at `start`)
+    rule: Option<YearlyRule>,
+}
+
+impl Observance {
+    /// Latest onset at or before a wall-clock time
+    fn latest_onset(&self, date_time: NaiveDateTime) -> Option<NaiveDateTime> {
+        let Some(rule) = self.rule else {
+            return (self.start <= date_time).then_some(self.start);
+        };
+        // After UNTIL the last onset is the one in the final year of the rule
+        let date_time = rule.until.map_or(date_time, |until| date_time.min(until));
+        [date_time.year(), date_time.year() - 1]
+            .into_iter()
+            .filter_map(|year| rule.date_in(year))
+            .map(|date| date.and_time(self.start.time()))
+            .find(|onset| *onset >= self.start && *onset <= date_time)
+    }
+}
+
+/// A time zone defined by an embedded VTIMEZONE component
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomZone {
+    /// The TZID events refer to
+    pub tzid: String,
+    observances: Vec<Observance>,
+}
+
+impl CustomZone {
+    /// UTC offset in seconds for a wall-clock time, from the observance that
+    /// started most recently. None when the time precedes every observance.
+    pub fn offset_at(&self, date_time: NaiveDateTime) -> Option<i32> {
+        self.observances
+            .iter()
+            .filter_map(|obs| obs.latest_onset(date_time).map(|onset| (onset, obs.offset_to)))
+            .max_by_key(|(onset, _)| *onset)
+            .map(|(_, offset)| offset)
+    }
+
+    /// Convert a wall-clock time in this zone to UTC
+    pub fn local_to_utc(&self, date_time: NaiveDateTime) -> Option<DateTime<Utc>> {
+        let offset = self.offset_at(date_time)?;
+        Some(Utc.from_utc_datetime(&(date_time - Duration::seconds(offset as i64))))
+    }
+}
+
+/// Parse all VTIMEZONE components in an iCalendar string.
+/// Zones with observances this parser can't follow (e.g. RDATE lists or
+/// unsupported RRULE parts) are skipped so their TZID falls back to IANA.
+pub fn parse_vtimezones(ical_str: &str) -> Vec<CustomZone> {
+    // Unfold continuation lines (RFC 5545 §3.1)
+    let unfolded = ical_str.replace("\r\n", "\n").replace("\n ", "").replace("\n\t", "");
+
+    let mut zones = Vec::new();
+    let mut tzid: Option<String> = None;
+    let mut observances = Vec::new();
+    let mut supported = true;
+    let mut in_zone = false;
+    // Properties of the observance being read: (DTSTART, TZOFFSETTO, RRULE)
+    let mut current: Option<(Option<NaiveDateTime>, Option<i32>, Option<YearlyRule>)> = None;
+
+    for line in unfolded.lines() {
+        let line = line.trim_end();
+        let Some((name_and_params, value)) = line.split_once(':') else { continue };
+        let name = name_and_params.split(';').next().unwrap_or_default().to_ascii_uppercase();
+
+        match (name.as_str(), value.to_ascii_uppercase().as_str()) {
+            ("BEGIN", "VTIMEZONE") => {
+                in_zone = true;
+                tzid = None;
+                observances.clear();
+                supported = true;
+            }
+            ("END", "VTIMEZONE") => {
+                in_zone = false;
+                match tzid.take() {
+                    Some(id) if supported && !observances.is_empty() => zones.push(CustomZone {
+                        tzid: id,
+                        observances: std::mem::take(&mut observances),
+                    }),
+                    Some(id) => warn!("timezone: Ignoring VTIMEZONE '{}' with unsupported rules", id),
+                    None => {}
+                }
+            }
+            ("BEGIN", "STANDARD" | "DAYLIGHT") if in_zone => current = Some((None, None, None)),
+            ("END", "STANDARD" | "DAYLIGHT") if in_zone => match current.take() {
+                Some((Some(start), Some(offset_to), rule)) => observances.push(Observance { start, offset_to, rule }),
+                _ => supported = false,
+            },
+            _ if !in_zone => {}
+            ("TZID", _) if current.is_none() => tzid = Some(value.trim().to_string()),
+            ("DTSTART", _) => match (current.as_mut(), parse_local_datetime(value)) {
+                (Some(obs), Some(start)) => obs.0 = Some(start),
+                _ => supported = false,
+            },
+            ("TZOFFSETTO", _) => match (current.as_mut(), parse_utc_offset(value)) {
+                (Some(obs), Some(offset)) => obs.1 = Some(offset),
+                _ => supported = false,
+            },
+            ("RRULE", _) => match (current.as_mut(), YearlyRule::parse(value)) {
+                (Some(obs), Some(rule)) => obs.2 = Some(rule),
+                _ => supported = false,
+            },
+            ("RDATE", _) => supported = false,
+            _ => {}
+        }
+    }
+
+    debug!("timezone: Parsed {} VTIMEZONE definition(s)", zones.len());
+    zones
+}
+
+/// Convert a wall-clock time to UTC, preferring an embedded VTIMEZONE
+/// definition for the TZID and falling back to the IANA lookup by name.
+///
+/// # Returns
+/// The UTC instant and whether the zone fell back to UTC
+pub fn local_to_utc_with_zones(date_time: NaiveDateTime, name: &str, zones: &[CustomZone]) -> (DateTime<Utc>, bool) {
+    zones
+        .iter()
+        .find(|zone| zone.tzid == name)
+        .and_then(|zone| zone.local_to_utc(date_time))
+        .map(|utc| (utc, false))
+        .unwrap_or_else(|| local_to_utc(date_time, name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn naive(h: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 1, 15).unwrap().and_hms_opt(h, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_known_zone_resolves() {
+        let zone = resolve("Europe/Berlin");
+        assert_eq!(zone.tz, chrono_tz::Europe::Berlin);
+        assert!(!zone.is_fallback);
+
+        // Berlin is UTC+1 in January
+        let (utc, fell_back) = local_to_utc(naive(10), "Europe/Berlin");
+        assert_eq!(utc.naive_utc(), naive(9));
+        assert!(!fell_back);
+    }
+
+    #[test]
+    fn test_unknown_zone_falls_back_to_utc() {
+        let zone = resolve("Mars/Olympus_Mons");
+        assert_eq!(zone.tz, Tz::UTC);
+        assert!(zone.is_fallback);
+
+        let (utc, fell_back) = local_to_utc(naive(10), "Mars/Olympus_Mons");
+        assert_eq!(utc.naive_utc(), naive(10));
+        assert!(fell_back);
+    }
+
+    #[test]
+    fn test_dst_gap_moves_to_next_valid_instant() {
+        // 02:30 doesn't exist in Berlin on 2025-03-30 (clocks jump 02:00 -> 03:00 CEST)
+        let gap = NaiveDate::from_ymd_opt(2025, 3, 30).unwrap().and_hms_opt(2, 30, 0).unwrap();
+        let (utc, fell_back) = local_to_utc(gap, "Europe/Berlin");
+        assert!(!fell_back);
+        // 03:00 CEST == 01:00 UTC
+        assert_eq!(utc.naive_utc(), NaiveDate::from_ymd_opt(2025, 3, 30).unwrap().and_hms_opt(1, 0, 0).unwrap());
+        assert_eq!(utc_to_local(utc, "Europe/Berlin").time(), chrono::NaiveTime::from_hms_opt(3, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_to_utc_floating_and_zoned() {
+        assert_eq!(to_utc(naive(10), None).naive_utc(), naive(10));
+        assert_eq!(to_utc(naive(10), Some("Europe/Berlin")).naive_utc(), naive(9));
+    }
+
+    #[test]
+    fn test_display_converts_zoned_times_only() {
+        // 09:00 in Berlin, before and after the switch to summer time
+        let winter = to_utc(naive(9), Some("Europe/Berlin"));
+        let summer_day = NaiveDate::from_ymd_opt(2025, 7, 15).unwrap().and_hms_opt(9, 0, 0).unwrap();
+        let summer = to_utc(summer_day, Some("Europe/Berlin"));
+
+        // London is one hour behind Berlin in both seasons
+        assert_eq!(to_display_in(winter, Some("Europe/Berlin"), "Europe/London"), naive(8));
+        assert_eq!(
+            to_display_in(summer, Some("Europe/Berlin"), "Europe/London"),
+            summer_day - Duration::hours(1)
+        );
+        assert_eq!(wall_clock(summer, Some("Europe/Berlin")), summer_day);
+
+        // Floating and unknown-zone times are shown as stored
+        assert_eq!(to_display_in(to_utc(naive(9), None), None, "Europe/London"), naive(9));
+        assert_eq!(to_display_in(to_utc(naive(9), Some("Not/A_Zone")), Some("Not/A_Zone"), "Asia/Tokyo"), naive(9));
+    }
+
+    #[test]
+    fn test_system_zone_resolves() {
+        assert!(lookup(system_zone()).is_some());
+    }
+
+    #[test]
+    fn test_is_fallback() {
+        assert!(!is_fallback(None));
+        assert!(!is_fallback(Some("America/New_York")));
+        assert!(is_fallback(Some("Not/A_Zone")));
+    }
+
+    const CUSTOM_EASTERN: &str = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+BEGIN:VTIMEZONE\r\n\
+TZID:Custom Eastern\r\n\
+BEGIN:STANDARD\r\n\
+DTSTART:19701101T020000\r\n\
+TZOFFSETFROM:-0400\r\n\
+TZOFFSETTO:-0500\r\n\
+RRULE:FREQ=YEARLY;BYMONTH=11;BYDAY=1SU\r\n\
+END:STANDARD\r\n\
+BEGIN:DAYLIGHT\r\n\
+DTSTART:19700308T020000\r\n\
+TZOFFSETFROM:-0500\r\n\
+TZOFFSETTO:-0400\r\n\
+RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=2SU\r\n\
+END:DAYLIGHT\r\n\
+END:VTIMEZONE\r\n\
+END:VCALENDAR\r\n";
+
+    fn at(month: u32, day: u32, h: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, month, day).unwrap().and_hms_opt(h, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_vtimezone_standard_daylight_offsets() {
+        let zones = parse_vtimezones(CUSTOM_EASTERN);
+        assert_eq!(zones.len(), 1);
+        let zone = &zones[0];
+        assert_eq!(zone.tzid, "Custom Eastern");
+
+        // Standard time in winter, daylight time between 2025-03-09 and 2025-11-02
+        assert_eq!(zone.offset_at(at(1, 15, 10)), Some(-5 * 3600));
+        assert_eq!(zone.offset_at(at(3, 9, 1)), Some(-5 * 3600));
+        assert_eq!(zone.offset_at(at(3, 9, 3)), Some(-4 * 3600));
+        assert_eq!(zone.offset_at(at(7, 1, 10)), Some(-4 * 3600));
+        assert_eq!(zone.offset_at(at(11, 2, 3)), Some(-5 * 3600));
+
+        assert_eq!(zone.local_to_utc(at(1, 15, 10)).unwrap().naive_utc(), at(1, 15, 15));
+        assert_eq!(zone.local_to_utc(at(7, 1, 10)).unwrap().naive_utc(), at(7, 1, 14));
+    }
+
+    #[test]
+    fn test_custom_zone_preferred_then_iana_fallback() {
+        let zones = parse_vtimezones(CUSTOM_EASTERN);
+
+        let (utc, fell_back) = local_to_utc_with_zones(at(7, 1, 10), "Custom Eastern", &zones);
+        assert_eq!(utc.naive_utc(), at(7, 1, 14));
+        assert!(!fell_back);
+
+        // TZIDs without an embedded definition use the IANA database
+        let (utc, fell_back) = local_to_utc_with_zones(naive(10), "Europe/Berlin", &zones);
+        assert_eq!(utc.naive_utc(), naive(9));
+        assert!(!fell_back);
+    }
+
+    #[test]
+    fn test_last_weekday_rule_and_unsupported_zones() {
+        let rule = YearlyRule::parse("FREQ=YEARLY;BYDAY=-1SU;BYMONTH=3").unwrap();
+        assert_eq!(rule.date_in(2025), NaiveDate::from_ymd_opt(2025, 3, 30));
+        assert_eq!(parse_utc_offset("+0530"), Some(5 * 3600 + 30 * 60));
+        assert_eq!(parse_utc_offset("0100"), None);
+
+        // Observances listed with RDATE aren't followed; the zone is dropped
+        let rdate_zone = CUSTOM_EASTERN.replace(
+            "RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=2SU",
+            "RDATE:20250309T020000",
+        );
+        assert!(parse_vtimezones(&rdate_zone).is_empty());
+    }
+}
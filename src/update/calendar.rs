@@ -1,11 +1,15 @@
 //! Calendar management handlers (create, edit, delete, toggle, color)
 
 use crate::app::CosmicCalendar;
+use crate::calendars::RemoteAccount;
 use crate::dialogs::{ActiveDialog, DialogManager};
-use crate::services::{CalendarHandler, ExportHandler, NewCalendarData, UpdateCalendarData};
+use crate::caldav::CalendarEvent;
+use crate::fl;
+use crate::services::{CalendarHandler, ExportHandler, NewCalendarData, SettingsHandler, SyncHandler, UpdateCalendarData};
 use chrono::Local;
 use cosmic::app::Task;
 use log::{debug, error, info, warn};
+use std::time::Instant;
 
 /// Toggle a calendar's enabled state and save configuration
 pub fn handle_toggle_calendar(app: &mut CosmicCalendar, id: String) {
@@ -23,6 +27,64 @@ pub fn handle_toggle_calendar(app: &mut CosmicCalendar, id: String) {
     }
 }
 
+/// Make the calendar at a sidebar index the default for new events, now and on startup
+pub fn handle_set_default_calendar(app: &mut CosmicCalendar, index: usize) {
+    let Some(calendar) = app.calendar_manager.sources().get(index) else {
+        return;
+    };
+    let id = calendar.info().id.clone();
+    debug!("handle_set_default_calendar: Setting '{}' as default", id);
+
+    if let Err(e) = SettingsHandler::set_default_calendar(&mut app.settings, Some(id.clone())) {
+        error!("Failed to set default calendar: {}", e);
+    }
+    app.selected_calendar_id = Some(id);
+    app.update_selected_calendar_color();
+}
+
+/// Pin or unpin the calendar at a sidebar index and save configuration
+pub fn handle_toggle_pin_calendar(app: &mut CosmicCalendar, index: usize) {
+    let Some(calendar) = app.calendar_manager.sources().get(index) else {
+        return;
+    };
+    let id = calendar.info().id.clone();
+
+    match CalendarHandler::toggle_pinned(&mut app.calendar_manager, &id) {
+        Ok(pinned) => info!("Calendar '{}' pinned={}", id, pinned),
+        Err(e) => error!("Failed to pin calendar '{}': {}", id, e),
+    }
+}
+
+/// Move the calendar at `index` to `new_index` in the list and save the order
+pub fn handle_move_calendar(app: &mut CosmicCalendar, index: usize, new_index: usize) {
+    let Some(calendar) = app.calendar_manager.sources().get(index) else {
+        return;
+    };
+    let id = calendar.info().id.clone();
+
+    if app.calendar_manager.reorder_calendar(&id, new_index) {
+        info!("Calendar '{}' moved to position {}", id, new_index);
+    }
+}
+
+/// Copy the calendar at `index` with all its events and select the copy
+pub fn handle_duplicate_calendar(app: &mut CosmicCalendar, index: usize) {
+    let Some(calendar) = app.calendar_manager.sources().get(index) else {
+        return;
+    };
+    let id = calendar.info().id.clone();
+
+    match app.calendar_manager.duplicate_calendar(&id) {
+        Ok(new_id) => {
+            info!("Calendar '{}' duplicated as '{}'", id, new_id);
+            app.selected_calendar_id = Some(new_id);
+            app.update_selected_calendar_color();
+            app.refresh_cached_events();
+        }
+        Err(e) => error!("Failed to duplicate calendar '{}': {}", id, e),
+    }
+}
+
 /// Change a calendar's color and save configuration
 pub fn handle_change_calendar_color(app: &mut CosmicCalendar, id: String, color: String) {
     debug!("handle_change_calendar_color: Changing color for '{}' to '{}'", id, color);
@@ -54,6 +116,7 @@ pub fn handle_open_calendar_dialog_create(app: &mut CosmicCalendar) {
         ActiveDialog::CalendarCreate {
             name: String::new(),
             color: default_color,
+            mute_alerts: false,
         },
     );
 }
@@ -64,12 +127,14 @@ pub fn handle_open_calendar_dialog_edit(app: &mut CosmicCalendar, calendar_id: S
 
     match CalendarHandler::get_info(&app.calendar_manager, &calendar_id) {
         Ok((name, color, _enabled)) => {
+            let mute_alerts = app.calendar_manager.alerts_muted(&calendar_id);
             DialogManager::open(
                 &mut app.active_dialog,
                 ActiveDialog::CalendarEdit {
                     calendar_id,
                     name,
                     color,
+                    mute_alerts,
                 },
             );
         }
@@ -83,16 +148,16 @@ pub fn handle_open_calendar_dialog_edit(app: &mut CosmicCalendar, calendar_id: S
 pub fn handle_confirm_calendar_dialog(app: &mut CosmicCalendar) {
     // Extract data from active_dialog before closing
     let dialog_data = match &app.active_dialog {
-        ActiveDialog::CalendarCreate { name, color } => {
-            Some((None, name.clone(), color.clone()))
+        ActiveDialog::CalendarCreate { name, color, mute_alerts } => {
+            Some((None, name.clone(), color.clone(), *mute_alerts))
         }
-        ActiveDialog::CalendarEdit { calendar_id, name, color } => {
-            Some((Some(calendar_id.clone()), name.clone(), color.clone()))
+        ActiveDialog::CalendarEdit { calendar_id, name, color, mute_alerts } => {
+            Some((Some(calendar_id.clone()), name.clone(), color.clone(), *mute_alerts))
         }
         _ => None,
     };
 
-    let Some((calendar_id_opt, name, color)) = dialog_data else {
+    let Some((calendar_id_opt, name, color, mute_alerts)) = dialog_data else {
         return;
     };
 
@@ -119,6 +184,17 @@ pub fn handle_confirm_calendar_dialog(app: &mut CosmicCalendar) {
             ) {
                 Ok(id) => {
                     info!("Calendar '{}' created with id '{}'", name, id);
+                    if mute_alerts {
+                        let muted = UpdateCalendarData {
+                            name: None,
+                            color: None,
+                            enabled: None,
+                            mute_alerts: Some(true),
+                        };
+                        if let Err(e) = CalendarHandler::update(&mut app.calendar_manager, &id, muted) {
+                            error!("Failed to mute alerts of calendar '{}': {}", id, e);
+                        }
+                    }
                     // Select the new calendar
                     app.selected_calendar_id = Some(id);
                     app.update_selected_calendar_color();
@@ -139,6 +215,7 @@ pub fn handle_confirm_calendar_dialog(app: &mut CosmicCalendar) {
                     name: Some(name.to_string()),
                     color: Some(color),
                     enabled: None,
+                    mute_alerts: Some(mute_alerts),
                 },
             ) {
                 Ok(()) => {
@@ -202,10 +279,7 @@ pub fn handle_confirm_delete_calendar(app: &mut CosmicCalendar) {
             info!("Calendar '{}' deleted", calendar_id);
 
             // If we deleted the selected calendar, select another one
-            if app.selected_calendar_id.as_ref() == Some(&calendar_id) {
-                app.selected_calendar_id = CalendarHandler::get_first_calendar_id(&app.calendar_manager);
-                app.update_selected_calendar_color();
-            }
+            app.ensure_selected_calendar();
 
             // Refresh events in case any events from the deleted calendar were displayed
             app.refresh_cached_events();
@@ -216,6 +290,168 @@ pub fn handle_confirm_delete_calendar(app: &mut CosmicCalendar) {
     }
 }
 
+/// Sync a calendar with its backend.
+/// Remote calendars are fetched on a background thread and show a loading indicator
+/// until `CalendarSynced` arrives; local calendars sync in place.
+pub fn handle_sync_calendar(app: &mut CosmicCalendar, calendar_id: String) -> Task<crate::message::Message> {
+    let Some(calendar) = app
+        .calendar_manager
+        .sources_mut()
+        .iter_mut()
+        .find(|c| c.info().id == calendar_id)
+    else {
+        warn!("handle_sync_calendar: Calendar '{}' not found", calendar_id);
+        return Task::none();
+    };
+
+    let Some(client) = SyncHandler::begin_remote_sync(calendar.as_mut()) else {
+        if let Err(e) = SyncHandler::sync_calendar(&mut app.calendar_manager, &calendar_id) {
+            error!("handle_sync_calendar: {}", e);
+        }
+        app.refresh_cached_events();
+        return Task::none();
+    };
+
+    let db = app.calendar_manager.database();
+    let sync_id = calendar_id.clone();
+    Task::perform(
+        async move {
+            // The CalDAV client is blocking - run it on its own thread so the UI stays responsive.
+            // Local changes go up first; the database is never locked during a request.
+            let (sender, receiver) = tokio::sync::oneshot::channel();
+            std::thread::spawn(move || {
+                let result = SyncHandler::upload_pending_changes(&db, &sync_id, &client).and_then(|_| {
+                    SyncHandler::fetch_and_store_remote(&db, &sync_id, || client.fetch_resources())
+                });
+                let _ = sender.send(result);
+            });
+            receiver
+                .await
+                .unwrap_or_else(|_| Err("Sync thread stopped unexpectedly".to_string()))
+        },
+        move |result| cosmic::Action::App(crate::message::Message::CalendarSynced(calendar_id, result)),
+    )
+}
+
+/// Sync every enabled calendar that isn't already syncing.
+/// Remote calendars are fetched in parallel and each reports back through `CalendarSynced`.
+pub fn handle_sync_all_calendars(app: &mut CosmicCalendar) -> Task<crate::message::Message> {
+    let calendar_ids: Vec<String> = app
+        .calendar_manager
+        .sources()
+        .iter()
+        .filter(|c| c.is_enabled() && !c.info().loading)
+        .map(|c| c.info().id.clone())
+        .collect();
+    info!("handle_sync_all_calendars: Syncing {} calendars", calendar_ids.len());
+
+    let tasks: Vec<_> = calendar_ids
+        .into_iter()
+        .map(|calendar_id| handle_sync_calendar(app, calendar_id))
+        .collect();
+    Task::batch(tasks)
+}
+
+/// Sync the remote calendars with local changes waiting for upload.
+/// Calendars already syncing keep their changes for the next round.
+pub fn sync_unsent_changes(app: &mut CosmicCalendar) -> Task<crate::message::Message> {
+    let calendar_ids: Vec<String> = app
+        .calendar_manager
+        .sources_mut()
+        .iter_mut()
+        .filter(|c| !c.info().loading && c.take_unsent_changes())
+        .map(|c| c.info().id.clone())
+        .collect();
+    if calendar_ids.is_empty() {
+        return Task::none();
+    }
+
+    debug!("sync_unsent_changes: Uploading changes of {} calendars", calendar_ids.len());
+    let tasks: Vec<_> = calendar_ids
+        .into_iter()
+        .map(|calendar_id| handle_sync_calendar(app, calendar_id))
+        .collect();
+    Task::batch(tasks)
+}
+
+/// Apply the result of a background calendar sync and clear its loading indicator.
+/// Failures keep the offline copy and are reported in a toast.
+pub fn handle_calendar_synced(
+    app: &mut CosmicCalendar,
+    calendar_id: String,
+    result: Result<Vec<CalendarEvent>, String>,
+) {
+    let Some(calendar) = app
+        .calendar_manager
+        .sources_mut()
+        .iter_mut()
+        .find(|c| c.info().id == calendar_id)
+    else {
+        // Calendar was deleted while syncing
+        debug!("handle_calendar_synced: Calendar '{}' no longer exists", calendar_id);
+        return;
+    };
+
+    let calendar_name = calendar.info().name.clone();
+    let failure = result.as_ref().err().cloned();
+    if let Err(e) = SyncHandler::finish_remote_sync(calendar.as_mut(), result) {
+        error!("handle_calendar_synced: {}", e);
+    }
+    if let Some(reason) = failure {
+        app.toast.show(
+            fl!("toast-sync-failed", calendar = calendar_name, reason = reason),
+            None,
+            Instant::now(),
+        );
+    }
+    app.refresh_cached_events();
+}
+
+/// Open the dialog for adding a CalDAV account
+pub fn handle_open_caldav_account_dialog(app: &mut CosmicCalendar) {
+    DialogManager::open(
+        &mut app.active_dialog,
+        ActiveDialog::CalDavAccount {
+            name: String::new(),
+            server_url: String::new(),
+            username: String::new(),
+            password: String::new(),
+            error: None,
+        },
+    );
+}
+
+/// Add the CalDAV calendar entered in the account dialog and start its first sync.
+/// On failure the dialog stays open and shows the error.
+pub fn handle_confirm_caldav_account(app: &mut CosmicCalendar) -> Task<crate::message::Message> {
+    let ActiveDialog::CalDavAccount { name, server_url, username, password, .. } = &app.active_dialog else {
+        return Task::none();
+    };
+    let name = name.clone();
+    let account = RemoteAccount {
+        server_url: server_url.trim().to_string(),
+        username: username.trim().to_string(),
+    };
+    let password = password.clone();
+
+    match CalendarHandler::create_caldav(&mut app.calendar_manager, &name, account, &password) {
+        Ok(id) => {
+            info!("CalDAV calendar '{}' added with id '{}'", name, id);
+            DialogManager::close(&mut app.active_dialog);
+            app.selected_calendar_id = Some(id.clone());
+            app.update_selected_calendar_color();
+            handle_sync_calendar(app, id)
+        }
+        Err(e) => {
+            error!("Failed to add CalDAV calendar: {}", e);
+            if let ActiveDialog::CalDavAccount { error, .. } = &mut app.active_dialog {
+                *error = Some(e.to_string());
+            }
+            Task::none()
+        }
+    }
+}
+
 /// Open a file save dialog to export a calendar to an iCalendar file
 pub fn handle_export_calendar_dialog(
     app: &mut CosmicCalendar,
@@ -3,15 +3,25 @@
 //! These handlers delegate to the EventHandler service for actual event operations.
 //! This ensures consistent validation, routing, and cache management.
 
-use chrono::{NaiveDate, NaiveTime, TimeZone, Timelike, Utc};
+use chrono::{Datelike, NaiveDate, NaiveTime, Timelike};
+use cosmic::app::Task;
 use cosmic::widget::{calendar::CalendarModel, text_editor};
 use log::{debug, error, info, warn};
 use uuid::Uuid;
 
 use crate::app::{CosmicCalendar, EventDialogState};
-use crate::caldav::{AlertTime, CalendarEvent, RepeatFrequency, TravelTime};
+use crate::caldav::{AlertTime, CalendarEvent, EventStatus, RecurrenceScope, RepeatFrequency, RepeatPeriod, TravelTime};
+use crate::calendars::CalendarManager;
+use crate::components::input_error_label;
 use crate::dialogs::{DialogAction, DialogManager, QuickEventResult};
-use crate::services::EventHandler;
+use crate::fl;
+use crate::localized_names::get_weekday_full;
+use crate::message::Message;
+use crate::models::{EventChange, UndoAction};
+use crate::selection::ResizeEdge;
+use crate::services::{show_notification, EventHandler, EventResult, ALERT_LOOKAHEAD_DAYS};
+use crate::settings::NewEventMode;
+use crate::timezone;
 
 /// Extract the master UID from an occurrence UID
 /// Occurrence UIDs have format "master-uid_YYYYMMDD" for recurring events
@@ -78,7 +88,11 @@ pub fn handle_commit_quick_event(app: &mut CosmicCalendar) {
         return;
     };
 
-    // Determine if this is a timed event or all-day event
+    // Determine if this is a timed event or all-day event; calendars that
+    // default to all-day take selected time ranges as whole days
+    let calendar_all_day = app.calendar_manager.default_all_day(&calendar_id);
+    let evt_start_time = evt_start_time.filter(|_| !calendar_all_day);
+    let evt_end_time = evt_end_time.filter(|_| !calendar_all_day);
     let is_timed = evt_start_time.is_some();
     let is_multi_day = start_date != end_date;
 
@@ -110,8 +124,10 @@ pub fn handle_commit_quick_event(app: &mut CosmicCalendar) {
         (midnight, end_of_day, true)
     };
 
-    let start = Utc.from_utc_datetime(&start_date.and_time(start_time));
-    let end = Utc.from_utc_datetime(&end_date.and_time(end_time));
+    // Timed quick events are in the system zone; all-day events are floating
+    let zone = (!all_day).then(|| timezone::system_zone().to_string());
+    let start = timezone::to_utc(start_date.and_time(start_time), zone.as_deref());
+    let end = timezone::to_utc(end_date.and_time(end_time), zone.as_deref());
 
     let event = CalendarEvent {
         uid: Uuid::new_v4().to_string(),
@@ -123,22 +139,32 @@ pub fn handle_commit_quick_event(app: &mut CosmicCalendar) {
         travel_time: TravelTime::None,
         repeat: RepeatFrequency::Never,
         repeat_until: None,
+        repeat_count: None,
+        repeat_interval: 1,
+        repeat_weekdays: vec![],
         exception_dates: vec![],
         invitees: vec![],
-        alert: AlertTime::None,
+        alert: if all_day { app.settings.all_day_alert.clone() } else { AlertTime::None },
         alert_second: None,
         attachments: vec![],
         url: None,
         notes: None,
+        status: EventStatus::Confirmed,
+        timezone: zone,
+        categories: vec![],
+        color: None,
+        overrides: vec![],
     };
 
     // Use EventHandler to add the event (handles validation, storage, and sync)
+    let change = EventChange::created(&calendar_id, event.clone());
     if let Err(e) = EventHandler::add_event(&mut app.calendar_manager, &calendar_id, event) {
         error!("handle_commit_quick_event: Failed to add event: {}", e);
         return;
     }
 
     info!("handle_commit_quick_event: Event created successfully");
+    record_changes(app, vec![change]);
     // Refresh the cached events to show the new event
     app.refresh_cached_events();
 }
@@ -160,6 +186,9 @@ pub fn handle_delete_event(app: &mut CosmicCalendar, uid: String) {
         }
     }
 
+    // Keep a copy of the event so the deletion can be undone
+    let deleted_event = EventHandler::find_event(&app.calendar_manager, master_uid).ok();
+
     // Use EventHandler to delete the event (searches all calendars)
     // Use master_uid to find the actual event in the database
     // Now returns Result<bool> with verification
@@ -167,6 +196,14 @@ pub fn handle_delete_event(app: &mut CosmicCalendar, uid: String) {
         Ok(was_deleted) => {
             if was_deleted {
                 info!("handle_delete_event: Event deleted and verified");
+                if let Some((event, calendar_id)) = deleted_event {
+                    record_changes(app, vec![EventChange::deleted(&calendar_id, event.clone())]);
+                    app.toast.show(
+                        fl!("toast-event-deleted"),
+                        Some(UndoAction::RestoreDeletedEvent(calendar_id, Box::new(event))),
+                        std::time::Instant::now(),
+                    );
+                }
             } else {
                 info!("handle_delete_event: Event was not found (may already be deleted)");
             }
@@ -186,11 +223,210 @@ pub fn handle_delete_event(app: &mut CosmicCalendar, uid: String) {
     info!("handle_delete_event: UI cache refreshed");
 }
 
+/// Delete the occurrence on `date` of a recurring event and all later ones
+/// by ending the series the day before (UNTIL)
+pub fn handle_delete_following_occurrences(app: &mut CosmicCalendar, uid: String, date: NaiveDate) {
+    let master_uid = extract_master_uid(&uid);
+    let (series, calendar_id) = match EventHandler::find_event(&app.calendar_manager, master_uid) {
+        Ok(found) => found,
+        Err(e) => {
+            warn!("handle_delete_following_occurrences: Event not found: {}", e);
+            return;
+        }
+    };
+
+    let Some(ended) = EventHandler::end_series_before(series.clone(), date) else {
+        // From the first occurrence on, nothing of the series is left
+        handle_delete_event(app, master_uid.to_string());
+        return;
+    };
+
+    info!("handle_delete_following_occurrences: Ending event uid={} before {}", master_uid, date);
+    match EventHandler::update_event(&mut app.calendar_manager, &calendar_id, ended.clone()) {
+        Ok(()) => {
+            record_changes(app, vec![EventChange::edited(&calendar_id, series, ended)]);
+            app.selected_event_uid = None;
+            app.refresh_cached_events();
+        }
+        Err(e) => {
+            error!("handle_delete_following_occurrences: Failed to update event: {}", e);
+        }
+    }
+}
+
+/// Select every event visible in the current view (Ctrl+A)
+/// Ignored while a dialog is open so the shortcut keeps working in text inputs
+pub fn handle_select_all_events_in_view(app: &mut CosmicCalendar) {
+    if app.active_dialog.is_open() || app.event_dialog.is_some() {
+        debug!("handle_select_all_events_in_view: Dialog open, ignoring");
+        return;
+    }
+
+    app.selected_event_uids = app.visible_event_ids();
+    app.selected_event_uid = None;
+    info!("handle_select_all_events_in_view: Selected {} events", app.selected_event_uids.len());
+
+    if !app.selected_event_uids.is_empty() {
+        app.toast.show(
+            fl!("toast-events-selected", count = app.selected_event_uids.len()),
+            None,
+            std::time::Instant::now(),
+        );
+    }
+}
+
+/// Delete all events in the multi-selection that are still visible
+/// A selected occurrence of a recurring event removes only that occurrence (EXDATE),
+/// like "Delete this event" does for a single selection
+pub fn handle_delete_selected_events(app: &mut CosmicCalendar) {
+    let visible = app.visible_event_ids();
+    let selected = std::mem::take(&mut app.selected_event_uids);
+    app.selected_event_uid = None;
+
+    // Selection ids have format "calendar_id:uid". Occurrences are grouped under
+    // their series (calendar_id, master_uid, occurrence dates) so each series is written once
+    let mut targets: Vec<(String, String, Vec<NaiveDate>)> = Vec::new();
+    for id in selected.iter().filter(|id| visible.contains(id)) {
+        let Some((calendar_id, uid)) = id.split_once(':') else {
+            continue;
+        };
+        let master_uid = extract_master_uid(uid);
+        let occurrence_date = extract_occurrence_date(uid);
+        match targets.iter_mut().find(|(c, u, _)| c == calendar_id && u == master_uid) {
+            Some((_, _, dates)) => dates.extend(occurrence_date),
+            None => targets.push((calendar_id.to_string(), master_uid.to_string(), occurrence_date.into_iter().collect())),
+        }
+    }
+    info!("handle_delete_selected_events: Deleting from {} events", targets.len());
+
+    let mut changes: Vec<EventChange> = Vec::new();
+    let mut restored: Vec<(String, CalendarEvent)> = Vec::new();
+    let mut removed = 0;
+    for (calendar_id, master_uid, dates) in targets {
+        let event = match EventHandler::find_event_in_calendar(&app.calendar_manager, &calendar_id, &master_uid) {
+            Ok(event) => event,
+            Err(e) => {
+                debug!("handle_delete_selected_events: {}, skipping", e);
+                continue;
+            }
+        };
+
+        let result = if dates.is_empty() || matches!(event.repeat, RepeatFrequency::Never) {
+            EventHandler::delete_event_in_calendar(&mut app.calendar_manager, &calendar_id, &master_uid)
+                .map(|()| (EventChange::deleted(&calendar_id, event.clone()), 1))
+        } else {
+            let skipped = EventHandler::skip_occurrences(event.clone(), &dates);
+            EventHandler::update_event(&mut app.calendar_manager, &calendar_id, skipped.clone())
+                .map(|()| (EventChange::edited(&calendar_id, event.clone(), skipped), dates.len()))
+        };
+        match result {
+            Ok((change, count)) => {
+                changes.push(change);
+                restored.push((calendar_id, event));
+                removed += count;
+            }
+            Err(e) => error!("handle_delete_selected_events: Failed to delete uid={}: {}", master_uid, e),
+        }
+    }
+
+    app.cached_week_events.clear();
+    app.cached_month_events.clear();
+    app.refresh_cached_events();
+
+    if !changes.is_empty() {
+        record_changes(app, changes);
+        app.toast.show(
+            fl!("toast-events-deleted", count = removed),
+            Some(UndoAction::RestoreDeletedEvents(restored)),
+            std::time::Instant::now(),
+        );
+    }
+}
+
+/// Revert the newest recorded event change (Ctrl+Z or the toast's "Undo" button)
+/// Ignored while a dialog is open so the shortcut keeps working in text inputs
+pub fn handle_undo(app: &mut CosmicCalendar) {
+    if app.active_dialog.is_open() || app.event_dialog.is_some() {
+        debug!("handle_undo: Dialog open, ignoring");
+        return;
+    }
+
+    // A toast's undo action always describes the newest change, which is reverted below
+    match app.toast.take_undo() {
+        Some(UndoAction::RestoreDeletedEvent(calendar_id, event)) => {
+            info!("handle_undo: Restoring deleted event uid={} in calendar '{}'", event.uid, calendar_id);
+        }
+        Some(UndoAction::RestoreDeletedEvents(events)) => {
+            info!("handle_undo: Restoring {} deleted events", events.len());
+        }
+        None => {}
+    }
+
+    let Some(changes) = app.undo_stack.undo() else {
+        debug!("handle_undo: Nothing to undo");
+        return;
+    };
+    info!("handle_undo: Reverting {} event changes", changes.len());
+    apply_changes(app, changes);
+}
+
+/// Apply the newest undone event change again (Ctrl+Shift+Z)
+pub fn handle_redo(app: &mut CosmicCalendar) {
+    if app.active_dialog.is_open() || app.event_dialog.is_some() {
+        debug!("handle_redo: Dialog open, ignoring");
+        return;
+    }
+
+    let Some(changes) = app.undo_stack.redo() else {
+        debug!("handle_redo: Nothing to redo");
+        return;
+    };
+    info!("handle_redo: Reapplying {} event changes", changes.len());
+    apply_changes(app, changes);
+}
+
+/// Record the event changes of one user action so they can be undone
+fn record_changes(app: &mut CosmicCalendar, changes: Vec<EventChange>) {
+    // An older toast's "Undo" would now revert this newer change instead of the one it announces
+    if app.toast.current().is_some_and(|toast| toast.undo.is_some()) {
+        app.toast.dismiss();
+    }
+    app.undo_stack.push(changes);
+}
+
+/// Write recorded event changes to the calendars and refresh the views
+fn apply_changes(app: &mut CosmicCalendar, changes: Vec<EventChange>) {
+    for change in changes {
+        let result = match (change.before, change.after) {
+            (None, Some(after)) => EventHandler::add_event(&mut app.calendar_manager, &after.calendar_id, after.event),
+            (Some(before), None) => {
+                EventHandler::delete_event_in_calendar(&mut app.calendar_manager, &before.calendar_id, &before.event.uid)
+            }
+            (Some(before), Some(after)) if before.calendar_id == after.calendar_id => {
+                EventHandler::update_event(&mut app.calendar_manager, &after.calendar_id, after.event)
+            }
+            (Some(before), Some(after)) => EventHandler::delete_event_in_calendar(&mut app.calendar_manager, &before.calendar_id, &before.event.uid)
+                .and_then(|_| EventHandler::add_event(&mut app.calendar_manager, &after.calendar_id, after.event)),
+            (None, None) => Ok(()),
+        };
+        if let Err(e) = result {
+            error!("apply_changes: Failed to apply event change: {}", e);
+        }
+    }
+
+    app.cached_week_events.clear();
+    app.cached_month_events.clear();
+    app.refresh_cached_events();
+}
+
 /// Select an event for viewing/editing
 /// Toggles selection - clicking the same event again deselects it
 pub fn handle_select_event(app: &mut CosmicCalendar, uid: String) {
     debug!("handle_select_event: uid={}", uid);
 
+    // A single click replaces any multi-selection
+    app.selected_event_uids.clear();
+
     // Toggle selection: if already selected, deselect
     if app.selected_event_uid.as_ref() == Some(&uid) {
         app.selected_event_uid = None;
@@ -221,8 +457,11 @@ pub fn handle_drag_event_start(
     // Create unique_id for caching (calendar_id:uid)
     let unique_id = format!("{}:{}", calendar_id, uid);
 
-    // Start the drag operation with display info for the preview
+    // Start the drag operation with display info for the preview; the drag
+    // threshold is measured from where the event was pressed
     app.event_drag_state.start(calendar_id, uid, original_date, summary, color);
+    let (x, y) = app.cursor_position;
+    app.event_drag_state.set_origin(x, y);
 
     // Cache the unique_id for UI rendering
     app.dragging_event_unique_id = Some(unique_id);
@@ -240,8 +479,13 @@ pub fn handle_drag_event_end(app: &mut CosmicCalendar) {
     let event_uid = app.event_drag_state.event_uid.clone();
     let calendar_id_opt = app.event_drag_state.calendar_id.clone();
 
-    // Try to end the drag and get move info
-    let move_result = app.event_drag_state.end();
+    // Tiny cursor movements during a click must not move the event
+    let move_result = if app.event_drag_state.is_past_drag_threshold() {
+        app.event_drag_state.end()
+    } else {
+        app.event_drag_state.cancel();
+        None
+    };
 
     match move_result {
         Some((calendar_id, uid, original_date, new_date)) => {
@@ -251,36 +495,17 @@ pub fn handle_drag_event_end(app: &mut CosmicCalendar) {
             info!("handle_drag_event_end: Moving calendar={} event={} (master_uid={}) from {} to {}",
                   calendar_id, uid, master_uid, original_date, new_date);
 
-            // Calculate the offset in days
-            let offset = (new_date - original_date).num_days();
-
             // Find the event in the specific calendar (use master UID for recurring events)
             if let Ok(event) = EventHandler::find_event_in_calendar(&app.calendar_manager, &calendar_id, master_uid) {
-                // Calculate new start and end times by adding the offset
-                let new_start = event.start + chrono::Duration::days(offset);
-                let new_end = event.end + chrono::Duration::days(offset);
-
-                // Create updated event with new dates
-                let updated_event = crate::caldav::CalendarEvent {
-                    start: new_start,
-                    end: new_end,
-                    ..event
-                };
-
-                // Update the event
-                if let Err(e) = EventHandler::update_event(&mut app.calendar_manager, &calendar_id, updated_event) {
-                    error!("handle_drag_event_end: Failed to move event: {}", e);
+                if !save_moved_event(app, &calendar_id, event, original_date, new_date) {
                     return;
                 }
-
-                info!("handle_drag_event_end: Event moved successfully");
-                app.refresh_cached_events();
             } else {
                 warn!("handle_drag_event_end: Event not found: {}", uid);
             }
         }
         None => {
-            // Event wasn't moved (clicked and released on same date) - treat as selection
+            // Event wasn't moved (clicked, or released on the same date) - treat as selection
             if let (Some(calendar_id), Some(uid)) = (calendar_id_opt, event_uid) {
                 // Create unique_id for selection (calendar_id:uid)
                 let unique_id = format!("{}:{}", calendar_id, uid);
@@ -299,6 +524,116 @@ pub fn handle_drag_event_end(app: &mut CosmicCalendar) {
     app.dragging_event_unique_id = None;
 }
 
+/// Shift an event by the days between `from` and `to`, keeping its duration and its
+/// wall-clock time of day in its own zone (also across a DST change)
+fn event_moved_to_date(event: CalendarEvent, from: NaiveDate, to: NaiveDate) -> CalendarEvent {
+    let offset = chrono::Duration::days((to - from).num_days());
+    let zone = CalendarManager::event_zone(&event);
+    let start = timezone::to_utc(timezone::wall_clock(event.start, zone) + offset, zone);
+    CalendarEvent {
+        start,
+        end: start + (event.end - event.start),
+        ..event
+    }
+}
+
+/// Save an event moved from `from` to `to` and refresh the views.
+/// Returns false if the update failed.
+fn save_moved_event(app: &mut CosmicCalendar, calendar_id: &str, event: CalendarEvent, from: NaiveDate, to: NaiveDate) -> bool {
+    let updated_event = event_moved_to_date(event.clone(), from, to);
+    let change = EventChange::edited(calendar_id, event, updated_event.clone());
+    if let Err(e) = EventHandler::update_event(&mut app.calendar_manager, calendar_id, updated_event) {
+        error!("save_moved_event: Failed to move event: {}", e);
+        return false;
+    }
+
+    info!("save_moved_event: Event moved from {} to {}", from, to);
+    record_changes(app, vec![change]);
+    app.refresh_cached_events();
+    true
+}
+
+/// Reschedule an event to today, keeping its time of day (timed) or making it today (all-day).
+/// For occurrences of recurring events the series is shifted like a drag of that occurrence.
+pub fn handle_move_event_to_today(app: &mut CosmicCalendar, uid: String) {
+    let master_uid = extract_master_uid(&uid);
+    let today = chrono::Local::now().date_naive();
+
+    match EventHandler::find_event(&app.calendar_manager, master_uid) {
+        Ok((event, calendar_id)) => {
+            // Occurrences move relative to the clicked occurrence, single events from their
+            // start; both are wall-clock dates in the event's own zone
+            let from = extract_occurrence_date(&uid).unwrap_or_else(|| CalendarManager::series_start_date(&event));
+            if from == today {
+                debug!("handle_move_event_to_today: Event {} is already today", uid);
+                return;
+            }
+            info!("handle_move_event_to_today: Moving event={} (master_uid={}) from {} to today", uid, master_uid, from);
+            save_moved_event(app, &calendar_id, event, from, today);
+        }
+        Err(e) => warn!("handle_move_event_to_today: Event not found: {} ({})", uid, e),
+    }
+}
+
+/// Copy of an event shifted forward by one period, with a fresh UID.
+/// The end moves by the same amount as the start so the duration is kept.
+fn event_repeated_forward(event: &CalendarEvent, period: RepeatPeriod) -> CalendarEvent {
+    let start = period.shift(event.start, CalendarManager::event_zone(event));
+    CalendarEvent {
+        uid: Uuid::new_v4().to_string(),
+        start,
+        end: start + (event.end - event.start),
+        ..event.clone()
+    }
+}
+
+/// Create a copy of a single event one week/month later.
+/// A lightweight alternative to recurrence for events repeated by hand;
+/// occurrences of recurring events are left alone.
+pub fn handle_repeat_event_forward(app: &mut CosmicCalendar, uid: String, period: RepeatPeriod) {
+    match EventHandler::find_event(&app.calendar_manager, extract_master_uid(&uid)) {
+        Ok((event, calendar_id)) => {
+            if event.repeat != RepeatFrequency::Never {
+                debug!("handle_repeat_event_forward: Event {} already recurs, not copying", uid);
+                return;
+            }
+            let copy = event_repeated_forward(&event, period);
+            info!("handle_repeat_event_forward: Copying event={} to {} as uid={}", uid, copy.start.date_naive(), copy.uid);
+            let change = EventChange::created(&calendar_id, copy.clone());
+            if let Err(e) = EventHandler::add_event(&mut app.calendar_manager, &calendar_id, copy) {
+                error!("handle_repeat_event_forward: Failed to add copy: {}", e);
+                return;
+            }
+            record_changes(app, vec![change]);
+            app.refresh_cached_events();
+        }
+        Err(e) => warn!("handle_repeat_event_forward: Event not found: {} ({})", uid, e),
+    }
+}
+
+/// Copy an event as a new event with a fresh UID in the same calendar and open
+/// the copy in the edit dialog. Everything else is kept, including invitees,
+/// attachments, alerts and recurrence; an occurrence duplicates its whole series.
+pub fn handle_duplicate_event(app: &mut CosmicCalendar, uid: String) {
+    match EventHandler::find_event(&app.calendar_manager, extract_master_uid(&uid)) {
+        Ok((event, calendar_id)) => {
+            let copy = CalendarManager::event_with_fresh_uid(event);
+            let copy_uid = copy.uid.clone();
+            info!("handle_duplicate_event: Copying event={} as uid={}", uid, copy_uid);
+            let change = EventChange::created(&calendar_id, copy.clone());
+            if let Err(e) = EventHandler::add_event(&mut app.calendar_manager, &calendar_id, copy) {
+                error!("handle_duplicate_event: Failed to add copy: {}", e);
+                return;
+            }
+            record_changes(app, vec![change]);
+            app.refresh_cached_events();
+            app.selected_event_uid = Some(format!("{}:{}", calendar_id, copy_uid));
+            handle_open_edit_event_dialog(app, calendar_id, copy_uid);
+        }
+        Err(e) => warn!("handle_duplicate_event: Event not found: {} ({})", uid, e),
+    }
+}
+
 /// Cancel the drag operation
 pub fn handle_drag_event_cancel(app: &mut CosmicCalendar) {
     debug!("handle_drag_event_cancel: Cancelling drag");
@@ -307,6 +642,63 @@ pub fn handle_drag_event_cancel(app: &mut CosmicCalendar) {
     app.dragging_event_unique_id = None;
 }
 
+// === Event Resize Handlers ===
+
+/// Start dragging the top or bottom edge of a timed event in the week/day grid
+pub fn handle_event_resize_start(
+    app: &mut CosmicCalendar,
+    calendar_id: String,
+    uid: String,
+    edge: ResizeEdge,
+    start: NaiveTime,
+    end: NaiveTime,
+) {
+    debug!("handle_event_resize_start: calendar={}, uid={}, edge={:?}", calendar_id, uid, edge);
+
+    // Cancel any time selection in progress
+    app.selection_state.cancel();
+    app.event_resize_state.start(calendar_id, uid, edge, start, end);
+}
+
+/// End the edge drag and save the event with its new start or end time
+pub fn handle_event_resize_end(app: &mut CosmicCalendar) {
+    let Some((calendar_id, uid, from, to)) = app.event_resize_state.end() else {
+        return;
+    };
+
+    // Resizing an occurrence changes the whole series, like dragging one
+    let master_uid = extract_master_uid(&uid);
+    info!("handle_event_resize_end: Resizing calendar={} event={} from {:?} to {:?}", calendar_id, master_uid, from, to);
+
+    let event = match EventHandler::find_event_in_calendar(&app.calendar_manager, &calendar_id, master_uid) {
+        Ok(event) => event,
+        Err(e) => {
+            warn!("handle_event_resize_end: Event not found: {} ({})", uid, e);
+            return;
+        }
+    };
+
+    let updated_event = event_resized(event.clone(), from, to);
+    let change = EventChange::edited(&calendar_id, event, updated_event.clone());
+    if let Err(e) = EventHandler::update_event(&mut app.calendar_manager, &calendar_id, updated_event) {
+        error!("handle_event_resize_end: Failed to resize event: {}", e);
+        return;
+    }
+
+    record_changes(app, vec![change]);
+    app.refresh_cached_events();
+}
+
+/// Move an event's start and end by as much as its shown times changed.
+/// Offsets apply to the stored times, so zoned and recurring events keep their dates.
+fn event_resized(event: CalendarEvent, from: (NaiveTime, NaiveTime), to: (NaiveTime, NaiveTime)) -> CalendarEvent {
+    CalendarEvent {
+        start: event.start + (to.0 - from.0),
+        end: event.end + (to.1 - from.1),
+        ..event
+    }
+}
+
 /// Start editing a quick event on a specific date
 /// Uses DialogManager to open ActiveDialog::QuickEvent
 pub fn handle_start_quick_event(app: &mut CosmicCalendar, date: NaiveDate) {
@@ -339,19 +731,81 @@ pub fn handle_cancel_quick_event(app: &mut CosmicCalendar) {
 
 // === Event Dialog Handlers ===
 
-/// Open the event dialog for creating a new event
+/// Maximum number of previously used locations offered in the event dialog
+const RECENT_LOCATIONS_LIMIT: usize = 50;
+
+/// Load previously used locations for the event dialog's location autocomplete
+fn load_recent_locations(app: &CosmicCalendar) -> Vec<String> {
+    EventHandler::recent_locations(&app.calendar_manager, RECENT_LOCATIONS_LIMIT).unwrap_or_else(|e| {
+        warn!("load_recent_locations: {}", e);
+        Vec::new()
+    })
+}
+
+/// Defaults for new events in the calendar they're created in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NewEventDefaults {
+    /// Length of timed events, if the calendar overrides the global default
+    pub duration_minutes: Option<u32>,
+    /// Whether new events start out as all-day
+    pub all_day: bool,
+}
+
+impl NewEventDefaults {
+    /// Defaults of the calendar with `calendar_id` (global defaults if it doesn't exist)
+    pub fn for_calendar(manager: &CalendarManager, calendar_id: &str) -> Self {
+        Self {
+            duration_minutes: manager.default_duration_minutes(calendar_id),
+            all_day: manager.default_all_day(calendar_id),
+        }
+    }
+}
+
+/// Pick the message for creating an event at a date (and optional start time)
+/// based on the user's new event mode setting. Quick events are all-day in
+/// calendars that default to all-day; otherwise a time slot starts a quick
+/// timed event lasting the calendar's default duration.
+pub fn new_event_message(
+    mode: NewEventMode,
+    date: NaiveDate,
+    start_time: Option<NaiveTime>,
+    defaults: NewEventDefaults,
+) -> Message {
+    match (mode, start_time) {
+        (NewEventMode::Dialog, _) => Message::OpenNewEventDialogAt(date, start_time),
+        (NewEventMode::QuickInput, Some(start)) if !defaults.all_day => {
+            Message::StartQuickTimedEvent(date, start, new_event_end_time(start, defaults.duration_minutes))
+        }
+        (NewEventMode::QuickInput, _) => Message::StartQuickEvent(date),
+    }
+}
+
+/// Length of new events unless their calendar sets its own default
+const DEFAULT_EVENT_DURATION_MINUTES: u32 = 60;
+
+/// End time for a new event starting at `start`, using the calendar's default duration
+/// (or the global default). Clamped to the end of the day.
+fn new_event_end_time(start: NaiveTime, calendar_duration_minutes: Option<u32>) -> NaiveTime {
+    let minutes = calendar_duration_minutes.unwrap_or(DEFAULT_EVENT_DURATION_MINUTES);
+    let (end, wrapped_days) = start.overflowing_add_signed(chrono::Duration::minutes(minutes as i64));
+    if wrapped_days != 0 {
+        NaiveTime::from_hms_opt(23, 59, 0).unwrap_or(start)
+    } else {
+        end
+    }
+}
+
+/// Open the event dialog for creating a new event on the selected date
 pub fn handle_open_new_event_dialog(app: &mut CosmicCalendar) {
-    debug!("handle_open_new_event_dialog: Opening new event dialog");
-    let today = app.selected_date;
+    let date = app.selected_date;
+    handle_open_new_event_dialog_at(app, date, None);
+}
 
-    // Default to current time (rounded to 5 minutes) and +1 hour for end time
-    let now = chrono::Local::now().time();
-    let rounded_minute = (now.minute() / 5) * 5;
-    let default_start_time = NaiveTime::from_hms_opt(now.hour(), rounded_minute, 0);
-    let default_end_time = default_start_time.map(|t| {
-        let new_hour = (t.hour() + 1) % 24;
-        NaiveTime::from_hms_opt(new_hour, t.minute(), 0).unwrap_or(t)
-    });
+/// Open the event dialog for creating a new event on a specific date
+/// If no start time is given, defaults to the current time (rounded to 5 minutes)
+pub fn handle_open_new_event_dialog_at(app: &mut CosmicCalendar, date: NaiveDate, start_time: Option<NaiveTime>) {
+    debug!("handle_open_new_event_dialog_at: Opening new event dialog for {}", date);
+    let today = date;
 
     // Use selected calendar or first available
     let calendar_id = app
@@ -365,11 +819,21 @@ pub fn handle_open_new_event_dialog(app: &mut CosmicCalendar) {
         })
         .unwrap_or_default();
 
+    // Default to current time (rounded to 5 minutes); the end uses the calendar's default duration
+    let now = chrono::Local::now().time();
+    let rounded_minute = (now.minute() / 5) * 5;
+    let default_start_time = start_time.or_else(|| NaiveTime::from_hms_opt(now.hour(), rounded_minute, 0));
+    let defaults = NewEventDefaults::for_calendar(&app.calendar_manager, &calendar_id);
+    let default_end_time = default_start_time.map(|t| new_event_end_time(t, defaults.duration_minutes));
+    let all_day = defaults.all_day;
+
     app.event_dialog = Some(EventDialogState {
         editing_uid: None,
+        occurrence_date: None,
+        scope_prompt_open: false,
         title: String::new(),
         location: String::new(),
-        all_day: false,
+        all_day,
         start_date: today,
         start_date_input: today.format("%Y-%m-%d").to_string(),
         start_time: default_start_time,
@@ -384,14 +848,22 @@ pub fn handle_open_new_event_dialog(app: &mut CosmicCalendar) {
             .unwrap_or_else(|| "10:00".to_string()),
         travel_time: TravelTime::None,
         repeat: RepeatFrequency::Never,
+        repeat_interval: 1,
+        repeat_weekdays: vec![],
         calendar_id,
         invitees: vec![],
         invitee_input: String::new(),
-        alert: AlertTime::None,
+        categories: vec![],
+        category_input: String::new(),
+        color: None,
+        timezone: Some(timezone::system_zone().to_string()),
+        alert: if all_day { app.settings.all_day_alert.clone() } else { AlertTime::None },
         alert_second: None,
         attachments: vec![],
         url: String::new(),
         notes_content: text_editor::Content::new(),
+        status: EventStatus::Confirmed,
+        recent_locations: load_recent_locations(app),
         editing_field: None,
         start_date_picker_open: false,
         start_date_calendar: CalendarModel::new(today, today),
@@ -421,17 +893,27 @@ pub fn handle_open_edit_event_dialog(app: &mut CosmicCalendar, calendar_id: Stri
 
     info!("handle_open_edit_event_dialog: Found event uid={} in calendar '{}'", event.uid, calendar_id);
 
-    // Convert UTC times to local dates/times
-    let start_date = event.start.date_naive();
-    let end_date = event.end.date_naive();
-    let start_time = Some(event.start.time());
-    let end_time = Some(event.end.time());
+    // Convert UTC times to local dates/times (in the event's own zone, if it has one)
+    let (start, end) = match event.timezone.as_deref() {
+        Some(zone) if !event.all_day => (timezone::utc_to_local(event.start, zone), timezone::utc_to_local(event.end, zone)),
+        _ => (event.start.naive_utc(), event.end.naive_utc()),
+    };
+    // An occurrence of a series is shown on its own date; saving asks which occurrences change
+    let occurrence_date = extract_occurrence_date(&uid).filter(|_| !matches!(event.repeat, RepeatFrequency::Never));
+    let shift = occurrence_date.map_or(chrono::Duration::zero(), |date| date - start.date());
+    let (start, end) = (start + shift, end + shift);
+    let start_date = start.date();
+    let end_date = end.date();
+    let start_time = Some(start.time());
+    let end_time = Some(end.time());
 
     let actual_start_time = if event.all_day { None } else { start_time };
     let actual_end_time = if event.all_day { None } else { end_time };
 
     app.event_dialog = Some(EventDialogState {
-        editing_uid: Some(uid),
+        editing_uid: Some(master_uid.to_string()),
+        occurrence_date,
+        scope_prompt_open: false,
         title: event.summary,
         location: event.location.unwrap_or_default(),
         all_day: event.all_day,
@@ -449,14 +931,22 @@ pub fn handle_open_edit_event_dialog(app: &mut CosmicCalendar, calendar_id: Stri
             .unwrap_or_else(|| "10:00".to_string()),
         travel_time: event.travel_time,
         repeat: event.repeat,
+        repeat_interval: event.repeat_interval,
+        repeat_weekdays: event.repeat_weekdays,
         calendar_id,
         invitees: event.invitees,
         invitee_input: String::new(),
+        categories: event.categories,
+        category_input: String::new(),
+        color: event.color,
+        timezone: event.timezone,
         alert: event.alert,
         alert_second: event.alert_second,
         attachments: event.attachments,
         url: event.url.unwrap_or_default(),
         notes_content: text_editor::Content::with_text(&event.notes.unwrap_or_default()),
+        status: event.status,
+        recent_locations: load_recent_locations(app),
         editing_field: None,
         start_date_picker_open: false,
         start_date_calendar: CalendarModel::new(start_date, start_date),
@@ -467,41 +957,73 @@ pub fn handle_open_edit_event_dialog(app: &mut CosmicCalendar, calendar_id: Stri
     });
 }
 
-/// Confirm the event dialog - create or update the event
+/// Confirm the event dialog - create or update the event.
+/// Edits of one occurrence of a series first ask which occurrences to change.
 pub fn handle_confirm_event_dialog(app: &mut CosmicCalendar) {
-    let Some(dialog) = app.event_dialog.take() else {
+    let Some(mut dialog) = app.event_dialog.take() else {
         return;
     };
 
     let is_edit = dialog.editing_uid.is_some();
     debug!("handle_confirm_event_dialog: {} event", if is_edit { "Updating" } else { "Creating" });
 
-    // Validate: title is required
-    let title = dialog.title.trim();
-    if title.is_empty() {
-        warn!("handle_confirm_event_dialog: Empty title, returning dialog");
-        // Put dialog back - can't save without title
+    // Invalid input keeps the dialog open; the dialog already marks the problem
+    if let Err(error) = dialog.validate() {
+        warn!("handle_confirm_event_dialog: Invalid input ({:?}), returning dialog", error);
+        app.toast.show(input_error_label(error), None, std::time::Instant::now());
         app.event_dialog = Some(dialog);
         return;
     }
 
-    // Build start and end times
-    let start_time = if dialog.all_day {
-        NaiveTime::from_hms_opt(0, 0, 0).unwrap()
-    } else {
-        dialog.start_time.unwrap_or_else(|| NaiveTime::from_hms_opt(9, 0, 0).unwrap())
-    };
+    if dialog.occurrence_date.is_some() {
+        debug!("handle_confirm_event_dialog: Editing an occurrence, asking for the scope");
+        dialog.scope_prompt_open = true;
+        app.event_dialog = Some(dialog);
+        return;
+    }
+    save_event_dialog(app, dialog, RecurrenceScope::AllEvents);
+}
 
-    let end_time = if dialog.all_day {
-        NaiveTime::from_hms_opt(23, 59, 59).unwrap()
-    } else {
-        dialog.end_time.unwrap_or_else(|| NaiveTime::from_hms_opt(10, 0, 0).unwrap())
+/// Save the event dialog for the occurrences picked in the scope prompt
+pub fn handle_confirm_edit_scope(app: &mut CosmicCalendar, scope: RecurrenceScope) {
+    let Some(dialog) = app.event_dialog.take() else {
+        return;
     };
+    debug!("handle_confirm_edit_scope: Saving for {:?}", scope);
+    save_event_dialog(app, dialog, scope);
+}
+
+/// Close the scope prompt and go back to the event dialog
+pub fn handle_cancel_edit_scope(app: &mut CosmicCalendar) {
+    if let Some(dialog) = app.event_dialog.as_mut() {
+        dialog.scope_prompt_open = false;
+    }
+}
 
-    let start = Utc.from_utc_datetime(&dialog.start_date.and_time(start_time));
-    let end = Utc.from_utc_datetime(&dialog.end_date.and_time(end_time));
+/// Create or update the dialog's event. For an occurrence of a series,
+/// `scope` picks whether it is detached, the series is split at it, or the
+/// whole series changes.
+fn save_event_dialog(app: &mut CosmicCalendar, dialog: EventDialogState, scope: RecurrenceScope) {
+    let title = dialog.title.trim();
 
-    let event = CalendarEvent {
+    // Zoned events resolve DST gaps/overlaps; all-day events are always floating
+    let (local_start, local_end) = dialog.local_span();
+    let zone = if dialog.all_day { None } else { dialog.timezone.clone() };
+    let start = timezone::to_utc(local_start, zone.as_deref());
+    let end = timezone::to_utc(local_end, zone.as_deref());
+
+    // The recurrence end isn't edited in the dialog, so keep the existing one
+    let existing = dialog
+        .editing_uid
+        .as_deref()
+        .and_then(|uid| EventHandler::find_event_in_calendar(&app.calendar_manager, &dialog.calendar_id, uid).ok());
+    // An end condition only applies to the frequency it was set for
+    let (repeat_until, repeat_count) = existing
+        .as_ref()
+        .filter(|existing| existing.repeat == dialog.repeat)
+        .map_or((None, None), |existing| (existing.repeat_until, existing.repeat_count));
+
+    let mut event = CalendarEvent {
         uid: dialog.editing_uid.clone().unwrap_or_else(|| Uuid::new_v4().to_string()),
         summary: title.to_string(),
         location: if dialog.location.is_empty() {
@@ -514,7 +1036,14 @@ pub fn handle_confirm_event_dialog(app: &mut CosmicCalendar) {
         end,
         travel_time: dialog.travel_time,
         repeat: dialog.repeat,
-        repeat_until: None, // TODO: Add to dialog state
+        repeat_until, // TODO: Add to dialog state
+        repeat_count,
+        repeat_interval: dialog.repeat_interval,
+        repeat_weekdays: if matches!(dialog.repeat, RepeatFrequency::Weekly | RepeatFrequency::Biweekly) {
+            dialog.repeat_weekdays
+        } else {
+            vec![]
+        },
         exception_dates: vec![], // Exception dates are preserved when editing existing events
         invitees: dialog.invitees,
         alert: dialog.alert,
@@ -533,33 +1062,331 @@ pub fn handle_confirm_event_dialog(app: &mut CosmicCalendar) {
                 Some(notes_text)
             }
         },
+        status: dialog.status,
+        // Times from the dialog are entered in the event's zone (or floating local time)
+        timezone: zone,
+        categories: dialog.categories,
+        color: dialog.color,
+        overrides: vec![],
     };
 
-    // Use EventHandler for create or update
-    let result = if dialog.editing_uid.is_some() {
-        info!("handle_confirm_event_dialog: Updating event '{}' in calendar '{}'", title, dialog.calendar_id);
-        // Update existing event (EventHandler handles delete + add)
-        EventHandler::update_event(&mut app.calendar_manager, &dialog.calendar_id, event)
-    } else {
-        info!("handle_confirm_event_dialog: Creating event '{}' in calendar '{}'", title, dialog.calendar_id);
-        // Create new event
-        EventHandler::add_event(&mut app.calendar_manager, &dialog.calendar_id, event)
+    let calendar_id = dialog.calendar_id;
+    let result = match existing {
+        None => {
+            info!("save_event_dialog: Creating event '{}' in calendar '{}'", title, calendar_id);
+            EventHandler::add_event(&mut app.calendar_manager, &calendar_id, event.clone())
+                .map(|()| vec![EventChange::created(&calendar_id, event)])
+        }
+        Some(series) => match (dialog.occurrence_date, scope) {
+            (Some(date), RecurrenceScope::ThisEvent) => {
+                info!("save_event_dialog: Detaching occurrence {} of '{}'", date, title);
+                let (updated, standalone) = EventHandler::detach_occurrence(series.clone(), date, event);
+                save_series_edit(app, &calendar_id, series, updated, standalone)
+            }
+            (Some(date), RecurrenceScope::ThisAndFollowing)
+                if date > CalendarManager::series_start_date(&series) =>
+            {
+                info!("save_event_dialog: Splitting '{}' at {}", title, date);
+                let (original, following) = EventHandler::split_series(series.clone(), date, event);
+                save_series_edit(app, &calendar_id, series, original, following)
+            }
+            (occurrence_date, _) => {
+                info!("save_event_dialog: Updating event '{}' in calendar '{}'", title, calendar_id);
+                // Edited from an occurrence: the date change applies to the series start
+                if let Some(date) = occurrence_date {
+                    let shift = date - CalendarManager::series_start_date(&series);
+                    event.start = timezone::to_utc(local_start - shift, event.timezone.as_deref());
+                    event.end = timezone::to_utc(local_end - shift, event.timezone.as_deref());
+                }
+                event.exception_dates = series.exception_dates.clone();
+                event.overrides = series.overrides.clone();
+                // Update existing event (EventHandler handles delete + add)
+                EventHandler::update_event(&mut app.calendar_manager, &calendar_id, event.clone())
+                    .map(|()| vec![EventChange::edited(&calendar_id, series, event)])
+            }
+        },
     };
 
     match result {
-        Ok(()) => {
-            info!("handle_confirm_event_dialog: Event saved successfully");
+        Ok(changes) => {
+            info!("save_event_dialog: Event saved successfully");
+            record_changes(app, changes);
             // Refresh cached events
             app.refresh_cached_events();
         }
         Err(e) => {
-            error!("handle_confirm_event_dialog: Failed to save event: {}", e);
+            error!("save_event_dialog: Failed to save event: {}", e);
+            // Remote calendars can reject the change (offline, edited on the server)
+            app.toast.show(
+                fl!("toast-event-save-failed", reason = e.to_string()),
+                None,
+                std::time::Instant::now(),
+            );
         }
     }
 }
 
+/// Store a series changed for one occurrence (or the following ones) together
+/// with the event split off from it; returns the changes for undo.
+/// The split-off event is added first and removed again if the series can't be
+/// updated, so a failure never drops the edited occurrences.
+fn save_series_edit(
+    app: &mut CosmicCalendar,
+    calendar_id: &str,
+    before: CalendarEvent,
+    series: CalendarEvent,
+    split_off: CalendarEvent,
+) -> EventResult<Vec<EventChange>> {
+    EventHandler::add_event(&mut app.calendar_manager, calendar_id, split_off.clone())?;
+    if let Err(e) = EventHandler::update_event(&mut app.calendar_manager, calendar_id, series.clone()) {
+        if let Err(rollback) = EventHandler::delete_event_in_calendar(&mut app.calendar_manager, calendar_id, &split_off.uid) {
+            error!("save_series_edit: Failed to remove split-off event uid={}: {}", split_off.uid, rollback);
+        }
+        return Err(e);
+    }
+    Ok(vec![
+        EventChange::edited(calendar_id, before, series),
+        EventChange::created(calendar_id, split_off),
+    ])
+}
+
 /// Cancel the event dialog
 pub fn handle_cancel_event_dialog(app: &mut CosmicCalendar) {
     debug!("handle_cancel_event_dialog: Cancelling event dialog");
     app.event_dialog = None;
 }
+
+// === Alert Notifications ===
+
+/// Post desktop notifications for event alerts that became due since the last tick.
+/// Clicking a notification opens the edit dialog for its event.
+pub fn handle_alert_tick(app: &mut CosmicCalendar) -> Task<Message> {
+    let now = chrono::Local::now().naive_local().and_utc();
+    let range_start = (now - chrono::Duration::days(1)).date_naive();
+    let range_end = (now + chrono::Duration::days(ALERT_LOOKAHEAD_DAYS)).date_naive();
+    let events = app.calendar_manager.get_alert_occurrences_between(range_start, range_end);
+
+    let due = app.alert_scheduler.due_alerts(&events, now);
+    if due.is_empty() {
+        return Task::none();
+    }
+    info!("handle_alert_tick: {} alert(s) due", due.len());
+
+    let app_name = fl!("app-title");
+    let open_label = fl!("notification-open-event");
+    Task::batch(due.into_iter().map(|alert| {
+        // Same date and clock style as the rest of the app
+        let body = if alert.all_day {
+            let date = alert.start.date_naive();
+            fl!("notification-alert-body-all-day", date = app.locale.format_day_header(&date, &get_weekday_full(date.weekday())))
+        } else {
+            fl!("notification-alert-body", time = app.locale.format_time(&alert.start))
+        };
+        // Occurrences open the series they belong to
+        let calendar_id = EventHandler::find_event(&app.calendar_manager, extract_master_uid(&alert.uid))
+            .ok()
+            .map(|(_, calendar_id)| calendar_id);
+        let app_name = app_name.clone();
+        let open_label = open_label.clone();
+
+        Task::perform(
+            async move {
+                // Waiting for the click blocks - run it on its own thread so the UI stays responsive
+                let (sender, receiver) = tokio::sync::oneshot::channel();
+                std::thread::spawn(move || {
+                    let _ = sender.send(show_notification(&app_name, &alert.summary, &body, &open_label));
+                });
+                let clicked = receiver.await.unwrap_or(false);
+                (clicked, alert.uid)
+            },
+            move |(clicked, uid)| match (clicked, calendar_id.clone()) {
+                (true, Some(calendar_id)) => cosmic::Action::App(Message::OpenEditEventDialog(calendar_id, uid)),
+                _ => cosmic::Action::App(Message::None),
+            },
+        )
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    fn make_event(all_day: bool) -> CalendarEvent {
+        CalendarEvent {
+            uid: "uid".to_string(),
+            summary: "Overdue".to_string(),
+            all_day,
+            start: Utc.with_ymd_and_hms(2025, 3, 10, 9, 15, 0).unwrap(),
+            end: Utc.with_ymd_and_hms(2025, 3, 10, 10, 45, 0).unwrap(),
+            ..CalendarEvent::test_default()
+        }
+    }
+
+    #[test]
+    fn test_event_moved_to_date_keeps_time_of_day() {
+        let event = make_event(false);
+        let from = event.start.date_naive();
+        let today = NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+
+        let moved = event_moved_to_date(event.clone(), from, today);
+        assert_eq!(moved.start.date_naive(), today);
+        assert_eq!(moved.start.time(), event.start.time());
+        assert_eq!(moved.end.time(), event.end.time());
+        assert_eq!(moved.end - moved.start, event.end - event.start);
+    }
+
+    #[test]
+    fn test_event_moved_to_date_keeps_wall_clock_time_across_dst() {
+        // 09:15 in Berlin: UTC+1 in March, UTC+2 after the switch
+        let mut event = make_event(false);
+        event.timezone = Some("Europe/Berlin".to_string());
+        event.start = Utc.with_ymd_and_hms(2025, 3, 10, 8, 15, 0).unwrap();
+        event.end = Utc.with_ymd_and_hms(2025, 3, 10, 9, 45, 0).unwrap();
+
+        let moved = event_moved_to_date(event.clone(), CalendarManager::series_start_date(&event), NaiveDate::from_ymd_opt(2025, 4, 1).unwrap());
+        assert_eq!(moved.start, Utc.with_ymd_and_hms(2025, 4, 1, 7, 15, 0).unwrap());
+        assert_eq!(moved.end - moved.start, event.end - event.start);
+    }
+
+    #[test]
+    fn test_event_moved_to_date_all_day_becomes_that_day() {
+        let mut event = make_event(true);
+        event.start = Utc.with_ymd_and_hms(2025, 3, 10, 0, 0, 0).unwrap();
+        event.end = Utc.with_ymd_and_hms(2025, 3, 11, 0, 0, 0).unwrap();
+        let today = NaiveDate::from_ymd_opt(2025, 2, 28).unwrap();
+
+        let moved = event_moved_to_date(event, NaiveDate::from_ymd_opt(2025, 3, 10).unwrap(), today);
+        assert_eq!(moved.start.date_naive(), today);
+        assert_eq!(moved.end.date_naive(), NaiveDate::from_ymd_opt(2025, 3, 1).unwrap());
+    }
+
+    #[test]
+    fn test_event_resized_changes_only_the_dragged_edge() {
+        let event = make_event(false);
+        let shown = (NaiveTime::from_hms_opt(9, 15, 0).unwrap(), NaiveTime::from_hms_opt(10, 45, 0).unwrap());
+
+        // Bottom edge dragged to 11:30
+        let longer = event_resized(event.clone(), shown, (shown.0, NaiveTime::from_hms_opt(11, 30, 0).unwrap()));
+        assert_eq!(longer.start, event.start);
+        assert_eq!(longer.end, Utc.with_ymd_and_hms(2025, 3, 10, 11, 30, 0).unwrap());
+
+        // Top edge dragged to 08:00
+        let earlier = event_resized(event.clone(), shown, (NaiveTime::from_hms_opt(8, 0, 0).unwrap(), shown.1));
+        assert_eq!(earlier.start, Utc.with_ymd_and_hms(2025, 3, 10, 8, 0, 0).unwrap());
+        assert_eq!(earlier.end, event.end);
+    }
+
+    #[test]
+    fn test_repeat_forward_next_week() {
+        let event = make_event(false);
+        let copy = event_repeated_forward(&event, RepeatPeriod::Week);
+
+        assert_ne!(copy.uid, event.uid);
+        assert_eq!(copy.summary, event.summary);
+        assert_eq!(copy.start, Utc.with_ymd_and_hms(2025, 3, 17, 9, 15, 0).unwrap());
+        assert_eq!(copy.end, Utc.with_ymd_and_hms(2025, 3, 17, 10, 45, 0).unwrap());
+    }
+
+    #[test]
+    fn test_repeat_forward_next_month() {
+        let event = make_event(false);
+        let copy = event_repeated_forward(&event, RepeatPeriod::Month);
+        assert_eq!(copy.start, Utc.with_ymd_and_hms(2025, 4, 10, 9, 15, 0).unwrap());
+        assert_eq!(copy.end - copy.start, event.end - event.start);
+
+        // Month-end dates clamp to the last day of the next month
+        let mut event = make_event(false);
+        event.start = Utc.with_ymd_and_hms(2025, 1, 31, 23, 0, 0).unwrap();
+        event.end = Utc.with_ymd_and_hms(2025, 2, 1, 1, 0, 0).unwrap();
+        let copy = event_repeated_forward(&event, RepeatPeriod::Month);
+        assert_eq!(copy.start, Utc.with_ymd_and_hms(2025, 2, 28, 23, 0, 0).unwrap());
+        assert_eq!(copy.end, Utc.with_ymd_and_hms(2025, 3, 1, 1, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_repeat_forward_keeps_wall_clock_time_across_dst() {
+        // 09:15 in New York: UTC-5 before the March 9 switch, UTC-4 after it
+        let mut event = make_event(false);
+        event.timezone = Some("America/New_York".to_string());
+        event.start = Utc.with_ymd_and_hms(2025, 3, 5, 14, 15, 0).unwrap();
+        event.end = Utc.with_ymd_and_hms(2025, 3, 5, 15, 45, 0).unwrap();
+
+        let copy = event_repeated_forward(&event, RepeatPeriod::Week);
+        assert_eq!(copy.start, Utc.with_ymd_and_hms(2025, 3, 12, 13, 15, 0).unwrap());
+        assert_eq!(copy.end - copy.start, event.end - event.start);
+    }
+
+    #[test]
+    fn test_new_event_in_all_day_calendar_defaults_to_all_day() {
+        let date = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+        let nine = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+        let birthdays = NewEventDefaults { duration_minutes: None, all_day: true };
+
+        // "Birthdays" defaults to all-day, so even a picked time slot starts an all-day quick event
+        assert!(matches!(
+            new_event_message(NewEventMode::QuickInput, date, Some(nine), birthdays),
+            Message::StartQuickEvent(d) if d == date
+        ));
+        assert!(matches!(
+            new_event_message(NewEventMode::QuickInput, date, None, birthdays),
+            Message::StartQuickEvent(d) if d == date
+        ));
+    }
+
+    #[test]
+    fn test_new_event_end_time_uses_calendar_duration() {
+        let nine = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+
+        // "Work" calendar defaults to 30 minutes, calendars without an override use one hour
+        assert_eq!(new_event_end_time(nine, Some(30)), NaiveTime::from_hms_opt(9, 30, 0).unwrap());
+        assert_eq!(new_event_end_time(nine, None), NaiveTime::from_hms_opt(10, 0, 0).unwrap());
+
+        // Long defaults don't wrap past midnight
+        let late = NaiveTime::from_hms_opt(23, 0, 0).unwrap();
+        assert_eq!(new_event_end_time(late, Some(90)), NaiveTime::from_hms_opt(23, 59, 0).unwrap());
+    }
+
+    #[test]
+    fn test_new_event_message_follows_setting() {
+        let date = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+        let nine = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+        let ten = NaiveTime::from_hms_opt(10, 0, 0).unwrap();
+
+        // Quick input: day cells start a quick event, time slots a quick timed event
+        // of the default length
+        assert!(matches!(
+            new_event_message(NewEventMode::QuickInput, date, None, NewEventDefaults::default()),
+            Message::StartQuickEvent(d) if d == date
+        ));
+        assert!(matches!(
+            new_event_message(NewEventMode::QuickInput, date, Some(nine), NewEventDefaults::default()),
+            Message::StartQuickTimedEvent(d, s, e) if d == date && s == nine && e == ten
+        ));
+
+        // A calendar's own default duration sets the quick event's end
+        let work = NewEventDefaults { duration_minutes: Some(30), all_day: false };
+        let nine_thirty = NaiveTime::from_hms_opt(9, 30, 0).unwrap();
+        assert!(matches!(
+            new_event_message(NewEventMode::QuickInput, date, Some(nine), work),
+            Message::StartQuickTimedEvent(_, _, e) if e == nine_thirty
+        ));
+
+        // Dialog: both open the full dialog at the clicked date/time
+        assert!(matches!(
+            new_event_message(NewEventMode::Dialog, date, None, NewEventDefaults::default()),
+            Message::OpenNewEventDialogAt(d, None) if d == date
+        ));
+        assert!(matches!(
+            new_event_message(NewEventMode::Dialog, date, Some(nine), NewEventDefaults::default()),
+            Message::OpenNewEventDialogAt(d, Some(s)) if d == date && s == nine
+        ));
+
+        // A late slot doesn't wrap past midnight
+        let late = NaiveTime::from_hms_opt(23, 30, 0).unwrap();
+        assert!(matches!(
+            new_event_message(NewEventMode::QuickInput, date, Some(late), NewEventDefaults::default()),
+            Message::StartQuickTimedEvent(_, _, e) if e == NaiveTime::from_hms_opt(23, 59, 0).unwrap()
+        ));
+    }
+}
@@ -4,11 +4,14 @@
 
 use crate::app::CosmicCalendar;
 use crate::dialogs::{ActiveDialog, DialogAction, DialogManager};
+use crate::fl;
 use crate::message::Message;
-use crate::services::{EventHandler, ExportHandler};
+use crate::caldav::CalendarEvent;
+use crate::services::{EventHandler, ExportHandler, ImportSummary};
 use cosmic::app::Task;
 use log::{debug, error, info, warn};
 use std::path::PathBuf;
+use std::time::Instant;
 
 /// Handle import file message - parse the file and show import dialog
 pub fn handle_import_file(app: &mut CosmicCalendar, path: PathBuf) -> Task<Message> {
@@ -48,7 +51,7 @@ pub fn handle_import_file(app: &mut CosmicCalendar, path: PathBuf) -> Task<Messa
             info!("handle_import_file: Parsed {} events", events.len());
 
             // Smart import logic:
-            // - Single event: Add to default calendar and open event dialog pre-filled
+            // - Single event: Add to selected calendar and open event dialog pre-filled
             // - Multiple events: Open import dialog for calendar selection
             if events.len() == 1 {
                 info!("handle_import_file: Single event - opening event dialog");
@@ -56,16 +59,14 @@ pub fn handle_import_file(app: &mut CosmicCalendar, path: PathBuf) -> Task<Messa
                 // Get first (and only) event
                 let event = events.into_iter().next().unwrap();
 
-                // Get the first available calendar as default
-                if let Some(calendar) = app.calendar_manager.sources().first() {
-                    let calendar_id = calendar.info().id.clone();
-
+                if let Some(calendar_id) = default_import_calendar_id(app) {
                     // Add event to the default calendar
                     match EventHandler::add_event(&mut app.calendar_manager, &calendar_id, event.clone()) {
                         Ok(_) => {
                             info!("handle_import_file: Event added to calendar '{}'", calendar_id);
                             // Refresh the calendar view
                             app.refresh_cached_events();
+                            app.toast.show(fl!("toast-events-imported", count = 1), None, Instant::now());
                             // Open the event dialog for editing/review
                             return Task::done(cosmic::Action::App(Message::OpenEditEventDialog(calendar_id, event.uid)));
                         }
@@ -81,13 +82,7 @@ pub fn handle_import_file(app: &mut CosmicCalendar, path: PathBuf) -> Task<Messa
             } else {
                 // Multiple events: Use import dialog for calendar selection
                 info!("handle_import_file: Multiple events - opening import dialog");
-                DialogManager::handle_action(
-                    &mut app.active_dialog,
-                    DialogAction::OpenImport {
-                        events,
-                        source_file_name,
-                    },
-                );
+                open_import_dialog(app, events, source_file_name);
             }
         }
         Err(e) => {
@@ -102,7 +97,7 @@ pub fn handle_import_file(app: &mut CosmicCalendar, path: PathBuf) -> Task<Messa
 /// Handle show import dialog message (events already parsed)
 pub fn handle_show_import_dialog(
     app: &mut CosmicCalendar,
-    events: Vec<crate::caldav::CalendarEvent>,
+    events: Vec<CalendarEvent>,
     source_file_name: String,
 ) -> Task<Message> {
     info!(
@@ -116,7 +111,13 @@ pub fn handle_show_import_dialog(
         return Task::none();
     }
 
-    // Open the import dialog
+    open_import_dialog(app, events, source_file_name);
+
+    Task::none()
+}
+
+/// Open the import dialog with the default target calendar pre-selected
+fn open_import_dialog(app: &mut CosmicCalendar, events: Vec<CalendarEvent>, source_file_name: String) {
     DialogManager::handle_action(
         &mut app.active_dialog,
         DialogAction::OpenImport {
@@ -124,8 +125,23 @@ pub fn handle_show_import_dialog(
             source_file_name,
         },
     );
+    if let Some(calendar_id) = default_import_calendar_id(app) {
+        DialogManager::handle_action(
+            &mut app.active_dialog,
+            DialogAction::SelectImportCalendar(calendar_id),
+        );
+    }
+}
 
-    Task::none()
+/// Calendar imports go into unless another is picked:
+/// the currently selected calendar, or the first one if none is selected
+fn default_import_calendar_id(app: &CosmicCalendar) -> Option<String> {
+    let sources = app.calendar_manager.sources();
+    app.selected_calendar_id
+        .as_ref()
+        .filter(|id| sources.iter().any(|cal| &cal.info().id == *id))
+        .cloned()
+        .or_else(|| sources.first().map(|cal| cal.info().id.clone()))
 }
 
 /// Handle select import calendar message
@@ -149,15 +165,17 @@ pub fn handle_confirm_import(app: &mut CosmicCalendar) -> Task<Message> {
     info!("handle_confirm_import: Confirming import");
 
     // Extract data from the import dialog
-    let (events, source_file_name, selected_calendar_id) = match &app.active_dialog {
+    let (events, source_file_name, selected_calendar_id, previewed) = match &app.active_dialog {
         ActiveDialog::Import {
             events,
             source_file_name,
             selected_calendar_id,
+            preview,
         } => (
             events.clone(),
             source_file_name.clone(),
             selected_calendar_id.clone(),
+            preview.is_some(),
         ),
         _ => {
             error!("handle_confirm_import: Not in import dialog state");
@@ -166,20 +184,37 @@ pub fn handle_confirm_import(app: &mut CosmicCalendar) -> Task<Message> {
     };
 
     // Determine target calendar
-    // If no calendar selected, use the first available calendar
-    let target_calendar_id = if let Some(id) = selected_calendar_id {
-        id
-    } else {
-        // Get first available calendar
-        if let Some(cal) = app.calendar_manager.sources().first() {
-            cal.info().id.clone()
-        } else {
+    // If none was picked in the dialog, use the default import calendar
+    let target_calendar_id = match selected_calendar_id.or_else(|| default_import_calendar_id(app)) {
+        Some(id) => id,
+        None => {
             error!("handle_confirm_import: No calendars available");
             DialogManager::close(&mut app.active_dialog);
             return Task::none();
         }
     };
 
+    // Importing into a calendar that already has events: show the add/skip
+    // counts first and import on the next confirm
+    if app.settings.confirm_import_duplicates && !previewed {
+        if let Some(preview) = preview_import(app, &target_calendar_id, &events) {
+            info!(
+                "handle_confirm_import: Previewing import - {} to add, {} to skip",
+                preview.added, preview.skipped
+            );
+            if let ActiveDialog::Import {
+                selected_calendar_id,
+                preview: dialog_preview,
+                ..
+            } = &mut app.active_dialog
+            {
+                *selected_calendar_id = Some(target_calendar_id);
+                *dialog_preview = Some(preview);
+            }
+            return Task::none();
+        }
+    }
+
     info!(
         "handle_confirm_import: Importing {} events into calendar '{}'",
         events.len(),
@@ -294,6 +329,32 @@ pub fn handle_confirm_import(app: &mut CosmicCalendar) -> Task<Message> {
     Task::none()
 }
 
+/// Dry-run the import into the target calendar.
+/// Returns None when the calendar has no events yet, since nothing can be skipped.
+fn preview_import(
+    app: &mut CosmicCalendar,
+    calendar_id: &str,
+    events: &[CalendarEvent],
+) -> Option<ImportSummary> {
+    let calendar = app
+        .calendar_manager
+        .sources_mut()
+        .iter_mut()
+        .find(|cal| cal.info().id == calendar_id)?;
+
+    if calendar.fetch_events().map(|e| e.is_empty()).unwrap_or(true) {
+        return None;
+    }
+
+    match ExportHandler::import_events(calendar.as_mut(), events, true) {
+        Ok(summary) => Some(summary),
+        Err(e) => {
+            warn!("preview_import: Dry run failed: {}", e);
+            None
+        }
+    }
+}
+
 /// Handle cancel import message
 pub fn handle_cancel_import(app: &mut CosmicCalendar) -> Task<Message> {
     debug!("handle_cancel_import: Canceling import");
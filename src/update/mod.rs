@@ -14,7 +14,7 @@
 //!
 //! - [`dismiss_on_focus_loss`]: Clear transient UI state when user navigates away
 //! - [`focus_quick_event_input`]: Focus the quick event text input
-//! - [`scroll_week_to_current_time`]: Scroll week view to show current time
+//! - [`scroll_time_grid_on_open`]: Scroll the week/day view to the current time or the day start
 //! - [`scroll_week_to_hour`]: Scroll week view to a specific hour
 //! - [`close_legacy_event_dialog`]: Close deprecated event dialog field
 //! - [`schedule_deferred_scroll_restore`]: Schedule scroll position restoration
@@ -26,14 +26,18 @@ mod import;
 mod navigation;
 mod selection;
 
-use chrono::{NaiveDate, Timelike};
+use chrono::{Datelike, NaiveDate, Timelike};
 use cosmic::app::Task;
 use cosmic::iced::widget::scrollable;
+use cosmic::widget::menu::Action as _; // Import trait for .message() method
 use log::{debug, error, info, warn};
 
 use crate::app::CosmicCalendar;
-use crate::components::quick_event_input_id;
+use crate::caldav::{AlertTime, MAX_REPEAT_INTERVAL};
+use crate::components::{quick_event_input_id, search_input_id};
+use crate::database::Database;
 use crate::dialogs::{ActiveDialog, DialogManager};
+use crate::fl;
 use crate::message::Message;
 use crate::services::{ExportHandler, SettingsHandler};
 use crate::views::{week_time_grid_id, CalendarView};
@@ -54,17 +58,33 @@ fn focus_quick_event_input() -> Task<Message> {
     text_input::focus(quick_event_input_id())
 }
 
-/// Scroll the week view time grid to the current time
-/// Returns a Task that scrolls to show the current hour (offset by 1-2 hours to show some past)
-#[inline]
-fn scroll_week_to_current_time() -> Task<Message> {
+/// Hour at the top of a time grid that was just opened: an hour before now when
+/// `shown_days` include today (some past for context), otherwise the day start hour
+fn time_grid_top_hour(shown_days: &[NaiveDate], today: NaiveDate, current_hour: u32, day_start_hour: u32) -> u32 {
+    if shown_days.contains(&today) {
+        current_hour.saturating_sub(1)
+    } else {
+        day_start_hour
+    }
+}
+
+/// Scroll the week/day time grid of the current view when it is opened:
+/// near the current time if today is shown, otherwise to the user's day start hour
+fn scroll_time_grid_on_open(app: &CosmicCalendar) -> Task<Message> {
+    let shown_days = match app.current_view {
+        CalendarView::Day => std::slice::from_ref(&app.day_state.date),
+        _ => app.week_state.days.as_slice(),
+    };
     let now = chrono::Local::now();
-    let current_hour = now.hour();
+    let top_hour = time_grid_top_hour(shown_days, now.date_naive(), now.hour(), app.settings.day_start_hour);
+    scroll_week_to_top_hour(top_hour)
+}
 
-    // Scroll to show current time with some context (show 1-2 hours before current time)
+/// Scroll the week view time grid so `hour` is the first row shown
+#[inline]
+fn scroll_week_to_top_hour(hour: u32) -> Task<Message> {
     // Each hour row is HOUR_ROW_HEIGHT pixels tall
-    let scroll_to_hour = current_hour.saturating_sub(1) as f32;
-    let scroll_offset = scroll_to_hour * HOUR_ROW_HEIGHT;
+    let scroll_offset = hour as f32 * HOUR_ROW_HEIGHT;
 
     // Use scroll_to with AbsoluteOffset for vertical scrolling
     scrollable::scroll_to(
@@ -78,13 +98,7 @@ fn scroll_week_to_current_time() -> Task<Message> {
 #[inline]
 fn scroll_week_to_hour(hour: u32) -> Task<Message> {
     // Show the hour with 1 hour of context above
-    let scroll_to_hour = hour.saturating_sub(1) as f32;
-    let scroll_offset = scroll_to_hour * HOUR_ROW_HEIGHT;
-
-    scrollable::scroll_to(
-        week_time_grid_id(),
-        scrollable::AbsoluteOffset { x: 0.0, y: scroll_offset },
-    )
+    scroll_week_to_top_hour(hour.saturating_sub(1))
 }
 
 /// Close the legacy event dialog field
@@ -129,9 +143,9 @@ fn handle_export_calendar_to_file(
     debug!("handle_export_calendar_to_file: Exporting calendar '{}' to {:?}", calendar_id, path);
 
     match ExportHandler::export_to_file(&app.calendar_manager, &calendar_id, &path) {
-        Ok(()) => {
+        Ok(count) => {
             info!("Exported calendar '{}' to {:?}", calendar_id, path);
-            // TODO: Show success toast notification
+            show_exported_toast(app, count);
         }
         Err(e) => {
             error!("Failed to export calendar '{}': {}", calendar_id, e);
@@ -142,6 +156,71 @@ fn handle_export_calendar_to_file(
     Task::none()
 }
 
+/// Export all enabled calendars to the chosen file
+fn handle_export_all_to_file(app: &mut CosmicCalendar, path: std::path::PathBuf) -> Task<Message> {
+    debug!("handle_export_all_to_file: Exporting enabled calendars to {:?}", path);
+
+    match ExportHandler::export_all_to_file(&app.calendar_manager, &path) {
+        Ok(count) => {
+            info!("Exported {} events to {:?}", count, path);
+            show_exported_toast(app, count);
+        }
+        Err(e) => {
+            error!("Failed to export calendars: {}", e);
+        }
+    }
+
+    Task::none()
+}
+
+/// Export the events selected with Select All to the chosen file
+fn handle_export_events_to_file(app: &mut CosmicCalendar, ids: Vec<(String, String)>, path: std::path::PathBuf) -> Task<Message> {
+    debug!("handle_export_events_to_file: Exporting {} events to {:?}", ids.len(), path);
+
+    match ExportHandler::export_events_to_file(&app.calendar_manager, &ids, &path) {
+        Ok(count) => {
+            info!("Exported {} events to {:?}", count, path);
+            show_exported_toast(app, count);
+        }
+        Err(e) => {
+            error!("Failed to export events: {}", e);
+        }
+    }
+
+    Task::none()
+}
+
+/// Write the visible week as a printable page, using the cached week events
+fn handle_print_week_to_file(app: &mut CosmicCalendar, path: std::path::PathBuf) -> Task<Message> {
+    debug!("handle_print_week_to_file: Printing week to {:?}", path);
+
+    let page = crate::views::render_week_print(&app.week_state, &app.cached_week_events, &app.locale);
+    match std::fs::write(&path, page) {
+        Ok(()) => {
+            info!("Printed week to {:?}", path);
+            let file = path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            app.toast.show(fl!("toast-week-printed", file = file), None, std::time::Instant::now());
+        }
+        Err(e) => {
+            error!("Failed to print week to {:?}: {}", path, e);
+        }
+    }
+
+    Task::none()
+}
+
+/// Confirm a finished export with the number of events written
+fn show_exported_toast(app: &mut CosmicCalendar, count: usize) {
+    app.toast.show(
+        fl!("toast-events-exported", count = (count as i64)),
+        None,
+        std::time::Instant::now(),
+    );
+}
+
 /// Handle URL processing (webcal://, ics://, calendar://)
 fn handle_process_url(app: &mut CosmicCalendar, url: String) -> Task<Message> {
     use crate::url_handler::{parse_url, UrlAction};
@@ -183,6 +262,7 @@ fn handle_process_url(app: &mut CosmicCalendar, url: String) -> Task<Message> {
                         "week" => CalendarView::Week,
                         "day" => CalendarView::Day,
                         "year" => CalendarView::Year,
+                        "agenda" => CalendarView::Agenda,
                         _ => {
                             warn!("Unknown view: {}, defaulting to Month", view);
                             CalendarView::Month
@@ -362,6 +442,7 @@ fn handle_confirm_subscription(app: &mut CosmicCalendar) -> Task<Message> {
             events: events_to_import,
             source_file_name: url.clone(),
             selected_calendar_id: Some(target_calendar_id),
+            preview: None,
         };
 
         // Immediately confirm the import
@@ -380,26 +461,38 @@ fn handle_cancel_subscription(app: &mut CosmicCalendar) -> Task<Message> {
 // Re-export handlers for use in this module
 use calendar::{
     handle_change_calendar_color, handle_confirm_calendar_dialog, handle_confirm_delete_calendar,
-    handle_delete_selected_calendar, handle_export_calendar_dialog,
+    handle_calendar_synced, handle_confirm_caldav_account, handle_delete_selected_calendar,
+    handle_duplicate_calendar, handle_export_calendar_dialog, handle_move_calendar, handle_open_caldav_account_dialog,
     handle_open_calendar_dialog_create, handle_open_calendar_dialog_edit,
-    handle_request_delete_calendar, handle_toggle_calendar,
+    handle_request_delete_calendar, handle_set_default_calendar, handle_sync_all_calendars,
+    handle_sync_calendar, handle_toggle_calendar, handle_toggle_pin_calendar, sync_unsent_changes,
 };
 use event::{
-    extract_master_uid, extract_occurrence_date, handle_cancel_event_dialog, handle_cancel_quick_event,
-    handle_commit_quick_event, handle_confirm_event_dialog, handle_delete_event,
-    handle_drag_event_cancel, handle_drag_event_end, handle_drag_event_start,
-    handle_drag_event_update, handle_open_edit_event_dialog, handle_open_new_event_dialog,
-    handle_quick_event_text_changed, handle_select_event, handle_start_quick_event,
-    handle_start_quick_timed_event,
+    extract_master_uid, extract_occurrence_date, handle_alert_tick, handle_cancel_edit_scope, handle_cancel_event_dialog,
+    handle_cancel_quick_event, handle_commit_quick_event, handle_confirm_edit_scope, handle_confirm_event_dialog,
+    handle_delete_event, handle_delete_following_occurrences, handle_delete_selected_events, handle_drag_event_cancel, handle_drag_event_end, handle_drag_event_start,
+    handle_drag_event_update, handle_event_resize_end, handle_event_resize_start, handle_move_event_to_today, handle_open_edit_event_dialog, handle_open_new_event_dialog,
+    handle_duplicate_event, handle_open_new_event_dialog_at, handle_repeat_event_forward,
+    handle_quick_event_text_changed, handle_select_all_events_in_view, handle_select_event, handle_start_quick_event,
+    handle_redo, handle_start_quick_timed_event, handle_undo, new_event_message, NewEventDefaults,
 };
-use navigation::{handle_next_period, handle_previous_period};
+use navigation::{handle_move_selected_date, handle_next_period, handle_previous_period};
 use selection::{
     handle_selection_cancel, handle_selection_end, handle_selection_start, handle_selection_update,
     handle_time_selection_start, handle_time_selection_update, handle_time_selection_end,
 };
 
-/// Handle all application messages and update state
+/// Handle all application messages and update state, then start uploading
+/// any changes the message made to remote calendars
 pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Message> {
+    let task = update_state(app, message);
+    Task::batch([task, sync_unsent_changes(app)])
+}
+
+/// Update the application state for one message
+fn update_state(app: &mut CosmicCalendar, message: Message) -> Task<Message> {
+    crate::logging::log_message(&message);
+
     // Sync sidebar with condensed state on every update
     let is_condensed = app.core.is_condensed();
     if is_condensed != app.last_condensed {
@@ -416,18 +509,24 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
             DialogManager::handle_action(&mut app.active_dialog, action);
         }
         Message::CloseDialog => {
+            // Escape or a backdrop click on the edit scope prompt goes back to the event dialog
+            #[allow(deprecated)]
+            if app.event_dialog.as_ref().is_some_and(|dialog| dialog.scope_prompt_open) {
+                handle_cancel_edit_scope(app);
+                return Task::none();
+            }
             debug!("Message::CloseDialog: Closing dialogs");
             // Close legacy event dialog
             close_legacy_event_dialog(app);
+            // Sent by Escape and by clicking a dialog backdrop or the main content.
             // For quick events: only dismiss if empty (focus loss behavior)
             // For other dialogs: close unconditionally
             let was_quick_event = app.active_dialog.is_quick_event();
+            DialogManager::close_from_backdrop(&mut app.active_dialog);
+            app.selected_event_uids.clear();
             if was_quick_event {
-                dismiss_on_focus_loss(app);
-                // Schedule scroll restore when closing quick event via Escape
+                // Schedule scroll restore when closing quick event
                 return schedule_deferred_scroll_restore(app);
-            } else {
-                DialogManager::close(&mut app.active_dialog);
             }
         }
 
@@ -437,9 +536,9 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
             dismiss_on_focus_loss(app);
             app.current_view = view;
             app.sync_views_to_selected_date();
-            // Auto-scroll to current time when entering week view
-            if view == CalendarView::Week {
-                return scroll_week_to_current_time();
+            // Auto-scroll when entering a view with a time grid
+            if matches!(view, CalendarView::Week | CalendarView::Day) {
+                return scroll_time_grid_on_open(app);
             }
         }
         Message::CycleViewNext => {
@@ -447,9 +546,9 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
             let new_view = app.current_view.next();
             app.current_view = new_view;
             app.sync_views_to_selected_date();
-            // Auto-scroll to current time when entering week view
-            if new_view == CalendarView::Week {
-                return scroll_week_to_current_time();
+            // Auto-scroll when entering a view with a time grid
+            if matches!(new_view, CalendarView::Week | CalendarView::Day) {
+                return scroll_time_grid_on_open(app);
             }
         }
         Message::CycleViewPrevious => {
@@ -457,9 +556,9 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
             let new_view = app.current_view.previous();
             app.current_view = new_view;
             app.sync_views_to_selected_date();
-            // Auto-scroll to current time when entering week view
-            if new_view == CalendarView::Week {
-                return scroll_week_to_current_time();
+            // Auto-scroll when entering a view with a time grid
+            if matches!(new_view, CalendarView::Week | CalendarView::Day) {
+                return scroll_time_grid_on_open(app);
             }
         }
         Message::PreviousPeriod => {
@@ -474,6 +573,12 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
             dismiss_on_focus_loss(app);
             app.navigate_to_today();
         }
+        Message::GoToNextEvent => {
+            dismiss_on_focus_loss(app);
+            if let Some(date) = app.next_event_date {
+                app.set_selected_date(date);
+            }
+        }
         Message::SelectDay(year, month, day) => {
             dismiss_on_focus_loss(app);
             if let Some(date) = NaiveDate::from_ymd_opt(year, month, day) {
@@ -484,11 +589,23 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
             dismiss_on_focus_loss(app);
             app.selected_date = date;
         }
+        Message::MoveSelectedDate(step) => {
+            return handle_move_selected_date(app, step);
+        }
+        Message::SelectWeek(date) => {
+            dismiss_on_focus_loss(app);
+            app.selected_date = date;
+            app.current_view = CalendarView::Week;
+            app.sync_views_to_selected_date();
+            return scroll_time_grid_on_open(app);
+        }
 
         // === UI State ===
         Message::TimeTick => {
-            // Timer tick to update the current time indicator
+            // Minute tick (see clock.rs) to update the current time indicator
             // The view will re-render with the new time automatically
+            app.record_last_run(false);
+            return handle_alert_tick(app);
         }
         Message::ToggleSidebar => {
             app.show_sidebar = !app.show_sidebar;
@@ -496,8 +613,39 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
         Message::WindowResized => {
             // Sync is handled at start of update(), nothing else needed
         }
+        Message::RefreshEvents => {
+            // Another tool or a finished sync may have changed the stored events
+            debug!("Message::RefreshEvents");
+            app.calendar_manager.reload_events();
+            app.refresh_cached_events();
+        }
         Message::ToggleSearch => {
             app.show_search = !app.show_search;
+            if app.show_search {
+                return text_input::focus(search_input_id());
+            }
+            app.search_query.clear();
+            app.search_results.clear();
+        }
+        Message::SearchQueryChanged(query) => {
+            app.search_query = query;
+            app.refresh_search_results();
+        }
+        Message::TextEditingShortcut(action) => {
+            // The search field has the keyboard while the panel is open
+            if app.show_search {
+                debug!("Message::TextEditingShortcut: Search open, ignoring {:?}", action);
+            } else {
+                return Task::done(cosmic::Action::App(action.message()));
+            }
+        }
+        Message::SelectSearchResult(date, unique_id) => {
+            debug!("Message::SelectSearchResult: {} on {}", unique_id, date);
+            dismiss_on_focus_loss(app);
+            app.set_selected_date(date);
+            // SelectEvent toggles, so clear first to always end up selected
+            app.selected_event_uid = None;
+            return Task::done(cosmic::Action::App(Message::SelectEvent(unique_id)));
         }
         Message::ToggleWeekNumbers => {
             debug!("Message::ToggleWeekNumbers");
@@ -505,6 +653,136 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
                 log::error!("Failed to toggle week numbers: {}", e);
             }
         }
+        Message::ToggleWeekends => {
+            debug!("Message::ToggleWeekends");
+            if let Err(e) = SettingsHandler::toggle_weekends(&mut app.settings) {
+                log::error!("Failed to toggle weekends: {}", e);
+            }
+            // Rebuild the week so its visible days match the setting
+            app.sync_views_to_selected_date();
+        }
+        Message::ToggleMiniWeek => {
+            debug!("Message::ToggleMiniWeek");
+            if let Err(e) = SettingsHandler::toggle_mini_week(&mut app.settings) {
+                log::error!("Failed to toggle mini week strip: {}", e);
+            }
+        }
+        Message::SetMaxEventsPerDay(max_events) => {
+            debug!("Message::SetMaxEventsPerDay: {:?}", max_events);
+            if let Err(e) = SettingsHandler::set_max_events_per_day(&mut app.settings, max_events) {
+                log::error!("Failed to set max events per day: {}", e);
+            }
+        }
+        Message::SetGridLineStyle(grid_lines) => {
+            debug!("Message::SetGridLineStyle: {:?}", grid_lines);
+            if let Err(e) = SettingsHandler::set_grid_lines(&mut app.settings, grid_lines) {
+                log::error!("Failed to set grid lines: {}", e);
+            }
+        }
+        Message::SetEventTextSize(text_size) => {
+            debug!("Message::SetEventTextSize: {:?}", text_size);
+            if let Err(e) = SettingsHandler::set_event_text_size(&mut app.settings, text_size) {
+                log::error!("Failed to set event text size: {}", e);
+            }
+        }
+        Message::SetEventOverlapStyle(style) => {
+            debug!("Message::SetEventOverlapStyle: {:?}", style);
+            if let Err(e) = SettingsHandler::set_event_overlap_style(&mut app.settings, style) {
+                log::error!("Failed to set event overlap style: {}", e);
+            }
+        }
+        Message::SetFirstDayOfWeek(first_day) => {
+            debug!("Message::SetFirstDayOfWeek: {:?}", first_day);
+            if let Err(e) = SettingsHandler::set_first_day_of_week(&mut app.settings, first_day) {
+                log::error!("Failed to set first day of week: {}", e);
+            }
+            app.apply_locale_preferences();
+        }
+        Message::SetWeekendDays(weekend_days) => {
+            debug!("Message::SetWeekendDays: {:?}", weekend_days);
+            if let Err(e) = SettingsHandler::set_weekend_days(&mut app.settings, weekend_days) {
+                log::error!("Failed to set weekend days: {}", e);
+            }
+            app.apply_locale_preferences();
+        }
+        Message::SetUse24Hour(use_24_hour) => {
+            debug!("Message::SetUse24Hour: {:?}", use_24_hour);
+            if let Err(e) = SettingsHandler::set_use_24_hour(&mut app.settings, use_24_hour) {
+                log::error!("Failed to set clock format: {}", e);
+            }
+            app.apply_locale_preferences();
+        }
+        Message::SetDefaultView(default_view) => {
+            debug!("Message::SetDefaultView: {:?}", default_view);
+            if let Err(e) = SettingsHandler::set_default_view(&mut app.settings, default_view) {
+                log::error!("Failed to set default view: {}", e);
+            }
+        }
+        Message::SetAllDayAlert(alert) => {
+            debug!("Message::SetAllDayAlert: {:?}", alert);
+            if let Err(e) = SettingsHandler::set_all_day_alert(&mut app.settings, alert) {
+                log::error!("Failed to set all-day alert: {}", e);
+            }
+        }
+        Message::ToggleOffHoursShading => {
+            debug!("Message::ToggleOffHoursShading");
+            if let Err(e) = SettingsHandler::toggle_off_hours_shading(&mut app.settings) {
+                log::error!("Failed to toggle off-hours shading: {}", e);
+            }
+        }
+        Message::SetWorkingHours(hours) => {
+            debug!("Message::SetWorkingHours: {:?}", hours);
+            if let Err(e) = SettingsHandler::set_working_hours(&mut app.settings, hours) {
+                log::error!("Failed to set working hours: {}", e);
+            }
+        }
+        Message::SetDayStartHour(hour) => {
+            debug!("Message::SetDayStartHour: {}", hour);
+            if let Err(e) = SettingsHandler::set_day_start_hour(&mut app.settings, hour) {
+                log::error!("Failed to set day start hour: {}", e);
+            }
+        }
+        Message::ResetSettingsToSystemDefaults => {
+            debug!("Message::ResetSettingsToSystemDefaults");
+            if let Err(e) = SettingsHandler::reset_to_system_defaults(&mut app.settings) {
+                log::error!("Failed to reset settings: {}", e);
+            }
+            app.apply_locale_preferences();
+        }
+        Message::ToggleAllDayCollapsed => {
+            debug!("Message::ToggleAllDayCollapsed");
+            if let Err(e) = SettingsHandler::toggle_all_day_collapsed(&mut app.settings) {
+                log::error!("Failed to toggle all-day section: {}", e);
+            }
+        }
+        Message::ToggleCategoryFilter(category) => {
+            app.category_filter.toggle(&category);
+            debug!("Message::ToggleCategoryFilter: active={}", app.category_filter.is_active());
+            app.refresh_cached_events();
+        }
+        Message::ClearCategoryFilter => {
+            debug!("Message::ClearCategoryFilter");
+            app.category_filter.clear();
+            app.refresh_cached_events();
+        }
+        Message::ToggleNewEventMode => {
+            debug!("Message::ToggleNewEventMode");
+            if let Err(e) = SettingsHandler::toggle_new_event_mode(&mut app.settings) {
+                log::error!("Failed to toggle new event mode: {}", e);
+            }
+        }
+        Message::ToggleMissedAlerts => {
+            debug!("Message::ToggleMissedAlerts");
+            if let Err(e) = SettingsHandler::toggle_missed_alerts(&mut app.settings) {
+                log::error!("Failed to toggle missed alerts summary: {}", e);
+            }
+        }
+        Message::ToggleConfirmImportDuplicates => {
+            debug!("Message::ToggleConfirmImportDuplicates");
+            if let Err(e) = SettingsHandler::toggle_confirm_import_duplicates(&mut app.settings) {
+                log::error!("Failed to toggle import confirmation: {}", e);
+            }
+        }
         Message::WeekViewScroll(viewport) => {
             // Track scroll position via on_scroll callback (COSMIC Files pattern)
             // This stores the actual pixel offset so we can restore it precisely
@@ -592,6 +870,46 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
                 return handle_export_calendar_dialog(app, calendar_id, calendar_name);
             }
         }
+        Message::SyncCalendarByIndex(index) => {
+            DialogManager::close(&mut app.active_dialog);
+            if let Some(calendar) = app.calendar_manager.sources().get(index) {
+                let calendar_id = calendar.info().id.clone();
+                return handle_sync_calendar(app, calendar_id);
+            }
+        }
+        Message::CalendarSynced(calendar_id, result) => {
+            handle_calendar_synced(app, calendar_id, result);
+        }
+        Message::SyncAllCalendars => {
+            return handle_sync_all_calendars(app);
+        }
+        Message::OpenCalDavAccountDialog => {
+            dismiss_on_focus_loss(app);
+            handle_open_caldav_account_dialog(app);
+        }
+        Message::CalDavAccountNameChanged(value) => {
+            if let ActiveDialog::CalDavAccount { name, .. } = &mut app.active_dialog {
+                *name = value;
+            }
+        }
+        Message::CalDavAccountServerUrlChanged(value) => {
+            if let ActiveDialog::CalDavAccount { server_url, .. } = &mut app.active_dialog {
+                *server_url = value;
+            }
+        }
+        Message::CalDavAccountUsernameChanged(value) => {
+            if let ActiveDialog::CalDavAccount { username, .. } = &mut app.active_dialog {
+                *username = value;
+            }
+        }
+        Message::CalDavAccountPasswordChanged(value) => {
+            if let ActiveDialog::CalDavAccount { password, .. } = &mut app.active_dialog {
+                *password = value;
+            }
+        }
+        Message::ConfirmCalDavAccount => {
+            return handle_confirm_caldav_account(app);
+        }
         Message::CalendarDialogNameChanged(name) => {
             // Update calendar dialog name via active_dialog
             match &mut app.active_dialog {
@@ -612,6 +930,15 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
                 _ => {}
             }
         }
+        Message::CalendarDialogMuteAlertsToggled(muted) => {
+            match &mut app.active_dialog {
+                ActiveDialog::CalendarCreate { mute_alerts, .. }
+                | ActiveDialog::CalendarEdit { mute_alerts, .. } => {
+                    *mute_alerts = muted;
+                }
+                _ => {}
+            }
+        }
         Message::ConfirmCalendarDialog => {
             handle_confirm_calendar_dialog(app);
         }
@@ -634,6 +961,14 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
                 app.update_selected_calendar_color();
             }
         }
+        Message::ToggleCalendarByIndex(index) => {
+            if let Some(calendar) = app.calendar_manager.sources().get(index) {
+                let id = calendar.info().id.clone();
+                let task = close_quick_event_with_scroll_restore(app);
+                handle_toggle_calendar(app, id);
+                return task;
+            }
+        }
         Message::DeleteCalendarByIndex(index) => {
             DialogManager::close(&mut app.active_dialog);
             if let Some(calendar) = app.calendar_manager.sources().get(index) {
@@ -641,6 +976,28 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
                 handle_request_delete_calendar(app, id);
             }
         }
+        Message::ChangeCalendarColorByIndex(index) => {
+            if let Some(calendar) = app.calendar_manager.sources().get(index) {
+                let id = calendar.info().id.clone();
+                DialogManager::open(&mut app.active_dialog, ActiveDialog::ColorPicker { calendar_id: id });
+            }
+        }
+        Message::SetDefaultCalendarByIndex(index) => {
+            DialogManager::close(&mut app.active_dialog);
+            handle_set_default_calendar(app, index);
+        }
+        Message::TogglePinCalendarByIndex(index) => {
+            DialogManager::close(&mut app.active_dialog);
+            handle_toggle_pin_calendar(app, index);
+        }
+        Message::MoveCalendarByIndex(index, new_index) => {
+            DialogManager::close(&mut app.active_dialog);
+            handle_move_calendar(app, index, new_index);
+        }
+        Message::DuplicateCalendarByIndex(index) => {
+            DialogManager::close(&mut app.active_dialog);
+            handle_duplicate_calendar(app, index);
+        }
         Message::ConfirmDeleteCalendar => {
             handle_confirm_delete_calendar(app);
         }
@@ -703,6 +1060,15 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
             handle_start_quick_timed_event(app, date, start_time, end_time);
             return focus_quick_event_input();
         }
+        Message::CreateEventAt(date, start_time) => {
+            let defaults = app
+                .selected_calendar_id
+                .as_deref()
+                .map(|id| NewEventDefaults::for_calendar(&app.calendar_manager, id))
+                .unwrap_or_default();
+            let message = new_event_message(app.settings.new_event_mode, date, start_time, defaults);
+            return update_state(app, message);
+        }
         Message::QuickEventTextChanged(text) => {
             handle_quick_event_text_changed(app, text);
         }
@@ -721,7 +1087,10 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
         }
         Message::RequestDeleteSelectedEvent => {
             // Request delete of selected event - opens confirmation dialog
-            if let Some(uid) = app.selected_event_uid.clone() {
+            if !app.selected_event_uids.is_empty() {
+                // Select All batch: deleted at once, undoable from the toast
+                handle_delete_selected_events(app);
+            } else if let Some(uid) = app.selected_event_uid.clone() {
                 // Extract master UID for recurring events (occurrence UIDs have format master-uid_YYYYMMDD)
                 let master_uid = extract_master_uid(&uid);
                 // Extract the occurrence date from the UID (if it's an occurrence)
@@ -745,6 +1114,45 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
                 debug!("RequestDeleteSelectedEvent: No event selected");
             }
         }
+        Message::MoveEventToToday(uid) => {
+            handle_move_event_to_today(app, uid);
+        }
+        Message::MoveSelectedEventToToday => {
+            // Selection holds "calendar_id:uid" for chips; the move looks events up by UID.
+            // Moving is for a single event: a Select All batch is deleted or exported, never moved
+            if !app.selected_event_uids.is_empty() {
+                debug!("MoveSelectedEventToToday: Batch selections are not moved");
+            } else if let Some(selected) = app.selected_event_uid.clone() {
+                let uid = selected.split_once(':').map_or(selected.as_str(), |(_, uid)| uid).to_string();
+                handle_move_event_to_today(app, uid);
+            } else {
+                debug!("MoveSelectedEventToToday: No event selected");
+            }
+        }
+        Message::RepeatEventForward(uid, period) => {
+            handle_repeat_event_forward(app, uid, period);
+        }
+        Message::RepeatSelectedEventForward(period) => {
+            if let Some(selected) = app.selected_event_uid.clone() {
+                let uid = selected.split_once(':').map_or(selected.as_str(), |(_, uid)| uid).to_string();
+                handle_repeat_event_forward(app, uid, period);
+            } else {
+                debug!("RepeatSelectedEventForward: No event selected");
+            }
+        }
+        Message::DuplicateEvent(uid) => {
+            handle_duplicate_event(app, uid);
+        }
+        Message::DuplicateSelectedEvent => {
+            if app.active_dialog.is_open() || app.event_dialog.is_some() {
+                debug!("DuplicateSelectedEvent: Dialog open, ignoring");
+            } else if let Some(selected) = app.selected_event_uid.clone() {
+                let uid = selected.split_once(':').map_or(selected.as_str(), |(_, uid)| uid).to_string();
+                handle_duplicate_event(app, uid);
+            } else {
+                debug!("DuplicateSelectedEvent: No event selected");
+            }
+        }
         Message::ConfirmDeleteEvent => {
             // Confirm event deletion from the dialog (deletes all occurrences for recurring events)
             if let Some((event_uid, _event_name, _is_recurring, _occurrence_date)) = app.active_dialog.event_delete_data() {
@@ -796,6 +1204,17 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
                 }
             }
         }
+        Message::DeleteFollowingOccurrences => {
+            // End the series before the selected occurrence
+            if let Some((event_uid, _event_name, is_recurring, occurrence_date)) = app.active_dialog.event_delete_data() {
+                let uid = event_uid.to_string();
+                DialogManager::close(&mut app.active_dialog);
+                match (is_recurring, occurrence_date) {
+                    (true, Some(date)) => handle_delete_following_occurrences(app, uid, date),
+                    _ => debug!("DeleteFollowingOccurrences: Not an occurrence of a recurring event, ignoring"),
+                }
+            }
+        }
         Message::CancelDeleteEvent => {
             // Cancel event deletion
             DialogManager::close(&mut app.active_dialog);
@@ -803,6 +1222,23 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
         Message::SelectEvent(uid) => {
             handle_select_event(app, uid);
         }
+        Message::SelectAllEventsInView => {
+            handle_select_all_events_in_view(app);
+        }
+        Message::Undo => {
+            handle_undo(app);
+        }
+        Message::Redo => {
+            handle_redo(app);
+        }
+
+        // === Toast Notifications ===
+        Message::DismissToast => {
+            app.toast.dismiss();
+        }
+        Message::ToastTick => {
+            app.toast.expire(std::time::Instant::now());
+        }
 
         // === Event Drag-and-Drop ===
         Message::DragEventStart(calendar_id, uid, date, summary, color) => {
@@ -812,7 +1248,9 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
             handle_drag_event_update(app, date);
         }
         Message::DragEventCursorMove(x, y) => {
+            app.cursor_position = (x, y);
             app.event_drag_state.update_cursor(x, y);
+            app.event_resize_state.update_cursor(y, HOUR_ROW_HEIGHT);
         }
         Message::DragEventEnd => {
             handle_drag_event_end(app);
@@ -821,17 +1259,37 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
             handle_drag_event_cancel(app);
         }
 
+        // === Event Resize ===
+        Message::EventResizeStart(calendar_id, uid, edge, start, end) => {
+            handle_event_resize_start(app, calendar_id, uid, edge, start, end);
+        }
+        Message::EventResizeEnd => {
+            handle_event_resize_end(app);
+        }
+
         // === Event Management - Event Dialog ===
         Message::OpenNewEventDialog => {
             handle_open_new_event_dialog(app);
         }
+        Message::OpenNewEventDialogAt(date, start_time) => {
+            handle_open_new_event_dialog_at(app, date, start_time);
+        }
         Message::OpenEditEventDialog(calendar_id, uid) => {
             // Cancel any drag operation that may have started from the first click of double-click
             app.event_drag_state.cancel();
             // Clear selection since we're opening the edit dialog
             app.selected_event_uid = None;
+            // Opened from the day events popover: the edit dialog replaces it
+            if matches!(app.active_dialog, ActiveDialog::DayEvents { .. }) {
+                DialogManager::close(&mut app.active_dialog);
+            }
             handle_open_edit_event_dialog(app, calendar_id, uid);
         }
+        Message::OpenDayEvents(date) => {
+            // The press that opened it must not leave a selection behind
+            app.selection_state.cancel();
+            DialogManager::open(&mut app.active_dialog, ActiveDialog::DayEvents { date, anchor: app.cursor_position });
+        }
         Message::EventDialogToggleEdit(field, editing) => {
             #[allow(deprecated)]
             if let Some(ref mut dialog) = app.event_dialog {
@@ -854,14 +1312,23 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
             #[allow(deprecated)]
             if let Some(ref mut dialog) = app.event_dialog {
                 dialog.all_day = all_day;
+                // New events switch between no alert and the all-day default
+                if dialog.editing_uid.is_none() {
+                    let all_day_alert = &app.settings.all_day_alert;
+                    if all_day && dialog.alert == AlertTime::None {
+                        dialog.alert = all_day_alert.clone();
+                    } else if !all_day && dialog.alert == *all_day_alert {
+                        dialog.alert = AlertTime::None;
+                    }
+                }
             }
         }
         Message::EventDialogStartDateInputChanged(input) => {
             #[allow(deprecated)]
             if let Some(ref mut dialog) = app.event_dialog {
                 dialog.start_date_input = input.clone();
-                // Try to parse the date
-                if let Ok(date) = chrono::NaiveDate::parse_from_str(&input, "%Y-%m-%d") {
+                // Unparsable input stays in the field; the dialog marks it and saving is refused
+                if let Some(date) = crate::validation::parse_date(&input) {
                     dialog.start_date = date;
                     // If end date is before start, adjust it
                     if dialog.end_date < date {
@@ -961,8 +1428,8 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
             #[allow(deprecated)]
             if let Some(ref mut dialog) = app.event_dialog {
                 dialog.end_date_input = input.clone();
-                // Try to parse the date
-                if let Ok(date) = chrono::NaiveDate::parse_from_str(&input, "%Y-%m-%d") {
+                // Unparsable input stays in the field; the dialog marks it and saving is refused
+                if let Some(date) = crate::validation::parse_date(&input) {
                     dialog.end_date = date;
                 }
             }
@@ -1047,6 +1514,26 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
                 dialog.repeat = repeat;
             }
         }
+        Message::EventDialogRepeatIntervalChanged(interval) => {
+            #[allow(deprecated)]
+            if let Some(ref mut dialog) = app.event_dialog {
+                dialog.repeat_interval = interval.clamp(1, MAX_REPEAT_INTERVAL);
+            }
+        }
+        Message::EventDialogRepeatWeekdayToggled(weekday) => {
+            #[allow(deprecated)]
+            if let Some(ref mut dialog) = app.event_dialog {
+                // No weekdays means the start date's weekday, so that one starts selected
+                if dialog.repeat_weekdays.is_empty() {
+                    dialog.repeat_weekdays.push(dialog.start_date.weekday());
+                }
+                if let Some(index) = dialog.repeat_weekdays.iter().position(|day| *day == weekday) {
+                    dialog.repeat_weekdays.remove(index);
+                } else {
+                    dialog.repeat_weekdays.push(weekday);
+                }
+            }
+        }
         Message::EventDialogCalendarChanged(calendar_id) => {
             #[allow(deprecated)]
             if let Some(ref mut dialog) = app.event_dialog {
@@ -1077,6 +1564,37 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
                 }
             }
         }
+        Message::EventDialogCategoryInputChanged(input) => {
+            #[allow(deprecated)]
+            if let Some(ref mut dialog) = app.event_dialog {
+                dialog.category_input = input;
+            }
+        }
+        Message::EventDialogAddCategory => {
+            #[allow(deprecated)]
+            if let Some(ref mut dialog) = app.event_dialog {
+                let category = dialog.category_input.trim().to_string();
+                let is_duplicate = dialog.categories.iter().any(|c| c.eq_ignore_ascii_case(&category));
+                if !category.is_empty() && !is_duplicate {
+                    dialog.categories.push(category);
+                    dialog.category_input.clear();
+                }
+            }
+        }
+        Message::EventDialogRemoveCategory(index) => {
+            #[allow(deprecated)]
+            if let Some(ref mut dialog) = app.event_dialog {
+                if index < dialog.categories.len() {
+                    dialog.categories.remove(index);
+                }
+            }
+        }
+        Message::EventDialogColorChanged(color) => {
+            #[allow(deprecated)]
+            if let Some(ref mut dialog) = app.event_dialog {
+                dialog.color = color;
+            }
+        }
         Message::EventDialogAlertChanged(alert) => {
             #[allow(deprecated)]
             if let Some(ref mut dialog) = app.event_dialog {
@@ -1120,6 +1638,12 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
         Message::ConfirmEventDialog => {
             handle_confirm_event_dialog(app);
         }
+        Message::ConfirmEditScope(scope) => {
+            handle_confirm_edit_scope(app, scope);
+        }
+        Message::CancelEditScope => {
+            handle_cancel_edit_scope(app);
+        }
         Message::CancelEventDialog => {
             handle_cancel_event_dialog(app);
         }
@@ -1161,48 +1685,100 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
             );
         }
         Message::ExportICal => {
-            // Export the first calendar to Downloads folder
-            info!("Message::ExportICal: Export iCal requested");
+            // Pick a destination via XDG portal (Flatpak-compatible), then export
+            // the events selected with Select All, or else the enabled calendars, there
+            info!("Message::ExportICal: Opening save dialog");
+
+            // Selection ids have format "calendar_id:uid"; an occurrence exports its whole series
+            let mut selected: Vec<(String, String)> = Vec::new();
+            for id in &app.selected_event_uids {
+                if let Some((calendar_id, uid)) = id.split_once(':') {
+                    let series = (calendar_id.to_string(), extract_master_uid(uid).to_string());
+                    if !selected.contains(&series) {
+                        selected.push(series);
+                    }
+                }
+            }
 
-            if let Some(calendar) = app.calendar_manager.sources().first() {
-                let calendar_id = calendar.info().id.clone();
-                let calendar_name = calendar.info().name.clone();
+            let today = chrono::Local::now().format("%Y-%m-%d");
+            let suggested_filename = if selected.is_empty() {
+                format!("Calendar-{}.ics", today)
+            } else {
+                format!("Events-{}.ics", today)
+            };
 
-                // Create export filename with timestamp
-                let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
-                let filename = format!("{}_{}.ics", calendar_name.replace(" ", "_"), timestamp);
-
-                // Export to Downloads folder
-                if let Some(downloads_dir) = dirs::download_dir() {
-                    let export_path = downloads_dir.join(&filename);
-
-                    match ExportHandler::export_to_file(
-                        &app.calendar_manager,
-                        &calendar_id,
-                        &export_path,
-                    ) {
-                        Ok(_) => {
-                            info!("Message::ExportICal: Exported to {:?}", export_path);
-                            // TODO: Show success notification
-                        }
-                        Err(e) => {
-                            error!("Message::ExportICal: Export failed: {}", e);
-                            // TODO: Show error notification
+            return Task::perform(
+                async move {
+                    rfd::AsyncFileDialog::new()
+                        .set_title("Export iCalendar File")
+                        .set_file_name(&suggested_filename)
+                        .add_filter("iCalendar", &["ics", "ical", "ifb", "icalendar"])
+                        .save_file()
+                        .await
+                        .map(|handle| handle.path().to_path_buf())
+                },
+                move |option_path| {
+                    if let Some(path) = option_path {
+                        if selected.is_empty() {
+                            cosmic::Action::App(Message::ExportAllToFile(path))
+                        } else {
+                            cosmic::Action::App(Message::ExportEventsToFile(selected.clone(), path))
                         }
+                    } else {
+                        // User cancelled the save dialog
+                        cosmic::Action::App(Message::None)
                     }
-                } else {
-                    error!("Message::ExportICal: Could not determine Downloads folder");
-                }
-            } else {
-                error!("Message::ExportICal: No calendars available to export");
+                },
+            );
+        }
+        Message::PrintWeek => {
+            // Pick a destination for the printable page of the visible week
+            info!("Message::PrintWeek: Opening save dialog");
+
+            let first_day = app.week_state.days.first().copied().unwrap_or(app.selected_date);
+            let suggested_filename = format!("Week-{}.svg", first_day.format("%Y-%m-%d"));
+
+            return Task::perform(
+                async move {
+                    rfd::AsyncFileDialog::new()
+                        .set_title("Print Week")
+                        .set_file_name(&suggested_filename)
+                        .add_filter("SVG image", &["svg"])
+                        .save_file()
+                        .await
+                        .map(|handle| handle.path().to_path_buf())
+                },
+                |option_path| {
+                    if let Some(path) = option_path {
+                        cosmic::Action::App(Message::PrintWeekToFile(path))
+                    } else {
+                        // User cancelled the save dialog
+                        cosmic::Action::App(Message::None)
+                    }
+                },
+            );
+        }
+        Message::OpenDataDirectory => {
+            // Open the directory holding the local database in the file manager
+            let data_dir = Database::get_data_directory();
+            info!("Message::OpenDataDirectory: Opening {:?}", data_dir);
+
+            if let Err(e) = std::fs::create_dir_all(&data_dir) {
+                error!("Message::OpenDataDirectory: Failed to create {:?}: {}", data_dir, e);
+            } else if let Err(e) = open::that(&data_dir) {
+                error!("Message::OpenDataDirectory: Failed to open {:?}: {}", data_dir, e);
             }
         }
         Message::Settings => {
-            // TODO: Open settings dialog
-            info!("Message::Settings: Settings requested (not yet implemented)");
+            info!("Message::Settings: Opening settings dialog");
+            dismiss_on_focus_loss(app);
+            DialogManager::open(&mut app.active_dialog, ActiveDialog::Settings);
         }
         Message::About => {
             app.core.window.show_context = !app.core.window.show_context;
+            if app.core.window.show_context {
+                app.refresh_calendar_stats();
+            }
         }
         Message::LaunchUrl(url) => {
             // Open URL in default browser
@@ -1210,6 +1786,9 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
         }
         Message::ToggleContextDrawer => {
             app.core.window.show_context = !app.core.window.show_context;
+            if app.core.window.show_context {
+                app.refresh_calendar_stats();
+            }
         }
         Message::Surface(action) => {
             return cosmic::task::message(cosmic::Action::Cosmic(
@@ -1254,6 +1833,15 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
         Message::ExportCalendarToFile(calendar_id, path) => {
             return handle_export_calendar_to_file(app, calendar_id, path);
         }
+        Message::ExportAllToFile(path) => {
+            return handle_export_all_to_file(app, path);
+        }
+        Message::ExportEventsToFile(ids, path) => {
+            return handle_export_events_to_file(app, ids, path);
+        }
+        Message::PrintWeekToFile(path) => {
+            return handle_print_week_to_file(app, path);
+        }
 
         Message::ProcessUrl(url) => {
             return handle_process_url(app, url);
@@ -1293,3 +1881,22 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
 
     Task::none()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_grid_opens_at_day_start_without_today() {
+        let monday = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+        let week: Vec<NaiveDate> = (0..7).map(|offset| monday + chrono::Duration::days(offset)).collect();
+
+        // Today is shown: an hour before the current time
+        assert_eq!(time_grid_top_hour(&week, monday + chrono::Duration::days(3), 14, 8), 13);
+        assert_eq!(time_grid_top_hour(&week, monday, 0, 8), 0);
+
+        // A past or future week: the day start hour
+        assert_eq!(time_grid_top_hour(&week, monday + chrono::Duration::days(30), 14, 8), 8);
+        assert_eq!(time_grid_top_hour(&[monday], monday - chrono::Duration::days(1), 14, 6), 6);
+    }
+}
@@ -1,8 +1,12 @@
 //! Navigation-related message handlers (Previous/Next period, view changes)
 
 use chrono::{Datelike, NaiveDate};
+use cosmic::app::Task;
+
 use crate::app::CosmicCalendar;
-use crate::views::CalendarView;
+use crate::keyboard::{step_date, DateStep};
+use crate::message::Message;
+use crate::views::{CalendarView, AGENDA_DAYS};
 
 /// Direction for period navigation
 pub enum NavigationDirection {
@@ -34,6 +38,10 @@ pub fn handle_period_navigation(app: &mut CosmicCalendar, direction: NavigationD
             // Move by one day
             Some(app.selected_date + chrono::Duration::days(multiplier as i64))
         }
+        CalendarView::Agenda => {
+            // Move by the length of the agenda
+            Some(app.selected_date + chrono::Duration::days(AGENDA_DAYS * multiplier as i64))
+        }
     };
 
     if let Some(date) = new_date {
@@ -60,6 +68,24 @@ fn navigate_by_month(date: NaiveDate, months: i32) -> Option<NaiveDate> {
         .or_else(|| NaiveDate::from_ymd_opt(new_year, new_month, 28))
 }
 
+/// Move the selected date with the keyboard. Dates within the visible range are
+/// selected in place; others navigate the view to them.
+/// Ignored while a dialog or quick event is open so its keys aren't taken over.
+pub fn handle_move_selected_date(app: &mut CosmicCalendar, step: DateStep) -> Task<Message> {
+    if app.active_dialog.is_open() || app.event_dialog.is_some() {
+        return Task::none();
+    }
+
+    let date = step_date(app.selected_date, step, app.current_view);
+    let (first, last) = app.visible_range();
+    let message = if date >= first && date <= last {
+        Message::SelectDayNoNavigate(date)
+    } else {
+        Message::SelectDay(date.year(), date.month(), date.day())
+    };
+    Task::done(cosmic::Action::App(message))
+}
+
 /// Handle previous period navigation
 pub fn handle_previous_period(app: &mut CosmicCalendar) {
     handle_period_navigation(app, NavigationDirection::Previous);
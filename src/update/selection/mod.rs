@@ -55,8 +55,8 @@ pub fn handle_selection_end(app: &mut CosmicCalendar) {
         CalendarView::Day => {
             day::handle_selection_end(app, range.start.date, range.end.date);
         }
-        CalendarView::Year => {
-            // Year view: just select the day (no special selection behavior)
+        CalendarView::Year | CalendarView::Agenda => {
+            // Year and agenda views: just select the day (no special selection behavior)
             app.set_selected_date(range.start.date);
         }
     }
@@ -93,48 +93,22 @@ pub fn handle_time_selection_end(app: &mut CosmicCalendar) {
         return;
     };
 
-    // Get the time range - time selection should always have times
-    let Some(start_time) = range.start_time() else {
-        debug!("handle_time_selection_end: No start time, skipping");
-        return;
-    };
-
-    // If no end time was set (no drag to a different cell), skip
-    let Some(end_time) = range.end_time() else {
-        debug!("handle_time_selection_end: No end time (single click), skipping");
-        return;
-    };
-
-    // Check if there was actual movement (different start/end)
-    // If start == end (same cell, no drag), don't open the quick event dialog
-    // This allows double-click to work properly
-    let start_date = range.start.date;
-    let end_date = range.end.date;
-
-    if start_date == end_date && start_time == end_time {
+    // Full date-times of the selection, which may cross days.
+    // None if a time is missing or there was no drag (same cell), so
+    // double-click keeps working
+    let Some((start, end)) = range.time_bounds() else {
         debug!("handle_time_selection_end: No drag detected (same cell), skipping quick event");
         return;
-    }
-
-    // Normalize: ensure start <= end
-    let (start_time, end_time) = if start_time <= end_time {
-        (start_time, end_time)
-    } else {
-        (end_time, start_time)
     };
 
     debug!(
-        "handle_time_selection_end: Creating quick timed event from {:?} to {:?}",
-        start_time, end_time
+        "handle_time_selection_end: Creating quick timed event from {} to {}",
+        start, end
     );
 
     // Open quick event input with the selected time range
     DialogManager::handle_action(
         &mut app.active_dialog,
-        DialogAction::StartQuickTimedEvent {
-            date: range.start_date(),
-            start_time,
-            end_time,
-        },
+        DialogAction::StartQuickTimedEventRange { start, end },
     );
 }
@@ -9,7 +9,7 @@
 // Allow unused for now - these are foundation functions for future refactoring
 #![allow(dead_code)]
 
-use chrono::NaiveDate;
+use chrono::{NaiveDate, NaiveDateTime};
 
 /// Validate and parse a date string in YYYY-MM-DD format
 pub fn parse_date(input: &str) -> Option<NaiveDate> {
@@ -21,6 +21,39 @@ pub fn validate_event_title(title: &str) -> bool {
     !title.trim().is_empty()
 }
 
+/// Why the event dialog's input can't be saved
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventInputError {
+    EmptyTitle,
+    InvalidStartDate,
+    InvalidEndDate,
+    EndBeforeStart,
+}
+
+/// Check event dialog input: a title, dates in YYYY-MM-DD format and an end
+/// (`start`/`end` as entered) that isn't before the start. Returns the first problem.
+pub fn validate_event_input(
+    title: &str,
+    start_date_input: &str,
+    end_date_input: &str,
+    start: NaiveDateTime,
+    end: NaiveDateTime,
+) -> Result<(), EventInputError> {
+    if !validate_event_title(title) {
+        return Err(EventInputError::EmptyTitle);
+    }
+    if parse_date(start_date_input).is_none() {
+        return Err(EventInputError::InvalidStartDate);
+    }
+    if parse_date(end_date_input).is_none() {
+        return Err(EventInputError::InvalidEndDate);
+    }
+    if end < start {
+        return Err(EventInputError::EndBeforeStart);
+    }
+    Ok(())
+}
+
 /// Validate an email address (basic check)
 pub fn validate_email(email: &str) -> bool {
     let email = email.trim();
@@ -54,6 +87,20 @@ mod tests {
         assert!(!validate_event_title("   "));
     }
 
+    #[test]
+    fn test_validate_event_input() {
+        let at = |day: u32, hour: u32| NaiveDate::from_ymd_opt(2025, 3, day).unwrap().and_hms_opt(hour, 0, 0).unwrap();
+        let validate = |title, start_input, end_input, end| validate_event_input(title, start_input, end_input, at(10, 9), end);
+
+        assert_eq!(validate("Standup", "2025-03-10", "2025-03-10", at(10, 10)), Ok(()));
+        // Zero-length events are allowed
+        assert_eq!(validate("Standup", "2025-03-10", "2025-03-10", at(10, 9)), Ok(()));
+        assert_eq!(validate("  ", "2025-03-10", "2025-03-10", at(10, 10)), Err(EventInputError::EmptyTitle));
+        assert_eq!(validate("Standup", "2025-3-1x", "2025-03-10", at(10, 10)), Err(EventInputError::InvalidStartDate));
+        assert_eq!(validate("Standup", "2025-03-10", "", at(10, 10)), Err(EventInputError::InvalidEndDate));
+        assert_eq!(validate("Standup", "2025-03-10", "2025-03-09", at(9, 10)), Err(EventInputError::EndBeforeStart));
+    }
+
     #[test]
     fn test_validate_email() {
         assert!(validate_email("test@example.com"));
@@ -0,0 +1,176 @@
+//! Agenda view
+//!
+//! Scrollable list of the events in the next `AGENDA_DAYS` days from the
+//! selected date, grouped by date. Days without events are left out.
+//! Clicking a row opens the event's edit dialog.
+
+use chrono::{Datelike, Duration, NaiveDate};
+use cosmic::iced::{Alignment, Length};
+use cosmic::widget::{column, container, mouse_area, row, scrollable, text};
+use cosmic::Element;
+use std::collections::HashMap;
+
+use crate::components::{parse_color_safe, DisplayEvent};
+use crate::fl;
+use crate::locale::LocalePreferences;
+use crate::localized_names::get_weekday_full;
+use crate::message::Message;
+use crate::ui_constants::{
+    FONT_SIZE_BODY, FONT_SIZE_MEDIUM, PADDING_MEDIUM, PADDING_SMALL, PADDING_TINY, SPACING_MEDIUM, SPACING_SMALL,
+    SPACING_XXS,
+};
+
+/// Number of days listed, starting at the selected date
+pub const AGENDA_DAYS: i64 = 30;
+
+/// Width of the time column in front of each summary
+const AGENDA_TIME_WIDTH: f32 = 110.0;
+
+/// Diameter of the calendar color dot in front of each row
+const AGENDA_COLOR_DOT_SIZE: f32 = 10.0;
+
+/// Dates covered by the agenda starting at `start`
+pub fn agenda_days(start: NaiveDate) -> Vec<NaiveDate> {
+    (0..AGENDA_DAYS).map(|offset| start + Duration::days(offset)).collect()
+}
+
+/// Events of the agenda range grouped by date, in date order.
+/// All-day events come first on each day, then timed events by start time.
+fn agenda_groups(
+    events_by_date: &HashMap<NaiveDate, Vec<DisplayEvent>>,
+    start: NaiveDate,
+) -> Vec<(NaiveDate, Vec<&DisplayEvent>)> {
+    agenda_days(start)
+        .into_iter()
+        .filter_map(|date| {
+            let mut events: Vec<&DisplayEvent> = events_by_date.get(&date)?.iter().collect();
+            if events.is_empty() {
+                return None;
+            }
+            events.sort_by_key(|event| (!event.all_day, event.start_time));
+            Some((date, events))
+        })
+        .collect()
+}
+
+/// Render the agenda list starting at `start`
+pub fn render_agenda_view<'a>(
+    events_by_date: &'a HashMap<NaiveDate, Vec<DisplayEvent>>,
+    start: NaiveDate,
+    locale: &LocalePreferences,
+) -> Element<'a, Message> {
+    let groups = agenda_groups(events_by_date, start);
+
+    if groups.is_empty() {
+        return container(text(fl!("agenda-no-events", days = AGENDA_DAYS)).size(FONT_SIZE_BODY))
+            .padding(PADDING_MEDIUM)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into();
+    }
+
+    let list = groups
+        .into_iter()
+        .fold(column().spacing(SPACING_MEDIUM), |list, (date, events)| {
+            let header = text(locale.format_day_header(&date, &get_weekday_full(date.weekday())))
+                .size(FONT_SIZE_MEDIUM)
+                .font(cosmic::font::bold());
+            let day = events
+                .into_iter()
+                .fold(column().spacing(SPACING_XXS).push(header), |day, event| {
+                    day.push(render_agenda_row(event, locale))
+                });
+            list.push(day)
+        });
+
+    scrollable(container(list).padding(PADDING_MEDIUM).width(Length::Fill))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
+}
+
+/// One event row: color dot, time (or "All day") and summary
+fn render_agenda_row<'a>(event: &DisplayEvent, locale: &LocalePreferences) -> Element<'a, Message> {
+    let color = parse_color_safe(&event.color);
+    let dot = container(text(""))
+        .width(Length::Fixed(AGENDA_COLOR_DOT_SIZE))
+        .height(Length::Fixed(AGENDA_COLOR_DOT_SIZE))
+        .style(move |_theme: &cosmic::Theme| container::Style {
+            background: Some(color.into()),
+            border: cosmic::iced::Border {
+                radius: (AGENDA_COLOR_DOT_SIZE / 2.0).into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+
+    let time = match (event.start_time, event.end_time) {
+        (Some(start), Some(end)) => format!("{} – {}", locale.format_time(&start), locale.format_time(&end)),
+        (Some(start), None) => locale.format_time(&start),
+        _ => fl!("event-all-day"),
+    };
+
+    let content = row()
+        .spacing(SPACING_SMALL)
+        .align_y(Alignment::Center)
+        .padding([PADDING_TINY, PADDING_SMALL])
+        .push(dot)
+        .push(text(time).size(FONT_SIZE_BODY).width(Length::Fixed(AGENDA_TIME_WIDTH)))
+        .push(text(event.summary.clone()).size(FONT_SIZE_BODY).width(Length::Fill));
+
+    mouse_area(container(content).width(Length::Fill))
+        .on_press(Message::OpenEditEventDialog(event.calendar_id.clone(), event.uid.clone()))
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveTime;
+
+    fn event(uid: &str, start_hour: Option<u32>) -> DisplayEvent {
+        DisplayEvent {
+            calendar_id: "work".to_string(),
+            uid: uid.to_string(),
+            summary: uid.to_string(),
+            all_day: start_hour.is_none(),
+            start_time: start_hour.map(|h| NaiveTime::from_hms_opt(h, 0, 0).unwrap()),
+            end_time: start_hour.map(|h| NaiveTime::from_hms_opt(h + 1, 0, 0).unwrap()),
+            ..DisplayEvent::test_default()
+        }
+    }
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 3, day).unwrap()
+    }
+
+    #[test]
+    fn test_groups_cover_range_in_order() {
+        let mut events = HashMap::new();
+        events.insert(date(20), vec![event("review", Some(15)), event("standup", Some(9)), event("offsite", None)]);
+        events.insert(date(12), vec![event("lunch", Some(12))]);
+        events.insert(date(14), vec![]);
+        // Before the start and after the last listed day
+        events.insert(date(11), vec![event("yesterday", Some(9))]);
+        events.insert(date(12) + Duration::days(AGENDA_DAYS), vec![event("too-late", Some(9))]);
+
+        let groups = agenda_groups(&events, date(12));
+        let summary: Vec<(NaiveDate, Vec<&str>)> = groups
+            .iter()
+            .map(|(date, events)| (*date, events.iter().map(|e| e.uid.as_str()).collect()))
+            .collect();
+
+        assert_eq!(summary, vec![
+            (date(12), vec!["lunch"]),
+            (date(20), vec!["offsite", "standup", "review"]),
+        ]);
+    }
+
+    #[test]
+    fn test_agenda_days_span() {
+        let days = agenda_days(date(12));
+        assert_eq!(days.len(), AGENDA_DAYS as usize);
+        assert_eq!(days.first(), Some(&date(12)));
+        assert_eq!(days.last(), Some(&NaiveDate::from_ymd_opt(2025, 4, 10).unwrap()));
+    }
+}
@@ -1,25 +1,47 @@
 use cosmic::iced::Length;
 use cosmic::widget::{column, container, row, scrollable};
 use cosmic::Element;
+use std::collections::HashMap;
 
-use crate::components::{render_time_grid, render_time_column_placeholder, DayColumn, render_day_header, DayHeaderConfig};
+use super::week::{apply_resize_preview, separate_events, week_time_grid_id, WeekViewEvents};
+use crate::components::{render_time_grid, render_time_column_placeholder, render_day_header, DayHeaderConfig, TimeGridState};
 use crate::styles::bordered_cell_style;
 use crate::locale::LocalePreferences;
 use crate::message::Message;
 use crate::models::DayState;
+use crate::settings::GridLineStyle;
 use crate::ui_constants::{PADDING_SMALL, ALL_DAY_HEADER_HEIGHT};
 
-pub fn render_day_view(day_state: &DayState, locale: &LocalePreferences) -> Element<'static, Message> {
-    let all_day_section = render_all_day_section(day_state);
+/// Render the day view: the week view's time grid with a single day column,
+/// so overlapping events, drag-to-create and the current time line work the same
+pub fn render_day_view<'a>(
+    day_state: &'a DayState,
+    locale: &'a LocalePreferences,
+    events: Option<WeekViewEvents<'a>>,
+    grid_lines: GridLineStyle,
+) -> Element<'a, Message> {
+    let all_day_section = render_all_day_section(day_state, grid_lines);
 
-    // Single day column for day view (never weekend-styled in day view)
-    let day_columns = vec![DayColumn::regular()];
-    let time_grid = render_time_grid(locale, &day_columns);
+    let days = [day_state.date];
+    let mut timed_events = events
+        .as_ref()
+        .map_or_else(HashMap::new, |ev| separate_events(ev.events_by_date, &days).1);
+    if let Some(ref ev) = events {
+        apply_resize_preview(&mut timed_events, ev.resize);
+    }
+    let grid_state = events.as_ref().map_or_else(TimeGridState::default, WeekViewEvents::grid_state);
+    let time_grid = render_time_grid(locale, &days, &timed_events, grid_state, grid_lines);
 
+    // Shares the week view's scroll ID, so scrolling to the current time works for both
     let content = column()
         .spacing(0)
         .push(all_day_section)
-        .push(scrollable(time_grid));
+        .push(
+            scrollable(time_grid)
+                .id(week_time_grid_id())
+                .on_scroll(Message::WeekViewScroll)
+                .height(Length::Fill)
+        );
 
     container(content)
         .width(Length::Fill)
@@ -28,7 +50,7 @@ pub fn render_day_view(day_state: &DayState, locale: &LocalePreferences) -> Elem
 }
 
 /// Render the all-day events section at the top
-fn render_all_day_section(day_state: &DayState) -> Element<'static, Message> {
+fn render_all_day_section(day_state: &DayState, grid_lines: GridLineStyle) -> Element<'static, Message> {
     let mut header_row = row().spacing(0);
 
     // Time column placeholder
@@ -46,9 +68,69 @@ fn render_all_day_section(day_state: &DayState) -> Element<'static, Message> {
             .width(Length::Fill)
             .height(Length::Fixed(ALL_DAY_HEADER_HEIGHT))
             .padding(PADDING_SMALL)
-            .style(|_theme: &cosmic::Theme| bordered_cell_style())
+            .style(move |_theme: &cosmic::Theme| bordered_cell_style(grid_lines))
     );
 
     header_row.into()
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{DisplayEvent, EventSelection};
+    use crate::dialogs::ActiveDialog;
+    use crate::locale::DateFormat;
+    use crate::selection::{EventResizeState, SelectionState};
+    use crate::settings::{EventOverlapStyle, EventTextSize, WorkingHours};
+    use chrono::{NaiveTime, Weekday};
+
+    fn event(uid: &str, start: u32, end: u32) -> DisplayEvent {
+        DisplayEvent {
+            calendar_id: "work".to_string(),
+            uid: uid.to_string(),
+            summary: uid.to_string(),
+            start_time: NaiveTime::from_hms_opt(start, 0, 0),
+            end_time: NaiveTime::from_hms_opt(end, 0, 0),
+            ..DisplayEvent::test_default()
+        }
+    }
+
+    #[test]
+    fn test_day_view_renders_overlapping_events() {
+        let locale = LocalePreferences {
+            use_24_hour: true,
+            first_day_of_week: Weekday::Mon,
+            weekend_days: vec![Weekday::Sat, Weekday::Sun],
+            date_format: DateFormat::YMD,
+            locale_string: "en_US.UTF-8".to_string(),
+        };
+        let selection = SelectionState::new();
+        let resize = EventResizeState::new();
+        let active_dialog = ActiveDialog::None;
+        // Today, so the current time line is drawn too
+        let day_state = DayState::current(&locale);
+
+        // Overlapping events share the column; another day's events stay out
+        let mut events_by_date = HashMap::new();
+        events_by_date.insert(day_state.date, vec![event("standup", 9, 10), event("review", 9, 11)]);
+        events_by_date.insert(day_state.date + chrono::Duration::days(1), vec![event("tomorrow", 9, 10)]);
+        let (_, timed) = separate_events(&events_by_date, &[day_state.date]);
+        assert_eq!(timed.values().map(Vec::len).sum::<usize>(), 2);
+
+        let events = WeekViewEvents {
+            events_by_date: &events_by_date,
+            selected_events: EventSelection { event: Some("standup"), batch: &[] },
+            selection: &selection,
+            resize: &resize,
+            active_dialog: &active_dialog,
+            calendar_color: "#3B82F6",
+            event_text_size: EventTextSize::default(),
+            overlap_style: EventOverlapStyle::default(),
+            all_day_collapsed: false,
+            working_hours: Some(WorkingHours::default()),
+        };
+        let _ = render_day_view(&day_state, &locale, Some(events), GridLineStyle::default());
+        let _ = render_day_view(&day_state, &locale, None, GridLineStyle::default());
+    }
+}
@@ -1,15 +1,150 @@
 use chrono::NaiveDate;
-use cosmic::iced::Length;
-use cosmic::widget::{column, divider};
+use std::collections::HashMap;
+use cosmic::iced::widget::stack;
+use cosmic::iced::{alignment, Color, Length};
+use cosmic::widget::{button, column, container, divider, mouse_area, text};
 use cosmic::Element;
 
 use crate::cache::CalendarCache;
-use crate::components;
+use crate::components::{self, DisplayEvent};
 use crate::locale::LocalePreferences;
 use crate::message::Message;
-use crate::models::{WeekState, DayState, YearState};
+use crate::fl;
+use crate::models::{self, CategoryFilter, WeekState, DayState, YearState};
+use crate::settings::{GridLineStyle, WorkingHours};
+use crate::ui_constants::PADDING_MEDIUM;
 use crate::views::{self, CalendarView, MonthViewEvents, WeekViewEvents};
 
+/// Opacity of the scrim dimming the window behind an open dialog
+const DIALOG_BACKDROP_ALPHA: f32 = 0.5;
+
+/// Center a dialog over a dimmed, clickable backdrop.
+/// Clicking the backdrop sends `CloseDialog`, which applies the same close rules as
+/// clicking outside an inline editor (empty quick events are dismissed, others close).
+pub fn wrap_with_dialog_backdrop(dialog: Element<'_, Message>) -> Element<'_, Message> {
+    let backdrop = mouse_area(
+        container(text(""))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .style(|_theme: &cosmic::Theme| container::Style {
+                background: Some(Color::from_rgba(0.0, 0.0, 0.0, DIALOG_BACKDROP_ALPHA).into()),
+                ..Default::default()
+            }),
+    )
+    .on_press(Message::CloseDialog);
+
+    let centered_dialog = container(dialog)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_x(alignment::Horizontal::Center)
+        .align_y(alignment::Vertical::Center);
+
+    // Stack: backdrop on bottom, dialog on top
+    stack![backdrop, centered_dialog].into()
+}
+
+/// Date the "Next event →" button jumps to. The button is only offered when no
+/// events are shown within the visible `range`; `next_event` is only queried then.
+pub fn next_event_button_target(
+    events_by_date: &HashMap<NaiveDate, Vec<DisplayEvent>>,
+    range: (NaiveDate, NaiveDate),
+    next_event: impl FnOnce() -> Option<NaiveDate>,
+) -> Option<NaiveDate> {
+    let (start, end) = range;
+    let range_has_events = events_by_date
+        .iter()
+        .any(|(date, events)| *date >= start && *date <= end && !events.is_empty());
+    if range_has_events {
+        None
+    } else {
+        next_event()
+    }
+}
+
+/// Unique ids (`calendar_id:uid`) of every event shown within the visible `range`,
+/// in date order. Multi-day events listed on several days appear once.
+pub fn visible_event_ids(
+    events_by_date: &HashMap<NaiveDate, Vec<DisplayEvent>>,
+    range: (NaiveDate, NaiveDate),
+) -> Vec<String> {
+    let (start, end) = range;
+    let mut dates: Vec<&NaiveDate> = events_by_date
+        .keys()
+        .filter(|date| **date >= start && **date <= end)
+        .collect();
+    dates.sort();
+
+    let mut ids: Vec<String> = Vec::new();
+    for date in dates {
+        for event in &events_by_date[date] {
+            let id = event.unique_id();
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+    }
+    ids
+}
+
+/// Float the "Next event →" button over the bottom-right corner of the view
+fn with_next_event_button(view: Element<'_, Message>) -> Element<'_, Message> {
+    let next_button = container(button::suggested(fl!("nav-next-event")).on_press(Message::GoToNextEvent))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(PADDING_MEDIUM)
+        .align_x(alignment::Horizontal::Right)
+        .align_y(alignment::Vertical::Bottom);
+
+    stack![view, next_button].into()
+}
+
+/// Free time within working hours for the day view toolbar, e.g. "Free 10:00–12:00".
+/// Empty when the day's events aren't cached (the day isn't a visible week day).
+fn day_free_slots_text(
+    day_state: &DayState,
+    week_events: Option<&WeekViewEvents>,
+    working_hours: WorkingHours,
+    locale: &LocalePreferences,
+) -> String {
+    // The cached week events always include the day view's day
+    let Some(week_events) = week_events else {
+        return String::new();
+    };
+    let events: Vec<DisplayEvent> = week_events
+        .events_by_date
+        .get(&day_state.date)
+        .into_iter()
+        .flatten()
+        .map(|event| views::week::clip_to_day(event, day_state.date))
+        .collect();
+    let slots = models::free_slots(&models::busy_intervals(&events), models::working_window(working_hours));
+    let free = if slots.is_empty() {
+        fl!("day-no-free-slots")
+    } else {
+        fl!("day-free-slots", slots = models::format_free_slots(&slots, locale))
+    };
+
+    // Today only: the running event that ends first (re-evaluated on every TimeTick)
+    if !day_state.is_today() {
+        return free;
+    }
+    match ending_soon_text(&events, chrono::Local::now().time()) {
+        Some(ending) => format!("{} · {}", free, ending),
+        None => free,
+    }
+}
+
+/// "Standup ending in 12 min" for the running event that ends first, if any.
+/// Minutes are rounded up so an event is never shown as ending in 0 min.
+fn ending_soon_text(events: &[DisplayEvent], now: chrono::NaiveTime) -> Option<String> {
+    let (event, remaining) = events
+        .iter()
+        .filter_map(|event| Some((event, models::time_until_end(event, now)?)))
+        .min_by_key(|(_, remaining)| *remaining)?;
+    let minutes = (remaining.num_seconds() + 59) / 60;
+    Some(fl!("day-event-ending", summary = event.summary.clone(), minutes = minutes))
+}
+
 /// Render the main content area (toolbar + calendar view)
 pub fn render_main_content<'a>(
     cache: &'a CalendarCache,
@@ -20,33 +155,141 @@ pub fn render_main_content<'a>(
     current_view: CalendarView,
     selected_date: Option<NaiveDate>,
     show_week_numbers: bool,
+    show_weekends: bool,
+    show_mini_week: bool,
+    grid_lines: GridLineStyle,
+    working_hours: WorkingHours,
+    condensed: bool,
+    categories: &'a [String],
+    category_filter: &'a CategoryFilter,
     month_events: Option<MonthViewEvents<'a>>,
     week_events: Option<WeekViewEvents<'a>>,
+    agenda_events: &'a HashMap<NaiveDate, Vec<DisplayEvent>>,
+    next_event_date: Option<NaiveDate>,
+    search: Option<(&'a str, &'a [(NaiveDate, DisplayEvent)])>,
 ) -> Element<'a, Message> {
     // Render toolbar - use appropriate text for each view
     // primary_text is bold (month/period), secondary_text is normal weight (year)
     let (primary_text, secondary_text): (String, String) = match current_view {
         CalendarView::Year => (year_state.year_text.clone(), String::new()),
         CalendarView::Week => (week_state.week_range_text.clone(), String::new()),
-        CalendarView::Day => (day_state.month_year_text.clone(), String::new()),
+        CalendarView::Day => (day_state.month_year_text.clone(), day_free_slots_text(day_state, week_events.as_ref(), working_hours, locale)),
         CalendarView::Month => (cache.current_month_text(), cache.current_year_text()),
+        CalendarView::Agenda => (fl!("agenda-title"), fl!("agenda-days", days = views::AGENDA_DAYS)),
     };
-    let toolbar = components::render_toolbar(&primary_text, &secondary_text);
+    let toolbar = components::render_toolbar(&primary_text, &secondary_text, condensed);
 
     // Render current calendar view
     let calendar_view = match current_view {
         CalendarView::Year => views::render_year_view(year_state, locale),
-        CalendarView::Month => views::render_month_view(cache.current_state(), selected_date, locale, show_week_numbers, month_events),
-        CalendarView::Week => views::render_week_view(week_state, locale, week_events),
-        CalendarView::Day => views::render_day_view(day_state, locale),
+        CalendarView::Month => {
+            let month_view = views::render_month_view(cache.current_state(), selected_date, locale, show_week_numbers, show_weekends, grid_lines, month_events);
+            match week_events.filter(|_| show_mini_week) {
+                Some(week_events) => {
+                    // Current week strip built from the week's cached events
+                    let today = chrono::Local::now().date_naive();
+                    let days = views::build_mini_week_days(&week_state.days, week_events.events_by_date, today, selected_date);
+                    column()
+                        .push(views::render_mini_week_strip(&days))
+                        .push(divider::horizontal::light())
+                        .push(month_view)
+                        .into()
+                }
+                None => month_view,
+            }
+        }
+        CalendarView::Week => views::render_week_view(week_state, locale, week_events, grid_lines),
+        CalendarView::Day => views::render_day_view(day_state, locale, week_events, grid_lines),
+        CalendarView::Agenda => views::render_agenda_view(agenda_events, day_state.date, locale),
+    };
+
+    // Offer a jump forward when nothing is visible in the current range
+    let calendar_view = if next_event_date.is_some() {
+        with_next_event_button(calendar_view)
+    } else {
+        calendar_view
     };
 
+    // Category filter chips (hidden when no event has categories)
+    let category_bar = components::render_category_filter_bar(categories, category_filter);
+
+    // Search field and results while search is open
+    let search_panel = search.map(|(query, results)| components::render_search_panel(query, results, locale));
+
     column()
         .spacing(0)
         .push(toolbar)
+        .push_maybe(search_panel)
+        .push_maybe(category_bar)
         .push(divider::horizontal::default())
         .push(calendar_view)
         .width(Length::Fill)
         .height(Length::Fill)
         .into()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn display_event(uid: &str) -> DisplayEvent {
+        DisplayEvent {
+            calendar_id: "cal".to_string(),
+            uid: uid.to_string(),
+            summary: uid.to_string(),
+            all_day: true,
+            start_time: None,
+            end_time: None,
+            ..DisplayEvent::test_default()
+        }
+    }
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 3, day).unwrap()
+    }
+
+    #[test]
+    fn test_next_event_button_shown_for_empty_range_with_future_event() {
+        // Only an empty entry inside the range, and an event outside it
+        let mut events_by_date = HashMap::new();
+        events_by_date.insert(date(12), vec![]);
+        events_by_date.insert(date(20), vec![display_event("later")]);
+
+        let target = next_event_button_target(&events_by_date, (date(10), date(16)), || Some(date(20)));
+        assert_eq!(target, Some(date(20)));
+    }
+
+    #[test]
+    fn test_next_event_button_hidden_when_range_has_events_or_nothing_ahead() {
+        let mut events_by_date = HashMap::new();
+        events_by_date.insert(date(12), vec![display_event("visible")]);
+
+        // Visible events: the upcoming event isn't even looked up
+        let target = next_event_button_target(&events_by_date, (date(10), date(16)), || {
+            panic!("next event should not be queried")
+        });
+        assert_eq!(target, None);
+
+        // Empty range but no upcoming event
+        assert_eq!(next_event_button_target(&HashMap::new(), (date(10), date(16)), || None), None);
+    }
+
+    #[test]
+    fn test_visible_event_ids_scoped_to_range() {
+        let mut spanning = display_event("trip");
+        spanning.span_start = Some(date(11));
+        spanning.span_end = Some(date(12));
+
+        let mut events_by_date = HashMap::new();
+        events_by_date.insert(date(9), vec![display_event("before")]);
+        events_by_date.insert(date(12), vec![spanning.clone(), display_event("standup")]);
+        events_by_date.insert(date(11), vec![spanning]);
+        events_by_date.insert(date(17), vec![display_event("after")]);
+
+        assert_eq!(
+            visible_event_ids(&events_by_date, (date(10), date(16))),
+            vec!["cal:trip".to_string(), "cal:standup".to_string()]
+        );
+        assert!(visible_event_ids(&events_by_date, (date(13), date(16))).is_empty());
+    }
+}
@@ -1,43 +1,156 @@
+mod agenda;
 mod day;
 mod main_view;
 mod month;
 mod sidebar;
-mod week;
+pub(crate) mod week;
 mod year;
 
+pub use agenda::{agenda_days, render_agenda_view, AGENDA_DAYS};
 pub use day::render_day_view;
-pub use main_view::render_main_content;
-pub use month::{render_month_view, MonthViewEvents};
+pub use main_view::{next_event_button_target, render_main_content, visible_event_ids, wrap_with_dialog_backdrop};
+pub use month::{build_mini_week_days, render_month_view, render_mini_week_strip, MonthViewEvents};
 pub use sidebar::render_sidebar;
-pub use week::{render_week_view, week_time_grid_id, WeekViewEvents};
+pub use week::{render_week_print, render_week_view, week_time_grid_id, WeekViewEvents};
 pub use year::render_year_view;
 
+use chrono::NaiveDate;
+use crate::models::{CalendarDay, CalendarState, DayState, WeekState};
+use crate::settings::DefaultView;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CalendarView {
     Year,
     Month,
     Week,
     Day,
+    Agenda,
+}
+
+impl From<DefaultView> for CalendarView {
+    fn from(view: DefaultView) -> Self {
+        match view {
+            DefaultView::Year => CalendarView::Year,
+            DefaultView::Month => CalendarView::Month,
+            DefaultView::Week => CalendarView::Week,
+            DefaultView::Day => CalendarView::Day,
+        }
+    }
 }
 
 impl CalendarView {
-    /// Get the next view in the cycle: Year → Month → Week → Day → Year
+    /// Get the next view in the cycle: Year → Month → Week → Day → Agenda → Year
     pub fn next(self) -> Self {
         match self {
             CalendarView::Year => CalendarView::Month,
             CalendarView::Month => CalendarView::Week,
             CalendarView::Week => CalendarView::Day,
-            CalendarView::Day => CalendarView::Year,
+            CalendarView::Day => CalendarView::Agenda,
+            CalendarView::Agenda => CalendarView::Year,
         }
     }
 
-    /// Get the previous view in the cycle: Day → Week → Month → Year → Day
+    /// Get the previous view in the cycle: Agenda → Day → Week → Month → Year → Agenda
     pub fn previous(self) -> Self {
         match self {
-            CalendarView::Year => CalendarView::Day,
+            CalendarView::Year => CalendarView::Agenda,
             CalendarView::Month => CalendarView::Year,
             CalendarView::Week => CalendarView::Month,
             CalendarView::Day => CalendarView::Week,
+            CalendarView::Agenda => CalendarView::Day,
+        }
+    }
+
+    /// First and last date shown by this view (inclusive).
+    /// Month: the grid including adjacent-month days; Week: the week's visible days;
+    /// Day: the single day; Agenda: `AGENDA_DAYS` days from the selected day;
+    /// Year: January 1 to December 31.
+    pub fn visible_range(
+        self,
+        month: &CalendarState,
+        week: &WeekState,
+        day: &DayState,
+        year: i32,
+    ) -> (NaiveDate, NaiveDate) {
+        let single_day = (day.date, day.date);
+        match self {
+            CalendarView::Year => (
+                NaiveDate::from_ymd_opt(year, 1, 1).unwrap_or(day.date),
+                NaiveDate::from_ymd_opt(year, 12, 31).unwrap_or(day.date),
+            ),
+            CalendarView::Month => {
+                let as_date = |d: &CalendarDay| NaiveDate::from_ymd_opt(d.year, d.month, d.day);
+                let first = month.weeks_full.first().and_then(|w| w.first()).and_then(as_date);
+                let last = month.weeks_full.last().and_then(|w| w.last()).and_then(as_date);
+                first.zip(last).unwrap_or(single_day)
+            }
+            CalendarView::Week => week.days.first().copied().zip(week.days.last().copied()).unwrap_or(single_day),
+            CalendarView::Day => single_day,
+            CalendarView::Agenda => (day.date, day.date + chrono::Duration::days(AGENDA_DAYS - 1)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::locale::{DateFormat, LocalePreferences};
+    use chrono::Weekday;
+
+    fn locale() -> LocalePreferences {
+        LocalePreferences {
+            use_24_hour: true,
+            first_day_of_week: Weekday::Mon,
+            weekend_days: vec![Weekday::Sat, Weekday::Sun],
+            date_format: DateFormat::YMD,
+            locale_string: "en_US.UTF-8".to_string(),
         }
     }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    /// Visible range of each view with Wed 12 March 2025 selected
+    fn range(view: CalendarView, show_weekends: bool) -> (NaiveDate, NaiveDate) {
+        let selected = date(2025, 3, 12);
+        let locale = locale();
+        view.visible_range(
+            &CalendarState::new(2025, 3, Weekday::Mon),
+            &WeekState::new(selected, Weekday::Mon, show_weekends, &locale),
+            &DayState::new(selected, &locale),
+            2025,
+        )
+    }
+
+    #[test]
+    fn test_month_range_includes_adjacent_days() {
+        // March 2025 starts on a Saturday: the grid runs Mon 24 Feb through Sun 6 Apr
+        assert_eq!(range(CalendarView::Month, true), (date(2025, 2, 24), date(2025, 4, 6)));
+    }
+
+    #[test]
+    fn test_week_range_follows_visible_days() {
+        assert_eq!(range(CalendarView::Week, true), (date(2025, 3, 10), date(2025, 3, 16)));
+        // Hidden weekends end the week on Friday
+        assert_eq!(range(CalendarView::Week, false), (date(2025, 3, 10), date(2025, 3, 14)));
+    }
+
+    #[test]
+    fn test_day_and_year_ranges() {
+        assert_eq!(range(CalendarView::Day, true), (date(2025, 3, 12), date(2025, 3, 12)));
+        assert_eq!(range(CalendarView::Year, true), (date(2025, 1, 1), date(2025, 12, 31)));
+    }
+
+    #[test]
+    fn test_agenda_range_starts_at_selected_day() {
+        assert_eq!(range(CalendarView::Agenda, true), (date(2025, 3, 12), date(2025, 4, 10)));
+    }
+
+    #[test]
+    fn test_view_cycle_includes_agenda() {
+        assert_eq!(CalendarView::Day.next(), CalendarView::Agenda);
+        assert_eq!(CalendarView::Agenda.next(), CalendarView::Year);
+        assert_eq!(CalendarView::Year.previous(), CalendarView::Agenda);
+    }
 }
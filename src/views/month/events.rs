@@ -9,8 +9,10 @@ use cosmic::widget::{container, mouse_area};
 use cosmic::{widget, Element};
 
 use crate::components::color_picker::parse_color_safe;
-use crate::components::{span_border_radius_from_flags, ChipOpacity};
+use crate::caldav::EventStatus;
+use crate::components::{apply_status_style, span_border_radius_from_flags, ChipOpacity};
 use crate::message::Message;
+use crate::settings::EventTextSize;
 use crate::ui_constants::{BORDER_RADIUS_SMALL, BORDER_RADIUS_VALUE, BORDER_WIDTH_HIGHLIGHT};
 
 /// Render a compact date event chip (thin colored line without text)
@@ -46,11 +48,13 @@ pub fn render_compact_date_event_chip(
 /// Works for both single-day and multi-day date events.
 /// Includes click/drag handling for event selection and movement.
 /// Events on past dates are rendered with reduced opacity.
+/// Cancelled and tentative events get a status decoration.
 pub fn render_date_event_chip(
     calendar_id: String,
     uid: String,
     summary: String,
     color_hex: String,
+    status: EventStatus,
     show_text: bool,
     is_event_start: bool,
     is_event_end: bool,
@@ -59,6 +63,7 @@ pub fn render_date_event_chip(
     is_drag_active: bool,
     is_being_dragged: bool,
     event_date: NaiveDate,
+    text_size: EventTextSize,
 ) -> Element<'static, Message> {
     let color = parse_color_safe(&color_hex);
 
@@ -70,12 +75,12 @@ pub fn render_date_event_chip(
 
     let content: Element<'static, Message> = if show_text {
         widget::text(summary)
-            .size(11)
+            .size(text_size.chip_size())
             .wrapping(Wrapping::None)
             .into()
     } else {
         widget::text("")
-            .size(11)
+            .size(text_size.chip_size())
             .into()
     };
 
@@ -104,6 +109,7 @@ pub fn render_date_event_chip(
                 ..Default::default()
             }
         });
+    let chip = apply_status_style(chip.into(), status, color);
 
     // Wrap with mouse area for drag and click handling
     // Use DragEventStart on press (like timed events) - if released without moving,
@@ -2,6 +2,7 @@
 //!
 //! Contains the weekday header row rendering logic.
 
+use chrono::Weekday;
 use cosmic::iced::{alignment, Length};
 use cosmic::widget::{container, row};
 use cosmic::{widget, Element};
@@ -12,7 +13,12 @@ use crate::message::Message;
 use crate::ui_constants::{FONT_SIZE_MEDIUM, FONT_SIZE_SMALL, PADDING_SMALL, SPACING_TINY, WEEK_NUMBER_WIDTH};
 
 /// Render the weekday header row with responsive names
-pub fn render_weekday_header(show_week_numbers: bool, use_short_names: bool) -> Element<'static, Message> {
+///
+/// # Arguments
+/// * `show_week_numbers` - Whether the week number column is visible
+/// * `use_short_names` - Use abbreviated weekday names for narrow cells
+/// * `weekdays` - The weekday of each visible column, in order
+pub fn render_weekday_header(show_week_numbers: bool, use_short_names: bool, weekdays: &[Weekday]) -> Element<'static, Message> {
     let mut header_row = row().spacing(SPACING_TINY);
 
     // Week number header (only if enabled)
@@ -32,9 +38,10 @@ pub fn render_weekday_header(show_week_numbers: bool, use_short_names: bool) ->
         localized_names::get_weekday_names_full()
     };
 
-    for weekday in weekday_names {
+    for weekday in weekdays {
+        let name = weekday_names[weekday.num_days_from_monday() as usize].clone();
         header_row = header_row.push(
-            container(widget::text(weekday).size(FONT_SIZE_MEDIUM))
+            container(widget::text(name).size(FONT_SIZE_MEDIUM))
                 .width(Length::Fill)
                 .padding(PADDING_SMALL)
                 .center_x(Length::Fill),
@@ -0,0 +1,174 @@
+//! Mini week strip rendering
+//!
+//! A thin row above the month grid showing the current week's days with one
+//! colored dot per event, for quick context while browsing months.
+
+use std::collections::HashMap;
+
+use chrono::{Datelike, NaiveDate};
+use cosmic::iced::Length;
+use cosmic::widget::{button, column, container, row};
+use cosmic::{widget, Element};
+
+use crate::components::color_picker::parse_hex_color;
+use crate::components::DisplayEvent;
+use crate::localized_names;
+use crate::message::Message;
+use crate::ui_constants::{COLOR_DEFAULT_GRAY, FONT_SIZE_SMALL, PADDING_MONTH_GRID, PADDING_TINY, SPACING_SMALL, SPACING_XXS};
+
+/// Maximum number of event dots shown per day before "+N"
+const MAX_DOTS_PER_DAY: usize = 4;
+
+/// Size of each event dot
+const DOT_SIZE: f32 = 6.0;
+
+/// Per-day data for the mini week strip
+#[derive(Debug, Clone, PartialEq)]
+pub struct MiniWeekDay {
+    pub date: NaiveDate,
+    pub is_today: bool,
+    pub is_selected: bool,
+    /// Calendar color of each event shown as a dot (capped at MAX_DOTS_PER_DAY)
+    pub dot_colors: Vec<String>,
+    /// Events beyond the dot cap
+    pub overflow: usize,
+}
+
+/// Assemble the strip's per-day data from the week's events.
+/// Multi-day events get a dot on every day they cover, since the
+/// events are already grouped per day.
+///
+/// # Arguments
+/// * `days` - The week's visible days, in order
+/// * `events_by_date` - Events for the week, keyed by date
+/// * `today` - Today's date (for highlighting)
+/// * `selected_date` - Currently selected date, if any
+pub fn build_mini_week_days(
+    days: &[NaiveDate],
+    events_by_date: &HashMap<NaiveDate, Vec<DisplayEvent>>,
+    today: NaiveDate,
+    selected_date: Option<NaiveDate>,
+) -> Vec<MiniWeekDay> {
+    days.iter()
+        .map(|&date| {
+            let events = events_by_date.get(&date).map(Vec::as_slice).unwrap_or_default();
+            MiniWeekDay {
+                date,
+                is_today: date == today,
+                is_selected: selected_date == Some(date),
+                dot_colors: events.iter().take(MAX_DOTS_PER_DAY).map(|e| e.color.clone()).collect(),
+                overflow: events.len().saturating_sub(MAX_DOTS_PER_DAY),
+            }
+        })
+        .collect()
+}
+
+/// Render a single colored event dot
+fn render_dot(color_hex: &str) -> Element<'static, Message> {
+    let color = parse_hex_color(color_hex).unwrap_or(COLOR_DEFAULT_GRAY);
+    container(widget::text(""))
+        .width(Length::Fixed(DOT_SIZE))
+        .height(Length::Fixed(DOT_SIZE))
+        .style(move |_theme: &cosmic::Theme| container::Style {
+            background: Some(cosmic::iced::Background::Color(color)),
+            border: cosmic::iced::Border {
+                color: cosmic::iced::Color::TRANSPARENT,
+                width: 0.0,
+                radius: (DOT_SIZE / 2.0).into(), // Circular
+            },
+            ..Default::default()
+        })
+        .into()
+}
+
+/// Render the mini week strip
+///
+/// # Arguments
+/// * `days` - Per-day strip data from `build_mini_week_days`
+pub fn render_mini_week_strip(days: &[MiniWeekDay]) -> Element<'static, Message> {
+    let weekday_names = localized_names::get_weekday_names_short();
+    let mut strip = row().spacing(SPACING_SMALL);
+
+    for day in days {
+        let name = weekday_names[day.date.weekday().num_days_from_monday() as usize].clone();
+
+        let mut dots = row().spacing(SPACING_XXS).height(Length::Fixed(DOT_SIZE));
+        for color in &day.dot_colors {
+            dots = dots.push(render_dot(color));
+        }
+
+        let mut content = row()
+            .spacing(SPACING_SMALL)
+            .align_y(cosmic::iced::Alignment::Center)
+            .push(widget::text(format!("{} {}", name, day.date.day())).size(FONT_SIZE_SMALL))
+            .push(dots);
+        if day.overflow > 0 {
+            content = content.push(widget::text(format!("+{}", day.overflow)).size(FONT_SIZE_SMALL));
+        }
+
+        let button_class = if day.is_today {
+            cosmic::theme::Button::Suggested
+        } else if day.is_selected {
+            cosmic::theme::Button::Standard
+        } else {
+            cosmic::theme::Button::Text
+        };
+
+        strip = strip.push(
+            button::custom(container(content).center_x(Length::Fill))
+                .on_press(Message::SelectDay(day.date.year(), day.date.month(), day.date.day()))
+                .padding(PADDING_TINY)
+                .width(Length::Fill)
+                .class(button_class),
+        );
+    }
+
+    column()
+        .push(strip)
+        .padding([PADDING_TINY, PADDING_MONTH_GRID])
+        .width(Length::Fill)
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_event(uid: &str, color: &str) -> DisplayEvent {
+        DisplayEvent {
+            calendar_id: "cal".to_string(),
+            uid: uid.to_string(),
+            summary: "Event".to_string(),
+            color: color.to_string(),
+            start_time: None,
+            end_time: None,
+            ..DisplayEvent::test_default()
+        }
+    }
+
+    #[test]
+    fn test_build_mini_week_days() {
+        let monday = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+        let days: Vec<NaiveDate> = (0..7).map(|i| monday + chrono::Duration::days(i)).collect();
+        let tuesday = days[1];
+        let friday = days[4];
+
+        let mut events_by_date = HashMap::new();
+        events_by_date.insert(tuesday, vec![make_event("a", "#ff0000"), make_event("b", "#00ff00")]);
+        events_by_date.insert(friday, (0..6).map(|i| make_event(&i.to_string(), "#0000ff")).collect());
+
+        let strip = build_mini_week_days(&days, &events_by_date, tuesday, Some(friday));
+
+        assert_eq!(strip.len(), 7);
+        assert!(strip[0].dot_colors.is_empty());
+        assert_eq!(strip[0].overflow, 0);
+
+        assert!(strip[1].is_today);
+        assert_eq!(strip[1].dot_colors, vec!["#ff0000", "#00ff00"]);
+
+        // Dots are capped, the rest counted as overflow
+        assert!(strip[4].is_selected);
+        assert_eq!(strip[4].dot_colors.len(), MAX_DOTS_PER_DAY);
+        assert_eq!(strip[4].overflow, 2);
+    }
+}
@@ -5,25 +5,28 @@
 //! - `overlay`: Slot computation and date event overlay rendering
 //! - `events`: Date event chip rendering
 //! - `selection`: Quick event selection overlay
+//! - `mini_week`: Optional current-week strip shown above the grid
 
 mod header;
 mod overlay;
 mod events;
 mod selection;
+mod mini_week;
 
-use chrono::{Datelike, NaiveDate};
+use chrono::{Datelike, NaiveDate, Weekday};
 use cosmic::iced::widget::stack;
 use cosmic::iced::{alignment, Length, Size};
-use cosmic::widget::{column, container, row, responsive};
+use cosmic::widget::{column, container, mouse_area, row, responsive};
 use cosmic::{widget, Element};
 
 use crate::components::spacer::fill_spacer;
-use crate::components::{render_day_cell_with_events, DayCellConfig, DisplayEvent, should_use_compact};
+use crate::components::{parse_hex_color, render_day_cell_with_events, DayCellConfig, DisplayEvent, EventSelection, should_use_compact};
 use crate::dialogs::ActiveDialog;
 use crate::locale::LocalePreferences;
 use crate::message::Message;
 use crate::models::{CalendarDay, CalendarState};
 use crate::selection::SelectionState;
+use crate::settings::{EventTextSize, GridLineStyle};
 use crate::ui_constants::{
     FONT_SIZE_SMALL, PADDING_MONTH_GRID, PADDING_SMALL,
     SPACING_TINY, WEEK_NUMBER_WIDTH,
@@ -33,6 +36,8 @@ use header::render_weekday_header;
 use overlay::{compute_week_event_slots, render_date_events_overlay, WEEKDAY_HEADER_HEIGHT};
 use selection::render_spanning_overlay;
 
+pub use mini_week::{build_mini_week_days, render_mini_week_strip};
+
 /// Minimum width per day cell to use full weekday names
 /// Below this threshold, short names are used
 const MIN_CELL_WIDTH_FOR_FULL_NAMES: f32 = 100.0;
@@ -47,14 +52,49 @@ pub struct MonthViewEvents<'a> {
     pub selection: &'a SelectionState,
     /// Active dialog state (for showing selection highlight during quick event input)
     pub active_dialog: &'a ActiveDialog,
-    /// Currently selected event UID (for visual feedback)
-    pub selected_event_uid: Option<&'a str>,
+    /// Currently selected events (for visual feedback)
+    pub selected_events: EventSelection<'a>,
     /// Whether an event drag operation is currently active
     pub event_drag_active: bool,
     /// The UID of the event currently being dragged (for dimming original)
     pub dragging_event_uid: Option<&'a str>,
     /// The current drop target date during drag (for highlighting target cell)
     pub drag_target_date: Option<NaiveDate>,
+    /// Color of the event being dragged (tints the drop target cell)
+    pub dragging_event_color: Option<&'a str>,
+    /// User cap on events shown per day cell (None = as many as fit)
+    pub max_events_per_day: Option<usize>,
+    /// Font size of event labels
+    pub event_text_size: EventTextSize,
+}
+
+/// Drop the locale's weekend days from each week when weekends are hidden,
+/// collapsing those columns out of the grid.
+fn visible_weeks(weeks: &[Vec<CalendarDay>], show_weekends: bool, locale: &LocalePreferences) -> Vec<Vec<CalendarDay>> {
+    if show_weekends {
+        return weeks.to_vec();
+    }
+    weeks.iter()
+        .map(|week| {
+            week.iter()
+                .filter(|d| {
+                    NaiveDate::from_ymd_opt(d.year, d.month, d.day)
+                        .is_none_or(|date| !locale.is_weekend(date.weekday()))
+                })
+                .copied()
+                .collect()
+        })
+        .collect()
+}
+
+/// Date to select when a week number is clicked.
+/// Prefers the first day of the row in the displayed month, so switching back
+/// to month view stays on the same month.
+fn week_navigation_target(week: &[CalendarDay]) -> Option<NaiveDate> {
+    week.iter()
+        .find(|d| d.is_current_month)
+        .or_else(|| week.first())
+        .and_then(|d| NaiveDate::from_ymd_opt(d.year, d.month, d.day))
 }
 
 pub fn render_month_view<'a>(
@@ -62,18 +102,33 @@ pub fn render_month_view<'a>(
     selected_date: Option<NaiveDate>,
     locale: &LocalePreferences,
     show_week_numbers: bool,
+    show_weekends: bool,
+    grid_lines: GridLineStyle,
     events: Option<MonthViewEvents<'a>>,
 ) -> Element<'a, Message> {
     let mut grid = column().spacing(SPACING_TINY).padding(PADDING_MONTH_GRID);
 
+    // Weeks with hidden weekend days removed; every layer uses these columns
+    let weeks = visible_weeks(&calendar_state.weeks_full, show_weekends, locale);
+    let header_weekdays: Vec<Weekday> = weeks
+        .first()
+        .map(|week| {
+            week.iter()
+                .filter_map(|d| NaiveDate::from_ymd_opt(d.year, d.month, d.day))
+                .map(|date| date.weekday())
+                .collect()
+        })
+        .unwrap_or_default();
+    let num_cols = header_weekdays.len().max(1) as f32;
+
     // Responsive weekday header - uses short names when cells are narrow
     let week_number_offset = if show_week_numbers { WEEK_NUMBER_WIDTH } else { 0.0 };
     let header = responsive(move |size: Size| {
-        // Calculate approximate cell width (7 days + spacing)
-        let available_for_days = size.width - week_number_offset - (SPACING_TINY as f32 * 6.0);
-        let cell_width = available_for_days / 7.0;
+        // Calculate approximate cell width (visible days + spacing)
+        let available_for_days = size.width - week_number_offset - (SPACING_TINY as f32 * (num_cols - 1.0));
+        let cell_width = available_for_days / num_cols;
         let use_short_names = cell_width < MIN_CELL_WIDTH_FOR_FULL_NAMES;
-        render_weekday_header(show_week_numbers, use_short_names)
+        render_weekday_header(show_week_numbers, use_short_names, &header_weekdays)
     });
 
     // Fixed height container for the header to prevent it from expanding
@@ -83,7 +138,7 @@ pub fn render_month_view<'a>(
     let week_numbers = calendar_state.week_numbers();
 
     // Use pre-calculated weeks from CalendarState cache (with adjacent month days)
-    for (week_index, week) in calendar_state.weeks_full.iter().enumerate() {
+    for (week_index, week) in weeks.iter().enumerate() {
         // Compute slot assignments for date events in this week
         let week_slot_info = events
             .as_ref()
@@ -99,19 +154,25 @@ pub fn render_month_view<'a>(
 
         let mut week_row = row().spacing(SPACING_TINY).height(Length::Fill);
 
-        // Week number cell (only if enabled)
+        // Week number cell (only if enabled) - clicking it opens that week
         if show_week_numbers {
             let week_number = week_numbers.get(week_index).copied().unwrap_or(0);
-            week_row = week_row.push(
-                container(
-                    widget::text(format!("{}", week_number))
-                        .size(FONT_SIZE_SMALL)
-                )
-                .width(Length::Fixed(WEEK_NUMBER_WIDTH))
-                .height(Length::Fill)
-                .padding(PADDING_SMALL)
-                .align_y(alignment::Vertical::Center)
-            );
+            let week_number_cell = container(
+                widget::text(format!("{}", week_number))
+                    .size(FONT_SIZE_SMALL)
+            )
+            .width(Length::Fixed(WEEK_NUMBER_WIDTH))
+            .height(Length::Fill)
+            .padding(PADDING_SMALL)
+            .align_y(alignment::Vertical::Center);
+
+            if let Some(target) = week_navigation_target(week) {
+                week_row = week_row.push(
+                    mouse_area(week_number_cell).on_press(Message::SelectWeek(target))
+                );
+            } else {
+                week_row = week_row.push(week_number_cell);
+            }
         }
 
         // Day cells
@@ -128,8 +189,7 @@ pub fn render_month_view<'a>(
             // Don't show cell selection if an event is selected - event selection takes priority
             let cell_date = NaiveDate::from_ymd_opt(year, month, day);
             let has_event_selected = events.as_ref()
-                .and_then(|e| e.selected_event_uid)
-                .is_some();
+                .is_some_and(|e| !e.selected_events.is_empty());
             let is_selected = !has_event_selected && selected_date.is_some() && cell_date == selected_date;
 
             // Get weekday for weekend detection
@@ -168,10 +228,13 @@ pub fn render_month_view<'a>(
                 (false, false)
             };
 
-            // Get selected event UID from events if available
-            let selected_event_uid = events.as_ref()
-                .and_then(|e| e.selected_event_uid)
-                .map(|s| s.to_string());
+            // Get the selected events shown in this cell
+            let selected_event_ids: Vec<String> = events.as_ref()
+                .map(|e| day_events.iter()
+                    .map(DisplayEvent::unique_id)
+                    .filter(|id| e.selected_events.contains(id))
+                    .collect())
+                .unwrap_or_default();
 
             // Check if event drag is active
             let event_drag_active = events.as_ref()
@@ -188,6 +251,9 @@ pub fn render_month_view<'a>(
                 .and_then(|e| e.drag_target_date)
                 .map(|target| cell_date == Some(target))
                 .unwrap_or(false);
+            let drag_color = events.as_ref()
+                .and_then(|e| e.dragging_event_color)
+                .and_then(|hex| parse_hex_color(hex).ok());
 
             // Get occupied slots for this specific day (for Tetris-style rendering)
             let day_occupied_slots = week_slot_info.as_ref()
@@ -209,10 +275,15 @@ pub fn render_month_view<'a>(
                 quick_event: quick_event_data,
                 is_in_selection,
                 selection_active,
-                selected_event_uid,
+                selected_event_ids,
                 event_drag_active,
                 dragging_event_uid,
                 is_drag_target,
+                drag_color,
+                max_events_cap: events.as_ref().and_then(|e| e.max_events_per_day),
+                grid_lines,
+                event_text_size: events.as_ref().map_or(EventTextSize::default(), |e| e.event_text_size),
+                locale: locale.clone(),
             });
 
             week_row = week_row.push(
@@ -243,17 +314,19 @@ pub fn render_month_view<'a>(
     // Wrapped in responsive to determine compact mode based on cell size
     if let Some(ref e) = events {
         // Clone data needed for the responsive closure
-        let weeks = calendar_state.weeks_full.clone();
+        let weeks = weeks.clone();
         let events_by_date = e.events_by_date.clone();
         let week_number_offset = if show_week_numbers { WEEK_NUMBER_WIDTH } else { 0.0 };
-        let selected_uid = e.selected_event_uid.map(|s| s.to_string());
+        let selected_ids = e.selected_events.to_ids();
         let event_drag_active = e.event_drag_active;
         let dragging_uid = e.dragging_event_uid.map(|s| s.to_string());
+        let max_events_per_day = e.max_events_per_day;
+        let event_text_size = e.event_text_size;
 
         let responsive_overlay = responsive(move |size: Size| {
-            // Calculate approximate cell width (7 days + spacing)
-            let available_for_days = size.width - week_number_offset - (SPACING_TINY as f32 * 6.0);
-            let cell_width = available_for_days / 7.0;
+            // Calculate approximate cell width (visible days + spacing)
+            let available_for_days = size.width - week_number_offset - (SPACING_TINY as f32 * (num_cols - 1.0));
+            let cell_width = available_for_days / num_cols;
 
             // Calculate approximate cell height (total height minus header, divided by weeks)
             let num_weeks = weeks.len().max(1) as f32;
@@ -268,9 +341,11 @@ pub fn render_month_view<'a>(
                 &events_by_date,
                 show_week_numbers,
                 compact,
-                selected_uid.as_deref(),
+                EventSelection { event: None, batch: &selected_ids },
                 event_drag_active,
                 dragging_uid.as_deref(),
+                max_events_per_day,
+                event_text_size,
             ) {
                 overlay
             } else {
@@ -292,7 +367,7 @@ pub fn render_month_view<'a>(
                     .unwrap_or_else(|| "#3B82F6".to_string());
 
                 render_spanning_overlay(
-                    &calendar_state.weeks_full,
+                    &weeks,
                     start,
                     end,
                     text.to_string(),
@@ -312,3 +387,49 @@ pub fn render_month_view<'a>(
         stack(layers).into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(year: i32, month: u32, day: u32, is_current_month: bool) -> CalendarDay {
+        CalendarDay { year, month, day, is_current_month }
+    }
+
+    #[test]
+    fn test_week_navigation_target() {
+        // First row of March 2025: Feb 24-28 are adjacent-month days
+        let week: Vec<CalendarDay> = (24..=28)
+            .map(|d| day(2025, 2, d, false))
+            .chain((1..=2).map(|d| day(2025, 3, d, true)))
+            .collect();
+        assert_eq!(week_navigation_target(&week), NaiveDate::from_ymd_opt(2025, 3, 1));
+
+        // A row fully inside the month targets its first day
+        let week: Vec<CalendarDay> = (10..=16).map(|d| day(2025, 3, d, true)).collect();
+        assert_eq!(week_navigation_target(&week), NaiveDate::from_ymd_opt(2025, 3, 10));
+
+        // A row without current-month days falls back to its first day
+        let week: Vec<CalendarDay> = (1..=7).map(|d| day(2025, 4, d, false)).collect();
+        assert_eq!(week_navigation_target(&week), NaiveDate::from_ymd_opt(2025, 4, 1));
+    }
+
+    #[test]
+    fn test_visible_weeks_collapses_weekends() {
+        let locale = LocalePreferences {
+            use_24_hour: true,
+            first_day_of_week: Weekday::Mon,
+            weekend_days: vec![Weekday::Sat, Weekday::Sun],
+            date_format: crate::locale::DateFormat::YMD,
+            locale_string: "en_US.UTF-8".to_string(),
+        };
+        // Mon 10 - Sun 16 March 2025
+        let weeks = vec![(10..=16).map(|d| day(2025, 3, d, true)).collect::<Vec<_>>()];
+
+        assert_eq!(visible_weeks(&weeks, true, &locale), weeks);
+
+        let weekdays = visible_weeks(&weeks, false, &locale);
+        let days: Vec<u32> = weekdays[0].iter().map(|d| d.day).collect();
+        assert_eq!(days, vec![10, 11, 12, 13, 14]);
+    }
+}
@@ -9,10 +9,12 @@ use cosmic::iced::Length;
 use cosmic::widget::{column, container, row};
 use cosmic::Element;
 
+use crate::caldav::EventStatus;
 use crate::components::spacer::{fill_spacer, horizontal_spacer, spacer, vertical_spacer};
-use crate::components::DisplayEvent;
+use crate::components::{DisplayEvent, EventSelection};
 use crate::message::Message;
 use crate::models::CalendarDay;
+use crate::settings::EventTextSize;
 use crate::ui_constants::{
     COMPACT_EVENT_HEIGHT, DATE_EVENT_HEIGHT, DATE_EVENT_SPACING,
     DAY_CELL_HEADER_OFFSET, DAY_CELL_TOP_PADDING, PADDING_MONTH_GRID,
@@ -37,6 +39,8 @@ pub struct DateEventSegment {
     pub summary: String,
     /// Event color (hex string)
     pub color: String,
+    /// Event status (for cancelled/tentative decoration)
+    pub status: EventStatus,
     /// Week index (0-based)
     pub week_idx: usize,
     /// Slot index within the week (for vertical stacking)
@@ -59,7 +63,7 @@ pub struct DateEventSegment {
 pub struct WeekSlotInfo {
     /// Map of event unique ID (calendar_id:uid) -> slot index
     pub slots: HashMap<String, usize>,
-    /// Occupied slots for each day (column) in the week: [day_0, day_1, ..., day_6]
+    /// Occupied slots for each visible day (column) in the week: [day_0, day_1, ...]
     /// Each set contains the slot indices that are occupied by date events on that day
     pub day_occupied_slots: Vec<std::collections::HashSet<usize>>,
 }
@@ -73,7 +77,7 @@ pub fn compute_week_date_event_slots(
     events_by_date: &HashMap<NaiveDate, Vec<DisplayEvent>>,
 ) -> WeekSlotInfo {
     let mut slots: HashMap<String, usize> = HashMap::new();
-    let mut slot_occupancy: Vec<std::collections::HashSet<usize>> = vec![std::collections::HashSet::new(); 7];
+    let mut slot_occupancy: Vec<std::collections::HashSet<usize>> = vec![std::collections::HashSet::new(); week.len()];
 
     // Get dates for this week
     let week_dates: Vec<NaiveDate> = week
@@ -87,6 +91,7 @@ pub fn compute_week_date_event_slots(
 
     let week_start = week_dates[0];
     let week_end = week_dates[week_dates.len() - 1];
+    let last_col = week_dates.len() - 1;
 
     // Collect all date events that appear in this week
     // Store: (start_col, end_col, unique_id) - column range within this week
@@ -113,7 +118,7 @@ pub fn compute_week_date_event_slots(
                                 .unwrap_or(0);
                             let ec = week_dates.iter()
                                 .rposition(|&d| d <= e)
-                                .unwrap_or(6);
+                                .unwrap_or(last_col);
                             (sc, ec)
                         },
                         _ => continue,
@@ -141,7 +146,7 @@ pub fn compute_week_date_event_slots(
         let mut slot = 0;
         loop {
             let mut slot_available = true;
-            for col in start_col..=end_col.min(6) {
+            for col in start_col..=end_col.min(last_col) {
                 if slot_occupancy[col].contains(&slot) {
                     slot_available = false;
                     break;
@@ -154,7 +159,7 @@ pub fn compute_week_date_event_slots(
         }
 
         // Mark this slot as occupied for all columns the event spans
-        for col in start_col..=end_col.min(6) {
+        for col in start_col..=end_col.min(last_col) {
             slot_occupancy[col].insert(slot);
         }
 
@@ -234,7 +239,7 @@ pub fn collect_date_event_segments(
                             .unwrap_or(0);
                         let ec = week_dates.iter()
                             .rposition(|&d| d <= span_end)
-                            .unwrap_or(6);
+                            .unwrap_or(week_dates.len() - 1);
                         (sc, ec, span_start)
                     } else {
                         // Single-day event: only spans its own column
@@ -257,6 +262,7 @@ pub fn collect_date_event_segments(
                         uid: event.uid.clone(),
                         summary: event.summary.clone(),
                         color: event.color.clone(),
+                        status: event.status,
                         week_idx,
                         slot,
                         start_col,
@@ -283,17 +289,21 @@ pub fn collect_date_event_segments(
 /// * `events_by_date` - Events grouped by date
 /// * `show_week_numbers` - Whether week numbers column is visible
 /// * `compact` - If true, render thin colored lines instead of full event chips
-/// * `selected_event_uid` - Currently selected event UID for visual feedback
+/// * `selected_events` - Currently selected events for visual feedback
 /// * `event_drag_active` - Whether an event drag operation is currently active
 /// * `dragging_event_uid` - UID of the event currently being dragged
+/// * `max_events_per_day` - User cap on visible slots per day (None = all slots)
+/// * `event_text_size` - Font size of event labels
 pub fn render_date_events_overlay<'a>(
     weeks: &[Vec<CalendarDay>],
     events_by_date: &HashMap<NaiveDate, Vec<DisplayEvent>>,
     show_week_numbers: bool,
     compact: bool,
-    selected_event_uid: Option<&str>,
+    selected_events: EventSelection<'_>,
     event_drag_active: bool,
     dragging_event_uid: Option<&str>,
+    max_events_per_day: Option<usize>,
+    event_text_size: EventTextSize,
 ) -> Option<Element<'a, Message>> {
     let segments = collect_date_event_segments(weeks, events_by_date);
 
@@ -302,6 +312,9 @@ pub fn render_date_events_overlay<'a>(
     }
 
     let num_weeks = weeks.len();
+    // Weekend columns may be hidden, so the column count comes from the weeks
+    let num_cols = weeks.first().map_or(7, Vec::len);
+    let last_col = num_cols.saturating_sub(1);
 
     // Group segments by week
     let mut segments_by_week: HashMap<usize, Vec<DateEventSegment>> = HashMap::new();
@@ -328,7 +341,12 @@ pub fn render_date_events_overlay<'a>(
 
         if let Some(segs) = week_segments {
             // Find max slot for this week
+            // Slots beyond the user's cap are hidden and counted in the cell's "+N more"
             let max_slot = segs.iter().map(|s| s.slot).max().unwrap_or(0);
+            let max_slot = match max_events_per_day {
+                Some(cap) => max_slot.min(cap.max(1) - 1),
+                None => max_slot,
+            };
 
             // Build week content: header offset + slot rows
             let mut week_content = column().spacing(DATE_EVENT_SPACING);
@@ -364,13 +382,13 @@ pub fn render_date_events_overlay<'a>(
                     // Render the spanning chip (full or compact based on mode)
                     let span_cols = seg.end_col - seg.start_col + 1;
                     let unique_id = format!("{}:{}", seg.calendar_id, seg.uid);
-                    let is_selected = selected_event_uid == Some(unique_id.as_str());
+                    let is_selected = selected_events.contains(&unique_id);
                     let is_being_dragged = dragging_event_uid == Some(unique_id.as_str());
                     let chip = if compact {
                         render_compact_date_event_chip(
                             seg.color.clone(),
                             seg.start_col == 0,
-                            seg.end_col == 6,
+                            seg.end_col == last_col,
                         )
                     } else {
                         render_date_event_chip(
@@ -378,14 +396,16 @@ pub fn render_date_events_overlay<'a>(
                             seg.uid.clone(),
                             seg.summary.clone(),
                             seg.color.clone(),
+                            seg.status,
                             seg.is_first_segment,
                             seg.start_col == 0,
-                            seg.end_col == 6,
+                            seg.end_col == last_col,
                             is_selected,
                             seg.event_start_date,
                             event_drag_active,
                             is_being_dragged,
                             seg.segment_end_date,
+                            event_text_size,
                         )
                     };
 
@@ -398,8 +418,8 @@ pub fn render_date_events_overlay<'a>(
                 }
 
                 // Add spacers for empty columns after the last segment
-                if current_col < 7 {
-                    for _ in current_col..7 {
+                if current_col < num_cols {
+                    for _ in current_col..num_cols {
                         slot_row = slot_row.push(spacer(Length::Fill, Length::Shrink));
                     }
                 }
@@ -434,3 +454,58 @@ pub fn render_date_events_overlay<'a>(
             .into()
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Datelike, NaiveTime};
+
+    fn display_event(uid: &str, all_day: bool, span: Option<(NaiveDate, NaiveDate)>) -> DisplayEvent {
+        DisplayEvent {
+            calendar_id: "cal".to_string(),
+            uid: uid.to_string(),
+            summary: uid.to_string(),
+            all_day,
+            start_time: if all_day { None } else { NaiveTime::from_hms_opt(9, 0, 0) },
+            end_time: if all_day { None } else { NaiveTime::from_hms_opt(10, 0, 0) },
+            span_start: span.map(|(s, _)| s),
+            span_end: span.map(|(_, e)| e),
+            ..DisplayEvent::test_default()
+        }
+    }
+
+    #[test]
+    fn test_multi_day_event_starting_midweek_leaves_monday_free() {
+        // Week of Mon 2025-03-10 .. Sun 2025-03-16
+        let monday = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+        let week: Vec<CalendarDay> = (0..7)
+            .map(|i| {
+                let d = monday + chrono::Duration::days(i);
+                CalendarDay { year: d.year(), month: d.month(), day: d.day(), is_current_month: true }
+            })
+            .collect();
+
+        // Multi-day event Wednesday..Friday, timed event on Monday
+        let wednesday = monday + chrono::Duration::days(2);
+        let friday = monday + chrono::Duration::days(4);
+        let mut events_by_date: HashMap<NaiveDate, Vec<DisplayEvent>> = HashMap::new();
+        for i in 2..=4 {
+            events_by_date
+                .entry(monday + chrono::Duration::days(i))
+                .or_default()
+                .push(display_event("trip", true, Some((wednesday, friday))));
+        }
+        events_by_date.entry(monday).or_default().push(display_event("standup", false, None));
+
+        let info = compute_week_date_event_slots(&week, &events_by_date);
+
+        // The bar only occupies its own columns, so Monday's timed event gets slot 0
+        assert_eq!(info.slots.get("cal:trip"), Some(&0));
+        assert!(info.day_occupied_slots[0].is_empty());
+        assert!(info.day_occupied_slots[1].is_empty());
+        for col in 2..=4 {
+            assert!(info.day_occupied_slots[col].contains(&0));
+        }
+        assert!(info.day_occupied_slots[5].is_empty());
+    }
+}
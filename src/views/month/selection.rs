@@ -63,6 +63,7 @@ pub fn render_spanning_overlay<'a>(
     overlay_column = overlay_column.push(vertical_spacer(WEEKDAY_HEADER_HEIGHT));
 
     let num_weeks = weeks.len();
+    let num_cols = weeks.first().map_or(7, Vec::len);
 
     for week_idx in 0..num_weeks {
         // Check if this week has part of the selection
@@ -102,7 +103,7 @@ pub fn render_spanning_overlay<'a>(
             week_row = week_row.push(spanning_container);
 
             // Add empty spacers for columns after the selection
-            for _ in (*end_col + 1)..7 {
+            for _ in (*end_col + 1)..num_cols {
                 week_row = week_row.push(spacer(Length::Fill, Length::Shrink));
             }
 
@@ -1,21 +1,29 @@
 //! Event rendering for the week view
 //!
 //! Contains timed event chip rendering and event overlay positioning.
+//! Timed events can be resized by dragging the grab strip at their top or bottom edge.
+//! Overlapping events are laid out side by side or cascaded, per `EventOverlapStyle`;
+//! events beyond the visible columns are summarized by a "+N" indicator.
 
-use chrono::{Local, NaiveDate, Timelike};
-use cosmic::iced::{Background, Border, Length};
+use chrono::{Local, NaiveDate, NaiveTime};
+use cosmic::iced::widget::Stack;
+use cosmic::iced::{alignment, Background, Border, Length};
 use cosmic::iced_widget::keyed::Column as KeyedColumn;
-use cosmic::widget::{container, mouse_area, row};
+use cosmic::widget::{column, container, mouse_area, popover, row, tooltip};
 use cosmic::{widget, Element};
 use std::hash::{Hash, Hasher};
 use std::collections::hash_map::DefaultHasher;
 
-use crate::components::{parse_color_safe, ChipOpacity, DisplayEvent};
+use crate::components::{apply_status_style, parse_color_safe, tooltip_text, ChipOpacity, DisplayEvent, EventSelection};
+use crate::fl;
+use crate::locale::LocalePreferences;
 use crate::components::spacer::vertical_spacer;
 use crate::message::Message;
+use crate::selection::ResizeEdge;
+use crate::settings::{EventOverlapStyle, EventTextSize};
 use crate::ui_constants::{HOUR_ROW_HEIGHT, BORDER_RADIUS};
 
-use super::utils::{event_time_range, PositionedEvent};
+use super::utils::{event_time_range, time_span_to_height, PositionedEvent};
 
 /// Spacing between event blocks in pixels (vertical gap)
 const EVENT_BLOCK_SPACING: f32 = 2.0;
@@ -30,43 +38,162 @@ fn hash_key(s: &str) -> u64 {
 /// Spacing between overlapping event columns in pixels (horizontal gap)
 const EVENT_COLUMN_SPACING: u16 = 4;
 
+/// Minimum height of an event block so very short events stay visible
+const MIN_EVENT_BLOCK_HEIGHT: f32 = 16.0;
+
+/// Vertical padding inside an event block (top + bottom)
+const EVENT_BLOCK_PADDING_Y: f32 = 4.0;
+
+/// Line height of block labels relative to their text size
+const EVENT_LINE_HEIGHT_FACTOR: f32 = 1.4;
+
+/// Width of the expanded details shown for short events
+const EXPANDED_EVENT_WIDTH: f32 = 200.0;
+
+/// Height of the grab strips at the top and bottom edge of a resizable block
+const RESIZE_HANDLE_HEIGHT: f32 = 4.0;
+
+/// Indent of each cascade step when few events overlap
+const CASCADE_STEP: f32 = 16.0;
+
+/// Largest indent of the last cascaded column, so narrow days keep room for its label
+const CASCADE_MAX_INDENT: f32 = 64.0;
+
+/// Left indent of `column` when `total_columns` overlapping events cascade.
+/// The step shrinks as more events overlap so the last one stays within `CASCADE_MAX_INDENT`.
+fn cascade_offset(column: usize, total_columns: usize) -> f32 {
+    if total_columns <= 1 {
+        return 0.0;
+    }
+    let step = CASCADE_STEP.min(CASCADE_MAX_INDENT / (total_columns - 1) as f32);
+    column as f32 * step
+}
+
+/// Height a block needs to show its time and summary on two lines
+fn readable_block_height(text_size: EventTextSize) -> f32 {
+    2.0 * text_size.block_size() as f32 * EVENT_LINE_HEIGHT_FACTOR + EVENT_BLOCK_PADDING_Y
+}
+
+/// Whether a block is too short to read, so hovering or selecting it
+/// shows the full details in an expanded overlay
+fn needs_expansion(block_height: f32, text_size: EventTextSize) -> bool {
+    block_height < readable_block_height(text_size)
+}
+
+/// Shown start and end of a block that can be resized by its edges.
+/// Busy blocks from free/busy feeds and events running past midnight or over
+/// several days can't be.
+fn resize_range(event: &DisplayEvent) -> Option<(NaiveTime, NaiveTime)> {
+    if event.is_busy_block() || event.is_multi_day_timed() {
+        return None;
+    }
+    let (start, end) = (event.start_time?, event.end_time?);
+    (start < end).then_some((start, end))
+}
+
+/// Grab strip at the edge of an event block; pressing it starts a resize
+fn resize_handle(message: Message) -> Element<'static, Message> {
+    mouse_area(vertical_spacer(RESIZE_HANDLE_HEIGHT))
+        .on_press(message)
+        .into()
+}
+
+/// "+N" label for the events hidden beyond the visible columns, right-aligned at the
+/// start of the earliest one. A press opens the day's events popover, as in the month view.
+pub fn render_overflow_indicator_layer(
+    date: NaiveDate,
+    hidden_events: &[DisplayEvent],
+    text_size: EventTextSize,
+) -> Element<'static, Message> {
+    let first_start = hidden_events.iter().map(|e| event_time_range(e).0).min().unwrap_or(0);
+    let offset = (first_start as f32 / 60.0) * HOUR_ROW_HEIGHT;
+
+    let label = container(widget::text(format!("+{}", hidden_events.len())).size(text_size.block_size()))
+        .padding([0, 6])
+        .style(|theme: &cosmic::Theme| container::Style {
+            background: Some(Background::Color(theme.cosmic().background.base.into())),
+            border: Border {
+                radius: BORDER_RADIUS.into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+    let indicator = mouse_area(label).on_press(Message::OpenDayEvents(date));
+
+    column()
+        .push(vertical_spacer(offset + EVENT_BLOCK_SPACING))
+        .push(container(indicator).width(Length::Fill).align_x(alignment::Horizontal::Right))
+        .into()
+}
+
+/// Events assigned to `col_idx`, sorted by start time
+fn column_events(positioned_events: &[PositionedEvent], col_idx: usize) -> Vec<&PositionedEvent> {
+    let mut col_events: Vec<&PositionedEvent> = positioned_events.iter()
+        .filter(|pe| pe.column == col_idx)
+        .collect();
+    col_events.sort_by_key(|pe| event_time_range(&pe.event).0);
+    col_events
+}
+
 /// Render the events overlay layer with events positioned based on their time spans
-/// Uses a row of columns approach where each column renders its events independently
+/// Each column renders its events independently; columns are placed side by side,
+/// or stacked with later columns on top and indented for the cascade style
 pub fn render_events_overlay_layer(
     date: NaiveDate,
     positioned_events: &[PositionedEvent],
     max_columns: usize,
-    selected_event_uid: Option<&str>,
+    selected_events: EventSelection<'_>,
+    text_size: EventTextSize,
+    overlap_style: EventOverlapStyle,
+    locale: &LocalePreferences,
 ) -> Element<'static, Message> {
-    // Each column renders its events independently with proper vertical positioning
-    // This ensures overlapping events appear side-by-side
-    let mut columns_row = row().spacing(EVENT_COLUMN_SPACING);
-
-    for col_idx in 0..max_columns {
-        // Get all events for this column, sorted by start time
-        let mut col_events: Vec<&PositionedEvent> = positioned_events.iter()
-            .filter(|pe| pe.column == col_idx)
-            .collect();
-        col_events.sort_by_key(|pe| event_time_range(&pe.event).0);
-
-        // Build this column's content with spacers and events
-        let col_content = render_column_events(date, &col_events, selected_event_uid);
-
-        columns_row = columns_row.push(
-            container(col_content)
-                .width(Length::Fill)
-        );
-    }
+    match overlap_style {
+        EventOverlapStyle::Columns => {
+            // This ensures overlapping events appear side-by-side
+            let mut columns_row = row().spacing(EVENT_COLUMN_SPACING);
+
+            for col_idx in 0..max_columns {
+                let col_events = column_events(positioned_events, col_idx);
+                let col_content = render_column_events(date, &col_events, selected_events, text_size, false, locale);
+
+                columns_row = columns_row.push(
+                    container(col_content)
+                        .width(Length::Fill)
+                );
+            }
+
+            columns_row.into()
+        }
+        EventOverlapStyle::Cascade => {
+            // Later events cover earlier ones but leave their start and left edge visible
+            let layers: Vec<Element<'static, Message>> = (0..max_columns)
+                .map(|col_idx| {
+                    let col_events = column_events(positioned_events, col_idx);
+                    let col_content = render_column_events(date, &col_events, selected_events, text_size, col_idx > 0, locale);
+                    let indent = cascade_offset(col_idx, max_columns);
+
+                    container(col_content)
+                        .padding([0, 0, 0, indent as u16])
+                        .width(Length::Fill)
+                        .into()
+                })
+                .collect();
 
-    columns_row.into()
+            Stack::with_children(layers).into()
+        }
+    }
 }
 
 /// Render a single column of events with proper vertical spacing
-/// Uses KeyedColumn to ensure proper widget reconciliation when events change
+/// Uses KeyedColumn to ensure proper widget reconciliation when events change.
+/// `covers_others` blocks get an opaque backing so events beneath don't show through.
 fn render_column_events(
     date: NaiveDate,
     events: &[&PositionedEvent],
-    selected_event_uid: Option<&str>,
+    selected_events: EventSelection<'_>,
+    text_size: EventTextSize,
+    covers_others: bool,
+    locale: &LocalePreferences,
 ) -> Element<'static, Message> {
     let mut keyed_children: Vec<(u64, Element<'static, Message>)> = Vec::new();
     let mut current_mins: u32 = 0;
@@ -94,12 +221,15 @@ fn render_column_events(
         ));
 
         // Render the event (subtract full spacing from height for top + bottom margins)
-        let ev_height = ((end_mins - start_mins) as f32 / 60.0) * HOUR_ROW_HEIGHT - EVENT_BLOCK_SPACING;
+        let ev_height = time_span_to_height(start_mins, end_mins) - EVENT_BLOCK_SPACING;
         let event_block = render_positioned_event_block(
             date,
             &pe.event,
-            ev_height.max(16.0), // Minimum height for visibility
-            selected_event_uid,
+            ev_height.max(MIN_EVENT_BLOCK_HEIGHT), // Minimum height for visibility
+            selected_events,
+            text_size,
+            covers_others,
+            locale,
         );
         // Key the event block with its UID hash for proper reconciliation
         keyed_children.push((event_key, event_block));
@@ -133,13 +263,16 @@ fn render_positioned_event_block(
     date: NaiveDate,
     event: &DisplayEvent,
     height: f32,
-    selected_event_uid: Option<&str>,
+    selected_events: EventSelection<'_>,
+    text_size: EventTextSize,
+    opaque: bool,
+    locale: &LocalePreferences,
 ) -> Element<'static, Message> {
     let color = parse_color_safe(&event.color);
     let calendar_id = event.calendar_id.clone();
     let uid = event.uid.clone();
     let unique_id = event.unique_id();
-    let is_selected = selected_event_uid == Some(&unique_id);
+    let is_selected = selected_events.contains(&unique_id);
 
     // Check if this event is in the past (considering time on today)
     let now = Local::now();
@@ -164,19 +297,31 @@ fn render_positioned_event_block(
         false
     };
 
-    let (bg_opacity, border_width) = ChipOpacity::timed_event_opacity(is_selected, is_past);
+    let (bg_opacity, border_width) = if event.is_busy_block() {
+        ChipOpacity::busy_block_opacity(is_selected, is_past)
+    } else {
+        ChipOpacity::timed_event_opacity(is_selected, is_past)
+    };
 
     // Build the label with time and summary
     let time_str = event.start_time
-        .map(|t| format!("{:02}:{:02}", t.hour(), t.minute()))
+        .map(|t| locale.format_time(&t))
         .unwrap_or_default();
     let label = format!("{} {}", time_str, event.summary);
 
-    let chip = container(
-        widget::text(label.clone())
-            .size(10)
-    )
-    .padding([2, 6])
+    let label_text = widget::text(label.clone()).size(text_size.block_size());
+
+    // The edges of resizable blocks are grab strips; pressing them wins over the block's own press
+    let content: Element<'static, Message> = match resize_range(event) {
+        Some((start, end)) => column()
+            .push(resize_handle(Message::EventResizeStart(calendar_id.clone(), uid.clone(), ResizeEdge::Start, start, end)))
+            .push(container(label_text).padding([0, 6]).height(Length::Fill))
+            .push(resize_handle(Message::EventResizeStart(calendar_id.clone(), uid.clone(), ResizeEdge::End, start, end)))
+            .into(),
+        None => container(label_text).padding([2, 6]).into(),
+    };
+
+    let chip = container(content)
     .width(Length::Fill)
     .height(Length::Fixed(height))
     .style(move |theme: &cosmic::Theme| container::Style {
@@ -197,13 +342,147 @@ fn render_positioned_event_block(
         ..Default::default()
     });
 
+    let chip = apply_status_style(chip.into(), event.status, color);
+
+    // Cascaded blocks sit on the window background so the block they cover doesn't show through
+    let chip: Element<'static, Message> = if opaque {
+        container(chip)
+            .style(|theme: &cosmic::Theme| container::Style {
+                background: Some(Background::Color(theme.cosmic().background.base.into())),
+                border: Border {
+                    radius: BORDER_RADIUS.into(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .into()
+    } else {
+        chip
+    };
+
     // Get color hex for drag preview
     let color_hex = event.color.clone();
 
-    mouse_area(chip)
+    let area = mouse_area(chip)
         .on_press(Message::DragEventStart(calendar_id.clone(), uid.clone(), date, event.summary.clone(), color_hex))
         .on_release(Message::DragEventEnd)
         .on_double_click(Message::OpenEditEventDialog(calendar_id, uid))
-        .on_enter(Message::DragEventUpdate(date))
+        .on_enter(Message::DragEventUpdate(date));
+
+    if !needs_expansion(height, text_size) {
+        return area.into();
+    }
+
+    // Too short to read: selecting shows the expanded block in place,
+    // hovering shows it as a popup
+    let expanded = render_expanded_event_block(event, color, bg_opacity, text_size, locale);
+    if is_selected {
+        popover(area).popup(expanded).into()
+    } else {
+        tooltip(area, expanded, tooltip::Position::Top)
+            .gap(2.0)
+            .into()
+    }
+}
+
+/// Render a short event at a readable size with its full details
+fn render_expanded_event_block(
+    event: &DisplayEvent,
+    color: cosmic::iced::Color,
+    bg_opacity: f32,
+    text_size: EventTextSize,
+    locale: &LocalePreferences,
+) -> Element<'static, Message> {
+    let details = tooltip_text(event, &fl!("event-all-day"), &fl!("event-timezone-fallback"), locale);
+
+    container(widget::text(details).size(text_size.block_size()))
+        .padding([2, 6])
+        .width(Length::Fixed(EXPANDED_EVENT_WIDTH))
+        .height(Length::Shrink)
+        .style(move |_theme: &cosmic::Theme| container::Style {
+            // Opaque so the overlay stays readable over neighbouring events
+            background: Some(Background::Color(cosmic::iced::Color {
+                a: bg_opacity.max(0.9),
+                ..color
+            })),
+            text_color: Some(cosmic::iced::Color::WHITE),
+            border: Border {
+                radius: BORDER_RADIUS.into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
         .into()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timed_event(start: (u32, u32), end: (u32, u32)) -> DisplayEvent {
+        DisplayEvent {
+            calendar_id: "work".to_string(),
+            uid: "review".to_string(),
+            summary: "Review".to_string(),
+            start_time: NaiveTime::from_hms_opt(start.0, start.1, 0),
+            end_time: NaiveTime::from_hms_opt(end.0, end.1, 0),
+            ..DisplayEvent::test_default()
+        }
+    }
+
+    #[test]
+    fn test_resize_range_only_for_blocks_within_the_day() {
+        let event = timed_event((9, 0), (10, 30));
+        assert_eq!(
+            resize_range(&event),
+            Some((NaiveTime::from_hms_opt(9, 0, 0).unwrap(), NaiveTime::from_hms_opt(10, 30, 0).unwrap()))
+        );
+
+        // Runs past midnight: its end is on the next day
+        assert_eq!(resize_range(&timed_event((23, 0), (1, 0))), None);
+
+        // A day's segment of an event spanning several days
+        let mut spanning = timed_event((0, 0), (14, 0));
+        spanning.span_start = NaiveDate::from_ymd_opt(2025, 3, 10);
+        spanning.span_end = NaiveDate::from_ymd_opt(2025, 3, 12);
+        assert_eq!(resize_range(&spanning), None);
+
+        let mut without_end = timed_event((9, 0), (10, 0));
+        without_end.end_time = None;
+        assert_eq!(resize_range(&without_end), None);
+    }
+
+    #[test]
+    fn test_cascade_offset_for_overlapping_events() {
+        // A single event isn't indented
+        assert_eq!(cascade_offset(0, 1), 0.0);
+
+        // Three overlapping events: one full step per later event
+        let offsets: Vec<f32> = (0..3).map(|column| cascade_offset(column, 3)).collect();
+        assert_eq!(offsets, vec![0.0, CASCADE_STEP, 2.0 * CASCADE_STEP]);
+
+        // Many overlapping events: steps shrink, the last one ends at the maximum indent
+        let offsets: Vec<f32> = (0..9).map(|column| cascade_offset(column, 9)).collect();
+        assert_eq!(offsets[0], 0.0);
+        assert_eq!(offsets[8], CASCADE_MAX_INDENT);
+        assert!(offsets.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_short_blocks_need_expansion() {
+        let size = EventTextSize::Normal;
+        let readable = readable_block_height(size);
+
+        // A 15-minute event is clamped to the minimum height and can't show two lines
+        let quarter_hour = (15.0 / 60.0) * HOUR_ROW_HEIGHT - EVENT_BLOCK_SPACING;
+        assert!(needs_expansion(quarter_hour.max(MIN_EVENT_BLOCK_HEIGHT), size));
+        assert!(needs_expansion(MIN_EVENT_BLOCK_HEIGHT, size));
+
+        // Blocks tall enough for time and summary keep their normal rendering
+        assert!(!needs_expansion(readable, size));
+        assert!(!needs_expansion(HOUR_ROW_HEIGHT, size));
+
+        // Larger text needs more room before it reads
+        assert!(readable_block_height(EventTextSize::ExtraLarge) > readable);
+    }
+}
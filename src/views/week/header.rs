@@ -5,22 +5,24 @@
 use chrono::{Datelike, Local, NaiveDate};
 use cosmic::iced::{alignment, Background, Border, Length};
 use cosmic::iced_widget::keyed::Column as KeyedColumn;
-use cosmic::widget::{column, container, mouse_area, row};
+use cosmic::widget::{button, column, container, mouse_area, row};
 use cosmic::{widget, Element};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::hash::{Hash, Hasher};
 use std::collections::hash_map::DefaultHasher;
 
-use crate::components::{parse_color_safe, ChipOpacity, DisplayEvent};
+use crate::components::{apply_status_style, parse_color_safe, ChipOpacity, DisplayEvent, EventSelection};
 use crate::components::spacer::fixed_spacer;
+use crate::fl;
 use crate::locale::LocalePreferences;
 use crate::localized_names;
 use crate::message::Message;
 use crate::models::WeekState;
-use crate::styles::{today_filled_style, weekend_background};
+use crate::settings::{EventTextSize, GridLineStyle};
+use crate::styles::{grid_border, today_filled_style, weekend_background};
 use crate::ui_constants::{
-    PADDING_SMALL, FONT_SIZE_SMALL, FONT_SIZE_MEDIUM, COLOR_DAY_CELL_BORDER,
-    TIME_LABEL_WIDTH, BORDER_WIDTH_THIN, SPACING_TINY, BORDER_RADIUS,
+    PADDING_SMALL, FONT_SIZE_SMALL, FONT_SIZE_MEDIUM,
+    TIME_LABEL_WIDTH, BORDER_WIDTH_THIN, SPACING_TINY, BORDER_RADIUS, ICON_COLLAPSE, ICON_EXPAND,
 };
 
 use super::utils::{DAY_HEADER_HEIGHT, ALL_DAY_EVENT_HEIGHT, ALL_DAY_SPACING};
@@ -32,13 +34,27 @@ fn hash_key(s: &str) -> u64 {
     hasher.finish()
 }
 
+/// Theme divider between the all-day section and the time grid, shown when
+/// all-day events would otherwise run straight into the first hour rows
+pub fn render_all_day_separator<'a>(
+    all_day_events: &HashMap<NaiveDate, Vec<DisplayEvent>>,
+) -> Option<Element<'a, Message>> {
+    all_day_events
+        .values()
+        .any(|events| !events.is_empty())
+        .then(|| widget::divider::horizontal::default().into())
+}
+
 /// Render the header section with day names, dates, and all-day events
 pub fn render_header_section<'a>(
     week_state: &'a WeekState,
     locale: &'a LocalePreferences,
     all_day_events: &HashMap<NaiveDate, Vec<DisplayEvent>>,
     all_day_section_height: f32,
-    selected_event_uid: Option<&str>,
+    selected_events: EventSelection<'_>,
+    grid_lines: GridLineStyle,
+    event_text_size: EventTextSize,
+    all_day_collapsed: bool,
 ) -> Element<'a, Message> {
     let mut header_col = column().spacing(0);
 
@@ -65,11 +81,7 @@ pub fn render_header_section<'a>(
                 .center_x(Length::Fill)
                 .style(move |_theme: &cosmic::Theme| container::Style {
                     background: weekend_background(is_weekend),
-                    border: Border {
-                        width: BORDER_WIDTH_THIN,
-                        color: COLOR_DAY_CELL_BORDER,
-                        ..Default::default()
-                    },
+                    border: grid_border(grid_lines, BORDER_WIDTH_THIN),
                     ..Default::default()
                 })
         );
@@ -78,7 +90,7 @@ pub fn render_header_section<'a>(
     header_col = header_col.push(day_headers);
 
     // All-day events section
-    let all_day_section = render_all_day_section(week_state, locale, all_day_events, all_day_section_height, selected_event_uid);
+    let all_day_section = render_all_day_section(week_state, locale, all_day_events, all_day_section_height, selected_events, grid_lines, event_text_size, all_day_collapsed);
     header_col = header_col.push(all_day_section);
 
     header_col.into()
@@ -111,36 +123,67 @@ fn render_all_day_section<'a>(
     locale: &'a LocalePreferences,
     all_day_events: &HashMap<NaiveDate, Vec<DisplayEvent>>,
     height: f32,
-    selected_event_uid: Option<&str>,
+    selected_events: EventSelection<'_>,
+    grid_lines: GridLineStyle,
+    event_text_size: EventTextSize,
+    collapsed: bool,
 ) -> Element<'a, Message> {
     let mut all_day_row = row().spacing(0);
+    let event_count = count_all_day_events(all_day_events);
 
-    // Time column with "all-day" label
+    // Time column holds the collapse toggle (only when there is something to collapse)
+    let toggle: Element<'a, Message> = if event_count > 0 {
+        let icon = if collapsed { ICON_EXPAND } else { ICON_COLLAPSE };
+        button::icon(widget::icon::from_name(icon))
+            .extra_small()
+            .on_press(Message::ToggleAllDayCollapsed)
+            .into()
+    } else {
+        widget::text("").size(FONT_SIZE_SMALL).into()
+    };
     all_day_row = all_day_row.push(
-        container(
-            widget::text("").size(FONT_SIZE_SMALL)
-        )
+        container(toggle)
         .width(Length::Fixed(TIME_LABEL_WIDTH))
         .height(Length::Fixed(height))
         .padding(PADDING_SMALL)
         .align_y(alignment::Vertical::Top)
-        .style(|_theme: &cosmic::Theme| container::Style {
-            border: Border {
-                width: BORDER_WIDTH_THIN,
-                color: COLOR_DAY_CELL_BORDER,
-                ..Default::default()
-            },
+        .style(move |_theme: &cosmic::Theme| container::Style {
+            border: grid_border(grid_lines, BORDER_WIDTH_THIN),
             ..Default::default()
         })
     );
 
+    // Collapsed: one summary row across all day columns, expanding on click
+    if collapsed && event_count > 0 {
+        let summary = button::custom(
+            widget::text(fl!("week-all-day-summary", count = event_count))
+                .size(event_text_size.block_size())
+        )
+        .on_press(Message::ToggleAllDayCollapsed)
+        .padding([2, 4])
+        .width(Length::Fill)
+        .class(cosmic::theme::Button::Text);
+
+        all_day_row = all_day_row.push(
+            container(summary)
+                .width(Length::Fill)
+                .height(Length::Fixed(height))
+                .padding([2, 2])
+                .style(move |_theme: &cosmic::Theme| container::Style {
+                    border: grid_border(grid_lines, BORDER_WIDTH_THIN),
+                    ..Default::default()
+                })
+        );
+        return all_day_row.into();
+    }
+
     // All-day events for each day
     for date in &week_state.days {
         let is_weekend = locale.is_weekend(date.weekday());
         let day_events = all_day_events.get(date).cloned().unwrap_or_default();
         let date_copy = *date;
 
-        let events_column = render_all_day_events_for_day(*date, &day_events, selected_event_uid);
+        let events_column = render_all_day_events_for_day(*date, &day_events, selected_events, event_text_size);
 
         let cell = container(events_column)
             .width(Length::Fill)
@@ -148,11 +191,7 @@ fn render_all_day_section<'a>(
             .padding([2, 2])
             .style(move |_theme: &cosmic::Theme| container::Style {
                 background: weekend_background(is_weekend),
-                border: Border {
-                    width: BORDER_WIDTH_THIN,
-                    color: COLOR_DAY_CELL_BORDER,
-                    ..Default::default()
-                },
+                border: grid_border(grid_lines, BORDER_WIDTH_THIN),
                 ..Default::default()
             });
 
@@ -166,9 +205,19 @@ fn render_all_day_section<'a>(
     all_day_row.into()
 }
 
+/// Count distinct all-day events in the week (multi-day events appear under several dates)
+fn count_all_day_events(all_day_events: &HashMap<NaiveDate, Vec<DisplayEvent>>) -> usize {
+    all_day_events
+        .values()
+        .flatten()
+        .map(|e| e.unique_id())
+        .collect::<HashSet<_>>()
+        .len()
+}
+
 /// Render all-day events for a single day as a vertical stack with click and drag support
 /// Uses KeyedColumn to ensure proper widget reconciliation when events change
-fn render_all_day_events_for_day(date: NaiveDate, events: &[DisplayEvent], selected_event_uid: Option<&str>) -> Element<'static, Message> {
+fn render_all_day_events_for_day(date: NaiveDate, events: &[DisplayEvent], selected_events: EventSelection<'_>, text_size: EventTextSize) -> Element<'static, Message> {
     // Check if this date is in the past (all-day events are past at end of day)
     let today = Local::now().date_naive();
     let is_past = date < today; // All-day events don't have time - check by day
@@ -180,14 +229,14 @@ fn render_all_day_events_for_day(date: NaiveDate, events: &[DisplayEvent], selec
         let uid = event.uid.clone();
         let key = hash_key(&uid);
         let unique_id = event.unique_id();
-        let is_selected = selected_event_uid == Some(&unique_id);
+        let is_selected = selected_events.contains(&unique_id);
 
         // Selection highlight with past event dimming
         let (bg_opacity, border_width) = ChipOpacity::timed_event_opacity(is_selected, is_past);
 
         let chip = container(
             widget::text(event.summary.clone())
-                .size(10)
+                .size(text_size.block_size())
         )
         .padding([2, 4])
         .width(Length::Fill)
@@ -210,6 +259,8 @@ fn render_all_day_events_for_day(date: NaiveDate, events: &[DisplayEvent], selec
             ..Default::default()
         });
 
+        let chip = apply_status_style(chip.into(), event.status, color);
+
         // Get color hex for drag preview
         let color_hex = event.color.clone();
 
@@ -229,3 +280,30 @@ fn render_all_day_events_for_day(date: NaiveDate, events: &[DisplayEvent], selec
         .spacing(ALL_DAY_SPACING as f32)
         .into()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_day_separator_present_with_all_day_events() {
+        let date = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+        let mut all_day_events: HashMap<NaiveDate, Vec<DisplayEvent>> = HashMap::new();
+        assert!(render_all_day_separator(&all_day_events).is_none());
+
+        // Days without events don't count
+        all_day_events.insert(date, vec![]);
+        assert!(render_all_day_separator(&all_day_events).is_none());
+
+        all_day_events.insert(date, vec![DisplayEvent {
+            calendar_id: "personal".to_string(),
+            uid: "holiday".to_string(),
+            summary: "Holiday".to_string(),
+            all_day: true,
+            start_time: None,
+            end_time: None,
+            ..DisplayEvent::test_default()
+        }]);
+        assert!(render_all_day_separator(&all_day_events).is_some());
+    }
+}
@@ -9,41 +9,44 @@
 //! - [`events`] - Timed event chip rendering and positioning
 //! - [`time_indicator`] - Current time line and dot
 //! - [`quick_event`] - Inline event creation input
+//! - [`print`] - Printable black-on-white week page
 //! - [`utils`] - Shared types and utility functions
 
 mod events;
 mod header;
+mod print;
 mod quick_event;
 mod time_grid;
 mod time_indicator;
 mod utils;
 
-use chrono::{Datelike, NaiveDate, NaiveTime, Timelike};
-use cosmic::iced::widget::stack;
+use chrono::NaiveDate;
 use cosmic::iced::Length;
 use cosmic::widget::{column, container, scrollable};
 use cosmic::Element;
 use std::collections::HashMap;
 
-use crate::components::DisplayEvent;
+use crate::components::{render_time_grid, DisplayEvent, EventSelection, TimeGridState};
 use crate::dialogs::ActiveDialog;
 use crate::locale::LocalePreferences;
 use crate::message::Message;
 use crate::models::WeekState;
-use crate::selection::SelectionState;
-use crate::ui_constants::HOUR_ROW_HEIGHT;
+use crate::selection::{EventResizeState, SelectionState};
+use crate::settings::{EventOverlapStyle, EventTextSize, GridLineStyle, WorkingHours};
 
-use events::render_events_overlay_layer;
-use header::render_header_section;
-use quick_event::render_quick_event_input_layer;
-use time_grid::{render_hour_grid_background, render_time_labels_column};
-use time_indicator::render_time_indicator_layer;
-use utils::{
-    calculate_event_columns, calculate_max_all_day_slots, separate_events,
-    ALL_DAY_EVENT_HEIGHT, ALL_DAY_MIN_HEIGHT, ALL_DAY_SPACING,
-};
+pub use print::render_week_print;
 
-/// Returns the scrollable ID for the week view time grid
+// Layers of the timed grid, composed by `components::render_time_grid` for the week and day views
+pub(crate) use events::{render_events_overlay_layer, render_overflow_indicator_layer};
+pub(crate) use quick_event::render_quick_event_input_layer;
+pub(crate) use time_grid::{render_hour_grid_background, render_time_labels_column};
+pub(crate) use time_indicator::{current_time_in_days, render_time_indicator_layer};
+pub(crate) use utils::{apply_resize_preview, calculate_event_columns, clip_to_day, separate_events, split_overflow_columns, MAX_VISIBLE_EVENT_COLUMNS};
+
+use header::{render_all_day_separator, render_header_section};
+use utils::{calculate_max_all_day_slots, ALL_DAY_EVENT_HEIGHT, ALL_DAY_MIN_HEIGHT, ALL_DAY_SPACING};
+
+/// Returns the scrollable ID of the time grid (week and day view)
 pub fn week_time_grid_id() -> cosmic::iced_core::id::Id {
     cosmic::iced_core::id::Id::new("week_time_grid")
 }
@@ -52,14 +55,39 @@ pub fn week_time_grid_id() -> cosmic::iced_core::id::Id {
 pub struct WeekViewEvents<'a> {
     /// Events for each day, keyed by date
     pub events_by_date: &'a HashMap<NaiveDate, Vec<DisplayEvent>>,
-    /// Currently selected event UID (for visual feedback)
-    pub selected_event_uid: Option<&'a str>,
+    /// Currently selected events (for visual feedback)
+    pub selected_events: EventSelection<'a>,
     /// Selection state for time slot highlighting
     pub selection: &'a SelectionState,
+    /// Edge drag in progress, previewed at the new times
+    pub resize: &'a EventResizeState,
     /// Active dialog state (for quick event input)
     pub active_dialog: &'a ActiveDialog,
     /// Selected calendar color (for quick event styling)
     pub calendar_color: &'a str,
+    /// Font size of event labels
+    pub event_text_size: EventTextSize,
+    /// Layout of overlapping timed events
+    pub overlap_style: EventOverlapStyle,
+    /// Whether the all-day section is collapsed to a summary row
+    pub all_day_collapsed: bool,
+    /// Working day to dim the other hours around (None = no shading)
+    pub working_hours: Option<WorkingHours>,
+}
+
+impl<'a> WeekViewEvents<'a> {
+    /// Interaction state for the timed grid
+    pub fn grid_state(&self) -> TimeGridState<'a> {
+        TimeGridState {
+            selected_events: self.selected_events,
+            selection: Some(self.selection),
+            active_dialog: Some(self.active_dialog),
+            calendar_color: Some(self.calendar_color),
+            event_text_size: self.event_text_size,
+            overlap_style: self.overlap_style,
+            working_hours: self.working_hours,
+        }
+    }
 }
 
 /// Render the week view with events
@@ -67,37 +95,47 @@ pub fn render_week_view<'a>(
     week_state: &'a WeekState,
     locale: &'a LocalePreferences,
     events: Option<WeekViewEvents<'a>>,
+    grid_lines: GridLineStyle,
 ) -> Element<'a, Message> {
-    // Extract selected event UID for selection highlighting
-    let selected_event_uid = events.as_ref().and_then(|e| e.selected_event_uid);
-
-    // Extract selection state for time slot highlighting
-    let selection = events.as_ref().map(|e| e.selection);
+    // Extract selected events for selection highlighting
+    let selected_events = events.as_ref().map_or_else(EventSelection::default, |e| e.selected_events);
 
-    // Extract active dialog and calendar color for quick event input
-    let active_dialog = events.as_ref().map(|e| e.active_dialog);
-    let calendar_color = events.as_ref().map(|e| e.calendar_color);
+    let event_text_size = events.as_ref().map_or(EventTextSize::default(), |e| e.event_text_size);
+    let all_day_collapsed = events.as_ref().is_some_and(|e| e.all_day_collapsed);
 
     // Separate events into all-day and timed
-    let (all_day_events, timed_events) = if let Some(ref ev) = events {
+    let (all_day_events, mut timed_events) = if let Some(ref ev) = events {
         separate_events(ev.events_by_date, &week_state.days)
     } else {
         (HashMap::new(), HashMap::new())
     };
+    if let Some(ref ev) = events {
+        apply_resize_preview(&mut timed_events, ev.resize);
+    }
 
     // Calculate how many rows we need for all-day events
     let max_all_day_slots = calculate_max_all_day_slots(&all_day_events);
-    let all_day_section_height = ALL_DAY_MIN_HEIGHT + (max_all_day_slots as f32 * (ALL_DAY_EVENT_HEIGHT + ALL_DAY_SPACING));
+    // Collapsed sections shrink to a single summary row
+    let all_day_section_height = if all_day_collapsed {
+        ALL_DAY_MIN_HEIGHT
+    } else {
+        ALL_DAY_MIN_HEIGHT + (max_all_day_slots as f32 * (ALL_DAY_EVENT_HEIGHT + ALL_DAY_SPACING))
+    };
 
     // Day headers with all-day events section
-    let header_section = render_header_section(week_state, locale, &all_day_events, all_day_section_height, selected_event_uid);
+    let header_section = render_header_section(week_state, locale, &all_day_events, all_day_section_height, selected_events, grid_lines, event_text_size, all_day_collapsed);
 
     // Time grid with timed events
-    let time_grid = render_time_grid_with_events(locale, week_state, &timed_events, selected_event_uid, selection, active_dialog, calendar_color);
+    let grid_state = events.as_ref().map_or_else(TimeGridState::default, WeekViewEvents::grid_state);
+    let time_grid = render_time_grid(locale, &week_state.days, &timed_events, grid_state, grid_lines);
+
+    // Keep the all-day section visually apart from the scrolling grid
+    let separator = render_all_day_separator(&all_day_events);
 
     let content = column()
         .spacing(0)
         .push(header_section)
+        .push_maybe(separator)
         .push(
             scrollable(time_grid)
                 .id(week_time_grid_id())
@@ -111,150 +149,77 @@ pub fn render_week_view<'a>(
         .into()
 }
 
-/// Render the time grid with timed events spanning their full duration
-fn render_time_grid_with_events<'a>(
-    locale: &'a LocalePreferences,
-    week_state: &'a WeekState,
-    timed_events: &HashMap<NaiveDate, Vec<DisplayEvent>>,
-    selected_event_uid: Option<&'a str>,
-    selection: Option<&'a SelectionState>,
-    active_dialog: Option<&'a ActiveDialog>,
-    calendar_color: Option<&'a str>,
-) -> Element<'a, Message> {
-    // Get current time for the "now" indicator
-    let now = chrono::Local::now();
-    let today = now.date_naive();
-    let current_hour = now.hour();
-    let current_minute = now.minute();
-
-    // Check if today is in the current week
-    let today_column_index = week_state.days.iter().position(|d| *d == today);
-
-    // Check if there's an active timed quick event to display
-    let quick_event_data = active_dialog.and_then(|dialog| {
-        if let ActiveDialog::QuickEvent { start_date, start_time: Some(start_time), end_time: Some(end_time), text, .. } = dialog {
-            Some((*start_date, *start_time, *end_time, text.as_str()))
-        } else {
-            None
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::locale::DateFormat;
+    use chrono::{NaiveTime, Weekday};
+
+    fn locale() -> LocalePreferences {
+        LocalePreferences {
+            use_24_hour: true,
+            first_day_of_week: Weekday::Mon,
+            weekend_days: vec![Weekday::Sat, Weekday::Sun],
+            date_format: DateFormat::YMD,
+            locale_string: "en_US.UTF-8".to_string(),
         }
-    });
-
-    // Build the grid as a row: time labels column + day columns
-    let mut main_row = cosmic::widget::row().spacing(0);
-
-    // Time labels column
-    let time_labels = render_time_labels_column(locale, today_column_index.is_some(), current_hour);
-    main_row = main_row.push(time_labels);
-
-    // Day columns with events
-    for (day_idx, date) in week_state.days.iter().enumerate() {
-        let is_weekend = locale.is_weekend(date.weekday());
-        let is_today_column = today_column_index == Some(day_idx);
-        let day_events = timed_events.get(date).cloned().unwrap_or_default();
-
-        // Check if this day has the quick event input
-        let day_quick_event = quick_event_data.and_then(|(qe_date, start, end, text)| {
-            if qe_date == *date {
-                Some((start, end, text, calendar_color.unwrap_or("#3B82F6")))
-            } else {
-                None
-            }
-        });
-
-        let day_column = render_day_column_with_events(
-            *date,
-            &day_events,
-            is_weekend,
-            is_today_column,
-            today_column_index.is_some(), // today_in_week - true if today is visible in this week
-            current_hour,
-            current_minute,
-            selected_event_uid,
-            selection,
-            day_quick_event,
-        );
-
-        main_row = main_row.push(day_column);
     }
 
-    main_row.into()
-}
-
-/// Render a single day column with events spanning their full duration using stack overlay
-fn render_day_column_with_events(
-    date: NaiveDate,
-    events: &[DisplayEvent],
-    is_weekend: bool,
-    is_today: bool,
-    today_in_week: bool,
-    current_hour: u32,
-    current_minute: u32,
-    selected_event_uid: Option<&str>,
-    selection: Option<&SelectionState>,
-    quick_event: Option<(NaiveTime, NaiveTime, &str, &str)>, // (start_time, end_time, text, color)
-) -> Element<'static, Message> {
-    // Build the base hour grid (background layer) - without time indicator
-    let hour_grid = render_hour_grid_background(date, is_weekend, selection);
-
-    // Build the time indicator layer (rendered on top of events)
-    let time_indicator_layer = if today_in_week {
-        let minute_offset = (current_minute as f32 / 60.0) * HOUR_ROW_HEIGHT;
-        Some(render_time_indicator_layer(current_hour, minute_offset, is_today))
-    } else {
-        None
-    };
-
-    // Build quick event input layer if active
-    let quick_event_layer = quick_event.map(|(start_time, end_time, text, color)| {
-        render_quick_event_input_layer(start_time, end_time, text.to_string(), color.to_string())
-    });
-
-    // If no events and no quick event, just return the grid with time indicator on top
-    if events.is_empty() && quick_event_layer.is_none() {
-        return if let Some(time_layer) = time_indicator_layer {
-            container(stack![hour_grid, time_layer])
-                .width(Length::Fill)
-                .into()
-        } else {
-            container(hour_grid)
-                .width(Length::Fill)
-                .into()
-        };
+    fn event(uid: &str, times: Option<(u32, u32)>) -> DisplayEvent {
+        DisplayEvent {
+            calendar_id: "work".to_string(),
+            uid: uid.to_string(),
+            summary: uid.to_string(),
+            all_day: times.is_none(),
+            start_time: times.map(|(start, _)| NaiveTime::from_hms_opt(start, 0, 0).unwrap()),
+            end_time: times.map(|(_, end)| NaiveTime::from_hms_opt(end, 0, 0).unwrap()),
+            ..DisplayEvent::test_default()
+        }
     }
 
-    // Calculate column assignments for overlapping events
-    let positioned_events = calculate_event_columns(events);
-    let max_columns = positioned_events.iter().map(|p| p.total_columns).max().unwrap_or(1).max(1);
-
-    // Build the events overlay layer
-    let events_layer = render_events_overlay_layer(date, &positioned_events, max_columns, selected_event_uid);
-
-    // Stack order: grid (bottom) -> events -> time indicator -> quick event (top)
-    // Time indicator must be above events so it's always visible
-    let stacked: Element<'static, Message> = match (time_indicator_layer, quick_event_layer) {
-        (Some(time_layer), Some(qe_layer)) => stack![
-            hour_grid,
-            events_layer,
-            time_layer,
-            qe_layer
-        ].into(),
-        (Some(time_layer), None) => stack![
-            hour_grid,
-            events_layer,
-            time_layer
-        ].into(),
-        (None, Some(qe_layer)) => stack![
-            hour_grid,
-            events_layer,
-            qe_layer
-        ].into(),
-        (None, None) => stack![
-            hour_grid,
-            events_layer
-        ].into(),
-    };
+    #[test]
+    fn test_week_view_renders_any_number_of_days() {
+        let locale = locale();
+        let selection = SelectionState::new();
+        let resize = EventResizeState::new();
+        let active_dialog = ActiveDialog::None;
+        // Start yesterday so today's column (and the time indicator) is part of every week but the 1-day one
+        let start = chrono::Local::now().date_naive() - chrono::Duration::days(1);
+
+        for count in [1, 3, 5, 7] {
+            let days: Vec<NaiveDate> = (0..count).map(|i| start + chrono::Duration::days(i)).collect();
+            let week_state = WeekState::from_days(days.clone(), Weekday::Mon, true, &locale);
+
+            // Events on the first and last day, plus one outside the visible days
+            let mut events_by_date = HashMap::new();
+            events_by_date.insert(days[0], vec![event("first", Some((9, 10))), event("holiday", None)]);
+            events_by_date.entry(days[days.len() - 1]).or_default().push(event("last", Some((14, 16))));
+            events_by_date.insert(start + chrono::Duration::days(count), vec![event("hidden", Some((9, 10)))]);
+
+            let (all_day, timed) = separate_events(&events_by_date, &week_state.days);
+            assert_eq!(all_day.values().map(Vec::len).sum::<usize>(), 1);
+            assert_eq!(timed.values().map(Vec::len).sum::<usize>(), 2);
+
+            let events = WeekViewEvents {
+                events_by_date: &events_by_date,
+                selected_events: EventSelection::default(),
+                selection: &selection,
+                resize: &resize,
+                active_dialog: &active_dialog,
+                calendar_color: "#3B82F6",
+                event_text_size: EventTextSize::default(),
+                overlap_style: EventOverlapStyle::default(),
+                all_day_collapsed: false,
+                working_hours: Some(WorkingHours::default()),
+            };
+            let _ = render_week_view(&week_state, &locale, Some(events), GridLineStyle::default());
+        }
+    }
 
-    container(stacked)
-        .width(Length::Fill)
-        .into()
+    #[test]
+    fn test_week_view_renders_without_days() {
+        let locale = locale();
+        let week_state = WeekState::from_days(vec![], Weekday::Mon, true, &locale);
+        let _ = render_week_view(&week_state, &locale, None, GridLineStyle::default());
+    }
 }
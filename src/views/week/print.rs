@@ -0,0 +1,265 @@
+//! Printable week layout
+//!
+//! Renders the visible week as a single black-on-white SVG page (A4 landscape):
+//! time labels, day headers, the hour grid, all-day events and timed events as
+//! outlined boxes. Theme and calendar colors are left out so the page prints
+//! cleanly on any printer. Uses the same event data and column layout as the
+//! on-screen week view.
+
+use chrono::{Datelike, NaiveDate};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use crate::caldav::EventStatus;
+use crate::components::DisplayEvent;
+use crate::locale::LocalePreferences;
+use crate::localized_names::get_weekday_short;
+use crate::models::WeekState;
+
+use super::utils::{calculate_event_columns, calculate_max_all_day_slots, clip_to_day, event_time_range, separate_events};
+
+/// Page width in CSS pixels (A4 landscape at 96 dpi)
+pub const PRINT_PAGE_WIDTH: u32 = 1123;
+/// Page height in CSS pixels (A4 landscape at 96 dpi)
+pub const PRINT_PAGE_HEIGHT: u32 = 794;
+
+/// Blank border around the printed content
+const PAGE_MARGIN: f32 = 32.0;
+/// Height reserved for the week title
+const TITLE_HEIGHT: f32 = 36.0;
+/// Height of the row with the day names
+const DAY_HEADER_HEIGHT: f32 = 24.0;
+/// Height of one all-day event row
+const ALL_DAY_ROW_HEIGHT: f32 = 18.0;
+/// Width of the hour label column
+const TIME_LABEL_WIDTH: f32 = 52.0;
+/// Inner padding of event boxes
+const EVENT_PADDING: f32 = 3.0;
+const FONT_SIZE: f32 = 10.0;
+const TITLE_FONT_SIZE: f32 = 16.0;
+/// Average glyph width, used to cut summaries to the width of their box
+const CHAR_WIDTH: f32 = FONT_SIZE * 0.55;
+
+/// Render the week as a printable SVG document of `PRINT_PAGE_WIDTH` x `PRINT_PAGE_HEIGHT`
+pub fn render_week_print(
+    week_state: &WeekState,
+    events_by_date: &HashMap<NaiveDate, Vec<DisplayEvent>>,
+    locale: &LocalePreferences,
+) -> String {
+    let page_width = PRINT_PAGE_WIDTH as f32;
+    let page_height = PRINT_PAGE_HEIGHT as f32;
+    let days = &week_state.days;
+    let (all_day_events, timed_events) = separate_events(events_by_date, days);
+
+    let grid_left = PAGE_MARGIN + TIME_LABEL_WIDTH;
+    let grid_right = page_width - PAGE_MARGIN;
+    let day_width = (grid_right - grid_left) / days.len().max(1) as f32;
+    let header_top = PAGE_MARGIN + TITLE_HEIGHT;
+    let all_day_top = header_top + DAY_HEADER_HEIGHT;
+    let all_day_height = calculate_max_all_day_slots(&all_day_events) as f32 * ALL_DAY_ROW_HEIGHT;
+    let hours_top = all_day_top + all_day_height;
+    let hour_height = (page_height - PAGE_MARGIN - hours_top) / 24.0;
+    let day_left = |index: usize| grid_left + index as f32 * day_width;
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" font-family="sans-serif" font-size="{size}">"#,
+        w = PRINT_PAGE_WIDTH,
+        h = PRINT_PAGE_HEIGHT,
+        size = FONT_SIZE,
+    );
+    let _ = writeln!(svg, r#"<rect x="0" y="0" width="{}" height="{}" fill="white"/>"#, page_width, page_height);
+    push_text(&mut svg, PAGE_MARGIN, PAGE_MARGIN + TITLE_FONT_SIZE, TITLE_FONT_SIZE, "bold", &week_state.week_range_text);
+
+    // Day names and dates
+    for (index, date) in days.iter().enumerate() {
+        let label = format!("{} {}", get_weekday_short(date.weekday()), date.day());
+        push_text(&mut svg, day_left(index) + EVENT_PADDING, header_top + DAY_HEADER_HEIGHT - 8.0, FONT_SIZE, "bold", &label);
+    }
+
+    // Hour rows with their labels
+    for hour in 0..24u32 {
+        let y = hours_top + hour as f32 * hour_height;
+        push_line(&mut svg, grid_left, y, grid_right, y, 0.5);
+        push_text(&mut svg, PAGE_MARGIN, y + FONT_SIZE, FONT_SIZE, "normal", &locale.format_hour(hour));
+    }
+
+    // Day separators through header, all-day rows and hours
+    for index in 0..=days.len() {
+        let x = day_left(index);
+        push_line(&mut svg, x, header_top, x, page_height - PAGE_MARGIN, 1.0);
+    }
+    push_line(&mut svg, grid_left, header_top, grid_right, header_top, 1.0);
+    push_line(&mut svg, grid_left, all_day_top, grid_right, all_day_top, 1.0);
+    push_line(&mut svg, grid_left, hours_top, grid_right, hours_top, 1.0);
+    push_line(&mut svg, grid_left, page_height - PAGE_MARGIN, grid_right, page_height - PAGE_MARGIN, 1.0);
+
+    for (index, date) in days.iter().enumerate() {
+        for (row, event) in all_day_events.get(date).into_iter().flatten().enumerate() {
+            let y = all_day_top + row as f32 * ALL_DAY_ROW_HEIGHT;
+            push_event_box(&mut svg, day_left(index), y, day_width, ALL_DAY_ROW_HEIGHT, event, &event.summary);
+        }
+
+        let day_events: Vec<DisplayEvent> = timed_events
+            .get(date)
+            .into_iter()
+            .flatten()
+            .map(|event| clip_to_day(event, *date))
+            .collect();
+        for positioned in calculate_event_columns(&day_events) {
+            let (start_mins, end_mins) = event_time_range(&positioned.event);
+            let column_width = day_width / positioned.total_columns.max(1) as f32;
+            let x = day_left(index) + positioned.column as f32 * column_width;
+            let y = hours_top + start_mins as f32 / 60.0 * hour_height;
+            // Keep short events tall enough for one line, without running off the page
+            let height = ((end_mins - start_mins) as f32 / 60.0 * hour_height)
+                .max(FONT_SIZE + 2.0 * EVENT_PADDING)
+                .min(page_height - PAGE_MARGIN - y);
+            let label = match positioned.event.start_time {
+                Some(start) => format!("{} {}", locale.format_time(&start), positioned.event.summary),
+                None => positioned.event.summary.clone(),
+            };
+            push_event_box(&mut svg, x, y, column_width, height, &positioned.event, &label);
+        }
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Outlined event box with its label cut to the box width.
+/// Tentative events get a dashed outline, cancelled events are struck through.
+fn push_event_box(svg: &mut String, x: f32, y: f32, width: f32, height: f32, event: &DisplayEvent, label: &str) {
+    let dash = if event.status == EventStatus::Tentative { r#" stroke-dasharray="3 2""# } else { "" };
+    let _ = writeln!(
+        svg,
+        r#"<rect x="{:.1}" y="{:.1}" width="{:.1}" height="{:.1}" fill="white" stroke="black" stroke-width="1"{}/>"#,
+        x + 1.0,
+        y + 1.0,
+        (width - 2.0).max(0.0),
+        (height - 2.0).max(0.0),
+        dash,
+    );
+
+    let max_chars = ((width - 2.0 * EVENT_PADDING) / CHAR_WIDTH).max(1.0) as usize;
+    let decoration = if event.status == EventStatus::Cancelled { r#" text-decoration="line-through""# } else { "" };
+    let _ = writeln!(
+        svg,
+        r#"<text x="{:.1}" y="{:.1}"{}>{}</text>"#,
+        x + EVENT_PADDING,
+        y + EVENT_PADDING + FONT_SIZE,
+        decoration,
+        escape_xml(&truncate_label(label, max_chars)),
+    );
+}
+
+fn push_line(svg: &mut String, x1: f32, y1: f32, x2: f32, y2: f32, width: f32) {
+    let _ = writeln!(
+        svg,
+        r#"<line x1="{:.1}" y1="{:.1}" x2="{:.1}" y2="{:.1}" stroke="black" stroke-width="{}"/>"#,
+        x1, y1, x2, y2, width,
+    );
+}
+
+fn push_text(svg: &mut String, x: f32, y: f32, size: f32, weight: &str, content: &str) {
+    let _ = writeln!(
+        svg,
+        r#"<text x="{:.1}" y="{:.1}" font-size="{}" font-weight="{}">{}</text>"#,
+        x,
+        y,
+        size,
+        weight,
+        escape_xml(content),
+    );
+}
+
+/// Cut a label to `max_chars` characters, ending with an ellipsis when shortened
+fn truncate_label(label: &str, max_chars: usize) -> String {
+    if label.chars().count() <= max_chars {
+        return label.to_string();
+    }
+    let mut short: String = label.chars().take(max_chars.saturating_sub(1)).collect();
+    short.push('…');
+    short
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::locale::DateFormat;
+    use chrono::{NaiveTime, Weekday};
+
+    fn locale() -> LocalePreferences {
+        LocalePreferences {
+            use_24_hour: true,
+            first_day_of_week: Weekday::Mon,
+            weekend_days: vec![Weekday::Sat, Weekday::Sun],
+            date_format: DateFormat::DMY,
+            locale_string: "en_GB.UTF-8".to_string(),
+        }
+    }
+
+    fn event(uid: &str, times: Option<(u32, u32)>) -> DisplayEvent {
+        DisplayEvent {
+            calendar_id: "work".to_string(),
+            uid: uid.to_string(),
+            summary: format!("{} <review> & notes", uid),
+            color: "#8B5CF6".to_string(),
+            all_day: times.is_none(),
+            start_time: times.map(|(start, _)| NaiveTime::from_hms_opt(start, 0, 0).unwrap()),
+            end_time: times.map(|(_, end)| NaiveTime::from_hms_opt(end, 0, 0).unwrap()),
+            ..DisplayEvent::test_default()
+        }
+    }
+
+    /// Value of a numeric attribute in one SVG element line
+    fn attribute(line: &str, name: &str) -> f32 {
+        let start = line.find(&format!(" {}=\"", name)).unwrap() + name.len() + 3;
+        let end = start + line[start..].find('"').unwrap();
+        line[start..end].parse().unwrap()
+    }
+
+    #[test]
+    fn test_print_page_has_fixed_size_and_contains_week() {
+        let locale = locale();
+        let week = WeekState::new(NaiveDate::from_ymd_opt(2025, 3, 12).unwrap(), Weekday::Mon, true, &locale);
+        let mut events = HashMap::new();
+        events.insert(week.days[0], vec![event("standup", Some((9, 10))), event("pairing", Some((9, 11)))]);
+        events.insert(week.days[2], vec![event("offsite", None), event("late", Some((23, 23)))]);
+
+        let svg = render_week_print(&week, &events, &locale);
+
+        let header = svg.lines().next().unwrap();
+        assert_eq!(attribute(header, "width"), PRINT_PAGE_WIDTH as f32);
+        assert_eq!(attribute(header, "height"), PRINT_PAGE_HEIGHT as f32);
+        assert!(svg.trim_end().ends_with("</svg>"));
+
+        // Four event boxes plus the page background, all on the page
+        let rects: Vec<&str> = svg.lines().filter(|l| l.starts_with("<rect")).collect();
+        assert_eq!(rects.len(), 5);
+        for rect in rects {
+            assert!(attribute(rect, "x") + attribute(rect, "width") <= PRINT_PAGE_WIDTH as f32);
+            assert!(attribute(rect, "y") + attribute(rect, "height") <= PRINT_PAGE_HEIGHT as f32);
+        }
+
+        // No theme or calendar colors, and text is escaped
+        assert!(!svg.contains("#8B5CF6"));
+        assert!(svg.contains("&lt;review&gt; &amp;"));
+        assert!(svg.contains(&week.week_range_text));
+        assert!(svg.contains(">13:00<"));
+    }
+
+    #[test]
+    fn test_truncate_label() {
+        assert_eq!(truncate_label("Standup", 10), "Standup");
+        assert_eq!(truncate_label("Quarterly planning", 8), "Quarter…");
+    }
+}
@@ -1,32 +1,41 @@
 //! Time grid rendering for the week view
 //!
 //! Contains the time labels column and hour cell grid background.
+//! Hours outside the user's working day are dimmed when off-hours shading is on.
+//! Hour cells are split into `TIME_SELECTION_SNAP_MINUTES` slots so selections snap to quarter hours.
 
-use chrono::{NaiveDate, NaiveTime};
-use cosmic::iced::{alignment, Border, Length};
+use chrono::{NaiveDate, NaiveTime, Timelike};
+use cosmic::iced::{alignment, Length};
 use cosmic::widget::{column, container, mouse_area};
 use cosmic::{widget, Element};
 
 use crate::locale::LocalePreferences;
 use crate::message::Message;
-use crate::selection::SelectionState;
-use crate::styles::weekend_background;
+use crate::selection::{SelectionState, TIME_SELECTION_SNAP_MINUTES};
+use crate::settings::{GridLineStyle, WorkingHours};
+use crate::styles::{grid_border, hour_cell_background};
 use crate::ui_constants::{
-    PADDING_SMALL, FONT_SIZE_SMALL, COLOR_DAY_CELL_BORDER,
+    PADDING_SMALL, FONT_SIZE_SMALL,
     HOUR_ROW_HEIGHT, TIME_LABEL_WIDTH, BORDER_WIDTH_THIN, COLOR_CURRENT_TIME,
 };
 
+/// Hour labels for the time column, in the clock format of `locale`.
+/// The app's locale already carries the user's 12h/24h override from settings.
+fn hour_labels(locale: &LocalePreferences) -> Vec<String> {
+    (0..24).map(|hour| locale.format_hour(hour)).collect()
+}
+
 /// Render the time labels column (left side)
 pub fn render_time_labels_column<'a>(
     locale: &'a LocalePreferences,
-    today_in_view: bool,
-    current_hour: u32,
+    current_time: Option<NaiveTime>,
+    grid_lines: GridLineStyle,
 ) -> Element<'a, Message> {
     let mut col = column().spacing(0);
+    let current_hour = current_time.map(|time| time.hour());
 
-    for hour in 0..24 {
-        let is_current_hour = today_in_view && hour == current_hour;
-        let time_label = locale.format_hour(hour);
+    for (hour, time_label) in (0u32..).zip(hour_labels(locale)) {
+        let is_current_hour = current_hour == Some(hour);
 
         col = col.push(
             container(
@@ -43,11 +52,7 @@ pub fn render_time_labels_column<'a>(
                 } else {
                     None
                 },
-                border: Border {
-                    width: BORDER_WIDTH_THIN,
-                    color: COLOR_DAY_CELL_BORDER,
-                    ..Default::default()
-                },
+                border: grid_border(grid_lines, BORDER_WIDTH_THIN),
                 ..Default::default()
             })
         );
@@ -56,52 +61,79 @@ pub fn render_time_labels_column<'a>(
     col.into()
 }
 
-/// Render the hour grid background (lines only, no events or time indicator) with clickable time slots
+/// Render the hour grid background (lines only, no events or time indicator) with clickable time slots.
+/// Hours outside `working_hours` are dimmed.
 pub fn render_hour_grid_background(
     date: NaiveDate,
     is_weekend: bool,
     selection: Option<&SelectionState>,
+    grid_lines: GridLineStyle,
+    working_hours: Option<WorkingHours>,
 ) -> Element<'static, Message> {
     let mut hour_cells = column().spacing(0);
 
     for hour in 0..24u32 {
-        // Check if this hour cell is within the current selection
-        let is_selected = selection.map(|s| s.is_active && s.contains_time(date, hour)).unwrap_or(false);
-        let cell = render_clickable_hour_cell(date, hour, is_weekend, is_selected);
+        let is_off_hours = working_hours.is_some_and(|hours| hours.is_off_hour(hour));
+        let cell = render_clickable_hour_cell(date, hour, is_weekend, is_off_hours, selection, grid_lines);
         hour_cells = hour_cells.push(cell);
     }
 
     hour_cells.into()
 }
 
-/// Render a clickable hour cell (for creating new events and drag targets)
-fn render_clickable_hour_cell(date: NaiveDate, hour: u32, is_weekend: bool, is_selected: bool) -> Element<'static, Message> {
-    // Create the time for this hour cell
-    let start_time = NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
-    let _end_time = NaiveTime::from_hms_opt(hour, 59, 59).unwrap_or_else(|| {
-        NaiveTime::from_hms_opt(23, 59, 59).unwrap()
+/// Start times of the selectable slots within an hour (:00, :15, :30, :45)
+fn hour_slot_times(hour: u32) -> Vec<NaiveTime> {
+    (0..60)
+        .step_by(TIME_SELECTION_SNAP_MINUTES as usize)
+        .filter_map(|minute| NaiveTime::from_hms_opt(hour, minute, 0))
+        .collect()
+}
+
+/// Render a clickable hour cell (for creating new events and drag targets).
+/// The grid line, weekend and off-hours shading belong to the hour; selection works per slot.
+fn render_clickable_hour_cell(
+    date: NaiveDate,
+    hour: u32,
+    is_weekend: bool,
+    is_off_hours: bool,
+    selection: Option<&SelectionState>,
+    grid_lines: GridLineStyle,
+) -> Element<'static, Message> {
+    let slots = hour_slot_times(hour);
+    let slot_height = HOUR_ROW_HEIGHT / slots.len() as f32;
+
+    let slot_column = slots.into_iter().fold(column().spacing(0), |slot_column, start_time| {
+        // Check if this slot is within the current selection
+        let is_selected = selection.is_some_and(|s| s.is_active && s.contains_time(date, start_time));
+        slot_column.push(render_time_slot(date, start_time, slot_height, is_selected))
     });
 
-    let cell = container(widget::text(""))
+    container(slot_column)
         .width(Length::Fill)
         .height(Length::Fixed(HOUR_ROW_HEIGHT))
+        .style(move |_theme: &cosmic::Theme| container::Style {
+            background: hour_cell_background(is_weekend, is_off_hours),
+            border: grid_border(grid_lines, BORDER_WIDTH_THIN),
+            ..Default::default()
+        })
+        .into()
+}
+
+/// Render one selectable slot of an hour cell
+fn render_time_slot(date: NaiveDate, start_time: NaiveTime, height: f32, is_selected: bool) -> Element<'static, Message> {
+    let slot = container(widget::text(""))
+        .width(Length::Fill)
+        .height(Length::Fixed(height))
         .style(move |theme: &cosmic::Theme| {
-            let background = if is_selected {
-                // Use theme accent color for selection (consistent with month view)
+            // Use theme accent color for selection (consistent with month view)
+            let background = is_selected.then(|| {
                 let accent = theme.cosmic().accent_color();
-                Some(cosmic::iced::Background::Color(cosmic::iced::Color::from_rgba(
+                cosmic::iced::Background::Color(cosmic::iced::Color::from_rgba(
                     accent.red, accent.green, accent.blue, 0.2
-                )))
-            } else {
-                weekend_background(is_weekend)
-            };
+                ))
+            });
             container::Style {
                 background,
-                border: Border {
-                    width: BORDER_WIDTH_THIN,
-                    color: COLOR_DAY_CELL_BORDER,
-                    ..Default::default()
-                },
                 ..Default::default()
             }
         });
@@ -109,11 +141,53 @@ fn render_clickable_hour_cell(date: NaiveDate, hour: u32, is_weekend: bool, is_s
     // Press: start time selection for creating timed events
     // Release: end time selection
     // on_enter: update time selection (for drag selection)
-    // Double-click: open new event dialog
-    mouse_area(cell)
+    // Double-click: create an event at this slot (quick input or dialog, per settings)
+    mouse_area(slot)
         .on_press(Message::TimeSelectionStart(date, start_time))
         .on_release(Message::TimeSelectionEnd)
-        .on_double_click(Message::OpenNewEventDialog)
+        .on_double_click(Message::CreateEventAt(date, Some(start_time)))
         .on_enter(Message::TimeSelectionUpdate(date, start_time))
         .into()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::locale::DateFormat;
+    use chrono::Weekday;
+
+    fn us_locale() -> LocalePreferences {
+        LocalePreferences {
+            use_24_hour: false,
+            first_day_of_week: Weekday::Sun,
+            weekend_days: vec![Weekday::Sat, Weekday::Sun],
+            date_format: DateFormat::MDY,
+            locale_string: "en_US.UTF-8".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_hour_slots_snap_to_interval() {
+        let slots = hour_slot_times(9);
+        assert_eq!(slots.len(), (60 / TIME_SELECTION_SNAP_MINUTES) as usize);
+        assert_eq!(slots.first(), NaiveTime::from_hms_opt(9, 0, 0).as_ref());
+        assert_eq!(slots.get(1), NaiveTime::from_hms_opt(9, TIME_SELECTION_SNAP_MINUTES, 0).as_ref());
+        assert!(slots.iter().all(|slot| slot.format("%H").to_string() == "09"));
+    }
+
+    #[test]
+    fn test_labels_use_24_hour_override_in_12_hour_locale() {
+        let labels = hour_labels(&us_locale().with_clock_override(Some(true)));
+        assert_eq!(labels.len(), 24);
+        assert_eq!(labels[0], "00:00");
+        assert_eq!(labels[13], "13:00");
+        assert_eq!(labels[23], "23:00");
+    }
+
+    #[test]
+    fn test_labels_switch_with_override() {
+        assert_eq!(hour_labels(&us_locale().with_clock_override(None))[13], "1 PM");
+        assert_eq!(hour_labels(&us_locale().with_clock_override(Some(true)))[13], "13:00");
+        assert_eq!(hour_labels(&us_locale().with_clock_override(Some(false)))[0], "12 AM");
+    }
+}
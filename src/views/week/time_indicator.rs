@@ -2,6 +2,7 @@
 //!
 //! Renders the red line and dot showing the current time.
 
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Timelike};
 use cosmic::iced::{alignment, Background, Border, Length};
 use cosmic::widget::{column, container, row};
 use cosmic::{widget, Element};
@@ -10,19 +11,29 @@ use crate::components::spacer::vertical_spacer;
 use crate::message::Message;
 use crate::ui_constants::{HOUR_ROW_HEIGHT, COLOR_CURRENT_TIME, BORDER_RADIUS};
 
+/// Index of today among `days` and the time of `now`, or None when today isn't shown.
+/// Views showing other days skip the indicator entirely.
+pub fn current_time_in_days(days: &[NaiveDate], now: NaiveDateTime) -> Option<(usize, NaiveTime)> {
+    let index = days.iter().position(|day| *day == now.date())?;
+    Some((index, now.time()))
+}
+
+/// Distance of `time` from the top of the 24-hour grid, to the minute
+fn indicator_offset(time: NaiveTime) -> f32 {
+    time.hour() as f32 * HOUR_ROW_HEIGHT + (time.minute() as f32 / 60.0) * HOUR_ROW_HEIGHT
+}
+
 /// Render the current time indicator as a separate overlay layer
 /// This is rendered on top of events so the red line is always visible
 pub fn render_time_indicator_layer(
-    current_hour: u32,
-    minute_offset: f32,
+    now: NaiveTime,
     show_dot: bool,
 ) -> Element<'static, Message> {
     // Total height of the grid (24 hours)
     let total_height = 24.0 * HOUR_ROW_HEIGHT;
 
     // Calculate position from start of day
-    let hour_offset = current_hour as f32 * HOUR_ROW_HEIGHT;
-    let total_offset = hour_offset + minute_offset;
+    let total_offset = indicator_offset(now);
 
     // Indicator dimensions
     let dot_size = 8.0_f32;
@@ -95,3 +106,29 @@ pub fn render_time_indicator_layer(
         .width(Length::Fill)
         .into()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 3, day).unwrap()
+    }
+
+    #[test]
+    fn test_indicator_only_when_today_is_shown() {
+        let days: Vec<NaiveDate> = (10..17).map(date).collect();
+        let now = date(12).and_hms_opt(14, 45, 30).unwrap();
+        assert_eq!(current_time_in_days(&days, now), Some((2, now.time())));
+        assert_eq!(current_time_in_days(&days, date(20).and_hms_opt(14, 45, 0).unwrap()), None);
+        assert_eq!(current_time_in_days(&[], now), None);
+    }
+
+    #[test]
+    fn test_offset_follows_the_minute() {
+        let offset = |h, m| indicator_offset(NaiveTime::from_hms_opt(h, m, 0).unwrap());
+        assert_eq!(offset(0, 0), 0.0);
+        assert_eq!(offset(14, 30), 14.5 * HOUR_ROW_HEIGHT);
+        assert!(offset(14, 31) > offset(14, 30));
+    }
+}
@@ -6,6 +6,7 @@ use chrono::{NaiveDate, NaiveTime, Timelike};
 use std::collections::HashMap;
 
 use crate::components::DisplayEvent;
+use crate::selection::EventResizeState;
 use crate::ui_constants::HOUR_ROW_HEIGHT;
 
 /// Represents an event with its calculated column position for overlap handling
@@ -16,6 +17,10 @@ pub struct PositionedEvent {
     pub total_columns: usize,
 }
 
+/// Most side-by-side columns of overlapping events a day shows;
+/// events beyond them are hidden behind a "+N" indicator
+pub const MAX_VISIBLE_EVENT_COLUMNS: usize = 4;
+
 /// Height of the day header row
 pub const DAY_HEADER_HEIGHT: f32 = 60.0;
 
@@ -51,6 +56,36 @@ pub fn separate_events(
     (all_day, timed)
 }
 
+/// The part of an event shown on `date` in the time grid. Timed events running
+/// over several days start at midnight on the days after their first and run to
+/// the end of the day on the days before their last; other events are unchanged.
+pub fn clip_to_day(event: &DisplayEvent, date: NaiveDate) -> DisplayEvent {
+    let mut segment = event.clone();
+    if !event.is_multi_day_timed() {
+        return segment;
+    }
+    if event.span_start.is_some_and(|first| date > first) {
+        segment.start_time = Some(NaiveTime::MIN);
+    }
+    if event.span_end.is_some_and(|last| date < last) {
+        segment.end_time = NaiveTime::from_hms_opt(23, 59, 59);
+    }
+    segment
+}
+
+/// Show the event being resized at its new times (live preview while an edge is dragged)
+pub fn apply_resize_preview(timed_events: &mut HashMap<NaiveDate, Vec<DisplayEvent>>, resize: &EventResizeState) {
+    if !resize.is_active {
+        return;
+    }
+    for event in timed_events.values_mut().flatten() {
+        if let Some((start, end)) = resize.preview_for(&event.unique_id()) {
+            event.start_time = Some(start);
+            event.end_time = Some(end);
+        }
+    }
+}
+
 /// Calculate the maximum number of all-day event slots needed
 pub fn calculate_max_all_day_slots(all_day_events: &HashMap<NaiveDate, Vec<DisplayEvent>>) -> usize {
     all_day_events.values().map(|v| v.len()).max().unwrap_or(0)
@@ -61,8 +96,9 @@ pub fn event_time_range(event: &DisplayEvent) -> (u32, u32) {
     let start = event.start_time
         .map(|t| t.hour() * 60 + t.minute())
         .unwrap_or(0);
+    // A partial minute counts, so 23:59:59 (end of day) ends at 24:00
     let end = event.end_time
-        .map(|t| t.hour() * 60 + t.minute())
+        .map(|t| t.hour() * 60 + t.minute() + u32::from(t.second() > 0))
         .unwrap_or(start + 60); // Default 1 hour if no end time
 
     // Ensure end is after start
@@ -148,8 +184,20 @@ pub fn calculate_event_columns(events: &[DisplayEvent]) -> Vec<PositionedEvent>
     positioned
 }
 
+/// Split positioned events into those shown in the first `max_columns` columns
+/// and the events hidden beyond them. Shown events keep their column, with
+/// `total_columns` clamped to `max_columns`.
+pub fn split_overflow_columns(positioned: Vec<PositionedEvent>, max_columns: usize) -> (Vec<PositionedEvent>, Vec<DisplayEvent>) {
+    let (mut visible, hidden): (Vec<_>, Vec<_>) = positioned
+        .into_iter()
+        .partition(|pe| pe.column < max_columns);
+    for pe in &mut visible {
+        pe.total_columns = pe.total_columns.min(max_columns);
+    }
+    (visible, hidden.into_iter().map(|pe| pe.event).collect())
+}
+
 /// Calculate the height for a time span in pixels
-#[allow(dead_code)]
 pub fn time_span_to_height(start_mins: u32, end_mins: u32) -> f32 {
     ((end_mins - start_mins) as f32 / 60.0) * HOUR_ROW_HEIGHT
 }
@@ -159,3 +207,71 @@ pub fn time_span_to_height(start_mins: u32, end_mins: u32) -> f32 {
 pub fn time_to_offset(hour: u32, minute: u32) -> f32 {
     (hour as f32 + minute as f32 / 60.0) * HOUR_ROW_HEIGHT
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timed_event(uid: &str, start_hour: u32, end_hour: u32) -> DisplayEvent {
+        DisplayEvent {
+            calendar_id: "work".to_string(),
+            uid: uid.to_string(),
+            summary: uid.to_string(),
+            start_time: NaiveTime::from_hms_opt(start_hour, 0, 0),
+            end_time: NaiveTime::from_hms_opt(end_hour, 0, 0),
+            ..DisplayEvent::test_default()
+        }
+    }
+
+    #[test]
+    fn test_overlapping_events_beyond_max_columns_are_hidden() {
+        // Six events at the same time, plus one later on its own
+        let mut events: Vec<DisplayEvent> = (0..6).map(|i| timed_event(&format!("meeting-{}", i), 9, 10)).collect();
+        events.push(timed_event("lunch", 12, 13));
+
+        let positioned = calculate_event_columns(&events);
+        assert_eq!(positioned.iter().map(|pe| pe.total_columns).max(), Some(6));
+
+        let (visible, hidden) = split_overflow_columns(positioned, 4);
+        assert_eq!(visible.len(), 5);
+        assert_eq!(hidden.len(), 2);
+        assert!(visible.iter().all(|pe| pe.column < 4 && pe.total_columns <= 4));
+        // Events without overlaps keep their full width
+        let lunch = visible.iter().find(|pe| pe.event.uid == "lunch").unwrap();
+        assert_eq!(lunch.total_columns, 1);
+    }
+
+    #[test]
+    fn test_multi_day_timed_event_segments() {
+        // Monday 10:00 to Wednesday 14:00
+        let monday = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+        let wednesday = monday + chrono::Duration::days(2);
+        let mut event = timed_event("offsite", 10, 14);
+        event.span_start = Some(monday);
+        event.span_end = Some(wednesday);
+
+        let heights: Vec<f32> = (0..3)
+            .map(|offset| {
+                let segment = clip_to_day(&event, monday + chrono::Duration::days(offset));
+                let (start, end) = event_time_range(&segment);
+                time_span_to_height(start, end)
+            })
+            .collect();
+        // Partial on the first and last day, full height in between
+        assert_eq!(heights, vec![14.0 * HOUR_ROW_HEIGHT, 24.0 * HOUR_ROW_HEIGHT, 14.0 * HOUR_ROW_HEIGHT]);
+        assert_eq!(event_time_range(&clip_to_day(&event, monday)), (10 * 60, 24 * 60));
+        assert_eq!(event_time_range(&clip_to_day(&event, wednesday)), (0, 14 * 60));
+
+        // Saturday 10:00 to Tuesday 14:00 seen from the next week: Monday is a
+        // full day, Tuesday runs from midnight
+        let mut weekend = timed_event("weekend", 10, 14);
+        weekend.span_start = Some(monday - chrono::Duration::days(2));
+        weekend.span_end = Some(monday + chrono::Duration::days(1));
+        assert_eq!(event_time_range(&clip_to_day(&weekend, monday)), (0, 24 * 60));
+        assert_eq!(event_time_range(&clip_to_day(&weekend, monday + chrono::Duration::days(1))), (0, 14 * 60));
+
+        // Single-day events are left as they are
+        let standup = timed_event("standup", 9, 10);
+        assert_eq!(event_time_range(&clip_to_day(&standup, monday)), (9 * 60, 10 * 60));
+    }
+}
@@ -140,9 +140,8 @@ fn render_mini_month(
     );
 
     // Weekday headers (abbreviated, single letter for space)
-    let weekday_names = localized_names::get_weekday_names_short();
     let mut header_row = row().spacing(SPACING_XXS);
-    for weekday in &weekday_names {
+    for weekday in month_state.weekdays().into_iter().map(localized_names::get_weekday_short) {
         let first_char = weekday.chars().next().unwrap_or(' ').to_string();
         header_row = header_row.push(
             container(widget::text(first_char).size(FONT_SIZE_SMALL))